@@ -10,6 +10,7 @@
 extern crate criterion;
 
 use criterion::Criterion;
+use opaque_ke::key_exchange::group::Group;
 use opaque_ke::*;
 use rand::rngs::OsRng;
 
@@ -34,6 +35,35 @@ impl CipherSuite for Default {
     type Ksf = opaque_ke::ksf::Identity;
 }
 
+// Key generation, which the ephemeral keys of every login also go through, for
+// each key exchange group that is enabled. Compare against a run with the
+// `precomputed-tables` feature, which also requires `std` for P-256 and
+// secp256k1:
+//
+// cargo bench --bench opaque --features curve25519,ed25519,secp256k1 -- --save-baseline generic "key generation|login start"
+// cargo bench --bench opaque --features curve25519,ed25519,secp256k1,precomputed-tables,std -- --baseline generic "key generation|login start"
+fn key_generation(c: &mut Criterion) {
+    fn bench<G: Group>(c: &mut Criterion, name: &str) {
+        let mut rng = OsRng;
+
+        c.bench_function(&format!("key generation ({name})"), move |b| {
+            b.iter(|| {
+                G::public_key(&G::random_sk(&mut rng));
+            })
+        });
+    }
+
+    #[cfg(feature = "ristretto255")]
+    bench::<opaque_ke::Ristretto255>(c, "ristretto255");
+    #[cfg(feature = "curve25519")]
+    bench::<opaque_ke::Curve25519>(c, "curve25519");
+    #[cfg(feature = "ed25519")]
+    bench::<opaque_ke::Ed25519>(c, "ed25519");
+    bench::<p256::NistP256>(c, "p256");
+    #[cfg(feature = "secp256k1")]
+    bench::<opaque_ke::Secp256k1>(c, "secp256k1");
+}
+
 fn server_setup(c: &mut Criterion) {
     let mut rng = OsRng;
 
@@ -323,6 +353,7 @@ fn server_login_finish(c: &mut Criterion) {
 
 criterion_group!(
     opaque_benches,
+    key_generation,
     server_setup,
     client_registration_start,
     server_registration_start,
@@ -0,0 +1,127 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Demonstrates a registration and login with a ciphersuite restricted to
+//! FIPS-approved primitives
+//!
+//! The OPRF and the 3DH key exchange run over P-256 with SHA-256, and the
+//! password is stretched with PBKDF2-HMAC-SHA256 instead of a memory-hard
+//! function such as Argon2 or scrypt, which are not FIPS-approved.
+//!
+//! Note that this crate itself is not FIPS-validated, the example only shows
+//! how to restrict the choice of primitives.
+
+use opaque_ke::ksf::Pbkdf2;
+use opaque_ke::rand::rngs::OsRng;
+use opaque_ke::suites::P256Sha256TripleDh;
+use opaque_ke::{
+    ClientLogin, ClientLoginFinishParameters, ClientRegistration,
+    ClientRegistrationFinishParameters, CredentialFinalization, CredentialRequest,
+    CredentialResponse, Identifiers, RegistrationRequest, RegistrationResponse, RegistrationUpload,
+    ServerLogin, ServerLoginParameters, ServerRegistration, ServerSetup,
+};
+
+// P-256/SHA-256 with TripleDh and PBKDF2-HMAC-SHA256
+type FipsCipherSuite = P256Sha256TripleDh<Pbkdf2<sha2::Sha256>>;
+
+fn main() {
+    let mut client_rng = OsRng;
+    let mut server_rng = OsRng;
+    let username = b"alice";
+    let password = b"correct horse battery staple";
+
+    // The client and the server have to agree on the number of iterations,
+    // `Pbkdf2::default()` follows the OWASP recommendation.
+    let ksf = Pbkdf2::default();
+    println!("PBKDF2-HMAC-SHA256 with {} iterations", ksf.iterations());
+
+    let server_setup = ServerSetup::<FipsCipherSuite>::new(&mut server_rng);
+
+    // Registration
+
+    let client_registration_start_result =
+        ClientRegistration::<FipsCipherSuite>::start(&mut client_rng, password).unwrap();
+    let registration_request_bytes = client_registration_start_result.message.serialize();
+
+    // Client sends registration_request_bytes to server
+
+    let server_registration_start_result = ServerRegistration::<FipsCipherSuite>::start(
+        &server_setup,
+        RegistrationRequest::deserialize(&registration_request_bytes).unwrap(),
+        username,
+    )
+    .unwrap();
+    let registration_response_bytes = server_registration_start_result.message.serialize();
+
+    // Server sends registration_response_bytes to client
+
+    let client_registration_finish_result = client_registration_start_result
+        .state
+        .finish(
+            &mut client_rng,
+            password,
+            RegistrationResponse::deserialize(&registration_response_bytes).unwrap(),
+            ClientRegistrationFinishParameters::new(Identifiers::default(), Some(&ksf)),
+        )
+        .unwrap();
+    let registration_upload_bytes = client_registration_finish_result.message.serialize();
+
+    // Client sends registration_upload_bytes to server
+
+    let password_file = ServerRegistration::finish(
+        RegistrationUpload::<FipsCipherSuite>::deserialize(&registration_upload_bytes).unwrap(),
+    );
+
+    // Login
+
+    let client_login_start_result =
+        ClientLogin::<FipsCipherSuite>::start(&mut client_rng, password).unwrap();
+    let credential_request_bytes = client_login_start_result.message.serialize();
+
+    // Client sends credential_request_bytes to server
+
+    let server_login_start_result = ServerLogin::start(
+        &mut server_rng,
+        &server_setup,
+        Some(password_file),
+        CredentialRequest::deserialize(&credential_request_bytes).unwrap(),
+        username,
+        ServerLoginParameters::default(),
+    )
+    .unwrap();
+    let credential_response_bytes = server_login_start_result.message.serialize();
+
+    // Server sends credential_response_bytes to client
+
+    let client_login_finish_result = client_login_start_result
+        .state
+        .finish(
+            &mut client_rng,
+            password,
+            CredentialResponse::deserialize(&credential_response_bytes).unwrap(),
+            ClientLoginFinishParameters::new(None, Identifiers::default(), Some(&ksf)),
+        )
+        .unwrap();
+    let credential_finalization_bytes = client_login_finish_result.message.serialize();
+
+    // Client sends credential_finalization_bytes to server
+
+    let server_login_finish_result = server_login_start_result
+        .state
+        .finish(
+            CredentialFinalization::deserialize(&credential_finalization_bytes).unwrap(),
+            ServerLoginParameters::default(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        client_login_finish_result.session_key,
+        server_login_finish_result.session_key
+    );
+    println!("Login success!");
+}
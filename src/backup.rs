@@ -0,0 +1,252 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Threshold backup of [`ServerSetup`](crate::ServerSetup)
+//!
+//! [`ServerSetup::split_backup()`](crate::ServerSetup::split_backup) splits
+//! the [serialized](crate::ServerSetup::serialize) server setup into shares
+//! with Shamir's secret sharing over GF(2^8), so that any `threshold` of them
+//! can [restore](crate::ServerSetup::restore) it, while fewer reveal nothing
+//! about it. Every share consists of:
+//! - the [`MAGIC`] value,
+//! - the format [`VERSION`],
+//! - the two byte big-endian ciphersuite identifier
+//!   [`CipherSuite::SUITE_ID`],
+//! - the threshold,
+//! - the index of the share, starting at 1,
+//! - a commitment to the server setup, the hash of `"OPAQUE-Backup"`, a random
+//!   nonce and the serialized server setup with the OPRF hash of the
+//!   ciphersuite,
+//! - the share of the serialized server setup followed by the nonce,
+//! - a checksum, the hash of all of the above.
+//!
+//! The checksum detects corrupted shares, the commitment detects shares of
+//! different backups and verifies the restored server setup. As the nonce is
+//! shared along with the server setup, fewer than `threshold` shares can't be
+//! used to confirm guesses of the server setup with the commitment.
+
+use alloc::vec::Vec;
+
+use digest::{Digest, Output};
+use generic_array::typenum::Unsigned;
+use rand::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+use crate::ciphersuite::{CipherSuite, OprfHash};
+use crate::errors::ProtocolError;
+use crate::hash::OutputSize;
+
+/// The magic value every backup share starts with
+pub const MAGIC: [u8; 4] = *b"OPQB";
+
+/// The version of the backup share encoding
+pub const VERSION: u8 = 1;
+
+const STR_BACKUP: &[u8; 13] = b"OPAQUE-Backup";
+const HEADER_LEN: usize = 9;
+
+// Splits `secret` into `shares` shares, `threshold` of which are required to
+// recover it.
+pub(crate) fn split<CS: CipherSuite, R: RngCore + CryptoRng>(
+    rng: &mut R,
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<Zeroizing<Vec<u8>>>, ProtocolError> {
+    if threshold == 0 || threshold > shares {
+        return Err(ProtocolError::InvalidBackupThresholdError { threshold, shares });
+    }
+
+    let mut nonce = Zeroizing::new(Output::<OprfHash<CS>>::default());
+    rng.fill_bytes(&mut nonce);
+    let commitment = compute_commitment::<CS>(&nonce, secret);
+
+    // The coefficients of a random polynomial for every byte of the secret and
+    // the nonce, whose constant term is that byte.
+    let mut coefficients = Zeroizing::new(Vec::with_capacity(
+        (secret.len() + nonce.len()) * usize::from(threshold),
+    ));
+
+    for byte in secret.iter().chain(nonce.iter()) {
+        coefficients.push(*byte);
+
+        for _ in 1..threshold {
+            let mut coefficient = [0];
+            rng.fill_bytes(&mut coefficient);
+            coefficients.push(coefficient[0]);
+        }
+    }
+
+    Ok((1..=shares)
+        .map(|index| {
+            let mut share = Zeroizing::new(Vec::with_capacity(
+                HEADER_LEN + 3 * OutputSize::<OprfHash<CS>>::USIZE + secret.len(),
+            ));
+            share.extend_from_slice(&MAGIC);
+            share.push(VERSION);
+            share.extend_from_slice(&CS::SUITE_ID.to_be_bytes());
+            share.push(threshold);
+            share.push(index);
+            share.extend_from_slice(&commitment);
+
+            for polynomial in coefficients.chunks(threshold.into()) {
+                // Horner's method
+                let value = polynomial
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| mul(value, index) ^ coefficient);
+                share.push(value);
+            }
+
+            let checksum = OprfHash::<CS>::digest(&share);
+            share.extend_from_slice(&checksum);
+
+            share
+        })
+        .collect())
+}
+
+// Checks all `shares` and recovers the secret.
+pub(crate) fn combine<CS: CipherSuite, S: AsRef<[u8]>>(
+    shares: &[S],
+) -> Result<Zeroizing<Vec<u8>>, ProtocolError> {
+    let hash_len = OutputSize::<OprfHash<CS>>::USIZE;
+    let mut parsed = Vec::with_capacity(shares.len());
+
+    for share in shares {
+        let share = share.as_ref();
+
+        if share.len() < HEADER_LEN + 3 * hash_len {
+            return Err(ProtocolError::InvalidBackupShareError);
+        }
+
+        if share[..4] != MAGIC {
+            return Err(ProtocolError::InvalidMagicError);
+        }
+
+        if share[4] != VERSION {
+            return Err(ProtocolError::UnsupportedVersionError(share[4]));
+        }
+
+        let actual = u16::from_be_bytes([share[5], share[6]]);
+
+        if actual != CS::SUITE_ID {
+            return Err(ProtocolError::CipherSuiteMismatchError {
+                expected: CS::SUITE_ID,
+                actual,
+            });
+        }
+
+        let (share, checksum) = share.split_at(share.len() - hash_len);
+
+        if !bool::from(OprfHash::<CS>::digest(share).as_slice().ct_eq(checksum)) {
+            return Err(ProtocolError::InvalidBackupShareError);
+        }
+
+        parsed.push(share);
+    }
+
+    let Some(first) = parsed.first() else {
+        return Err(ProtocolError::InsufficientBackupSharesError {
+            threshold: 1,
+            actual: 0,
+        });
+    };
+    let threshold = first[7];
+    let header = &first[..HEADER_LEN - 1];
+    let commitment = &first[HEADER_LEN..HEADER_LEN + hash_len];
+
+    for (position, share) in parsed.iter().enumerate() {
+        // All shares have to belong to the same backup and have distinct
+        // indices.
+        if share.len() != first.len()
+            || &share[..HEADER_LEN - 1] != header
+            || &share[HEADER_LEN..HEADER_LEN + hash_len] != commitment
+            || share[HEADER_LEN - 1] == 0
+            || parsed[..position]
+                .iter()
+                .any(|other| other[HEADER_LEN - 1] == share[HEADER_LEN - 1])
+        {
+            return Err(ProtocolError::InvalidBackupShareError);
+        }
+    }
+
+    if parsed.len() < threshold.into() {
+        return Err(ProtocolError::InsufficientBackupSharesError {
+            threshold,
+            actual: parsed.len(),
+        });
+    }
+
+    let parsed = &parsed[..threshold.into()];
+    let mut secret = Zeroizing::new(Vec::with_capacity(first.len() - HEADER_LEN - hash_len));
+
+    for position in HEADER_LEN + hash_len..first.len() {
+        // Lagrange interpolation at 0
+        let byte = parsed.iter().fold(0, |byte, share| {
+            let index = share[HEADER_LEN - 1];
+            let basis = parsed
+                .iter()
+                .map(|other| other[HEADER_LEN - 1])
+                .filter(|other| *other != index)
+                .fold(1, |basis, other| mul(basis, mul(other, inv(other ^ index))));
+
+            byte ^ mul(share[position], basis)
+        });
+        secret.push(byte);
+    }
+
+    let secret_len = secret.len() - hash_len;
+    let nonce = Zeroizing::new(secret.split_off(secret_len));
+
+    if !bool::from(
+        compute_commitment::<CS>(&nonce, &secret)
+            .as_slice()
+            .ct_eq(commitment),
+    ) {
+        return Err(ProtocolError::InvalidBackupShareError);
+    }
+
+    Ok(secret)
+}
+
+fn compute_commitment<CS: CipherSuite>(nonce: &[u8], secret: &[u8]) -> Output<OprfHash<CS>> {
+    OprfHash::<CS>::new()
+        .chain_update(STR_BACKUP)
+        .chain_update(nonce)
+        .chain_update(secret)
+        .finalize()
+}
+
+// Multiplication in GF(2^8) with the AES polynomial, without secret-dependent
+// branches or lookups.
+fn mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        a = (a << 1) ^ ((a >> 7).wrapping_neg() & 0x1b);
+        b >>= 1;
+    }
+
+    product
+}
+
+// Inversion in GF(2^8) as `a^254`.
+fn inv(a: u8) -> u8 {
+    let a2 = mul(a, a);
+    let a4 = mul(a2, a2);
+    let a8 = mul(a4, a4);
+    let a16 = mul(a8, a8);
+    let a32 = mul(a16, a16);
+    let a64 = mul(a32, a32);
+    let a128 = mul(a64, a64);
+
+    mul(mul(mul(a128, a64), mul(a32, a16)), mul(mul(a8, a4), a2))
+}
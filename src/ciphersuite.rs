@@ -42,11 +42,25 @@ where
     MaskedResponseLen<Self>: ArrayLength<u8>,
 {
     /// A VOPRF ciphersuite, see [`voprf::CipherSuite`].
+    ///
+    /// Decaf448 with SHAKE-256 is not supported: `voprf` provides no Decaf448
+    /// group and its [`voprf::CipherSuite::Hash`] must be a fixed-output hash,
+    /// which SHAKE-256 is not. At the 224-bit security level use P-521.
     type OprfCs: voprf::CipherSuite;
     /// A key exchange protocol
     type KeyExchange: KeyExchange;
     /// A key stretching function, typically used for password hashing
     type Ksf: Ksf;
+
+    /// The ciphersuite identifier used to tag the human-readable `serde`
+    /// representation, see [`framing`](crate::framing). The
+    /// [`suites`](crate::suites) use their
+    /// [`NamedCipherSuite::ID`](crate::suites::NamedCipherSuite::ID), other
+    /// ciphersuites should use an identifier of `0x8000` or above.
+    ///
+    /// Defaults to `0x8000`, which doesn't tell apart the ciphersuites that
+    /// keep the default.
+    const SUITE_ID: u16 = 0x8000;
 }
 
 pub(crate) type OprfGroup<CS: CipherSuite> = <CS::OprfCs as voprf::CipherSuite>::Group;
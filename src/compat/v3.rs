@@ -0,0 +1,106 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Migration of records created by opaque-ke 3.x
+//!
+//! opaque-ke 3.x only supported the 3DH key exchange with the OPRF hash.
+//! Records can therefore only be migrated to a [`CipherSuite`] using
+//! [`TripleDh`](crate::TripleDh) with the same groups and hash as the 3.x
+//! ciphersuite, e.g. a 3.x ciphersuite with `OprfCs = NistP256` and
+//! `KeGroup = NistP256` corresponds to `KeyExchange = TripleDh<NistP256,
+//! Sha256>`. The functions in this module validate the records with the
+//! deserializers of `CS`, e.g. public keys have to be valid non-identity points
+//! and private keys non-zero scalars, but can not detect whether the
+//! ciphersuites match.
+//!
+//! Registration requests, responses and uploads have the same encoding in
+//! both versions, only [`ServerSetup`] has to be converted.
+
+use generic_array::typenum::Unsigned;
+
+use crate::ciphersuite::{CipherSuite, KeGroup, OprfHash};
+use crate::envelope::EnvelopeLen;
+use crate::errors::ProtocolError;
+use crate::hash::OutputSize;
+use crate::key_exchange::group::Group;
+use crate::keypair::{KeyPair, OprfSeed, PrivateKey, PublicKey};
+use crate::serialization::SliceExt;
+use crate::{ServerRegistration, ServerSetup};
+
+/// Length of a serialized opaque-ke 3.x `ServerSetup` in bytes.
+pub fn server_setup_len<CS: CipherSuite>() -> usize {
+    OutputSize::<OprfHash<CS>>::USIZE + 2 * <KeGroup<CS> as Group>::SkLen::USIZE
+}
+
+/// Length of a serialized opaque-ke 3.x `ServerRegistration` in bytes.
+pub fn server_registration_len<CS: CipherSuite>() -> usize {
+    <KeGroup<CS> as Group>::PkLen::USIZE
+        + OutputSize::<OprfHash<CS>>::USIZE
+        + EnvelopeLen::<CS>::USIZE
+}
+
+/// Converts a serialized opaque-ke 3.x `ServerSetup` into a [`ServerSetup`].
+///
+/// The 3.x encoding stores the private key used for dummy credential
+/// responses, while [`ServerSetup`] only stores its public key, which is
+/// derived here.
+///
+/// # Errors
+/// - [`ProtocolError::SizeError`] if `input` has the wrong length.
+/// - [`ProtocolError::SerializationError`] if a private key or a derived
+///   public key is rejected by the deserializers of `CS`.
+pub fn server_setup<CS: CipherSuite>(mut input: &[u8]) -> Result<ServerSetup<CS>, ProtocolError> {
+    check_len(input, server_setup_len::<CS>(), "v3 server setup")?;
+
+    let oprf_seed = input.take_array::<OutputSize<OprfHash<CS>>>("oprf seed")?;
+    let sk = PrivateKey::<KeGroup<CS>>::deserialize_take(&mut input)?;
+    let dummy_sk = PrivateKey::<KeGroup<CS>>::deserialize_take(&mut input)?;
+
+    // Run the derived public keys through the same checks as public keys
+    // deserialized by `ServerSetup::deserialize()`.
+    let pk = PublicKey::deserialize(&sk.public_key().serialize())?;
+    let dummy_pk = PublicKey::deserialize(&dummy_sk.public_key().serialize())?;
+
+    Ok(ServerSetup::from_parts(
+        OprfSeed(oprf_seed),
+        KeyPair::new(sk, pk),
+        dummy_pk,
+    ))
+}
+
+/// Converts a serialized opaque-ke 3.x `ServerRegistration` into a
+/// [`ServerRegistration`].
+///
+/// # Errors
+/// - [`ProtocolError::SizeError`] if `input` has the wrong length.
+/// - [`ProtocolError::SerializationError`] if the client public key is
+///   rejected by the deserializers of `CS`.
+/// - Any other error from [`ServerRegistration::deserialize()`].
+pub fn server_registration<CS: CipherSuite>(
+    input: &[u8],
+) -> Result<ServerRegistration<CS>, ProtocolError> {
+    check_len(
+        input,
+        server_registration_len::<CS>(),
+        "v3 server registration",
+    )?;
+
+    ServerRegistration::deserialize(input)
+}
+
+fn check_len(input: &[u8], len: usize, name: &'static str) -> Result<(), ProtocolError> {
+    if input.len() == len {
+        Ok(())
+    } else {
+        Err(ProtocolError::SizeError {
+            name,
+            len,
+            actual_len: input.len(),
+        })
+    }
+}
@@ -0,0 +1,717 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Selecting a [`CipherSuite`] at runtime
+//!
+//! [`DynServer`] and [`DynClient`] hold any number of registered ciphersuites,
+//! each under a numeric identifier chosen by the application. Every message,
+//! state and password file passing through them uses the
+//! [framed encoding](crate::framing) with the identifier of its ciphersuite,
+//! which is used to dispatch it to the right implementation. This way a single
+//! code path can serve clients using different ciphersuites.
+//!
+//! Messages produced by [`DynServer`] and [`DynClient`] are not compatible with
+//! the plain serialization of the message types, as they carry the header of
+//! the framed encoding.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use generic_array::ArrayLength;
+use generic_array::typenum::Sum;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::ciphersuite::{CipherSuite, KeGroup, KeHash, OprfGroup, OprfHash};
+use crate::envelope::EnvelopeLen;
+use crate::errors::ProtocolError;
+use crate::framing::{self, MessageType};
+use crate::hash::OutputSize;
+use crate::key_exchange::group::Group;
+use crate::key_exchange::shared::{self, NonceLen};
+use crate::key_exchange::{
+    Deserialize, Ke1MessageLen, Ke1StateLen, Ke2MessageLen, KeyExchange, Serialize,
+};
+use crate::messages::{
+    CredentialRequestLen, CredentialResponseLen, CredentialResponseWithoutKeLen,
+    RegistrationResponseLen, RegistrationUploadLen,
+};
+use crate::opaque::{ClientLoginLen, ClientRegistrationLen, MaskedResponseLen};
+use crate::suites::NamedCipherSuite;
+use crate::{
+    ClientLogin, ClientLoginFinishParameters, ClientRegistration,
+    ClientRegistrationFinishParameters, CredentialFinalization, CredentialRequest,
+    CredentialResponse, Identifiers, PreSharedKey, RegistrationRequest, RegistrationResponse,
+    RegistrationUpload, ServerLogin, ServerLoginParameters, ServerRegistration, ServerSetup,
+};
+
+/// A server that dispatches to the [`ServerSetup`] registered for the
+/// ciphersuite identifier of each incoming message.
+#[derive(Default)]
+pub struct DynServer {
+    suites: BTreeMap<u16, Box<dyn ServerSuite>>,
+}
+
+/// A client that can run the protocol with any registered ciphersuite.
+#[derive(Default)]
+pub struct DynClient {
+    suites: BTreeMap<u16, Box<dyn ClientSuite>>,
+}
+
+/// Optional parameters for [`DynClient::registration_finish()`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DynClientRegistrationFinishParameters<'i, 'h> {
+    /// Specifying the identifiers idU and idS
+    pub identifiers: Identifiers<'i>,
+    /// Specifying a configuration for the key stretching function, which has
+    /// to be of the [`CipherSuite::Ksf`] type of the ciphersuite
+    pub ksf: Option<&'h dyn Any>,
+}
+
+/// Optional parameters for [`DynClient::login_finish()`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DynClientLoginFinishParameters<'c, 'i, 'h> {
+    /// Specifying a context field that the server must agree on
+    pub context: Option<&'c [u8]>,
+    /// Specifying a user identifier and server identifier that will be matched
+    /// against the server
+    pub identifiers: Identifiers<'i>,
+    /// Specifying a configuration for the key stretching function, which has
+    /// to be of the [`CipherSuite::Ksf`] type of the ciphersuite
+    pub ksf: Option<&'h dyn Any>,
+    /// Specifying a pre-shared key that the server must agree on
+    pub psk: Option<PreSharedKey<'c>>,
+    /// Specifying a payload to send to the server, see
+    /// [`ClientLoginFinishParameters::with_payload()`]
+    #[cfg(feature = "payload")]
+    pub payload: Option<&'c [u8]>,
+}
+
+/// Contains the fields that are returned by a start of the registration or
+/// login
+pub struct DynStartResult {
+    /// The message to send to the other party
+    pub message: Vec<u8>,
+    /// The state that must be kept in order to finish the protocol
+    pub state: Zeroizing<Vec<u8>>,
+}
+
+/// Contains the fields that are returned by
+/// [`DynClient::registration_finish()`]
+pub struct DynClientRegistrationFinishResult {
+    /// The registration upload message to send to the server
+    pub message: Vec<u8>,
+    /// The client-side export key
+    pub export_key: Zeroizing<Vec<u8>>,
+}
+
+/// Contains the fields that are returned by [`DynClient::login_finish()`]
+pub struct DynClientLoginFinishResult {
+    /// The message to send to the server to complete the protocol
+    pub message: Vec<u8>,
+    /// The session key
+    pub session_key: Zeroizing<Vec<u8>>,
+    /// Secret for [`Self::export_keying_material()`]
+    exporter_secret: Zeroizing<Vec<u8>>,
+    exporter: Exporter,
+    /// The decrypted payload the server sent with
+    /// [`ServerLoginParameters::with_payload()`], if any
+    #[cfg(feature = "payload")]
+    pub server_payload: Option<Vec<u8>>,
+    /// The client-side export key
+    pub export_key: Zeroizing<Vec<u8>>,
+}
+
+/// Contains the fields that are returned by [`DynServer::login_finish()`]
+pub struct DynServerLoginFinishResult {
+    /// The session key between client and server
+    pub session_key: Zeroizing<Vec<u8>>,
+    /// Secret for [`Self::export_keying_material()`]
+    exporter_secret: Zeroizing<Vec<u8>>,
+    exporter: Exporter,
+    /// The decrypted payload the client sent with
+    /// [`DynClientLoginFinishParameters::payload`], if any
+    #[cfg(feature = "payload")]
+    pub client_payload: Option<Vec<u8>>,
+}
+
+// Derives keying material from the exporter secret with the hash of the key
+// exchange of a ciphersuite
+type Exporter = fn(&[u8], &[u8], &[u8], &mut [u8]) -> Result<(), ProtocolError>;
+
+impl DynClientLoginFinishResult {
+    /// Derives keying material of `output.len()` bytes, bound to `label` and
+    /// `context`. See
+    /// [`ClientLoginFinishResult::export_keying_material()`](crate::ClientLoginFinishResult::export_keying_material).
+    pub fn export_keying_material(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), ProtocolError> {
+        (self.exporter)(&self.exporter_secret, label, context, output)
+    }
+}
+
+impl DynServerLoginFinishResult {
+    /// Derives keying material of `output.len()` bytes, bound to `label` and
+    /// `context`. See
+    /// [`ServerLoginFinishResult::export_keying_material()`](crate::ServerLoginFinishResult::export_keying_material).
+    pub fn export_keying_material(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), ProtocolError> {
+        (self.exporter)(&self.exporter_secret, label, context, output)
+    }
+}
+
+impl DynServer {
+    /// Create a [`DynServer`] without any ciphersuites
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `server_setup` under the ciphersuite identifier `id`.
+    /// Registering the same identifier again replaces the previous
+    /// [`ServerSetup`].
+    pub fn register<CS: 'static + CipherSuite>(&mut self, id: u16, server_setup: ServerSetup<CS>)
+    where
+        ServerSetup<CS>: ServerSuite,
+    {
+        self.suites.insert(id, Box::new(server_setup));
+    }
+
+    /// Registers `server_setup` under [`NamedCipherSuite::ID`].
+    pub fn register_named<CS: 'static + CipherSuite + NamedCipherSuite>(
+        &mut self,
+        server_setup: ServerSetup<CS>,
+    ) where
+        ServerSetup<CS>: ServerSuite,
+    {
+        self.register(CS::ID, server_setup);
+    }
+
+    /// Returns the identifiers of all registered ciphersuites
+    pub fn suites(&self) -> impl Iterator<Item = u16> + '_ {
+        self.suites.keys().copied()
+    }
+
+    /// Processes a [`RegistrationRequest`] and returns the
+    /// [`RegistrationResponse`] to send back to the client. See
+    /// [`ServerRegistration::start()`].
+    pub fn registration_start(
+        &self,
+        message: &[u8],
+        credential_identifier: &[u8],
+    ) -> Result<Vec<u8>, ProtocolError> {
+        let (id, message) = unframe(MessageType::RegistrationRequest, message)?;
+        let response = self
+            .suite(id)?
+            .registration_start(message, credential_identifier)?;
+
+        Ok(framing::frame(
+            id,
+            MessageType::RegistrationResponse,
+            &response,
+        ))
+    }
+
+    /// Processes a [`RegistrationUpload`] and returns the password file to
+    /// store. See [`ServerRegistration::finish()`].
+    pub fn registration_finish(&self, message: &[u8]) -> Result<Vec<u8>, ProtocolError> {
+        let (id, message) = unframe(MessageType::RegistrationUpload, message)?;
+        let password_file = self.suite(id)?.registration_finish(message)?;
+
+        Ok(framing::frame(
+            id,
+            MessageType::ServerRegistration,
+            &password_file,
+        ))
+    }
+
+    /// Processes a [`CredentialRequest`] and returns the
+    /// [`CredentialResponse`] together with the server state. See
+    /// [`ServerLogin::start()`].
+    ///
+    /// # Errors
+    /// [`ProtocolError::CipherSuiteMismatchError`] if `password_file` was
+    /// created with a different ciphersuite than the request.
+    pub fn login_start<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        password_file: Option<&[u8]>,
+        message: &[u8],
+        credential_identifier: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<DynStartResult, ProtocolError> {
+        let (id, message) = unframe(MessageType::CredentialRequest, message)?;
+        let password_file = password_file
+            .map(|password_file| {
+                framing::unframe(id, MessageType::ServerRegistration, password_file)
+            })
+            .transpose()?;
+
+        let (response, state) = self.suite(id)?.login_start(
+            rng,
+            password_file,
+            message,
+            credential_identifier,
+            parameters,
+        )?;
+
+        Ok(DynStartResult {
+            message: framing::frame(id, MessageType::CredentialResponse, &response),
+            state: Zeroizing::new(framing::frame(id, MessageType::ServerLogin, &state)),
+        })
+    }
+
+    /// Processes a [`CredentialFinalization`] with the state returned by
+    /// [`Self::login_start()`]. See [`ServerLogin::finish()`].
+    pub fn login_finish(
+        &self,
+        state: &[u8],
+        message: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<DynServerLoginFinishResult, ProtocolError> {
+        let (id, message) = unframe(MessageType::CredentialFinalization, message)?;
+        let state = framing::unframe(id, MessageType::ServerLogin, state)?;
+
+        self.suite(id)?.login_finish(state, message, parameters)
+    }
+
+    fn suite(&self, id: u16) -> Result<&dyn ServerSuite, ProtocolError> {
+        self.suites
+            .get(&id)
+            .map(Box::as_ref)
+            .ok_or(ProtocolError::UnsupportedCipherSuiteError(id))
+    }
+}
+
+impl DynClient {
+    /// Create a [`DynClient`] without any ciphersuites
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the ciphersuite `CS` under the identifier `id`. The default
+    /// [`CipherSuite::Ksf`] configuration is used unless the parameters of
+    /// [`Self::registration_finish()`] or [`Self::login_finish()`] specify one.
+    pub fn register<CS: 'static + CipherSuite>(&mut self, id: u16)
+    where
+        PhantomData<CS>: ClientSuite,
+    {
+        self.suites.insert(id, Box::new(PhantomData::<CS>));
+    }
+
+    /// Registers the ciphersuite `CS` under [`NamedCipherSuite::ID`].
+    pub fn register_named<CS: 'static + CipherSuite + NamedCipherSuite>(&mut self)
+    where
+        PhantomData<CS>: ClientSuite,
+    {
+        self.register::<CS>(CS::ID);
+    }
+
+    /// Returns the identifiers of all registered ciphersuites
+    pub fn suites(&self) -> impl Iterator<Item = u16> + '_ {
+        self.suites.keys().copied()
+    }
+
+    /// Starts the registration with the ciphersuite `id`. See
+    /// [`ClientRegistration::start()`].
+    pub fn registration_start<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        id: u16,
+        password: &[u8],
+    ) -> Result<DynStartResult, ProtocolError> {
+        let (message, state) = self.suite(id)?.registration_start(rng, password)?;
+
+        Ok(DynStartResult {
+            message: framing::frame(id, MessageType::RegistrationRequest, &message),
+            state: Zeroizing::new(framing::frame(id, MessageType::ClientRegistration, &state)),
+        })
+    }
+
+    /// Processes a [`RegistrationResponse`] with the state returned by
+    /// [`Self::registration_start()`]. See [`ClientRegistration::finish()`].
+    ///
+    /// # Errors
+    /// [`ProtocolError::KsfTypeMismatchError`] if
+    /// [`DynClientRegistrationFinishParameters::ksf`] is not of the
+    /// [`CipherSuite::Ksf`] type of the ciphersuite.
+    pub fn registration_finish<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientRegistrationFinishParameters,
+    ) -> Result<DynClientRegistrationFinishResult, ProtocolError> {
+        let (id, message) = unframe(MessageType::RegistrationResponse, message)?;
+        let state = framing::unframe(id, MessageType::ClientRegistration, state)?;
+        let (upload, export_key) = self
+            .suite(id)?
+            .registration_finish(rng, state, password, message, parameters)?;
+
+        Ok(DynClientRegistrationFinishResult {
+            message: framing::frame(id, MessageType::RegistrationUpload, &upload),
+            export_key,
+        })
+    }
+
+    /// Starts the login with the ciphersuite `id`. See
+    /// [`ClientLogin::start()`].
+    pub fn login_start<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        id: u16,
+        password: &[u8],
+    ) -> Result<DynStartResult, ProtocolError> {
+        let (message, state) = self.suite(id)?.login_start(rng, password)?;
+
+        Ok(DynStartResult {
+            message: framing::frame(id, MessageType::CredentialRequest, &message),
+            state: Zeroizing::new(framing::frame(id, MessageType::ClientLogin, &state)),
+        })
+    }
+
+    /// Processes a [`CredentialResponse`] with the state returned by
+    /// [`Self::login_start()`]. See [`ClientLogin::finish()`].
+    ///
+    /// # Errors
+    /// [`ProtocolError::KsfTypeMismatchError`] if
+    /// [`DynClientLoginFinishParameters::ksf`] is not of the
+    /// [`CipherSuite::Ksf`] type of the ciphersuite.
+    pub fn login_finish<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientLoginFinishParameters,
+    ) -> Result<DynClientLoginFinishResult, ProtocolError> {
+        let (id, message) = unframe(MessageType::CredentialResponse, message)?;
+        let state = framing::unframe(id, MessageType::ClientLogin, state)?;
+        let mut result = self
+            .suite(id)?
+            .login_finish(rng, state, password, message, parameters)?;
+        result.message = framing::frame(id, MessageType::CredentialFinalization, &result.message);
+
+        Ok(result)
+    }
+
+    fn suite(&self, id: u16) -> Result<&dyn ClientSuite, ProtocolError> {
+        self.suites
+            .get(&id)
+            .map(Box::as_ref)
+            .ok_or(ProtocolError::UnsupportedCipherSuiteError(id))
+    }
+}
+
+////////////////////////////////////////////////
+// Helper functions and Trait Implementations //
+// ========================================== //
+////////////////////////////////////////////////
+
+/// Object-safe combination of [`RngCore`] and [`CryptoRng`].
+pub trait DynRng: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng> DynRng for R {}
+
+/// Type-erased server operations of a [`CipherSuite`], implemented by
+/// [`ServerSetup`].
+pub trait ServerSuite {
+    /// See [`DynServer::registration_start()`].
+    fn registration_start(
+        &self,
+        message: &[u8],
+        credential_identifier: &[u8],
+    ) -> Result<Vec<u8>, ProtocolError>;
+
+    /// See [`DynServer::registration_finish()`].
+    fn registration_finish(&self, message: &[u8]) -> Result<Vec<u8>, ProtocolError>;
+
+    /// See [`DynServer::login_start()`].
+    fn login_start(
+        &self,
+        rng: &mut dyn DynRng,
+        password_file: Option<&[u8]>,
+        message: &[u8],
+        credential_identifier: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError>;
+
+    /// See [`DynServer::login_finish()`].
+    fn login_finish(
+        &self,
+        state: &[u8],
+        message: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<DynServerLoginFinishResult, ProtocolError>;
+}
+
+/// Type-erased client operations of a [`CipherSuite`].
+pub trait ClientSuite {
+    /// See [`DynClient::registration_start()`].
+    fn registration_start(
+        &self,
+        rng: &mut dyn DynRng,
+        password: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError>;
+
+    /// See [`DynClient::registration_finish()`].
+    fn registration_finish(
+        &self,
+        rng: &mut dyn DynRng,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientRegistrationFinishParameters,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), ProtocolError>;
+
+    /// See [`DynClient::login_start()`].
+    fn login_start(
+        &self,
+        rng: &mut dyn DynRng,
+        password: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError>;
+
+    /// See [`DynClient::login_finish()`].
+    fn login_finish(
+        &self,
+        rng: &mut dyn DynRng,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientLoginFinishParameters,
+    ) -> Result<DynClientLoginFinishResult, ProtocolError>;
+}
+
+impl<CS: CipherSuite> ServerSuite for ServerSetup<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE1Message: Deserialize,
+    <CS::KeyExchange as KeyExchange>::KE2Message: Serialize,
+    <CS::KeyExchange as KeyExchange>::KE2State<CS>: Deserialize + Serialize,
+    <CS::KeyExchange as KeyExchange>::KE3Message: Deserialize,
+    // RegistrationResponse: KgPk + KePk
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<<KeGroup<CS> as Group>::PkLen>,
+    RegistrationResponseLen<CS>: ArrayLength<u8>,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+    Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+        ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+    CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+    // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+    CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+    CredentialResponseLen<CS>: ArrayLength<u8>,
+{
+    fn registration_start(
+        &self,
+        message: &[u8],
+        credential_identifier: &[u8],
+    ) -> Result<Vec<u8>, ProtocolError> {
+        let result = ServerRegistration::start(
+            self,
+            RegistrationRequest::deserialize(message)?,
+            credential_identifier,
+        )?;
+
+        Ok(result.message.serialize().to_vec())
+    }
+
+    fn registration_finish(&self, message: &[u8]) -> Result<Vec<u8>, ProtocolError> {
+        let password_file =
+            ServerRegistration::finish(RegistrationUpload::<CS>::deserialize(message)?);
+
+        Ok(password_file.serialize_with_extensions())
+    }
+
+    fn login_start(
+        &self,
+        mut rng: &mut dyn DynRng,
+        password_file: Option<&[u8]>,
+        message: &[u8],
+        credential_identifier: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError> {
+        let password_file = password_file
+            .map(ServerRegistration::deserialize)
+            .transpose()?;
+        let result = ServerLogin::start(
+            &mut rng,
+            self,
+            password_file,
+            CredentialRequest::deserialize(message)?,
+            credential_identifier,
+            parameters,
+        )?;
+
+        Ok((
+            result.message.serialize_with_extensions(),
+            result.state.serialize().to_vec(),
+        ))
+    }
+
+    fn login_finish(
+        &self,
+        state: &[u8],
+        message: &[u8],
+        parameters: ServerLoginParameters,
+    ) -> Result<DynServerLoginFinishResult, ProtocolError> {
+        let result = ServerLogin::<CS>::deserialize(state)?
+            .finish(CredentialFinalization::deserialize(message)?, parameters)?;
+
+        Ok(DynServerLoginFinishResult {
+            session_key: Zeroizing::new(result.session_key.to_vec()),
+            exporter_secret: Zeroizing::new(result.exporter_secret.to_vec()),
+            exporter: shared::export_keying_material::<KeHash<CS>>,
+            #[cfg(feature = "payload")]
+            client_payload: result.client_payload,
+        })
+    }
+}
+
+impl<CS: CipherSuite> ClientSuite for PhantomData<CS>
+where
+    CS::Ksf: 'static,
+    <CS::KeyExchange as KeyExchange>::KE1Message: Deserialize + Serialize,
+    <CS::KeyExchange as KeyExchange>::KE1State: Deserialize + Serialize,
+    <CS::KeyExchange as KeyExchange>::KE2Message: Deserialize,
+    <CS::KeyExchange as KeyExchange>::KE3Message: Serialize,
+    // ClientRegistration: KgSk + KgPk
+    <OprfGroup<CS> as voprf::Group>::ScalarLen: Add<<OprfGroup<CS> as voprf::Group>::ElemLen>,
+    ClientRegistrationLen<CS>: ArrayLength<u8>,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // CredentialRequest: KgPk + Ke1Message
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<Ke1MessageLen<CS>>,
+    CredentialRequestLen<CS>: ArrayLength<u8>,
+    // ClientLogin: KgSk + CredentialRequest + Ke1State
+    <OprfGroup<CS> as voprf::Group>::ScalarLen: Add<CredentialRequestLen<CS>>,
+    Sum<<OprfGroup<CS> as voprf::Group>::ScalarLen, CredentialRequestLen<CS>>:
+        ArrayLength<u8> + Add<Ke1StateLen<CS>>,
+    ClientLoginLen<CS>: ArrayLength<u8>,
+{
+    fn registration_start(
+        &self,
+        mut rng: &mut dyn DynRng,
+        password: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError> {
+        let result = ClientRegistration::<CS>::start(&mut rng, password)?;
+
+        Ok((
+            result.message.serialize().to_vec(),
+            result.state.serialize().to_vec(),
+        ))
+    }
+
+    fn registration_finish(
+        &self,
+        mut rng: &mut dyn DynRng,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientRegistrationFinishParameters,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), ProtocolError> {
+        let ksf = parameters.ksf.map(downcast_ksf::<CS>).transpose()?;
+        let result = ClientRegistration::<CS>::deserialize(state)?.finish(
+            &mut rng,
+            password,
+            RegistrationResponse::deserialize(message)?,
+            ClientRegistrationFinishParameters::new(parameters.identifiers, ksf),
+        )?;
+
+        Ok((
+            result.message.serialize_with_extensions(),
+            Zeroizing::new(result.export_key.to_vec()),
+        ))
+    }
+
+    fn login_start(
+        &self,
+        mut rng: &mut dyn DynRng,
+        password: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), ProtocolError> {
+        let result = ClientLogin::<CS>::start(&mut rng, password)?;
+
+        Ok((
+            result.message.serialize().to_vec(),
+            result.state.serialize().to_vec(),
+        ))
+    }
+
+    fn login_finish(
+        &self,
+        mut rng: &mut dyn DynRng,
+        state: &[u8],
+        password: &[u8],
+        message: &[u8],
+        parameters: DynClientLoginFinishParameters,
+    ) -> Result<DynClientLoginFinishResult, ProtocolError> {
+        let ksf = parameters.ksf.map(downcast_ksf::<CS>).transpose()?;
+        let mut finish_parameters =
+            ClientLoginFinishParameters::new(parameters.context, parameters.identifiers, ksf);
+
+        if let Some(psk) = parameters.psk {
+            finish_parameters = finish_parameters.with_psk(psk);
+        }
+
+        #[cfg(feature = "payload")]
+        if let Some(payload) = parameters.payload {
+            finish_parameters = finish_parameters.with_payload(payload);
+        }
+
+        let result = ClientLogin::<CS>::deserialize(state)?.finish(
+            &mut rng,
+            password,
+            CredentialResponse::deserialize(message)?,
+            finish_parameters,
+        )?;
+
+        Ok(DynClientLoginFinishResult {
+            message: result.message.serialize_with_extensions(),
+            session_key: Zeroizing::new(result.session_key.to_vec()),
+            exporter_secret: Zeroizing::new(result.exporter_secret.to_vec()),
+            exporter: shared::export_keying_material::<KeHash<CS>>,
+            #[cfg(feature = "payload")]
+            server_payload: result.server_payload,
+            export_key: Zeroizing::new(result.export_key.to_vec()),
+        })
+    }
+}
+
+// Recovers the KSF configuration of the ciphersuite `CS` from `ksf`
+fn downcast_ksf<CS: CipherSuite>(ksf: &dyn Any) -> Result<&CS::Ksf, ProtocolError>
+where
+    CS::Ksf: 'static,
+{
+    ksf.downcast_ref()
+        .ok_or(ProtocolError::KsfTypeMismatchError)
+}
+
+// Strips the header of the framed `input`, which has to be of type
+// `message_type`, and returns the ciphersuite identifier
+fn unframe(message_type: MessageType, input: &[u8]) -> Result<(u16, &[u8]), ProtocolError> {
+    let id = framing::suite_id(input)?;
+
+    Ok((id, framing::unframe(id, message_type, input)?))
+}
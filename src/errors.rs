@@ -26,6 +26,12 @@ pub enum InternalError {
     KsfError,
     /// Error while performing a KEM operation
     KemError,
+    /// Computing the legacy password hash failed
+    LegacyHashError,
+    /// Encrypting an application payload failed
+    PayloadError,
+    /// Encrypting a sealed `ServerSetup` failed
+    SealError,
     /** This error occurs when the envelope seal open hmac check fails
     HMAC check in seal open failed. */
     SealOpenHmacError,
@@ -46,6 +52,9 @@ impl Debug for InternalError {
             Self::HmacError => f.debug_tuple("HmacError").finish(),
             Self::KsfError => f.debug_tuple("KsfError").finish(),
             Self::KemError => f.debug_tuple("KemError").finish(),
+            Self::LegacyHashError => f.debug_tuple("LegacyHashError").finish(),
+            Self::PayloadError => f.debug_tuple("PayloadError").finish(),
+            Self::SealError => f.debug_tuple("SealError").finish(),
             Self::SealOpenHmacError => f.debug_tuple("SealOpenHmacError").finish(),
             Self::IncompatibleEnvelopeModeError => {
                 f.debug_tuple("IncompatibleEnvelopeModeError").finish()
@@ -100,6 +109,74 @@ pub enum ProtocolError<T = Infallible> {
     /** This error occurs when the client detects that the server has
     reflected the OPRF value (beta == alpha) */
     ReflectedValueError,
+    /// No ciphersuite is registered for the identifier {0}
+    UnsupportedCipherSuiteError(u16),
+    /** Ciphersuite identifier mismatch: expected {expected}, actual
+    {actual} */
+    #[allow(unused_assignments)]
+    CipherSuiteMismatchError {
+        /// expected
+        expected: u16,
+        /// actual
+        actual: u16,
+    },
+    /// The framed or sealed encoding does not start with the expected magic
+    /// value
+    InvalidMagicError,
+    /// Unsupported version {0} of the framed or sealed encoding
+    UnsupportedVersionError(u8),
+    /** Message type mismatch in the framed encoding: expected {expected},
+    actual {actual} */
+    #[allow(unused_assignments)]
+    MessageTypeMismatchError {
+        /// expected
+        expected: u8,
+        /// actual
+        actual: u8,
+    },
+    /** The sealed encoding could not be opened, because the key or passphrase
+    is wrong or the sealed encoding was modified */
+    SealOpenError,
+    /// Invalid backup threshold {threshold} for {shares} shares
+    #[allow(unused_assignments)]
+    InvalidBackupThresholdError {
+        /// threshold
+        threshold: u8,
+        /// shares
+        shares: u8,
+    },
+    /** A backup share is corrupted, duplicated or belongs to a different
+    backup */
+    InvalidBackupShareError,
+    /** At least {threshold} backup shares are required, but only {actual}
+    were given */
+    #[allow(unused_assignments)]
+    InsufficientBackupSharesError {
+        /// threshold
+        threshold: u8,
+        /// actual
+        actual: usize,
+    },
+    /** The key exchange of the ciphersuite does not support application
+    payloads */
+    UnsupportedPayloadError,
+    /** The key exchange of the ciphersuite does not support precomputed
+    ephemeral key pairs */
+    UnsupportedEphemeralError,
+    /** The parameters of a key stretching function exceed the limits of the
+    caller */
+    KsfLimitError,
+    /** The randomized password was derived from a different
+    `CredentialResponse` */
+    RandomizedPasswordMismatchError,
+    /// The message carries the extension {0}, which is not supported
+    UnsupportedExtensionError(u16),
+    /** The KSF configuration passed to the `dynamic` API is not of the
+    `CipherSuite::Ksf` type of the ciphersuite */
+    KsfTypeMismatchError,
+    /** The deserialized `ServerLoginBuilder` was created with a
+    `PreSharedKey`, which is never serialized */
+    MissingPskError,
     /// Custom [`SecretKey`](crate::keypair::PrivateKeySerialization) error type
     Custom(T),
 }
@@ -123,6 +200,49 @@ impl<T: Debug> Debug for ProtocolError<T> {
                 .field("actual_len", actual_len)
                 .finish(),
             Self::ReflectedValueError => f.debug_tuple("ReflectedValueError").finish(),
+            Self::UnsupportedCipherSuiteError(id) => f
+                .debug_tuple("UnsupportedCipherSuiteError")
+                .field(id)
+                .finish(),
+            Self::CipherSuiteMismatchError { expected, actual } => f
+                .debug_struct("CipherSuiteMismatchError")
+                .field("expected", expected)
+                .field("actual", actual)
+                .finish(),
+            Self::InvalidMagicError => f.debug_tuple("InvalidMagicError").finish(),
+            Self::UnsupportedVersionError(version) => f
+                .debug_tuple("UnsupportedVersionError")
+                .field(version)
+                .finish(),
+            Self::MessageTypeMismatchError { expected, actual } => f
+                .debug_struct("MessageTypeMismatchError")
+                .field("expected", expected)
+                .field("actual", actual)
+                .finish(),
+            Self::SealOpenError => f.debug_tuple("SealOpenError").finish(),
+            Self::InvalidBackupThresholdError { threshold, shares } => f
+                .debug_struct("InvalidBackupThresholdError")
+                .field("threshold", threshold)
+                .field("shares", shares)
+                .finish(),
+            Self::InvalidBackupShareError => f.debug_tuple("InvalidBackupShareError").finish(),
+            Self::InsufficientBackupSharesError { threshold, actual } => f
+                .debug_struct("InsufficientBackupSharesError")
+                .field("threshold", threshold)
+                .field("actual", actual)
+                .finish(),
+            Self::UnsupportedPayloadError => f.debug_tuple("UnsupportedPayloadError").finish(),
+            Self::UnsupportedEphemeralError => f.debug_tuple("UnsupportedEphemeralError").finish(),
+            Self::KsfLimitError => f.debug_tuple("KsfLimitError").finish(),
+            Self::RandomizedPasswordMismatchError => {
+                f.debug_tuple("RandomizedPasswordMismatchError").finish()
+            }
+            Self::UnsupportedExtensionError(tag) => f
+                .debug_tuple("UnsupportedExtensionError")
+                .field(tag)
+                .finish(),
+            Self::KsfTypeMismatchError => f.debug_tuple("KsfTypeMismatchError").finish(),
+            Self::MissingPskError => f.debug_tuple("MissingPskError").finish(),
             Self::Custom(custom) => f.debug_tuple("Custom").field(custom).finish(),
         }
     }
@@ -164,6 +284,32 @@ impl ProtocolError {
                 actual_len,
             },
             Self::ReflectedValueError => ProtocolError::ReflectedValueError,
+            Self::UnsupportedCipherSuiteError(id) => ProtocolError::UnsupportedCipherSuiteError(id),
+            Self::CipherSuiteMismatchError { expected, actual } => {
+                ProtocolError::CipherSuiteMismatchError { expected, actual }
+            }
+            Self::InvalidMagicError => ProtocolError::InvalidMagicError,
+            Self::UnsupportedVersionError(version) => {
+                ProtocolError::UnsupportedVersionError(version)
+            }
+            Self::MessageTypeMismatchError { expected, actual } => {
+                ProtocolError::MessageTypeMismatchError { expected, actual }
+            }
+            Self::SealOpenError => ProtocolError::SealOpenError,
+            Self::InvalidBackupThresholdError { threshold, shares } => {
+                ProtocolError::InvalidBackupThresholdError { threshold, shares }
+            }
+            Self::InvalidBackupShareError => ProtocolError::InvalidBackupShareError,
+            Self::InsufficientBackupSharesError { threshold, actual } => {
+                ProtocolError::InsufficientBackupSharesError { threshold, actual }
+            }
+            Self::UnsupportedPayloadError => ProtocolError::UnsupportedPayloadError,
+            Self::UnsupportedEphemeralError => ProtocolError::UnsupportedEphemeralError,
+            Self::KsfLimitError => ProtocolError::KsfLimitError,
+            Self::RandomizedPasswordMismatchError => ProtocolError::RandomizedPasswordMismatchError,
+            Self::UnsupportedExtensionError(tag) => ProtocolError::UnsupportedExtensionError(tag),
+            Self::KsfTypeMismatchError => ProtocolError::KsfTypeMismatchError,
+            Self::MissingPskError => ProtocolError::MissingPskError,
         }
     }
 }
@@ -0,0 +1,144 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Self-describing encoding of messages and server records
+//!
+//! The `serialize_framed()` functions prefix the regular serialization with a
+//! header consisting of:
+//! - the [`MAGIC`] value,
+//! - the format [`VERSION`],
+//! - a two byte big-endian ciphersuite identifier chosen by the application,
+//! - the [`MessageType`].
+//!
+//! Messages and records that can carry extensions are followed by them, as in
+//! their `serialize_with_extensions()`.
+//!
+//! The corresponding `deserialize_framed()` functions check every field of the
+//! header before deserializing the rest of the input, so that a record created
+//! under a different ciphersuite or for a different purpose is rejected with a
+//! dedicated [`ProtocolError`].
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use generic_array::GenericArray;
+#[cfg(feature = "alloc")]
+use generic_array::typenum::Unsigned;
+use generic_array::typenum::{Sum, U8};
+
+use crate::errors::ProtocolError;
+use crate::serialization::SliceExt;
+
+/// The magic value every framed encoding starts with
+pub const MAGIC: [u8; 4] = *b"OPQK";
+
+/// The version of the framed encoding
+pub const VERSION: u8 = 1;
+
+/// Length of the header in bytes that is prepended by the framed encoding.
+pub type FrameHeaderLen = U8;
+
+/// Length of a framed encoding in bytes with a payload of length `L`.
+pub type FramedLen<L> = Sum<FrameHeaderLen, L>;
+
+/// The type of a framed message
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum MessageType {
+    /// [`RegistrationRequest`](crate::RegistrationRequest)
+    RegistrationRequest = 1,
+    /// [`RegistrationResponse`](crate::RegistrationResponse)
+    RegistrationResponse = 2,
+    /// [`RegistrationUpload`](crate::RegistrationUpload)
+    RegistrationUpload = 3,
+    /// [`CredentialRequest`](crate::CredentialRequest)
+    CredentialRequest = 4,
+    /// [`CredentialResponse`](crate::CredentialResponse)
+    CredentialResponse = 5,
+    /// [`CredentialFinalization`](crate::CredentialFinalization)
+    CredentialFinalization = 6,
+    /// [`ServerSetup`](crate::ServerSetup)
+    ServerSetup = 7,
+    /// [`ServerRegistration`](crate::ServerRegistration)
+    ServerRegistration = 8,
+    /// [`ClientRegistration`](crate::ClientRegistration)
+    ClientRegistration = 9,
+    /// [`ClientLogin`](crate::ClientLogin)
+    ClientLogin = 10,
+    /// [`ServerLogin`](crate::ServerLogin)
+    ServerLogin = 11,
+}
+
+// Builds the header for a message of type `message_type`
+pub(crate) fn header(suite_id: u16, message_type: MessageType) -> GenericArray<u8, FrameHeaderLen> {
+    let [suite_high, suite_low] = suite_id.to_be_bytes();
+    let [m0, m1, m2, m3] = MAGIC;
+
+    [
+        m0,
+        m1,
+        m2,
+        m3,
+        VERSION,
+        suite_high,
+        suite_low,
+        message_type as u8,
+    ]
+    .into()
+}
+
+// Prepends the header for a message of type `message_type` to `input`
+#[cfg(feature = "alloc")]
+pub(crate) fn frame(suite_id: u16, message_type: MessageType, input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(FrameHeaderLen::USIZE + input.len());
+    output.extend_from_slice(&header(suite_id, message_type));
+    output.extend_from_slice(input);
+    output
+}
+
+// Validates the magic value and version of the header and returns the
+// ciphersuite identifier
+pub(crate) fn suite_id(mut input: &[u8]) -> Result<u16, ProtocolError> {
+    let header = input.take_array::<FrameHeaderLen>("frame header")?;
+
+    if header[..4] != MAGIC {
+        return Err(ProtocolError::InvalidMagicError);
+    }
+
+    if header[4] != VERSION {
+        return Err(ProtocolError::UnsupportedVersionError(header[4]));
+    }
+
+    Ok(u16::from_be_bytes([header[5], header[6]]))
+}
+
+// Validates the header and returns the remaining input
+pub(crate) fn unframe(
+    suite_id: u16,
+    message_type: MessageType,
+    mut input: &[u8],
+) -> Result<&[u8], ProtocolError> {
+    let actual = self::suite_id(input)?;
+    let header = input.take_array::<FrameHeaderLen>("frame header")?;
+
+    if actual != suite_id {
+        return Err(ProtocolError::CipherSuiteMismatchError {
+            expected: suite_id,
+            actual,
+        });
+    }
+
+    if header[7] != message_type as u8 {
+        return Err(ProtocolError::MessageTypeMismatchError {
+            expected: message_type as u8,
+            actual: header[7],
+        });
+    }
+
+    Ok(input)
+}
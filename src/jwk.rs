@@ -0,0 +1,188 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! JSON Web Key ([RFC 7517](https://www.rfc-editor.org/rfc/rfc7517.html))
+//! encoding of server public keys, for distributing them to clients via a JWK
+//! Set.
+//!
+//! Keys of the NIST curves and [`Secp256k1`](crate::Secp256k1) are encoded as
+//! `EC` keys, [`Curve25519`](crate::Curve25519) and
+//! [`Ed25519`](crate::Ed25519) keys as `OKP` keys
+//! ([RFC 8037](https://www.rfc-editor.org/rfc/rfc8037.html)). The key ID is
+//! the JWK thumbprint ([RFC 7638](https://www.rfc-editor.org/rfc/rfc7638.html))
+//! of the key, so it only depends on the public key itself.
+//!
+//! ```
+//! # type KeGroup = ::p256::NistP256;
+//! # struct Default;
+//! # impl opaque_ke::CipherSuite for Default {
+//! #     type OprfCs = ::p256::NistP256;
+//! #     type KeyExchange = opaque_ke::TripleDh<KeGroup, sha2::Sha256>;
+//! #     type Ksf = opaque_ke::ksf::Identity;
+//! # }
+//! use opaque_ke::ServerSetup;
+//! use opaque_ke::jwk::Jwks;
+//! use opaque_ke::keypair::PublicKey;
+//! use rand::rngs::OsRng;
+//!
+//! let current = ServerSetup::<Default>::new(&mut OsRng);
+//! let previous = ServerSetup::<Default>::new(&mut OsRng);
+//!
+//! // Publish the current key first, followed by keys that are being rotated out.
+//! let jwks = Jwks::builder()
+//!     .server_setup(&current)
+//!     .server_setup(&previous)
+//!     .build();
+//!
+//! // A client pins a key by its ID.
+//! let kid = current.keypair().public().to_jwk().kid.unwrap();
+//! let jwk = jwks.find(&kid).unwrap();
+//! let pk = PublicKey::<KeGroup>::from_jwk(jwk)?;
+//! assert_eq!(&pk, current.keypair().public());
+//! # Ok::<(), opaque_ke::errors::ProtocolError>(())
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::ciphersuite::{CipherSuite, KeGroup};
+use crate::errors::ProtocolError;
+use crate::key_exchange::group::JwkGroup;
+use crate::keypair::PublicKey;
+use crate::opaque::ServerSetup;
+
+/// A public key encoded as a JSON Web Key, see
+/// [`PublicKey::to_jwk()`](crate::keypair::PublicKey::to_jwk).
+///
+/// Parameters that are not listed here are ignored when deserializing.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct Jwk {
+    /// The key type, `EC` or `OKP`
+    pub kty: String,
+    /// The curve
+    pub crv: String,
+    /// The base64url encoded `x` coordinate
+    pub x: String,
+    /// The base64url encoded `y` coordinate, only present for `EC` keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// The key ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+impl Jwk {
+    pub(crate) fn new(kty: &str, crv: &str, x: &[u8], y: Option<&[u8]>) -> Self {
+        let mut jwk = Self {
+            kty: kty.into(),
+            crv: crv.into(),
+            x: Base64UrlUnpadded::encode_string(x),
+            y: y.map(Base64UrlUnpadded::encode_string),
+            kid: None,
+        };
+        jwk.kid = Some(jwk.thumbprint());
+
+        jwk
+    }
+
+    /// Returns the base64url encoded SHA-256 JWK thumbprint of this key as
+    /// specified in [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638.html).
+    /// This is used as the key ID by
+    /// [`PublicKey::to_jwk()`](crate::keypair::PublicKey::to_jwk).
+    pub fn thumbprint(&self) -> String {
+        // The required members in lexicographic order, without whitespace. None
+        // of the values of valid keys need to be escaped.
+        let json = match &self.y {
+            Some(y) => format!(
+                r#"{{"crv":"{}","kty":"{}","x":"{}","y":"{}"}}"#,
+                self.crv, self.kty, self.x, y
+            ),
+            None => format!(
+                r#"{{"crv":"{}","kty":"{}","x":"{}"}}"#,
+                self.crv, self.kty, self.x
+            ),
+        };
+
+        Base64UrlUnpadded::encode_string(&Sha256::digest(json))
+    }
+
+    pub(crate) fn decode_x(&self) -> Result<Vec<u8>, ProtocolError> {
+        Base64UrlUnpadded::decode_vec(&self.x).map_err(|_| ProtocolError::SerializationError)
+    }
+
+    pub(crate) fn decode_y(&self) -> Result<Option<Vec<u8>>, ProtocolError> {
+        self.y
+            .as_deref()
+            .map(Base64UrlUnpadded::decode_vec)
+            .transpose()
+            .map_err(|_| ProtocolError::SerializationError)
+    }
+}
+
+/// A JSON Web Key Set, see [`Jwks::builder()`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub struct Jwks {
+    /// The keys of this set
+    pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+    /// Returns a [`JwksBuilder`] to create a [`Jwks`] from server keys.
+    pub fn builder() -> JwksBuilder {
+        JwksBuilder::default()
+    }
+
+    /// Returns the key with the given key ID.
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|jwk| jwk.kid.as_deref() == Some(kid))
+    }
+}
+
+/// Builder for a [`Jwks`], see [`Jwks::builder()`].
+///
+/// [`ServerSetup`] holds a single key, so to publish rotated keys, add the
+/// [`ServerSetup`] of each key that is still in use. Keys are published in
+/// the order they are added, keys that were already added are skipped.
+#[derive(Clone, Debug, Default)]
+pub struct JwksBuilder {
+    keys: Vec<Jwk>,
+}
+
+impl JwksBuilder {
+    /// Adds the public key of a [`ServerSetup`].
+    pub fn server_setup<CS: CipherSuite, SK: Clone, OS: Clone>(
+        self,
+        server_setup: &ServerSetup<CS, SK, OS>,
+    ) -> Self
+    where
+        KeGroup<CS>: JwkGroup,
+    {
+        self.public_key(server_setup.keypair().public())
+    }
+
+    /// Adds a public key.
+    pub fn public_key<G: JwkGroup>(mut self, public_key: &PublicKey<G>) -> Self {
+        let jwk = public_key.to_jwk();
+
+        if !self.keys.contains(&jwk) {
+            self.keys.push(jwk);
+        }
+
+        self
+    }
+
+    /// Builds the [`Jwks`].
+    pub fn build(self) -> Jwks {
+        Jwks { keys: self.keys }
+    }
+}
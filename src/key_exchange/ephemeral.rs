@@ -0,0 +1,158 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Precomputed server ephemeral key pairs and nonces
+
+#[cfg(any(feature = "batch", feature = "ephemeral-pool"))]
+use alloc::vec::Vec;
+
+use derive_where::derive_where;
+use generic_array::GenericArray;
+use rand::{CryptoRng, RngCore};
+
+use crate::key_exchange::group::Group;
+use crate::key_exchange::shared::{NonceLen, generate_nonce};
+use crate::keypair::{KeyPair, PrivateKey, PublicKey};
+
+/// An ephemeral key pair and nonce of the server, generated ahead of time to
+/// take the base-point multiplication off the path of
+/// [`ServerLogin::start_with_ephemeral()`](crate::ServerLogin::start_with_ephemeral)
+///
+/// It can't be cloned, as every [`Ephemeral`] must only be used for a single
+/// login.
+#[derive_where(ZeroizeOnDrop)]
+#[derive_where(Debug, Eq, Hash, PartialEq; G::Sk, G::Pk)]
+pub struct Ephemeral<G: Group> {
+    pub(crate) sk: PrivateKey<G>,
+    #[derive_where(skip(Zeroize))]
+    pub(crate) pk: PublicKey<G>,
+    // The public key is serialized ahead of time as well.
+    #[derive_where(skip(Zeroize))]
+    pub(crate) pk_bytes: GenericArray<u8, G::PkLen>,
+    pub(crate) nonce: GenericArray<u8, NonceLen>,
+}
+
+impl<G: Group> Ephemeral<G> {
+    /// Generates a new random [`Ephemeral`]
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let keypair = KeyPair::<G>::derive_random(rng);
+        let nonce = generate_nonce::<R>(rng);
+
+        Self {
+            sk: keypair.private().clone(),
+            pk: keypair.public().clone(),
+            pk_bytes: keypair.public().serialize(),
+            nonce,
+        }
+    }
+
+    /// Draws the private key and nonce of an [`Ephemeral`] from `rng` in the
+    /// same order as [`Ephemeral::random()`], leaving the public key to
+    /// [`Ephemeral::from_secrets()`]
+    #[cfg(feature = "batch")]
+    pub(crate) fn random_secret<R: RngCore + CryptoRng>(
+        rng: &mut R,
+    ) -> (PrivateKey<G>, GenericArray<u8, NonceLen>) {
+        let sk = PrivateKey::derive_random(rng);
+        let nonce = generate_nonce::<R>(rng);
+
+        (sk, nonce)
+    }
+
+    /// Completes the outputs of [`Ephemeral::random_secret()`], serializing all
+    /// public keys at once, and keeps each one together with its `T`
+    #[cfg(feature = "batch")]
+    pub(crate) fn from_secrets<T>(
+        secrets: Vec<(T, PrivateKey<G>, GenericArray<u8, NonceLen>)>,
+    ) -> Vec<(T, Self)> {
+        let pks: Vec<_> = secrets.iter().map(|(_, sk, _)| sk.public_key()).collect();
+        let pks_bytes = PublicKey::serialize_batch(&pks);
+
+        secrets
+            .into_iter()
+            .zip(pks)
+            .zip(pks_bytes)
+            .map(|(((value, sk, nonce), pk), pk_bytes)| {
+                (
+                    value,
+                    Self {
+                        sk,
+                        pk,
+                        pk_bytes,
+                        nonce,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// The ephemeral public key
+    pub fn public(&self) -> &PublicKey<G> {
+        &self.pk
+    }
+}
+
+/// A pool of [`Ephemeral`]s, refilled ahead of time, e.g. by a background
+/// thread, and drained by logins
+///
+/// The pool doesn't synchronize itself, share it between threads behind a
+/// lock. [`Ephemeral::random()`] can run outside of the lock and its result be
+/// added with [`EphemeralPool::push()`].
+#[cfg(feature = "ephemeral-pool")]
+#[derive_where(Default)]
+#[derive_where(Debug, Eq, Hash, PartialEq; G::Sk, G::Pk)]
+pub struct EphemeralPool<G: Group> {
+    ephemerals: Vec<Ephemeral<G>>,
+}
+
+#[cfg(feature = "ephemeral-pool")]
+impl<G: Group> EphemeralPool<G> {
+    /// Creates an empty [`EphemeralPool`]
+    pub fn new() -> Self {
+        Self {
+            ephemerals: Vec::new(),
+        }
+    }
+
+    /// Generates [`Ephemeral`]s until the pool holds `len` of them
+    pub fn refill<R: RngCore + CryptoRng>(&mut self, rng: &mut R, len: usize) {
+        self.ephemerals
+            .reserve(len.saturating_sub(self.ephemerals.len()));
+
+        while self.ephemerals.len() < len {
+            self.ephemerals.push(Ephemeral::random(rng));
+        }
+    }
+
+    /// Adds an [`Ephemeral`] to the pool
+    pub fn push(&mut self, ephemeral: Ephemeral<G>) {
+        self.ephemerals.push(ephemeral);
+    }
+
+    /// Removes an [`Ephemeral`] from the pool, returns `None` if the pool is
+    /// empty
+    pub fn pop(&mut self) -> Option<Ephemeral<G>> {
+        self.ephemerals.pop()
+    }
+
+    /// Removes an [`Ephemeral`] from the pool, or generates a new one if the
+    /// pool is empty
+    pub fn take<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> Ephemeral<G> {
+        self.pop().unwrap_or_else(|| Ephemeral::random(rng))
+    }
+
+    /// The number of [`Ephemeral`]s in the pool
+    pub fn len(&self) -> usize {
+        self.ephemerals.len()
+    }
+
+    /// Returns `true` if the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.ephemerals.is_empty()
+    }
+}
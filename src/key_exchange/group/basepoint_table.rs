@@ -0,0 +1,147 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Precomputed tables for multiplications with the generator of
+//! [`elliptic_curve`] curves, which unlike `curve25519-dalek` don't ship with
+//! any.
+
+use core::any::{Any, TypeId};
+use std::boxed::Box;
+use std::sync::{PoisonError, RwLock};
+use std::vec::Vec;
+
+use elliptic_curve::bigint::Encoding;
+use elliptic_curve::group::{Curve, Group};
+use elliptic_curve::{AffinePoint, CurveArithmetic, Field, ProjectivePoint, Scalar};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+type Tables = Vec<(TypeId, &'static (dyn Any + Send + Sync))>;
+
+// The tables of all curves in use, built on first use and never freed.
+static TABLES: RwLock<Tables> = RwLock::new(Vec::new());
+
+/// Multiplies the generator of `C` with `scalar`, with the same result as
+/// [`MulByGenerator`](elliptic_curve::ops::MulByGenerator)
+pub(super) fn mul_by_generator<C: CurveArithmetic>(scalar: &Scalar<C>) -> ProjectivePoint<C> {
+    table::<C>().mul(scalar)
+}
+
+fn table<C: CurveArithmetic>() -> &'static BasepointTable<C> {
+    fn find<C: CurveArithmetic>(tables: &Tables) -> Option<&'static BasepointTable<C>> {
+        tables
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<C>())
+            .map(|(_, table)| {
+                table
+                    .downcast_ref()
+                    .expect("tables are stored under the `TypeId` of their curve")
+            })
+    }
+
+    if let Some(table) = find(&TABLES.read().unwrap_or_else(PoisonError::into_inner)) {
+        return table;
+    }
+
+    let mut tables = TABLES.write().unwrap_or_else(PoisonError::into_inner);
+
+    // Another thread might have built the table in the meantime.
+    if let Some(table) = find(&tables) {
+        return table;
+    }
+
+    let table: &'static BasepointTable<C> = Box::leak(Box::new(BasepointTable::new()));
+    tables.push((TypeId::of::<C>(), table));
+
+    table
+}
+
+// For every 4-bit digit `i` of a scalar the multiples `j * 16^i * G` for `j` in
+// `0..16`.
+struct BasepointTable<C: CurveArithmetic>(Vec<[AffinePoint<C>; 16]>);
+
+impl<C: CurveArithmetic> BasepointTable<C> {
+    fn new() -> Self {
+        let digits = 2 * Self::bytes(&Scalar::<C>::ZERO).as_ref().len();
+        let mut table = Vec::with_capacity(digits);
+        let mut base = ProjectivePoint::<C>::generator();
+
+        for _ in 0..digits {
+            let mut multiples = [ProjectivePoint::<C>::identity(); 16];
+
+            for j in 1..16 {
+                multiples[j] = multiples[j - 1] + base;
+            }
+
+            let mut affine = [AffinePoint::<C>::default(); 16];
+            ProjectivePoint::<C>::batch_normalize(&multiples, &mut affine);
+            table.push(affine);
+
+            base = multiples[15] + base;
+        }
+
+        Self(table)
+    }
+
+    // Adds one multiple per digit, selected in constant time.
+    fn mul(&self, scalar: &Scalar<C>) -> ProjectivePoint<C> {
+        let bytes = Self::bytes(scalar);
+        let digits = bytes
+            .as_ref()
+            .iter()
+            .flat_map(|byte| [byte & 0xf, byte >> 4]);
+        let mut point = ProjectivePoint::<C>::identity();
+
+        for (multiples, digit) in self.0.iter().zip(digits) {
+            let mut multiple = multiples[0];
+
+            for (j, candidate) in (0..).zip(multiples).skip(1) {
+                multiple.conditional_assign(candidate, digit.ct_eq(&j));
+            }
+
+            point += multiple;
+        }
+
+        point
+    }
+
+    fn bytes(scalar: &Scalar<C>) -> <C::Uint as Encoding>::Repr {
+        let uint: C::Uint = (*scalar).into();
+        uint.to_le_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use elliptic_curve::ops::MulByGenerator;
+    use p256::NistP256;
+    use p384::NistP384;
+    use p521::NistP521;
+    use rand::rngs::OsRng;
+
+    use super::*;
+
+    fn inner<C: CurveArithmetic>() {
+        let scalars = [Scalar::<C>::ZERO, Scalar::<C>::ONE, -Scalar::<C>::ONE]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::<C>::random(&mut OsRng)));
+
+        for scalar in scalars {
+            assert_eq!(
+                super::mul_by_generator::<C>(&scalar),
+                ProjectivePoint::<C>::mul_by_generator(&scalar)
+            );
+        }
+    }
+
+    #[test]
+    fn mul_by_generator() {
+        inner::<NistP256>();
+        inner::<NistP384>();
+        inner::<NistP521>();
+    }
+}
@@ -8,17 +8,26 @@
 
 //! Key Exchange group implementation for Curve25519
 
+#[cfg(feature = "jwk")]
+use alloc::vec::Vec;
 pub use curve25519_dalek;
+
 use curve25519_dalek::montgomery::MontgomeryPoint;
 use curve25519_dalek::scalar;
 use curve25519_dalek::traits::IsIdentity;
 use generic_array::GenericArray;
 use generic_array::typenum::U32;
+#[cfg(feature = "pkcs8")]
+use pkcs8::{ObjectIdentifier, SecretDocument, der::Document};
 use rand::{CryptoRng, RngCore};
 use subtle::ConstantTimeEq;
 use zeroize::ZeroizeOnDrop;
 
 use super::Group;
+#[cfg(feature = "jwk")]
+use super::JwkGroup;
+#[cfg(feature = "pkcs8")]
+use super::{Pkcs8Group, rfc8410};
 use crate::errors::{InternalError, ProtocolError};
 use crate::key_exchange::shared::DiffieHellman;
 use crate::serialization::SliceExt;
@@ -71,6 +80,50 @@ impl Group for Curve25519 {
     }
 }
 
+/// `id-X25519` from [RFC 8410](https://www.rfc-editor.org/rfc/rfc8410.html)
+#[cfg(feature = "pkcs8")]
+const ID_X25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.110");
+
+#[cfg(feature = "pkcs8")]
+impl Pkcs8Group for Curve25519 {
+    fn sk_from_pkcs8_der(der: &[u8]) -> Result<Self::Sk, ProtocolError> {
+        // Other implementations store unclamped keys, which are equivalent.
+        rfc8410::sk_from_pkcs8_der(ID_X25519, der).map(|sk| Scalar(scalar::clamp_integer(*sk)))
+    }
+
+    fn sk_to_pkcs8_der(sk: &Self::Sk) -> Result<SecretDocument, ProtocolError> {
+        rfc8410::sk_to_pkcs8_der(ID_X25519, &sk.0)
+    }
+
+    fn pk_from_spki_der(der: &[u8]) -> Result<Self::Pk, ProtocolError> {
+        rfc8410::pk_from_spki_der(ID_X25519, der).and_then(NonIdentity::from_bytes)
+    }
+
+    fn pk_to_spki_der(pk: &Self::Pk) -> Result<Document, ProtocolError> {
+        rfc8410::pk_to_spki_der(ID_X25519, &pk.0.to_bytes())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl JwkGroup for Curve25519 {
+    const KTY: &'static str = "OKP";
+    const CRV: &'static str = "X25519";
+
+    fn pk_to_jwk(pk: &Self::Pk) -> (Vec<u8>, Option<Vec<u8>>) {
+        (pk.0.to_bytes().to_vec(), None)
+    }
+
+    fn pk_from_jwk(x: &[u8], y: Option<&[u8]>) -> Result<Self::Pk, ProtocolError> {
+        if y.is_some() {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        x.try_into()
+            .map_err(|_| ProtocolError::SerializationError)
+            .and_then(NonIdentity::from_bytes)
+    }
+}
+
 impl DiffieHellman<Curve25519> for Scalar {
     fn diffie_hellman(&self, pk: &NonIdentity) -> GenericArray<u8, U32> {
         Curve25519::serialize_pk(&NonIdentity(pk.0.mul_clamped(self.0)))
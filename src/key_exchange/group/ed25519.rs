@@ -8,6 +8,8 @@
 
 //! Key Exchange group implementation for Ed25519
 
+#[cfg(feature = "jwk")]
+use alloc::vec::Vec;
 use core::iter;
 
 use curve25519_dalek::edwards::CompressedEdwardsY;
@@ -20,10 +22,16 @@ use ed25519_dalek::{SecretKey, Sha512};
 use generic_array::GenericArray;
 use generic_array::sequence::Concat;
 use generic_array::typenum::{U32, U64};
+#[cfg(feature = "pkcs8")]
+use pkcs8::{ObjectIdentifier, SecretDocument, der::Document};
 use rand::{CryptoRng, RngCore};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::Group;
+#[cfg(feature = "jwk")]
+use super::JwkGroup;
+#[cfg(feature = "pkcs8")]
+use super::{Pkcs8Group, rfc8410};
 use crate::ciphersuite::CipherSuite;
 use crate::errors::{InternalError, ProtocolError};
 use crate::key_exchange::sigma_i::hash_eddsa::implementation::HashEddsaImpl;
@@ -77,6 +85,49 @@ impl Group for Ed25519 {
     }
 }
 
+/// `id-Ed25519` from [RFC 8410](https://www.rfc-editor.org/rfc/rfc8410.html)
+#[cfg(feature = "pkcs8")]
+const ID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+#[cfg(feature = "pkcs8")]
+impl Pkcs8Group for Ed25519 {
+    fn sk_from_pkcs8_der(der: &[u8]) -> Result<Self::Sk, ProtocolError> {
+        rfc8410::sk_from_pkcs8_der(ID_ED25519, der).map(|sk| SigningKey::from_bytes(*sk))
+    }
+
+    fn sk_to_pkcs8_der(sk: &Self::Sk) -> Result<SecretDocument, ProtocolError> {
+        rfc8410::sk_to_pkcs8_der(ID_ED25519, &sk.sk)
+    }
+
+    fn pk_from_spki_der(der: &[u8]) -> Result<Self::Pk, ProtocolError> {
+        rfc8410::pk_from_spki_der(ID_ED25519, der).and_then(VerifyingKey::from_bytes)
+    }
+
+    fn pk_to_spki_der(pk: &Self::Pk) -> Result<Document, ProtocolError> {
+        rfc8410::pk_to_spki_der(ID_ED25519, &pk.compressed.0)
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl JwkGroup for Ed25519 {
+    const KTY: &'static str = "OKP";
+    const CRV: &'static str = "Ed25519";
+
+    fn pk_to_jwk(pk: &Self::Pk) -> (Vec<u8>, Option<Vec<u8>>) {
+        (pk.compressed.0.to_vec(), None)
+    }
+
+    fn pk_from_jwk(x: &[u8], y: Option<&[u8]>) -> Result<Self::Pk, ProtocolError> {
+        if y.is_some() {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        x.try_into()
+            .map_err(|_| ProtocolError::SerializationError)
+            .and_then(VerifyingKey::from_bytes)
+    }
+}
+
 impl PureEddsaImpl for Ed25519 {
     type Signature = Signature;
     type SignatureLen = U64;
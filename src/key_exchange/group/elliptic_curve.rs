@@ -8,19 +8,45 @@
 
 //! Implementation for EC curves via [`elliptic_curve`] traits.
 
+#[cfg(any(feature = "batch", feature = "jwk"))]
+use alloc::vec::Vec;
 use core::fmt::{self, Debug, Formatter};
 
 use derive_where::derive_where;
+#[cfg(any(feature = "batch", feature = "jwk", feature = "pkcs8"))]
+use elliptic_curve::AffinePoint;
+#[cfg(feature = "jwk")]
+use elliptic_curve::JwkParameters;
+#[cfg(any(feature = "batch", feature = "jwk", feature = "pkcs8"))]
+use elliptic_curve::group::Curve;
 use elliptic_curve::group::GroupEncoding;
+#[cfg(not(all(feature = "precomputed-tables", feature = "std")))]
 use elliptic_curve::ops::MulByGenerator;
+#[cfg(feature = "batch")]
+use elliptic_curve::point::AffineCoordinates;
+#[cfg(feature = "jwk")]
+use elliptic_curve::sec1::EncodedPoint;
+#[cfg(any(feature = "jwk", feature = "pkcs8"))]
+use elliptic_curve::sec1::FromEncodedPoint;
 use elliptic_curve::sec1::{ModulusSize, ToEncodedPoint};
 use elliptic_curve::{
     CurveArithmetic, FieldBytesSize, NonZeroScalar, ProjectivePoint, Scalar, SecretKey, point,
 };
 use generic_array::GenericArray;
+#[cfg(feature = "jwk")]
+use generic_array::typenum::Unsigned;
+#[cfg(feature = "pkcs8")]
+use pkcs8::{
+    AssociatedOid, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey,
+    SecretDocument, der::Document,
+};
 use rand::{CryptoRng, RngCore};
 use voprf::Mode;
 
+#[cfg(feature = "jwk")]
+use super::JwkGroup;
+#[cfg(feature = "pkcs8")]
+use super::Pkcs8Group;
 use super::{Group, STR_OPAQUE_DERIVE_AUTH_KEY_PAIR};
 use crate::errors::{InternalError, ProtocolError};
 use crate::key_exchange::shared::DiffieHellman;
@@ -49,6 +75,11 @@ where
         GenericArray::clone_from_slice(pk.0.to_encoded_point(true).as_bytes())
     }
 
+    #[cfg(feature = "batch")]
+    fn serialize_pks(pks: &[Self::Pk]) -> Vec<GenericArray<u8, Self::PkLen>> {
+        serialize_pks(pks)
+    }
+
     fn deserialize_take_pk(bytes: &mut &[u8]) -> Result<Self::Pk, ProtocolError> {
         point::NonIdentity::<ProjectivePoint<Self>>::from_bytes(&bytes.take_array("public key")?)
             .into_option()
@@ -70,12 +101,15 @@ where
     }
 
     fn public_key(sk: &Self::Sk) -> Self::Pk {
+        #[cfg(all(feature = "precomputed-tables", feature = "std"))]
+        let point = super::basepoint_table::mul_by_generator::<Self>(&sk.to_nonzero_scalar());
+        #[cfg(not(all(feature = "precomputed-tables", feature = "std")))]
+        let point = ProjectivePoint::<Self>::mul_by_generator(&sk.to_nonzero_scalar());
+
         // Non-panicking version in https://github.com/RustCrypto/traits/pull/1833.
         NonIdentity(
-            point::NonIdentity::new(ProjectivePoint::<Self>::mul_by_generator(
-                &sk.to_nonzero_scalar(),
-            ))
-            .expect("multiplying with a non-zero scalar can never yield the identity element"),
+            point::NonIdentity::new(point)
+                .expect("multiplying with a non-zero scalar can never yield the identity element"),
         )
     }
 
@@ -109,6 +143,123 @@ where
     }
 }
 
+/// Serializes `pks` in compressed SEC1 form, normalizing them with the batch
+/// normalization of the backend.
+#[cfg(feature = "batch")]
+pub(super) fn serialize_pks<C: CurveArithmetic>(
+    pks: &[NonIdentity<C>],
+) -> Vec<GenericArray<u8, <FieldBytesSize<C> as ModulusSize>::CompressedPointSize>>
+where
+    FieldBytesSize<C>: ModulusSize,
+{
+    let points: Vec<_> = pks.iter().map(|pk| pk.0.to_point()).collect();
+    let mut affine = alloc::vec![AffinePoint::<C>::default(); points.len()];
+    ProjectivePoint::<C>::batch_normalize(&points, &mut affine);
+
+    affine
+        .iter()
+        .map(|point| {
+            let mut bytes = GenericArray::default();
+            // The SEC1 tag of a compressed point is 2 or 3 depending on the parity of
+            // the y-coordinate, followed by the x-coordinate.
+            bytes[0] = 2 | point.y_is_odd().unwrap_u8();
+            bytes[1..].copy_from_slice(&point.x());
+            bytes
+        })
+        .collect()
+}
+
+/// A [`Group`] backed by an [`elliptic_curve`] curve.
+///
+/// This is implemented for all curves supported by the generic implementation
+/// and for wrappers like `Secp256k1` that can't use it.
+pub trait CurveGroup: Group<Pk = NonIdentity<Self::Curve>, Sk = SecretKey<Self::Curve>> {
+    /// The underlying curve.
+    type Curve: CurveArithmetic;
+}
+
+impl<G> CurveGroup for G
+where
+    G: CurveArithmetic + voprf::CipherSuite<Group = G> + voprf::Group<Scalar = Scalar<G>>,
+    FieldBytesSize<G>: ModulusSize,
+    ProjectivePoint<G>: GroupEncoding<
+            Repr = GenericArray<u8, <FieldBytesSize<G> as ModulusSize>::CompressedPointSize>,
+        > + ToEncodedPoint<G>,
+{
+    type Curve = G;
+}
+
+#[cfg(feature = "pkcs8")]
+impl<G: CurveGroup> Pkcs8Group for G
+where
+    G::Curve: AssociatedOid,
+    FieldBytesSize<G::Curve>: ModulusSize,
+    AffinePoint<G::Curve>: FromEncodedPoint<G::Curve> + ToEncodedPoint<G::Curve>,
+{
+    fn sk_from_pkcs8_der(der: &[u8]) -> Result<Self::Sk, ProtocolError> {
+        SecretKey::from_pkcs8_der(der).map_err(|_| ProtocolError::SerializationError)
+    }
+
+    fn sk_to_pkcs8_der(sk: &Self::Sk) -> Result<SecretDocument, ProtocolError> {
+        sk.to_pkcs8_der()
+            .map_err(|_| ProtocolError::SerializationError)
+    }
+
+    fn pk_from_spki_der(der: &[u8]) -> Result<Self::Pk, ProtocolError> {
+        let pk = elliptic_curve::PublicKey::<G::Curve>::from_public_key_der(der)
+            .map_err(|_| ProtocolError::SerializationError)?;
+
+        point::NonIdentity::new(pk.to_projective())
+            .into_option()
+            .map(NonIdentity)
+            .ok_or(ProtocolError::SerializationError)
+    }
+
+    fn pk_to_spki_der(pk: &Self::Pk) -> Result<Document, ProtocolError> {
+        elliptic_curve::PublicKey::<G::Curve>::from_affine(pk.0.to_point().to_affine())
+            .and_then(|pk| pk.to_public_key_der().map_err(|_| elliptic_curve::Error))
+            .map_err(|_| ProtocolError::SerializationError)
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl<G: CurveGroup> JwkGroup for G
+where
+    G::Curve: JwkParameters,
+    FieldBytesSize<G::Curve>: ModulusSize,
+    AffinePoint<G::Curve>: FromEncodedPoint<G::Curve> + ToEncodedPoint<G::Curve>,
+{
+    const KTY: &'static str = "EC";
+    const CRV: &'static str = <G::Curve as JwkParameters>::CRV;
+
+    fn pk_to_jwk(pk: &Self::Pk) -> (Vec<u8>, Option<Vec<u8>>) {
+        let point = pk.0.to_point().to_affine().to_encoded_point(false);
+        // Skip the SEC1 tag, both coordinates have the size of a field element.
+        let (x, y) = point.as_bytes()[1..].split_at(FieldBytesSize::<G::Curve>::USIZE);
+
+        (x.to_vec(), Some(y.to_vec()))
+    }
+
+    fn pk_from_jwk(x: &[u8], y: Option<&[u8]>) -> Result<Self::Pk, ProtocolError> {
+        let x = GenericArray::from_exact_iter(x.iter().copied());
+        let y = y.and_then(|y| GenericArray::from_exact_iter(y.iter().copied()));
+        let (Some(x), Some(y)) = (x, y) else {
+            return Err(ProtocolError::SerializationError);
+        };
+
+        let point = EncodedPoint::<G::Curve>::from_affine_coordinates(&x, &y, false);
+        let pk = Option::<elliptic_curve::PublicKey<G::Curve>>::from(
+            elliptic_curve::PublicKey::from_encoded_point(&point),
+        )
+        .ok_or(ProtocolError::SerializationError)?;
+
+        point::NonIdentity::new(pk.to_projective())
+            .into_option()
+            .map(NonIdentity)
+            .ok_or(ProtocolError::SerializationError)
+    }
+}
+
 /// Wrapper around [`NonIdentity`](point::NonIdentity) to [`Eq`].
 // TODO: remove after https://github.com/RustCrypto/traits/pull/1834.
 #[derive_where(Clone, Copy)]
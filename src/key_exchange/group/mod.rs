@@ -8,15 +8,28 @@
 
 //! Includes the [`Group`] trait and definitions for the key exchange groups
 
+#[cfg(all(feature = "precomputed-tables", feature = "std"))]
+mod basepoint_table;
 #[cfg(feature = "curve25519")]
 pub mod curve25519;
 #[cfg(feature = "ed25519")]
 pub mod ed25519;
 pub mod elliptic_curve;
+#[cfg(all(feature = "pkcs8", any(feature = "curve25519", feature = "ed25519")))]
+mod rfc8410;
 #[cfg(feature = "ristretto255")]
 pub mod ristretto255;
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+
+#[cfg(any(feature = "batch", feature = "jwk"))]
+use alloc::vec::Vec;
 
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "pkcs8")]
+use pkcs8::SecretDocument;
+#[cfg(feature = "pkcs8")]
+use pkcs8::der::Document;
 use rand::{CryptoRng, RngCore};
 use zeroize::ZeroizeOnDrop;
 
@@ -38,6 +51,16 @@ pub trait Group {
     /// Serializes `self`
     fn serialize_pk(pk: &Self::Pk) -> GenericArray<u8, Self::PkLen>;
 
+    /// Serializes many public keys at once, with the same result as
+    /// [`Self::serialize_pk()`] for every one of them
+    ///
+    /// Backends that can normalize many points with a single field inversion
+    /// override this.
+    #[cfg(feature = "batch")]
+    fn serialize_pks(pks: &[Self::Pk]) -> Vec<GenericArray<u8, Self::PkLen>> {
+        pks.iter().map(Self::serialize_pk).collect()
+    }
+
     /// Return a public key from its fixed-length bytes representation
     ///
     /// The deserialized bytes must be taken from `bytes`.
@@ -60,3 +83,40 @@ pub trait Group {
     /// The deserialized bytes must be taken from `bytes`.
     fn deserialize_take_sk(bytes: &mut &[u8]) -> Result<Self::Sk, ProtocolError>;
 }
+
+/// A [`Group`] whose keys have a standard PKCS#8 and SPKI encoding, see
+/// [`PrivateKey::from_pkcs8_der()`](crate::keypair::PrivateKey::from_pkcs8_der)
+/// and
+/// [`PublicKey::from_public_key_der()`](crate::keypair::PublicKey::from_public_key_der).
+#[cfg(feature = "pkcs8")]
+pub trait Pkcs8Group: Group {
+    /// Decodes a secret key from a PKCS#8 `PrivateKeyInfo` document
+    fn sk_from_pkcs8_der(der: &[u8]) -> Result<Self::Sk, ProtocolError>;
+
+    /// Encodes a secret key into a PKCS#8 `PrivateKeyInfo` document
+    fn sk_to_pkcs8_der(sk: &Self::Sk) -> Result<SecretDocument, ProtocolError>;
+
+    /// Decodes a public key from a `SubjectPublicKeyInfo` document
+    fn pk_from_spki_der(der: &[u8]) -> Result<Self::Pk, ProtocolError>;
+
+    /// Encodes a public key into a `SubjectPublicKeyInfo` document
+    fn pk_to_spki_der(pk: &Self::Pk) -> Result<Document, ProtocolError>;
+}
+
+/// A [`Group`] whose public keys have a JSON Web Key encoding, see
+/// [`PublicKey::to_jwk()`](crate::keypair::PublicKey::to_jwk).
+#[cfg(feature = "jwk")]
+pub trait JwkGroup: Group {
+    /// The JWK `kty` parameter
+    const KTY: &'static str;
+
+    /// The JWK `crv` parameter
+    const CRV: &'static str;
+
+    /// Returns the `x` and, if the key type has one, the `y` coordinate of a
+    /// public key
+    fn pk_to_jwk(pk: &Self::Pk) -> (Vec<u8>, Option<Vec<u8>>);
+
+    /// Decodes a public key from its `x` and `y` coordinates
+    fn pk_from_jwk(x: &[u8], y: Option<&[u8]>) -> Result<Self::Pk, ProtocolError>;
+}
@@ -0,0 +1,96 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! PKCS#8 and SPKI encoding of X25519 and Ed25519 keys as specified in
+//! [RFC 8410](https://www.rfc-editor.org/rfc/rfc8410.html): private keys are
+//! wrapped in an octet string inside of the `PrivateKeyInfo`, public keys are
+//! stored as is, and neither has algorithm parameters.
+
+use pkcs8::der::asn1::{BitStringRef, OctetStringRef};
+use pkcs8::der::{Decode, Document, Encode};
+use pkcs8::spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
+use pkcs8::{ObjectIdentifier, PrivateKeyInfo, SecretDocument};
+use zeroize::Zeroizing;
+
+use crate::errors::ProtocolError;
+
+pub(super) fn sk_from_pkcs8_der(
+    oid: ObjectIdentifier,
+    der: &[u8],
+) -> Result<Zeroizing<[u8; 32]>, ProtocolError> {
+    let info = PrivateKeyInfo::try_from(der).map_err(|_| ProtocolError::SerializationError)?;
+    info.algorithm
+        .assert_algorithm_oid(oid)
+        .map_err(|_| ProtocolError::SerializationError)?;
+
+    if info.algorithm.parameters.is_some() {
+        return Err(ProtocolError::SerializationError);
+    }
+
+    let sk = OctetStringRef::from_der(info.private_key)
+        .map_err(|_| ProtocolError::SerializationError)?;
+
+    sk.as_bytes()
+        .try_into()
+        .map(Zeroizing::new)
+        .map_err(|_| ProtocolError::SerializationError)
+}
+
+pub(super) fn sk_to_pkcs8_der(
+    oid: ObjectIdentifier,
+    sk: &[u8; 32],
+) -> Result<SecretDocument, ProtocolError> {
+    let sk = OctetStringRef::new(sk).map_err(|_| ProtocolError::SerializationError)?;
+    let mut buffer = Zeroizing::new([0; 34]);
+    let sk = sk
+        .encode_to_slice(buffer.as_mut())
+        .map_err(|_| ProtocolError::SerializationError)?;
+    let algorithm = AlgorithmIdentifierRef {
+        oid,
+        parameters: None,
+    };
+
+    SecretDocument::encode_msg(&PrivateKeyInfo::new(algorithm, sk))
+        .map_err(|_| ProtocolError::SerializationError)
+}
+
+pub(super) fn pk_from_spki_der(
+    oid: ObjectIdentifier,
+    der: &[u8],
+) -> Result<[u8; 32], ProtocolError> {
+    let info =
+        SubjectPublicKeyInfoRef::try_from(der).map_err(|_| ProtocolError::SerializationError)?;
+    info.algorithm
+        .assert_algorithm_oid(oid)
+        .map_err(|_| ProtocolError::SerializationError)?;
+
+    if info.algorithm.parameters.is_some() {
+        return Err(ProtocolError::SerializationError);
+    }
+
+    info.subject_public_key
+        .as_bytes()
+        .and_then(|pk| pk.try_into().ok())
+        .ok_or(ProtocolError::SerializationError)
+}
+
+pub(super) fn pk_to_spki_der(
+    oid: ObjectIdentifier,
+    pk: &[u8; 32],
+) -> Result<Document, ProtocolError> {
+    let info = SubjectPublicKeyInfoRef {
+        algorithm: AlgorithmIdentifierRef {
+            oid,
+            parameters: None,
+        },
+        subject_public_key: BitStringRef::from_bytes(pk)
+            .map_err(|_| ProtocolError::SerializationError)?,
+    };
+
+    Document::encode_msg(&info).map_err(|_| ProtocolError::SerializationError)
+}
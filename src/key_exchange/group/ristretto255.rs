@@ -9,7 +9,6 @@
 //! Key Exchange group implementation for ristretto255
 
 pub use curve25519_dalek;
-use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
 use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
 use curve25519_dalek::scalar::Scalar;
 use curve25519_dalek::traits::IsIdentity;
@@ -65,7 +64,7 @@ impl Group for Ristretto255 {
     }
 
     fn public_key(sk: &Self::Sk) -> Self::Pk {
-        NonIdentity(RISTRETTO_BASEPOINT_POINT * sk.0)
+        NonIdentity(RistrettoPoint::mul_base(&sk.0))
     }
 
     fn serialize_sk(sk: &Self::Sk) -> GenericArray<u8, Self::SkLen> {
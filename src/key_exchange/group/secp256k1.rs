@@ -0,0 +1,127 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Key Exchange group implementation for secp256k1
+
+#[cfg(feature = "batch")]
+use alloc::vec::Vec;
+
+use elliptic_curve::group::GroupEncoding;
+use elliptic_curve::ops::MulByGenerator;
+use elliptic_curve::sec1::ToEncodedPoint;
+use elliptic_curve::{NonZeroScalar, ProjectivePoint, SecretKey, point};
+use generic_array::GenericArray;
+use generic_array::typenum::{U32, U33};
+pub use k256;
+use rand::{CryptoRng, RngCore};
+use voprf::Mode;
+
+use super::elliptic_curve::{CurveGroup, NonIdentity};
+use super::{Group, STR_OPAQUE_DERIVE_AUTH_KEY_PAIR};
+use crate::errors::{InternalError, ProtocolError};
+use crate::key_exchange::shared::DiffieHellman;
+use crate::serialization::SliceExt;
+
+/// Implementation for secp256k1.
+///
+/// This can be used as the [`KeyExchange::Group`](crate::key_exchange::KeyExchange::Group)
+/// for [`TripleDh`](crate::TripleDh) and [`SigmaI`](crate::SigmaI), and as the
+/// group for [`Ecdsa`](crate::Ecdsa).
+///
+/// secp256k1 is not an [RFC 9497](https://www.rfc-editor.org/rfc/rfc9497)
+/// ciphersuite and can't be used as
+/// [`CipherSuite::OprfCs`](crate::CipherSuite::OprfCs).
+// This is necessary because `k256::Secp256k1` doesn't implement
+// `voprf::CipherSuite`, which the generic `elliptic_curve` implementation
+// requires, and we can't implement it for a foreign type.
+pub struct Secp256k1;
+
+// Only used to derive keys in the same way as the other groups, through
+// `voprf::derive_key()`. It is kept private so it can't be used as a
+// non-standard OPRF ciphersuite.
+struct DeriveKeySuite;
+
+impl voprf::CipherSuite for DeriveKeySuite {
+    const ID: &'static str = "secp256k1-SHA256";
+
+    type Group = k256::Secp256k1;
+
+    type Hash = k256::sha2::Sha256;
+}
+
+impl Group for Secp256k1 {
+    type Pk = NonIdentity<k256::Secp256k1>;
+    type PkLen = U33;
+    type Sk = SecretKey<k256::Secp256k1>;
+    type SkLen = U32;
+
+    fn serialize_pk(pk: &Self::Pk) -> GenericArray<u8, Self::PkLen> {
+        GenericArray::clone_from_slice(pk.0.to_encoded_point(true).as_bytes())
+    }
+
+    #[cfg(feature = "batch")]
+    fn serialize_pks(pks: &[Self::Pk]) -> Vec<GenericArray<u8, Self::PkLen>> {
+        super::elliptic_curve::serialize_pks(pks)
+    }
+
+    fn deserialize_take_pk(bytes: &mut &[u8]) -> Result<Self::Pk, ProtocolError> {
+        point::NonIdentity::<ProjectivePoint<k256::Secp256k1>>::from_bytes(
+            &bytes.take_array("public key")?,
+        )
+        .into_option()
+        .map(NonIdentity)
+        .ok_or(ProtocolError::SerializationError)
+    }
+
+    fn random_sk<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Sk {
+        SecretKey::random(rng)
+    }
+
+    fn derive_scalar(seed: GenericArray<u8, Self::SkLen>) -> Result<Self::Sk, InternalError> {
+        voprf::derive_key::<DeriveKeySuite>(&seed, &STR_OPAQUE_DERIVE_AUTH_KEY_PAIR, Mode::Oprf)
+            .map(|scalar| {
+                NonZeroScalar::new(scalar).expect("`voprf::derive_key()` returned a zero scalar")
+            })
+            .map(SecretKey::from)
+            .map_err(InternalError::from)
+    }
+
+    fn public_key(sk: &Self::Sk) -> Self::Pk {
+        // Uses `k256`'s precomputed base-point table with the `precomputed-tables`
+        // feature.
+        NonIdentity(
+            point::NonIdentity::new(ProjectivePoint::<k256::Secp256k1>::mul_by_generator(
+                &sk.to_nonzero_scalar(),
+            ))
+            .expect("multiplying with a non-zero scalar can never yield the identity element"),
+        )
+    }
+
+    fn serialize_sk(sk: &Self::Sk) -> GenericArray<u8, Self::SkLen> {
+        sk.to_bytes()
+    }
+
+    fn deserialize_take_sk(bytes: &mut &[u8]) -> Result<Self::Sk, ProtocolError> {
+        SecretKey::from_bytes(&bytes.take_array("secret key")?)
+            .map_err(|_| ProtocolError::SerializationError)
+    }
+}
+
+impl CurveGroup for Secp256k1 {
+    type Curve = k256::Secp256k1;
+}
+
+impl DiffieHellman<Secp256k1> for SecretKey<k256::Secp256k1> {
+    fn diffie_hellman(&self, pk: &NonIdentity<k256::Secp256k1>) -> GenericArray<u8, U33> {
+        GenericArray::clone_from_slice(
+            (pk.0 * self.to_nonzero_scalar())
+                .to_encoded_point(true)
+                .as_bytes(),
+        )
+    }
+}
@@ -9,6 +9,7 @@
 //! Includes instantiations of key exchange protocols used in the login step for
 //! OPAQUE
 
+pub mod ephemeral;
 pub mod group;
 pub(crate) mod shared;
 pub mod sigma_i;
@@ -16,6 +17,8 @@ pub mod tripledh;
 #[cfg(feature = "kem")]
 pub mod tripledh_kem;
 
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
 use core::iter;
 use core::ops::Add;
 
@@ -29,15 +32,14 @@ use rand::{CryptoRng, RngCore};
 use voprf::{BlindedElement, EvaluationElement};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-#[cfg(test)]
-use crate::ciphersuite::KeHash;
-use crate::ciphersuite::{CipherSuite, OprfGroup};
+use crate::ciphersuite::{CipherSuite, KeHash, OprfGroup};
 use crate::errors::ProtocolError;
 use crate::hash::{Hash, ProxyHash};
+use crate::key_exchange::ephemeral::Ephemeral;
 use crate::key_exchange::group::Group;
-use crate::key_exchange::shared::{NonceLen, STR_CONTEXT};
+use crate::key_exchange::shared::{NonceLen, STR_CONTEXT, STR_PSK};
 use crate::keypair::{PrivateKey, PublicKey};
-use crate::opaque::{Identifiers, MaskedResponse, MaskedResponseLen};
+use crate::opaque::{Identifiers, MaskedResponse, MaskedResponseLen, PreSharedKey};
 use crate::serialization::{SliceExt, i2osp};
 
 /// The key exchange trait.
@@ -69,6 +71,21 @@ where
     /// Second message sent by the client.
     type KE3Message: ZeroizeOnDrop + Clone;
 
+    /// Whether [`Self::ke2_builder_with_ephemeral()`] and
+    /// [`Self::generate_ke2_input()`] draw no randomness from `rng` besides the
+    /// [`Ephemeral`], so that
+    /// [`ServerLogin::start_batch()`](crate::ServerLogin::start_batch) can
+    /// generate the [`Ephemeral`]s of all requests together.
+    #[cfg(feature = "batch")]
+    const DETERMINISTIC_WITH_EPHEMERAL: bool = false;
+
+    /// Whether the key exchange encrypts the application payloads of a login
+    /// and authenticates them with its MACs. Logins with a payload fail with
+    /// [`ProtocolError::UnsupportedPayloadError`] otherwise. Only the key
+    /// exchanges of this crate support payloads.
+    #[cfg(feature = "payload")]
+    const AUTHENTICATES_PAYLOADS: bool = false;
+
     /// Client generates [`KE1Message`](Self::KE1Message) and
     /// [`KE1State`](Self::KE1State).
     fn generate_ke1<R: RngCore + CryptoRng>(
@@ -86,6 +103,39 @@ where
         context: SerializedContext<'a>,
     ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError>;
 
+    /// Server generates [`KE2Builder`](Self::KE2Builder) with a precomputed
+    /// [`Ephemeral`] instead of generating one from `rng`. Fails with
+    /// [`ProtocolError::UnsupportedEphemeralError`] by default, which is also
+    /// the case for [`SigmaI`](crate::SigmaI), whose ephemeral key is not in
+    /// [`Self::Group`](Self::Group).
+    #[allow(clippy::too_many_arguments)]
+    fn ke2_builder_with_ephemeral<
+        'a,
+        CS: CipherSuite<KeyExchange = Self>,
+        R: RngCore + CryptoRng,
+    >(
+        rng: &mut R,
+        ephemeral: Ephemeral<Self::Group>,
+        credential_request: SerializedCredentialRequest<CS>,
+        ke1_message: Self::KE1Message,
+        credential_response: SerializedCredentialResponse<CS>,
+        client_s_pk: PublicKey<Self::Group>,
+        identifiers: SerializedIdentifiers<'a, Self::Group>,
+        context: SerializedContext<'a>,
+    ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError> {
+        let _ = (
+            rng,
+            ephemeral,
+            credential_request,
+            ke1_message,
+            credential_response,
+            client_s_pk,
+            identifiers,
+            context,
+        );
+        Err(ProtocolError::UnsupportedEphemeralError)
+    }
+
     /// Server returns the data for the remote key interaction.
     fn ke2_builder_data<'a, CS: CipherSuite<KeyExchange = Self>>(
         builder: &'a Self::KE2Builder<'_, CS>,
@@ -126,7 +176,7 @@ where
         ke3_message: Self::KE3Message,
         identifiers: Identifiers<'_>,
         context: SerializedContext<'_>,
-    ) -> Result<Output<Self::Hash>, ProtocolError>;
+    ) -> Result<FinishKeResult<Self>, ProtocolError>;
 }
 
 /// Serialized form of [`CredentialRequest`](crate::CredentialRequest).
@@ -234,13 +284,13 @@ where
     }
 }
 
-/// Serialized form of a `context` given in
+/// Serialized form of a `context` and `psk` given in
 /// [`ClientLoginFinishParameters`](crate::ClientLoginFinishParameters) or
 /// [`ServerLoginParameters`](crate::ServerLoginParameters).
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
-    serde(bound = "")
+    serde(bound(deserialize = "'de: 'a", serialize = ""))
 )]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Zeroize)]
 #[allow(unused_assignments)]
@@ -248,21 +298,113 @@ pub struct SerializedContext<'a> {
     length: GenericArray<u8, U2>,
     #[zeroize(skip)]
     context: &'a [u8],
+    psk: Option<SerializedPsk<'a>>,
+    #[cfg(feature = "payload")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[zeroize(skip)]
+    payloads: Payloads<'a>,
+}
+
+/// The application payloads of a login, which are never serialized.
+#[cfg(feature = "payload")]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[derive_where(Debug)]
+pub(crate) struct Payloads<'a> {
+    // The plaintext of the payload to encrypt and send
+    #[derive_where(skip)]
+    pub(crate) send: Option<&'a [u8]>,
+    // The encrypted payload received from the peer
+    pub(crate) received: Option<&'a [u8]>,
+}
+
+/// Serialized form of a [`PreSharedKey`].
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(bound(deserialize = "'de: 'a", serialize = ""))
+)]
+#[derive(Clone, Eq, Hash, PartialEq, Zeroize)]
+#[derive_where(Debug)]
+#[allow(unused_assignments)]
+struct SerializedPsk<'a> {
+    identity_length: GenericArray<u8, U2>,
+    #[zeroize(skip)]
+    identity: &'a [u8],
+    // Only the identity is part of the transcript, the key is never serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derive_where(skip)]
+    #[zeroize(skip)]
+    key: Option<&'a [u8]>,
 }
 
 impl<'a> SerializedContext<'a> {
-    pub(crate) fn from(context: Option<&'a [u8]>) -> Result<Self, ProtocolError> {
+    pub(crate) fn from(
+        context: Option<&'a [u8]>,
+        psk: Option<PreSharedKey<'a>>,
+    ) -> Result<Self, ProtocolError> {
         let context = context.unwrap_or(&[]);
+        let psk = psk
+            .map(|psk| {
+                Ok::<_, ProtocolError>(SerializedPsk {
+                    identity_length: i2osp::<U2>(psk.identity.len())?,
+                    identity: psk.identity,
+                    key: Some(psk.key),
+                })
+            })
+            .transpose()?;
 
         Ok(Self {
             length: i2osp::<U2>(context.len())?,
             context,
+            psk,
+            #[cfg(feature = "payload")]
+            payloads: Payloads::default(),
         })
     }
 
+    // Adds the application payloads of a login.
+    #[cfg(feature = "payload")]
+    pub(crate) fn with_payloads(mut self, payloads: Payloads<'a>) -> Self {
+        self.payloads = payloads;
+        self
+    }
+
+    // Returns the plaintext of the payload to send, if one was given.
+    #[cfg(feature = "payload")]
+    pub(crate) fn payload_to_send(&self) -> Option<&'a [u8]> {
+        self.payloads.send
+    }
+
+    // Returns the encrypted payload received from the peer, if any.
+    pub(crate) fn received_payload(&self) -> Option<&'a [u8]> {
+        #[cfg(feature = "payload")]
+        return self.payloads.received;
+        #[cfg(not(feature = "payload"))]
+        None
+    }
+
     /// Returns the serialized form of `context` in multiple byte slices.
+    ///
+    /// If a [`PreSharedKey`] was given, its identity is appended, but never the
+    /// key itself.
     pub fn iter(&self) -> impl Clone + Iterator<Item = &[u8]> {
-        iter::once(STR_CONTEXT).chain([self.length.as_slice(), self.context])
+        iter::once(STR_CONTEXT)
+            .chain([self.length.as_slice(), self.context])
+            .chain(
+                self.psk
+                    .iter()
+                    .flat_map(|psk| [STR_PSK, psk.identity_length.as_slice(), psk.identity]),
+            )
+    }
+
+    /// Returns the key of the [`PreSharedKey`], if one was given. Key exchange
+    /// implementations have to input it into the HKDF-Extract of their key
+    /// schedule.
+    ///
+    /// The key is skipped when serializing, a deserialized
+    /// [`SerializedContext`] returns [`None`].
+    pub fn psk(&self) -> Option<&'a [u8]> {
+        self.psk.as_ref().and_then(|psk| psk.key)
     }
 }
 
@@ -379,6 +521,11 @@ pub struct GenerateKe2Result<CS: CipherSuite> {
     pub state: <CS::KeyExchange as KeyExchange>::KE2State<CS>,
     /// The server message.
     pub message: <CS::KeyExchange as KeyExchange>::KE2Message,
+    /// The secret used to export keying material.
+    pub exporter_secret: Output<KeHash<CS>>,
+    /// The encrypted payload to the client, if one was given.
+    #[cfg(feature = "payload")]
+    pub payload: Option<Vec<u8>>,
     #[cfg(test)]
     pub(crate) handshake_secret: Output<KeHash<CS>>,
     #[cfg(test)]
@@ -389,14 +536,27 @@ pub struct GenerateKe2Result<CS: CipherSuite> {
 pub struct GenerateKe3Result<KE: KeyExchange + ?Sized> {
     /// The session key.
     pub session_key: Output<KE::Hash>,
+    /// The secret used to export keying material.
+    pub exporter_secret: Output<KE::Hash>,
     /// The second client message.
     pub message: KE::KE3Message,
+    /// The encrypted payload to the server, if one was given.
+    #[cfg(feature = "payload")]
+    pub payload: Option<Vec<u8>>,
     #[cfg(test)]
     pub(crate) handshake_secret: Output<KE::Hash>,
     #[cfg(test)]
     pub(crate) km3: Output<KE::Hash>,
 }
 
+/// Result type of [`KeyExchange::finish_ke()`].
+pub struct FinishKeResult<KE: KeyExchange + ?Sized> {
+    /// The session key.
+    pub session_key: Output<KE::Hash>,
+    /// The secret used to export keying material.
+    pub exporter_secret: Output<KE::Hash>,
+}
+
 pub(crate) type Ke1StateLen<CS: CipherSuite> =
     <<CS::KeyExchange as KeyExchange>::KE1State as Serialize>::Len;
 pub(crate) type Ke1MessageLen<CS: CipherSuite> =
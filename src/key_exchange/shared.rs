@@ -6,27 +6,33 @@
 // of this source tree. You may select, at your option, one of the above-listed
 // licenses.
 
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
 use core::ops::Add;
 
 use derive_where::derive_where;
 use digest::core_api::BlockSizeUser;
 use digest::{Digest, Mac, Output, OutputSizeUser, Update};
 use generic_array::sequence::Concat;
-use generic_array::typenum::{IsLess, Le, NonZero, Sum, U1, U2, U32, U256, Unsigned};
+use generic_array::typenum::{IsLess, Le, NonZero, Sum, U1, U2, U32, U256};
 use generic_array::{ArrayLength, GenericArray};
 use hkdf::{Hkdf, HkdfExtract};
 use hmac::Hmac;
 use rand::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
 
+use super::ephemeral::Ephemeral;
 use super::{
     Deserialize, GenerateKe1Result, KeyExchange, Serialize, SerializedContext,
     SerializedCredentialRequest, SerializedCredentialResponse, SerializedIdentifiers,
 };
 use crate::ciphersuite::{CipherSuite, KeGroup, KeHash};
 use crate::errors::{InternalError, ProtocolError};
-use crate::hash::{Hash, OutputSize, ProxyHash};
+use crate::hash::{Hash, ProxyHash};
 use crate::key_exchange::group::Group;
 use crate::keypair::{KeyPair, PrivateKey, PublicKey};
+#[cfg(feature = "payload")]
+use crate::payload;
 use crate::serialization::{SliceExt, UpdateExt, i2osp};
 
 ///////////////
@@ -37,10 +43,14 @@ use crate::serialization::{SliceExt, UpdateExt, i2osp};
 pub(crate) type NonceLen = U32;
 pub(super) static STR_CONTEXT: &[u8] = b"OPAQUEv1-";
 static STR_CLIENT_MAC: &[u8] = b"ClientMAC";
+static STR_EXPORTER: &[u8] = b"Exporter";
+static STR_EXPORTER_SECRET: &[u8] = b"ExporterSecret";
 static STR_HANDSHAKE_SECRET: &[u8] = b"HandshakeSecret";
 static STR_SERVER_MAC: &[u8] = b"ServerMAC";
 static STR_SESSION_KEY: &[u8] = b"SessionKey";
 static STR_OPAQUE: &[u8] = b"OPAQUE-";
+pub(super) static STR_PSK: &[u8] = b"PSK";
+static STR_PAYLOAD: &[u8] = b"Payload";
 
 ////////////////////////////
 // High-level API Structs //
@@ -92,9 +102,11 @@ pub struct Ke1Message<G: Group> {
 //==================== //
 /////////////////////////
 
-// Consists of a session key, followed by two mac keys: (session_key, km2, km3)
+// Consists of a session key, an exporter secret, followed by two mac keys:
+// (session_key, exporter_secret, km2, km3)
 pub(super) struct DerivedKeys<H: OutputSizeUser> {
     pub(super) session_key: Output<H>,
+    pub(super) exporter_secret: Output<H>,
     pub(super) km2: Output<H>,
     pub(super) km3: Output<H>,
     #[cfg(test)]
@@ -116,6 +128,7 @@ where
     pub(super) server_e_pk: PublicKey<G>,
     pub(super) shared_secret_1: GenericArray<u8, G::PkLen>,
     pub(super) shared_secret_3: GenericArray<u8, G::PkLen>,
+    pub(super) psk: Option<Output<H>>,
 }
 
 ////////////////////////////////////////////////
@@ -178,19 +191,18 @@ pub(super) fn transcript<CS: CipherSuite, KE: Group>(
 
 /// Generates the server-side `TripleDH` transcript state shared by multiple
 /// key-exchange variants.
-pub(super) fn ke2_builder_common<'a, G, H, CS, R>(
-    rng: &mut R,
+pub(super) fn ke2_builder_common<G, H, CS>(
+    server_ephemeral: Ephemeral<G>,
     credential_request: SerializedCredentialRequest<CS>,
     ke1_message: Ke1Message<G>,
     credential_response: SerializedCredentialResponse<CS>,
     client_s_pk: PublicKey<G>,
-    identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
-    context: SerializedContext<'a>,
+    identifiers: SerializedIdentifiers<'_, KeGroup<CS>>,
+    context: SerializedContext<'_>,
 ) -> Result<Ke2BuilderCommon<G, H>, ProtocolError>
 where
     G: Group,
     H: Hash,
-    R: RngCore + CryptoRng,
     CS: CipherSuite,
     H::Core: ProxyHash,
     <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
@@ -198,9 +210,7 @@ where
     G::Sk: DiffieHellman<G>,
     CS::KeyExchange: KeyExchange<Group = G, Hash = H>,
 {
-    let server_ephemeral = KeyPair::<G>::derive_random(rng);
-    let server_nonce = generate_nonce::<R>(rng);
-    let server_e_pk_bytes = server_ephemeral.public().serialize();
+    let server_nonce = server_ephemeral.nonce;
 
     let ke1_iter = ke1_message.to_iter();
     let client_e_pk = ke1_message.client_e_pk.clone();
@@ -212,24 +222,38 @@ where
         &ke1_iter,
         &credential_response,
         server_nonce,
-        &server_e_pk_bytes,
+        &server_ephemeral.pk_bytes,
     );
 
     let shared_secret_1 = server_ephemeral
-        .private()
+        .sk
         .ke_diffie_hellman(&ke1_message.client_e_pk);
-    let shared_secret_3 = server_ephemeral.private().ke_diffie_hellman(&client_s_pk);
+    let shared_secret_3 = server_ephemeral.sk.ke_diffie_hellman(&client_s_pk);
 
     Ok(Ke2BuilderCommon {
         server_nonce,
         transcript_hasher,
         client_e_pk,
-        server_e_pk: server_ephemeral.public().clone(),
+        server_e_pk: server_ephemeral.pk.clone(),
         shared_secret_1,
         shared_secret_3,
+        psk: psk_ikm::<H>(&context),
     })
 }
 
+// Extracts the input into the key schedule from the key of a `PreSharedKey`. It
+// has a fixed length, so builders can keep it without borrowing the key.
+pub(super) fn psk_ikm<H: Hash>(context: &SerializedContext<'_>) -> Option<Output<H>>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    context
+        .psk()
+        .map(|psk| Hkdf::<H>::extract(Some(STR_PSK), psk).0)
+}
+
 // Internal function which takes computed shared secrets, along with some
 // auxiliary metadata, to produce the session key and two MAC keys
 pub(super) fn derive_keys<'a, H: Hash>(
@@ -259,11 +283,13 @@ where
         hashed_derivation_transcript,
     )?;
 
+    let exporter_secret = hkdf_expand_label::<H>(&handshake_secret, STR_EXPORTER_SECRET, b"")?;
     let km2 = hkdf_expand_label::<H>(&handshake_secret, STR_SERVER_MAC, b"")?;
     let km3 = hkdf_expand_label::<H>(&handshake_secret, STR_CLIENT_MAC, b"")?;
 
     Ok(DerivedKeys {
         session_key,
+        exporter_secret,
         km2,
         km3,
         #[cfg(test)]
@@ -308,6 +334,7 @@ pub(super) fn finalize_ke3_transcript<'a, H: Hash>(
     transcript_hasher: &mut H,
     shared_secrets: impl Iterator<Item = &'a [u8]>,
     server_mac: &Output<H>,
+    server_payload: Option<&[u8]>,
 ) -> Result<(DerivedKeys<H>, Output<H>), ProtocolError>
 where
     H::Core: ProxyHash,
@@ -319,11 +346,13 @@ where
     let mut server_mac_hasher =
         Hmac::<H>::new_from_slice(&derived_keys.km2).map_err(|_| InternalError::HmacError)?;
     Mac::update(&mut server_mac_hasher, &transcript_digest);
-    server_mac_hasher
-        .verify(server_mac)
-        .map_err(|_| ProtocolError::InvalidLoginError)?;
+    let expected_server_mac = server_mac_hasher.finalize().into_bytes();
 
-    transcript_hasher.update(server_mac.as_slice());
+    bool::from(bind_payload::<H>(expected_server_mac.clone(), server_payload)?.ct_eq(server_mac))
+        .then_some(())
+        .ok_or(ProtocolError::InvalidLoginError)?;
+
+    transcript_hasher.update(expected_server_mac.as_slice());
     let finalized_transcript = transcript_hasher.clone().finalize();
 
     let mut client_mac_hasher =
@@ -335,6 +364,84 @@ where
     Ok((derived_keys, client_mac))
 }
 
+// Extends a MAC over the encrypted application payload sent with it, if any, so
+// that stripping, adding or modifying the payload fails the MAC check. Only the
+// MAC without the payload is part of the transcript.
+pub(super) fn bind_payload<H: Hash>(
+    mac: Output<H>,
+    payload: Option<&[u8]>,
+) -> Result<Output<H>, ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let Some(payload) = payload else {
+        return Ok(mac);
+    };
+
+    let mut mac_hasher = Hmac::<H>::new_from_slice(&mac).map_err(|_| InternalError::HmacError)?;
+    Mac::update(&mut mac_hasher, STR_PAYLOAD);
+    Mac::update(&mut mac_hasher, payload);
+    Ok(mac_hasher.finalize().into_bytes())
+}
+
+// Encrypts the payload to send, if one was given, and binds `mac` to it
+#[cfg(feature = "payload")]
+pub(super) fn seal_payload<H: Hash>(
+    exporter_secret: &[u8],
+    label: &'static [u8],
+    plaintext: Option<&[u8]>,
+    mac: Output<H>,
+) -> Result<(Option<Vec<u8>>, Output<H>), ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let payload = plaintext
+        .map(|plaintext| payload::seal::<H>(exporter_secret, label, plaintext))
+        .transpose()?;
+    let mac = bind_payload::<H>(mac, payload.as_deref())?;
+
+    Ok((payload, mac))
+}
+
+// Derives keying material from the exporter secret, analogous to the TLS 1.3
+// exporter (RFC 8446 Section 7.5)
+pub(crate) fn export_keying_material<H: Hash>(
+    exporter_secret: &[u8],
+    label: &[u8],
+    context: &[u8],
+    output: &mut [u8],
+) -> Result<(), ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let secret = hkdf_expand_label::<H>(exporter_secret, label, b"")?;
+    let h = Hkdf::<H>::from_prk(&secret).map_err(|_| InternalError::HkdfError)?;
+    hkdf_expand_label_into(&h, STR_EXPORTER, &H::digest(context), output)
+}
+
+// Derives the key for an application payload from the exporter secret
+#[cfg(feature = "payload")]
+pub(crate) fn derive_payload_key<H: Hash>(
+    exporter_secret: &[u8],
+    label: &[u8],
+) -> Result<chacha20poly1305::Key, ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let h = Hkdf::<H>::from_prk(exporter_secret).map_err(|_| InternalError::HkdfError)?;
+    let mut key = chacha20poly1305::Key::default();
+    hkdf_expand_label_into(&h, label, b"", &mut key)?;
+    Ok(key)
+}
+
 fn hkdf_expand_label<H: Hash>(
     secret: &[u8],
     label: &[u8],
@@ -360,8 +467,22 @@ where
     Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
 {
     let mut okm = GenericArray::default();
+    hkdf_expand_label_into(hkdf, label, context, &mut okm)?;
+    Ok(okm)
+}
 
-    let length = i2osp::<U2>(OutputSize::<H>::USIZE)?;
+fn hkdf_expand_label_into<H: Hash>(
+    hkdf: &Hkdf<H>,
+    label: &[u8],
+    context: &[u8],
+    okm: &mut [u8],
+) -> Result<(), ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    let length = i2osp::<U2>(okm.len())?;
     let label_length = i2osp::<U1>(STR_OPAQUE.len() + label.len())?;
     let context_len = i2osp::<U1>(context.len())?;
 
@@ -374,9 +495,9 @@ where
         context,
     ];
 
-    hkdf.expand_multi_info(&hkdf_label, &mut okm)
+    hkdf.expand_multi_info(&hkdf_label, okm)
         .map_err(|_| InternalError::HkdfError)?;
-    Ok(okm)
+    Ok(())
 }
 
 fn derive_secrets<H: Hash>(
@@ -27,7 +27,7 @@ use super::{Message, MessageBuilder, SignatureProtocol};
 use crate::ciphersuite::CipherSuite;
 use crate::errors::ProtocolError;
 use crate::key_exchange::group::Group;
-use crate::key_exchange::group::elliptic_curve::NonIdentity;
+use crate::key_exchange::group::elliptic_curve::{CurveGroup, NonIdentity};
 pub use crate::key_exchange::sigma_i::shared::PreHash;
 use crate::serialization::SliceExt;
 
@@ -39,17 +39,18 @@ pub struct Ecdsa<G, H>(PhantomData<(G, H)>);
 
 impl<G, H> SignatureProtocol for Ecdsa<G, H>
 where
-    G: CurveArithmetic + Group<Sk = SecretKey<G>, Pk = NonIdentity<G>> + PrimeCurve,
-    SignatureSize<G>: ArrayLength<u8>,
+    G: CurveGroup,
+    G::Curve: PrimeCurve,
+    SignatureSize<G::Curve>: ArrayLength<u8>,
     H: Clone
         + Default
         + BlockSizeUser
-        + FixedOutputReset<OutputSize = FieldBytesSize<G>>
+        + FixedOutputReset<OutputSize = FieldBytesSize<G::Curve>>
         + HashMarker,
 {
     type Group = G;
-    type Signature = Signature<G>;
-    type SignatureLen = SignatureSize<G>;
+    type Signature = Signature<G::Curve>;
+    type SignatureLen = SignatureSize<G::Curve>;
     type VerifyState<CS: CipherSuite, KE: Group> = PreHash<H>;
 
     // We use a manual implementation of `RandomizedPrehashSigner` to use the same
@@ -63,7 +64,7 @@ where
         let hash = message.hash::<H>();
 
         (
-            Signature(sign::<_, G, H>(sk, rng, &hash.sign.finalize_fixed())),
+            Signature(sign::<_, G::Curve, H>(sk, rng, &hash.sign.finalize_fixed())),
             PreHash(hash.verify.finalize_fixed()),
         )
     }
@@ -190,3 +191,45 @@ fn ecdsa() {
         .unwrap();
     verify(&pk, &hash.finalize(), &custom_signature).unwrap();
 }
+
+#[cfg(feature = "secp256k1")]
+#[test]
+fn ecdsa_secp256k1() {
+    use std::vec;
+
+    use digest::Digest;
+    use k256::PublicKey;
+    use k256::ecdsa::signature::{DigestVerifier, RandomizedDigestSigner};
+    use k256::ecdsa::{Signature, SigningKey, VerifyingKey};
+    use rand::rngs::OsRng;
+    use sha2::Sha256;
+
+    use crate::Secp256k1;
+    use crate::tests::mock_rng::CycleRng;
+
+    let mut rng = CycleRng::new(vec![1; 32]);
+
+    let mut message = [0; 1024];
+    OsRng.fill_bytes(&mut message);
+    let hash = Sha256::new_with_prefix(message);
+
+    let sk = Secp256k1::random_sk(&mut OsRng);
+    let signing_key = SigningKey::from(sk.clone());
+
+    let signature: Signature = signing_key.sign_digest_with_rng(&mut rng, hash.clone());
+    let custom_signature = sign::<_, _, Sha256>(&sk, &mut rng, &hash.clone().finalize());
+
+    // `k256` always normalizes `s` to the lower half of the scalar field.
+    assert_eq!(
+        signature,
+        custom_signature.normalize_s().unwrap_or(custom_signature)
+    );
+
+    let pk = Secp256k1::public_key(&sk);
+    let verifying_key = VerifyingKey::from(PublicKey::from(pk.0));
+
+    verifying_key
+        .verify_digest(hash.clone(), &signature)
+        .unwrap();
+    verify(&pk, &hash.finalize(), &custom_signature).unwrap();
+}
@@ -18,6 +18,8 @@ mod message;
 pub mod pure_eddsa;
 pub(super) mod shared;
 
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
 use core::iter;
 use core::marker::PhantomData;
 use core::ops::Add;
@@ -36,19 +38,25 @@ use zeroize::Zeroize;
 use self::message::Role;
 pub use self::message::{CachedMessage, HashOutput, Message, MessageBuilder, VerifyMessage};
 use super::{
-    Deserialize, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result, KeyExchange, Serialize,
-    SerializedContext, SerializedCredentialRequest, SerializedCredentialResponse,
-    SerializedIdentifier, SerializedIdentifiers,
+    Deserialize, FinishKeResult, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result,
+    KeyExchange, Serialize, SerializedContext, SerializedCredentialRequest,
+    SerializedCredentialResponse, SerializedIdentifier, SerializedIdentifiers,
 };
 use crate::ciphersuite::{CipherSuite, KeGroup, KeHash};
 use crate::envelope::NonceLen;
 use crate::errors::{InternalError, ProtocolError};
 use crate::hash::{Hash, OutputSize, ProxyHash};
 use crate::key_exchange::group::Group;
+#[cfg(feature = "payload")]
+use crate::key_exchange::shared::seal_payload;
 pub use crate::key_exchange::shared::{DiffieHellman, Ke1Message, Ke1State};
-use crate::key_exchange::shared::{derive_keys, generate_ke1, generate_nonce, transcript};
+use crate::key_exchange::shared::{
+    bind_payload, derive_keys, generate_ke1, generate_nonce, psk_ikm, transcript,
+};
 use crate::keypair::{KeyPair, PrivateKey, PublicKey};
 use crate::opaque::Identifiers;
+#[cfg(feature = "payload")]
+use crate::payload::{STR_CLIENT_PAYLOAD, STR_SERVER_PAYLOAD};
 use crate::serialization::{SliceExt, UpdateExt};
 
 /// The SIGMA-I key exchange implementation
@@ -161,6 +169,9 @@ pub struct Ke2Builder<'a, CS: CipherSuite, KE: Group> {
     server_e_pk: PublicKey<KE>,
     expected_mac: Output<KeHash<CS>>,
     session_key: Output<KeHash<CS>>,
+    exporter_secret: Output<KeHash<CS>>,
+    #[cfg(feature = "payload")]
+    payload: Option<Vec<u8>>,
     #[cfg(test)]
     handshake_secret: Output<KeHash<CS>>,
     #[cfg(test)]
@@ -184,6 +195,7 @@ pub struct Ke2State<CS: CipherSuite, SIG: SignatureProtocol, KE: Group> {
     #[derive_where(skip(Zeroize))]
     client_s_pk: PublicKey<SIG::Group>,
     session_key: Output<KeHash<CS>>,
+    exporter_secret: Output<KeHash<CS>>,
     verify_state: SIG::VerifyState<CS, KE>,
     expected_mac: Output<KeHash<CS>>,
 }
@@ -247,6 +259,9 @@ where
     type KE2Message = Ke2Message<SIG, KE, KEH>;
     type KE3Message = Ke3Message<SIG, KEH>;
 
+    #[cfg(feature = "payload")]
+    const AUTHENTICATES_PAYLOADS: bool = true;
+
     fn generate_ke1<R: RngCore + CryptoRng>(
         rng: &mut R,
     ) -> Result<GenerateKe1Result<Self>, ProtocolError> {
@@ -283,7 +298,7 @@ where
             .ke_diffie_hellman(&ke1_message.client_e_pk);
 
         let derived_keys = derive_keys::<KEH>(
-            iter::once(shared_secret.as_slice()),
+            iter::once(shared_secret.as_slice()).chain(psk_ikm::<KEH>(&context).as_deref()),
             &transcript_hasher.finalize(),
         )?;
 
@@ -292,6 +307,14 @@ where
         server_mac.update_iter(identifiers.server.iter());
         let server_mac = server_mac.finalize().into_bytes();
 
+        #[cfg(feature = "payload")]
+        let (payload, server_mac) = seal_payload::<KEH>(
+            &derived_keys.exporter_secret,
+            STR_SERVER_PAYLOAD,
+            context.payload_to_send(),
+            server_mac,
+        )?;
+
         let mut client_mac =
             Hmac::<KEH>::new_from_slice(&derived_keys.km3).map_err(|_| InternalError::HmacError)?;
         client_mac.update_iter(identifiers.client.iter());
@@ -318,6 +341,9 @@ where
             server_e_pk: server_e.public().clone(),
             expected_mac: client_mac,
             session_key: derived_keys.session_key,
+            exporter_secret: derived_keys.exporter_secret,
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -347,6 +373,7 @@ where
             state: Ke2State {
                 client_s_pk: builder.client_s_pk.clone(),
                 session_key: builder.session_key.clone(),
+                exporter_secret: builder.exporter_secret.clone(),
                 verify_state: input.1,
                 expected_mac: builder.expected_mac.clone(),
             },
@@ -356,6 +383,9 @@ where
                 signature: input.0,
                 mac: builder.transcript.cache.server_mac.clone(),
             },
+            exporter_secret: builder.exporter_secret.clone(),
+            #[cfg(feature = "payload")]
+            payload: builder.payload.clone(),
             #[cfg(test)]
             handshake_secret: builder.handshake_secret.clone(),
             #[cfg(test)]
@@ -393,14 +423,17 @@ where
             .ke_diffie_hellman(&ke2_message.server_e_pk);
 
         let derived_keys = derive_keys::<KEH>(
-            iter::once(shared_secret.as_slice()),
+            iter::once(shared_secret.as_slice()).chain(psk_ikm::<KEH>(&context).as_deref()),
             &transcript_hasher.finalize(),
         )?;
 
         let mut server_mac =
             Hmac::<KEH>::new_from_slice(&derived_keys.km2).map_err(|_| InternalError::HmacError)?;
         server_mac.update_iter(identifiers.server.iter());
-        let server_mac = server_mac.finalize().into_bytes();
+        let server_mac = bind_payload::<KEH>(
+            server_mac.finalize().into_bytes(),
+            context.received_payload(),
+        )?;
 
         bool::from(server_mac.ct_eq(&ke2_message.mac))
             .then_some(())
@@ -411,6 +444,14 @@ where
         client_mac.update_iter(identifiers.client.iter());
         let client_mac = client_mac.finalize().into_bytes();
 
+        #[cfg(feature = "payload")]
+        let (payload, client_mac) = seal_payload::<KEH>(
+            &derived_keys.exporter_secret,
+            STR_CLIENT_PAYLOAD,
+            context.payload_to_send(),
+            client_mac,
+        )?;
+
         let message = Message {
             role: Role::Client,
             context: context.clone(),
@@ -439,10 +480,13 @@ where
 
         Ok(GenerateKe3Result {
             session_key: derived_keys.session_key,
+            exporter_secret: derived_keys.exporter_secret,
             message: Ke3Message {
                 signature,
                 mac: client_mac,
             },
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -455,7 +499,9 @@ where
         ke3_message: Self::KE3Message,
         identifiers: Identifiers<'_>,
         context: SerializedContext<'_>,
-    ) -> Result<Output<KEH>, ProtocolError> {
+    ) -> Result<FinishKeResult<Self>, ProtocolError> {
+        let received_payload = context.received_payload();
+
         ke2_state.client_s_pk.verify::<CS, SIG, KE>(
             MessageBuilder {
                 role: Role::Server,
@@ -469,9 +515,14 @@ where
             &ke3_message.signature,
         )?;
 
+        let expected_mac = bind_payload::<KEH>(ke2_state.expected_mac.clone(), received_payload)?;
+
         CtOption::new(
-            ke2_state.session_key.clone(),
-            ke2_state.expected_mac.ct_eq(&ke3_message.mac),
+            FinishKeResult {
+                session_key: ke2_state.session_key.clone(),
+                exporter_secret: ke2_state.exporter_secret.clone(),
+            },
+            expected_mac.ct_eq(&ke3_message.mac),
         )
         .into_option()
         .ok_or(ProtocolError::InvalidLoginError)
@@ -486,6 +537,7 @@ where
         Ok(Self {
             client_s_pk: PublicKey::deserialize_take(input)?,
             session_key: input.take_array("session key")?,
+            exporter_secret: input.take_array("exporter secret")?,
             verify_state: SIG::VerifyState::deserialize_take(input)?,
             expected_mac: input.take_array("expected mac")?,
         })
@@ -493,20 +545,24 @@ where
 }
 
 type Ke2StateLen<CS, SIG: SignatureProtocol, KE> = Sum<
-    Sum<Sum<<SIG::Group as Group>::PkLen, OutputSize<KeHash<CS>>>, VerifyStateLen<CS, SIG, KE>>,
+    Sum<Sum<Ke2StateKeysLen<CS, SIG>, OutputSize<KeHash<CS>>>, VerifyStateLen<CS, SIG, KE>>,
     OutputSize<KeHash<CS>>,
 >;
 
+type Ke2StateKeysLen<CS, SIG: SignatureProtocol> =
+    Sum<<SIG::Group as Group>::PkLen, OutputSize<KeHash<CS>>>;
+
 type VerifyStateLen<CS, SIG: SignatureProtocol, KE> = <SIG::VerifyState<CS, KE> as Serialize>::Len;
 
 impl<CS: CipherSuite, SIG: SignatureProtocol, KE: Group> Serialize for Ke2State<CS, SIG, KE>
 where
     SIG::VerifyState<CS, KE>: Serialize,
-    // Ke2State: ((SigPk + Hash) + VerifyState) + Hash
+    // Ke2State: (((SigPk + Hash) + Hash) + VerifyState) + Hash
     <SIG::Group as Group>::PkLen: Add<OutputSize<KeHash<CS>>>,
-    Sum<<SIG::Group as Group>::PkLen, OutputSize<KeHash<CS>>>:
+    Ke2StateKeysLen<CS, SIG>: ArrayLength<u8> + Add<OutputSize<KeHash<CS>>>,
+    Sum<Ke2StateKeysLen<CS, SIG>, OutputSize<KeHash<CS>>>:
         ArrayLength<u8> + Add<VerifyStateLen<CS, SIG, KE>>,
-    Sum<Sum<<SIG::Group as Group>::PkLen, OutputSize<KeHash<CS>>>, VerifyStateLen<CS, SIG, KE>>:
+    Sum<Sum<Ke2StateKeysLen<CS, SIG>, OutputSize<KeHash<CS>>>, VerifyStateLen<CS, SIG, KE>>:
         ArrayLength<u8> + Add<OutputSize<KeHash<CS>>>,
     Ke2StateLen<CS, SIG, KE>: ArrayLength<u8>,
 {
@@ -516,6 +572,7 @@ where
         self.client_s_pk
             .serialize()
             .concat(self.session_key.clone())
+            .concat(self.exporter_secret.clone())
             .concat(self.verify_state.serialize())
             .concat(self.expected_mac.clone())
     }
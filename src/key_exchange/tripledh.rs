@@ -8,6 +8,8 @@
 
 //! An implementation of the Triple Diffie-Hellman key exchange protocol
 
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use core::ops::Add;
 
@@ -22,18 +24,21 @@ use subtle::{ConstantTimeEq, CtOption};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::{
-    Deserialize, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result, KeyExchange, Serialize,
-    SerializedContext, SerializedCredentialRequest, SerializedCredentialResponse,
-    SerializedIdentifiers,
+    Deserialize, FinishKeResult, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result,
+    KeyExchange, Serialize, SerializedContext, SerializedCredentialRequest,
+    SerializedCredentialResponse, SerializedIdentifiers,
 };
 use crate::ciphersuite::{CipherSuite, KeGroup};
 use crate::errors::ProtocolError;
 use crate::hash::{Hash, OutputSize, ProxyHash};
+use crate::key_exchange::ephemeral::Ephemeral;
 use crate::key_exchange::group::Group;
 use crate::key_exchange::shared::{self, NonceLen};
 pub use crate::key_exchange::shared::{DiffieHellman, Ke1Message, Ke1State};
 use crate::keypair::{PrivateKey, PublicKey};
 use crate::opaque::Identifiers;
+#[cfg(feature = "payload")]
+use crate::payload::{STR_CLIENT_PAYLOAD, STR_SERVER_PAYLOAD};
 use crate::serialization::SliceExt;
 
 ////////////////////////////
@@ -62,10 +67,16 @@ pub struct TripleDh<G, H>(PhantomData<(G, H)>);
 #[derive_where(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, ZeroizeOnDrop)]
 pub struct Ke2State<H: OutputSizeUser> {
     pub(super) session_key: Output<H>,
+    pub(super) exporter_secret: Output<H>,
     pub(super) expected_mac: Output<H>,
 }
 
 /// Builder for the second key exchange message
+///
+/// A [`PreSharedKey`](crate::PreSharedKey) is not serialized, building a
+/// deserialized builder that was given one fails with
+/// [`ProtocolError::MissingPskError`]. The plaintext of the payload to send is
+/// serialized.
 #[cfg_attr(
     feature = "serde",
     derive(serde::Deserialize, serde::Serialize),
@@ -88,6 +99,15 @@ where
     server_e_pk: PublicKey<G>,
     shared_secret_1: GenericArray<u8, G::PkLen>,
     shared_secret_3: GenericArray<u8, G::PkLen>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derive_where(skip(Debug))]
+    psk: Option<Output<H>>,
+    // Whether a `PreSharedKey` was given, which survives serialization unlike
+    // the key itself.
+    psk_given: bool,
+    #[cfg(feature = "payload")]
+    #[derive_where(skip(Debug))]
+    payload: Option<Vec<u8>>,
 }
 
 /// The second key exchange message
@@ -153,6 +173,12 @@ where
     type KE2Message = Ke2Message<G, H>;
     type KE3Message = Ke3Message<H>;
 
+    #[cfg(feature = "batch")]
+    const DETERMINISTIC_WITH_EPHEMERAL: bool = true;
+
+    #[cfg(feature = "payload")]
+    const AUTHENTICATES_PAYLOADS: bool = true;
+
     fn generate_ke1<R: RngCore + CryptoRng>(
         rng: &mut R,
     ) -> Result<GenerateKe1Result<Self>, ProtocolError> {
@@ -165,9 +191,40 @@ where
         ke1_message: Self::KE1Message,
         credential_response: SerializedCredentialResponse<CS>,
         client_s_pk: PublicKey<G>,
-        identifiers: SerializedIdentifiers<'_, KeGroup<CS>>,
+        identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
+        context: SerializedContext<'a>,
+    ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError> {
+        let ephemeral = Ephemeral::random(rng);
+
+        Self::ke2_builder_with_ephemeral(
+            rng,
+            ephemeral,
+            credential_request,
+            ke1_message,
+            credential_response,
+            client_s_pk,
+            identifiers,
+            context,
+        )
+    }
+
+    fn ke2_builder_with_ephemeral<
+        'a,
+        CS: CipherSuite<KeyExchange = Self>,
+        R: RngCore + CryptoRng,
+    >(
+        _: &mut R,
+        ephemeral: Ephemeral<G>,
+        credential_request: SerializedCredentialRequest<CS>,
+        ke1_message: Self::KE1Message,
+        credential_response: SerializedCredentialResponse<CS>,
+        client_s_pk: PublicKey<G>,
+        identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
         context: SerializedContext<'a>,
     ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError> {
+        #[cfg(feature = "payload")]
+        let payload = context.payload_to_send().map(<[u8]>::to_vec);
+
         let shared::Ke2BuilderCommon {
             server_nonce,
             transcript_hasher,
@@ -175,8 +232,9 @@ where
             server_e_pk,
             shared_secret_1,
             shared_secret_3,
-        } = shared::ke2_builder_common::<G, H, CS, R>(
-            rng,
+            psk,
+        } = shared::ke2_builder_common::<G, H, CS>(
+            ephemeral,
             credential_request,
             ke1_message,
             credential_response,
@@ -192,6 +250,10 @@ where
             server_e_pk,
             shared_secret_1,
             shared_secret_3,
+            psk_given: psk.is_some(),
+            psk,
+            #[cfg(feature = "payload")]
+            payload,
         })
     }
 
@@ -213,6 +275,10 @@ where
         mut builder: Self::KE2Builder<'_, CS>,
         shared_secret_2: Self::KE2BuilderInput<CS>,
     ) -> Result<GenerateKe2Result<CS>, ProtocolError> {
+        if builder.psk_given && builder.psk.is_none() {
+            return Err(ProtocolError::MissingPskError);
+        }
+
         let transcript_digest = builder.transcript_hasher.clone().finalize();
         let derived_keys = shared::derive_keys::<H>(
             [
@@ -220,7 +286,8 @@ where
                 &shared_secret_2,
                 &builder.shared_secret_3,
             ]
-            .into_iter(),
+            .into_iter()
+            .chain(builder.psk.as_deref()),
             &transcript_digest,
         )?;
 
@@ -230,9 +297,18 @@ where
             &transcript_digest,
         )?;
 
+        #[cfg(feature = "payload")]
+        let (payload, mac) = shared::seal_payload::<H>(
+            &derived_keys.exporter_secret,
+            STR_SERVER_PAYLOAD,
+            builder.payload.as_deref(),
+            mac,
+        )?;
+
         Ok(GenerateKe2Result {
             state: Ke2State {
                 session_key: derived_keys.session_key,
+                exporter_secret: derived_keys.exporter_secret.clone(),
                 expected_mac,
             },
             message: Ke2Message {
@@ -240,6 +316,9 @@ where
                 server_e_pk: builder.server_e_pk.clone(),
                 mac,
             },
+            exporter_secret: derived_keys.exporter_secret,
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -282,13 +361,26 @@ where
                 shared_secret_2.as_slice(),
                 shared_secret_3.as_slice(),
             ]
-            .into_iter(),
+            .into_iter()
+            .chain(shared::psk_ikm::<H>(&context).as_deref()),
             &ke2_message.mac,
+            context.received_payload(),
+        )?;
+
+        #[cfg(feature = "payload")]
+        let (payload, client_mac) = shared::seal_payload::<H>(
+            &derived_keys.exporter_secret,
+            STR_CLIENT_PAYLOAD,
+            context.payload_to_send(),
+            client_mac,
         )?;
 
         Ok(GenerateKe3Result {
             session_key: derived_keys.session_key,
+            exporter_secret: derived_keys.exporter_secret,
             message: Ke3Message { mac: client_mac },
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -300,11 +392,17 @@ where
         ke2_state: &Self::KE2State<CS>,
         ke3_message: Self::KE3Message,
         _: Identifiers<'_>,
-        _: SerializedContext<'_>,
-    ) -> Result<Output<H>, ProtocolError> {
+        context: SerializedContext<'_>,
+    ) -> Result<FinishKeResult<Self>, ProtocolError> {
+        let expected_mac =
+            shared::bind_payload::<H>(ke2_state.expected_mac.clone(), context.received_payload())?;
+
         CtOption::new(
-            ke2_state.session_key.clone(),
-            ke2_state.expected_mac.ct_eq(&ke3_message.mac),
+            FinishKeResult {
+                session_key: ke2_state.session_key.clone(),
+                exporter_secret: ke2_state.exporter_secret.clone(),
+            },
+            expected_mac.ct_eq(&ke3_message.mac),
         )
         .into_option()
         .ok_or(ProtocolError::InvalidLoginError)
@@ -325,6 +423,7 @@ where
     fn deserialize_take(input: &mut &[u8]) -> Result<Self, ProtocolError> {
         Ok(Self {
             session_key: input.take_array("session key")?,
+            exporter_secret: input.take_array("exporter secret")?,
             expected_mac: input.take_array("expected mac")?,
         })
     }
@@ -335,14 +434,18 @@ where
     H::Core: ProxyHash,
     <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
     Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
-    // Ke2State: Hash + Hash
+    // Ke2State: (Hash + Hash) + Hash
     OutputSize<H>: Add<OutputSize<H>>,
-    Sum<OutputSize<H>, OutputSize<H>>: ArrayLength<u8>,
+    Sum<OutputSize<H>, OutputSize<H>>: ArrayLength<u8> + Add<OutputSize<H>>,
+    Sum<Sum<OutputSize<H>, OutputSize<H>>, OutputSize<H>>: ArrayLength<u8>,
 {
-    type Len = Sum<OutputSize<H>, OutputSize<H>>;
+    type Len = Sum<Sum<OutputSize<H>, OutputSize<H>>, OutputSize<H>>;
 
     fn serialize(&self) -> GenericArray<u8, Self::Len> {
-        self.session_key.clone().concat(self.expected_mac.clone())
+        self.session_key
+            .clone()
+            .concat(self.exporter_secret.clone())
+            .concat(self.expected_mac.clone())
     }
 }
 
@@ -362,12 +465,19 @@ where
             server_e_pk: _,
             shared_secret_1,
             shared_secret_3,
+            psk,
+            psk_given: _,
+            #[cfg(feature = "payload")]
+            payload,
         } = self;
 
         server_nonce.zeroize();
         transcript_hasher.reset();
         shared_secret_1.zeroize();
         shared_secret_3.zeroize();
+        psk.zeroize();
+        #[cfg(feature = "payload")]
+        payload.zeroize();
     }
 }
 
@@ -18,6 +18,8 @@
 //! the generic `ml-kem` abstractions into the existing OPAQUE key-exchange
 //! pipeline.
 
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
 use core::fmt::Debug;
 use core::marker::PhantomData;
 use core::ops::Add;
@@ -40,16 +42,19 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::shared::{self, Ke1Message, Ke1State, NonceLen};
 use super::{
-    Deserialize, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result, KeyExchange, Serialize,
-    SerializedContext, SerializedCredentialRequest, SerializedCredentialResponse,
-    SerializedIdentifiers,
+    Deserialize, FinishKeResult, GenerateKe1Result, GenerateKe2Result, GenerateKe3Result,
+    KeyExchange, Serialize, SerializedContext, SerializedCredentialRequest,
+    SerializedCredentialResponse, SerializedIdentifiers,
 };
 use crate::ciphersuite::{CipherSuite, KeGroup};
 use crate::errors::ProtocolError;
 use crate::hash::{Hash, OutputSize, ProxyHash};
+use crate::key_exchange::ephemeral::Ephemeral;
 use crate::key_exchange::group::Group;
 use crate::keypair::{PrivateKey, PublicKey};
 use crate::opaque::Identifiers;
+#[cfg(feature = "payload")]
+use crate::payload::{STR_CLIENT_PAYLOAD, STR_SERVER_PAYLOAD};
 use crate::serialization::SliceExt;
 
 /// Adapter trait that augments the `ml-kem` core traits with the metadata
@@ -307,6 +312,9 @@ where
     kem_encapsulation_key: GenericArray<u8, K::EncapsulationKeyLen>,
     kem_ciphertext: GenericArray<u8, K::CiphertextLen>,
     kem_shared_secret: GenericArray<u8, K::SharedSecretLen>,
+    psk: Option<Output<H>>,
+    #[cfg(feature = "payload")]
+    payload: Option<Vec<u8>>,
 }
 
 /// Server message bundles the `TripleDH` payload with the KEM encapsulation.
@@ -349,6 +357,9 @@ where
         self.shared_secret_3.zeroize();
         self.kem_shared_secret.zeroize();
         self.kem_ciphertext.zeroize();
+        self.psk.zeroize();
+        #[cfg(feature = "payload")]
+        self.payload.zeroize();
     }
 }
 
@@ -390,6 +401,9 @@ where
     type KE2Message = KemKe2Message<G, H, K>;
     type KE3Message = KemKe3Message<H>;
 
+    #[cfg(feature = "payload")]
+    const AUTHENTICATES_PAYLOADS: bool = true;
+
     fn generate_ke1<R: RngCore + CryptoRng>(
         rng: &mut R,
     ) -> Result<GenerateKe1Result<Self>, ProtocolError> {
@@ -415,9 +429,40 @@ where
         ke1_message: Self::KE1Message,
         credential_response: SerializedCredentialResponse<CS>,
         client_s_pk: PublicKey<G>,
-        identifiers: SerializedIdentifiers<'_, KeGroup<CS>>,
+        identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
         context: SerializedContext<'a>,
     ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError> {
+        let ephemeral = Ephemeral::random(rng);
+
+        Self::ke2_builder_with_ephemeral(
+            rng,
+            ephemeral,
+            credential_request,
+            ke1_message,
+            credential_response,
+            client_s_pk,
+            identifiers,
+            context,
+        )
+    }
+
+    fn ke2_builder_with_ephemeral<
+        'a,
+        CS: CipherSuite<KeyExchange = Self>,
+        R: RngCore + CryptoRng,
+    >(
+        rng: &mut R,
+        ephemeral: Ephemeral<G>,
+        credential_request: SerializedCredentialRequest<CS>,
+        ke1_message: Self::KE1Message,
+        credential_response: SerializedCredentialResponse<CS>,
+        client_s_pk: PublicKey<G>,
+        identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
+        context: SerializedContext<'a>,
+    ) -> Result<Self::KE2Builder<'a, CS>, ProtocolError> {
+        #[cfg(feature = "payload")]
+        let payload = context.payload_to_send().map(<[u8]>::to_vec);
+
         let shared::Ke2BuilderCommon {
             server_nonce,
             transcript_hasher,
@@ -425,8 +470,9 @@ where
             server_e_pk,
             shared_secret_1,
             shared_secret_3,
-        } = shared::ke2_builder_common::<G, H, CS, R>(
-            rng,
+            psk,
+        } = shared::ke2_builder_common::<G, H, CS>(
+            ephemeral,
             credential_request,
             ke1_message.dh_message.clone(),
             credential_response,
@@ -453,6 +499,9 @@ where
             kem_encapsulation_key: ke1_message.kem_encapsulation_key.clone(),
             kem_ciphertext,
             kem_shared_secret,
+            psk,
+            #[cfg(feature = "payload")]
+            payload,
         })
     }
 
@@ -482,7 +531,8 @@ where
                 builder.shared_secret_3.as_slice(),
                 builder.kem_shared_secret.as_slice(),
             ]
-            .into_iter(),
+            .into_iter()
+            .chain(builder.psk.as_deref()),
             &transcript_digest,
         )?;
 
@@ -492,10 +542,19 @@ where
             &transcript_digest,
         )?;
 
+        #[cfg(feature = "payload")]
+        let (payload, mac) = shared::seal_payload::<H>(
+            &derived_keys.exporter_secret,
+            STR_SERVER_PAYLOAD,
+            builder.payload.as_deref(),
+            mac,
+        )?;
+
         Ok(GenerateKe2Result {
             state: KemKe2State {
                 base_state: super::tripledh::Ke2State {
                     session_key: derived_keys.session_key.clone(),
+                    exporter_secret: derived_keys.exporter_secret.clone(),
                     expected_mac,
                 },
                 kem_encapsulation_key: builder.kem_encapsulation_key.clone(),
@@ -509,6 +568,9 @@ where
                 },
                 kem_ciphertext: builder.kem_ciphertext.clone(),
             },
+            exporter_secret: derived_keys.exporter_secret,
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -562,13 +624,26 @@ where
                 shared_secret_3.as_slice(),
                 kem_shared_secret.as_slice(),
             ]
-            .into_iter(),
+            .into_iter()
+            .chain(shared::psk_ikm::<H>(&context).as_deref()),
             &ke2_message.dh_message.mac,
+            context.received_payload(),
+        )?;
+
+        #[cfg(feature = "payload")]
+        let (payload, client_mac) = shared::seal_payload::<H>(
+            &derived_keys.exporter_secret,
+            STR_CLIENT_PAYLOAD,
+            context.payload_to_send(),
+            client_mac,
         )?;
 
         Ok(GenerateKe3Result {
             session_key: derived_keys.session_key,
+            exporter_secret: derived_keys.exporter_secret,
             message: super::tripledh::Ke3Message { mac: client_mac },
+            #[cfg(feature = "payload")]
+            payload,
             #[cfg(test)]
             handshake_secret: derived_keys.handshake_secret,
             #[cfg(test)]
@@ -580,11 +655,19 @@ where
         ke2_state: &Self::KE2State<CS>,
         ke3_message: Self::KE3Message,
         _identifiers: Identifiers<'_>,
-        _context: SerializedContext<'_>,
-    ) -> Result<Output<Self::Hash>, ProtocolError> {
+        context: SerializedContext<'_>,
+    ) -> Result<FinishKeResult<Self>, ProtocolError> {
+        let expected_mac = shared::bind_payload::<H>(
+            ke2_state.base_state.expected_mac.clone(),
+            context.received_payload(),
+        )?;
+
         CtOption::new(
-            ke2_state.base_state.session_key.clone(),
-            ke2_state.base_state.expected_mac.ct_eq(&ke3_message.mac),
+            FinishKeResult {
+                session_key: ke2_state.base_state.session_key.clone(),
+                exporter_secret: ke2_state.base_state.exporter_secret.clone(),
+            },
+            expected_mac.ct_eq(&ke3_message.mac),
         )
         .into_option()
         .ok_or(ProtocolError::InvalidLoginError)
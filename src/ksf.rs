@@ -8,9 +8,46 @@
 
 //! Trait specifying a key stretching function
 
+#[cfg(all(feature = "argon2", feature = "ksf-params"))]
+use alloc::format;
+#[cfg(any(
+    feature = "argon2",
+    feature = "ksf-params",
+    feature = "pbkdf2",
+    feature = "scrypt"
+))]
+use alloc::vec::Vec;
+#[cfg(feature = "pbkdf2")]
+use core::marker::PhantomData;
+#[cfg(all(feature = "ksf-params", feature = "std"))]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "pbkdf2")]
+use derive_where::derive_where;
+#[cfg(feature = "pbkdf2")]
+use digest::core_api::BlockSizeUser;
+#[cfg(all(feature = "ksf-params", feature = "std"))]
+use generic_array::typenum::U64;
+#[cfg(feature = "pbkdf2")]
+use generic_array::typenum::{IsLess, Le, NonZero, U256};
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "pbkdf2")]
+use hmac::Hmac;
+#[cfg(feature = "ksf-params")]
+use zeroize::Zeroize;
 
+use crate::Identifiers;
 use crate::errors::InternalError;
+#[cfg(feature = "ksf-params")]
+use crate::errors::ProtocolError;
+#[cfg(feature = "pbkdf2")]
+use crate::hash::{Hash, ProxyHash};
+
+/// The fixed all-zero salt recommended for the KSF by
+/// [RFC 9807](https://www.rfc-editor.org/rfc/rfc9807.html#section-4.3.2). The
+/// OPRF output is already unique per user, so no random salt is needed.
+#[cfg(any(feature = "argon2", feature = "pbkdf2", feature = "scrypt"))]
+const SALT: [u8; 16] = [0; 16];
 
 /// Used for the key stretching function in OPAQUE
 pub trait Ksf: Default {
@@ -19,6 +56,201 @@ pub trait Ksf: Default {
         &self,
         input: GenericArray<u8, L>,
     ) -> Result<GenericArray<u8, L>, InternalError>;
+
+    /// Computes the key stretching function for a registration or login with
+    /// the given [`Identifiers`]. This is what the protocol calls, it ignores
+    /// the identifiers and calls [`Self::hash()`] by default. [`WithContext`]
+    /// adds the identifiers to the salt of the KSFs of this crate.
+    fn hash_with_identifiers<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+        identifiers: Identifiers<'_>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        let _ = identifiers;
+        self.hash(input)
+    }
+
+    /// Returns the identifier and the parameters of this KSF, which are stored
+    /// with a registration and sent to the client during login. Returns `None`
+    /// by default, in which case the client uses the KSF it is configured
+    /// with.
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        None
+    }
+
+    /// Creates the KSF from the parameters returned by [`Self::to_params()`],
+    /// as long as none of its costs exceed those of `limits`.
+    ///
+    /// The client calls this with the parameters received in a
+    /// [`CredentialResponse`](crate::CredentialResponse) before the server is
+    /// authenticated, see
+    /// [`ClientLoginFinishParameters::with_ksf_limits()`](crate::ClientLoginFinishParameters::with_ksf_limits()).
+    ///
+    /// # Errors
+    /// [`ProtocolError::KsfLimitError`] if the parameters are more expensive
+    /// than `limits`.
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        let _ = (params, limits);
+        Err(InternalError::KsfError.into())
+    }
+}
+
+/// Adds the [`Identifiers`] of the registration or login to the salt of the
+/// KSF `K`, see [`Ksf::hash_with_identifiers()`]. Implemented for `Argon2`,
+/// `scrypt::Params` and [`Pbkdf2`], custom KSFs can override
+/// [`Ksf::hash_with_identifiers()`] instead.
+///
+/// Only the client computes the KSF, the identifiers have to be the same at
+/// registration and every login. As the default client identity is the
+/// client's public key, which is only known after the KSF, a user-specific
+/// salt requires setting [`Identifiers::client`], e.g. to the credential
+/// identifier. The KSF fails without it.
+#[derive(Clone, Debug, Default)]
+pub struct WithContext<K>(pub K);
+
+/// The salt used by [`WithContext`]: the fixed [`SALT`] followed by the
+/// client and the server identity, each prefixed with its two byte big-endian
+/// length. An absent server identity is encoded as empty, an absent client
+/// identity is an error.
+#[cfg(any(feature = "argon2", feature = "pbkdf2", feature = "scrypt"))]
+fn salt_with_identifiers(identifiers: Identifiers<'_>) -> Result<Vec<u8>, InternalError> {
+    let client = identifiers.client.ok_or(InternalError::KsfError)?;
+    let server = identifiers.server.unwrap_or_default();
+    let mut salt = Vec::with_capacity(SALT.len() + 4 + client.len() + server.len());
+    salt.extend_from_slice(&SALT);
+
+    for identity in [client, server] {
+        let len = u16::try_from(identity.len()).map_err(|_| InternalError::KsfError)?;
+        salt.extend_from_slice(&len.to_be_bytes());
+        salt.extend_from_slice(identity);
+    }
+
+    Ok(salt)
+}
+
+/// The identifier and the parameters of a [`Ksf`], see [`Ksf::to_params()`]
+#[cfg(feature = "ksf-params")]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Zeroize)]
+pub struct KsfParams {
+    id: u16,
+    params: Vec<u8>,
+}
+
+#[cfg(feature = "ksf-params")]
+impl KsfParams {
+    /// Identifier of Argon2id, version 0x13
+    pub const ARGON2ID: u16 = 1;
+    /// Identifier of scrypt
+    pub const SCRYPT: u16 = 2;
+    /// Identifier of PBKDF2-HMAC, the hash is given by the [`Ksf`]
+    pub const PBKDF2: u16 = 3;
+
+    /// Create new [`KsfParams`] with the identifier `id`, which must not be
+    /// `0`, and the encoded `params`, which must not exceed `u16::MAX - 2`
+    /// bytes
+    pub fn new(id: u16, params: Vec<u8>) -> Result<Self, ProtocolError> {
+        if id == 0 || params.len() > usize::from(u16::MAX - 2) {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        Ok(Self { id, params })
+    }
+
+    /// Returns the identifier of the key stretching function
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    /// Returns the encoded parameters
+    pub fn params(&self) -> &[u8] {
+        &self.params
+    }
+
+    // Encodes the parameters as the two byte big-endian identifier followed by
+    // the parameters, which is the value of their extension
+    pub(crate) fn serialize(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(2 + self.params.len());
+        output.extend_from_slice(&self.id.to_be_bytes());
+        output.extend_from_slice(&self.params);
+
+        output
+    }
+
+    // Decodes parameters encoded by `Self::serialize()`
+    pub(crate) fn deserialize(input: &[u8]) -> Result<Self, ProtocolError> {
+        let [id_high, id_low, params @ ..] = input else {
+            return Err(ProtocolError::SerializationError);
+        };
+
+        Self::new(u16::from_be_bytes([*id_high, *id_low]), params.to_vec())
+    }
+
+    // Returns the parameters as an array of `N` bytes if the identifier is `id`
+    #[cfg(any(feature = "argon2", feature = "pbkdf2", feature = "scrypt"))]
+    fn to_array<const N: usize>(&self, id: u16) -> Result<[u8; N], InternalError> {
+        if self.id != id {
+            return Err(InternalError::KsfError);
+        }
+
+        self.params
+            .as_slice()
+            .try_into()
+            .map_err(|_| InternalError::KsfError)
+    }
+}
+
+/// A [`Ksf`] whose cost can be fitted to the current machine with
+/// [`calibrate()`]
+#[cfg(all(feature = "ksf-params", feature = "std"))]
+pub trait Calibrate: Ksf {
+    /// The cheapest parameters [`calibrate()`] returns
+    fn minimum() -> Result<Self, InternalError>;
+
+    /// Returns `self` with its cost raised by about `target / elapsed`, where
+    /// `elapsed` is the time `self` takes to compute, without exceeding the
+    /// costs of `limits`. The cost never goes below that of `self`.
+    fn scale(
+        &self,
+        elapsed: Duration,
+        target: Duration,
+        limits: &Self,
+    ) -> Result<Self, InternalError>;
+}
+
+/// Benchmarks the KSF `K` on the current machine and returns parameters that
+/// take about `target` to compute without exceeding the costs of `limits`.
+///
+/// The parameters never go below [`Calibrate::minimum()`], so the result can
+/// take longer than `target` on slow machines. `limits` can also be passed to
+/// [`ClientLoginFinishParameters::with_ksf_limits()`](crate::ClientLoginFinishParameters::with_ksf_limits()),
+/// the result to [`Ksf::from_params()`].
+///
+/// # Errors
+/// [`ProtocolError::KsfLimitError`] if [`Calibrate::minimum()`] exceeds
+/// `limits`.
+#[cfg(all(feature = "ksf-params", feature = "std"))]
+pub fn calibrate<K: Calibrate>(target: Duration, limits: &K) -> Result<KsfParams, ProtocolError> {
+    let minimum = K::minimum()?.to_params().ok_or(InternalError::KsfError)?;
+    let minimum = K::from_params(&minimum, limits)?;
+
+    let start = Instant::now();
+    minimum.hash(GenericArray::<u8, U64>::default())?;
+    let elapsed = start.elapsed();
+
+    Ok(minimum
+        .scale(elapsed, target, limits)?
+        .to_params()
+        .ok_or(InternalError::KsfError)?)
+}
+
+// Returns `cost` multiplied by `numerator / denominator`, saturating at
+// `u32::MAX`
+#[cfg(all(feature = "ksf-params", feature = "std"))]
+fn scale_cost(cost: u32, numerator: u128, denominator: u128) -> u32 {
+    u32::try_from(u128::from(cost) * numerator / denominator.max(1)).unwrap_or(u32::MAX)
 }
 
 /// A no-op hash which simply returns its input
@@ -41,8 +273,472 @@ impl Ksf for argon2::Argon2<'_> {
         input: GenericArray<u8, L>,
     ) -> Result<GenericArray<u8, L>, InternalError> {
         let mut output = GenericArray::default();
-        self.hash_password_into(&input, &[0; argon2::RECOMMENDED_SALT_LEN], &mut output)
+        self.hash_password_into(&input, &SALT, &mut output)
+            .map_err(|_| InternalError::KsfError)?;
+        Ok(output)
+    }
+
+    /// Encodes the memory cost, the number of iterations and the degree of
+    /// parallelism as four byte big-endian integers. The identifier
+    /// [`KsfParams::ARGON2ID`] fixes the algorithm and version, so `None` is
+    /// returned for anything but Argon2id version 0x13 without a key identifier
+    /// or associated data.
+    ///
+    /// A secret is never encoded and can't be detected through the API of
+    /// `argon2`, instances created with
+    /// [`Argon2::new_with_secret()`](argon2::Argon2::new_with_secret) must not
+    /// be used with [`KsfParams`].
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        let params = self.params();
+
+        // `argon2` only exposes the algorithm and version through `Debug`, which
+        // also covers the key identifier and associated data of the parameters.
+        let argon2id = Self::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            argon2::Params::new(
+                params.m_cost(),
+                params.t_cost(),
+                params.p_cost(),
+                params.output_len(),
+            )
+            .ok()?,
+        );
+
+        if format!("{self:?}") != format!("{argon2id:?}") {
+            return None;
+        }
+
+        let mut output = Vec::with_capacity(12);
+        output.extend_from_slice(&params.m_cost().to_be_bytes());
+        output.extend_from_slice(&params.t_cost().to_be_bytes());
+        output.extend_from_slice(&params.p_cost().to_be_bytes());
+
+        KsfParams::new(KsfParams::ARGON2ID, output).ok()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        let params = params.to_array::<12>(KsfParams::ARGON2ID)?;
+        let [m0, m1, m2, m3, t0, t1, t2, t3, p0, p1, p2, p3] = params;
+        let (m_cost, t_cost, p_cost) = (
+            u32::from_be_bytes([m0, m1, m2, m3]),
+            u32::from_be_bytes([t0, t1, t2, t3]),
+            u32::from_be_bytes([p0, p1, p2, p3]),
+        );
+        let limits = limits.params();
+
+        if m_cost > limits.m_cost() || t_cost > limits.t_cost() || p_cost > limits.p_cost() {
+            return Err(ProtocolError::KsfLimitError);
+        }
+
+        let params = argon2::Params::new(m_cost, t_cost, p_cost, None)
+            .map_err(|_| InternalError::KsfError)?;
+
+        Ok(Self::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params,
+        ))
+    }
+}
+
+#[cfg(feature = "argon2")]
+impl<'k> Ksf for WithContext<argon2::Argon2<'k>> {
+    fn hash<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        self.0.hash(input)
+    }
+
+    fn hash_with_identifiers<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+        identifiers: Identifiers<'_>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        let mut output = GenericArray::default();
+        self.0
+            .hash_password_into(&input, &salt_with_identifiers(identifiers)?, &mut output)
+            .map_err(|_| InternalError::KsfError)?;
+        Ok(output)
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        self.0.to_params()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        argon2::Argon2::from_params(params, &limits.0).map(Self)
+    }
+}
+
+/// The memory cost in KiB of [`Calibrate::minimum()`] for Argon2id, the second
+/// recommended option of
+/// [RFC 9106](https://www.rfc-editor.org/rfc/rfc9106.html#section-4)
+#[cfg(all(feature = "argon2", feature = "ksf-params", feature = "std"))]
+pub const ARGON2_MIN_M_COST: u32 = 64 * 1024;
+
+/// The number of iterations of [`Calibrate::minimum()`] for Argon2id, the
+/// second recommended option of
+/// [RFC 9106](https://www.rfc-editor.org/rfc/rfc9106.html#section-4)
+#[cfg(all(feature = "argon2", feature = "ksf-params", feature = "std"))]
+pub const ARGON2_MIN_T_COST: u32 = 3;
+
+/// The degree of parallelism of [`Calibrate::minimum()`] for Argon2id, the
+/// second recommended option of
+/// [RFC 9106](https://www.rfc-editor.org/rfc/rfc9106.html#section-4)
+#[cfg(all(feature = "argon2", feature = "ksf-params", feature = "std"))]
+pub const ARGON2_MIN_P_COST: u32 = 4;
+
+/// The memory cost is preferred over the number of iterations, as recommended
+/// by [RFC 9106](https://www.rfc-editor.org/rfc/rfc9106.html#section-4). The
+/// degree of parallelism stays at [`ARGON2_MIN_P_COST`].
+#[cfg(all(feature = "argon2", feature = "ksf-params", feature = "std"))]
+impl Calibrate for argon2::Argon2<'_> {
+    fn minimum() -> Result<Self, InternalError> {
+        let params = argon2::Params::new(
+            ARGON2_MIN_M_COST,
+            ARGON2_MIN_T_COST,
+            ARGON2_MIN_P_COST,
+            None,
+        )
+        .map_err(|_| InternalError::KsfError)?;
+
+        Ok(Self::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params,
+        ))
+    }
+
+    fn scale(
+        &self,
+        elapsed: Duration,
+        target: Duration,
+        limits: &Self,
+    ) -> Result<Self, InternalError> {
+        let (params, limits) = (self.params(), limits.params());
+        let (elapsed, target) = (elapsed.as_nanos(), target.as_nanos());
+
+        // The time is roughly linear in both the memory cost and the number of
+        // iterations.
+        let m_cost = scale_cost(params.m_cost(), target, elapsed)
+            .min(limits.m_cost())
+            .max(params.m_cost());
+        let t_cost = scale_cost(
+            params.t_cost(),
+            target * u128::from(params.m_cost()),
+            elapsed * u128::from(m_cost),
+        )
+        .min(limits.t_cost())
+        .max(params.t_cost());
+
+        let params = argon2::Params::new(m_cost, t_cost, params.p_cost(), None)
+            .map_err(|_| InternalError::KsfError)?;
+
+        Ok(Self::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params,
+        ))
+    }
+}
+
+/// scrypt with the fixed salt of
+/// [RFC 9807](https://www.rfc-editor.org/rfc/rfc9807.html#section-4.3.2). The
+/// [`Default`] parameters are the `scrypt` crate's recommended ones, the
+/// configuration recommended by RFC 9807 is `scrypt::Params::new(15, 8, 1,
+/// 32)`. The output length is always the length of the input.
+#[cfg(feature = "scrypt")]
+impl Ksf for scrypt::Params {
+    fn hash<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        let mut output = GenericArray::default();
+        scrypt::scrypt(&input, &SALT, self, &mut output).map_err(|_| InternalError::KsfError)?;
+        Ok(output)
+    }
+
+    /// Encodes the logarithm of the cost as a single byte, followed by the
+    /// block size and the degree of parallelism as four byte big-endian
+    /// integers.
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        let mut output = Vec::with_capacity(9);
+        output.push(self.log_n());
+        output.extend_from_slice(&self.r().to_be_bytes());
+        output.extend_from_slice(&self.p().to_be_bytes());
+
+        KsfParams::new(KsfParams::SCRYPT, output).ok()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        let [log_n, r0, r1, r2, r3, p0, p1, p2, p3] = params.to_array::<9>(KsfParams::SCRYPT)?;
+        let (r, p) = (
+            u32::from_be_bytes([r0, r1, r2, r3]),
+            u32::from_be_bytes([p0, p1, p2, p3]),
+        );
+
+        if log_n > limits.log_n() || r > limits.r() || p > limits.p() {
+            return Err(ProtocolError::KsfLimitError);
+        }
+
+        Ok(
+            scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+                .map_err(|_| InternalError::KsfError)?,
+        )
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl Ksf for WithContext<scrypt::Params> {
+    fn hash<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        self.0.hash(input)
+    }
+
+    fn hash_with_identifiers<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+        identifiers: Identifiers<'_>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        let mut output = GenericArray::default();
+        scrypt::scrypt(
+            &input,
+            &salt_with_identifiers(identifiers)?,
+            &self.0,
+            &mut output,
+        )
+        .map_err(|_| InternalError::KsfError)?;
+        Ok(output)
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        self.0.to_params()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        scrypt::Params::from_params(params, &limits.0).map(Self)
+    }
+}
+
+/// The minimum are the `scrypt` crate's recommended parameters, the cost is
+/// raised by doubling `N` and then by the degree of parallelism. The block
+/// size stays the same.
+#[cfg(all(feature = "ksf-params", feature = "scrypt", feature = "std"))]
+impl Calibrate for scrypt::Params {
+    fn minimum() -> Result<Self, InternalError> {
+        Ok(Self::recommended())
+    }
+
+    fn scale(
+        &self,
+        elapsed: Duration,
+        target: Duration,
+        limits: &Self,
+    ) -> Result<Self, InternalError> {
+        let (mut elapsed, target) = (elapsed.as_nanos().max(1), target.as_nanos());
+        let mut log_n = self.log_n();
+
+        while log_n < limits.log_n() && elapsed * 2 <= target {
+            log_n += 1;
+            elapsed *= 2;
+        }
+
+        let p = scale_cost(self.p(), target, elapsed)
+            .min(limits.p())
+            .max(self.p());
+
+        Self::new(log_n, self.r(), p, Self::RECOMMENDED_LEN).map_err(|_| InternalError::KsfError)
+    }
+}
+
+/// PBKDF2-HMAC with the hash `H` and the fixed salt of
+/// [RFC 9807](https://www.rfc-editor.org/rfc/rfc9807.html#section-4.3.2), for
+/// deployments restricted to FIPS-approved primitives. The output length is
+/// always the length of the input. `H` has to implement [`Pbkdf2Hash`], which
+/// provides the default number of iterations.
+///
+/// PBKDF2 is not memory-hard, prefer `Argon2` or scrypt where possible.
+#[cfg(feature = "pbkdf2")]
+#[derive_where(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Pbkdf2<H = sha2::Sha256> {
+    iterations: u32,
+    hash: PhantomData<H>,
+}
+
+/// A hash with a recommended number of PBKDF2-HMAC iterations, used by
+/// [`Pbkdf2::default()`]
+#[cfg(feature = "pbkdf2")]
+pub trait Pbkdf2Hash {
+    /// The default number of iterations, following the
+    /// [OWASP recommendation](https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html#pbkdf2)
+    const DEFAULT_ITERATIONS: u32;
+}
+
+#[cfg(feature = "pbkdf2")]
+impl Pbkdf2Hash for sha2::Sha256 {
+    const DEFAULT_ITERATIONS: u32 = 600_000;
+}
+
+#[cfg(feature = "pbkdf2")]
+impl Pbkdf2Hash for sha2::Sha512 {
+    const DEFAULT_ITERATIONS: u32 = 210_000;
+}
+
+#[cfg(feature = "pbkdf2")]
+impl<H: Pbkdf2Hash> Pbkdf2<H> {
+    /// The default number of iterations for the hash `H`, see
+    /// [`Pbkdf2Hash::DEFAULT_ITERATIONS`]
+    pub const DEFAULT_ITERATIONS: u32 = H::DEFAULT_ITERATIONS;
+}
+
+#[cfg(feature = "pbkdf2")]
+impl<H> Pbkdf2<H> {
+    /// Create a new [`Pbkdf2`] with the given number of `iterations`, which
+    /// has to be at least 1
+    pub const fn new(iterations: u32) -> Self {
+        Self {
+            iterations,
+            hash: PhantomData,
+        }
+    }
+
+    /// Returns the number of iterations
+    pub const fn iterations(&self) -> u32 {
+        self.iterations
+    }
+}
+
+#[cfg(feature = "pbkdf2")]
+impl<H: Pbkdf2Hash> Default for Pbkdf2<H> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ITERATIONS)
+    }
+}
+
+#[cfg(feature = "pbkdf2")]
+impl<H: Hash + Pbkdf2Hash> Ksf for Pbkdf2<H>
+where
+    H::Core: ProxyHash + Sync,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    fn hash<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        if self.iterations == 0 {
+            return Err(InternalError::KsfError);
+        }
+
+        let mut output = GenericArray::default();
+        pbkdf2::pbkdf2::<Hmac<H>>(&input, &SALT, self.iterations, &mut output)
             .map_err(|_| InternalError::KsfError)?;
         Ok(output)
     }
+
+    /// Encodes the number of iterations as a four byte big-endian integer. The
+    /// hash is not encoded, it is fixed by the type.
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        KsfParams::new(KsfParams::PBKDF2, self.iterations.to_be_bytes().to_vec()).ok()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        let iterations = u32::from_be_bytes(params.to_array(KsfParams::PBKDF2)?);
+
+        if iterations == 0 {
+            return Err(InternalError::KsfError.into());
+        }
+
+        if iterations > limits.iterations {
+            return Err(ProtocolError::KsfLimitError);
+        }
+
+        Ok(Self::new(iterations))
+    }
+}
+
+#[cfg(feature = "pbkdf2")]
+impl<H: Hash + Pbkdf2Hash> Ksf for WithContext<Pbkdf2<H>>
+where
+    H::Core: ProxyHash + Sync,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    fn hash<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        self.0.hash(input)
+    }
+
+    fn hash_with_identifiers<L: ArrayLength<u8>>(
+        &self,
+        input: GenericArray<u8, L>,
+        identifiers: Identifiers<'_>,
+    ) -> Result<GenericArray<u8, L>, InternalError> {
+        if self.0.iterations == 0 {
+            return Err(InternalError::KsfError);
+        }
+
+        let mut output = GenericArray::default();
+        pbkdf2::pbkdf2::<Hmac<H>>(
+            &input,
+            &salt_with_identifiers(identifiers)?,
+            self.0.iterations,
+            &mut output,
+        )
+        .map_err(|_| InternalError::KsfError)?;
+        Ok(output)
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn to_params(&self) -> Option<KsfParams> {
+        self.0.to_params()
+    }
+
+    #[cfg(feature = "ksf-params")]
+    fn from_params(params: &KsfParams, limits: &Self) -> Result<Self, ProtocolError> {
+        Pbkdf2::from_params(params, &limits.0).map(Self)
+    }
+}
+
+/// The minimum is [`Pbkdf2::default()`], the cost is raised by the number of
+/// iterations.
+#[cfg(all(feature = "ksf-params", feature = "pbkdf2", feature = "std"))]
+impl<H: Hash + Pbkdf2Hash> Calibrate for Pbkdf2<H>
+where
+    H::Core: ProxyHash + Sync,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    fn minimum() -> Result<Self, InternalError> {
+        Ok(Self::default())
+    }
+
+    fn scale(
+        &self,
+        elapsed: Duration,
+        target: Duration,
+        limits: &Self,
+    ) -> Result<Self, InternalError> {
+        let iterations = scale_cost(self.iterations, target.as_nanos(), elapsed.as_nanos())
+            .min(limits.iterations)
+            .max(self.iterations);
+
+        Ok(Self::new(iterations))
+    }
 }
@@ -0,0 +1,359 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Upgrading legacy password hashes to OPAQUE
+//!
+//! A server that stores password hashes of a legacy scheme, e.g. Argon2,
+//! bcrypt or scrypt, can convert them into [`ServerRegistration`]s without
+//! knowing the passwords by using the legacy hash as the OPAQUE password. The
+//! server runs the registration on behalf of the client with
+//! [`server_registration()`]. During login, the server sends the salt and
+//! parameters of the legacy hash to the client as [`LegacyParameters`], which
+//! computes the hash with [`LegacyParameters::hash()`] and passes the result as
+//! the password to [`ClientLogin`](crate::ClientLogin).
+//!
+//! The following legacy hashes are supported:
+//! - [`Argon2`] with the `argon2` feature.
+//! - [`Bcrypt`] with the `bcrypt` feature.
+//! - [`scrypt::Params`] with the `scrypt` feature.
+//!
+//! Other schemes can be added by implementing [`LegacyHash`].
+//!
+//! Some care is needed when using this mode:
+//! - Anyone who obtains the legacy hash can log in, the legacy hashes should
+//!   therefore be deleted after the conversion.
+//! - The server knows the export key of converted registrations. Clients can
+//!   register again with their password after the first successful login to
+//!   complete the upgrade.
+//! - To not reveal which users exist, the server should send parameters for
+//!   unknown users as well, see [`LegacyParameters::dummy()`].
+//! - The parameters are not authenticated, the client therefore passes the
+//!   most expensive parameters it accepts to
+//!   [`LegacyParameters::deserialize()`].
+
+use alloc::vec::Vec;
+
+use hkdf::Hkdf;
+use rand::{CryptoRng, RngCore};
+#[cfg(feature = "bcrypt")]
+use zeroize::Zeroize;
+
+use crate::ciphersuite::{CipherSuite, OprfHash};
+use crate::errors::{InternalError, ProtocolError};
+use crate::{
+    ClientRegistration, ClientRegistrationFinishParameters, ServerRegistration, ServerSetup,
+};
+
+static STR_LEGACY_SALT: &[u8] = b"OPAQUE-LegacySalt";
+
+/// A legacy password hashing scheme, used to compute the OPAQUE password from
+/// the password of the user.
+pub trait LegacyHash: Sized {
+    /// Computes the hash of `password` with `salt` into `output`. The length of
+    /// `output` is given by the length of the stored legacy hashes.
+    fn hash(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), InternalError>;
+
+    /// Encodes the parameters of the hash, which are sent to the client as
+    /// part of [`LegacyParameters`]
+    fn serialize_params(&self) -> Vec<u8>;
+
+    /// Decodes parameters encoded by [`LegacyHash::serialize_params()`], as
+    /// long as none of their costs exceed those of `limits`.
+    ///
+    /// # Errors
+    /// [`ProtocolError::KsfLimitError`] if the parameters are more expensive
+    /// than `limits`.
+    fn deserialize_params(input: &[u8], limits: &Self) -> Result<Self, ProtocolError>;
+}
+
+/// Argon2 as a [`LegacyHash`], which unlike [`argon2::Argon2`] exposes the
+/// algorithm and version to send them to the client.
+#[cfg(feature = "argon2")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Argon2 {
+    /// The Argon2 variant
+    pub algorithm: argon2::Algorithm,
+    /// The Argon2 version
+    pub version: argon2::Version,
+    /// The memory cost, the number of iterations and the degree of parallelism
+    pub params: argon2::Params,
+}
+
+#[cfg(feature = "argon2")]
+impl LegacyHash for Argon2 {
+    fn hash(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), InternalError> {
+        argon2::Argon2::new(self.algorithm, self.version, self.params.clone())
+            .hash_password_into(password, salt, output)
+            .map_err(|_| InternalError::LegacyHashError)
+    }
+
+    /// Encodes the algorithm and the version as single bytes, followed by the
+    /// memory cost, the number of iterations and the degree of parallelism as
+    /// four byte big-endian integers.
+    fn serialize_params(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(14);
+        output.push(self.algorithm as u8);
+        output.push(self.version as u8);
+        output.extend_from_slice(&self.params.m_cost().to_be_bytes());
+        output.extend_from_slice(&self.params.t_cost().to_be_bytes());
+        output.extend_from_slice(&self.params.p_cost().to_be_bytes());
+
+        output
+    }
+
+    fn deserialize_params(input: &[u8], limits: &Self) -> Result<Self, ProtocolError> {
+        let [a, v, m0, m1, m2, m3, t0, t1, t2, t3, p0, p1, p2, p3] =
+            <[u8; 14]>::try_from(input).map_err(|_| ProtocolError::SerializationError)?;
+        let (m_cost, t_cost, p_cost) = (
+            u32::from_be_bytes([m0, m1, m2, m3]),
+            u32::from_be_bytes([t0, t1, t2, t3]),
+            u32::from_be_bytes([p0, p1, p2, p3]),
+        );
+        let limits = &limits.params;
+
+        if m_cost > limits.m_cost() || t_cost > limits.t_cost() || p_cost > limits.p_cost() {
+            return Err(ProtocolError::KsfLimitError);
+        }
+
+        let algorithm = match a {
+            0 => argon2::Algorithm::Argon2d,
+            1 => argon2::Algorithm::Argon2i,
+            2 => argon2::Algorithm::Argon2id,
+            _ => return Err(ProtocolError::SerializationError),
+        };
+        let version = argon2::Version::try_from(u32::from(v))
+            .map_err(|_| ProtocolError::SerializationError)?;
+        let params = argon2::Params::new(m_cost, t_cost, p_cost, None)
+            .map_err(|_| ProtocolError::SerializationError)?;
+
+        Ok(Self {
+            algorithm,
+            version,
+            params,
+        })
+    }
+}
+
+/// bcrypt as a [`LegacyHash`], with the 16 byte salt and the 23 byte hash of
+/// the modular crypt format (`$2b$<cost>$<salt><hash>`) in their decoded form.
+///
+/// Like the modular crypt format, the password is terminated with a zero byte
+/// and truncated to 72 bytes.
+#[cfg(feature = "bcrypt")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bcrypt {
+    /// The logarithm of the number of iterations, between 4 and 31
+    pub cost: u8,
+}
+
+#[cfg(feature = "bcrypt")]
+impl LegacyHash for Bcrypt {
+    fn hash(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), InternalError> {
+        let salt = <[u8; 16]>::try_from(salt).map_err(|_| InternalError::LegacyHashError)?;
+
+        if !(4..=31).contains(&self.cost) || output.len() > 24 {
+            return Err(InternalError::LegacyHashError);
+        }
+
+        let mut key = [0; 72];
+        let len = password.len().min(72);
+        key[..len].copy_from_slice(&password[..len]);
+        let mut hash = bcrypt::bcrypt(self.cost.into(), salt, &key[..(len + 1).min(72)]);
+        output.copy_from_slice(&hash[..output.len()]);
+
+        key.zeroize();
+        hash.zeroize();
+
+        Ok(())
+    }
+
+    /// Encodes the cost as a single byte.
+    fn serialize_params(&self) -> Vec<u8> {
+        [self.cost].to_vec()
+    }
+
+    fn deserialize_params(input: &[u8], limits: &Self) -> Result<Self, ProtocolError> {
+        match *input {
+            [cost] if cost > limits.cost => Err(ProtocolError::KsfLimitError),
+            [cost @ 4..=31] => Ok(Self { cost }),
+            _ => Err(ProtocolError::SerializationError),
+        }
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl LegacyHash for scrypt::Params {
+    fn hash(&self, password: &[u8], salt: &[u8], output: &mut [u8]) -> Result<(), InternalError> {
+        scrypt::scrypt(password, salt, self, output).map_err(|_| InternalError::LegacyHashError)
+    }
+
+    /// Encodes the logarithm of the cost as a single byte, followed by the
+    /// block size and the degree of parallelism as four byte big-endian
+    /// integers.
+    fn serialize_params(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(9);
+        output.push(self.log_n());
+        output.extend_from_slice(&self.r().to_be_bytes());
+        output.extend_from_slice(&self.p().to_be_bytes());
+
+        output
+    }
+
+    fn deserialize_params(input: &[u8], limits: &Self) -> Result<Self, ProtocolError> {
+        let [log_n, r0, r1, r2, r3, p0, p1, p2, p3] =
+            <[u8; 9]>::try_from(input).map_err(|_| ProtocolError::SerializationError)?;
+        let (r, p) = (
+            u32::from_be_bytes([r0, r1, r2, r3]),
+            u32::from_be_bytes([p0, p1, p2, p3]),
+        );
+
+        if log_n > limits.log_n() || r > limits.r() || p > limits.p() {
+            return Err(ProtocolError::KsfLimitError);
+        }
+
+        scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+            .map_err(|_| ProtocolError::SerializationError)
+    }
+}
+
+/// The parameters and the salt of a stored legacy hash, which the server
+/// sends to the client before the login
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyParameters<L> {
+    hash: L,
+    salt: Vec<u8>,
+}
+
+impl<L: LegacyHash> LegacyParameters<L> {
+    /// Creates the parameters of a stored legacy hash from the hash `hash` and
+    /// the `salt` it was computed with, which must not exceed `u16::MAX` bytes
+    pub fn new(hash: L, salt: &[u8]) -> Result<Self, ProtocolError> {
+        if salt.len() > u16::MAX.into() {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        Ok(Self {
+            hash,
+            salt: salt.to_vec(),
+        })
+    }
+
+    /// Creates parameters for a client without a stored legacy hash, which
+    /// are indistinguishable from the parameters of an existing client.
+    ///
+    /// The salt of `salt_len` bytes is derived from the `secret` of the server
+    /// and the `credential_identifier`, so it stays the same across logins.
+    pub fn dummy<CS: CipherSuite>(
+        hash: L,
+        secret: &[u8],
+        credential_identifier: &[u8],
+        salt_len: usize,
+    ) -> Result<Self, ProtocolError> {
+        let mut salt = alloc::vec![0; salt_len];
+        Hkdf::<OprfHash<CS>>::new(None, secret)
+            .expand_multi_info(&[STR_LEGACY_SALT, credential_identifier], &mut salt)
+            .map_err(|_| InternalError::HkdfError)?;
+
+        Self::new(hash, &salt)
+    }
+
+    /// Returns the legacy hash
+    pub fn legacy_hash(&self) -> &L {
+        &self.hash
+    }
+
+    /// Returns the salt
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    /// Computes the legacy hash of `password` into `output`, to be used as the
+    /// password for [`ClientLogin`](crate::ClientLogin)
+    pub fn hash(&self, password: &[u8], output: &mut [u8]) -> Result<(), ProtocolError> {
+        Ok(self.hash.hash(password, &self.salt, output)?)
+    }
+
+    /// Serialization into bytes: the two byte big-endian length of the
+    /// parameters, the parameters, the two byte big-endian length of the salt
+    /// and the salt
+    pub fn serialize(&self) -> Vec<u8> {
+        let params = self.hash.serialize_params();
+        let mut output = Vec::with_capacity(4 + params.len() + self.salt.len());
+
+        for field in [params.as_slice(), &self.salt] {
+            // `serialize_params()` of the supported hashes and `Self::new()`
+            // keep both below `u16::MAX` bytes
+            output.extend_from_slice(&(field.len() as u16).to_be_bytes());
+            output.extend_from_slice(field);
+        }
+
+        output
+    }
+
+    /// Deserialization from bytes. As the parameters are sent by the server
+    /// before it is authenticated, their costs must not exceed those of
+    /// `limits`, see [`LegacyHash::deserialize_params()`].
+    ///
+    /// # Errors
+    /// [`ProtocolError::KsfLimitError`] if the parameters are more expensive
+    /// than `limits`.
+    pub fn deserialize(mut input: &[u8], limits: &L) -> Result<Self, ProtocolError> {
+        let hash = L::deserialize_params(take_field(&mut input)?, limits)?;
+        let salt = take_field(&mut input)?.to_vec();
+
+        if !input.is_empty() {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        Ok(Self { hash, salt })
+    }
+}
+
+// Takes a field prefixed with its two byte big-endian length from `input`
+fn take_field<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], ProtocolError> {
+    if input.len() < 2 {
+        return Err(ProtocolError::SerializationError);
+    }
+
+    let len = usize::from(u16::from_be_bytes([input[0], input[1]]));
+
+    if input.len() - 2 < len {
+        return Err(ProtocolError::SerializationError);
+    }
+
+    let field = &input[2..2 + len];
+    *input = &input[2 + len..];
+
+    Ok(field)
+}
+
+/// Creates a [`ServerRegistration`] from a stored legacy hash, by running the
+/// registration with `legacy_hash` as the password.
+///
+/// The `params` have to match the ones the client uses for logging in, in
+/// particular the identifiers and the key stretching function.
+pub fn server_registration<CS: CipherSuite, R: CryptoRng + RngCore, SK: Clone>(
+    rng: &mut R,
+    server_setup: &ServerSetup<CS, SK>,
+    credential_identifier: &[u8],
+    legacy_hash: &[u8],
+    params: ClientRegistrationFinishParameters<CS>,
+) -> Result<ServerRegistration<CS>, ProtocolError> {
+    let client_start_result = ClientRegistration::<CS>::start(rng, legacy_hash)?;
+    let server_start_result = ServerRegistration::start(
+        server_setup,
+        client_start_result.message,
+        credential_identifier,
+    )?;
+    let client_finish_result =
+        client_start_result
+            .state
+            .finish(rng, legacy_hash, server_start_result.message, params)?;
+
+    Ok(ServerRegistration::finish(client_finish_result.message))
+}
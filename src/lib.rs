@@ -37,6 +37,13 @@
 //!     type Ksf = opaque_ke::ksf::Identity;
 //! }
 //! ```
+//! The [`suites`] module provides ready-made ciphersuites for the
+//! configurations of RFC 9807, e.g.
+//! `suites::Ristretto255Sha512TripleDh<opaque_ke::ksf::Identity>` is equivalent
+//! to the one above. Each of them carries a stable numeric
+//! identifier and a human-readable name through
+//! [`NamedCipherSuite`](suites::NamedCipherSuite).
+//!
 //! See [examples/simple_login.rs](https://github.com/facebook/opaque-ke/blob/main/examples/simple_login.rs)
 //! for a working example of a simple password-based login using OPAQUE.
 //!
@@ -537,6 +544,11 @@
 //! combination of [Client Login Finish](#client-login-finish) and [Server Login
 //! Finish](#server-login-finish) for example usage.
 //!
+//! If several subsystems need their own keys, both results also provide
+//! [`export_keying_material()`](ClientLoginFinishResult::export_keying_material),
+//! which derives keys of arbitrary length bound to a label and context from
+//! the handshake, instead of hashing the session key for each purpose.
+//!
 //! ## Checking Server Consistency
 //!
 //! A [`ClientLoginFinishResult`] contains the `server_s_pk` field, which is
@@ -845,13 +857,13 @@
 //!     Some(password_file),
 //!     client_login_start_result.message,
 //!     b"alice@example.com",
-//!     ServerLoginParameters {
-//!         context: None,
-//!         identifiers: Identifiers {
+//!     ServerLoginParameters::new(
+//!         None,
+//!         Identifiers {
 //!             client: Some(b"Alice_the_Cryptographer"),
 //!             server: Some(b"Facebook"),
 //!         },
-//!     },
+//!     ),
 //! )?;
 //! # Ok::<(), ProtocolError>(())
 //! ```
@@ -898,7 +910,7 @@
 //! #     &password_file_bytes,
 //! #   )?;
 //! # let server_login_start_result =
-//! #     ServerLogin::start(&mut server_rng, &server_setup, Some(password_file), client_login_start_result.message, b"alice@example.com", ServerLoginParameters { context: None, identifiers: Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") } })?;
+//! #     ServerLogin::start(&mut server_rng, &server_setup, Some(password_file), client_login_start_result.message, b"alice@example.com", ServerLoginParameters::new(None, Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") }))?;
 //! let client_login_finish_result = client_login_start_result.state.finish(
 //!     &mut client_rng,
 //!     b"password",
@@ -957,7 +969,7 @@
 //! #     &password_file_bytes,
 //! #   )?;
 //! # let server_login_start_result =
-//! #     ServerLogin::start(&mut server_rng, &server_setup, Some(password_file), client_login_start_result.message, b"alice@example.com", ServerLoginParameters { context: None, identifiers: Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") } })?;
+//! #     ServerLogin::start(&mut server_rng, &server_setup, Some(password_file), client_login_start_result.message, b"alice@example.com", ServerLoginParameters::new(None, Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") }))?;
 //! # let client_login_finish_result = client_login_start_result.state.finish(
 //! #   &mut client_rng,
 //! #   b"password",
@@ -966,7 +978,7 @@
 //! # )?;
 //! let server_login_finish_result = server_login_start_result.state.finish(
 //!     client_login_finish_result.message,
-//!     ServerLoginParameters { context: None, identifiers: Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") } },
+//!     ServerLoginParameters::new(None, Identifiers { client: Some(b"Alice_the_Cryptographer"), server: Some(b"Facebook") }),
 //! )?;
 //!
 //! # Ok::<(), ProtocolError>(())
@@ -991,6 +1003,22 @@
 //! - In [Server Login Finish](#server-login-finish), where the server can
 //!   populate [`ServerLoginParameters::context`].
 //!
+//! ## Pre-Shared Keys
+//!
+//! In addition to the password, the client and server can share an external
+//! key, e.g. one provisioned to a managed device. A [`PreSharedKey`] given with
+//! [`ClientLoginFinishParameters::with_psk()`] and
+//! [`ServerLoginParameters::with_psk()`] is input into the key schedule together with the shared secrets of the key
+//! exchange, so the session stays secure as long as either the password or the
+//! pre-shared key is uncompromised. The identity of the pre-shared key is
+//! committed to in the transcript. If the client and server disagree on the
+//! pre-shared key or its identity, the login fails with
+//! [`ProtocolError::InvalidLoginError`](errors::ProtocolError::InvalidLoginError).
+//!
+//! The pre-shared key is not part of the credential request, so the server has
+//! to determine the identity out-of-band, e.g. from the device making the
+//! request.
+//!
 //! ## Dummy Server Login
 //!
 //! For applications in which the server does not wish to reveal to the client
@@ -1001,6 +1029,94 @@
 //! message is created by passing a `None` to the `password_file` parameter for
 //! [`ServerLogin::start`].
 //!
+//! ## Legacy Password Hashes
+//!
+//! With the `legacy` feature, servers migrating from a legacy password hashing
+//! scheme can convert the stored hashes into [`ServerRegistration`]s with
+//! `legacy::server_registration()`, without knowing the passwords. The legacy
+//! hash then takes the place of the password: during login the server sends the
+//! legacy salt and parameters to the client as `legacy::LegacyParameters`, and
+//! the client checks them against its limits, computes the hash with
+//! `LegacyParameters::hash()` and uses it as the password for [`ClientLogin`]. Argon2, bcrypt and scrypt are supported
+//! with the `argon2`, `bcrypt` and `scrypt` features. See the `legacy` module
+//! for the security considerations.
+//!
+//! ## Encrypted Payloads
+//!
+//! With the `payload` feature, the server can send an encrypted payload with
+//! the [`CredentialResponse`] by passing it to
+//! `ServerLoginParameters::with_payload()`, and the client can send one with
+//! the [`CredentialFinalization`] by passing it to
+//! `ClientLoginFinishParameters::with_payload()`. Payloads are encrypted with
+//! ChaCha20-Poly1305 under keys derived from the handshake, and the key
+//! exchange MACs cover the encrypted payload, so that stripping, adding or
+//! modifying a payload makes the login fail with
+//! [`ProtocolError::InvalidLoginError`](errors::ProtocolError::InvalidLoginError).
+//! The decrypted payloads are available in
+//! `ClientLoginFinishResult::server_payload` and
+//! `ServerLoginFinishResult::client_payload`. Only the key exchanges of this
+//! crate support payloads, others fail with
+//! [`ProtocolError::UnsupportedPayloadError`](errors::ProtocolError::UnsupportedPayloadError).
+//!
+//! Payloads are sent as an [extension](#message-extensions) of the message and
+//! are at most `MAX_PAYLOAD_LEN` bytes long.
+//!
+//! Some care is needed when using payloads:
+//! - The server payload is sent before the client is authenticated. Only a
+//!   client that knows the password can decrypt it, but the server gets no
+//!   assurance that it was received.
+//! - The length of a payload is not hidden. If it could reveal whether a
+//!   client is registered, pad the payload to a fixed length.
+//!
+//! ## Message Extensions
+//!
+//! The KSF parameters of the `ksf-params` feature and the payloads of the
+//! `payload` feature follow the fixed-size encoding of a message or record as
+//! extensions. Each extension consists of a two byte big-endian tag, the two
+//! byte big-endian length of its value and the value, in strictly increasing
+//! order of their tags:
+//! - `1`: the `ksf::KsfParams` of [`RegistrationUpload`],
+//!   [`ServerRegistration`] and [`CredentialResponse`], as the two byte
+//!   big-endian identifier followed by the parameters,
+//! - `2`: the encrypted payload of [`CredentialResponse`] and
+//!   [`CredentialFinalization`].
+//!
+//! `serialize_with_extensions()` includes the extensions, `serialize()` keeps
+//! the fixed-size encoding without them. The encoding is the same regardless
+//! of the enabled features: `deserialize()` fails with
+//! [`ProtocolError::UnsupportedExtensionError`](errors::ProtocolError::UnsupportedExtensionError)
+//! for a tag that the enabled features do not support, and with
+//! [`ProtocolError::SerializationError`](errors::ProtocolError::SerializationError)
+//! for malformed extensions or any other trailing bytes.
+//!
+//! ## Runtime-Selected Ciphersuites
+//!
+//! The ciphersuite is usually fixed at compile time through the [`CipherSuite`]
+//! type parameter. A server that has to support clients using different
+//! ciphersuites can instead use `dynamic::DynServer`, available with the
+//! `dynamic` feature, which holds a [`ServerSetup`] for each supported
+//! ciphersuite under an application-chosen numeric identifier. Messages
+//! exchanged with `dynamic::DynClient` use the
+//! [framed encoding](#framed-encoding) with this identifier, and the server
+//! dispatches each message to the matching ciphersuite, returning the
+//! type-erased serialized response. Password files are framed as well, and
+//! using one with a request for a different ciphersuite fails with
+//! [`ProtocolError::CipherSuiteMismatchError`](errors::ProtocolError::CipherSuiteMismatchError).
+//!
+//! ## Framed Encoding
+//!
+//! The output of `serialize()` carries no indication of the ciphersuite it was
+//! created with. To store records or send messages in a self-describing format,
+//! all messages as well as [`ServerSetup`] and [`ServerRegistration`] provide
+//! `serialize_framed()` and `deserialize_framed()`, which take an
+//! application-chosen ciphersuite identifier. The framed encoding prefixes the
+//! regular serialization with a magic value, a format version, the ciphersuite
+//! identifier and the message type, see [`framing`]. Deserialization fails with
+//! a dedicated [`ProtocolError`](errors::ProtocolError) if any of them does not
+//! match. Messages and records that can carry
+//! [extensions](#message-extensions) include them in the framed encoding,
+//! which therefore requires the `alloc` feature for them.
+//!
 //! ## Remote Private Keys
 //!
 //! Servers that want to store their private key in an external location (e.g.
@@ -1038,7 +1154,7 @@
 //! use opaque_ke::keypair::{KeyPair, PrivateKeySerialization};
 //! use opaque_ke::errors::ProtocolError;
 //!
-//! // Implement if you intend to use `ServerSetup::de/serialize` instead of `serde`.
+//! // Implement if you intend to serialize `ServerSetup`, with `ServerSetup::de/serialize` or `serde`.
 //! impl PrivateKeySerialization<Ristretto255> for YourRemoteKey {
 //!     type Error = YourRemoteKeyError;
 //!     type Len = U0;
@@ -1147,7 +1263,7 @@
 //! use opaque_ke::keypair::{KeyPair, OprfSeedSerialization};
 //! use opaque_ke::errors::ProtocolError;
 //!
-//! // Implement if you intend to use `ServerSetup::de/serialize` instead of `serde`.
+//! // Implement if you intend to serialize `ServerSetup`, with `ServerSetup::de/serialize` or `serde`.
 //! impl OprfSeedSerialization<sha2::Sha512, YourRemoteSecretsError> for YourRemoteSeed {
 //!     type Len = U0;
 //!
@@ -1228,7 +1344,8 @@
 //! that's not supported directly by this crate. The maintainer of the said KSF
 //! or of the application itself can implement the [`Ksf`](ksf::Ksf) trait to
 //! use it with `opaque-ke`. `scrypt` is used for this example, but any KSF
-//! can be used.
+//! can be used. The `scrypt` feature already implements [`Ksf`](ksf::Ksf) for
+//! `scrypt::Params`.
 //! ```
 //! # use generic_array::GenericArray;
 //! use opaque_ke::ksf::Ksf;
@@ -1319,8 +1436,25 @@
 //! # Ok::<(), ProtocolError>(())
 //! ```
 //!
+//! The KSFs of this crate use the fixed salt recommended by RFC 9807, as the
+//! OPRF output is already unique per user. Where a per-user salt is required
+//! nonetheless, [`WithContext`](ksf::WithContext) adds the [`Identifiers`] of
+//! the registration or login to the salt. It is implemented for `Argon2`,
+//! `scrypt::Params` and [`Pbkdf2`](ksf::Pbkdf2), and used by setting the
+//! ciphersuite's KSF to e.g. `ksf::WithContext<argon2::Argon2<'static>>`.
+//! Custom KSFs can override
+//! [`Ksf::hash_with_identifiers()`](ksf::Ksf::hash_with_identifiers()). The
+//! client identity has to be set explicitly, e.g. to the credential
+//! identifier, and be the same at registration and every login.
+//!
 //! # Features
 //!
+//! - The `alloc` feature enables the functions that require [`alloc`], e.g.
+//!   `serialize_with_extensions()` and `serialize_framed()` of the messages
+//!   and records that can carry [extensions](#message-extensions). It is
+//!   enabled by every feature below
+//!   that requires [`alloc`].
+//!
 //! - The `argon2` feature, when enabled, introduces a dependency on `argon2`
 //!   and implements the `Ksf` trait for `Argon2` with a set of default parameters.
 //!   In general, secure instantiations should choose to invoke a memory-hard password
@@ -1329,9 +1463,39 @@
 //!   more computationally intensive the `Ksf` function is, the more resistant
 //!   the server's password file records will be against offline dictionary and precomputation
 //!   attacks; see [the OPAQUE paper](https://eprint.iacr.org/2018/163.pdf) for
-//!   more details. The `argon2` feature requires [`alloc`].
+//!   more details. Together with the `ksf-params` and `std` features,
+//!   [`ksf::calibrate()`] picks Argon2id parameters for a latency and memory
+//!   budget on the current machine, starting from the recommendations of
+//!   RFC 9106. The `argon2` feature requires [`alloc`].
+//!
+//! - The `pbkdf2` feature, when enabled, introduces a dependency on `pbkdf2`
+//!   and provides [`Pbkdf2`](ksf::Pbkdf2), a KSF over PBKDF2-HMAC with a
+//!   configurable hash and number of iterations, for deployments restricted to
+//!   FIPS-approved primitives, e.g. with
+//!   [`P256Sha256TripleDh`](suites::P256Sha256TripleDh). It is not memory-hard
+//!   and should only be used where `argon2` or `scrypt` are not an option.
+//!
+//! - The `scrypt` feature, when enabled, introduces a dependency on `scrypt`
+//!   and implements the `Ksf` trait for `scrypt::Params`, using the fixed salt
+//!   recommended by RFC 9807.
+//!
+//! - The `ksf-params` feature stores the identifier and parameters of the KSF
+//!   used at registration, see [`KsfParams`](ksf::KsfParams), in the
+//!   [`ServerRegistration`] and sends them to the client in the
+//!   [`CredentialResponse`]. Clients then pick up the cost of each record
+//!   automatically, up to the limits set with
+//!   [`ClientLoginFinishParameters::with_ksf_limits()`], and servers can detect
+//!   records registered with outdated parameters. Modified parameters change
+//!   the output of the KSF and fail like a wrong password. The parameters are
+//!   not part of the key derivation, records serialized without them work
+//!   with clients configured with the same KSF. The `ksf-params` feature
+//!   requires [`alloc`].
 //!
 //! - The `serde` feature, enabled by default, provides convenience functions for serializing and deserializing with [serde](https://serde.rs/).
+//!   Human-readable formats, e.g. JSON, represent [`ServerSetup`],
+//!   [`ServerRegistration`] and the protocol messages as base64url strings of
+//!   their [framed encoding](framing), tagged with [`CipherSuite::SUITE_ID`].
+//!   Other formats, e.g. bincode, use a compact binary representation.
 //!
 //! - The `ristretto255` feature enables using [`Ristretto255`] as a `KeGroup`
 //!   and `OprfCs`. To select a specific backend see the [curve25519-dalek]
@@ -1340,6 +1504,12 @@
 //! - The `curve25519` feature enables Curve25519 as a `KeGroup`. To select a
 //!   specific backend see the [curve25519-dalek] documentation.
 //!
+//! - The `secp256k1` feature enables [`Secp256k1`] as a `KeGroup`. Together
+//!   with the `ecdsa` feature it can also be used with [`Ecdsa`] for
+//!   [`SigmaI`]s signature algorithm.
+//!
+//! - The `p256` feature enables the P-256 based ciphersuites in [`suites`].
+//!
 //! - The `ecdsa` feature enables using [`elliptic_curve`]s with [`Ecdsa`] for
 //!   [`SigmaI`]s signature algorithm.
 //!
@@ -1350,8 +1520,63 @@
 //!   for KEM-based handshakes backed by the `ml-kem` crate. Disabling the
 //!   feature removes those types and their associated tests from the build.
 //!
+//! - The `payload` feature enables attaching encrypted application payloads
+//!   to the login messages, see [Encrypted Payloads](#encrypted-payloads). The
+//!   `payload` feature requires [`alloc`].
+//!
+//! - The `legacy` feature enables the `legacy` module for converting password
+//!   hashes of a legacy scheme into [`ServerRegistration`]s, see
+//!   [Legacy Password Hashes](#legacy-password-hashes). The `bcrypt` feature
+//!   adds bcrypt as a legacy scheme. The `legacy` feature requires [`alloc`].
+//!
+//! - The `compat-v3` feature enables the `compat::v3` module for converting
+//!   `ServerSetup` and `ServerRegistration` records created by opaque-ke 3.x.
+//!
+//! - The `dynamic` feature enables the `dynamic` module for selecting
+//!   ciphersuites at runtime, see
+//!   [Runtime-Selected Ciphersuites](#runtime-selected-ciphersuites). The
+//!   `dynamic` feature requires [`alloc`].
+//!
+//! - The `pkcs8` feature enables importing and exporting private keys as
+//!   PKCS#8 and public keys as `SubjectPublicKeyInfo`, in DER or PEM, for the
+//!   NIST curves, [`Secp256k1`], [`Curve25519`] and [`Ed25519`]. A
+//!   [`ServerSetup`] can then be created from an existing key file with
+//!   [`KeyPair::from_pkcs8_pem`](keypair::KeyPair::from_pkcs8_pem) and
+//!   [`ServerSetup::new_with_key_pair`]. The `pkcs8` feature requires
+//!   [`alloc`].
+//!
+//! - The `jwk` feature enables the `jwk` module for encoding public keys as
+//!   JSON Web Keys and publishing the keys of a [`ServerSetup`] as a JWK Set.
+//!   The `jwk` feature requires [`alloc`].
+//!
+//! - The `seal` feature enables encrypting a [`ServerSetup`] at rest under a
+//!   key-encryption key or a passphrase, see [`ServerSetup::seal_with_key`]
+//!   and [`ServerSetup::seal_with_passphrase`]. The `seal` feature requires
+//!   [`alloc`].
+//!
+//! - The `backup` feature enables splitting a [`ServerSetup`] into m-of-n
+//!   backup shares with [`ServerSetup::split_backup`] and restoring it with
+//!   [`ServerSetup::restore`]. The `backup` feature requires [`alloc`].
+//!
+//! - The `ephemeral-pool` feature enables [`EphemeralPool`], a pool of
+//!   [`Ephemeral`]s generated ahead of time for
+//!   [`ServerLogin::start_with_ephemeral`]. The `ephemeral-pool` feature
+//!   requires [`alloc`].
+//!
+//! - The `batch` feature enables [`ServerLogin::start_batch`] for starting
+//!   many logins at once, with the same results as [`ServerLogin::start`]. The
+//!   `batch` feature requires [`alloc`].
+//!
+//! - The `precomputed-tables` feature speeds up key generation, including the
+//!   ephemeral keys of every login, with precomputed tables for the
+//!   multiplication with the generator: those of [curve25519-dalek] for
+//!   [`Ristretto255`], [`Curve25519`] and [`Ed25519`], those of [k256] for
+//!   [`Secp256k1`], and tables built on first use for other [`elliptic_curve`]
+//!   curves like P-256. The latter two additionally require the `std` feature.
+//!
 //! [`alloc`]: https://doc.rust-lang.org/alloc
 //! [curve25519-dalek]: https://docs.rs/curve25519-dalek/4/curve25519_dalek/index.html#backends
+//! [k256]: https://docs.rs/k256/0.13
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -1360,41 +1585,66 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![allow(type_alias_bounds)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
 // Error types
 pub mod errors;
 
+#[cfg(feature = "backup")]
+pub mod backup;
 pub mod ciphersuite;
+#[cfg(feature = "compat-v3")]
+pub mod compat;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
 mod envelope;
+pub mod framing;
 pub mod hash;
+#[cfg(feature = "jwk")]
+pub mod jwk;
 pub mod key_exchange;
 pub mod keypair;
 pub mod ksf;
+#[cfg(feature = "legacy")]
+pub mod legacy;
 mod messages;
 mod opaque;
+#[cfg(feature = "payload")]
+mod payload;
+#[cfg(feature = "seal")]
+pub mod seal;
 mod serialization;
+pub mod suites;
 
 #[cfg(test)]
 mod tests;
 
 // Exports
 
-#[cfg(feature = "argon2")]
+#[cfg(any(feature = "argon2", feature = "seal"))]
 pub use argon2;
 pub use generic_array;
 #[cfg(feature = "kem")]
 pub use ml_kem;
 pub use rand;
+#[cfg(feature = "scrypt")]
+pub use scrypt;
 
 pub use crate::ciphersuite::CipherSuite;
+pub use crate::key_exchange::ephemeral::Ephemeral;
+#[cfg(feature = "ephemeral-pool")]
+pub use crate::key_exchange::ephemeral::EphemeralPool;
 #[cfg(feature = "curve25519")]
 pub use crate::key_exchange::group::curve25519::Curve25519;
 #[cfg(feature = "ed25519")]
 pub use crate::key_exchange::group::ed25519::Ed25519;
 #[cfg(feature = "ristretto255")]
 pub use crate::key_exchange::group::ristretto255::Ristretto255;
+#[cfg(feature = "secp256k1")]
+pub use crate::key_exchange::group::secp256k1::Secp256k1;
 pub use crate::key_exchange::sigma_i::SigmaI;
 #[cfg(feature = "ecdsa")]
 pub use crate::key_exchange::sigma_i::ecdsa::Ecdsa;
@@ -1409,10 +1659,15 @@ pub use crate::messages::{
     RegistrationResponse, RegistrationResponseLen, RegistrationUpload, RegistrationUploadLen,
     ServerLoginBuilder,
 };
+#[cfg(feature = "batch")]
+pub use crate::opaque::ServerLoginRequest;
 pub use crate::opaque::{
-    ClientLogin, ClientLoginFinishParameters, ClientLoginFinishResult, ClientLoginStartResult,
-    ClientRegistration, ClientRegistrationFinishParameters, ClientRegistrationFinishResult,
-    ClientRegistrationStartResult, Identifiers, KeyMaterialInfo, ServerLogin,
+    ClientLogin, ClientLoginFinishParameters, ClientLoginFinishResult, ClientLoginPrecomputation,
+    ClientLoginRandomizedPassword, ClientLoginStartResult, ClientRegistration,
+    ClientRegistrationFinishParameters, ClientRegistrationFinishResult,
+    ClientRegistrationStartResult, Identifiers, KeyMaterialInfo, PreSharedKey, ServerLogin,
     ServerLoginFinishResult, ServerLoginParameters, ServerLoginStartResult, ServerRegistration,
     ServerRegistrationLen, ServerRegistrationStartResult, ServerSetup,
 };
+#[cfg(feature = "payload")]
+pub use crate::payload::MAX_PAYLOAD_LEN;
@@ -8,6 +8,8 @@
 
 //! Contains the messages used for OPAQUE
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::Add;
 
 use derive_where::derive_where;
@@ -22,6 +24,7 @@ use zeroize::Zeroizing;
 use crate::ciphersuite::{CipherSuite, KeGroup, OprfGroup, OprfHash};
 use crate::envelope::{Envelope, EnvelopeLen};
 use crate::errors::ProtocolError;
+use crate::framing::{self, FrameHeaderLen, FramedLen, MessageType};
 use crate::hash::OutputSize;
 use crate::key_exchange::group::Group;
 use crate::key_exchange::shared::NonceLen;
@@ -30,10 +33,18 @@ use crate::key_exchange::{
     SerializedCredentialRequest, SerializedCredentialResponse,
 };
 use crate::keypair::PublicKey;
+#[cfg(feature = "ksf-params")]
+use crate::ksf::{Ksf, KsfParams};
 use crate::opaque::{
     MaskedResponse, MaskedResponseLen, ServerLogin, ServerLoginStartResult, ServerSetup,
 };
-use crate::serialization::SliceExt;
+#[cfg(feature = "ksf-params")]
+use crate::serialization::EXTENSION_KSF_PARAMS;
+#[cfg(feature = "payload")]
+use crate::serialization::EXTENSION_PAYLOAD;
+#[cfg(any(feature = "ksf-params", feature = "payload"))]
+use crate::serialization::with_extension;
+use crate::serialization::{Extensions, SliceExt};
 
 ////////////////////////////
 // High-level API Structs //
@@ -41,11 +52,6 @@ use crate::serialization::SliceExt;
 ////////////////////////////
 
 /// The message sent by the client to the server, to initiate registration
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound = "")
-)]
 #[derive_where(Clone)]
 #[derive_where(Debug, Eq, Hash, Ord, PartialEq, PartialOrd; voprf::BlindedElement<CS::OprfCs>)]
 pub struct RegistrationRequest<CS: CipherSuite> {
@@ -55,14 +61,6 @@ pub struct RegistrationRequest<CS: CipherSuite> {
 
 /// The answer sent by the server to the user, upon reception of the
 /// registration attempt
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>",
-        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone)]
 #[derive_where(Debug, Eq, Hash, Ord, PartialEq, PartialOrd; voprf::EvaluationElement<CS::OprfCs>, <KeGroup<CS> as Group>::Pk)]
 pub struct RegistrationResponse<CS: CipherSuite> {
@@ -74,14 +72,6 @@ pub struct RegistrationResponse<CS: CipherSuite> {
 
 /// The final message from the client, containing sealed cryptographic
 /// identifiers
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>",
-        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone, ZeroizeOnDrop)]
 #[derive_where(Debug, Eq, Hash, Ord, PartialEq, PartialOrd; <KeGroup<CS> as Group>::Pk)]
 pub struct RegistrationUpload<CS: CipherSuite> {
@@ -93,17 +83,12 @@ pub struct RegistrationUpload<CS: CipherSuite> {
     /// The user's public key
     #[derive_where(skip(Zeroize))]
     pub(crate) client_s_pk: PublicKey<KeGroup<CS>>,
+    /// The identifier and parameters of the KSF the client registered with
+    #[cfg(feature = "ksf-params")]
+    pub(crate) ksf_params: Option<KsfParams>,
 }
 
 /// The message sent by the user to the server, to initiate registration
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<CS::KeyExchange as KeyExchange>::KE1Message: serde::Deserialize<'de>",
-        serialize = "<CS::KeyExchange as KeyExchange>::KE1Message: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone, ZeroizeOnDrop)]
 #[derive_where(
     Debug, Eq, Hash, PartialEq;
@@ -139,6 +124,8 @@ pub struct ServerLoginBuilder<'a, CS: CipherSuite, SK: Clone> {
     pub(crate) evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
     pub(crate) masking_nonce: Zeroizing<GenericArray<u8, NonceLen>>,
     pub(crate) masked_response: MaskedResponse<CS>,
+    #[cfg(feature = "ksf-params")]
+    pub(crate) ksf_params: Option<KsfParams>,
     #[cfg(test)]
     pub(crate) oprf_key: Zeroizing<GenericArray<u8, <OprfGroup<CS> as voprf::Group>::ScalarLen>>,
     pub(crate) ke2_builder: <CS::KeyExchange as KeyExchange>::KE2Builder<'a, CS>,
@@ -173,14 +160,6 @@ impl<CS: CipherSuite, SK: Clone> ServerLoginBuilder<'_, CS, SK> {
 
 /// The answer sent by the server to the user, upon reception of the login
 /// attempt
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<CS::KeyExchange as KeyExchange>::KE2Message: serde::Deserialize<'de>",
-        serialize = "<CS::KeyExchange as KeyExchange>::KE2Message: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone)]
 #[derive_where(
     Debug, Eq, Hash, PartialEq;
@@ -193,18 +172,17 @@ pub struct CredentialResponse<CS: CipherSuite> {
     pub(crate) masking_nonce: GenericArray<u8, NonceLen>,
     pub(crate) masked_response: MaskedResponse<CS>,
     pub(crate) ke2_message: <CS::KeyExchange as KeyExchange>::KE2Message,
+    /// The identifier and parameters of the KSF stored with the registration
+    #[cfg(feature = "ksf-params")]
+    pub(crate) ksf_params: Option<KsfParams>,
+    /// Encrypted application payload, see
+    /// [`ServerLoginParameters::with_payload()`](crate::ServerLoginParameters::with_payload())
+    #[cfg(feature = "payload")]
+    pub(crate) payload: Option<Vec<u8>>,
 }
 
 /// The answer sent by the client to the server, upon reception of the sealed
 /// envelope
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<CS::KeyExchange as KeyExchange>::KE3Message: serde::Deserialize<'de>",
-        serialize = "<CS::KeyExchange as KeyExchange>::KE3Message: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone)]
 #[derive_where(
     Debug, Eq, Hash, PartialEq;
@@ -212,6 +190,10 @@ pub struct CredentialResponse<CS: CipherSuite> {
 )]
 pub struct CredentialFinalization<CS: CipherSuite> {
     pub(crate) ke3_message: <CS::KeyExchange as KeyExchange>::KE3Message,
+    /// Encrypted application payload, see
+    /// [`ClientLoginFinishParameters::with_payload()`](crate::ClientLoginFinishParameters::with_payload())
+    #[cfg(feature = "payload")]
+    pub(crate) payload: Option<Vec<u8>>,
 }
 
 ////////////////////////////////
@@ -240,6 +222,30 @@ impl<CS: CipherSuite> RegistrationRequest<CS> {
             blinded_element: voprf::BlindedElement::deserialize(input)?,
         })
     }
+
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`
+    pub fn serialize_framed(
+        &self,
+        suite_id: u16,
+    ) -> GenericArray<u8, FramedLen<RegistrationRequestLen<CS>>>
+    where
+        // FramedRegistrationRequest: FrameHeader + RegistrationRequest
+        FrameHeaderLen: Add<RegistrationRequestLen<CS>>,
+        FramedLen<RegistrationRequestLen<CS>>: ArrayLength<u8>,
+    {
+        framing::header(suite_id, MessageType::RegistrationRequest).concat(self.serialize())
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError> {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::RegistrationRequest,
+            input,
+        )?)
+    }
 }
 
 /// Length of [`RegistrationResponse`] in bytes for serialization.
@@ -269,6 +275,33 @@ impl<CS: CipherSuite> RegistrationResponse<CS> {
         })
     }
 
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`
+    pub fn serialize_framed(
+        &self,
+        suite_id: u16,
+    ) -> GenericArray<u8, FramedLen<RegistrationResponseLen<CS>>>
+    where
+        // RegistrationResponse: KgPk + KePk
+        <OprfGroup<CS> as voprf::Group>::ElemLen: Add<<KeGroup<CS> as Group>::PkLen>,
+        RegistrationResponseLen<CS>: ArrayLength<u8>,
+        // FramedRegistrationResponse: FrameHeader + RegistrationResponse
+        FrameHeaderLen: Add<RegistrationResponseLen<CS>>,
+        FramedLen<RegistrationResponseLen<CS>>: ArrayLength<u8>,
+    {
+        framing::header(suite_id, MessageType::RegistrationResponse).concat(self.serialize())
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError> {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::RegistrationResponse,
+            input,
+        )?)
+    }
+
     #[cfg(test)]
     /// Only used for tests, where we can set the beta value to test for the
     /// reflection error case
@@ -303,15 +336,104 @@ impl<CS: CipherSuite> RegistrationUpload<CS> {
             .concat(self.envelope.serialize())
     }
 
-    /// Deserialization from bytes
+    /// Serialization into bytes, followed by the extensions of the message:
+    /// the KSF parameters if the client registered with any.
+    /// [`Self::serialize()`] omits the extensions, in which case clients use
+    /// the KSF they are configured with.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_with_extensions(&self) -> Vec<u8>
+    where
+        // RegistrationUpload: (KePk + Hash) + Envelope
+        <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+        Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+            ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+        RegistrationUploadLen<CS>: ArrayLength<u8>,
+    {
+        self.append_extensions(self.serialize().to_vec())
+    }
+
+    // Appends the extensions of the message to `output`
+    #[cfg(feature = "alloc")]
+    pub(crate) fn append_extensions(&self, output: Vec<u8>) -> Vec<u8> {
+        #[cfg(feature = "ksf-params")]
+        let output = with_extension(
+            output,
+            EXTENSION_KSF_PARAMS,
+            self.ksf_params
+                .as_ref()
+                .map(KsfParams::serialize)
+                .as_deref(),
+        );
+
+        output
+    }
+
+    /// Deserialization from bytes, followed by any extensions
+    ///
+    /// # Errors
+    /// [`ProtocolError::UnsupportedExtensionError`] if an extension is not
+    /// supported by the enabled features.
     pub fn deserialize(mut input: &[u8]) -> Result<Self, ProtocolError> {
+        let client_s_pk = PublicKey::deserialize_take(&mut input)?;
+        let masking_key = input.take_array("masking key")?;
+        let envelope = Envelope::deserialize_take(&mut input)?;
+        #[cfg(feature = "ksf-params")]
+        let mut ksf_params = None;
+
+        Extensions::new(input).try_for_each(|extension| match extension? {
+            #[cfg(feature = "ksf-params")]
+            (EXTENSION_KSF_PARAMS, value) => {
+                ksf_params = Some(KsfParams::deserialize(value)?);
+                Ok(())
+            }
+            (tag, _) => Err(ProtocolError::UnsupportedExtensionError(tag)),
+        })?;
+
         Ok(Self {
-            client_s_pk: PublicKey::deserialize_take(&mut input)?,
-            masking_key: input.take_array("masking key")?,
-            envelope: Envelope::deserialize_take(&mut input)?,
+            client_s_pk,
+            masking_key,
+            envelope,
+            #[cfg(feature = "ksf-params")]
+            ksf_params,
         })
     }
 
+    /// Returns the identifier and parameters of the KSF the client registered
+    /// with, if it supports [`Ksf::to_params()`]
+    #[cfg(feature = "ksf-params")]
+    pub fn ksf_params(&self) -> Option<&KsfParams> {
+        self.ksf_params.as_ref()
+    }
+
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`, followed by the extensions of the
+    /// message like [`Self::serialize_with_extensions()`]
+    #[cfg(feature = "alloc")]
+    pub fn serialize_framed(&self, suite_id: u16) -> Vec<u8>
+    where
+        // RegistrationUpload: (KePk + Hash) + Envelope
+        <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+        Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+            ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+        RegistrationUploadLen<CS>: ArrayLength<u8>,
+    {
+        self.append_extensions(framing::frame(
+            suite_id,
+            MessageType::RegistrationUpload,
+            &self.serialize(),
+        ))
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError> {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::RegistrationUpload,
+            input,
+        )?)
+    }
+
     // Creates a dummy instance used for faking a [CredentialResponse]
     pub(crate) fn dummy<R: RngCore + CryptoRng, SK: Clone, OS: Clone>(
         rng: &mut R,
@@ -324,6 +446,9 @@ impl<CS: CipherSuite> RegistrationUpload<CS> {
             envelope: Envelope::<CS>::dummy(),
             masking_key,
             client_s_pk: server_setup.dummy_pk.clone(),
+            // Unknown users receive the parameters of new registrations
+            #[cfg(feature = "ksf-params")]
+            ksf_params: CS::Ksf::default().to_params(),
         }
     }
 }
@@ -353,6 +478,37 @@ impl<CS: CipherSuite> CredentialRequest<CS> {
         Self::deserialize_take(&mut input)
     }
 
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`
+    pub fn serialize_framed(
+        &self,
+        suite_id: u16,
+    ) -> GenericArray<u8, FramedLen<CredentialRequestLen<CS>>>
+    where
+        <CS::KeyExchange as KeyExchange>::KE1Message: Serialize,
+        // CredentialRequest: KgPk + Ke1Message
+        <OprfGroup<CS> as voprf::Group>::ElemLen: Add<Ke1MessageLen<CS>>,
+        CredentialRequestLen<CS>: ArrayLength<u8>,
+        // FramedCredentialRequest: FrameHeader + CredentialRequest
+        FrameHeaderLen: Add<CredentialRequestLen<CS>>,
+        FramedLen<CredentialRequestLen<CS>>: ArrayLength<u8>,
+    {
+        framing::header(suite_id, MessageType::CredentialRequest).concat(self.serialize())
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError>
+    where
+        <CS::KeyExchange as KeyExchange>::KE1Message: Deserialize,
+    {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::CredentialRequest,
+            input,
+        )?)
+    }
+
     pub(crate) fn deserialize_take(input: &mut &[u8]) -> Result<Self, ProtocolError>
     where
         <CS::KeyExchange as KeyExchange>::KE1Message: Deserialize,
@@ -404,24 +560,136 @@ impl<CS: CipherSuite> CredentialResponse<CS> {
             .concat(self.ke2_message.serialize())
     }
 
-    /// Deserialization from bytes
+    /// Serialization into bytes, followed by the extensions of the message: the
+    /// KSF parameters stored with the registration, if any, and the encrypted
+    /// payload, if one was sent. [`Self::serialize()`] omits the extensions,
+    /// in which case clients use the KSF they are configured with and a login
+    /// with a payload fails.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_with_extensions(&self) -> Vec<u8>
+    where
+        <CS::KeyExchange as KeyExchange>::KE2Message: Serialize,
+        // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+        <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+        Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+            ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+        CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+        // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+        CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+        CredentialResponseLen<CS>: ArrayLength<u8>,
+    {
+        self.append_extensions(self.serialize().to_vec())
+    }
+
+    // Appends the extensions of the message to `output`
+    #[cfg(feature = "alloc")]
+    pub(crate) fn append_extensions(&self, output: Vec<u8>) -> Vec<u8> {
+        #[cfg(feature = "ksf-params")]
+        let output = with_extension(
+            output,
+            EXTENSION_KSF_PARAMS,
+            self.ksf_params
+                .as_ref()
+                .map(KsfParams::serialize)
+                .as_deref(),
+        );
+        #[cfg(feature = "payload")]
+        let output = with_extension(output, EXTENSION_PAYLOAD, self.payload.as_deref());
+
+        output
+    }
+
+    /// Deserialization from bytes, followed by any extensions
+    ///
+    /// # Errors
+    /// [`ProtocolError::UnsupportedExtensionError`] if an extension is not
+    /// supported by the enabled features.
     pub fn deserialize(mut input: &[u8]) -> Result<Self, ProtocolError>
     where
         <CS::KeyExchange as KeyExchange>::KE2Message: Deserialize,
     {
         let evaluation_element = EvaluationElement::deserialize(input)?;
         input = &input[voprf::EvaluationElementLen::<CS::OprfCs>::USIZE..];
+        let masking_nonce = input.take_array("masking nonce")?;
+        let masked_response = MaskedResponse::deserialize_take(&mut input)?;
+        let ke2_message =
+            <CS::KeyExchange as KeyExchange>::KE2Message::deserialize_take(&mut input)?;
+        #[cfg(feature = "ksf-params")]
+        let mut ksf_params = None;
+        #[cfg(feature = "payload")]
+        let mut payload = None;
+
+        Extensions::new(input).try_for_each(|extension| match extension? {
+            #[cfg(feature = "ksf-params")]
+            (EXTENSION_KSF_PARAMS, value) => {
+                ksf_params = Some(KsfParams::deserialize(value)?);
+                Ok(())
+            }
+            #[cfg(feature = "payload")]
+            (EXTENSION_PAYLOAD, value) => {
+                payload = Some(value.to_vec());
+                Ok(())
+            }
+            (tag, _) => Err(ProtocolError::UnsupportedExtensionError(tag)),
+        })?;
 
         Ok(Self {
             evaluation_element,
-            masking_nonce: input.take_array("masking nonce")?,
-            masked_response: MaskedResponse::deserialize_take(&mut input)?,
-            ke2_message: <CS::KeyExchange as KeyExchange>::KE2Message::deserialize_take(
-                &mut input,
-            )?,
+            masking_nonce,
+            masked_response,
+            ke2_message,
+            #[cfg(feature = "ksf-params")]
+            ksf_params,
+            #[cfg(feature = "payload")]
+            payload,
         })
     }
 
+    /// Returns the identifier and parameters of the KSF stored with the
+    /// registration, which the client uses unless
+    /// [`ClientLoginFinishParameters::ksf`](crate::ClientLoginFinishParameters::ksf)
+    /// is set
+    #[cfg(feature = "ksf-params")]
+    pub fn ksf_params(&self) -> Option<&KsfParams> {
+        self.ksf_params.as_ref()
+    }
+
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`, followed by the extensions of the
+    /// message like [`Self::serialize_with_extensions()`]
+    #[cfg(feature = "alloc")]
+    pub fn serialize_framed(&self, suite_id: u16) -> Vec<u8>
+    where
+        <CS::KeyExchange as KeyExchange>::KE2Message: Serialize,
+        // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+        <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+        Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+            ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+        CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+        // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+        CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+        CredentialResponseLen<CS>: ArrayLength<u8>,
+    {
+        self.append_extensions(framing::frame(
+            suite_id,
+            MessageType::CredentialResponse,
+            &self.serialize(),
+        ))
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError>
+    where
+        <CS::KeyExchange as KeyExchange>::KE2Message: Deserialize,
+    {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::CredentialResponse,
+            input,
+        )?)
+    }
+
     pub(crate) fn to_parts(&self) -> SerializedCredentialResponse<CS> {
         SerializedCredentialResponse::new(
             &self.evaluation_element,
@@ -442,6 +710,10 @@ impl<CS: CipherSuite> CredentialResponse<CS> {
             masking_nonce: self.masking_nonce,
             masked_response: self.masked_response.clone(),
             ke2_message: self.ke2_message.clone(),
+            #[cfg(feature = "ksf-params")]
+            ksf_params: self.ksf_params.clone(),
+            #[cfg(feature = "payload")]
+            payload: self.payload.clone(),
         }
     }
 }
@@ -458,15 +730,81 @@ impl<CS: CipherSuite> CredentialFinalization<CS> {
         self.ke3_message.serialize()
     }
 
-    /// Deserialization from bytes
+    /// Serialization into bytes, followed by the extensions of the message:
+    /// the encrypted payload, if one was sent. [`Self::serialize()`] omits the
+    /// extensions, in which case a login with a payload fails.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_with_extensions(&self) -> Vec<u8>
+    where
+        <CS::KeyExchange as KeyExchange>::KE3Message: Serialize,
+    {
+        self.append_extensions(self.serialize().to_vec())
+    }
+
+    // Appends the extensions of the message to `output`
+    #[cfg(feature = "alloc")]
+    pub(crate) fn append_extensions(&self, output: Vec<u8>) -> Vec<u8> {
+        #[cfg(feature = "payload")]
+        let output = with_extension(output, EXTENSION_PAYLOAD, self.payload.as_deref());
+
+        output
+    }
+
+    /// Deserialization from bytes, followed by any extensions
+    ///
+    /// # Errors
+    /// [`ProtocolError::UnsupportedExtensionError`] if an extension is not
+    /// supported by the enabled features.
     pub fn deserialize(mut input: &[u8]) -> Result<Self, ProtocolError>
     where
         <CS::KeyExchange as KeyExchange>::KE3Message: Deserialize,
     {
+        let ke3_message =
+            <CS::KeyExchange as KeyExchange>::KE3Message::deserialize_take(&mut input)?;
+        #[cfg(feature = "payload")]
+        let mut payload = None;
+
+        Extensions::new(input).try_for_each(|extension| match extension? {
+            #[cfg(feature = "payload")]
+            (EXTENSION_PAYLOAD, value) => {
+                payload = Some(value.to_vec());
+                Ok(())
+            }
+            (tag, _) => Err(ProtocolError::UnsupportedExtensionError(tag)),
+        })?;
+
         Ok(Self {
-            ke3_message: <CS::KeyExchange as KeyExchange>::KE3Message::deserialize_take(
-                &mut input,
-            )?,
+            ke3_message,
+            #[cfg(feature = "payload")]
+            payload,
         })
     }
+
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`, followed by the extensions of the
+    /// message like [`Self::serialize_with_extensions()`]
+    #[cfg(feature = "alloc")]
+    pub fn serialize_framed(&self, suite_id: u16) -> Vec<u8>
+    where
+        <CS::KeyExchange as KeyExchange>::KE3Message: Serialize,
+    {
+        self.append_extensions(framing::frame(
+            suite_id,
+            MessageType::CredentialFinalization,
+            &self.serialize(),
+        ))
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError>
+    where
+        <CS::KeyExchange as KeyExchange>::KE3Message: Deserialize,
+    {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::CredentialFinalization,
+            input,
+        )?)
+    }
 }
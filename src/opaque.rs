@@ -8,6 +8,8 @@
 
 //! Provides the main OPAQUE API
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::{Add, Deref};
 
 use derive_where::derive_where;
@@ -21,12 +23,18 @@ use subtle::{Choice, ConstantTimeEq, CtOption};
 use voprf::{BlindedElement, Group as _, OprfClient, OprfClientLen};
 use zeroize::Zeroizing;
 
+#[cfg(feature = "backup")]
+use crate::backup;
 use crate::ciphersuite::{CipherSuite, KeGroup, KeHash, OprfGroup, OprfHash};
 use crate::envelope::{Envelope, EnvelopeLen};
 use crate::errors::{InternalError, ProtocolError};
+use crate::framing::{self, FrameHeaderLen, FramedLen, MessageType};
 use crate::hash::OutputSize;
+#[cfg(feature = "payload")]
+use crate::key_exchange::Payloads;
+use crate::key_exchange::ephemeral::Ephemeral;
 use crate::key_exchange::group::Group;
-use crate::key_exchange::shared::NonceLen;
+use crate::key_exchange::shared::{self, NonceLen};
 use crate::key_exchange::{
     Deserialize, Ke1MessageLen, Ke1StateLen, Ke2StateLen, KeyExchange, Serialize,
     SerializedContext, SerializedCredentialResponse, SerializedIdentifiers,
@@ -35,7 +43,13 @@ use crate::keypair::{
     KeyPair, OprfSeed, OprfSeedSerialization, PrivateKey, PrivateKeySerialization, PublicKey,
 };
 use crate::ksf::Ksf;
+#[cfg(feature = "ksf-params")]
+use crate::ksf::KsfParams;
 use crate::messages::{CredentialRequestLen, RegistrationUploadLen};
+#[cfg(feature = "payload")]
+use crate::payload::{self, STR_CLIENT_PAYLOAD, STR_SERVER_PAYLOAD};
+#[cfg(feature = "seal")]
+use crate::seal::{self, SealKey};
 use crate::serialization::{GenericArrayExt, SliceExt};
 use crate::{
     CredentialFinalization, CredentialRequest, CredentialResponse, RegistrationRequest,
@@ -58,17 +72,6 @@ const STR_OPAQUE_DERIVE_KEY_PAIR: &[u8; 20] = b"OPAQUE-DeriveKeyPair";
 ////////////////////////////
 
 /// The state elements the server holds upon setup
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>, <KeGroup<CS> as \
-                       Group>::Sk: serde::Deserialize<'de>, SK: serde::Deserialize<'de>, OS: \
-                       serde::Deserialize<'de>",
-        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize, <KeGroup<CS> as Group>::Sk: \
-                     serde::Serialize, SK: serde::Serialize, OS: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone)]
 #[derive_where(Debug, Eq, Hash, Ord, PartialEq, PartialOrd; <KeGroup<CS> as Group>::Pk, <KeGroup<CS> as Group>::Sk, SK, OS)]
 pub struct ServerSetup<
@@ -76,8 +79,8 @@ pub struct ServerSetup<
     SK: Clone = PrivateKey<KeGroup<CS>>,
     OS: Clone = OprfSeed<OprfHash<CS>>,
 > {
-    oprf_seed: OS,
-    keypair: KeyPair<KeGroup<CS>, SK>,
+    pub(crate) oprf_seed: OS,
+    pub(crate) keypair: KeyPair<KeGroup<CS>, SK>,
     pub(crate) dummy_pk: PublicKey<KeGroup<CS>>,
 }
 
@@ -99,14 +102,6 @@ pub struct ClientRegistration<CS: CipherSuite> {
 }
 
 /// The state elements the server holds to record a registration
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Deserialize, serde::Serialize),
-    serde(bound(
-        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>",
-        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize"
-    ))
-)]
 #[derive_where(Clone, ZeroizeOnDrop)]
 #[derive_where(Debug, Eq, Hash, Ord, PartialEq, PartialOrd; <KeGroup<CS> as Group>::Pk)]
 pub struct ServerRegistration<CS: CipherSuite>(pub(crate) RegistrationUpload<CS>);
@@ -132,9 +127,28 @@ pub struct ServerRegistration<CS: CipherSuite>(pub(crate) RegistrationUpload<CS>
 pub struct ClientLogin<CS: CipherSuite> {
     pub(crate) oprf_client: voprf::OprfClient<CS::OprfCs>,
     pub(crate) ke1_state: <CS::KeyExchange as KeyExchange>::KE1State,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::serde::CredentialRequestDef")
+    )]
     pub(crate) credential_request: CredentialRequest<CS>,
 }
 
+/// The password-independent part of a [`ClientLogin`], generated ahead of time
+/// by [`ClientLogin::precompute()`]
+///
+/// It can't be cloned, so that it is only used for a single login. The key
+/// exchange state is zeroized on drop.
+#[derive_where(
+    Debug, Eq, Hash, PartialEq;
+    <CS::KeyExchange as KeyExchange>::KE1State,
+    <CS::KeyExchange as KeyExchange>::KE1Message,
+)]
+pub struct ClientLoginPrecomputation<CS: CipherSuite> {
+    ke1_state: <CS::KeyExchange as KeyExchange>::KE1State,
+    ke1_message: <CS::KeyExchange as KeyExchange>::KE1Message,
+}
+
 /// The state elements the server holds to record a login
 #[cfg_attr(
     feature = "serde",
@@ -191,6 +205,21 @@ impl<CS: CipherSuite, SK: Clone, OS: Clone> ServerSetup<CS, SK, OS> {
         }
     }
 
+    // Assembles a [`ServerSetup`] from existing parts, e.g. when migrating
+    // records of previous versions
+    #[cfg(feature = "compat-v3")]
+    pub(crate) fn from_parts(
+        oprf_seed: OS,
+        keypair: KeyPair<KeGroup<CS>, SK>,
+        dummy_pk: PublicKey<KeGroup<CS>>,
+    ) -> Self {
+        Self {
+            oprf_seed,
+            keypair,
+            dummy_pk,
+        }
+    }
+
     /// The information required to generate the key material for
     /// [`ServerRegistration::start_with_key_material()`] and
     /// [`ServerLogin::builder_with_key_material()`].
@@ -234,6 +263,171 @@ impl<CS: CipherSuite, SK: Clone, OS: Clone> ServerSetup<CS, SK, OS> {
         })
     }
 
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`
+    pub fn serialize_framed(
+        &self,
+        suite_id: u16,
+    ) -> GenericArray<u8, FramedLen<ServerSetupLen<CS, SK, OS>>>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+        // ServerSetup: Hash + KeSk + KePk
+        OS::Len: Add<SK::Len>,
+        Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+        ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+        // FramedServerSetup: FrameHeader + ServerSetup
+        FrameHeaderLen: Add<ServerSetupLen<CS, SK, OS>>,
+        FramedLen<ServerSetupLen<CS, SK, OS>>: ArrayLength<u8>,
+    {
+        framing::header(suite_id, MessageType::ServerSetup).concat(self.serialize())
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError<SK::Error>>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    {
+        let input = framing::unframe(suite_id, MessageType::ServerSetup, input)
+            .map_err(ProtocolError::into_custom)?;
+
+        Self::deserialize(input)
+    }
+
+    /// Encrypts the [serialization](Self::serialize) under the key-encryption
+    /// key `kek`, see [`seal`] for the format. Use
+    /// [`ServerSetup::open_with_key()`] to restore it.
+    #[cfg(feature = "seal")]
+    pub fn seal_with_key<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+        kek: &[u8; 32],
+    ) -> Result<Vec<u8>, ProtocolError>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+        // ServerSetup: Hash + KeSk + KePk
+        OS::Len: Add<SK::Len>,
+        Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+        ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+    {
+        let plaintext = Zeroizing::new(self.serialize());
+
+        seal::seal_with_key(rng, kek, CS::SUITE_ID, &plaintext)
+    }
+
+    /// Encrypts the [serialization](Self::serialize) under a key derived from
+    /// `passphrase` with Argon2id and `params`, see [`seal`] for
+    /// the format. Use [`ServerSetup::open_with_passphrase()`] to restore it.
+    ///
+    /// [`argon2::Params::default()`] follows the OWASP recommendations.
+    #[cfg(feature = "seal")]
+    pub fn seal_with_passphrase<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+        passphrase: &[u8],
+        params: &argon2::Params,
+    ) -> Result<Vec<u8>, ProtocolError>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+        // ServerSetup: Hash + KeSk + KePk
+        OS::Len: Add<SK::Len>,
+        Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+        ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+    {
+        let plaintext = Zeroizing::new(self.serialize());
+
+        seal::seal_with_passphrase(rng, passphrase, params, CS::SUITE_ID, &plaintext)
+    }
+
+    /// Decrypts a [`ServerSetup`] sealed with [`ServerSetup::seal_with_key()`].
+    /// Returns [`ProtocolError::SealOpenError`] if `kek` is wrong or the
+    /// input was modified.
+    #[cfg(feature = "seal")]
+    pub fn open_with_key(kek: &[u8; 32], input: &[u8]) -> Result<Self, ProtocolError<SK::Error>>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    {
+        let plaintext = seal::open(SealKey::Key(kek), CS::SUITE_ID, input)
+            .map_err(ProtocolError::into_custom)?;
+
+        Self::deserialize(&plaintext)
+    }
+
+    /// Decrypts a [`ServerSetup`] sealed with
+    /// [`ServerSetup::seal_with_passphrase()`]. Returns
+    /// [`ProtocolError::SealOpenError`] if `passphrase` is wrong or the input
+    /// was modified.
+    ///
+    /// The Argon2id parameters are read from the input before it can be
+    /// authenticated. Returns [`ProtocolError::KsfLimitError`] without
+    /// deriving the key if the memory cost, the number of iterations or the
+    /// degree of parallelism exceed those of `limits`, usually the parameters
+    /// passed to [`ServerSetup::seal_with_passphrase()`].
+    #[cfg(feature = "seal")]
+    pub fn open_with_passphrase(
+        passphrase: &[u8],
+        limits: &argon2::Params,
+        input: &[u8],
+    ) -> Result<Self, ProtocolError<SK::Error>>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    {
+        let plaintext = seal::open(SealKey::Passphrase(passphrase, limits), CS::SUITE_ID, input)
+            .map_err(ProtocolError::into_custom)?;
+
+        Self::deserialize(&plaintext)
+    }
+
+    /// Splits the [serialization](Self::serialize) into `shares` backup
+    /// shares, any `threshold` of which restore it with
+    /// [`ServerSetup::restore()`], see [`backup`] for the format.
+    ///
+    /// Returns [`ProtocolError::InvalidBackupThresholdError`] if `threshold`
+    /// is zero or larger than `shares`.
+    #[cfg(feature = "backup")]
+    pub fn split_backup<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+        threshold: u8,
+        shares: u8,
+    ) -> Result<Vec<Zeroizing<Vec<u8>>>, ProtocolError>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+        // ServerSetup: Hash + KeSk + KePk
+        OS::Len: Add<SK::Len>,
+        Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+        ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+    {
+        let secret = Zeroizing::new(self.serialize());
+
+        backup::split::<CS, _>(rng, &secret, threshold, shares)
+    }
+
+    /// Restores a [`ServerSetup`] from backup shares created with
+    /// [`ServerSetup::split_backup()`].
+    ///
+    /// Returns [`ProtocolError::InvalidBackupShareError`] if a share is
+    /// corrupted, duplicated or belongs to a different backup, and
+    /// [`ProtocolError::InsufficientBackupSharesError`] if there are fewer
+    /// shares than the threshold.
+    #[cfg(feature = "backup")]
+    pub fn restore<S: AsRef<[u8]>>(shares: &[S]) -> Result<Self, ProtocolError<SK::Error>>
+    where
+        SK: PrivateKeySerialization<KeGroup<CS>>,
+        OS: OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    {
+        let secret = backup::combine::<CS, _>(shares).map_err(ProtocolError::into_custom)?;
+
+        Self::deserialize(&secret)
+    }
+
     /// Returns the keypair
     pub fn keypair(&self) -> &KeyPair<KeGroup<CS>, SK> {
         &self.keypair
@@ -342,12 +536,18 @@ impl<CS: CipherSuite> ClientRegistration<CS> {
             return Err(ProtocolError::ReflectedValueError);
         }
 
+        #[cfg(feature = "ksf-params")]
+        let ksf_params = params
+            .ksf
+            .map_or_else(|| CS::Ksf::default().to_params(), Ksf::to_params);
+
         #[cfg_attr(not(test), allow(unused_variables))]
         let (randomized_pwd, randomized_pwd_hasher) = get_password_derived_key::<CS>(
             password,
             self.oprf_client.clone(),
             registration_response.evaluation_element,
             params.ksf,
+            params.identifiers,
         )?;
 
         let mut masking_key = Output::<OprfHash<CS>>::default();
@@ -367,6 +567,8 @@ impl<CS: CipherSuite> ClientRegistration<CS> {
                 envelope: result.0,
                 masking_key,
                 client_s_pk: result.1,
+                #[cfg(feature = "ksf-params")]
+                ksf_params,
             },
             export_key: result.2,
             server_s_pk: registration_response.server_s_pk,
@@ -397,11 +599,71 @@ impl<CS: CipherSuite> ServerRegistration<CS> {
         self.0.serialize()
     }
 
-    /// Deserialization from bytes
+    /// Serialization into bytes, followed by the extensions of the record: the
+    /// KSF parameters if the client registered with any. [`Self::serialize()`]
+    /// omits the extensions, in which case clients use the KSF they are
+    /// configured with.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_with_extensions(&self) -> Vec<u8>
+    where
+        // RegistrationUpload: (KePk + Hash) + Envelope
+        <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+        Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+            ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+        RegistrationUploadLen<CS>: ArrayLength<u8>,
+    {
+        self.0.serialize_with_extensions()
+    }
+
+    /// Deserialization from bytes, followed by any extensions
+    ///
+    /// # Errors
+    /// [`ProtocolError::UnsupportedExtensionError`] if an extension is not
+    /// supported by the enabled features.
     pub fn deserialize(input: &[u8]) -> Result<Self, ProtocolError> {
         Ok(Self(RegistrationUpload::deserialize(input)?))
     }
 
+    /// Returns the identifier and parameters of the KSF the client registered
+    /// with, which are sent to the client during login.
+    ///
+    /// To raise the cost of the KSF over time, compare them with
+    /// [`Ksf::to_params()`] of the current configuration and let the client
+    /// register again after the next successful login if they differ.
+    #[cfg(feature = "ksf-params")]
+    pub fn ksf_params(&self) -> Option<&KsfParams> {
+        self.0.ksf_params()
+    }
+
+    /// Serialization into the [framed encoding](crate::framing) with the
+    /// ciphersuite identifier `suite_id`, followed by the extensions of the
+    /// record like [`Self::serialize_with_extensions()`]
+    #[cfg(feature = "alloc")]
+    pub fn serialize_framed(&self, suite_id: u16) -> Vec<u8>
+    where
+        // RegistrationUpload: (KePk + Hash) + Envelope
+        <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+        Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+            ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+        RegistrationUploadLen<CS>: ArrayLength<u8>,
+    {
+        self.0.append_extensions(framing::frame(
+            suite_id,
+            MessageType::ServerRegistration,
+            &self.serialize(),
+        ))
+    }
+
+    /// Deserialization from the [framed encoding](crate::framing), which has to
+    /// carry the ciphersuite identifier `suite_id`
+    pub fn deserialize_framed(suite_id: u16, input: &[u8]) -> Result<Self, ProtocolError> {
+        Self::deserialize(framing::unframe(
+            suite_id,
+            MessageType::ServerRegistration,
+            input,
+        )?)
+    }
+
     /// Create a [`RegistrationResponse`] with a remote OPRF seed. To generate
     /// the `key_material` see [`ServerSetup::key_material_info()`].
     ///
@@ -526,8 +788,53 @@ impl<CS: CipherSuite> ClientLogin<CS> {
         })
     }
 
+    /// Generates the password-independent part of [`ClientLogin::start()`],
+    /// the ephemeral key pair and nonce of the key exchange, e.g. while the
+    /// user is still entering the password
+    ///
+    /// The login is started with [`ClientLogin::start_with_precomputation()`],
+    /// which consumes the precomputation.
+    pub fn precompute<R: RngCore + CryptoRng>(
+        rng: &mut R,
+    ) -> Result<ClientLoginPrecomputation<CS>, ProtocolError> {
+        let ke1_result = CS::KeyExchange::generate_ke1(rng)?;
+
+        Ok(ClientLoginPrecomputation {
+            ke1_state: ke1_result.state,
+            ke1_message: ke1_result.message,
+        })
+    }
+
+    /// Returns an initial "blinded" password request to send to the server, as
+    /// well as a [`ClientLogin`], using the result of
+    /// [`ClientLogin::precompute()`]
+    pub fn start_with_precomputation<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        password: &[u8],
+        precomputation: ClientLoginPrecomputation<CS>,
+    ) -> Result<ClientLoginStartResult<CS>, ProtocolError> {
+        let blind_result = blind::<CS, _>(rng, password)?;
+
+        let credential_request = CredentialRequest {
+            blinded_element: blind_result.message,
+            ke1_message: precomputation.ke1_message,
+        };
+
+        Ok(ClientLoginStartResult {
+            message: credential_request.clone(),
+            state: Self {
+                oprf_client: blind_result.state,
+                ke1_state: precomputation.ke1_state,
+                credential_request,
+            },
+        })
+    }
+
     /// "Unblinds" the server's answer and returns the opened assets from the
     /// server
+    ///
+    /// This is [`ClientLogin::derive_randomized_password()`] followed by
+    /// [`ClientLogin::finish_with_randomized_password()`].
     pub fn finish<R: CryptoRng + RngCore>(
         self,
         rng: &mut R,
@@ -535,6 +842,25 @@ impl<CS: CipherSuite> ClientLogin<CS> {
         credential_response: CredentialResponse<CS>,
         params: ClientLoginFinishParameters<CS>,
     ) -> Result<ClientLoginFinishResult<CS>, ProtocolError> {
+        let randomized_password =
+            self.derive_randomized_password(password, &credential_response, &params)?;
+
+        self.finish_with_randomized_password(rng, randomized_password, credential_response, params)
+    }
+
+    /// "Unblinds" the server's answer and runs the key stretching function,
+    /// the expensive part of [`ClientLogin::finish()`]
+    ///
+    /// The state is not consumed, so this can run on a worker thread while the
+    /// login is cancelled by dropping the state and the result. The login is
+    /// completed with [`ClientLogin::finish_with_randomized_password()`], which
+    /// has to be called with the same `credential_response` and `params`.
+    pub fn derive_randomized_password(
+        &self,
+        password: &[u8],
+        credential_response: &CredentialResponse<CS>,
+        params: &ClientLoginFinishParameters<CS>,
+    ) -> Result<ClientLoginRandomizedPassword<CS>, ProtocolError> {
         // Check if beta value from server is equal to alpha value from client
         if self
             .credential_request
@@ -546,13 +872,76 @@ impl<CS: CipherSuite> ClientLogin<CS> {
             return Err(ProtocolError::ReflectedValueError);
         }
 
-        let (_, randomized_pwd_hasher) = get_password_derived_key::<CS>(
+        // The KSF parameters stored with the registration replace the default
+        #[cfg(feature = "ksf-params")]
+        let stored_ksf = match (params.ksf, &credential_response.ksf_params) {
+            (None, Some(ksf_params)) => Some(match params.ksf_limits {
+                Some(ksf_limits) => CS::Ksf::from_params(ksf_params, ksf_limits)?,
+                None => CS::Ksf::from_params(ksf_params, &CS::Ksf::default())?,
+            }),
+            _ => None,
+        };
+        #[cfg(feature = "ksf-params")]
+        let ksf = params.ksf.or(stored_ksf.as_ref());
+        #[cfg(not(feature = "ksf-params"))]
+        let ksf = params.ksf;
+
+        let (randomized_pwd, _) = get_password_derived_key::<CS>(
             password,
             self.oprf_client.clone(),
             credential_response.evaluation_element.clone(),
-            params.ksf,
+            ksf,
+            params.identifiers,
         )?;
 
+        Ok(ClientLoginRandomizedPassword {
+            randomized_pwd,
+            evaluation_element: credential_response.evaluation_element.clone(),
+            masking_nonce: credential_response.masking_nonce,
+        })
+    }
+
+    /// Opens the envelope with the result of
+    /// [`ClientLogin::derive_randomized_password()`] and returns the opened
+    /// assets from the server, the cheap part of [`ClientLogin::finish()`]
+    ///
+    /// # Errors
+    /// [`ProtocolError::RandomizedPasswordMismatchError`] if
+    /// `randomized_password` was derived from a different
+    /// `credential_response`.
+    pub fn finish_with_randomized_password<R: CryptoRng + RngCore>(
+        self,
+        rng: &mut R,
+        randomized_password: ClientLoginRandomizedPassword<CS>,
+        credential_response: CredentialResponse<CS>,
+        params: ClientLoginFinishParameters<CS>,
+    ) -> Result<ClientLoginFinishResult<CS>, ProtocolError> {
+        // Check if beta value from server is equal to alpha value from client
+        if self
+            .credential_request
+            .blinded_element
+            .value()
+            .ct_eq(&credential_response.evaluation_element.value())
+            .into()
+        {
+            return Err(ProtocolError::ReflectedValueError);
+        }
+
+        // The randomized password has to be derived from this response
+        if !bool::from(
+            randomized_password
+                .evaluation_element
+                .value()
+                .ct_eq(&credential_response.evaluation_element.value()),
+        ) || randomized_password.masking_nonce != credential_response.masking_nonce
+        {
+            return Err(ProtocolError::RandomizedPasswordMismatchError);
+        }
+
+        let randomized_pwd_hasher =
+            Hkdf::<OprfHash<CS>>::from_prk(&randomized_password.randomized_pwd)
+                .map_err(|_| InternalError::HkdfError)?;
+
         let mut masking_key = Output::<OprfHash<CS>>::default();
         randomized_pwd_hasher
             .expand(STR_MASKING_KEY, &mut masking_key)
@@ -581,7 +970,12 @@ impl<CS: CipherSuite> ClientLogin<CS> {
                 err => err,
             })?;
 
-        let context = SerializedContext::from(params.context)?;
+        let context = SerializedContext::from(params.context, params.psk)?;
+        #[cfg(feature = "payload")]
+        let context = context.with_payloads(payloads::<CS>(
+            params.payload,
+            credential_response.payload.as_deref(),
+        )?);
 
         let result = CS::KeyExchange::generate_ke3(
             rng,
@@ -596,11 +990,26 @@ impl<CS: CipherSuite> ClientLogin<CS> {
             context,
         )?;
 
+        // The payload key is only available after the server MAC was verified
+        #[cfg(feature = "payload")]
+        let server_payload = credential_response
+            .payload
+            .as_deref()
+            .map(|payload| {
+                payload::open::<KeHash<CS>>(&result.exporter_secret, STR_SERVER_PAYLOAD, payload)
+            })
+            .transpose()?;
+
         Ok(ClientLoginFinishResult {
             message: CredentialFinalization {
                 ke3_message: result.message,
+                #[cfg(feature = "payload")]
+                payload: result.payload,
             },
+            #[cfg(feature = "payload")]
+            server_payload,
             session_key: result.session_key,
+            exporter_secret: result.exporter_secret,
             export_key: opened_envelope.export_key,
             server_s_pk,
             #[cfg(test)]
@@ -642,6 +1051,47 @@ impl<CS: CipherSuite> ServerLogin<CS> {
     /// See [`ServerLogin::start()`] for the regular path. Or
     /// [`ServerLogin::builder()`] with just a remote private key.
     pub fn builder_with_key_material<'a, R: RngCore + CryptoRng, SK: Clone, OS: Clone>(
+        rng: &mut R,
+        server_setup: &ServerSetup<CS, SK, OS>,
+        key_material: GenericArray<u8, <OprfGroup<CS> as voprf::Group>::ScalarLen>,
+        password_file: Option<ServerRegistration<CS>>,
+        credential_request: CredentialRequest<CS>,
+        params: ServerLoginParameters<'a, 'a>,
+    ) -> Result<ServerLoginBuilder<'a, CS, SK>, ProtocolError> {
+        Self::builder_with_ephemeral(
+            rng,
+            server_setup,
+            key_material,
+            password_file,
+            credential_request,
+            params,
+            None,
+        )
+    }
+
+    fn builder_with_ephemeral<'a, R: RngCore + CryptoRng, SK: Clone, OS: Clone>(
+        rng: &mut R,
+        server_setup: &ServerSetup<CS, SK, OS>,
+        key_material: GenericArray<u8, <OprfGroup<CS> as voprf::Group>::ScalarLen>,
+        password_file: Option<ServerRegistration<CS>>,
+        credential_request: CredentialRequest<CS>,
+        params: ServerLoginParameters<'a, 'a>,
+        ephemeral: Option<Ephemeral<KeGroup<CS>>>,
+    ) -> Result<ServerLoginBuilder<'a, CS, SK>, ProtocolError> {
+        let prepared = Self::prepare(
+            rng,
+            server_setup,
+            key_material,
+            password_file,
+            credential_request,
+            params,
+        )?;
+
+        Self::builder_from_prepared(rng, server_setup, prepared, ephemeral)
+    }
+
+    // Runs a login up to the key exchange.
+    fn prepare<'a, R: RngCore + CryptoRng, SK: Clone, OS: Clone>(
         rng: &mut R,
         server_setup: &ServerSetup<CS, SK, OS>,
         key_material: GenericArray<u8, <OprfGroup<CS> as voprf::Group>::ScalarLen>,
@@ -650,8 +1100,11 @@ impl<CS: CipherSuite> ServerLogin<CS> {
         ServerLoginParameters {
             context,
             identifiers,
+            psk,
+            #[cfg(feature = "payload")]
+            payload,
         }: ServerLoginParameters<'a, 'a>,
-    ) -> Result<ServerLoginBuilder<'a, CS, SK>, ProtocolError> {
+    ) -> Result<PreparedServerLogin<'a, CS>, ProtocolError> {
         let record = CtOption::new(
             ServerRegistration::dummy(rng, server_setup),
             Choice::from(password_file.is_none() as u8),
@@ -660,7 +1113,9 @@ impl<CS: CipherSuite> ServerLogin<CS> {
         .unwrap_or_else(|| password_file.unwrap());
 
         let client_s_pk = record.0.client_s_pk.clone();
-        let context = SerializedContext::from(context)?;
+        let context = SerializedContext::from(context, psk)?;
+        #[cfg(feature = "payload")]
+        let context = context.with_payloads(payloads::<CS>(payload, None)?);
         let server_s_pk = server_setup.keypair.public();
 
         let mut masking_nonce = GenericArray::<_, NonceLen>::default();
@@ -685,27 +1140,75 @@ impl<CS: CipherSuite> ServerLogin<CS> {
         let server = voprf::OprfServer::new_with_key(&oprf_key).map_err(ProtocolError::from)?;
         let evaluation_element = server.blind_evaluate(&credential_request.blinded_element);
 
+        Ok(PreparedServerLogin {
+            credential_request,
+            client_s_pk,
+            context,
+            identifiers,
+            evaluation_element,
+            masking_nonce,
+            masked_response,
+            #[cfg(feature = "ksf-params")]
+            ksf_params: record.0.ksf_params.clone(),
+            #[cfg(test)]
+            oprf_key,
+        })
+    }
+
+    // Runs the key exchange of a login prepared by `ServerLogin::prepare()`.
+    fn builder_from_prepared<'a, R: RngCore + CryptoRng, SK: Clone, OS: Clone>(
+        rng: &mut R,
+        server_setup: &ServerSetup<CS, SK, OS>,
+        PreparedServerLogin {
+            credential_request,
+            client_s_pk,
+            context,
+            identifiers,
+            evaluation_element,
+            masking_nonce,
+            masked_response,
+            #[cfg(feature = "ksf-params")]
+            ksf_params,
+            #[cfg(test)]
+            oprf_key,
+        }: PreparedServerLogin<'a, CS>,
+        ephemeral: Option<Ephemeral<KeGroup<CS>>>,
+    ) -> Result<ServerLoginBuilder<'a, CS, SK>, ProtocolError> {
         let credential_response = SerializedCredentialResponse::new(
             &evaluation_element,
             masking_nonce,
             masked_response.clone(),
         );
 
-        let ke2_builder = CS::KeyExchange::ke2_builder(
-            rng,
-            credential_request.to_parts(),
-            credential_request.ke1_message.clone(),
-            credential_response,
-            client_s_pk,
-            identifiers,
-            context,
-        )?;
+        let ke2_builder = match ephemeral {
+            Some(ephemeral) => CS::KeyExchange::ke2_builder_with_ephemeral(
+                rng,
+                ephemeral,
+                credential_request.to_parts(),
+                credential_request.ke1_message.clone(),
+                credential_response,
+                client_s_pk,
+                identifiers,
+                context,
+            )?,
+            None => CS::KeyExchange::ke2_builder(
+                rng,
+                credential_request.to_parts(),
+                credential_request.ke1_message.clone(),
+                credential_response,
+                client_s_pk,
+                identifiers,
+                context,
+            )?,
+        };
 
         Ok(ServerLoginBuilder {
             server_s_sk: server_setup.keypair().private().clone(),
             evaluation_element,
             masking_nonce: Zeroizing::new(masking_nonce),
             masked_response,
+            #[cfg(feature = "ksf-params")]
+            ksf_params,
             #[cfg(test)]
             oprf_key: Zeroizing::new(oprf_key),
             ke2_builder,
@@ -750,6 +1253,10 @@ impl<CS: CipherSuite> ServerLogin<CS> {
             masking_nonce: *builder.masking_nonce.deref(),
             masked_response: builder.masked_response.clone(),
             ke2_message: result.message,
+            #[cfg(feature = "ksf-params")]
+            ksf_params: builder.ksf_params.clone(),
+            #[cfg(feature = "payload")]
+            payload: result.payload,
         };
 
         Ok(ServerLoginStartResult {
@@ -793,6 +1300,148 @@ impl<CS: CipherSuite> ServerLogin<CS> {
         Self::build(builder, input)
     }
 
+    /// Like [`ServerLogin::start()`], but with a precomputed [`Ephemeral`],
+    /// e.g. taken from an [`EphemeralPool`](crate::EphemeralPool), instead of
+    /// generating the ephemeral key pair and nonce on the spot
+    ///
+    /// The `ephemeral` is consumed, so that it is only used for a single login.
+    ///
+    /// # Errors
+    /// [`ProtocolError::UnsupportedEphemeralError`] if the key exchange doesn't
+    /// support precomputed ephemerals, e.g. [`SigmaI`](crate::SigmaI).
+    pub fn start_with_ephemeral<R: RngCore + CryptoRng>(
+        rng: &mut R,
+        server_setup: &ServerSetup<CS>,
+        password_file: Option<ServerRegistration<CS>>,
+        credential_request: CredentialRequest<CS>,
+        credential_identifier: &[u8],
+        parameters: ServerLoginParameters,
+        ephemeral: Ephemeral<KeGroup<CS>>,
+    ) -> Result<ServerLoginStartResult<CS>, ProtocolError> {
+        let KeyMaterialInfo {
+            ikm: oprf_seed,
+            info,
+        } = server_setup.key_material_info(credential_identifier);
+        let key_material = oprf_key_material::<CS>(&oprf_seed.0, &info)?;
+
+        let builder = Self::builder_with_ephemeral(
+            rng,
+            server_setup,
+            key_material,
+            password_file,
+            credential_request,
+            parameters,
+            Some(ephemeral),
+        )?;
+        let input = CS::KeyExchange::generate_ke2_input(
+            &builder.ke2_builder,
+            rng,
+            server_setup.keypair.private(),
+        );
+
+        Self::build(builder, input)
+    }
+
+    /// Runs [`ServerLogin::start()`] for many `requests` at once, returning
+    /// their results in the same order
+    ///
+    /// The results are byte-identical to calling [`ServerLogin::start()`] for
+    /// every request in turn with the same `rng`. The only work shared between
+    /// the requests is the serialization of the ephemeral public keys: if the
+    /// key exchange allows it, see
+    /// [`KeyExchange::DETERMINISTIC_WITH_EPHEMERAL`], they are serialized
+    /// together with [`Group::serialize_pks()`], which backends with batched
+    /// normalization implement with a single field inversion. The OPRF
+    /// evaluation, the scalar multiplications and the key exchange still run
+    /// once per request, so the speed-up over [`ServerLogin::start()`] is
+    /// modest and limited to those backends. Otherwise the requests are simply
+    /// processed one after the other.
+    #[cfg(feature = "batch")]
+    pub fn start_batch<
+        'a,
+        R: RngCore + CryptoRng,
+        I: IntoIterator<Item = ServerLoginRequest<'a, CS>>,
+    >(
+        rng: &mut R,
+        server_setup: &ServerSetup<CS>,
+        requests: I,
+    ) -> Vec<Result<ServerLoginStartResult<CS>, ProtocolError>> {
+        if !CS::KeyExchange::DETERMINISTIC_WITH_EPHEMERAL {
+            return requests
+                .into_iter()
+                .map(|request| {
+                    Self::start(
+                        rng,
+                        server_setup,
+                        request.password_file,
+                        request.credential_request,
+                        request.credential_identifier,
+                        request.parameters,
+                    )
+                })
+                .collect();
+        }
+
+        // Draw the randomness of every request in the same order as `start()`,
+        // but defer computing the ephemeral public keys. Failed requests keep
+        // their index to be merged back in order.
+        let mut errors = Vec::new();
+        let mut secrets = Vec::new();
+
+        for (index, request) in requests.into_iter().enumerate() {
+            let KeyMaterialInfo {
+                ikm: oprf_seed,
+                info,
+            } = server_setup.key_material_info(request.credential_identifier);
+            let result = oprf_key_material::<CS>(&oprf_seed.0, &info)
+                .map_err(ProtocolError::from)
+                .and_then(|key_material| {
+                    Self::prepare(
+                        rng,
+                        server_setup,
+                        key_material,
+                        request.password_file,
+                        request.credential_request,
+                        request.parameters,
+                    )
+                });
+
+            match result {
+                Ok(prepared) => {
+                    let (sk, nonce) = Ephemeral::random_secret(rng);
+                    secrets.push(((index, prepared), sk, nonce));
+                }
+                Err(error) => errors.push((index, error)),
+            }
+        }
+
+        let mut errors = errors.into_iter().peekable();
+        let mut results = Vec::with_capacity(secrets.len() + errors.len());
+
+        for ((index, prepared), ephemeral) in Ephemeral::from_secrets(secrets) {
+            while let Some((_, error)) = errors.next_if(|(error_index, _)| *error_index < index) {
+                results.push(Err(error));
+            }
+
+            results.push(
+                Self::builder_from_prepared(rng, server_setup, prepared, Some(ephemeral)).and_then(
+                    |builder| {
+                        let input = CS::KeyExchange::generate_ke2_input(
+                            &builder.ke2_builder,
+                            rng,
+                            server_setup.keypair.private(),
+                        );
+
+                        Self::build(builder, input)
+                    },
+                ),
+            );
+        }
+
+        results.extend(errors.map(|(_, error)| Err(error)));
+        results
+    }
+
     /// From the client's second and final message, check the client's
     /// authentication and produce a message transport
     pub fn finish(
@@ -800,17 +1449,32 @@ impl<CS: CipherSuite> ServerLogin<CS> {
         message: CredentialFinalization<CS>,
         parameters: ServerLoginParameters,
     ) -> Result<ServerLoginFinishResult<CS>, ProtocolError> {
-        let context = SerializedContext::from(parameters.context)?;
+        let context = SerializedContext::from(parameters.context, parameters.psk)?;
+        #[cfg(feature = "payload")]
+        let context = context.with_payloads(payloads::<CS>(None, message.payload.as_deref())?);
 
-        let session_key = <CS::KeyExchange as KeyExchange>::finish_ke(
+        let result = <CS::KeyExchange as KeyExchange>::finish_ke(
             &self.ke2_state,
             message.ke3_message,
             parameters.identifiers,
             context,
         )?;
 
+        // The payload key is only available after the client MAC was verified
+        #[cfg(feature = "payload")]
+        let client_payload = message
+            .payload
+            .as_deref()
+            .map(|payload| {
+                payload::open::<KeHash<CS>>(&result.exporter_secret, STR_CLIENT_PAYLOAD, payload)
+            })
+            .transpose()?;
+
         Ok(ServerLoginFinishResult {
-            session_key,
+            session_key: result.session_key,
+            #[cfg(feature = "payload")]
+            client_payload,
+            exporter_secret: result.exporter_secret,
             #[cfg(test)]
             state: self,
         })
@@ -831,6 +1495,24 @@ pub struct Identifiers<'a> {
     pub server: Option<&'a [u8]>,
 }
 
+/// An external pre-shared key, which is input into the key schedule together
+/// with the shared secrets of the key exchange. The resulting session is secure
+/// as long as either the password or the key is uncompromised.
+///
+/// The `identity` is committed to in the transcript, the `key` is never sent.
+/// Client and server have to use the same pre-shared key, otherwise the login
+/// fails with [`ProtocolError::InvalidLoginError`].
+#[derive(Clone, Copy)]
+#[derive_where(Debug)]
+pub struct PreSharedKey<'a> {
+    /// Identifies the key to the server
+    pub identity: &'a [u8],
+    /// The key, which should contain at least as many bytes of entropy as the
+    /// security level of the ciphersuite
+    #[derive_where(skip)]
+    pub key: &'a [u8],
+}
+
 /// Optional parameters for client registration finish
 #[derive_where(Clone, Default)]
 pub struct ClientRegistrationFinishParameters<'i, 'h, CS: CipherSuite> {
@@ -898,6 +1580,19 @@ pub struct ClientLoginStartResult<CS: CipherSuite> {
     pub state: ClientLogin<CS>,
 }
 
+/// The randomized password derived from the password and the server's answer
+/// by [`ClientLogin::derive_randomized_password()`], zeroized on drop
+#[derive_where(Clone, ZeroizeOnDrop)]
+pub struct ClientLoginRandomizedPassword<CS: CipherSuite> {
+    randomized_pwd: Output<OprfHash<CS>>,
+    // The parts of the `CredentialResponse` the randomized password was
+    // derived from
+    #[derive_where(skip(Zeroize))]
+    evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
+    #[derive_where(skip(Zeroize))]
+    masking_nonce: GenericArray<u8, NonceLen>,
+}
+
 /// Optional parameters for client login finish
 #[derive_where(Clone, Default)]
 pub struct ClientLoginFinishParameters<'c, 'i, 'h, CS: CipherSuite> {
@@ -908,6 +1603,17 @@ pub struct ClientLoginFinishParameters<'c, 'i, 'h, CS: CipherSuite> {
     pub identifiers: Identifiers<'i>,
     /// Specifying a configuration for the key stretching hash
     pub ksf: Option<&'h CS::Ksf>,
+    /// Specifying a pre-shared key that the server must agree on, see
+    /// [`Self::with_psk()`]
+    psk: Option<PreSharedKey<'c>>,
+    /// Specifying a payload to send to the server, see
+    /// [`Self::with_payload()`]
+    #[cfg(feature = "payload")]
+    payload: Option<&'c [u8]>,
+    /// Specifying the most expensive KSF parameters to accept from the server,
+    /// see [`Self::with_ksf_limits()`]
+    #[cfg(feature = "ksf-params")]
+    ksf_limits: Option<&'h CS::Ksf>,
 }
 
 impl<'c, 'i, 'h, CS: CipherSuite> ClientLoginFinishParameters<'c, 'i, 'h, CS> {
@@ -921,8 +1627,43 @@ impl<'c, 'i, 'h, CS: CipherSuite> ClientLoginFinishParameters<'c, 'i, 'h, CS> {
             context,
             identifiers,
             ksf,
+            psk: None,
+            #[cfg(feature = "payload")]
+            payload: None,
+            #[cfg(feature = "ksf-params")]
+            ksf_limits: None,
         }
     }
+
+    /// Specifies a [`PreSharedKey`] that the server must agree on
+    pub fn with_psk(mut self, psk: PreSharedKey<'c>) -> Self {
+        self.psk = Some(psk);
+        self
+    }
+
+    /// Specifies a payload to encrypt to the server and send with the
+    /// [`CredentialFinalization`]. The server receives it in
+    /// [`ServerLoginFinishResult::client_payload`]. Payloads longer than
+    /// [`MAX_PAYLOAD_LEN`](crate::MAX_PAYLOAD_LEN) fail with
+    /// [`InternalError::PayloadError`].
+    #[cfg(feature = "payload")]
+    pub fn with_payload(mut self, payload: &'c [u8]) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    /// Specifies the most expensive KSF the client computes with the
+    /// parameters received in the [`CredentialResponse`], which the server
+    /// can choose before it is authenticated. Defaults to the costs of
+    /// [`Default::default()`] of [`CipherSuite::Ksf`].
+    ///
+    /// Parameters exceeding `ksf_limits` fail with
+    /// [`ProtocolError::KsfLimitError`]. Only used if [`Self::ksf`] is not set.
+    #[cfg(feature = "ksf-params")]
+    pub fn with_ksf_limits(mut self, ksf_limits: &'h CS::Ksf) -> Self {
+        self.ksf_limits = Some(ksf_limits);
+        self
+    }
 }
 
 /// Contains the fields that are returned by a client login finish
@@ -932,6 +1673,12 @@ pub struct ClientLoginFinishResult<CS: CipherSuite> {
     pub message: CredentialFinalization<CS>,
     /// The session key
     pub session_key: Output<KeHash<CS>>,
+    /// Secret for [`Self::export_keying_material()`]
+    pub(crate) exporter_secret: Output<KeHash<CS>>,
+    /// The decrypted payload the server sent with
+    /// [`ServerLoginParameters::with_payload()`], if any
+    #[cfg(feature = "payload")]
+    pub server_payload: Option<Vec<u8>>,
     /// The client-side export key
     pub export_key: Output<OprfHash<CS>>,
     /// The server's static public key
@@ -954,12 +1701,58 @@ pub struct ClientLoginFinishResult<CS: CipherSuite> {
 pub struct ServerLoginFinishResult<CS: CipherSuite> {
     /// The session key between client and server
     pub session_key: Output<KeHash<CS>>,
+    /// Secret for [`Self::export_keying_material()`]
+    pub(crate) exporter_secret: Output<KeHash<CS>>,
+    /// The decrypted payload the client sent with
+    /// [`ClientLoginFinishParameters::with_payload()`], if any
+    #[cfg(feature = "payload")]
+    pub client_payload: Option<Vec<u8>>,
     /// Instance of the `ClientRegistration`, only used in tests for checking
     /// zeroize
     #[cfg(test)]
     pub state: ServerLogin<CS>,
 }
 
+impl<CS: CipherSuite> ClientLoginFinishResult<CS> {
+    /// Derives keying material of `output.len()` bytes, bound to `label` and
+    /// `context`, similar to the TLS 1.3 exporter. The server derives the same
+    /// output with [`ServerLoginFinishResult::export_keying_material()`].
+    ///
+    /// Different labels produce independent keys, which is preferable to
+    /// hashing the `session_key` for each use.
+    ///
+    /// # Errors
+    /// [`ProtocolError::SerializationError`] if `label` is longer than 248
+    /// bytes or [`InternalError::HkdfError`] if `output` is longer than 255
+    /// times the hash output size.
+    pub fn export_keying_material(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), ProtocolError> {
+        shared::export_keying_material::<KeHash<CS>>(&self.exporter_secret, label, context, output)
+    }
+}
+
+impl<CS: CipherSuite> ServerLoginFinishResult<CS> {
+    /// Derives keying material of `output.len()` bytes, bound to `label` and
+    /// `context`. See [`ClientLoginFinishResult::export_keying_material()`].
+    ///
+    /// # Errors
+    /// [`ProtocolError::SerializationError`] if `label` is longer than 248
+    /// bytes or [`InternalError::HkdfError`] if `output` is longer than 255
+    /// times the hash output size.
+    pub fn export_keying_material(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), ProtocolError> {
+        shared::export_keying_material::<KeHash<CS>>(&self.exporter_secret, label, context, output)
+    }
+}
+
 /// Optional parameters for server login start and finish
 #[derive(Clone, Debug, Default)]
 pub struct ServerLoginParameters<'c, 'i> {
@@ -968,6 +1761,83 @@ pub struct ServerLoginParameters<'c, 'i> {
     /// Specifying a user identifier and server identifier that will be matched
     /// against the client
     pub identifiers: Identifiers<'i>,
+    /// Specifying a pre-shared key that the client must agree on, see
+    /// [`Self::with_psk()`]
+    psk: Option<PreSharedKey<'c>>,
+    /// Specifying a payload to send to the client, see
+    /// [`Self::with_payload()`]
+    #[cfg(feature = "payload")]
+    payload: Option<&'c [u8]>,
+}
+
+impl<'c, 'i> ServerLoginParameters<'c, 'i> {
+    /// Create a new [`ServerLoginParameters`]
+    pub fn new(context: Option<&'c [u8]>, identifiers: Identifiers<'i>) -> Self {
+        Self {
+            context,
+            identifiers,
+            psk: None,
+            #[cfg(feature = "payload")]
+            payload: None,
+        }
+    }
+
+    /// Specifies a [`PreSharedKey`] that the client must agree on
+    pub fn with_psk(mut self, psk: PreSharedKey<'c>) -> Self {
+        self.psk = Some(psk);
+        self
+    }
+
+    /// Specifies a payload to encrypt to the client and send with the
+    /// [`CredentialResponse`]. The client receives it in
+    /// [`ClientLoginFinishResult::server_payload`] after verifying the server.
+    /// Payloads longer than [`MAX_PAYLOAD_LEN`](crate::MAX_PAYLOAD_LEN)
+    /// fail with [`InternalError::PayloadError`].
+    ///
+    /// Only used by [`ServerLogin::start()`] and its variants. The client is
+    /// not yet authenticated at this point: anyone who knows the password can
+    /// decrypt the payload.
+    #[cfg(feature = "payload")]
+    pub fn with_payload(mut self, payload: &'c [u8]) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+}
+
+/// A request to [`ServerLogin::start_batch()`], with the arguments of
+/// [`ServerLogin::start()`]
+#[cfg(feature = "batch")]
+#[derive_where(Clone)]
+#[derive_where(
+    Debug;
+    <KeGroup<CS> as Group>::Pk,
+    voprf::BlindedElement<CS::OprfCs>,
+    <CS::KeyExchange as KeyExchange>::KE1Message,
+)]
+pub struct ServerLoginRequest<'a, CS: CipherSuite> {
+    /// The password file of the user, `None` if the user is not registered
+    pub password_file: Option<ServerRegistration<CS>>,
+    /// The message sent by the client
+    pub credential_request: CredentialRequest<CS>,
+    /// The identifier the password file was registered with
+    pub credential_identifier: &'a [u8],
+    /// The parameters of the login
+    pub parameters: ServerLoginParameters<'a, 'a>,
+}
+
+// A login run up to the key exchange by `ServerLogin::prepare()`.
+struct PreparedServerLogin<'a, CS: CipherSuite> {
+    credential_request: CredentialRequest<CS>,
+    client_s_pk: PublicKey<KeGroup<CS>>,
+    context: SerializedContext<'a>,
+    identifiers: SerializedIdentifiers<'a, KeGroup<CS>>,
+    evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
+    masking_nonce: GenericArray<u8, NonceLen>,
+    masked_response: MaskedResponse<CS>,
+    #[cfg(feature = "ksf-params")]
+    ksf_params: Option<KsfParams>,
+    #[cfg(test)]
+    oprf_key: GenericArray<u8, <OprfGroup<CS> as voprf::Group>::ScalarLen>,
 }
 
 /// Contains the fields that are returned by a server login start
@@ -1001,25 +1871,41 @@ pub struct ServerLoginStartResult<CS: CipherSuite> {
 ////////////////////////////////////////////////
 
 // Helper functions
+
+// Collects the payloads of a login, if the key exchange can authenticate them
+#[cfg(feature = "payload")]
+fn payloads<'a, CS: CipherSuite>(
+    send: Option<&'a [u8]>,
+    received: Option<&'a [u8]>,
+) -> Result<Payloads<'a>, ProtocolError> {
+    if !CS::KeyExchange::AUTHENTICATES_PAYLOADS && (send.is_some() || received.is_some()) {
+        return Err(ProtocolError::UnsupportedPayloadError);
+    }
+
+    Ok(Payloads { send, received })
+}
+
 #[allow(clippy::type_complexity)]
 fn get_password_derived_key<CS: CipherSuite>(
     input: &[u8],
     oprf_client: voprf::OprfClient<CS::OprfCs>,
     evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
     ksf: Option<&CS::Ksf>,
+    identifiers: Identifiers,
 ) -> Result<(Output<OprfHash<CS>>, Hkdf<OprfHash<CS>>), ProtocolError> {
     let oprf_output = oprf_client.finalize(input, &evaluation_element)?;
 
     let hardened_output = if let Some(ksf) = ksf {
-        ksf.hash(oprf_output.clone())
+        ksf.hash_with_identifiers(oprf_output.clone(), identifiers)
     } else {
-        CS::Ksf::default().hash(oprf_output.clone())
+        CS::Ksf::default().hash_with_identifiers(oprf_output.clone(), identifiers)
     }
     .map_err(ProtocolError::from)?;
 
     let mut hkdf = HkdfExtract::<OprfHash<CS>>::new(None);
     hkdf.input_ikm(&oprf_output);
     hkdf.input_ikm(&hardened_output);
+
     Ok(hkdf.finalize())
 }
 
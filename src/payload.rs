@@ -0,0 +1,93 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Encrypted application payloads carried by
+//! [`CredentialResponse`](crate::CredentialResponse) and
+//! [`CredentialFinalization`](crate::CredentialFinalization).
+
+use alloc::vec::Vec;
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use digest::core_api::BlockSizeUser;
+use generic_array::typenum::{IsLess, Le, NonZero, U256};
+use zeroize::Zeroizing;
+
+use crate::errors::{InternalError, ProtocolError};
+use crate::hash::{Hash, ProxyHash};
+use crate::key_exchange::shared;
+
+// Constant strings used as labels for deriving the payload keys
+pub(crate) const STR_SERVER_PAYLOAD: &[u8] = b"ServerPayload";
+pub(crate) const STR_CLIENT_PAYLOAD: &[u8] = b"ClientPayload";
+
+const TAG_LEN: usize = 16;
+
+/// The maximum length of a payload in bytes, so that it fits into the two byte
+/// length of its extension together with the tag
+pub const MAX_PAYLOAD_LEN: usize = u16::MAX as usize - TAG_LEN;
+
+// Encrypts `plaintext`, of at most `MAX_PAYLOAD_LEN` bytes, under the key
+// derived from `exporter_secret` and `label`. The output is the ciphertext
+// followed by the tag. Each key encrypts only the
+// single payload sent in one direction of a login, so the nonce is fixed.
+pub(crate) fn seal<H: Hash>(
+    exporter_secret: &[u8],
+    label: &'static [u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    if plaintext.len() > MAX_PAYLOAD_LEN {
+        return Err(InternalError::PayloadError.into());
+    }
+
+    let key = Zeroizing::new(shared::derive_payload_key::<H>(exporter_secret, label)?);
+
+    ChaCha20Poly1305::new(&key)
+        .encrypt(
+            &Nonce::default(),
+            Payload {
+                msg: plaintext,
+                aad: label,
+            },
+        )
+        .map_err(|_| InternalError::PayloadError.into())
+}
+
+// Decrypts a payload produced by `seal()`. Any failure is reported as an
+// `InvalidLoginError`, as the payload is part of the authenticated login.
+pub(crate) fn open<H: Hash>(
+    exporter_secret: &[u8],
+    label: &'static [u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, ProtocolError>
+where
+    H::Core: ProxyHash,
+    <H::Core as BlockSizeUser>::BlockSize: IsLess<U256>,
+    Le<<H::Core as BlockSizeUser>::BlockSize, U256>: NonZero,
+{
+    if payload.len() < TAG_LEN {
+        return Err(ProtocolError::InvalidLoginError);
+    }
+
+    let key = Zeroizing::new(shared::derive_payload_key::<H>(exporter_secret, label)?);
+
+    ChaCha20Poly1305::new(&key)
+        .decrypt(
+            &Nonce::default(),
+            Payload {
+                msg: payload,
+                aad: label,
+            },
+        )
+        .map_err(|_| ProtocolError::InvalidLoginError)
+}
@@ -0,0 +1,211 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Encrypted storage of [`ServerSetup`](crate::ServerSetup) at rest
+//!
+//! [`ServerSetup::seal_with_key()`](crate::ServerSetup::seal_with_key) and
+//! [`ServerSetup::seal_with_passphrase()`](crate::ServerSetup::seal_with_passphrase)
+//! encrypt the [serialized](crate::ServerSetup::serialize) server setup with
+//! XChaCha20-Poly1305 under a key-encryption key or under a key derived from a
+//! passphrase with Argon2id. The output consists of:
+//! - the [`MAGIC`] value,
+//! - the format [`VERSION`],
+//! - the [`KeyDerivation`] identifier,
+//! - the two byte big-endian ciphersuite identifier
+//!   [`CipherSuite::SUITE_ID`](crate::CipherSuite::SUITE_ID),
+//! - for [`KeyDerivation::Argon2id`], the memory cost, the number of
+//!   iterations and the degree of parallelism as four byte big-endian integers
+//!   followed by a 16 byte salt,
+//! - a 24 byte random nonce,
+//! - the ciphertext and tag.
+//!
+//! Everything preceding the ciphertext is authenticated as associated data, so
+//! any modification is detected when opening. The Argon2id parameters have to
+//! be read before they can be authenticated, which is why
+//! [`ServerSetup::open_with_passphrase()`](crate::ServerSetup::open_with_passphrase)
+//! rejects parameters exceeding the limits of the caller before deriving the
+//! key.
+
+use alloc::vec::Vec;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use generic_array::typenum::{U4, U8, U16, U24};
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::errors::{InternalError, ProtocolError};
+use crate::serialization::SliceExt;
+
+/// The magic value every sealed encoding starts with
+pub const MAGIC: [u8; 4] = *b"OPQS";
+
+/// The version of the sealed encoding
+pub const VERSION: u8 = 1;
+
+const SALT_LEN: usize = 16;
+
+/// The derivation of the encryption key of a sealed encoding
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum KeyDerivation {
+    /// The key-encryption key is used as is
+    Key = 1,
+    /// The key is derived from a passphrase with Argon2id
+    Argon2id = 2,
+}
+
+/// The key material to open with
+pub(crate) enum SealKey<'a> {
+    Key(&'a [u8; 32]),
+    // The passphrase and the largest accepted Argon2id parameters
+    Passphrase(&'a [u8], &'a Params),
+}
+
+// Encrypts `plaintext` under the key-encryption key `key`.
+pub(crate) fn seal_with_key<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    key: &[u8; 32],
+    suite_id: u16,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    let header = header(KeyDerivation::Key, suite_id);
+
+    encrypt(rng, Key::from_slice(key), header, plaintext)
+}
+
+// Encrypts `plaintext` under a key derived from `passphrase`.
+pub(crate) fn seal_with_passphrase<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    passphrase: &[u8],
+    params: &Params,
+    suite_id: u16,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    let mut salt = [0; SALT_LEN];
+    rng.fill_bytes(&mut salt);
+
+    let mut header = header(KeyDerivation::Argon2id, suite_id);
+    header.extend_from_slice(&params.m_cost().to_be_bytes());
+    header.extend_from_slice(&params.t_cost().to_be_bytes());
+    header.extend_from_slice(&params.p_cost().to_be_bytes());
+    header.extend_from_slice(&salt);
+
+    let key = derive_key(passphrase, params.clone(), &salt)?;
+
+    encrypt(rng, &key, header, plaintext)
+}
+
+fn header(key_derivation: KeyDerivation, suite_id: u16) -> Vec<u8> {
+    let mut header = Vec::new();
+    header.extend_from_slice(&MAGIC);
+    header.push(VERSION);
+    header.push(key_derivation as u8);
+    header.extend_from_slice(&suite_id.to_be_bytes());
+
+    header
+}
+
+// Appends a random nonce to `header` and then the ciphertext of `plaintext`,
+// authenticating the whole header.
+fn encrypt<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    key: &Key,
+    mut header: Vec<u8>,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ProtocolError> {
+    let mut nonce = XNonce::default();
+    rng.fill_bytes(&mut nonce);
+    header.extend_from_slice(&nonce);
+
+    let ciphertext = XChaCha20Poly1305::new(key)
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad: &header,
+            },
+        )
+        .map_err(|_| InternalError::SealError)?;
+
+    header.extend_from_slice(&ciphertext);
+
+    Ok(header)
+}
+
+// Checks the header of `input` and decrypts it.
+pub(crate) fn open(
+    key: SealKey<'_>,
+    suite_id: u16,
+    input: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, ProtocolError> {
+    let mut rest = input;
+    let header = rest.take_array::<U8>("sealed header")?;
+
+    if header[..4] != MAGIC {
+        return Err(ProtocolError::InvalidMagicError);
+    }
+
+    if header[4] != VERSION {
+        return Err(ProtocolError::UnsupportedVersionError(header[4]));
+    }
+
+    let actual = u16::from_be_bytes([header[6], header[7]]);
+
+    if actual != suite_id {
+        return Err(ProtocolError::CipherSuiteMismatchError {
+            expected: suite_id,
+            actual,
+        });
+    }
+
+    let key = match (key, header[5]) {
+        (SealKey::Key(key), id) if id == KeyDerivation::Key as u8 => {
+            Zeroizing::new(*Key::from_slice(key))
+        }
+        (SealKey::Passphrase(passphrase, limits), id) if id == KeyDerivation::Argon2id as u8 => {
+            let m_cost = u32::from_be_bytes(rest.take_array::<U4>("memory cost")?.into());
+            let t_cost = u32::from_be_bytes(rest.take_array::<U4>("iterations")?.into());
+            let p_cost = u32::from_be_bytes(rest.take_array::<U4>("parallelism")?.into());
+            let salt = rest.take_array::<U16>("salt")?;
+
+            if m_cost > limits.m_cost() || t_cost > limits.t_cost() || p_cost > limits.p_cost() {
+                return Err(ProtocolError::KsfLimitError);
+            }
+
+            let params = Params::new(m_cost, t_cost, p_cost, Some(32))
+                .map_err(|_| ProtocolError::SerializationError)?;
+
+            derive_key(passphrase, params, &salt)?
+        }
+        _ => return Err(ProtocolError::SerializationError),
+    };
+
+    let nonce = rest.take_array::<U24>("nonce")?;
+    let aad = &input[..input.len() - rest.len()];
+
+    XChaCha20Poly1305::new(&key)
+        .decrypt(&nonce, Payload { msg: rest, aad })
+        .map(Zeroizing::new)
+        .map_err(|_| ProtocolError::SealOpenError)
+}
+
+fn derive_key(
+    passphrase: &[u8],
+    params: Params,
+    salt: &[u8],
+) -> Result<Zeroizing<Key>, ProtocolError> {
+    let mut key = Zeroizing::new(Key::default());
+
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase, salt, &mut key)
+        .map_err(|_| InternalError::KsfError)?;
+
+    Ok(key)
+}
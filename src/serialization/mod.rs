@@ -6,6 +6,8 @@
 // of this source tree. You may select, at your option, one of the above-listed
 // licenses.
 
+#[cfg(any(feature = "ksf-params", feature = "payload"))]
+use alloc::vec::Vec;
 use core::ops::Add;
 
 use digest::Update;
@@ -94,6 +96,86 @@ impl SliceExt for [u8] {
     }
 }
 
+/// Tag of the extension carrying the [`KsfParams`](crate::ksf::KsfParams)
+#[cfg(feature = "ksf-params")]
+pub(crate) const EXTENSION_KSF_PARAMS: u16 = 1;
+/// Tag of the extension carrying an encrypted application payload
+#[cfg(feature = "payload")]
+pub(crate) const EXTENSION_PAYLOAD: u16 = 2;
+
+// Appends the extension with the tag `tag` and the value `value`, if any, to
+// `output`, see `Extensions`. The value must not exceed `u16::MAX` bytes and
+// extensions have to be appended in the order of their tags.
+#[cfg(any(feature = "ksf-params", feature = "payload"))]
+pub(crate) fn with_extension(mut output: Vec<u8>, tag: u16, value: Option<&[u8]>) -> Vec<u8> {
+    if let Some(value) = value {
+        output.extend_from_slice(&tag.to_be_bytes());
+        // Checked by `KsfParams::new()` and `payload::seal()`
+        output.extend_from_slice(&(value.len() as u16).to_be_bytes());
+        output.extend_from_slice(value);
+    }
+
+    output
+}
+
+// Iterates over the extensions following a message. Each one consists of the
+// two byte big-endian tag, the two byte big-endian length of the value and the
+// value. Tags have to be strictly increasing, so every extension appears at
+// most once and the encoding is unique. Truncated extensions are an error.
+//
+// The encoding does not depend on the enabled features, callers reject tags
+// they do not support with `ProtocolError::UnsupportedExtensionError`.
+pub(crate) struct Extensions<'a> {
+    input: &'a [u8],
+    previous: Option<u16>,
+}
+
+impl<'a> Extensions<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            previous: None,
+        }
+    }
+
+    fn take(&mut self) -> Result<(u16, &'a [u8]), ProtocolError> {
+        let [tag_high, tag_low, len_high, len_low, input @ ..] = self.input else {
+            return Err(ProtocolError::SerializationError);
+        };
+        let tag = u16::from_be_bytes([*tag_high, *tag_low]);
+        let len = usize::from(u16::from_be_bytes([*len_high, *len_low]));
+
+        if self.previous >= Some(tag) || input.len() < len {
+            return Err(ProtocolError::SerializationError);
+        }
+
+        let (value, rest) = input.split_at(len);
+        self.input = rest;
+        self.previous = Some(tag);
+
+        Ok((tag, value))
+    }
+}
+
+impl<'a> Iterator for Extensions<'a> {
+    type Item = Result<(u16, &'a [u8]), ProtocolError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let extension = self.take();
+
+        // Stop after the first error
+        if extension.is_err() {
+            self.input = &[];
+        }
+
+        Some(extension)
+    }
+}
+
 pub(crate) trait GenericArrayExt<O: ArrayLength<u8>> {
     type Output: ArrayLength<u8>;
 
@@ -119,6 +201,8 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,728 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! `serde` implementations of the messages and server records.
+//!
+//! Human-readable formats receive the [framed encoding](crate::framing),
+//! tagged with [`CipherSuite::SUITE_ID`], as an unpadded base64url string.
+//! Other formats receive the same compact representation as the previously
+//! derived implementations, which the `*Def` types below mirror. To read
+//! records stored before, human-readable formats still accept the derived
+//! representation, e.g. JSON objects with arrays of integers.
+
+#[cfg(feature = "payload")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::Add;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use digest::Output;
+#[cfg(not(feature = "alloc"))]
+use generic_array::sequence::Concat;
+use generic_array::typenum::Sum;
+use generic_array::{ArrayLength, GenericArray};
+use serde::Serializer;
+use serde::de::{self, Deserializer, Visitor};
+use zeroize::Zeroizing;
+
+use crate::ciphersuite::{CipherSuite, KeGroup, OprfGroup, OprfHash};
+use crate::envelope::{Envelope, EnvelopeLen};
+use crate::errors::ProtocolError;
+#[cfg(not(feature = "alloc"))]
+use crate::framing::{self, MessageType};
+use crate::framing::{FrameHeaderLen, FramedLen};
+use crate::hash::OutputSize;
+use crate::key_exchange::group::Group;
+use crate::key_exchange::shared::NonceLen;
+use crate::key_exchange::{Deserialize, Ke1MessageLen, Ke2MessageLen, KeyExchange, Serialize};
+use crate::keypair::{KeyPair, OprfSeedSerialization, PrivateKeySerialization, PublicKey};
+#[cfg(feature = "ksf-params")]
+use crate::ksf::KsfParams;
+use crate::messages::{
+    CredentialFinalizationLen, CredentialRequestLen, CredentialResponseLen,
+    CredentialResponseWithoutKeLen, RegistrationRequestLen, RegistrationResponseLen,
+    RegistrationUploadLen,
+};
+use crate::opaque::{MaskedResponse, MaskedResponseLen, ServerRegistrationLen, ServerSetupLen};
+use crate::{
+    CredentialFinalization, CredentialRequest, CredentialResponse, RegistrationRequest,
+    RegistrationResponse, RegistrationUpload, ServerRegistration, ServerSetup,
+};
+
+// Number of bytes encoded at once, a multiple of 3 so that no padding is
+// required in between
+const CHUNK_LEN: usize = 48;
+
+struct Base64Url<'a>(&'a [u8]);
+
+impl Display for Base64Url<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buffer = Zeroizing::new([0; CHUNK_LEN / 3 * 4]);
+
+        for chunk in self.0.chunks(CHUNK_LEN) {
+            let encoded =
+                Base64UrlUnpadded::encode(chunk, buffer.as_mut()).map_err(|_| fmt::Error)?;
+            f.write_str(encoded)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn serialize_str<S: Serializer>(serializer: S, bytes: &[u8]) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&Base64Url(bytes))
+}
+
+// Deserializes the representation of the previously derived implementations
+trait DeserializeDerived<'de>: Sized {
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+struct StrVisitor<F, T>(F, PhantomData<T>);
+
+impl<'de, F, T, E> Visitor<'de> for StrVisitor<F, T>
+where
+    F: FnOnce(&str) -> Result<T, ProtocolError<E>>,
+    T: DeserializeDerived<'de>,
+    E: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a base64url string")
+    }
+
+    fn visit_str<DE: de::Error>(self, input: &str) -> Result<T, DE> {
+        (self.0)(input).map_err(DE::custom)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        T::deserialize_derived(de::value::MapAccessDeserializer::new(map))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<T, A::Error> {
+        T::deserialize_derived(de::value::SeqAccessDeserializer::new(seq))
+    }
+}
+
+// Decodes the string into a buffer of `L` bytes before passing it on to
+// `deserialize_framed`, or falls back to the derived representation
+fn deserialize_str<'de, D, L, T, E>(
+    deserializer: D,
+    deserialize_framed: impl FnOnce(&[u8]) -> Result<T, ProtocolError<E>>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    L: ArrayLength<u8>,
+    T: DeserializeDerived<'de>,
+    E: Display,
+{
+    deserializer.deserialize_any(StrVisitor(
+        |input: &str| {
+            let mut buffer = Zeroizing::new(GenericArray::<u8, L>::default());
+            let bytes = Base64UrlUnpadded::decode(input, &mut buffer)
+                .map_err(|_| ProtocolError::SerializationError)?;
+            deserialize_framed(bytes)
+        },
+        PhantomData,
+    ))
+}
+
+// Messages and records with extensions have no upper bound on their length
+#[cfg(feature = "alloc")]
+fn deserialize_str_vec<'de, D, T>(
+    deserializer: D,
+    deserialize_framed: impl FnOnce(&[u8]) -> Result<T, ProtocolError>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeDerived<'de>,
+{
+    deserializer.deserialize_any(StrVisitor(
+        |input: &str| {
+            let bytes = Zeroizing::new(
+                Base64UrlUnpadded::decode_vec(input)
+                    .map_err(|_| ProtocolError::SerializationError)?,
+            );
+            deserialize_framed(&bytes)
+        },
+        PhantomData,
+    ))
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "RegistrationRequest",
+    rename = "RegistrationRequest",
+    bound = ""
+)]
+struct RegistrationRequestDef<CS: CipherSuite> {
+    blinded_element: voprf::BlindedElement<CS::OprfCs>,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for RegistrationRequest<CS> {
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RegistrationRequestDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for RegistrationRequest<CS>
+where
+    // FramedRegistrationRequest: FrameHeader + RegistrationRequest
+    FrameHeaderLen: Add<RegistrationRequestLen<CS>>,
+    FramedLen<RegistrationRequestLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_str(serializer, &self.serialize_framed(CS::SUITE_ID))
+        } else {
+            RegistrationRequestDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for RegistrationRequest<CS>
+where
+    // FramedRegistrationRequest: FrameHeader + RegistrationRequest
+    FrameHeaderLen: Add<RegistrationRequestLen<CS>>,
+    FramedLen<RegistrationRequestLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str::<_, FramedLen<RegistrationRequestLen<CS>>, _, _>(
+                deserializer,
+                |input| Self::deserialize_framed(CS::SUITE_ID, input),
+            )
+        } else {
+            RegistrationRequestDef::deserialize(deserializer)
+        }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "RegistrationResponse",
+    rename = "RegistrationResponse",
+    bound(
+        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>",
+        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize"
+    )
+)]
+struct RegistrationResponseDef<CS: CipherSuite> {
+    evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
+    server_s_pk: PublicKey<KeGroup<CS>>,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for RegistrationResponse<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RegistrationResponseDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for RegistrationResponse<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Serialize,
+    // RegistrationResponse: KgPk + KePk
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<<KeGroup<CS> as Group>::PkLen>,
+    RegistrationResponseLen<CS>: ArrayLength<u8>,
+    // FramedRegistrationResponse: FrameHeader + RegistrationResponse
+    FrameHeaderLen: Add<RegistrationResponseLen<CS>>,
+    FramedLen<RegistrationResponseLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_str(serializer, &self.serialize_framed(CS::SUITE_ID))
+        } else {
+            RegistrationResponseDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for RegistrationResponse<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+    // RegistrationResponse: KgPk + KePk
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<<KeGroup<CS> as Group>::PkLen>,
+    RegistrationResponseLen<CS>: ArrayLength<u8>,
+    // FramedRegistrationResponse: FrameHeader + RegistrationResponse
+    FrameHeaderLen: Add<RegistrationResponseLen<CS>>,
+    FramedLen<RegistrationResponseLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str::<_, FramedLen<RegistrationResponseLen<CS>>, _, _>(
+                deserializer,
+                |input| Self::deserialize_framed(CS::SUITE_ID, input),
+            )
+        } else {
+            RegistrationResponseDef::deserialize(deserializer)
+        }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "RegistrationUpload",
+    rename = "RegistrationUpload",
+    bound(
+        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>",
+        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize"
+    )
+)]
+struct RegistrationUploadDef<CS: CipherSuite> {
+    envelope: Envelope<CS>,
+    masking_key: Output<OprfHash<CS>>,
+    client_s_pk: PublicKey<KeGroup<CS>>,
+    #[cfg(feature = "ksf-params")]
+    ksf_params: Option<KsfParams>,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for RegistrationUpload<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RegistrationUploadDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for RegistrationUpload<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Serialize,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // FramedRegistrationUpload: FrameHeader + RegistrationUpload
+    FrameHeaderLen: Add<RegistrationUploadLen<CS>>,
+    FramedLen<RegistrationUploadLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            #[cfg(feature = "alloc")]
+            let output = self.serialize_framed(CS::SUITE_ID);
+            #[cfg(not(feature = "alloc"))]
+            let output = framing::header(CS::SUITE_ID, MessageType::RegistrationUpload)
+                .concat(self.serialize());
+            serialize_str(serializer, &Zeroizing::new(output))
+        } else {
+            RegistrationUploadDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for RegistrationUpload<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // FramedRegistrationUpload: FrameHeader + RegistrationUpload
+    FrameHeaderLen: Add<RegistrationUploadLen<CS>>,
+    FramedLen<RegistrationUploadLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let deserialize_framed = |input: &[u8]| Self::deserialize_framed(CS::SUITE_ID, input);
+            #[cfg(feature = "alloc")]
+            return deserialize_str_vec(deserializer, deserialize_framed);
+            #[cfg(not(feature = "alloc"))]
+            return deserialize_str::<_, FramedLen<RegistrationUploadLen<CS>>, _, _>(
+                deserializer,
+                deserialize_framed,
+            );
+        }
+
+        RegistrationUploadDef::deserialize(deserializer)
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "CredentialRequest",
+    rename = "CredentialRequest",
+    bound(
+        deserialize = "<CS::KeyExchange as KeyExchange>::KE1Message: serde::Deserialize<'de>",
+        serialize = "<CS::KeyExchange as KeyExchange>::KE1Message: serde::Serialize"
+    )
+)]
+// Also used for the `CredentialRequest` inside of `ClientLogin`
+pub(crate) struct CredentialRequestDef<CS: CipherSuite> {
+    blinded_element: voprf::BlindedElement<CS::OprfCs>,
+    ke1_message: <CS::KeyExchange as KeyExchange>::KE1Message,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for CredentialRequest<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE1Message: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CredentialRequestDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for CredentialRequest<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE1Message: serde::Serialize + Serialize,
+    // CredentialRequest: KgPk + Ke1Message
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<Ke1MessageLen<CS>>,
+    CredentialRequestLen<CS>: ArrayLength<u8>,
+    // FramedCredentialRequest: FrameHeader + CredentialRequest
+    FrameHeaderLen: Add<CredentialRequestLen<CS>>,
+    FramedLen<CredentialRequestLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_str(serializer, &self.serialize_framed(CS::SUITE_ID))
+        } else {
+            CredentialRequestDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for CredentialRequest<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE1Message: serde::Deserialize<'de> + Deserialize + Serialize,
+    // CredentialRequest: KgPk + Ke1Message
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<Ke1MessageLen<CS>>,
+    CredentialRequestLen<CS>: ArrayLength<u8>,
+    // FramedCredentialRequest: FrameHeader + CredentialRequest
+    FrameHeaderLen: Add<CredentialRequestLen<CS>>,
+    FramedLen<CredentialRequestLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str::<_, FramedLen<CredentialRequestLen<CS>>, _, _>(deserializer, |input| {
+                Self::deserialize_framed(CS::SUITE_ID, input)
+            })
+        } else {
+            CredentialRequestDef::deserialize(deserializer)
+        }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "CredentialResponse",
+    rename = "CredentialResponse",
+    bound(
+        deserialize = "<CS::KeyExchange as KeyExchange>::KE2Message: serde::Deserialize<'de>",
+        serialize = "<CS::KeyExchange as KeyExchange>::KE2Message: serde::Serialize"
+    )
+)]
+struct CredentialResponseDef<CS: CipherSuite> {
+    evaluation_element: voprf::EvaluationElement<CS::OprfCs>,
+    masking_nonce: GenericArray<u8, NonceLen>,
+    masked_response: MaskedResponse<CS>,
+    ke2_message: <CS::KeyExchange as KeyExchange>::KE2Message,
+    #[cfg(feature = "ksf-params")]
+    ksf_params: Option<KsfParams>,
+    #[cfg(feature = "payload")]
+    payload: Option<Vec<u8>>,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for CredentialResponse<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE2Message: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CredentialResponseDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for CredentialResponse<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE2Message: serde::Serialize + Serialize,
+    // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+    Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+        ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+    CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+    // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+    CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+    CredentialResponseLen<CS>: ArrayLength<u8>,
+    // FramedCredentialResponse: FrameHeader + CredentialResponse
+    FrameHeaderLen: Add<CredentialResponseLen<CS>>,
+    FramedLen<CredentialResponseLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            #[cfg(feature = "alloc")]
+            let output = self.serialize_framed(CS::SUITE_ID);
+            #[cfg(not(feature = "alloc"))]
+            let output = framing::header(CS::SUITE_ID, MessageType::CredentialResponse)
+                .concat(self.serialize());
+            serialize_str(serializer, &output)
+        } else {
+            CredentialResponseDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for CredentialResponse<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE2Message: serde::Deserialize<'de> + Deserialize + Serialize,
+    // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+    <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+    Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+        ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+    CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+    // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+    CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+    CredentialResponseLen<CS>: ArrayLength<u8>,
+    // FramedCredentialResponse: FrameHeader + CredentialResponse
+    FrameHeaderLen: Add<CredentialResponseLen<CS>>,
+    FramedLen<CredentialResponseLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let deserialize_framed = |input: &[u8]| Self::deserialize_framed(CS::SUITE_ID, input);
+            #[cfg(feature = "alloc")]
+            return deserialize_str_vec(deserializer, deserialize_framed);
+            #[cfg(not(feature = "alloc"))]
+            return deserialize_str::<_, FramedLen<CredentialResponseLen<CS>>, _, _>(
+                deserializer,
+                deserialize_framed,
+            );
+        }
+
+        CredentialResponseDef::deserialize(deserializer)
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "CredentialFinalization",
+    rename = "CredentialFinalization",
+    bound(
+        deserialize = "<CS::KeyExchange as KeyExchange>::KE3Message: serde::Deserialize<'de>",
+        serialize = "<CS::KeyExchange as KeyExchange>::KE3Message: serde::Serialize"
+    )
+)]
+struct CredentialFinalizationDef<CS: CipherSuite> {
+    ke3_message: <CS::KeyExchange as KeyExchange>::KE3Message,
+    #[cfg(feature = "payload")]
+    payload: Option<Vec<u8>>,
+}
+
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for CredentialFinalization<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE3Message: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CredentialFinalizationDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for CredentialFinalization<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE3Message: serde::Serialize + Serialize,
+    // FramedCredentialFinalization: FrameHeader + CredentialFinalization
+    FrameHeaderLen: Add<CredentialFinalizationLen<CS>>,
+    FramedLen<CredentialFinalizationLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            #[cfg(feature = "alloc")]
+            let output = self.serialize_framed(CS::SUITE_ID);
+            #[cfg(not(feature = "alloc"))]
+            let output = framing::header(CS::SUITE_ID, MessageType::CredentialFinalization)
+                .concat(self.serialize());
+            serialize_str(serializer, &output)
+        } else {
+            CredentialFinalizationDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for CredentialFinalization<CS>
+where
+    <CS::KeyExchange as KeyExchange>::KE3Message: serde::Deserialize<'de> + Deserialize + Serialize,
+    // FramedCredentialFinalization: FrameHeader + CredentialFinalization
+    FrameHeaderLen: Add<CredentialFinalizationLen<CS>>,
+    FramedLen<CredentialFinalizationLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let deserialize_framed = |input: &[u8]| Self::deserialize_framed(CS::SUITE_ID, input);
+            #[cfg(feature = "alloc")]
+            return deserialize_str_vec(deserializer, deserialize_framed);
+            #[cfg(not(feature = "alloc"))]
+            return deserialize_str::<_, FramedLen<CredentialFinalizationLen<CS>>, _, _>(
+                deserializer,
+                deserialize_framed,
+            );
+        }
+
+        CredentialFinalizationDef::deserialize(deserializer)
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "ServerSetup",
+    rename = "ServerSetup",
+    bound(
+        deserialize = "<KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>, <KeGroup<CS> as \
+                       Group>::Sk: serde::Deserialize<'de>, SK: serde::Deserialize<'de>, OS: \
+                       serde::Deserialize<'de>",
+        serialize = "<KeGroup<CS> as Group>::Pk: serde::Serialize, <KeGroup<CS> as Group>::Sk: \
+                     serde::Serialize, SK: serde::Serialize, OS: serde::Serialize"
+    )
+)]
+struct ServerSetupDef<CS: CipherSuite, SK: Clone, OS: Clone> {
+    oprf_seed: OS,
+    keypair: KeyPair<KeGroup<CS>, SK>,
+    dummy_pk: PublicKey<KeGroup<CS>>,
+}
+
+impl<'de, CS: CipherSuite, SK: Clone, OS: Clone> DeserializeDerived<'de> for ServerSetup<CS, SK, OS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+    <KeGroup<CS> as Group>::Sk: serde::Deserialize<'de>,
+    SK: serde::Deserialize<'de>,
+    OS: serde::Deserialize<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ServerSetupDef::deserialize(deserializer)
+    }
+}
+
+impl<CS: CipherSuite, SK: Clone, OS: Clone> serde::Serialize for ServerSetup<CS, SK, OS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Serialize,
+    <KeGroup<CS> as Group>::Sk: serde::Serialize,
+    SK: serde::Serialize + PrivateKeySerialization<KeGroup<CS>>,
+    OS: serde::Serialize + OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    // ServerSetup: Hash + KeSk + KePk
+    OS::Len: Add<SK::Len>,
+    Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+    ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+    // FramedServerSetup: FrameHeader + ServerSetup
+    FrameHeaderLen: Add<ServerSetupLen<CS, SK, OS>>,
+    FramedLen<ServerSetupLen<CS, SK, OS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_str(
+                serializer,
+                &Zeroizing::new(self.serialize_framed(CS::SUITE_ID)),
+            )
+        } else {
+            ServerSetupDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite, SK: Clone, OS: Clone> serde::Deserialize<'de> for ServerSetup<CS, SK, OS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+    <KeGroup<CS> as Group>::Sk: serde::Deserialize<'de>,
+    SK: serde::Deserialize<'de> + PrivateKeySerialization<KeGroup<CS>, Error: Display>,
+    OS: serde::Deserialize<'de> + OprfSeedSerialization<OprfHash<CS>, SK::Error>,
+    // ServerSetup: Hash + KeSk + KePk
+    OS::Len: Add<SK::Len>,
+    Sum<OS::Len, SK::Len>: ArrayLength<u8> + Add<<KeGroup<CS> as Group>::PkLen>,
+    ServerSetupLen<CS, SK, OS>: ArrayLength<u8>,
+    // FramedServerSetup: FrameHeader + ServerSetup
+    FrameHeaderLen: Add<ServerSetupLen<CS, SK, OS>>,
+    FramedLen<ServerSetupLen<CS, SK, OS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserialize_str::<_, FramedLen<ServerSetupLen<CS, SK, OS>>, _, _>(
+                deserializer,
+                |input| Self::deserialize_framed(CS::SUITE_ID, input),
+            )
+        } else {
+            ServerSetupDef::deserialize(deserializer)
+        }
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(
+    remote = "ServerRegistration",
+    rename = "ServerRegistration",
+    bound(
+        deserialize = "RegistrationUpload<CS>: serde::Deserialize<'de>",
+        serialize = "RegistrationUpload<CS>: serde::Serialize"
+    )
+)]
+struct ServerRegistrationDef<CS: CipherSuite>(RegistrationUpload<CS>);
+
+// The newtype is transparent in human-readable formats
+impl<'de, CS: CipherSuite> DeserializeDerived<'de> for ServerRegistration<CS>
+where
+    RegistrationUpload<CS>: DeserializeDerived<'de>,
+{
+    fn deserialize_derived<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RegistrationUpload::deserialize_derived(deserializer).map(Self)
+    }
+}
+
+impl<CS: CipherSuite> serde::Serialize for ServerRegistration<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Serialize,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // FramedServerRegistration: FrameHeader + ServerRegistration
+    FrameHeaderLen: Add<ServerRegistrationLen<CS>>,
+    FramedLen<ServerRegistrationLen<CS>>: ArrayLength<u8>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            #[cfg(feature = "alloc")]
+            let output = self.serialize_framed(CS::SUITE_ID);
+            #[cfg(not(feature = "alloc"))]
+            let output = framing::header(CS::SUITE_ID, MessageType::ServerRegistration)
+                .concat(self.serialize());
+            serialize_str(serializer, &Zeroizing::new(output))
+        } else {
+            ServerRegistrationDef::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, CS: CipherSuite> serde::Deserialize<'de> for ServerRegistration<CS>
+where
+    <KeGroup<CS> as Group>::Pk: serde::Deserialize<'de>,
+    // RegistrationUpload: (KePk + Hash) + Envelope
+    <KeGroup<CS> as Group>::PkLen: Add<OutputSize<OprfHash<CS>>>,
+    Sum<<KeGroup<CS> as Group>::PkLen, OutputSize<OprfHash<CS>>>:
+        ArrayLength<u8> + Add<EnvelopeLen<CS>>,
+    RegistrationUploadLen<CS>: ArrayLength<u8>,
+    // FramedServerRegistration: FrameHeader + ServerRegistration
+    FrameHeaderLen: Add<ServerRegistrationLen<CS>>,
+    FramedLen<ServerRegistrationLen<CS>>: ArrayLength<u8>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let deserialize_framed = |input: &[u8]| Self::deserialize_framed(CS::SUITE_ID, input);
+            #[cfg(feature = "alloc")]
+            return deserialize_str_vec(deserializer, deserialize_framed);
+            #[cfg(not(feature = "alloc"))]
+            return deserialize_str::<_, FramedLen<ServerRegistrationLen<CS>>, _, _>(
+                deserializer,
+                deserialize_framed,
+            );
+        }
+
+        ServerRegistrationDef::deserialize(deserializer)
+    }
+}
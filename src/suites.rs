@@ -0,0 +1,204 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+//! Ready-made [`CipherSuite`]s
+//!
+//! The 3DH suites correspond to the configurations of
+//! [RFC 9807](https://www.rfc-editor.org/rfc/rfc9807.html), the KEM and
+//! SIGMA-I suites combine the same OPRF configurations with the
+//! [`TripleDhKem`](crate::TripleDhKem) and [`SigmaI`](crate::SigmaI) key
+//! exchanges.
+//!
+//! Every suite is parameterized by the [`Ksf`], which has to be chosen
+//! explicitly, and is available with the key stretching functions of this
+//! crate: a memory-hard key stretching function should be used in practice,
+//! e.g. `Ristretto255Sha512TripleDh<argon2::Argon2<'static>>`. [`Identity`]
+//! performs no key stretching at all and is only meant for testing. Deployments restricted
+//! to FIPS-approved primitives can use `P256Sha256TripleDh<ksf::Pbkdf2>` with
+//! the `pbkdf2` feature instead. Other key stretching functions require a
+//! custom [`CipherSuite`].
+//!
+//! Each suite implements [`NamedCipherSuite`], which assigns it a stable
+//! numeric identifier and a human-readable name. The identifiers are specific
+//! to this crate and can be used with [`framing`](crate::framing) or to
+//! register suites at runtime. The upper four bits of an identifier select the
+//! key stretching function:
+//!
+//! | Bits     | KSF                          | Name suffix      |
+//! |----------|------------------------------|------------------|
+//! | `0x0000` | [`Identity`]                 |                  |
+//! | `0x1000` | `argon2::Argon2<'static>`    | `-Argon2id`      |
+//! | `0x2000` | `scrypt::Params`             | `-scrypt`        |
+//! | `0x3000` | `ksf::Pbkdf2<sha2::Sha256>`  | `-PBKDF2-SHA256` |
+//! | `0x4000` | `ksf::Pbkdf2<sha2::Sha512>`  | `-PBKDF2-SHA512` |
+
+#![cfg_attr(
+    not(any(feature = "p256", feature = "ristretto255")),
+    allow(unused_imports)
+)]
+
+use core::marker::PhantomData;
+
+use crate::CipherSuite;
+use crate::ksf::Identity;
+#[cfg(doc)]
+use crate::ksf::Ksf;
+
+/// A [`CipherSuite`] with a stable identifier and name
+pub trait NamedCipherSuite {
+    /// The numeric identifier of this ciphersuite
+    const ID: u16;
+    /// The human-readable name of this ciphersuite
+    const NAME: &'static str;
+}
+
+// Implements `CipherSuite` and `NamedCipherSuite` for `$suite` with every KSF
+// of this crate, see the table in the module documentation.
+macro_rules! impl_suite {
+    (
+        #[cfg($cfg:meta)]
+        $suite:ident,
+        $id:literal,
+        $name:literal,
+        $oprf:ty,
+        $ke:ty $(,)?
+    ) => {
+        impl_suite!(@ksf $cfg, $suite<Identity>, $id, $name, $oprf, $ke);
+        impl_suite!(
+            @ksf all($cfg, feature = "argon2"),
+            $suite<argon2::Argon2<'static>>,
+            $id | 0x1000,
+            concat!($name, "-Argon2id"),
+            $oprf,
+            $ke
+        );
+        impl_suite!(
+            @ksf all($cfg, feature = "scrypt"),
+            $suite<scrypt::Params>,
+            $id | 0x2000,
+            concat!($name, "-scrypt"),
+            $oprf,
+            $ke
+        );
+        impl_suite!(
+            @ksf all($cfg, feature = "pbkdf2"),
+            $suite<crate::ksf::Pbkdf2<sha2::Sha256>>,
+            $id | 0x3000,
+            concat!($name, "-PBKDF2-SHA256"),
+            $oprf,
+            $ke
+        );
+        impl_suite!(
+            @ksf all($cfg, feature = "pbkdf2"),
+            $suite<crate::ksf::Pbkdf2<sha2::Sha512>>,
+            $id | 0x4000,
+            concat!($name, "-PBKDF2-SHA512"),
+            $oprf,
+            $ke
+        );
+    };
+    (@ksf $cfg:meta, $suite:ident<$ksf:ty>, $id:expr, $name:expr, $oprf:ty, $ke:ty) => {
+        #[cfg($cfg)]
+        impl CipherSuite for $suite<$ksf> {
+            type OprfCs = $oprf;
+            type KeyExchange = $ke;
+            type Ksf = $ksf;
+
+            const SUITE_ID: u16 = <Self as NamedCipherSuite>::ID;
+        }
+
+        #[cfg($cfg)]
+        impl NamedCipherSuite for $suite<$ksf> {
+            const ID: u16 = $id;
+            const NAME: &'static str = $name;
+        }
+    };
+}
+
+/// OPRF: ristretto255-SHA512, key exchange: 3DH over ristretto255 with
+/// SHA512
+#[cfg(feature = "ristretto255")]
+pub struct Ristretto255Sha512TripleDh<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(feature = "ristretto255")]
+    Ristretto255Sha512TripleDh,
+    0x0001,
+    "ristretto255-SHA512-3DH",
+    crate::Ristretto255,
+    crate::TripleDh<crate::Ristretto255, sha2::Sha512>,
+);
+
+/// OPRF: P256-SHA256, key exchange: 3DH over P-256 with SHA256
+#[cfg(feature = "p256")]
+pub struct P256Sha256TripleDh<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(feature = "p256")]
+    P256Sha256TripleDh,
+    0x0002,
+    "P256-SHA256-3DH",
+    p256::NistP256,
+    crate::TripleDh<p256::NistP256, sha2::Sha256>,
+);
+
+/// OPRF: ristretto255-SHA512, key exchange: 3DH over ristretto255 with
+/// SHA512 combined with ML-KEM-768
+#[cfg(all(feature = "kem", feature = "ristretto255"))]
+pub struct Ristretto255Sha512TripleDhKem<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(all(feature = "kem", feature = "ristretto255"))]
+    Ristretto255Sha512TripleDhKem,
+    0x0101,
+    "ristretto255-SHA512-3DH-ML-KEM-768",
+    crate::Ristretto255,
+    crate::TripleDhKem<crate::Ristretto255, sha2::Sha512, ml_kem::MlKem768>,
+);
+
+/// OPRF: P256-SHA256, key exchange: 3DH over P-256 with SHA256 combined
+/// with ML-KEM-768
+#[cfg(all(feature = "kem", feature = "p256"))]
+pub struct P256Sha256TripleDhKem<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(all(feature = "kem", feature = "p256"))]
+    P256Sha256TripleDhKem,
+    0x0102,
+    "P256-SHA256-3DH-ML-KEM-768",
+    p256::NistP256,
+    crate::TripleDhKem<p256::NistP256, sha2::Sha256, ml_kem::MlKem768>,
+);
+
+/// OPRF: ristretto255-SHA512, key exchange: SIGMA-I over ristretto255 with
+/// SHA512 and Ed25519 signatures
+#[cfg(all(feature = "ed25519", feature = "ristretto255"))]
+pub struct Ristretto255Sha512SigmaIEd25519<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(all(feature = "ed25519", feature = "ristretto255"))]
+    Ristretto255Sha512SigmaIEd25519,
+    0x0201,
+    "ristretto255-SHA512-SIGMA-I-Ed25519",
+    crate::Ristretto255,
+    crate::SigmaI<crate::PureEddsa<crate::Ed25519>, crate::Ristretto255, sha2::Sha512>,
+);
+
+/// OPRF: P256-SHA256, key exchange: SIGMA-I over P-256 with SHA256 and
+/// ECDSA-P256-SHA256 signatures
+#[cfg(all(feature = "ecdsa", feature = "p256"))]
+pub struct P256Sha256SigmaIEcdsa<KSF>(PhantomData<KSF>);
+
+impl_suite!(
+    #[cfg(all(feature = "ecdsa", feature = "p256"))]
+    P256Sha256SigmaIEcdsa,
+    0x0202,
+    "P256-SHA256-SIGMA-I-ECDSA-P256-SHA256",
+    p256::NistP256,
+    crate::SigmaI<crate::Ecdsa<p256::NistP256, sha2::Sha256>, p256::NistP256, sha2::Sha256>,
+);
@@ -8,6 +8,8 @@
 
 #![allow(unsafe_code)]
 
+#[cfg(feature = "batch")]
+use core::fmt::Debug;
 use core::ops::Add;
 use std::string::String;
 use std::vec::Vec;
@@ -28,6 +30,8 @@ use voprf::Group as _;
 use crate::ciphersuite::{CipherSuite, KeGroup, OprfGroup, OprfHash};
 use crate::envelope::EnvelopeLen;
 use crate::errors::*;
+#[cfg(feature = "serde")]
+use crate::framing::{FrameHeaderLen, FramedLen};
 use crate::hash::OutputSize;
 use crate::key_exchange::group::Group;
 use crate::key_exchange::shared::NonceLen;
@@ -155,6 +159,9 @@ macro_rules! triple_dh_ciphersuites {
                 #[cfg(feature = "curve25519")] [
                     TripleDhCurve25519, TripleDh<crate::Curve25519, sha2::Sha512>,
                 ],
+                #[cfg(feature = "secp256k1")] [
+                    TripleDhSecp256k1, TripleDh<crate::Secp256k1, sha2::Sha256>,
+                ],
             ]
         );
     };
@@ -187,6 +194,9 @@ macro_rules! sigma_i_ciphersuites {
             $macro!$par => [
                 #[cfg(feature = "ecdsa")] [P256, Ecdsa<p256::NistP256, sha2::Sha256>],
                 #[cfg(feature = "ecdsa")] [P384, Ecdsa<p384::NistP384, sha2::Sha384>],
+                #[cfg(all(feature = "ecdsa", feature = "secp256k1"))] [
+                    Secp256k1, Ecdsa<crate::Secp256k1, sha2::Sha256>,
+                ],
                 #[cfg(feature = "ed25519")] [Ed25519, PureEddsa<Ed25519>],
                 #[cfg(feature = "ed25519")] [Ed25519Ph, HashEddsa<Ed25519>],
             ],
@@ -194,7 +204,7 @@ macro_rules! sigma_i_ciphersuites {
     };
     (
         $macro:ident!$par:tt =>
-        [$($(#[$sig_attr:meta])? [$sig_name:ident, $sig:ty]),+$(,)?],
+        [$($(#[$sig_attr:meta])? [$sig_name:ident, $sig:ty$(,)?]),+$(,)?],
     ) => {
         paste::paste! {
             $(
@@ -209,6 +219,9 @@ macro_rules! sigma_i_ciphersuites {
                         $(#[$sig_attr])? #[cfg(feature = "curve25519")] [
                             [<SigmaI $sig_name Curve25519>], SigmaI<$sig, crate::Curve25519, sha2::Sha512>,
                         ],
+                        $(#[$sig_attr])? #[cfg(feature = "secp256k1")] [
+                            [<SigmaI $sig_name Secp256k1>], SigmaI<$sig, crate::Secp256k1, sha2::Sha256>,
+                        ],
                     ]
                 );
             )+
@@ -676,13 +689,13 @@ where
         Some(password_file),
         client_login_start_result.message,
         credential_identifier,
-        ServerLoginParameters {
-            context: Some(context),
-            identifiers: Identifiers {
+        ServerLoginParameters::new(
+            Some(context),
+            Identifiers {
                 client: Some(id_u),
                 server: Some(id_s),
             },
-        },
+        ),
     )
     .unwrap();
     let credential_response_bytes = server_login_start_result.message.serialize();
@@ -761,7 +774,7 @@ fn generate_test_vectors() -> Result<(), ProtocolError> {
         // licenses.\n\
         //\n\
         // To regenerate these test vectors, run:\n\
-        // FULL_TEST_VECTORS_FILE=src/tests/full_test_vectors.rs cargo test --features curve25519,ecdsa,ed25519 -- generate_test_vectors\n\
+        // FULL_TEST_VECTORS_FILE=src/tests/full_test_vectors.rs cargo test --features curve25519,ecdsa,ed25519,kem,secp256k1 -- generate_test_vectors\n\
         \n\
         #![allow(clippy::duplicated_attributes)]\n\
         \n",
@@ -815,7 +828,12 @@ fn test_registration_request() -> Result<(), ProtocolError> {
 #[cfg(feature = "serde")]
 #[test]
 fn test_serialization() -> Result<(), ProtocolError> {
-    fn inner<CS: CipherSuite>(test_vector: &str) -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(test_vector: &str) -> Result<(), ProtocolError>
+    where
+        // FramedRegistrationRequest: FrameHeader + RegistrationRequest
+        FrameHeaderLen: Add<RegistrationRequestLen<CS>>,
+        FramedLen<RegistrationRequestLen<CS>>: ArrayLength<u8>,
+    {
         let parameters = populate_test_vectors(&serde_json::from_str(test_vector).unwrap());
         let mut rng = CycleRng::new(parameters.blinding_factor.to_vec());
         let client_registration_start_result =
@@ -846,6 +864,128 @@ fn test_serialization() -> Result<(), ProtocolError> {
     Ok(())
 }
 
+#[cfg(all(feature = "alloc", feature = "serde", feature = "ristretto255"))]
+#[test]
+fn test_human_readable_serialization() -> Result<(), ProtocolError> {
+    use base64ct::{Base64UrlUnpadded, Encoding};
+
+    type Suite = crate::suites::Ristretto255Sha512TripleDh<Identity>;
+
+    struct UnnamedSuite;
+
+    impl CipherSuite for UnnamedSuite {
+        type OprfCs = Ristretto255;
+        type KeyExchange = TripleDh<Ristretto255, sha2::Sha512>;
+        type Ksf = Identity;
+    }
+
+    fn json<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T, framed: &[u8]) -> T {
+        let json = serde_json::to_string(value).unwrap();
+        assert_eq!(
+            json,
+            format!("\"{}\"", Base64UrlUnpadded::encode_string(framed))
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    let password = b"password";
+    let credential_identifier = b"credentialIdentifier";
+    let mut rng = OsRng;
+    let server_setup = ServerSetup::<Suite>::new(&mut rng);
+    let server_setup = json(
+        &server_setup,
+        &server_setup.serialize_framed(Suite::SUITE_ID),
+    );
+
+    let client_registration_start_result = ClientRegistration::<Suite>::start(&mut rng, password)?;
+    let message = &client_registration_start_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let server_registration_start_result =
+        ServerRegistration::<Suite>::start(&server_setup, message, credential_identifier)?;
+    let message = &server_registration_start_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        message,
+        ClientRegistrationFinishParameters::default(),
+    )?;
+    let message = &client_registration_finish_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let password_file = ServerRegistration::finish(message);
+    let password_file = json(
+        &password_file,
+        &password_file.serialize_framed(Suite::SUITE_ID),
+    );
+
+    let client_login_start_result = ClientLogin::<Suite>::start(&mut rng, password)?;
+    let message = &client_login_start_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        Some(password_file.clone()),
+        message,
+        credential_identifier,
+        ServerLoginParameters::default(),
+    )?;
+    let message = &server_login_start_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let client_login_finish_result = client_login_start_result.state.finish(
+        &mut rng,
+        password,
+        message,
+        ClientLoginFinishParameters::default(),
+    )?;
+    let message = &client_login_finish_result.message;
+    let message = json(message, &message.serialize_framed(Suite::SUITE_ID));
+    let server_login_finish_result = server_login_start_result
+        .state
+        .finish(message, ServerLoginParameters::default())?;
+    assert_eq!(
+        client_login_finish_result.session_key,
+        server_login_finish_result.session_key
+    );
+
+    // Non-human-readable formats keep the compact representation.
+    let compact = bincode::serialize(&password_file).unwrap();
+    assert!(compact.len() < Base64UrlUnpadded::encoded_len(&password_file.serialize()));
+    let compact: ServerRegistration<Suite> = bincode::deserialize(&compact).unwrap();
+    assert_eq!(compact, password_file);
+
+    // The suite tag and message type are checked.
+    let password_file = serde_json::to_string(&password_file).unwrap();
+    assert!(serde_json::from_str::<ServerRegistration<UnnamedSuite>>(&password_file).is_err());
+    assert!(serde_json::from_str::<RegistrationUpload<Suite>>(&password_file).is_err());
+    assert!(serde_json::from_str::<ServerRegistration<Suite>>("[0, 1, 2]").is_err());
+
+    // The derived representation of earlier versions is still accepted.
+    let legacy = [
+        (
+            r#"{"oprf_seed":[55,132,41,12,96,107,4,253,192,114,4,0,201,97,227,102,182,234,20,212,17,252,197,61,234,79,68,201,187,77,116,8,222,14,168,154,83,241,236,243,243,208,164,98,251,119,210,186,245,224,229,223,239,182,137,123,189,116,96,49,94,129,24,42],"keypair":{"pk":[202,63,157,179,183,128,139,221,135,250,172,54,57,176,218,25,126,155,157,173,221,55,151,62,41,132,88,106,36,1,142,42],"sk":[8,101,101,162,237,182,18,31,98,174,178,89,214,52,218,220,186,163,154,80,60,187,128,184,225,118,100,60,181,215,217,9]},"dummy_pk":[132,185,225,161,16,72,109,67,2,54,182,238,51,75,254,217,161,148,137,214,171,38,28,144,81,253,238,150,14,87,132,38]}"#,
+            "3784290c606b04fdc0720400c961e366b6ea14d411fcc53dea4f44c9bb4d7408de0ea89a53f1ecf3f3d0a462fb77d2baf5e0e5dfefb6897bbd7460315e81182a086565a2edb6121f62aeb259d634dadcbaa39a503cbb80b8e176643cb5d7d90984b9e1a110486d430236b6ee334bfed9a19489d6ab261c9051fdee960e578426",
+        ),
+        (
+            r#"{"envelope":{"mode":"Internal","nonce":[99,49,118,66,229,163,95,104,174,208,15,39,210,208,2,76,52,180,99,17,69,64,232,119,139,224,3,106,57,136,101,251],"hmac":[71,24,86,148,143,93,118,95,164,237,116,7,178,6,200,179,234,217,4,141,4,210,214,6,69,55,136,226,150,42,6,242,78,140,145,118,5,250,58,82,72,97,86,228,133,241,151,95,178,137,219,236,183,255,9,100,90,186,160,116,45,11,54,134]},"masking_key":[6,52,209,193,95,63,161,251,228,228,26,149,244,47,117,231,44,113,142,238,205,119,151,118,150,226,162,39,95,211,230,82,163,109,193,14,154,115,189,196,206,230,187,137,248,86,40,87,4,109,119,0,142,12,194,204,101,245,176,157,26,7,114,37],"client_s_pk":[250,65,17,35,33,227,212,100,241,209,219,158,146,32,204,169,181,231,17,107,104,49,192,180,42,140,158,35,229,152,115,86]}"#,
+            "fa41112321e3d464f1d1db9e9220cca9b5e7116b6831c0b42a8c9e23e59873560634d1c15f3fa1fbe4e41a95f42f75e72c718eeecd77977696e2a2275fd3e652a36dc10e9a73bdc4cee6bb89f8562857046d77008e0cc2cc65f5b09d1a07722563317642e5a35f68aed00f27d2d0024c34b463114540e8778be0036a398865fb471856948f5d765fa4ed7407b206c8b3ead9048d04d2d606453788e2962a06f24e8c917605fa3a52486156e485f1975fb289dbecb7ff09645abaa0742d0b3686",
+        ),
+    ];
+    let server_setup: ServerSetup<Suite> = serde_json::from_str(legacy[0].0).unwrap();
+    assert_eq!(
+        server_setup,
+        ServerSetup::deserialize(&hex::decode(legacy[0].1).unwrap())?
+    );
+    let password_file: ServerRegistration<Suite> = serde_json::from_str(legacy[1].0).unwrap();
+    assert_eq!(
+        password_file,
+        ServerRegistration::deserialize(&hex::decode(legacy[1].1).unwrap())?
+    );
+    let upload: RegistrationUpload<Suite> = serde_json::from_str(legacy[1].0).unwrap();
+    assert_eq!(upload.serialize(), password_file.serialize());
+
+    Ok(())
+}
+
 #[test]
 fn test_registration_response() -> Result<(), ProtocolError> {
     fn inner<CS: CipherSuite>(test_vector: &str) -> Result<(), ProtocolError>
@@ -1058,13 +1198,13 @@ fn test_credential_response() -> Result<(), ProtocolError> {
             Some(ServerRegistration::deserialize(&parameters.password_file)?),
             CredentialRequest::<CS>::deserialize(&parameters.credential_request)?,
             &parameters.credential_identifier,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: Some(&parameters.id_u),
                     server: Some(&parameters.id_s),
                 },
-            },
+            ),
         )?;
         assert_eq!(
             hex::encode(&parameters.credential_response),
@@ -1151,13 +1291,13 @@ fn test_server_login_finish() -> Result<(), ProtocolError> {
         let server_login_result = ServerLogin::<CS>::deserialize(&parameters.server_login_state)?
             .finish(
             CredentialFinalization::deserialize(&parameters.credential_finalization)?,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: Some(&parameters.id_u),
                     server: Some(&parameters.id_s),
                 },
-            },
+            ),
         )?;
 
         assert_eq!(
@@ -1219,7 +1359,7 @@ fn test_complete_flow<CS: CipherSuite>(
     if hex::encode(registration_password) == hex::encode(login_password) {
         let client_login_finish_result = client_login_result?;
         let server_login_finish_result = server_login_start_result.state.finish(
-            client_login_finish_result.message,
+            client_login_finish_result.message.clone(),
             ServerLoginParameters::default(),
         )?;
 
@@ -1228,9 +1368,37 @@ fn test_complete_flow<CS: CipherSuite>(
             hex::encode(&client_login_finish_result.session_key)
         );
         assert_eq!(
-            hex::encode(client_registration_finish_result.export_key),
-            hex::encode(client_login_finish_result.export_key)
+            hex::encode(&client_registration_finish_result.export_key),
+            hex::encode(&client_login_finish_result.export_key)
         );
+
+        let mut client_keying_material = [0; 100];
+        let mut server_keying_material = [0; 100];
+        client_login_finish_result.export_keying_material(
+            b"label",
+            b"context",
+            &mut client_keying_material,
+        )?;
+        server_login_finish_result.export_keying_material(
+            b"label",
+            b"context",
+            &mut server_keying_material,
+        )?;
+        assert_eq!(client_keying_material, server_keying_material);
+
+        let mut other_keying_material = [0; 100];
+        server_login_finish_result.export_keying_material(
+            b"other label",
+            b"context",
+            &mut other_keying_material,
+        )?;
+        assert_ne!(server_keying_material, other_keying_material);
+        server_login_finish_result.export_keying_material(
+            b"label",
+            b"other context",
+            &mut other_keying_material,
+        )?;
+        assert_ne!(server_keying_material, other_keying_material);
     } else {
         assert!(matches!(
             client_login_result,
@@ -1283,6 +1451,2023 @@ fn test_complete_flow_fail() -> Result<(), ProtocolError> {
     Ok(())
 }
 
+#[cfg(feature = "payload")]
+#[test]
+fn test_payload() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError>
+    where
+        <CS::KeyExchange as KeyExchange>::KE2Message: Serialize + Deserialize,
+        <CS::KeyExchange as KeyExchange>::KE3Message: Serialize + Deserialize,
+        // CredentialResponseWithoutKeLen: (KgPk + Nonce) + MaskedResponse
+        <OprfGroup<CS> as voprf::Group>::ElemLen: Add<NonceLen>,
+        Sum<<OprfGroup<CS> as voprf::Group>::ElemLen, NonceLen>:
+            ArrayLength<u8> + Add<MaskedResponseLen<CS>>,
+        CredentialResponseWithoutKeLen<CS>: ArrayLength<u8>,
+        // CredentialResponse: CredentialResponseWithoutKeLen + Ke2Message
+        CredentialResponseWithoutKeLen<CS>: Add<Ke2MessageLen<CS>>,
+        CredentialResponseLen<CS>: ArrayLength<u8>,
+    {
+        let password = b"password";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+        let server_registration_start_result = ServerRegistration::<CS>::start(
+            &server_setup,
+            client_registration_start_result.message,
+            credential_identifier,
+        )?;
+        let client_registration_finish_result = client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+        let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+        #[derive(Clone, Copy, PartialEq)]
+        enum Tamper {
+            None,
+            ModifyResponse,
+            StripResponse,
+            ModifyFinalization,
+            StripFinalization,
+            ExtendResponse,
+            ExtendFinalization,
+        }
+
+        let run = |tamper: Tamper| {
+            let mut rng = OsRng;
+            let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+            let server_login_start_result = ServerLogin::<CS>::start(
+                &mut rng,
+                &server_setup,
+                Some(password_file.clone()),
+                client_login_start_result.message,
+                credential_identifier,
+                ServerLoginParameters::default().with_payload(b"server payload"),
+            )?;
+
+            let mut bytes = server_login_start_result
+                .message
+                .serialize_with_extensions();
+            match tamper {
+                Tamper::ModifyResponse => *bytes.last_mut().unwrap() ^= 1,
+                Tamper::StripResponse => {
+                    bytes = server_login_start_result.message.serialize().to_vec()
+                }
+                Tamper::ExtendResponse => bytes.push(0),
+                _ => (),
+            }
+            let credential_response = CredentialResponse::<CS>::deserialize(&bytes)?;
+
+            let client_login_finish_result = client_login_start_result.state.finish(
+                &mut rng,
+                password,
+                credential_response,
+                ClientLoginFinishParameters::default().with_payload(b"client payload"),
+            )?;
+            assert_eq!(
+                client_login_finish_result.server_payload.as_deref(),
+                Some(b"server payload".as_slice())
+            );
+
+            let mut bytes = client_login_finish_result
+                .message
+                .serialize_with_extensions();
+            match tamper {
+                Tamper::ModifyFinalization => *bytes.last_mut().unwrap() ^= 1,
+                Tamper::StripFinalization => {
+                    bytes = client_login_finish_result.message.serialize().to_vec()
+                }
+                Tamper::ExtendFinalization => bytes.push(0),
+                _ => (),
+            }
+            let server_login_finish_result = server_login_start_result.state.finish(
+                CredentialFinalization::<CS>::deserialize(&bytes)?,
+                ServerLoginParameters::default(),
+            )?;
+            assert_eq!(
+                server_login_finish_result.client_payload.as_deref(),
+                Some(b"client payload".as_slice())
+            );
+
+            Ok::<_, ProtocolError>(())
+        };
+
+        run(Tamper::None)?;
+        for tamper in [
+            Tamper::ModifyResponse,
+            Tamper::StripResponse,
+            Tamper::ModifyFinalization,
+            Tamper::StripFinalization,
+        ] {
+            assert!(matches!(run(tamper), Err(ProtocolError::InvalidLoginError)));
+        }
+        // Bytes following the extensions are rejected instead of being read as
+        // part of the payload
+        for tamper in [Tamper::ExtendResponse, Tamper::ExtendFinalization] {
+            assert!(matches!(
+                run(tamper),
+                Err(ProtocolError::SerializationError)
+            ));
+        }
+
+        // Without a payload the serialization is unchanged
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let server_login_start_result = ServerLogin::<CS>::start(
+            &mut rng,
+            &server_setup,
+            Some(password_file),
+            client_login_start_result.message,
+            credential_identifier,
+            ServerLoginParameters::default(),
+        )?;
+        let message = server_login_start_result.message;
+        assert_eq!(
+            message.serialize_with_extensions(),
+            message.serialize().to_vec()
+        );
+        let client_login_finish_result = client_login_start_result.state.clone().finish(
+            &mut rng,
+            password,
+            CredentialResponse::<CS>::deserialize(&message.serialize())?,
+            ClientLoginFinishParameters::default(),
+        )?;
+        assert!(client_login_finish_result.server_payload.is_none());
+
+        // Adding a payload the server did not send fails the login
+        let mut bytes = message.serialize().to_vec();
+        bytes.extend_from_slice(&[0, 2, 0, 32]);
+        bytes.extend_from_slice(&[0; 32]);
+        assert!(matches!(
+            client_login_start_result.state.finish(
+                &mut rng,
+                password,
+                CredentialResponse::<CS>::deserialize(&bytes)?,
+                ClientLoginFinishParameters::default(),
+            ),
+            Err(ProtocolError::InvalidLoginError)
+        ));
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+    Ok(())
+}
+
+#[test]
+fn test_pre_shared_key() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError> {
+        let password = b"password";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+        let server_registration_start_result = ServerRegistration::<CS>::start(
+            &server_setup,
+            client_registration_start_result.message,
+            credential_identifier,
+        )?;
+        let client_registration_finish_result = client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+        let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+        let psk = PreSharedKey {
+            identity: b"device",
+            key: &[0x42; 32],
+        };
+
+        let run = |client_psk: Option<PreSharedKey>, server_psk: Option<PreSharedKey>| {
+            let mut rng = OsRng;
+            let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+            let server_login_start_result = ServerLogin::<CS>::start(
+                &mut rng,
+                &server_setup,
+                Some(password_file.clone()),
+                client_login_start_result.message,
+                credential_identifier,
+                server_psk.map_or_else(ServerLoginParameters::default, |psk| {
+                    ServerLoginParameters::default().with_psk(psk)
+                }),
+            )?;
+            let client_login_finish_result = client_login_start_result.state.finish(
+                &mut rng,
+                password,
+                server_login_start_result.message,
+                client_psk.map_or_else(ClientLoginFinishParameters::default, |psk| {
+                    ClientLoginFinishParameters::default().with_psk(psk)
+                }),
+            )?;
+            let server_login_finish_result = server_login_start_result.state.finish(
+                client_login_finish_result.message,
+                server_psk.map_or_else(ServerLoginParameters::default, |psk| {
+                    ServerLoginParameters::default().with_psk(psk)
+                }),
+            )?;
+
+            Ok::<_, ProtocolError>((
+                client_login_finish_result.session_key,
+                server_login_finish_result.session_key,
+            ))
+        };
+
+        let (client_session_key, server_session_key) = run(Some(psk), Some(psk))?;
+        assert_eq!(client_session_key, server_session_key);
+
+        let (session_key, _) = run(None, None)?;
+        assert_ne!(client_session_key, session_key);
+
+        let other_key = PreSharedKey {
+            key: &[0x43; 32],
+            ..psk
+        };
+        let other_identity = PreSharedKey {
+            identity: b"other device",
+            ..psk
+        };
+
+        for (client_psk, server_psk) in [
+            (Some(psk), None),
+            (None, Some(psk)),
+            (Some(psk), Some(other_key)),
+            (Some(psk), Some(other_identity)),
+        ] {
+            assert!(matches!(
+                run(client_psk, server_psk),
+                Err(ProtocolError::InvalidLoginError)
+            ));
+        }
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_pre_shared_key_serialization() -> Result<(), ProtocolError> {
+    let psk = PreSharedKey {
+        identity: b"device",
+        key: &[0x42; 32],
+    };
+    let context = crate::key_exchange::SerializedContext::from(Some(b"context"), Some(psk))?;
+    assert_eq!(context.psk(), Some(psk.key));
+
+    // Only the identity is serialized, the key is gone after deserialization.
+    let serialized = bincode::serialize(&context).unwrap();
+    let deserialized: crate::key_exchange::SerializedContext<'_> =
+        bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.psk(), None);
+    assert!(deserialized.iter().eq(context.iter()));
+
+    Ok(())
+}
+
+#[test]
+fn test_split_login_finish() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError> {
+        let password = b"password";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+        let server_registration_start_result = ServerRegistration::<CS>::start(
+            &server_setup,
+            client_registration_start_result.message,
+            credential_identifier,
+        )?;
+        let client_registration_finish_result = client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+        let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+        for login_password in [&b"password"[..], b"wrong password"] {
+            let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+            let server_login_start_result = ServerLogin::<CS>::start(
+                &mut rng,
+                &server_setup,
+                Some(password_file.clone()),
+                client_login_start_result.message,
+                credential_identifier,
+                ServerLoginParameters::default(),
+            )?;
+
+            let params = ClientLoginFinishParameters::default();
+            let randomized_password = client_login_start_result.state.derive_randomized_password(
+                login_password,
+                &server_login_start_result.message,
+                &params,
+            )?;
+            let client_login_finish_result = client_login_start_result
+                .state
+                .finish_with_randomized_password(
+                    &mut rng,
+                    randomized_password,
+                    server_login_start_result.message,
+                    params,
+                );
+
+            if login_password == password {
+                let client_login_finish_result = client_login_finish_result?;
+                let server_login_finish_result = server_login_start_result.state.finish(
+                    client_login_finish_result.message,
+                    ServerLoginParameters::default(),
+                )?;
+                assert_eq!(
+                    client_login_finish_result.session_key,
+                    server_login_finish_result.session_key
+                );
+                assert_eq!(
+                    client_login_finish_result.export_key,
+                    client_registration_finish_result.export_key
+                );
+            } else {
+                assert!(matches!(
+                    client_login_finish_result,
+                    Err(ProtocolError::InvalidLoginError)
+                ));
+            }
+        }
+
+        // The randomized password only finishes the response it was derived
+        // from.
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let [first, second] = [(); 2].map(|_| {
+            ServerLogin::<CS>::start(
+                &mut rng,
+                &server_setup,
+                Some(password_file.clone()),
+                client_login_start_result.message.clone(),
+                credential_identifier,
+                ServerLoginParameters::default(),
+            )
+        });
+        let params = ClientLoginFinishParameters::default();
+        let randomized_password = client_login_start_result.state.derive_randomized_password(
+            password,
+            &first?.message,
+            &params,
+        )?;
+        assert!(matches!(
+            client_login_start_result
+                .state
+                .finish_with_randomized_password(
+                    &mut rng,
+                    randomized_password,
+                    second?.message,
+                    params,
+                ),
+            Err(ProtocolError::RandomizedPasswordMismatchError)
+        ));
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+    Ok(())
+}
+
+#[test]
+fn test_precomputed_login_start() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError> {
+        let password = b"password";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+        let server_registration_start_result = ServerRegistration::<CS>::start(
+            &server_setup,
+            client_registration_start_result.message,
+            credential_identifier,
+        )?;
+        let client_registration_finish_result = client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+        let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+        // Precompute before the password is known
+        let precomputation = ClientLogin::<CS>::precompute(&mut rng)?;
+
+        let client_login_start_result =
+            ClientLogin::<CS>::start_with_precomputation(&mut rng, password, precomputation)?;
+        let server_login_start_result = ServerLogin::<CS>::start(
+            &mut rng,
+            &server_setup,
+            Some(password_file),
+            client_login_start_result.message,
+            credential_identifier,
+            ServerLoginParameters::default(),
+        )?;
+        let client_login_finish_result = client_login_start_result.state.finish(
+            &mut rng,
+            password,
+            server_login_start_result.message,
+            ClientLoginFinishParameters::default(),
+        )?;
+        let server_login_finish_result = server_login_start_result.state.finish(
+            client_login_finish_result.message,
+            ServerLoginParameters::default(),
+        )?;
+        assert_eq!(
+            client_login_finish_result.session_key,
+            server_login_finish_result.session_key
+        );
+        assert_eq!(
+            client_login_finish_result.export_key,
+            client_registration_finish_result.export_key
+        );
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+    Ok(())
+}
+
+#[test]
+fn test_ephemeral() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str, supported: bool) -> Result<(), ProtocolError>
+    where
+        <CS::KeyExchange as KeyExchange>::KE2Message: Serialize,
+    {
+        let password = b"password";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+        let server_registration_start_result = ServerRegistration::<CS>::start(
+            &server_setup,
+            client_registration_start_result.message,
+            credential_identifier,
+        )?;
+        let client_registration_finish_result = client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+        let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+        #[cfg(feature = "ephemeral-pool")]
+        let ephemeral = {
+            let mut pool = crate::EphemeralPool::<KeGroup<CS>>::new();
+            pool.refill(&mut rng, 2);
+            assert_eq!(pool.len(), 2);
+            pool.refill(&mut rng, 1);
+            assert_eq!(pool.len(), 2);
+            let _ = pool.take(&mut rng);
+            let ephemeral = pool.take(&mut rng);
+            assert!(pool.is_empty());
+            assert!(pool.pop().is_none());
+            ephemeral
+        };
+        #[cfg(not(feature = "ephemeral-pool"))]
+        let ephemeral = crate::Ephemeral::<KeGroup<CS>>::random(&mut rng);
+        let server_e_pk = ephemeral.public().serialize();
+
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let server_login_start_result = ServerLogin::<CS>::start_with_ephemeral(
+            &mut rng,
+            &server_setup,
+            Some(password_file),
+            client_login_start_result.message,
+            credential_identifier,
+            ServerLoginParameters::default(),
+            ephemeral,
+        );
+
+        if !supported {
+            assert!(matches!(
+                server_login_start_result,
+                Err(ProtocolError::UnsupportedEphemeralError)
+            ));
+            return Ok(());
+        }
+
+        // The precomputed ephemeral public key is sent to the client.
+        let server_login_start_result = server_login_start_result?;
+        let ke2_message = server_login_start_result.message.ke2_message.serialize();
+        assert!(
+            ke2_message
+                .windows(server_e_pk.len())
+                .any(|window| window == server_e_pk.as_slice())
+        );
+
+        let client_login_finish_result = client_login_start_result.state.finish(
+            &mut rng,
+            password,
+            server_login_start_result.message,
+            ClientLoginFinishParameters::default(),
+        )?;
+        let server_login_finish_result = server_login_start_result.state.finish(
+            client_login_finish_result.message,
+            ServerLoginParameters::default(),
+        )?;
+        assert_eq!(
+            client_login_finish_result.session_key,
+            server_login_finish_result.session_key
+        );
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner, true));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner, true));
+    // `SigmaI` rejects the precomputed ephemeral.
+    sigma_i_ciphersuites!(run_all!(inner, false));
+    Ok(())
+}
+
+#[cfg(feature = "batch")]
+#[test]
+fn test_start_batch() -> Result<(), ProtocolError> {
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError>
+    where
+        CredentialResponse<CS>: Debug + PartialEq,
+        ServerLogin<CS>: Debug + PartialEq,
+    {
+        let password = b"password";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+        // Too long to be encoded, fails the login.
+        let invalid_context = vec![0; usize::from(u16::MAX) + 1];
+
+        let mut client_states = Vec::new();
+        let mut requests = Vec::new();
+
+        for (credential_identifier, registered, context) in [
+            (b"alice".as_slice(), true, None),
+            (b"bob", false, None),
+            (b"carol", true, Some(invalid_context.as_slice())),
+            (b"dave", true, None),
+        ] {
+            let client_registration_start_result =
+                ClientRegistration::<CS>::start(&mut rng, password)?;
+            let server_registration_start_result = ServerRegistration::<CS>::start(
+                &server_setup,
+                client_registration_start_result.message,
+                credential_identifier,
+            )?;
+            let client_registration_finish_result = client_registration_start_result.state.finish(
+                &mut rng,
+                password,
+                server_registration_start_result.message,
+                ClientRegistrationFinishParameters::default(),
+            )?;
+            let password_file =
+                ServerRegistration::finish(client_registration_finish_result.message);
+
+            let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+            client_states.push(client_login_start_result.state);
+            requests.push(ServerLoginRequest {
+                password_file: registered.then_some(password_file),
+                credential_request: client_login_start_result.message,
+                credential_identifier,
+                parameters: ServerLoginParameters::new(context, Identifiers::default()),
+            });
+        }
+
+        let mut batch_rng = ChaCha20Rng::seed_from_u64(0);
+        let results = ServerLogin::start_batch(&mut batch_rng, &server_setup, requests.clone());
+        assert_eq!(results.len(), requests.len());
+
+        // The batch draws the same randomness as `start()` in turn and arrives at the
+        // same results.
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut results_iter = results.into_iter();
+
+        for (request, client_state) in requests.into_iter().zip(client_states) {
+            let registered = request.password_file.is_some();
+            let result = results_iter.next().unwrap();
+            let expected = ServerLogin::start(
+                &mut rng,
+                &server_setup,
+                request.password_file,
+                request.credential_request,
+                request.credential_identifier,
+                request.parameters,
+            );
+
+            match (result, expected) {
+                (Ok(result), Ok(expected)) => {
+                    assert_eq!(result.message, expected.message);
+                    assert_eq!(result.state, expected.state);
+
+                    let client_login_finish_result = client_state.finish(
+                        &mut OsRng,
+                        password,
+                        result.message,
+                        ClientLoginFinishParameters::default(),
+                    );
+
+                    if registered {
+                        let client_login_finish_result = client_login_finish_result?;
+                        let server_login_finish_result = result.state.finish(
+                            client_login_finish_result.message,
+                            ServerLoginParameters::default(),
+                        )?;
+                        assert_eq!(
+                            client_login_finish_result.session_key,
+                            server_login_finish_result.session_key
+                        );
+                    } else {
+                        assert!(matches!(
+                            client_login_finish_result,
+                            Err(ProtocolError::InvalidLoginError)
+                        ));
+                    }
+                }
+                (Err(error), Err(expected)) => assert_eq!(error, expected),
+                _ => panic!("`start_batch()` and `start()` disagree"),
+            }
+        }
+
+        assert_eq!(
+            rand::RngCore::next_u64(&mut batch_rng),
+            rand::RngCore::next_u64(&mut rng)
+        );
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+    Ok(())
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn test_legacy_hash() -> Result<(), ProtocolError> {
+    use crate::legacy::{self, LegacyHash, LegacyParameters};
+
+    struct HkdfLegacyHash;
+
+    impl LegacyHash for HkdfLegacyHash {
+        fn hash(
+            &self,
+            password: &[u8],
+            salt: &[u8],
+            output: &mut [u8],
+        ) -> Result<(), InternalError> {
+            hkdf::Hkdf::<sha2::Sha256>::new(Some(salt), password)
+                .expand(b"legacy", output)
+                .map_err(|_| InternalError::LegacyHashError)
+        }
+
+        fn serialize_params(&self) -> Vec<u8> {
+            Vec::new()
+        }
+
+        fn deserialize_params(input: &[u8], _: &Self) -> Result<Self, ProtocolError> {
+            if input.is_empty() {
+                Ok(Self)
+            } else {
+                Err(ProtocolError::SerializationError)
+            }
+        }
+    }
+
+    fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError> {
+        let password = b"password";
+        let salt = b"salt";
+        let credential_identifier = b"credentialIdentifier";
+        let mut rng = OsRng;
+        let server_setup = ServerSetup::<CS>::new(&mut rng);
+
+        let mut stored_hash = [0; 32];
+        HkdfLegacyHash.hash(password, salt, &mut stored_hash)?;
+        let password_file = legacy::server_registration(
+            &mut rng,
+            &server_setup,
+            credential_identifier,
+            &stored_hash,
+            ClientRegistrationFinishParameters::default(),
+        )?;
+
+        // The server sends the salt to the client.
+        let parameters = LegacyParameters::new(HkdfLegacyHash, salt)?.serialize();
+
+        let login = |password: &[u8]| {
+            let mut rng = OsRng;
+            let mut legacy_hash = [0; 32];
+            LegacyParameters::deserialize(&parameters, &HkdfLegacyHash)?
+                .hash(password, &mut legacy_hash)?;
+
+            let client_login_start_result = ClientLogin::<CS>::start(&mut rng, &legacy_hash)?;
+            let server_login_start_result = ServerLogin::start(
+                &mut rng,
+                &server_setup,
+                Some(password_file.clone()),
+                client_login_start_result.message,
+                credential_identifier,
+                ServerLoginParameters::default(),
+            )?;
+            let client_login_finish_result = client_login_start_result.state.finish(
+                &mut rng,
+                &legacy_hash,
+                server_login_start_result.message,
+                ClientLoginFinishParameters::default(),
+            )?;
+            let server_login_finish_result = server_login_start_result.state.finish(
+                client_login_finish_result.message,
+                ServerLoginParameters::default(),
+            )?;
+
+            assert_eq!(
+                client_login_finish_result.session_key,
+                server_login_finish_result.session_key
+            );
+
+            Ok::<_, ProtocolError>(())
+        };
+
+        login(password)?;
+        assert!(matches!(
+            login(b"wrong password"),
+            Err(ProtocolError::InvalidLoginError)
+        ));
+
+        Ok(())
+    }
+
+    triple_dh_ciphersuites!(run_all!(inner));
+    #[cfg(feature = "kem")]
+    triple_dh_kem_ciphersuites!(run_all!(inner));
+    sigma_i_ciphersuites!(run_all!(inner));
+
+    // Unknown clients get a stable salt of the same length.
+    let dummy = |credential_identifier: &[u8]| {
+        LegacyParameters::dummy::<P256TripleDhP256>(
+            HkdfLegacyHash,
+            b"secret",
+            credential_identifier,
+            16,
+        )
+    };
+    assert_eq!(dummy(b"alice")?.salt(), dummy(b"alice")?.salt());
+    assert_ne!(dummy(b"alice")?.salt(), dummy(b"bob")?.salt());
+    assert_eq!(dummy(b"alice")?.salt().len(), 16);
+
+    let mut serialized = LegacyParameters::new(HkdfLegacyHash, b"salt")?.serialize();
+    serialized.push(0);
+    assert!(matches!(
+        LegacyParameters::deserialize(&serialized, &HkdfLegacyHash),
+        Err(ProtocolError::SerializationError)
+    ));
+
+    #[cfg(feature = "argon2")]
+    {
+        let argon2 = legacy::Argon2 {
+            algorithm: argon2::Algorithm::Argon2i,
+            version: argon2::Version::V0x10,
+            params: argon2::Params::new(1024, 2, 1, None).unwrap(),
+        };
+        let parameters = LegacyParameters::new(argon2.clone(), b"saltsaltsaltsalt")?.serialize();
+        let parameters = LegacyParameters::deserialize(&parameters, &argon2)?;
+        assert_eq!(parameters.legacy_hash(), &argon2);
+
+        // The client rejects parameters above its limits.
+        for limits in [(1023, 2, 1), (1024, 1, 1)] {
+            let limits = legacy::Argon2 {
+                params: argon2::Params::new(limits.0, limits.1, limits.2, None).unwrap(),
+                ..argon2.clone()
+            };
+            assert!(matches!(
+                LegacyParameters::deserialize(&parameters.serialize(), &limits),
+                Err(ProtocolError::KsfLimitError)
+            ));
+        }
+
+        let mut output = [0; 32];
+        parameters.hash(b"password", &mut output)?;
+        let mut expected = [0; 32];
+        argon2::Argon2::new(argon2.algorithm, argon2.version, argon2.params)
+            .hash_password_into(b"password", b"saltsaltsaltsalt", &mut expected)
+            .unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[cfg(feature = "bcrypt")]
+    {
+        // `$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW` from
+        // the OpenBSD test vectors, with the password `U*U`
+        let salt = hex::decode("10410410410410410410410410410410").unwrap();
+        let expected = hex::decode("1bb69143f9a8d304c8d23d99ab049a77a68e2ccc744206").unwrap();
+
+        let bcrypt = legacy::Bcrypt { cost: 5 };
+        let parameters = LegacyParameters::new(bcrypt, &salt)?.serialize();
+        let parameters = LegacyParameters::deserialize(&parameters, &bcrypt)?;
+        let mut output = [0; 23];
+        parameters.hash(b"U*U", &mut output)?;
+        assert_eq!(output.as_slice(), expected);
+
+        let limits = legacy::Bcrypt { cost: 31 };
+        assert!(legacy::Bcrypt::deserialize_params(&[3], &limits).is_err());
+        assert!(legacy::Bcrypt::deserialize_params(&[32], &limits).is_err());
+        assert!(matches!(
+            legacy::Bcrypt::deserialize_params(&[5], &legacy::Bcrypt { cost: 4 }),
+            Err(ProtocolError::KsfLimitError)
+        ));
+    }
+
+    #[cfg(feature = "scrypt")]
+    {
+        let scrypt = scrypt::Params::new(10, 8, 1, 32).unwrap();
+        let parameters = LegacyParameters::new(scrypt, b"saltsaltsaltsalt")?.serialize();
+        assert!(matches!(
+            LegacyParameters::deserialize(&parameters, &scrypt::Params::new(9, 8, 1, 32).unwrap()),
+            Err(ProtocolError::KsfLimitError)
+        ));
+        let parameters = LegacyParameters::deserialize(&parameters, &scrypt)?;
+
+        let mut output = [0; 32];
+        parameters.hash(b"password", &mut output)?;
+        let mut expected = [0; 32];
+        scrypt::scrypt(b"password", b"saltsaltsaltsalt", &scrypt, &mut expected).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_framing() -> Result<(), ProtocolError> {
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        type OprfCs = p256::NistP256;
+        type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
+        type Ksf = Identity;
+    }
+
+    const SUITE_ID: u16 = 0x0102;
+
+    let password = b"password";
+    let credential_identifier = b"credentialIdentifier";
+    let mut rng = OsRng;
+    let server_setup = ServerSetup::<Suite>::new(&mut rng);
+    let framed_server_setup = server_setup.serialize_framed(SUITE_ID);
+    assert_eq!(
+        framed_server_setup[..8],
+        [b'O', b'P', b'Q', b'K', 1, 0x01, 0x02, 7]
+    );
+    assert_eq!(framed_server_setup[8..], server_setup.serialize()[..]);
+    let server_setup = ServerSetup::<Suite>::deserialize_framed(SUITE_ID, &framed_server_setup)?;
+
+    let client_registration_start_result = ClientRegistration::<Suite>::start(&mut rng, password)?;
+    let message = RegistrationRequest::deserialize_framed(
+        SUITE_ID,
+        &client_registration_start_result
+            .message
+            .serialize_framed(SUITE_ID),
+    )?;
+    let server_registration_start_result =
+        ServerRegistration::<Suite>::start(&server_setup, message, credential_identifier)?;
+    let message = RegistrationResponse::deserialize_framed(
+        SUITE_ID,
+        &server_registration_start_result
+            .message
+            .serialize_framed(SUITE_ID),
+    )?;
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        message,
+        ClientRegistrationFinishParameters::default(),
+    )?;
+    let message = RegistrationUpload::<Suite>::deserialize_framed(
+        SUITE_ID,
+        &client_registration_finish_result
+            .message
+            .serialize_framed(SUITE_ID),
+    )?;
+    let password_file = ServerRegistration::finish(message);
+    let password_file = ServerRegistration::deserialize_framed(
+        SUITE_ID,
+        &password_file.serialize_framed(SUITE_ID),
+    )?;
+
+    let client_login_start_result = ClientLogin::<Suite>::start(&mut rng, password)?;
+    let message = CredentialRequest::deserialize_framed(
+        SUITE_ID,
+        &client_login_start_result.message.serialize_framed(SUITE_ID),
+    )?;
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        Some(password_file.clone()),
+        message,
+        credential_identifier,
+        ServerLoginParameters::default(),
+    )?;
+    let message = CredentialResponse::deserialize_framed(
+        SUITE_ID,
+        &server_login_start_result.message.serialize_framed(SUITE_ID),
+    )?;
+    let client_login_finish_result = client_login_start_result.state.finish(
+        &mut rng,
+        password,
+        message,
+        ClientLoginFinishParameters::default(),
+    )?;
+    let message = CredentialFinalization::deserialize_framed(
+        SUITE_ID,
+        &client_login_finish_result
+            .message
+            .serialize_framed(SUITE_ID),
+    )?;
+    let server_login_finish_result = server_login_start_result
+        .state
+        .finish(message, ServerLoginParameters::default())?;
+    assert_eq!(
+        client_login_finish_result.session_key,
+        server_login_finish_result.session_key
+    );
+
+    let framed_password_file = password_file.serialize_framed(SUITE_ID);
+
+    let mut invalid = framed_password_file.clone();
+    invalid[0] ^= 1;
+    assert!(matches!(
+        ServerRegistration::<Suite>::deserialize_framed(SUITE_ID, &invalid),
+        Err(ProtocolError::InvalidMagicError)
+    ));
+
+    let mut invalid = framed_password_file.clone();
+    invalid[4] = 2;
+    assert!(matches!(
+        ServerRegistration::<Suite>::deserialize_framed(SUITE_ID, &invalid),
+        Err(ProtocolError::UnsupportedVersionError(2))
+    ));
+
+    assert!(matches!(
+        ServerRegistration::<Suite>::deserialize_framed(0x0201, &framed_password_file),
+        Err(ProtocolError::CipherSuiteMismatchError {
+            expected: 0x0201,
+            actual: SUITE_ID,
+        })
+    ));
+
+    assert!(matches!(
+        RegistrationUpload::<Suite>::deserialize_framed(SUITE_ID, &framed_password_file),
+        Err(ProtocolError::MessageTypeMismatchError {
+            expected: 3,
+            actual: 8,
+        })
+    ));
+
+    assert!(matches!(
+        ServerRegistration::<Suite>::deserialize_framed(SUITE_ID, &framed_password_file[..7]),
+        Err(ProtocolError::SizeError { .. })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "seal")]
+#[test]
+fn test_seal() -> Result<(), ProtocolError> {
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        const SUITE_ID: u16 = 0x0102;
+
+        type OprfCs = p256::NistP256;
+        type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
+        type Ksf = Identity;
+    }
+
+    let mut rng = OsRng;
+    let server_setup = ServerSetup::<Suite>::new(&mut rng);
+    let kek = [1; 32];
+    // Cheap parameters to keep the test fast.
+    let params = argon2::Params::new(8, 1, 1, None).unwrap();
+
+    let sealed = server_setup.seal_with_key(&mut rng, &kek)?;
+    assert_eq!(sealed[..8], [b'O', b'P', b'Q', b'S', 1, 1, 0x01, 0x02]);
+    assert_eq!(
+        ServerSetup::<Suite>::open_with_key(&kek, &sealed)?,
+        server_setup
+    );
+    // The nonce is random.
+    assert_ne!(server_setup.seal_with_key(&mut rng, &kek)?, sealed);
+
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&[2; 32], &sealed),
+        Err(ProtocolError::SealOpenError)
+    ));
+
+    for index in [5, 8, 8 + 24, sealed.len() - 1] {
+        let mut invalid = sealed.clone();
+        invalid[index] ^= 1;
+        assert!(ServerSetup::<Suite>::open_with_key(&kek, &invalid).is_err());
+    }
+
+    let mut invalid = sealed.clone();
+    invalid[0] ^= 1;
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&kek, &invalid),
+        Err(ProtocolError::InvalidMagicError)
+    ));
+
+    let mut invalid = sealed.clone();
+    invalid[4] = 2;
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&kek, &invalid),
+        Err(ProtocolError::UnsupportedVersionError(2))
+    ));
+
+    let mut invalid = sealed.clone();
+    invalid[7] = 0x03;
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&kek, &invalid),
+        Err(ProtocolError::CipherSuiteMismatchError {
+            expected: 0x0102,
+            actual: 0x0103,
+        })
+    ));
+
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&kek, &sealed[..sealed.len() - 1]),
+        Err(ProtocolError::SealOpenError)
+    ));
+
+    let sealed = server_setup.seal_with_passphrase(&mut rng, b"passphrase", &params)?;
+    assert_eq!(sealed[..8], [b'O', b'P', b'Q', b'S', 1, 2, 0x01, 0x02]);
+    assert_eq!(sealed[8..20], [0, 0, 0, 8, 0, 0, 0, 1, 0, 0, 0, 1]);
+    assert_eq!(
+        ServerSetup::<Suite>::open_with_passphrase(b"passphrase", &params, &sealed)?,
+        server_setup
+    );
+
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_passphrase(b"Passphrase", &params, &sealed),
+        Err(ProtocolError::SealOpenError)
+    ));
+
+    // Modifying the parameters or the salt changes the derived key.
+    for index in [11, 20] {
+        let mut invalid = sealed.clone();
+        invalid[index] ^= 1;
+        assert!(
+            ServerSetup::<Suite>::open_with_passphrase(b"passphrase", &params, &invalid).is_err()
+        );
+    }
+
+    // Parameters exceeding the limits are rejected before deriving the key.
+    for index in [8, 12, 16] {
+        let mut invalid = sealed.clone();
+        invalid[index] = 0xff;
+        assert!(matches!(
+            ServerSetup::<Suite>::open_with_passphrase(b"passphrase", &params, &invalid),
+            Err(ProtocolError::KsfLimitError)
+        ));
+    }
+    let limits = argon2::Params::new(1024, 1, 1, None).unwrap();
+    let sealed_with_limits = server_setup.seal_with_passphrase(&mut rng, b"passphrase", &limits)?;
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_passphrase(b"passphrase", &params, &sealed_with_limits),
+        Err(ProtocolError::KsfLimitError)
+    ));
+
+    // The key derivation has to match.
+    assert!(matches!(
+        ServerSetup::<Suite>::open_with_key(&kek, &sealed),
+        Err(ProtocolError::SerializationError)
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "backup")]
+#[test]
+fn test_backup() -> Result<(), ProtocolError> {
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        const SUITE_ID: u16 = 0x0102;
+
+        type OprfCs = p256::NistP256;
+        type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
+        type Ksf = Identity;
+    }
+
+    let mut rng = OsRng;
+    let server_setup = ServerSetup::<Suite>::new(&mut rng);
+
+    let shares = server_setup.split_backup(&mut rng, 3, 5)?;
+    assert_eq!(shares.len(), 5);
+
+    for (index, share) in shares.iter().enumerate() {
+        assert_eq!(
+            share[..9],
+            [b'O', b'P', b'Q', b'B', 1, 0x01, 0x02, 3, index as u8 + 1]
+        );
+    }
+
+    // Every subset of at least three shares restores the exact server setup.
+    for subset in 0..32_u32 {
+        let subset: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| subset & (1 << index) != 0)
+            .map(|(_, share)| share.as_slice())
+            .collect();
+
+        if subset.len() >= 3 {
+            assert_eq!(ServerSetup::<Suite>::restore(&subset)?, server_setup);
+        } else if !subset.is_empty() {
+            assert!(matches!(
+                ServerSetup::<Suite>::restore(&subset),
+                Err(ProtocolError::InsufficientBackupSharesError {
+                    threshold: 3,
+                    actual,
+                }) if actual == subset.len()
+            ));
+        }
+    }
+
+    assert!(matches!(
+        ServerSetup::<Suite>::restore(&[&shares[0], &shares[1], &shares[1]]),
+        Err(ProtocolError::InvalidBackupShareError)
+    ));
+
+    let mut corrupted = shares[1].clone();
+    corrupted[20] ^= 1;
+    assert!(matches!(
+        ServerSetup::<Suite>::restore(&[&shares[0], &corrupted, &shares[2]]),
+        Err(ProtocolError::InvalidBackupShareError)
+    ));
+
+    // The commitment is randomized, so it doesn't reveal whether two backups
+    // are of the same server setup.
+    let other = server_setup.split_backup(&mut rng, 3, 5)?;
+    assert_ne!(shares[0][9..9 + 32], other[0][9..9 + 32]);
+    assert!(matches!(
+        ServerSetup::<Suite>::restore(&[&shares[0], &shares[1], &other[2]]),
+        Err(ProtocolError::InvalidBackupShareError)
+    ));
+
+    let mut invalid = shares[0].clone();
+    invalid[4] = 2;
+    assert!(matches!(
+        ServerSetup::<Suite>::restore(&[&invalid, &shares[1], &shares[2]]),
+        Err(ProtocolError::UnsupportedVersionError(2))
+    ));
+
+    assert!(matches!(
+        ServerSetup::<Suite>::restore(&[&shares[0][..40]]),
+        Err(ProtocolError::InvalidBackupShareError)
+    ));
+
+    for (threshold, shares) in [(0, 3), (4, 3)] {
+        assert!(matches!(
+            server_setup.split_backup(&mut rng, threshold, shares),
+            Err(ProtocolError::InvalidBackupThresholdError { .. })
+        ));
+    }
+
+    let shares = server_setup.split_backup(&mut rng, 1, 1)?;
+    assert_eq!(ServerSetup::<Suite>::restore(&shares)?, server_setup);
+
+    let shares = server_setup.split_backup(&mut rng, 2, 255)?;
+    assert_eq!(ServerSetup::<Suite>::restore(&shares[253..])?, server_setup);
+
+    Ok(())
+}
+
+// Runs a complete flow with the KSF parameters `ksf`, which fails when
+// logging in with `other_ksf`.
+#[cfg(any(feature = "pbkdf2", feature = "scrypt"))]
+fn test_ksf_flow<CS: CipherSuite>(ksf: &CS::Ksf, other_ksf: &CS::Ksf) -> Result<(), ProtocolError> {
+    let mut rng = OsRng;
+    let password = b"password";
+    let server_setup = ServerSetup::<CS>::new(&mut rng);
+
+    let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+    let server_registration_start_result = ServerRegistration::<CS>::start(
+        &server_setup,
+        client_registration_start_result.message,
+        b"credential identifier",
+    )?;
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        server_registration_start_result.message,
+        ClientRegistrationFinishParameters::new(Identifiers::default(), Some(ksf)),
+    )?;
+    let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+    for (login_ksf, success) in [(ksf, true), (other_ksf, false)] {
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let server_login_start_result = ServerLogin::start(
+            &mut rng,
+            &server_setup,
+            Some(password_file.clone()),
+            client_login_start_result.message,
+            b"credential identifier",
+            ServerLoginParameters::default(),
+        )?;
+        let client_login_finish_result = client_login_start_result.state.finish(
+            &mut rng,
+            password,
+            server_login_start_result.message,
+            ClientLoginFinishParameters::new(None, Identifiers::default(), Some(login_ksf)),
+        );
+
+        if success {
+            let client_login_finish_result = client_login_finish_result?;
+            let server_login_finish_result = server_login_start_result.state.finish(
+                client_login_finish_result.message,
+                ServerLoginParameters::default(),
+            )?;
+            assert_eq!(
+                client_login_finish_result.session_key,
+                server_login_finish_result.session_key
+            );
+            assert_eq!(
+                client_login_finish_result.export_key,
+                client_registration_finish_result.export_key
+            );
+        } else {
+            assert!(matches!(
+                client_login_finish_result,
+                Err(ProtocolError::InvalidLoginError)
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "p256", feature = "scrypt"))]
+#[test]
+fn test_scrypt() -> Result<(), ProtocolError> {
+    use generic_array::typenum::{U32, U48, U64};
+
+    use crate::ksf::Ksf;
+
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        type OprfCs = p256::NistP256;
+        type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
+        type Ksf = scrypt::Params;
+    }
+
+    // Vectors computed with an independent scrypt implementation and a salt of
+    // 16 zero bytes.
+    let params = scrypt::Params::new(4, 8, 1, 10).unwrap();
+    let input = GenericArray::<u8, U64>::from_exact_iter(0..64).unwrap();
+    assert_eq!(
+        hex::encode(params.hash(input)?),
+        "eab3ac23cc0604f60b13ddc4f3f6b9777b32f2b71827fe48eca7ee9ccbf12217\
+         89149fcabec2503b952a95d7b7d5d7f29f8ed51123d6a8f443f95e143aab5610"
+    );
+
+    let params = scrypt::Params::new(10, 8, 2, 10).unwrap();
+    let input = GenericArray::<u8, U32>::clone_from_slice(b"opaque-ke scrypt key stretching!");
+    assert_eq!(
+        hex::encode(params.hash(input)?),
+        "8684535185c14a3472be012bdcc8dc5d480375ba38f21e5724675634c462acb1"
+    );
+
+    let params = scrypt::Params::new(8, 1, 1, 10).unwrap();
+    assert_eq!(
+        hex::encode(params.hash(GenericArray::<u8, U48>::default())?),
+        "7eb0db603c4f4ba3543063a42eacc34ee4d4ec8e779973236ab33b3092b05f1a\
+         4f7471901d33b023bdef3b754791102b"
+    );
+
+    // The login has to use the same parameters as the registration.
+    test_ksf_flow::<Suite>(
+        &scrypt::Params::new(4, 8, 1, 32).unwrap(),
+        &scrypt::Params::new(5, 8, 1, 32).unwrap(),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "pbkdf2", feature = "p256"))]
+#[test]
+fn test_pbkdf2() -> Result<(), ProtocolError> {
+    use generic_array::typenum::{U32, U64};
+
+    use crate::ksf::{Ksf, Pbkdf2};
+    use crate::suites::P256Sha256TripleDh;
+
+    // Vectors computed with an independent PBKDF2 implementation and a salt of
+    // 16 zero bytes.
+    let input = GenericArray::<u8, U64>::from_exact_iter(0..64).unwrap();
+    assert_eq!(
+        hex::encode(Pbkdf2::<sha2::Sha256>::new(1000).hash(input)?),
+        "f2660883579281c7328ac93863c165d28dd1778e455d37136a6c8c2d71a83d39\
+         5ab29812f4baf87282c55843c259a5fd82096637858449efc5788128b0b8ca8a"
+    );
+
+    let input = GenericArray::<u8, U32>::clone_from_slice(b"opaque-ke pbkdf2 key stretching!");
+    assert_eq!(
+        hex::encode(Pbkdf2::<sha2::Sha256>::new(1).hash(input)?),
+        "f7f8e32c2bc7936e919f8a0fd8cb27c6a5bc8a93c53d75cefb6aade0663b3bba"
+    );
+
+    assert_eq!(
+        hex::encode(Pbkdf2::<sha2::Sha512>::new(4096).hash(GenericArray::<u8, U64>::default())?),
+        "61ce7dbec3616e9fc17e0c32578b3f380337b1b61a1f3cb9651aee30670e6fb3\
+         970419a23a2e4e4082d12bf78faa8645dfc882cee2ae7179e2b07fe88098abb2"
+    );
+
+    assert!(matches!(
+        Pbkdf2::<sha2::Sha256>::new(0).hash(GenericArray::<u8, U32>::default()),
+        Err(InternalError::KsfError)
+    ));
+    assert_eq!(Pbkdf2::<sha2::Sha256>::default().iterations(), 600_000);
+    assert_eq!(Pbkdf2::<sha2::Sha512>::default().iterations(), 210_000);
+
+    // The login has to use the same number of iterations as the registration.
+    test_ksf_flow::<P256Sha256TripleDh<Pbkdf2>>(&Pbkdf2::new(1000), &Pbkdf2::new(1001))?;
+
+    Ok(())
+}
+
+#[cfg(all(feature = "p256", feature = "pbkdf2"))]
+#[test]
+fn test_ksf_with_context() -> Result<(), ProtocolError> {
+    use generic_array::typenum::U32;
+
+    use crate::ksf::{Ksf, Pbkdf2, WithContext};
+
+    struct CS;
+
+    impl CipherSuite for CS {
+        type OprfCs = p256::NistP256;
+        type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
+        type Ksf = WithContext<Pbkdf2>;
+    }
+
+    let input = GenericArray::<u8, U32>::clone_from_slice(b"opaque-ke pbkdf2 key stretching!");
+    let alice = Identifiers {
+        client: Some(b"alice"),
+        server: Some(b"example.com"),
+    };
+    let bob = Identifiers {
+        client: Some(b"bob"),
+        server: Some(b"example.com"),
+    };
+
+    // The salt is the fixed salt followed by the length-prefixed identities.
+    let mut salt = [0; 16].to_vec();
+    salt.extend_from_slice(b"\x00\x05alice\x00\x0bexample.com");
+    let mut expected = GenericArray::<u8, U32>::default();
+    pbkdf2::pbkdf2::<hmac::Hmac<sha2::Sha256>>(&input, &salt, 1, &mut expected).unwrap();
+
+    let ksf = WithContext(Pbkdf2::<sha2::Sha256>::new(1));
+    assert_eq!(ksf.hash_with_identifiers(input, alice)?, expected);
+    assert_ne!(ksf.hash_with_identifiers(input, bob)?, expected);
+    // The client identity is required, the server identity is optional.
+    assert!(matches!(
+        ksf.hash_with_identifiers(input, Identifiers::default()),
+        Err(InternalError::KsfError)
+    ));
+    let mut salt = [0; 16].to_vec();
+    salt.extend_from_slice(b"\x00\x05alice\x00\x00");
+    pbkdf2::pbkdf2::<hmac::Hmac<sha2::Sha256>>(&input, &salt, 1, &mut expected).unwrap();
+    assert_eq!(
+        ksf.hash_with_identifiers(
+            input,
+            Identifiers {
+                client: Some(b"alice"),
+                server: None,
+            }
+        )?,
+        expected
+    );
+    // Without context the fixed salt is used.
+    assert_eq!(
+        ksf.hash(input)?,
+        Pbkdf2::<sha2::Sha256>::new(1).hash(input)?
+    );
+    assert_eq!(
+        Pbkdf2::<sha2::Sha256>::new(1).hash_with_identifiers(input, alice)?,
+        ksf.hash(input)?
+    );
+
+    let mut rng = OsRng;
+    let password = b"password";
+    let server_setup = ServerSetup::<CS>::new(&mut rng);
+    let ksf = WithContext(Pbkdf2::new(1000));
+
+    // Registering without a client identity fails instead of falling back to
+    // a salt shared by all users.
+    let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+    let server_registration_start_result = ServerRegistration::<CS>::start(
+        &server_setup,
+        client_registration_start_result.message,
+        b"alice",
+    )?;
+    assert!(matches!(
+        client_registration_start_result.state.finish(
+            &mut rng,
+            password,
+            server_registration_start_result.message,
+            ClientRegistrationFinishParameters::new(Identifiers::default(), Some(&ksf)),
+        ),
+        Err(ProtocolError::LibraryError(InternalError::KsfError))
+    ));
+
+    let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+    let server_registration_start_result = ServerRegistration::<CS>::start(
+        &server_setup,
+        client_registration_start_result.message,
+        b"alice",
+    )?;
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        server_registration_start_result.message,
+        ClientRegistrationFinishParameters::new(alice, Some(&ksf)),
+    )?;
+    let password_file = ServerRegistration::finish(client_registration_finish_result.message);
+
+    let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        Some(password_file),
+        client_login_start_result.message,
+        b"alice",
+        ServerLoginParameters::new(None, alice),
+    )?;
+    let client_login_finish_result = client_login_start_result.state.finish(
+        &mut rng,
+        password,
+        server_login_start_result.message,
+        ClientLoginFinishParameters::new(None, alice, Some(&ksf)),
+    )?;
+    let server_login_finish_result = server_login_start_result.state.finish(
+        client_login_finish_result.message,
+        ServerLoginParameters::new(None, alice),
+    )?;
+    assert_eq!(
+        client_login_finish_result.session_key,
+        server_login_finish_result.session_key
+    );
+    assert_eq!(
+        client_login_finish_result.export_key,
+        client_registration_finish_result.export_key
+    );
+
+    Ok(())
+}
+
+#[cfg(all(feature = "argon2", feature = "ksf-params", feature = "std"))]
+#[test]
+fn test_calibrate() -> Result<(), ProtocolError> {
+    use core::time::Duration;
+
+    use crate::ksf::{
+        ARGON2_MIN_M_COST, ARGON2_MIN_P_COST, ARGON2_MIN_T_COST, Calibrate, Ksf, calibrate,
+    };
+
+    fn argon2id(m_cost: u32, t_cost: u32) -> argon2::Argon2<'static> {
+        argon2::Argon2::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            argon2::Params::new(m_cost, t_cost, ARGON2_MIN_P_COST, None).unwrap(),
+        )
+    }
+
+    assert!(matches!(
+        calibrate(
+            Duration::from_secs(1),
+            &argon2id(ARGON2_MIN_M_COST - 1, u32::MAX)
+        ),
+        Err(ProtocolError::KsfLimitError)
+    ));
+
+    // A target that can't be met falls back to the minimums.
+    assert_eq!(
+        calibrate(Duration::ZERO, &argon2id(2 * ARGON2_MIN_M_COST, u32::MAX))?,
+        argon2::Argon2::minimum()?.to_params().unwrap()
+    );
+
+    // Spare time is spent on more iterations once the memory cost reaches the
+    // limit.
+    let limits = argon2id(ARGON2_MIN_M_COST, u32::MAX);
+    let argon2 =
+        argon2::Argon2::from_params(&calibrate(Duration::from_secs(3600), &limits)?, &limits)?;
+    let params = argon2.params();
+    assert_eq!(params.m_cost(), ARGON2_MIN_M_COST);
+    assert!(params.t_cost() > ARGON2_MIN_T_COST);
+    assert_eq!(params.p_cost(), ARGON2_MIN_P_COST);
+
+    // Four times the time doubles the memory cost up to the limit and the
+    // number of iterations with the rest.
+    let argon2 = argon2::Argon2::minimum()?.scale(
+        Duration::from_secs(1),
+        Duration::from_secs(4),
+        &argon2id(2 * ARGON2_MIN_M_COST, u32::MAX),
+    )?;
+    let params = argon2.params();
+    assert_eq!(
+        (params.m_cost(), params.t_cost()),
+        (2 * ARGON2_MIN_M_COST, 2 * ARGON2_MIN_T_COST)
+    );
+
+    #[cfg(feature = "scrypt")]
+    {
+        let limits = scrypt::Params::new(18, 8, 2, 32).unwrap();
+        let params = scrypt::Params::minimum()?.scale(
+            Duration::from_secs(1),
+            Duration::from_secs(8),
+            &limits,
+        )?;
+        assert_eq!((params.log_n(), params.r(), params.p()), (18, 8, 2));
+    }
+
+    #[cfg(feature = "pbkdf2")]
+    {
+        use crate::ksf::Pbkdf2;
+
+        let pbkdf2 = Pbkdf2::<sha2::Sha256>::new(1000);
+        let scaled = pbkdf2.scale(
+            Duration::from_millis(10),
+            Duration::from_millis(100),
+            &Pbkdf2::new(u32::MAX),
+        )?;
+        assert_eq!(scaled.iterations(), 10_000);
+        let scaled = pbkdf2.scale(
+            Duration::from_millis(10),
+            Duration::from_millis(100),
+            &Pbkdf2::new(5000),
+        )?;
+        assert_eq!(scaled.iterations(), 5000);
+        let scaled = pbkdf2.scale(
+            Duration::from_millis(10),
+            Duration::from_millis(1),
+            &Pbkdf2::new(5000),
+        )?;
+        assert_eq!(scaled.iterations(), 1000);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "ksf-params", feature = "p256", feature = "pbkdf2"))]
+#[test]
+fn test_ksf_params() -> Result<(), ProtocolError> {
+    use crate::ksf::{Ksf, KsfParams, Pbkdf2};
+    use crate::suites::P256Sha256TripleDh;
+
+    type CS = P256Sha256TripleDh<Pbkdf2>;
+
+    let mut rng = OsRng;
+    let password = b"password";
+    let server_setup = ServerSetup::<CS>::new(&mut rng);
+    let ksf = Pbkdf2::new(1000);
+
+    let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+    let server_registration_start_result = ServerRegistration::<CS>::start(
+        &server_setup,
+        client_registration_start_result.message,
+        b"credential identifier",
+    )?;
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        server_registration_start_result.message,
+        ClientRegistrationFinishParameters::new(Identifiers::default(), Some(&ksf)),
+    )?;
+    let upload = RegistrationUpload::<CS>::deserialize(
+        &client_registration_finish_result
+            .message
+            .serialize_with_extensions(),
+    )?;
+    assert_eq!(upload.ksf_params(), ksf.to_params().as_ref());
+
+    let password_file = ServerRegistration::finish(upload);
+    let password_file =
+        ServerRegistration::<CS>::deserialize(&password_file.serialize_with_extensions())?;
+    assert_eq!(password_file.ksf_params(), ksf.to_params().as_ref());
+    assert_eq!(
+        password_file
+            .ksf_params()
+            .map(|params| Pbkdf2::<sha2::Sha256>::from_params(params, &Pbkdf2::default()))
+            .transpose()?,
+        Some(ksf)
+    );
+    // The server detects records registered with outdated parameters.
+    assert_ne!(
+        password_file.ksf_params(),
+        Pbkdf2::<sha2::Sha256>::default().to_params().as_ref()
+    );
+
+    // The parameters follow the record as an extension: its tag, its length
+    // and the identifier followed by the parameters. Unknown, repeated and
+    // truncated extensions are rejected.
+    let record = password_file.serialize_with_extensions();
+    let extensions = &record[password_file.serialize().len()..];
+    assert_eq!(extensions, [0, 1, 0, 6, 0, 3, 0, 0, 0x03, 0xe8]);
+    for (extra, error) in [
+        (
+            &[0, 9, 0, 0][..],
+            ProtocolError::UnsupportedExtensionError(9),
+        ),
+        (extensions, ProtocolError::SerializationError),
+        (&[0, 9, 0, 1][..], ProtocolError::SerializationError),
+        (&[0][..], ProtocolError::SerializationError),
+    ] {
+        let mut record = record.clone();
+        record.extend_from_slice(extra);
+        assert_eq!(
+            ServerRegistration::<CS>::deserialize(&record).unwrap_err(),
+            error
+        );
+    }
+
+    // The client isn't configured with a KSF and picks up the parameters from
+    // the response, tampering with them fails like a wrong password.
+    for tamper in [false, true] {
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let server_login_start_result = ServerLogin::start(
+            &mut rng,
+            &server_setup,
+            Some(password_file.clone()),
+            client_login_start_result.message,
+            b"credential identifier",
+            ServerLoginParameters::default(),
+        )?;
+        let mut credential_response = server_login_start_result
+            .message
+            .serialize_with_extensions();
+
+        if tamper {
+            *credential_response.last_mut().unwrap() ^= 1;
+        }
+
+        let credential_response = CredentialResponse::<CS>::deserialize(&credential_response)?;
+        assert_eq!(
+            credential_response
+                .ksf_params()
+                .map(|params| params.params()),
+            Some(&[0, 0, 0x03, 0xe8 ^ u8::from(tamper)][..])
+        );
+
+        let client_login_finish_result = client_login_start_result.state.finish(
+            &mut rng,
+            password,
+            credential_response,
+            ClientLoginFinishParameters::default(),
+        );
+
+        if tamper {
+            assert!(matches!(
+                client_login_finish_result,
+                Err(ProtocolError::InvalidLoginError)
+            ));
+        } else {
+            let client_login_finish_result = client_login_finish_result?;
+            let server_login_finish_result = server_login_start_result.state.finish(
+                client_login_finish_result.message,
+                ServerLoginParameters::default(),
+            )?;
+            assert_eq!(
+                client_login_finish_result.session_key,
+                server_login_finish_result.session_key
+            );
+            assert_eq!(
+                client_login_finish_result.export_key,
+                client_registration_finish_result.export_key
+            );
+        }
+    }
+
+    // The client rejects parameters above its limits before computing the KSF.
+    for (ksf_limits, success) in [(Pbkdf2::new(1000), true), (Pbkdf2::new(999), false)] {
+        let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+        let server_login_start_result = ServerLogin::start(
+            &mut rng,
+            &server_setup,
+            Some(password_file.clone()),
+            client_login_start_result.message,
+            b"credential identifier",
+            ServerLoginParameters::default(),
+        )?;
+        let client_login_finish_result = client_login_start_result.state.finish(
+            &mut rng,
+            password,
+            server_login_start_result.message,
+            ClientLoginFinishParameters::default().with_ksf_limits(&ksf_limits),
+        );
+
+        if success {
+            assert_eq!(
+                client_login_finish_result?.export_key,
+                client_registration_finish_result.export_key
+            );
+        } else {
+            assert!(matches!(
+                client_login_finish_result,
+                Err(ProtocolError::KsfLimitError)
+            ));
+        }
+    }
+
+    // The parameters don't enter the key derivation, a record serialized
+    // without them works with a client configured with the same KSF.
+    let password_file = ServerRegistration::<CS>::deserialize(&password_file.serialize())?;
+    assert_eq!(password_file.ksf_params(), None);
+    let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        Some(password_file),
+        client_login_start_result.message,
+        b"credential identifier",
+        ServerLoginParameters::default(),
+    )?;
+    let client_login_finish_result = client_login_start_result.state.finish(
+        &mut rng,
+        password,
+        server_login_start_result.message,
+        ClientLoginFinishParameters::new(None, Identifiers::default(), Some(&ksf)),
+    )?;
+    assert_eq!(
+        client_login_finish_result.export_key,
+        client_registration_finish_result.export_key
+    );
+
+    // Unknown users get the parameters of new registrations.
+    let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        None,
+        client_login_start_result.message,
+        b"unknown",
+        ServerLoginParameters::default(),
+    )?;
+    assert_eq!(
+        server_login_start_result.message.ksf_params(),
+        Pbkdf2::<sha2::Sha256>::default().to_params().as_ref()
+    );
+
+    // Identifier 0 is reserved for the absence of parameters.
+    assert!(KsfParams::new(0, Vec::new()).is_err());
+    assert!(matches!(
+        Pbkdf2::<sha2::Sha256>::from_params(
+            &KsfParams::new(KsfParams::PBKDF2, vec![0; 4])?,
+            &Pbkdf2::default()
+        ),
+        Err(ProtocolError::LibraryError(InternalError::KsfError))
+    ));
+
+    #[cfg(feature = "scrypt")]
+    {
+        let params = scrypt::Params::new(10, 8, 2, 32).unwrap();
+        let encoded = params.to_params().unwrap();
+        assert_eq!(encoded.id(), KsfParams::SCRYPT);
+        let decoded = scrypt::Params::from_params(&encoded, &params)?;
+        assert_eq!(
+            (decoded.log_n(), decoded.r(), decoded.p()),
+            (params.log_n(), params.r(), params.p())
+        );
+        for limits in [(9, 8, 2), (10, 7, 2), (10, 8, 1)] {
+            let limits = scrypt::Params::new(limits.0, limits.1, limits.2, 32).unwrap();
+            assert!(matches!(
+                scrypt::Params::from_params(&encoded, &limits),
+                Err(ProtocolError::KsfLimitError)
+            ));
+        }
+        assert!(Pbkdf2::<sha2::Sha256>::from_params(&encoded, &Pbkdf2::default()).is_err());
+    }
+
+    #[cfg(feature = "argon2")]
+    {
+        let params = argon2::Params::new(1024, 2, 1, None).unwrap();
+        let argon2 = argon2::Argon2::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params.clone(),
+        );
+        let encoded = argon2.to_params().unwrap();
+        assert_eq!(encoded.id(), KsfParams::ARGON2ID);
+        assert_eq!(
+            argon2::Argon2::from_params(&encoded, &argon2)?.params(),
+            &params
+        );
+        for (algorithm, version) in [
+            (argon2::Algorithm::Argon2i, argon2::Version::V0x13),
+            (argon2::Algorithm::Argon2d, argon2::Version::V0x13),
+            (argon2::Algorithm::Argon2id, argon2::Version::V0x10),
+        ] {
+            assert!(
+                argon2::Argon2::new(algorithm, version, params.clone())
+                    .to_params()
+                    .is_none()
+            );
+        }
+        let with_data = argon2::ParamsBuilder::new()
+            .m_cost(1024)
+            .t_cost(2)
+            .p_cost(1)
+            .data(argon2::AssociatedData::new(b"data").unwrap())
+            .build()
+            .unwrap();
+        assert!(
+            argon2::Argon2::new(
+                argon2::Algorithm::Argon2id,
+                argon2::Version::V0x13,
+                with_data
+            )
+            .to_params()
+            .is_none()
+        );
+        for limits in [(1023, 2, 1), (1024, 1, 1)] {
+            let limits = argon2::Argon2::new(
+                argon2::Algorithm::Argon2id,
+                argon2::Version::V0x13,
+                argon2::Params::new(limits.0, limits.1, limits.2, None).unwrap(),
+            );
+            assert!(matches!(
+                argon2::Argon2::from_params(&encoded, &limits),
+                Err(ProtocolError::KsfLimitError)
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(
+    feature = "ksf-params",
+    feature = "p256",
+    feature = "payload",
+    feature = "pbkdf2"
+))]
+#[test]
+fn test_framed_extensions() -> Result<(), ProtocolError> {
+    use crate::ksf::{Ksf, Pbkdf2};
+    use crate::suites::P256Sha256TripleDh;
+
+    type CS = P256Sha256TripleDh<Pbkdf2>;
+
+    const SUITE_ID: u16 = 0x0102;
+
+    let mut rng = OsRng;
+    let password = b"password";
+    let server_setup = ServerSetup::<CS>::new(&mut rng);
+    let ksf = Pbkdf2::new(1000);
+
+    let client_registration_start_result = ClientRegistration::<CS>::start(&mut rng, password)?;
+    let server_registration_start_result = ServerRegistration::<CS>::start(
+        &server_setup,
+        client_registration_start_result.message,
+        b"credential identifier",
+    )?;
+    let client_registration_finish_result = client_registration_start_result.state.finish(
+        &mut rng,
+        password,
+        server_registration_start_result.message,
+        ClientRegistrationFinishParameters::new(Identifiers::default(), Some(&ksf)),
+    )?;
+
+    // The framed encoding is the header followed by the serialization with
+    // extensions.
+    let message = &client_registration_finish_result.message;
+    let framed = message.serialize_framed(SUITE_ID);
+    assert_eq!(framed[8..], message.serialize_with_extensions());
+    let message = RegistrationUpload::<CS>::deserialize_framed(SUITE_ID, &framed)?;
+    assert_eq!(message.ksf_params(), ksf.to_params().as_ref());
+
+    let password_file = ServerRegistration::finish(message);
+    let framed = password_file.serialize_framed(SUITE_ID);
+    assert_eq!(framed[8..], password_file.serialize_with_extensions());
+    let password_file = ServerRegistration::<CS>::deserialize_framed(SUITE_ID, &framed)?;
+    assert_eq!(password_file.ksf_params(), ksf.to_params().as_ref());
+
+    let client_login_start_result = ClientLogin::<CS>::start(&mut rng, password)?;
+    let server_login_start_result = ServerLogin::start(
+        &mut rng,
+        &server_setup,
+        Some(password_file),
+        client_login_start_result.message,
+        b"credential identifier",
+        ServerLoginParameters::default().with_payload(b"server payload"),
+    )?;
+    let message = CredentialResponse::<CS>::deserialize_framed(
+        SUITE_ID,
+        &server_login_start_result.message.serialize_framed(SUITE_ID),
+    )?;
+    assert_eq!(message.ksf_params(), ksf.to_params().as_ref());
+
+    let client_login_finish_result = client_login_start_result.state.finish(
+        &mut rng,
+        password,
+        message,
+        ClientLoginFinishParameters::default().with_payload(b"client payload"),
+    )?;
+    assert_eq!(
+        client_login_finish_result.server_payload.as_deref(),
+        Some(b"server payload".as_slice())
+    );
+
+    let message = CredentialFinalization::<CS>::deserialize_framed(
+        SUITE_ID,
+        &client_login_finish_result
+            .message
+            .serialize_framed(SUITE_ID),
+    )?;
+    let server_login_finish_result = server_login_start_result
+        .state
+        .finish(message, ServerLoginParameters::default())?;
+    assert_eq!(
+        server_login_finish_result.client_payload.as_deref(),
+        Some(b"client payload".as_slice())
+    );
+    assert_eq!(
+        client_login_finish_result.session_key,
+        server_login_finish_result.session_key
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_named_ciphersuites() -> Result<(), ProtocolError> {
+    use crate::suites::*;
+
+    fn inner<CS: CipherSuite + NamedCipherSuite>(
+        suites: &mut Vec<(u16, &'static str)>,
+    ) -> Result<(), ProtocolError> {
+        test_complete_flow::<CS>("", b"good password", b"good password")?;
+        suites.push((CS::ID, CS::NAME));
+        Ok(())
+    }
+
+    let mut suites: Vec<(u16, &str)> = Vec::new();
+    #[cfg(feature = "ristretto255")]
+    inner::<Ristretto255Sha512TripleDh<Identity>>(&mut suites)?;
+    #[cfg(feature = "p256")]
+    inner::<P256Sha256TripleDh<Identity>>(&mut suites)?;
+    #[cfg(all(feature = "kem", feature = "ristretto255"))]
+    inner::<Ristretto255Sha512TripleDhKem<Identity>>(&mut suites)?;
+    #[cfg(all(feature = "kem", feature = "p256"))]
+    inner::<P256Sha256TripleDhKem<Identity>>(&mut suites)?;
+    #[cfg(all(feature = "ed25519", feature = "ristretto255"))]
+    inner::<Ristretto255Sha512SigmaIEd25519<Identity>>(&mut suites)?;
+    #[cfg(all(feature = "ecdsa", feature = "p256"))]
+    inner::<P256Sha256SigmaIEcdsa<Identity>>(&mut suites)?;
+    #[cfg(all(feature = "argon2", feature = "ristretto255"))]
+    inner::<Ristretto255Sha512TripleDh<argon2::Argon2<'static>>>(&mut suites)?;
+    #[cfg(all(feature = "scrypt", feature = "ristretto255"))]
+    inner::<Ristretto255Sha512TripleDh<scrypt::Params>>(&mut suites)?;
+    #[cfg(all(feature = "pbkdf2", feature = "p256"))]
+    {
+        inner::<P256Sha256TripleDh<crate::ksf::Pbkdf2<sha2::Sha256>>>(&mut suites)?;
+        inner::<P256Sha256TripleDh<crate::ksf::Pbkdf2<sha2::Sha512>>>(&mut suites)?;
+    }
+
+    for (index, (id, name)) in suites.iter().enumerate() {
+        for (other_id, other_name) in &suites[index + 1..] {
+            assert_ne!(id, other_id);
+            assert_ne!(name, other_name);
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_scalar_always_nonzero() -> Result<(), ProtocolError> {
     fn inner<CS: CipherSuite>(_test_vector: &str) -> Result<(), ProtocolError> {
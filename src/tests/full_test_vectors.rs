@@ -7,7 +7,7 @@
 // licenses.
 //
 // To regenerate these test vectors, run:
-// FULL_TEST_VECTORS_FILE=src/tests/full_test_vectors.rs cargo test --features curve25519,ecdsa,ed25519 -- generate_test_vectors
+// FULL_TEST_VECTORS_FILE=src/tests/full_test_vectors.rs cargo test --features curve25519,ecdsa,ed25519,kem,secp256k1 -- generate_test_vectors
 
 #![allow(clippy::duplicated_attributes)]
 
@@ -46,7 +46,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_RISTRETTO255: &str = r#"
     "credential_finalization": "8d3ec73f86f4204189c8a7862765488c383ec9df7d1eeb4a8b93463a981483b579a9e0e6b0129cc627247beeed7639071c5da58e9d8bd86be6d8e95094fe4f39",
     "client_registration_state": "e6b5a66409921dac759fa642ce40cd6ac6935d8a84c4b8098dc9d73795d4e302368ae23cfac76038d7ecd5fb57687c9d6dc664d5bb4d016e7d874953781bef44",
     "client_login_state": "e6b5a66409921dac759fa642ce40cd6ac6935d8a84c4b8098dc9d73795d4e302368ae23cfac76038d7ecd5fb57687c9d6dc664d5bb4d016e7d874953781bef44d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e55fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
-    "server_login_state": "8b6ee1970d420bd2e258e992cace00b41327424bac52f311136a10452d77eb2340d3b5909fa9b1bd0c224cbc6fde1420fcfe2c27da231cdbaa71823fff8920268d3ec73f86f4204189c8a7862765488c383ec9df7d1eeb4a8b93463a981483b579a9e0e6b0129cc627247beeed7639071c5da58e9d8bd86be6d8e95094fe4f39",
+    "server_login_state": "8b6ee1970d420bd2e258e992cace00b41327424bac52f311136a10452d77eb2340d3b5909fa9b1bd0c224cbc6fde1420fcfe2c27da231cdbaa71823fff8920262cf95a75e1027b2321ddf6b61b91e530b28c30e53c98f1e1eaa57ae9f18d88c56469901149b140f4d42f689615f0bcbd5763b94dbbe50320f78d4c083dd8ceb18d3ec73f86f4204189c8a7862765488c383ec9df7d1eeb4a8b93463a981483b579a9e0e6b0129cc627247beeed7639071c5da58e9d8bd86be6d8e95094fe4f39",
     "password_file": "b2dfea72bb9ea93ab47cb7aa60de281e5040bbc56445fd2de61ac1dc5846d14c1f08c0cc740cec36c5229cc291774dcbce698c97103217c677d864391c1aa87838a7bf20d32e1b5bff19f135ecd4e080ce52e4f9c55f1bf8ba8fb95f194ea4466c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f4a0f14903fb1baa3ee37946e6b0b4d89dec9ff5a1a4fee3f89d657816f96ce944a4f0124c0b94d7257de7fa9a420c531f7c15b1734c9eaa17e254881d57eea7b",
     "export_key": "f9e4f5484325c4ebd2af7e558642729a483b10d1c17efa4eb36a4535b3186a7e857e75ec039dfe8d4ca998beda5a0eaf37a9de4c2a6e1548b52f6a7a7535c850",
     "session_key": "8b6ee1970d420bd2e258e992cace00b41327424bac52f311136a10452d77eb2340d3b5909fa9b1bd0c224cbc6fde1420fcfe2c27da231cdbaa71823fff892026"
@@ -86,7 +86,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_RISTRETTO255: &str = r#"
     "credential_finalization": "4589b55c80e41c3c3173ea6558fd4594c63e1016d0c1b66b173086b4955e74d7404cc39af806d9facc637e0293f950767444c227039d629d75eab4bdd5dff4fc",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b024c4ae302511a1f8ae37d71ba2cdcffbfb816910e4d1aa313033403ca7bdde745",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b024c4ae302511a1f8ae37d71ba2cdcffbfb816910e4d1aa313033403ca7bdde745a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e55fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "e7308db99e85ca97ac68e69f1b9f58bcd4166eb92703da0793a4966129eebd2ddc7899c9ab650c0b2b6e46fbb5f1c1b738edf5825a5453e8fec7c99a5a46dcf44589b55c80e41c3c3173ea6558fd4594c63e1016d0c1b66b173086b4955e74d7404cc39af806d9facc637e0293f950767444c227039d629d75eab4bdd5dff4fc",
+    "server_login_state": "e7308db99e85ca97ac68e69f1b9f58bcd4166eb92703da0793a4966129eebd2ddc7899c9ab650c0b2b6e46fbb5f1c1b738edf5825a5453e8fec7c99a5a46dcf4c9505ceb0b1002660e6aa66c96725fcf6ee43a046820aa4591613a7be99c7d7b43d2b7e56fd4e9cd18a950b8454a3dbf82faca244cbb89dbd37f7f7e7a25652d4589b55c80e41c3c3173ea6558fd4594c63e1016d0c1b66b173086b4955e74d7404cc39af806d9facc637e0293f950767444c227039d629d75eab4bdd5dff4fc",
     "password_file": "3e02fe2aa6d1735419baf59ed3247e2cefa7f49563d2625ab381fce8a2b74c5dc6df1104406f6bf004ca66ea1bb12f9dd6ec8dad89e70951be4d6d1d3c20a3d56c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f77a02225e442834c2dcf8c221b21fa66ff2320cd0f0a9fea7766ac787eeec530",
     "export_key": "e91d5ba62f92971ccc8267735c5b8c5ced5d66dffb4a4f73fb76483f14317a61",
     "session_key": "e7308db99e85ca97ac68e69f1b9f58bcd4166eb92703da0793a4966129eebd2ddc7899c9ab650c0b2b6e46fbb5f1c1b738edf5825a5453e8fec7c99a5a46dcf4"
@@ -126,7 +126,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_RISTRETTO255: &str = r#"
     "credential_finalization": "4cd294c40395bbf0dcfaf6c860436b2e9f7645a9d0724c8ea5bf5b5e5c6fc6f77c56c6e666ce02cbe1fe1df2eb9d925423ea4cf30e638129b8995bf48020f101",
     "client_registration_state": "d198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b0211762ea9a8599a7127521c65455f5fec4cdf9ba363bc3d485e647e1f5f9d1c11305bb97193aec8cdb142b5940b94f504",
     "client_login_state": "d198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b0211762ea9a8599a7127521c65455f5fec4cdf9ba363bc3d485e647e1f5f9d1c11305bb97193aec8cdb142b5940b94f5042488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e55fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b002488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0381ba9559312c886834bd1e8ef3ae1f953824e1a566f0c603b39ef15cd22c57a9a18d7316bdd7927cc275796d9b0677fafcda4c983a5b44d9bda336974fddf84cd294c40395bbf0dcfaf6c860436b2e9f7645a9d0724c8ea5bf5b5e5c6fc6f77c56c6e666ce02cbe1fe1df2eb9d925423ea4cf30e638129b8995bf48020f101",
+    "server_login_state": "0381ba9559312c886834bd1e8ef3ae1f953824e1a566f0c603b39ef15cd22c57a9a18d7316bdd7927cc275796d9b0677fafcda4c983a5b44d9bda336974fddf836b4de3e72f5c076457f4e0c671df73c580f06f7c66272a8a3fdc91b378be99197e84b813f680974e34baddb940b9d2657e922174e18fac88c5d838347e700394cd294c40395bbf0dcfaf6c860436b2e9f7645a9d0724c8ea5bf5b5e5c6fc6f77c56c6e666ce02cbe1fe1df2eb9d925423ea4cf30e638129b8995bf48020f101",
     "password_file": "8491d48aca36c45f7ab2d76171ffc439fb2b489306e4bc53e15901ec637c4d694f6739dc7acf68899b8a142cfc20d9477f5f78d8c468b6c42769173f9a47e0c49ba5fe59121a9c4abdb8b51aaf2527a96c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f22e0d7030455cce24f8ecb61c9fa3455a36f064941e4d47d1392d6ba1665c13be6164dd0b0f459b29cc8245c15fbae98",
     "export_key": "38aad036f28a5570a99f86709f81283857fe62767fa06ad05cfd4630dedd235acd0df99a7a2d5454f4be8a829ee9c0e0",
     "session_key": "0381ba9559312c886834bd1e8ef3ae1f953824e1a566f0c603b39ef15cd22c57a9a18d7316bdd7927cc275796d9b0677fafcda4c983a5b44d9bda336974fddf8"
@@ -166,7 +166,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_RISTRETTO255: &str = r#"
     "credential_finalization": "3222e3f4a5fe430e76c1e000fc4945b0cffc289e7bcf13494d31e2213f396a66ec0bcfcba00ff5c7e0d0e084d6381733ddf9d45363911b7e96f0a8786b8da6be",
     "client_registration_state": "01e8e8b444c2187242d7d6068d47af4e3c3dda297a7f66bdef4f3f61ea1f1eb7fe07eb830b6fc1c964481252595e17a3fdd274c8eea80e720ff035545c056c2a4e6f03008fa98ec77ed88056a620d86dc9561600864dbf5b0c5cdcb26dabb9677e58baa71a4a41e0a912bad39e3c374222909bfae21c4edf60645de1477e797b9f02597880",
     "client_login_state": "01e8e8b444c2187242d7d6068d47af4e3c3dda297a7f66bdef4f3f61ea1f1eb7fe07eb830b6fc1c964481252595e17a3fdd274c8eea80e720ff035545c056c2a4e6f03008fa98ec77ed88056a620d86dc9561600864dbf5b0c5cdcb26dabb9677e58baa71a4a41e0a912bad39e3c374222909bfae21c4edf60645de1477e797b9f02597880d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e55fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
-    "server_login_state": "9cf3405f07ad099101b5f614c160f65586e870f951c3abbe06afda34d03b6b1b0028473e39a93bf1e93e04121d73f8b85efc37a1e219164bec6d3b30df5ffe4b3222e3f4a5fe430e76c1e000fc4945b0cffc289e7bcf13494d31e2213f396a66ec0bcfcba00ff5c7e0d0e084d6381733ddf9d45363911b7e96f0a8786b8da6be",
+    "server_login_state": "9cf3405f07ad099101b5f614c160f65586e870f951c3abbe06afda34d03b6b1b0028473e39a93bf1e93e04121d73f8b85efc37a1e219164bec6d3b30df5ffe4b958a61061bd3219f391ca8e3143146531411476ce775bf2c727de7bb1418a3146495d62227dca5c4922dba3b72020878359aef9043085231482e59799a8bca3f3222e3f4a5fe430e76c1e000fc4945b0cffc289e7bcf13494d31e2213f396a66ec0bcfcba00ff5c7e0d0e084d6381733ddf9d45363911b7e96f0a8786b8da6be",
     "password_file": "78a92cf7d33838b10a2066d6e26226930ef7814b962b7a04e9a2348f34754d44caaa49418d001ab4727288be44e8fc834ced9ccb4598b8180306295ba6ad41de1bc89d4c31f3910ab398928cb73c8c24048169ec6a6379a4d400addd745856736c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f5e9b99bc244e39cf575ecb0465e74b7596dc082b6f738025ebce9908094635243ca4fdb8b730d9d1ffd67c34f2930ff333c4bbd078109e2ec0b3a135a4e3be28",
     "export_key": "76717386597491f4bc35bb18329ffcd593677e0add6b4e37b85cde7c7d9410f8da105d3eaea4e93a644c6c44650261bd80066e196f7a74d96a275e751c1130bd",
     "session_key": "9cf3405f07ad099101b5f614c160f65586e870f951c3abbe06afda34d03b6b1b0028473e39a93bf1e93e04121d73f8b85efc37a1e219164bec6d3b30df5ffe4b"
@@ -206,7 +206,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_P256: &str = r#"
     "credential_finalization": "9c8428b71759332109e085822c7d5ce0585ca6728dc2a9f50945c0b21ea432ae",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "4bcf39b2e1b1c74b59059361b654ca0fa6207a7c98f40754c58ea46fb494cc5b9c8428b71759332109e085822c7d5ce0585ca6728dc2a9f50945c0b21ea432ae",
+    "server_login_state": "4bcf39b2e1b1c74b59059361b654ca0fa6207a7c98f40754c58ea46fb494cc5bf5885dff0b9b2ff6e31954f441088c37bc1c955221fb50b64c87aa9cbe1b40259c8428b71759332109e085822c7d5ce0585ca6728dc2a9f50945c0b21ea432ae",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "4bcf39b2e1b1c74b59059361b654ca0fa6207a7c98f40754c58ea46fb494cc5b"
@@ -245,7 +245,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_P256: &str = r#"
     "credential_finalization": "b74ba51ab58b40b6e4a7fb281725f8a5d20b69b75d9e3c3d61d5f3d3d0d975a5",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "b1c69e6c7a571d49a740e40d69e203373c2b28528ab4f94bc7663301313bf834b74ba51ab58b40b6e4a7fb281725f8a5d20b69b75d9e3c3d61d5f3d3d0d975a5",
+    "server_login_state": "b1c69e6c7a571d49a740e40d69e203373c2b28528ab4f94bc7663301313bf834707a6c5c4fb44569ff9ef662f84267e7298f9d3eef1e4d24807b90585fefbbbeb74ba51ab58b40b6e4a7fb281725f8a5d20b69b75d9e3c3d61d5f3d3d0d975a5",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "b1c69e6c7a571d49a740e40d69e203373c2b28528ab4f94bc7663301313bf834"
@@ -284,7 +284,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_P256: &str = r#"
     "credential_finalization": "9f382a016c53c435eb3cf53b858db7c5341f439d806d0da6d8c7d77ef7f5412a",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "1216143877f4a978902bc162925e83ce3f6dc0fedbb9d88571ede99cddf5e2429f382a016c53c435eb3cf53b858db7c5341f439d806d0da6d8c7d77ef7f5412a",
+    "server_login_state": "1216143877f4a978902bc162925e83ce3f6dc0fedbb9d88571ede99cddf5e242cbd779587a4bd9f7b4397e0f096ff4b06a80af217d67aa6e1ca38527a766e8999f382a016c53c435eb3cf53b858db7c5341f439d806d0da6d8c7d77ef7f5412a",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "1216143877f4a978902bc162925e83ce3f6dc0fedbb9d88571ede99cddf5e242"
@@ -323,7 +323,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_P256: &str = r#"
     "credential_finalization": "70bf7386f49cccf25c0a26a42e89475b27c24887af6665f71b24dd5d3b1c359b",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "220325387ced97b7b9de672d2ba270ecfd5edcfc5df182778665106025fde40470bf7386f49cccf25c0a26a42e89475b27c24887af6665f71b24dd5d3b1c359b",
+    "server_login_state": "220325387ced97b7b9de672d2ba270ecfd5edcfc5df182778665106025fde40487bd19dc211e691e36bd665e011c1c69e22a5f0831dee6ad2546bf1991222a3d70bf7386f49cccf25c0a26a42e89475b27c24887af6665f71b24dd5d3b1c359b",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "220325387ced97b7b9de672d2ba270ecfd5edcfc5df182778665106025fde404"
@@ -363,7 +363,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_P384: &str = r#"
     "credential_finalization": "4f7b7933a598457beecf011468e25334b4046d74b9fdca87153c4025dfeae49f0b2b4ad0ca91fadab8888d7aa198b18c",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c20550cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "6bf5049f727d2dd150d04da90d8c9411b0d0e5a0e8bbfc23a7ec4bfcb5d8917298b638e987641a4cd963c9501379ae984f7b7933a598457beecf011468e25334b4046d74b9fdca87153c4025dfeae49f0b2b4ad0ca91fadab8888d7aa198b18c",
+    "server_login_state": "6bf5049f727d2dd150d04da90d8c9411b0d0e5a0e8bbfc23a7ec4bfcb5d8917298b638e987641a4cd963c9501379ae988e7ab6e527f05a18f2ac186505992e655deb5938ccd373f71025f724d57482f5682bc8e87bbea8a590ebe0d4c140301e4f7b7933a598457beecf011468e25334b4046d74b9fdca87153c4025dfeae49f0b2b4ad0ca91fadab8888d7aa198b18c",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "6bf5049f727d2dd150d04da90d8c9411b0d0e5a0e8bbfc23a7ec4bfcb5d8917298b638e987641a4cd963c9501379ae98"
@@ -402,7 +402,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_P384: &str = r#"
     "credential_finalization": "0f47cd5979f1e0ed8df4348f5d4f04c033ecdef62fdc6d2f21552ad9542c4dfa11ef1ec06c737d97deefb2c8256c44c5",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe15ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f604203066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c015ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
-    "server_login_state": "ec765404597b0d6c0b5db0bc342de3e1dbfaab7e66bc526a8ad4a187c21dbf19b89547175e4d1142e83ffd59c49c23ff0f47cd5979f1e0ed8df4348f5d4f04c033ecdef62fdc6d2f21552ad9542c4dfa11ef1ec06c737d97deefb2c8256c44c5",
+    "server_login_state": "ec765404597b0d6c0b5db0bc342de3e1dbfaab7e66bc526a8ad4a187c21dbf19b89547175e4d1142e83ffd59c49c23ff0e4893b86dc41cd89edc22f54dc8afb9c918aac8c43d8d4782ffd4af9b7fc96053db8cdbf7704e7276d6ce146bd0fc050f47cd5979f1e0ed8df4348f5d4f04c033ecdef62fdc6d2f21552ad9542c4dfa11ef1ec06c737d97deefb2c8256c44c5",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "ec765404597b0d6c0b5db0bc342de3e1dbfaab7e66bc526a8ad4a187c21dbf19b89547175e4d1142e83ffd59c49c23ff"
@@ -441,7 +441,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_P384: &str = r#"
     "credential_finalization": "9092b99ef174aefb8d7196254b2a180029cc6a5221fa9e33ba9d3b72853af8eb85eb4b97b9114ee3c370d9d83d10eec3",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b835295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c05295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
-    "server_login_state": "2218d70d7d9b5b49c41f5a02d7af9b8af3aad8995525fb30ebda8214a3f568f94554519cf1f71ebbccf534b5f4b290039092b99ef174aefb8d7196254b2a180029cc6a5221fa9e33ba9d3b72853af8eb85eb4b97b9114ee3c370d9d83d10eec3",
+    "server_login_state": "2218d70d7d9b5b49c41f5a02d7af9b8af3aad8995525fb30ebda8214a3f568f94554519cf1f71ebbccf534b5f4b29003b6eeafb20abd7ba40a7a1bb57036c97027f65c1c3ea07f0fff215b1533881915e088a5484c9b0cc8044dc2a1412355939092b99ef174aefb8d7196254b2a180029cc6a5221fa9e33ba9d3b72853af8eb85eb4b97b9114ee3c370d9d83d10eec3",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "2218d70d7d9b5b49c41f5a02d7af9b8af3aad8995525fb30ebda8214a3f568f94554519cf1f71ebbccf534b5f4b29003"
@@ -480,7 +480,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_P384: &str = r#"
     "credential_finalization": "bcb9307a51028e7d329f67dec1a1b2fc2fe140bdd143ed5dd2a5c60603cff2f180dc7cd0e471f5213a6b96eb70ab0455",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b690cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "fba59952f82db70c56b27b76feb8b26e5c892ad2120e8bff23bedc73c46efe3632b6e8f25f2fb03f3a2a32409d8b2f21bcb9307a51028e7d329f67dec1a1b2fc2fe140bdd143ed5dd2a5c60603cff2f180dc7cd0e471f5213a6b96eb70ab0455",
+    "server_login_state": "fba59952f82db70c56b27b76feb8b26e5c892ad2120e8bff23bedc73c46efe3632b6e8f25f2fb03f3a2a32409d8b2f2113cc66b5d489a2ab1c4d30ed17a30ddb4175f3766f5a9f0ea9e13b9a537bacdc49098748fbda85bfb4de7879bd0449eebcb9307a51028e7d329f67dec1a1b2fc2fe140bdd143ed5dd2a5c60603cff2f180dc7cd0e471f5213a6b96eb70ab0455",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "fba59952f82db70c56b27b76feb8b26e5c892ad2120e8bff23bedc73c46efe3632b6e8f25f2fb03f3a2a32409d8b2f21"
@@ -520,7 +520,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_P521: &str = r#"
     "credential_finalization": "c1a5f3337f9db7b9dd684aba6cf43b7b23bf355d2f3f9173b2cdeea1fdcb70b5a82b58bb4aa3ba6581ff9be3f8e66b2b29293f0f4b0b4e53b9cca19868aa65fa",
     "client_registration_state": "6eafbb4b709675bdd22a9e289048b20053b0ac0ab8853a1034ee431cd3353a0c70426f744606f980fc25b993b26bed3e5b7de20f73b2d17c0d67757581985255",
     "client_login_state": "6eafbb4b709675bdd22a9e289048b20053b0ac0ab8853a1034ee431cd3353a0c70426f744606f980fc25b993b26bed3e5b7de20f73b2d17c0d677575819852554239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd101b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a74239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b",
-    "server_login_state": "0678dc24b5e98c9e61993e34f440178e953487b7b6c53d33b4f1361d50cfa39a2062d613cf6aeb660237e3c259d9ad1a8304df3511197e1c6a6dd5039581565ec1a5f3337f9db7b9dd684aba6cf43b7b23bf355d2f3f9173b2cdeea1fdcb70b5a82b58bb4aa3ba6581ff9be3f8e66b2b29293f0f4b0b4e53b9cca19868aa65fa",
+    "server_login_state": "0678dc24b5e98c9e61993e34f440178e953487b7b6c53d33b4f1361d50cfa39a2062d613cf6aeb660237e3c259d9ad1a8304df3511197e1c6a6dd5039581565e8a9f7faff988152800111722447ae3eef4bf104ee763ed42934586c33c82fde5aea07c5771053b86aa940e15060ec55fb9ca134452f90ee76587209c79354287c1a5f3337f9db7b9dd684aba6cf43b7b23bf355d2f3f9173b2cdeea1fdcb70b5a82b58bb4aa3ba6581ff9be3f8e66b2b29293f0f4b0b4e53b9cca19868aa65fa",
     "password_file": "0301af3d14ba5f304c4af480ec7841579f114a6bae3689bb8592fc2cde2b7a77c07bd6ea1ad0c90e88fee416652ade13b124d7ee36b8dc704cc88adbffe670e3bc9ff9278cfd16bb338664586c6d0a0f7eaf309417dce66568b13475ab028af0f2b5c1c96d9e1c4e06d7724ec5a3f9559fabcfcba3c673c9e6f846c3f1aa74042ef3430191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c385843e261f2b8813ed4e7b6b824570581275b0512380953244e66066e7a7049a9199107824f3d041f5e8f9af9a196c39b163b3416dc81dc4f456570c94fd19d93",
     "export_key": "2d8bd543482be9dba0b9a2d1a92d2a26bddcd7a64bbbef1eb9b6f9426c9428620d0034c2e184e48e5ea02155562e7ea6cfc10a03bb7d9c222588b6134839065c",
     "session_key": "0678dc24b5e98c9e61993e34f440178e953487b7b6c53d33b4f1361d50cfa39a2062d613cf6aeb660237e3c259d9ad1a8304df3511197e1c6a6dd5039581565e"
@@ -559,7 +559,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_P521: &str = r#"
     "credential_finalization": "59f440c52ecdce6095cf69273586660fb1e3dfa50438b8b75b7b436bf7832983d7281a0eeecf160424d9250e01e0b3c4a662667d2c3b51c237fc3099c11a1799",
     "client_registration_state": "7296a3d1056cec20cca5f153e55fbffc96b85b8fa86d5b47edb354ea465314ed03127f88d68a28399e27c35dad8310a60019544b3d4673922e2ab5f060d834423b",
     "client_login_state": "7296a3d1056cec20cca5f153e55fbffc96b85b8fa86d5b47edb354ea465314ed03127f88d68a28399e27c35dad8310a60019544b3d4673922e2ab5f060d834423b2f98027f49cecdece384850929ce9d3b0fd624860acda513da8ab39bc36f782102000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd101b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a72f98027f49cecdece384850929ce9d3b0fd624860acda513da8ab39bc36f7821",
-    "server_login_state": "b63c7ec832beec8f89f1fe0f736d3bdbb31bf2cf4eead919023a50099fdd75dde0756c84f84e85534cdad2b80e1e511018ed8ba4b67d932b38c228740141a06159f440c52ecdce6095cf69273586660fb1e3dfa50438b8b75b7b436bf7832983d7281a0eeecf160424d9250e01e0b3c4a662667d2c3b51c237fc3099c11a1799",
+    "server_login_state": "b63c7ec832beec8f89f1fe0f736d3bdbb31bf2cf4eead919023a50099fdd75dde0756c84f84e85534cdad2b80e1e511018ed8ba4b67d932b38c228740141a061751753b4d42832f6a6455ce1bbf14209f2e4034264968deb8a70ff73a6f55a48d052655c48bc9d2c3166fca808ec0241224eccc6422d7c19e4816d8648b07fcc59f440c52ecdce6095cf69273586660fb1e3dfa50438b8b75b7b436bf7832983d7281a0eeecf160424d9250e01e0b3c4a662667d2c3b51c237fc3099c11a1799",
     "password_file": "020173db9eeec10ccfc4946c5674d3f5a9b76738cc0d5a68b108bd334cde85b49e29a68973cb485f88cb2a2f204ff9ce71f80bf27380bbc707798c4ed7b88e2c85d33280fa66615afa29e71cc76873a2fea65d54b20425ed3c40671c4f7a2c645af8ed0191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c3897be3be74cfc2db573d5937c7008b347a60e6c6edb356164a661d28a33eac73c",
     "export_key": "b107a66f22beb9b180e6526edc62ccf79b02ec10a5caf6e079ed67cdbfd81d53",
     "session_key": "b63c7ec832beec8f89f1fe0f736d3bdbb31bf2cf4eead919023a50099fdd75dde0756c84f84e85534cdad2b80e1e511018ed8ba4b67d932b38c228740141a061"
@@ -598,7 +598,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_P521: &str = r#"
     "credential_finalization": "e0322360527cb9ac993983c5a30271228b93e60a37e86c1722ffb672c5e94523bb9b9df0f071e7814c5f3cd1982f88d42ab8763c91c9f1d49c28a732188cff40",
     "client_registration_state": "34786051c54dfe7a8386928e812386cc83d84dc595bc9efd6d82fee5d5be24109d2508e3fa5575483e8973eb72f59f7503510d65a177ad3d0db25e4c62c8d34eaea0516ab6cc8967b2d25e597af2e33f619e9bc4312059cb68faeb1aec655cee76",
     "client_login_state": "34786051c54dfe7a8386928e812386cc83d84dc595bc9efd6d82fee5d5be24109d2508e3fa5575483e8973eb72f59f7503510d65a177ad3d0db25e4c62c8d34eaea0516ab6cc8967b2d25e597af2e33f619e9bc4312059cb68faeb1aec655cee76d7d6ba25df2b8fe865bb2f3a94db41b56d7275384017c033e1a62d5536d39d8f02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd101b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a7d7d6ba25df2b8fe865bb2f3a94db41b56d7275384017c033e1a62d5536d39d8f",
-    "server_login_state": "37a99959c48174a37479c893302c42492d91319b2f5d80f6b87736d5de031f71b65ad9f1944b52da65ce836521b139bc00197da4290043b95560a9f3869aaac2e0322360527cb9ac993983c5a30271228b93e60a37e86c1722ffb672c5e94523bb9b9df0f071e7814c5f3cd1982f88d42ab8763c91c9f1d49c28a732188cff40",
+    "server_login_state": "37a99959c48174a37479c893302c42492d91319b2f5d80f6b87736d5de031f71b65ad9f1944b52da65ce836521b139bc00197da4290043b95560a9f3869aaac266bae7d18308ebb7d634d5332b46e4d30cf82e5a1222c6dc01b899b6b183fe591258f44afbe3d3bc1792c4156a02b99af3ce57c6791990598d1f0289e6be105de0322360527cb9ac993983c5a30271228b93e60a37e86c1722ffb672c5e94523bb9b9df0f071e7814c5f3cd1982f88d42ab8763c91c9f1d49c28a732188cff40",
     "password_file": "030095f619e2660b78bf4ddef4f1ff23b1839366f9a214b7aa9b15a5f4b94994c45aab08be769a4d1e37d1bbd32ea3247914007487afbc34face786b9dcb326002baa55a1eca0bf52b6faff5330bf6cd1f5d2c6511da4c920da9f2da98beeec1c125926850846d29c84775872e777362b73fd00191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c384216604c9da2f064937780896e19b83417592b478f7440348b524e9a1fad82f631a3f8ae1d1702a12d927e67f037b961",
     "export_key": "fdef7f35937b70422146685688e870eb110a37ec6d0b516362e3df8b0e3c60614f4e69f06efb85a7266d80245e36e0d8",
     "session_key": "37a99959c48174a37479c893302c42492d91319b2f5d80f6b87736d5de031f71b65ad9f1944b52da65ce836521b139bc00197da4290043b95560a9f3869aaac2"
@@ -637,7 +637,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_P521: &str = r#"
     "credential_finalization": "897d50388f5ec72b1d0a80950e1faca3b4226d3714181e7ed0ba47018b734237fd2696b8bd83de89d3388b2a596bcf14b4e3cf8636900e3abc027e939576f023",
     "client_registration_state": "012be71e97d83cdb4b933911353bc06e41094bbc2d3d2b94cd3ace502fff999b7a54c5c767fe565cb3fe0390fa94fffecd8d026d6e1a31d6637867fab48183342c9a02017a4ba32aaef77ffcde95ee4cb6162b44c3bb1f3b09fecd19b03117d2ff58501773ec01c5f9a642c3defdeeeb3611a64dbe7b626ac4debb77b90acd9a1b605aea27",
     "client_login_state": "012be71e97d83cdb4b933911353bc06e41094bbc2d3d2b94cd3ace502fff999b7a54c5c767fe565cb3fe0390fa94fffecd8d026d6e1a31d6637867fab48183342c9a02017a4ba32aaef77ffcde95ee4cb6162b44c3bb1f3b09fecd19b03117d2ff58501773ec01c5f9a642c3defdeeeb3611a64dbe7b626ac4debb77b90acd9a1b605aea274239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd101b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a74239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b",
-    "server_login_state": "574563f669b439ddede699f9572b8b8cda8e774a61bd03d04739bfb022f7ecb9fd9c111e50b154eca587b0089ef52c42b3088a9229a406a828065429384368cd897d50388f5ec72b1d0a80950e1faca3b4226d3714181e7ed0ba47018b734237fd2696b8bd83de89d3388b2a596bcf14b4e3cf8636900e3abc027e939576f023",
+    "server_login_state": "574563f669b439ddede699f9572b8b8cda8e774a61bd03d04739bfb022f7ecb9fd9c111e50b154eca587b0089ef52c42b3088a9229a406a828065429384368cd9e1b46916ee023a1068558d649e571090b997c67e6901e9dd3007ec1749a88cfd63ba9b8918a4b723a508b1e2287ebf7898cd12494fc11557ff2ac01c316139c897d50388f5ec72b1d0a80950e1faca3b4226d3714181e7ed0ba47018b734237fd2696b8bd83de89d3388b2a596bcf14b4e3cf8636900e3abc027e939576f023",
     "password_file": "020197c811fdaa93a80cb94bb8be013424acfc2767b93f554bb5e70c28b1f77829cb5570f60e9eb490159f799324e017afba0c04f843a013da6a00290166b80eb678240ea7f95f3016c49752e34ab060ae19ad19cc24212ddd005f4bae4fb6533a4690550b3feb300768e6c7dffb0f238230bd8886fb7142153485c67f260baea0a2930191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c3846621e4d65ddf941eaf2f1b68bda57f81b968c24190bc5744a58f0be15bfcc634facbd2d9432ae426cc67ec826dc1fc1ae1023613ccda8f46d3566ec8f380763",
     "export_key": "bda7c3ff38c8733d2ae136597cf10a0371ff3aac919e1b3ef5d52f0b4a6093abd5e9784f6cb688099d11e77a7e22530eb33c1d3d24dcf8b1ce69e3a1382a2a8c",
     "session_key": "574563f669b439ddede699f9572b8b8cda8e774a61bd03d04739bfb022f7ecb9fd9c111e50b154eca587b0089ef52c42b3088a9229a406a828065429384368cd"
@@ -678,7 +678,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_CURVE25519: &str = r#"
     "credential_finalization": "95363694296b6acb0e4de76dd4afb9f42295fdaae2c239bbc0ecc7fcb6cd9ef620864bc96e79003151bae0e2cb60199baf11d16083a82e2d7c8843cf9ebb8e2b",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "a3fc0c66202c6adb684d901737a99e09a6f67adbf341a1527b29fef15f500021dfdee3361cd47d182daccf85bceb981c6ba2d8a75fc732a0b2a187e9d5aab92495363694296b6acb0e4de76dd4afb9f42295fdaae2c239bbc0ecc7fcb6cd9ef620864bc96e79003151bae0e2cb60199baf11d16083a82e2d7c8843cf9ebb8e2b",
+    "server_login_state": "a3fc0c66202c6adb684d901737a99e09a6f67adbf341a1527b29fef15f500021dfdee3361cd47d182daccf85bceb981c6ba2d8a75fc732a0b2a187e9d5aab924eac0ee333630d55bc661a429855d8a02752252aa58686f0ecb88768322f98bb11fec83971f1a53987b4245296af85e8c435b3777e09b350b2ed12ea86b34c9fb95363694296b6acb0e4de76dd4afb9f42295fdaae2c239bbc0ecc7fcb6cd9ef620864bc96e79003151bae0e2cb60199baf11d16083a82e2d7c8843cf9ebb8e2b",
     "password_file": "ea0118578326d5c0ce0ccce4c836590e85610e646acc9092252ac84aca00d71d2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265d0e1f51f070336bcb2ff904a18092cb6542cf25a370088dcb4a0b5f2966f1ff16c507b79be408613f6320e05a30ea7470858e1020b228988386384cb46a039a2",
     "export_key": "501f39334fef4a701457c59ca3c4125f5e611e762f813f45fc89abeb6e4ae23f898bbad030623669251ee2c4a6d986266abb9c64182aab549942250f5f824401",
     "session_key": "a3fc0c66202c6adb684d901737a99e09a6f67adbf341a1527b29fef15f500021dfdee3361cd47d182daccf85bceb981c6ba2d8a75fc732a0b2a187e9d5aab924"
@@ -718,7 +718,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_CURVE25519: &str = r#"
     "credential_finalization": "1b52a05d3c445bd395439368f7dffa531b7e579065d72f8f58cabe3084cf86e16e51f5e2124075922be25d388499dc987f2820cac099c787d21bf22883f1c509",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87bba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "e6536633d76dbc39dce0c113d65ff72fde57497c37e0b78153636a29097a02b21cbf75c7fd1602eb28df0c2a911d6252492d5d7c544da3b5ffae7aa4702914541b52a05d3c445bd395439368f7dffa531b7e579065d72f8f58cabe3084cf86e16e51f5e2124075922be25d388499dc987f2820cac099c787d21bf22883f1c509",
+    "server_login_state": "e6536633d76dbc39dce0c113d65ff72fde57497c37e0b78153636a29097a02b21cbf75c7fd1602eb28df0c2a911d6252492d5d7c544da3b5ffae7aa47029145449163b96ba4854c31d79bfb32e3875f150aa75cca24cf70a609a13edc8016e4cb26c0901090c4ca16c9dd9a526ec7b569e2c516c3b2a636eb07b2274236e23c81b52a05d3c445bd395439368f7dffa531b7e579065d72f8f58cabe3084cf86e16e51f5e2124075922be25d388499dc987f2820cac099c787d21bf22883f1c509",
     "password_file": "f57518dfd29789ada99a4c350ed95f10361855205a3cb98ea0dfc3e829dbf53e4226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265502af63ffb3f21e6610335d3ca3665a0ebeceed19fb2166c343f89f26e12f1d0",
     "export_key": "bde7d6a20eead70c96e22b28d5e979b04c990746145e96af3c9e05c1566d1d5a",
     "session_key": "e6536633d76dbc39dce0c113d65ff72fde57497c37e0b78153636a29097a02b21cbf75c7fd1602eb28df0c2a911d6252492d5d7c544da3b5ffae7aa470291454"
@@ -758,7 +758,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_CURVE25519: &str = r#"
     "credential_finalization": "6894b76dce887d4f3726377e79b51c2f0061571dbcd6df561c06da4917ae99c4572429712a727d60f8325cd987414e0ea76eacbdc3ff271e1c21386352e5d3e3",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87ba4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "4cd6ff11bd749c0e807bb2e119503fa3347fd09e1d633b1a2b48dbf4687571cea99c6c26fccc2fa06fabcf7879d8d06376b664448b8f8bc927be9238362e787e6894b76dce887d4f3726377e79b51c2f0061571dbcd6df561c06da4917ae99c4572429712a727d60f8325cd987414e0ea76eacbdc3ff271e1c21386352e5d3e3",
+    "server_login_state": "4cd6ff11bd749c0e807bb2e119503fa3347fd09e1d633b1a2b48dbf4687571cea99c6c26fccc2fa06fabcf7879d8d06376b664448b8f8bc927be9238362e787ef912569be7aa2562c24212fde51d95093f96982a11805f34b2778967b71cb083b7c2f3d544152e6af15aac28dac92a92515356881679e23f5e8d1adfa5eda16f6894b76dce887d4f3726377e79b51c2f0061571dbcd6df561c06da4917ae99c4572429712a727d60f8325cd987414e0ea76eacbdc3ff271e1c21386352e5d3e3",
     "password_file": "e214e4f49be85f32c071f9b24368fd564a9b8a264e9dc604906679a0cb488a014b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265ce46b56ef7a727d9f1702b81b61f20ff13d901cac4c6e56cd7ec15d4bc72508c86f23115e46e07731048f8ae1a2927ba",
     "export_key": "817e99936783cb333e726f759681ffe9781d976d8ba90f269356807e7d01c8017f98ff65314efac6a30dc40b08f84392",
     "session_key": "4cd6ff11bd749c0e807bb2e119503fa3347fd09e1d633b1a2b48dbf4687571cea99c6c26fccc2fa06fabcf7879d8d06376b664448b8f8bc927be9238362e787e"
@@ -798,12 +798,173 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_CURVE25519: &str = r#"
     "credential_finalization": "43eee6035cffc434d1d7625e2935487bff575b049af4d77f2df776148263467601dc621b240d013fc1c48d1d0b3f560ca6dce8f2693d9016ff266bf7fd2c2b59",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "d40e639b9dae532ec8788fe690063890caa5564455edca59af3db3f5744163ac18a69c71f1a2436cdd509c0109344535ae8086b65f97c0521201c99e9b97626343eee6035cffc434d1d7625e2935487bff575b049af4d77f2df776148263467601dc621b240d013fc1c48d1d0b3f560ca6dce8f2693d9016ff266bf7fd2c2b59",
+    "server_login_state": "d40e639b9dae532ec8788fe690063890caa5564455edca59af3db3f5744163ac18a69c71f1a2436cdd509c0109344535ae8086b65f97c0521201c99e9b976263ac77a26087544b55ea8a19cb7b224a73224a5317bbfeb4b2a9f9a441b89527b2a12b2081882683e85571bc63152f736c2186a68c50c7d0142277bb39c886633d43eee6035cffc434d1d7625e2935487bff575b049af4d77f2df776148263467601dc621b240d013fc1c48d1d0b3f560ca6dce8f2693d9016ff266bf7fd2c2b59",
     "password_file": "f82087d45d44875f2469129118714c1811028b0b2e173970fd130ae12a258a6a5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265107c246317b40bd8e4c19336b9caee0d1f79fc2428907e5adf6c62ec188072b196bd570c5a06777c1103b3a64d3e19c16bdfa37c93a70bad7da1d1a6b4bffa4c",
     "export_key": "beee088578f1b48e4729fc54e12f03898281a25dde1a4d6bcc879a5e99cb1ccf976dfc63f9ce2d4f7e839f7d29f8e1b99dc4d0df99d4a32970981ad1a98344fd",
     "session_key": "d40e639b9dae532ec8788fe690063890caa5564455edca59af3db3f5744163ac18a69c71f1a2436cdd509c0109344535ae8086b65f97c0521201c99e9b976263"
 }
 "#;
+#[cfg(feature = "secp256k1")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084d962537de87555eca70ddd1695cb5054223469a0022f53902d834c503634fbf1",
+    "credential_finalization": "d441c49b4678078ccc52d5f62c1dd38a7c68cf2cba1d572c4f5eade0c642d086",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca1942488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "fd93e4f26f20a3309e32c32781e924553260eb155eecc13721113dad4b5c19216fa3c477cfc743d62b4b2cca3104492204c0dd81f2ef8858a4a558a8d6b5c304d441c49b4678078ccc52d5f62c1dd38a7c68cf2cba1d572c4f5eade0c642d086",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "fd93e4f26f20a3309e32c32781e924553260eb155eecc13721113dad4b5c1921"
+}
+"#;
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_TRIPLE_DH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea670347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fae45f1c445762e5979056099281d4c44d0f3ad5737c720996d19484619c246e95",
+    "credential_finalization": "a9a98958bf2980982118d825f05132267f1919e038760ced6e0e2acc3a5d5fbc",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca194ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "099b6feb42d2cf7654779d0186036f9ee70690a8da9c36993f3e22d5708001142f4e1b115d2fd7caf852c6e88973e8c09086d9805e2d21e3b78c499caa3d1e4ea9a98958bf2980982118d825f05132267f1919e038760ced6e0e2acc3a5d5fbc",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "099b6feb42d2cf7654779d0186036f9ee70690a8da9c36993f3e22d570800114"
+}
+"#;
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_TRIPLE_DH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d102583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea6703f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa8546310f37063ea64a73c218eb3328faa6e429fdaa781eab2e658ad09a5ee95ae",
+    "credential_finalization": "c33df9bc88735d949255aa549f617fb239e81bf1704eb7c433fbdb0609136522",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d102583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca194a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "b7adc5e990e289986a7748fecfc2d3aeacd25a820688b5b58edfb4d2131774dd2370508c096e77db8f81af75b73533e6c6aabca6914095cd8e01bfa871544648c33df9bc88735d949255aa549f617fb239e81bf1704eb7c433fbdb0609136522",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "b7adc5e990e289986a7748fecfc2d3aeacd25a820688b5b58edfb4d2131774dd"
+}
+"#;
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_TRIPLE_DH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa0828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084f26bec601111f9624ac3136913d5098e49a6d2480a28761209be465c7c35d9bd",
+    "credential_finalization": "12f2cccbfcf2812314e48de70f0f5af904848b2e7f20c257cfd1d6ee4369aa31",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca1942488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "5ba4018a76cc637ec48ca69a1815feac3ec60838005ff2bcd48e8e32f693e0b56f88ee75e9d9c768e0d90acaba605db112c422138d8958c4dd1bbe54c420548d12f2cccbfcf2812314e48de70f0f5af904848b2e7f20c257cfd1d6ee4369aa31",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "5ba4018a76cc637ec48ca69a1815feac3ec60838005ff2bcd48e8e32f693e0b5"
+}
+"#;
 #[cfg(feature = "ristretto255")]
 #[cfg(feature = "ristretto255")]
 pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_RISTRETTO255: &str = r#"
@@ -839,7 +1000,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_RISTRETTO255: &str = r#"
     "credential_finalization": "9f3c830c137e41163a8656c690aaa11e0708d2c19ec50a129d6c2642b1b4b4d68b7a86a0481071ed28d2b5b97d60768f89a58df855cac11ab4d7de96162f6104",
     "client_registration_state": "e6b5a66409921dac759fa642ce40cd6ac6935d8a84c4b8098dc9d73795d4e302368ae23cfac76038d7ecd5fb57687c9d6dc664d5bb4d016e7d874953781bef44",
     "client_login_state": "e6b5a66409921dac759fa642ce40cd6ac6935d8a84c4b8098dc9d73795d4e302368ae23cfac76038d7ecd5fb57687c9d6dc664d5bb4d016e7d874953781bef44d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e55059229fd6bb46984114f4b6fb90555d59b86e4a73d199b0e023964ee3bad3b6349275757849186d33877efb59b025ac4b3eb5a88e4aeb0d84c3b543d2d29a19fb258069c8a701c8172890e1a05bdcae46512e382abf54114c320001858e2a65e92b60d3a60ccc423cb6887c8f3c6660505338c20b22b7abd475209a9632d677264256a1562e42c08700813ba5521749c6ef044da588f4b082c4ad43b2cd1bb545659a6422be5420a5e283746b2b0abba7e50e1cc513288efc6bc734a0d22b1a7a161aafb098b5483cfe4958376c9304f7701804c806033c46ad81fea3a273374976f6a347a1bad82d18b741b8ce0c08fd233aa814347490c622aba0175272de1db1e4f6434f71894f4eca3a4a4442303b1d19047e4f7bc3b77c7268bae5e1903c9fc6a4a754820941e90f57c5c2caa12c089e930bd11d68c7fe5a800a25aa87bcdacf1c321323f62c357d55a5de328c8d7a920d8029d2e75a47f66a7c1a94fdeeba784f739c7c08203b72089b65f5458850fe24ba1688d9bd7bd36d592a37bace99152815171a9f6c331fb8bdcd780627376fc3416da428cafba9f315c9767da92e2410d2d270adebcbe9dec5073b0970e44bda8d8818e049d57ca9b175c21c10192b1622fbdd0925a6541f3442c18432a67e894a077a9c0db8ab86a080264005b9b3cb891cc29862e64e600a546a3b53274799aabc7f1b57ac00db776c6768581c4eb1b62b96c03bc1065e073f6c79411c5b3ede334fa36b132820f3a20b3ca601d67e18029a895d00b987a2c928f984f85db6c52c66e7d660d9c5b44edf708cbc843971b3a4f1b0d0a90aad122b45840c84237761241c78c742b398297f2f131f654147f637586871872751bea78bd2439cd804453aec85e6135aa41bb4861a12e8ec53341660e787186ac97493508541af55510f94cd8b191f240447ab90caa74a97ab9b254d44da4408c7de263ea20070be2a09d142a12678fc758c8c77927516b1fa04793a2f161adc04592743d12ebb586a1ad3c7780a59823add36b2b716f3b40988665142cd69838f29e45d064bb3b49c9a0ce7ec16f4f29bd73173d3c90b0f1f3547d39929e5590d2431a88ec14e63967bb37341aeaa7b0675fc4f7a3a947b25f4a6c1af2544a9767d7826233987b8eb97201602ef892cc937871cc49aff8ab2218145628882dd36a002aa0a2c19670f616010532ce869c1afa214522bb5911da5bac5458a76687c4d606e80c38193b82cc77c473745d212a9e7541c07efa40e285b51fd8c30d229692d072835a8ba3214b55c20ff1c288ed4c35c70a61e432802cbc112a533d97077e1a11ce3a0b360d670f821446707835e3fb7fbfe397f71b9e86e88a46e98717e1c5e83c591df2486c655a75a46ff1a006357421c7749d3bdb2bb8560f1f2bbaff366b27864c39a033e8d5031e49b6872baf696054843b0a7e648f4bfa7d28ca06abf17f16889052060d54774646b70499942b15099824b05be0f33d7fd5ce20db0e1bc637ddc0759fbb0f9c361ebe1c616d3b1a8b8c0061873d4a3714689c72a8c617609801adbb4d45a6b43e753a9ab7635545764d679937647d25e2098ad66197977945011b32c14a2a4b175d81096979a58d1b6019bb8db4cb60cd7d8a060863a269ac6870cad2d2b13c95ef041ac7e6e7fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b8865b0bd2bb21c78ba12c011027322e165d9a395283348deef5c10d176f3683546d342149caa70dd3c92262708a6c1b85e4952e45a92d77c528d706b38227326cc4c1dc576250cc2f3816e0e25c43c16f061bcbcf74c37fa04293d25f25a416cc831a2787197153b9ea7bb3c2e5146af271eaa03ebaa17c123a49f5f51ab28910882428e3cb4675a4ba152a0d0c883490fbb507013a46f545eeac19a7625418cb51edc003f51baf5f5302827115f6b416f1959b77b769910924d84125043441b63b2ff304b40b9682ef87361a9356b543a080156d3a677d0fe099f854462ad85e0bac5b63030b7637744c544dfae04b0ec2c69f93644d18cbf5506032eca3b923890ab6cb02ea52b635c920508b234543ff319a54e761d883626f9ac81fb18021f778fe741a6e0803422713cf295e40cb3aa8b8099f4750b7a4465554c16b44a3c364b110c8c005fc01a99102f5f5bafbd7511b1b3844203437b50abd9bb4858a49717bafc0969d74206c9ef27f1222b8a8139fe8556fdf431bb74b455f198cb5d809bfe97f9259674659be39d95dcc84808bfa7a4ef47499178300eb270cfc94f6b71f70359aa1bb41ae015697001134f46edcd3a9a61b6b8db944cae5bb81801ba368a5573ac1e451b0f2dc9886991cd32a80a21a40d9b2a27c3153b16a61fb848cb5f2b639bc2d84877397a5c068ec141317654a955442e859b811cd12129ac745937eeab497b293f354af5d9b3ae3656352775250c23621cc73247b9c2a03a385347633c93e6d5b289a1bc04dbcb577191e7c93361b0c9f60da283c1abdc424ae19ea3b6ba137e3d3c6ff15a1b9e94ed73a3cf23943d1f7abdba140bb6c5733a243d9395a98e2653d272a0e11bc10f749ce2bc0e00bd0365483b5324be3178470f5097bd5a3d133ccc0119839b88cb7326b3375654e0b2f54762ea832ce96f78875a87bcde35c5df592da6b48099ac28f0576b8647c28e49f1485c5cb65716d38886f56ca33e97403ea10ab3bc40c91065a082663d562f39693ee759aab52322a8b45126a36d7e75a57c5136a38be9c53616bc1045dca14df74cc1a84081cc16d9fa47b130676585470f13a1149966967f263a1a6c41e7ace7bec22bd2a59fea592ec497e382467c056bb1375750158953fe5330860ce0bdab8dc50261f95217be4958dd93a8c93cd611cb4b6b8bfcd2475c1ea1c8c24bbda6b9880c8a0f3c008223ab933439d4da91e945b5a6c5540e2b5cf90d96eccc8a270db54a8a9c1f344192b6447c755741ee82e36e841f27c040f399248e3328e338e9b568afed710262823c73b43d5cc764260c991e17b0cb9c44c7b67ecd07ab34aa30182c4074a23afa8246e1400746b716d8330b329a1be311fed62bd5d713458ca0ecd73715d7b47e6763a3384bb120a518f2097ab7aacdd69ac684228ebcc5dfb4c1891b15a4747c99ab96c27da6e23e8acedb04347e4b85065cf89433cc1a9a8a5329c5acc8547f589ec4ab2757b5fb2214345936375da601c7424988679f6fb9d330c27fc8b8f6aeaa073539e2a92952d362505d8ae6bb8c15a59324ce191738c7b6dd2782923b5bc976e5062b0053b3215a2c1ec40a95f6c9808e566234a69cc545a059229fd6bb46984114f4b6fb90555d59b86e4a73d199b0e023964ee3bad3b6349275757849186d33877efb59b025ac4b3eb5a88e4aeb0d84c3b543d2d29a19fb258069c8a701c8172890e1a05bdcae46512e382abf54114c320001858e2a65e92b60d3a60ccc423cb6887c8f3c6660505338c20b22b7abd475209a9632d677264256a1562e42c08700813ba5521749c6ef044da588f4b082c4ad43b2cd1bb545659a6422be5420a5e283746b2b0abba7e50e1cc513288efc6bc734a0d22b1a7a161aafb098b5483cfe4958376c9304f7701804c806033c46ad81fea3a273374976f6a347a1bad82d18b741b8ce0c08fd233aa814347490c622aba0175272de1db1e4f6434f71894f4eca3a4a4442303b1d19047e4f7bc3b77c7268bae5e1903c9fc6a4a754820941e90f57c5c2caa12c089e930bd11d68c7fe5a800a25aa87bcdacf1c321323f62c357d55a5de328c8d7a920d8029d2e75a47f66a7c1a94fdeeba784f739c7c08203b72089b65f5458850fe24ba1688d9bd7bd36d592a37bace99152815171a9f6c331fb8bdcd780627376fc3416da428cafba9f315c9767da92e2410d2d270adebcbe9dec5073b0970e44bda8d8818e049d57ca9b175c21c10192b1622fbdd0925a6541f3442c18432a67e894a077a9c0db8ab86a080264005b9b3cb891cc29862e64e600a546a3b53274799aabc7f1b57ac00db776c6768581c4eb1b62b96c03bc1065e073f6c79411c5b3ede334fa36b132820f3a20b3ca601d67e18029a895d00b987a2c928f984f85db6c52c66e7d660d9c5b44edf708cbc843971b3a4f1b0d0a90aad122b45840c84237761241c78c742b398297f2f131f654147f637586871872751bea78bd2439cd804453aec85e6135aa41bb4861a12e8ec53341660e787186ac97493508541af55510f94cd8b191f240447ab90caa74a97ab9b254d44da4408c7de263ea20070be2a09d142a12678fc758c8c77927516b1fa04793a2f161adc04592743d12ebb586a1ad3c7780a59823add36b2b716f3b40988665142cd69838f29e45d064bb3b49c9a0ce7ec16f4f29bd73173d3c90b0f1f3547d39929e5590d2431a88ec14e63967bb37341aeaa7b0675fc4f7a3a947b25f4a6c1af2544a9767d7826233987b8eb97201602ef892cc937871cc49aff8ab2218145628882dd36a002aa0a2c19670f616010532ce869c1afa214522bb5911da5bac5458a76687c4d606e80c38193b82cc77c473745d212a9e7541c07efa40e285b51fd8c30d229692d072835a8ba3214b55c20ff1c288ed4c35c70a61e432802cbc112a533d97077e1a11ce3a0b360d670f821446707835e3fb7fbfe397f71b9e86e88a46e98717e1c5e83c591df2486c655a75a46ff1a006357421c7749d3bdb2bb8560f1f2bbaff366b27864c39a033e8d5031e49b6872baf696054843b0a7e648f4bfa7d28ca06abf17f16889052060d54774646b70499942b15099824b05be0f33d7fd5ce20db0e1bc637ddc0759fbb0f9c361ebe1c616d3b1a8b8c0061873d4a3714689c72a8c617609801adbb4d45a6b43e753a9ab7635545764d679937647d25e2098ad66197977945011b32c14a2a4b175d81096979a58d1b6019bb8db4cb60cd7d8a060863a269ac6870cad2d2b13c95ef041ac7e6e7ce141e696fb9cb86e0399705b3f4f0dd001b11697272feef4522c06fabfb3cb5990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606",
-    "server_login_state": "57e1eef6dbfae519586cdad593b4416a4a7406ff79f96680ef4166d4a741b9567da7ae3f8135f9ba60dec35f9b8c2bc69c3c2aa5b226ed7c90fbf99a1e6a570a9f3c830c137e41163a8656c690aaa11e0708d2c19ec50a129d6c2642b1b4b4d68b7a86a0481071ed28d2b5b97d60768f89a58df855cac11ab4d7de96162f6104059229fd6bb46984114f4b6fb90555d59b86e4a73d199b0e023964ee3bad3b6349275757849186d33877efb59b025ac4b3eb5a88e4aeb0d84c3b543d2d29a19fb258069c8a701c8172890e1a05bdcae46512e382abf54114c320001858e2a65e92b60d3a60ccc423cb6887c8f3c6660505338c20b22b7abd475209a9632d677264256a1562e42c08700813ba5521749c6ef044da588f4b082c4ad43b2cd1bb545659a6422be5420a5e283746b2b0abba7e50e1cc513288efc6bc734a0d22b1a7a161aafb098b5483cfe4958376c9304f7701804c806033c46ad81fea3a273374976f6a347a1bad82d18b741b8ce0c08fd233aa814347490c622aba0175272de1db1e4f6434f71894f4eca3a4a4442303b1d19047e4f7bc3b77c7268bae5e1903c9fc6a4a754820941e90f57c5c2caa12c089e930bd11d68c7fe5a800a25aa87bcdacf1c321323f62c357d55a5de328c8d7a920d8029d2e75a47f66a7c1a94fdeeba784f739c7c08203b72089b65f5458850fe24ba1688d9bd7bd36d592a37bace99152815171a9f6c331fb8bdcd780627376fc3416da428cafba9f315c9767da92e2410d2d270adebcbe9dec5073b0970e44bda8d8818e049d57ca9b175c21c10192b1622fbdd0925a6541f3442c18432a67e894a077a9c0db8ab86a080264005b9b3cb891cc29862e64e600a546a3b53274799aabc7f1b57ac00db776c6768581c4eb1b62b96c03bc1065e073f6c79411c5b3ede334fa36b132820f3a20b3ca601d67e18029a895d00b987a2c928f984f85db6c52c66e7d660d9c5b44edf708cbc843971b3a4f1b0d0a90aad122b45840c84237761241c78c742b398297f2f131f654147f637586871872751bea78bd2439cd804453aec85e6135aa41bb4861a12e8ec53341660e787186ac97493508541af55510f94cd8b191f240447ab90caa74a97ab9b254d44da4408c7de263ea20070be2a09d142a12678fc758c8c77927516b1fa04793a2f161adc04592743d12ebb586a1ad3c7780a59823add36b2b716f3b40988665142cd69838f29e45d064bb3b49c9a0ce7ec16f4f29bd73173d3c90b0f1f3547d39929e5590d2431a88ec14e63967bb37341aeaa7b0675fc4f7a3a947b25f4a6c1af2544a9767d7826233987b8eb97201602ef892cc937871cc49aff8ab2218145628882dd36a002aa0a2c19670f616010532ce869c1afa214522bb5911da5bac5458a76687c4d606e80c38193b82cc77c473745d212a9e7541c07efa40e285b51fd8c30d229692d072835a8ba3214b55c20ff1c288ed4c35c70a61e432802cbc112a533d97077e1a11ce3a0b360d670f821446707835e3fb7fbfe397f71b9e86e88a46e98717e1c5e83c591df2486c655a75a46ff1a006357421c7749d3bdb2bb8560f1f2bbaff366b27864c39a033e8d5031e49b6872baf696054843b0a7e648f4bfa7d28ca06abf17f16889052060d54774646b70499942b15099824b05be0f33d7fd5ce20db0e1bc637ddc0759fbb0f9c361ebe1c616d3b1a8b8c0061873d4a3714689c72a8c617609801adbb4d45a6b43e753a9ab7635545764d679937647d25e2098ad66197977945011b32c14a2a4b175d81096979a58d1b6019bb8db4cb60cd7d8a060863a269ac6870cad2d2b13c95ef041ac7e6e7bcd7c73428b506226f1f9276610347f7585f5b74b42362f3dfe5a81c9422443244b6b094ff4cefeb0bd2262a547b16051b93716d439f0ef8e93fc59a14739b813191d09e31962be97a7c79621e189d3dd7feac68225b99fbb2df418eb55b2253636f3a7f9e142579dad9801298ab031aa56741b4cc964c6e81718611621cc2c60ed4f2bb96cca02cf5131b4f306ab7115f92e0f9e9d88893c6b9b3de3f4fe6b73e7db4dc8b6a24c0ff8ac35563ef1319fb4e8c210723c53f5d9e9662d7cd071edd55ebb4a4299d5ddf52a8e6916bea1f1e82cfcd1c6cfc1230f461ddcf03333126c921d8139a62e86f8a17a9b554d7a70e6ea15e6143e23ca02f9c8e7ca730e7108d10d2a6f785b50d50d030a64196af9ad199b9971905e7c153662370b3070003e27b0db09dfa9c440a801580ffd900f5665426ddba60b2bc085b8a59c9ebb1ed0e25613ad742c7920b90506117904a6e92f733e4af05a49edc5f7875a70923f65eab679ad12e19b3d8c7c3ba7717858ffb60d3f0032ce64dad1a27b0a02d05cc9578f9ab424d5451e0e3a43751d620bbc2c49a665f4f51e0d96ad0195365b216648297deda3be84e3e486ca835be789e9d81ad9522af1ced2067a78422c3086626ba6a59a188808b60beb68680ab536d667d81a11d470530165cc16f30ad6e7181903cff7faf65374499e8199697a9adddca6026f06631571ec3e7dff05b53514fd1a325b3338130f47f80506d3c03dfb522c4da53d4392d35775ed6f6b545be6a3653c10c1c5de14d5eceb54fbf13be24320ede77f375c07c635fe765217338cf8f8fc389011f0d850f9733132f100ee2d3f26fe646dd9e57a0bf2a934afe60ad3c53f40404c0af3dd614cbf4f9685a55cf5c39051dc33320863429a931e54a100ae866b784eeff5fc4f1937e8ffdc9e3512fd9eaa3f3205da57af71e7d8036133e22878c4e925d9f96763497fb52aad7f563a78c135aec2356e5fddcd9de91bcb62948d856e73215be92924288980a7c5a1cede239d38b19deee85eb686950b2d6d2364ae97bedd85d82b2100ff66b111f221d6ba75150e6cc69a5205a417f2c545f4d14a0f8ae1152af81167efdd8e175caf4488567d758025292a05ec8672afe769addd7e9706d549a18f7784a6ebce5c057d60aa3e673ad5e3f7e653f59d638571fe606a144ae7d785e94097aba7edd1c2b1f3dfe0e0f8c7fc01bf0af501070e1b97df143b522a9ab62dac28e5725c2a2e9146e93cb856fb8c38a0ecc40e19e34450904167b1810f151c43bc52c49baeb3fea02560895d85864e7eaaa68c92432426d794cf0a37c033e7cf51e86cf47353a5b7afc4d40fbe5b2b2fc0d39598577631315176dd66d750c62621253fcf73723d207d15cfe593e06926c7088225e3c0322411bd5fc5c57ea55b4cd6cfb61afe6468cd13613eba87eee723a81142faffd327608f1afa2fa5637b98fd89e3cd903ea552457859b87668f0c5aa1971becfd9bf510250154d958b551e7d29042e549f51416efceb0f637a4a2ff",
+    "server_login_state": "57e1eef6dbfae519586cdad593b4416a4a7406ff79f96680ef4166d4a741b9567da7ae3f8135f9ba60dec35f9b8c2bc69c3c2aa5b226ed7c90fbf99a1e6a570a1d089d44cb49d8f75e44169f517612670d6a6413463649b5344b41460f63a6e510244c8df8292595c2e86b85bc514931d769e2e7938e6a062e6e62f97e4149e39f3c830c137e41163a8656c690aaa11e0708d2c19ec50a129d6c2642b1b4b4d68b7a86a0481071ed28d2b5b97d60768f89a58df855cac11ab4d7de96162f6104059229fd6bb46984114f4b6fb90555d59b86e4a73d199b0e023964ee3bad3b6349275757849186d33877efb59b025ac4b3eb5a88e4aeb0d84c3b543d2d29a19fb258069c8a701c8172890e1a05bdcae46512e382abf54114c320001858e2a65e92b60d3a60ccc423cb6887c8f3c6660505338c20b22b7abd475209a9632d677264256a1562e42c08700813ba5521749c6ef044da588f4b082c4ad43b2cd1bb545659a6422be5420a5e283746b2b0abba7e50e1cc513288efc6bc734a0d22b1a7a161aafb098b5483cfe4958376c9304f7701804c806033c46ad81fea3a273374976f6a347a1bad82d18b741b8ce0c08fd233aa814347490c622aba0175272de1db1e4f6434f71894f4eca3a4a4442303b1d19047e4f7bc3b77c7268bae5e1903c9fc6a4a754820941e90f57c5c2caa12c089e930bd11d68c7fe5a800a25aa87bcdacf1c321323f62c357d55a5de328c8d7a920d8029d2e75a47f66a7c1a94fdeeba784f739c7c08203b72089b65f5458850fe24ba1688d9bd7bd36d592a37bace99152815171a9f6c331fb8bdcd780627376fc3416da428cafba9f315c9767da92e2410d2d270adebcbe9dec5073b0970e44bda8d8818e049d57ca9b175c21c10192b1622fbdd0925a6541f3442c18432a67e894a077a9c0db8ab86a080264005b9b3cb891cc29862e64e600a546a3b53274799aabc7f1b57ac00db776c6768581c4eb1b62b96c03bc1065e073f6c79411c5b3ede334fa36b132820f3a20b3ca601d67e18029a895d00b987a2c928f984f85db6c52c66e7d660d9c5b44edf708cbc843971b3a4f1b0d0a90aad122b45840c84237761241c78c742b398297f2f131f654147f637586871872751bea78bd2439cd804453aec85e6135aa41bb4861a12e8ec53341660e787186ac97493508541af55510f94cd8b191f240447ab90caa74a97ab9b254d44da4408c7de263ea20070be2a09d142a12678fc758c8c77927516b1fa04793a2f161adc04592743d12ebb586a1ad3c7780a59823add36b2b716f3b40988665142cd69838f29e45d064bb3b49c9a0ce7ec16f4f29bd73173d3c90b0f1f3547d39929e5590d2431a88ec14e63967bb37341aeaa7b0675fc4f7a3a947b25f4a6c1af2544a9767d7826233987b8eb97201602ef892cc937871cc49aff8ab2218145628882dd36a002aa0a2c19670f616010532ce869c1afa214522bb5911da5bac5458a76687c4d606e80c38193b82cc77c473745d212a9e7541c07efa40e285b51fd8c30d229692d072835a8ba3214b55c20ff1c288ed4c35c70a61e432802cbc112a533d97077e1a11ce3a0b360d670f821446707835e3fb7fbfe397f71b9e86e88a46e98717e1c5e83c591df2486c655a75a46ff1a006357421c7749d3bdb2bb8560f1f2bbaff366b27864c39a033e8d5031e49b6872baf696054843b0a7e648f4bfa7d28ca06abf17f16889052060d54774646b70499942b15099824b05be0f33d7fd5ce20db0e1bc637ddc0759fbb0f9c361ebe1c616d3b1a8b8c0061873d4a3714689c72a8c617609801adbb4d45a6b43e753a9ab7635545764d679937647d25e2098ad66197977945011b32c14a2a4b175d81096979a58d1b6019bb8db4cb60cd7d8a060863a269ac6870cad2d2b13c95ef041ac7e6e7bcd7c73428b506226f1f9276610347f7585f5b74b42362f3dfe5a81c9422443244b6b094ff4cefeb0bd2262a547b16051b93716d439f0ef8e93fc59a14739b813191d09e31962be97a7c79621e189d3dd7feac68225b99fbb2df418eb55b2253636f3a7f9e142579dad9801298ab031aa56741b4cc964c6e81718611621cc2c60ed4f2bb96cca02cf5131b4f306ab7115f92e0f9e9d88893c6b9b3de3f4fe6b73e7db4dc8b6a24c0ff8ac35563ef1319fb4e8c210723c53f5d9e9662d7cd071edd55ebb4a4299d5ddf52a8e6916bea1f1e82cfcd1c6cfc1230f461ddcf03333126c921d8139a62e86f8a17a9b554d7a70e6ea15e6143e23ca02f9c8e7ca730e7108d10d2a6f785b50d50d030a64196af9ad199b9971905e7c153662370b3070003e27b0db09dfa9c440a801580ffd900f5665426ddba60b2bc085b8a59c9ebb1ed0e25613ad742c7920b90506117904a6e92f733e4af05a49edc5f7875a70923f65eab679ad12e19b3d8c7c3ba7717858ffb60d3f0032ce64dad1a27b0a02d05cc9578f9ab424d5451e0e3a43751d620bbc2c49a665f4f51e0d96ad0195365b216648297deda3be84e3e486ca835be789e9d81ad9522af1ced2067a78422c3086626ba6a59a188808b60beb68680ab536d667d81a11d470530165cc16f30ad6e7181903cff7faf65374499e8199697a9adddca6026f06631571ec3e7dff05b53514fd1a325b3338130f47f80506d3c03dfb522c4da53d4392d35775ed6f6b545be6a3653c10c1c5de14d5eceb54fbf13be24320ede77f375c07c635fe765217338cf8f8fc389011f0d850f9733132f100ee2d3f26fe646dd9e57a0bf2a934afe60ad3c53f40404c0af3dd614cbf4f9685a55cf5c39051dc33320863429a931e54a100ae866b784eeff5fc4f1937e8ffdc9e3512fd9eaa3f3205da57af71e7d8036133e22878c4e925d9f96763497fb52aad7f563a78c135aec2356e5fddcd9de91bcb62948d856e73215be92924288980a7c5a1cede239d38b19deee85eb686950b2d6d2364ae97bedd85d82b2100ff66b111f221d6ba75150e6cc69a5205a417f2c545f4d14a0f8ae1152af81167efdd8e175caf4488567d758025292a05ec8672afe769addd7e9706d549a18f7784a6ebce5c057d60aa3e673ad5e3f7e653f59d638571fe606a144ae7d785e94097aba7edd1c2b1f3dfe0e0f8c7fc01bf0af501070e1b97df143b522a9ab62dac28e5725c2a2e9146e93cb856fb8c38a0ecc40e19e34450904167b1810f151c43bc52c49baeb3fea02560895d85864e7eaaa68c92432426d794cf0a37c033e7cf51e86cf47353a5b7afc4d40fbe5b2b2fc0d39598577631315176dd66d750c62621253fcf73723d207d15cfe593e06926c7088225e3c0322411bd5fc5c57ea55b4cd6cfb61afe6468cd13613eba87eee723a81142faffd327608f1afa2fa5637b98fd89e3cd903ea552457859b87668f0c5aa1971becfd9bf510250154d958b551e7d29042e549f51416efceb0f637a4a2ff",
     "password_file": "b2dfea72bb9ea93ab47cb7aa60de281e5040bbc56445fd2de61ac1dc5846d14c1f08c0cc740cec36c5229cc291774dcbce698c97103217c677d864391c1aa87838a7bf20d32e1b5bff19f135ecd4e080ce52e4f9c55f1bf8ba8fb95f194ea4466c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f4a0f14903fb1baa3ee37946e6b0b4d89dec9ff5a1a4fee3f89d657816f96ce944a4f0124c0b94d7257de7fa9a420c531f7c15b1734c9eaa17e254881d57eea7b",
     "export_key": "f9e4f5484325c4ebd2af7e558642729a483b10d1c17efa4eb36a4535b3186a7e857e75ec039dfe8d4ca998beda5a0eaf37a9de4c2a6e1548b52f6a7a7535c850",
     "session_key": "57e1eef6dbfae519586cdad593b4416a4a7406ff79f96680ef4166d4a741b9567da7ae3f8135f9ba60dec35f9b8c2bc69c3c2aa5b226ed7c90fbf99a1e6a570a"
@@ -879,7 +1040,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_KEM_RISTRETTO255: &str = r#"
     "credential_finalization": "8b1671171d6bad3dcd6965853003e7583f1b62779180cbb9d363c1ccbb55220df922c5d7910050c9bc798d84cac7c04b13a5f53cd235537c51c22d8271012d8d",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b024c4ae302511a1f8ae37d71ba2cdcffbfb816910e4d1aa313033403ca7bdde745",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b024c4ae302511a1f8ae37d71ba2cdcffbfb816910e4d1aa313033403ca7bdde745a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e550df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1e234b6047353e07437a54b0b4ce1784896bfa3a52fabca52c7222f3414623f9a4fbbf7447c04aaea852adeb839539560148ca4a89a8f8c2b8582ac29f4867a6bc9135aba83027a61189b3581a18184b1802a88463ea8cd41c40f2e344707314ba01c4e320292ce440c8081cb0960832cd03800dbcf333563218084a042b0f05c2f09936847d4bd391962b6860808a1a262bc7af6f11de67b98a7a1104cf6b434b86a23f15ceb019e197790c8f0116a551f04664f72286ff4c437288ab9b5b27366c8ab7d15327b85cba340713c67baab684edeb251fcd70a000c15b90cbf41b36259f37a7f743e47c525e5436e19815d91d452cd4c8e0cc70e069b491176adcf8ac58a349f98d669a1e511aa59bdaf34a0aea104ce6ca27a2347de406ea5a26bf4924fb5a27132113768f40573016867e5161251a09e1513a79b98907864c7b3b14fd350b6d5c6dda6025b4c6a5c7841bf4407fc3c780ff7866ca89715ab18317cb67ba78198312ca3ac7218b44d45b593948a6585d2836927b27418cf38a3cf5091579279c514356b43b0014ad53f20377de1dc32c89031e8ac94cc4c3caf4513edfb6e8a634866e78a86c8c0296275c873858e985dbb505c279145741807f0c419a10345825b7a3394cda9512920f49a8cf37709b0bddc10bd1f96587843b5be795ab8642c34506b285c70788a660fc73f96198ebb16c67ad11316124aa5457c972cc8192553ea450704cb0730ba9814326b083c14a23962ced1bcdbb790985bbfe9f323e4b463a386866b546a24d06d9b7704cba29eaf437016180d2b1b406e2a4eafd87b6b4a5e811734e47bbc2be6cefeda8c9ef09b37ec9df84086e21b352e7293eb508143fbb09f142cea7515e99baa730a12c7b916c21941eddc5a6a8c32312c3a7e2c5dfec934ba33cced8206b54a1785442c11044805699f991153880628d0032acb84bc62ca2e256c4ad44459bdbb0baf5b62ce1c37561c517765947c195656ccce8fb5269981b70f0c79c44c8ebcf5c46a007177e9c62df67531ba8f23253539371466215745181c25c3c74147861190c833ecbecf02d0552cc5265a395f892a191a76c861bdf5c2966c5ba0f5f8c414555ca1c7a95f264cd56ba40a454522885e57e2b7e03428d75ba7edb44c9d183a2a94bc48f6c7bba360ac242475da225c42c1430ac5da6b3a518ba0e378700699a3cef48744589295092623647110e461a8aa39b2919fc1f257e4d49cb69a8f4933109770bf9ab7a1e54c89ed1387aee61cbef99b1348834d2c7d33837767512b4ec3c38dfc89d0129f29f171f9fac246aa7537150f62a60c6f4945360823c95c585c4a09451029baf86e1f25b6189266338c862648a746f4590022bf70d59dbe67b1a9f14362e08181d4183b3a48c867147eb453124488f94173f8e78495302290874b305659084a4cc983ce51372895e054117082914ca9fc4040d6c66ac50164e813314ac4284339031ef27c217c843022260b462e7c92204f72a5b3a48bdee23e080b7a6eab7fc92a3d34a952b209c0a671257c051249137154060c1605034d10c3004a42302715010184de80cca2d2819dbc5caed72888d38ae6eb043b663b3de1c8e6955deb8b900df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7c43e8ded31c09c3034e2803679bcb56541de9c8492c916517cc1a84a7bba3e3c990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606",
-    "server_login_state": "33a84c972711206f3aa59acf32c392813509f31b890457416def702b3cc3210e1f2a0da1f597c1d46f209a4b2bceee9b2d8a823090c3fa4ddb1a0de2e665c2978b1671171d6bad3dcd6965853003e7583f1b62779180cbb9d363c1ccbb55220df922c5d7910050c9bc798d84cac7c04b13a5f53cd235537c51c22d8271012d8d0df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
+    "server_login_state": "33a84c972711206f3aa59acf32c392813509f31b890457416def702b3cc3210e1f2a0da1f597c1d46f209a4b2bceee9b2d8a823090c3fa4ddb1a0de2e665c297cb4d87eb5fba19ffff15f5b563d431a627c7b6492f0f4fa94cb10544f02646f79aaa2baac6f3150792920858ddefe0c418fd95d1185353ec41544d4ba656b25c8b1671171d6bad3dcd6965853003e7583f1b62779180cbb9d363c1ccbb55220df922c5d7910050c9bc798d84cac7c04b13a5f53cd235537c51c22d8271012d8d0df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
     "password_file": "3e02fe2aa6d1735419baf59ed3247e2cefa7f49563d2625ab381fce8a2b74c5dc6df1104406f6bf004ca66ea1bb12f9dd6ec8dad89e70951be4d6d1d3c20a3d56c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f77a02225e442834c2dcf8c221b21fa66ff2320cd0f0a9fea7766ac787eeec530",
     "export_key": "e91d5ba62f92971ccc8267735c5b8c5ced5d66dffb4a4f73fb76483f14317a61",
     "session_key": "33a84c972711206f3aa59acf32c392813509f31b890457416def702b3cc3210e1f2a0da1f597c1d46f209a4b2bceee9b2d8a823090c3fa4ddb1a0de2e665c297"
@@ -919,7 +1080,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_KEM_RISTRETTO255: &str = r#"
     "credential_finalization": "028f4025b8478259ea2a56a583b75b27f94cefd531bf92ea5c181d1ae9bd6339cd378e19ae4366a208d175cdd6768cd188a72410d7d7896bdbe956092c694cae",
     "client_registration_state": "d198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b0211762ea9a8599a7127521c65455f5fec4cdf9ba363bc3d485e647e1f5f9d1c11305bb97193aec8cdb142b5940b94f504",
     "client_login_state": "d198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b0211762ea9a8599a7127521c65455f5fec4cdf9ba363bc3d485e647e1f5f9d1c11305bb97193aec8cdb142b5940b94f5042488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e550a90765576556e26c4710196d074b0b7364fde35571c25a07c26c0c7b376fd3b7dfe677fc623a925d04f6e71c3d112275e6b2b31459d27fb80a6609347abcf1aabb1c3283e9bfa6dbc9311c3630f99b09da61778351492c50a3212962f6dac1442cb3d6119a7891523260800f971b5e41c859e4a25cd214090cb16f8d0b42e77403900785821a349940088fcaaad0b4cb3046965a58842829a9f53aa6ac0978834200015c881817fcb06113118cbded1ae7148066615a6430a6382094323076d9e076156052bfca392d3f23749499fb1f220c779101aa36a1024ad221605a13627e3665487e18be8b089cbe7b64f5116ba5b7cd9032ca49787edc09dbc9305fa17ca149c1df0698378db32a4287193d58d48db13685a69cec061eaa4251ee17bb5063ec8b34a75aa089deb6965c83d6c4cb2f40c375abb3afddb32c8003673692a49bb10c01528d708bc2d108ba0e935f723a772d0306423b55181bb3e1308406387fd6c3011d59e2572abaed1760aa8cf85fa349236a0a43b7e33f0b093b54e9b67600b7cce542c937aab77ffca6f953624094062d7bb669b40a97344cf88c44328c0bb8a04c035c1bcbb77cdcff27b952c1d81ac30e29387b335182bd4a4e079bec3c0ab894aaf3a2c9ffafb715af3237864805875c4f7b5aa7faac089694e2dfc201c692d4fc5b258d6cf2857cfbd041f768b0f2022bd14e9b3a05827c7089a10311bfdd598dd105b899b159a1438d891128431b1e9c7068a663122094ff29b76e3548d9c4206f5895f1dc1754261557cd414505c1939217087da8aaf4b1dc027c7a19205add2a9c3f74cfbc4b313a85bd1e35886db274a130593f9b02e98a89edcc342c0423359bbd930b49fa392d39679cc217502e3c3655b1acd25aff73b2e99f19f0fb96af5503bec374a35b4c2bc88816d435b805478a5523f451b0ee2252c8deb86e8c14ac36201fbd263c568548bfa3917db019a3316512c44b379503a3a496d8a8b73c9b8999b74d1996fe576b96aaa9642fb3fb71747972c79e61c9d7ce46cd011a24cca5db3b29b6ec2a6c750b5e12b9dc9d8506a386986db100c76381d1841fd232a293079bd1bb7aa2c07392bc94b9395ec858ce80292fa71cec5f280ecf5be7e95a09bbc7d1b0c247ac926a4f566de12809877c1ab3920a45a8579a560d5b39242f618c2b6bbe6c99d03701e76d9018ad4b0933c661b7757c85b2dd730c0f4c03723e61f016a9303e01014fc1cdb025342f3b8d1d82339f766548b85c8325b0c509ea26026fc35bf1d442c445c056ac0ac12ca4057d2730e00c2b56c9c79e896b3f0768cb2c678291e01e41f4e0a2e5214aa2f48070d015b1632147ba0532de19d8452aec9fb078713816341acf8b807dfba0f31445e37c316044693aaca34d6bca20e9a3395f7487d3b9ec43591a17c660594a974bc775e3301de214b2e5111d8e68e42c40ef7804a3cb079bf9b4ac1d0b9131693465c5e3c55c695541c7fa00907c40d4951904b0b29a21b58d5bc501633691238ce91b4093be78f95db022e3c5bfa01805b03c0e536c30e9cae3a02a223b4124b3c28bd8b5b3d2119917194f717095e5acc8c7457a1b16b43c591ab201c96349c6c651fd900b0e3f19afd636f20290ddc6361bdb72aa63ef39d7bf513b06a808f0140dafd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b002488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff4205c7cae06e7f6161260078c533a9dd0a2dc897b09a52c0ac0908aa2b5896834943f569fcab165ea309f35b251e96618ac37a9309a7c2f2aa05a43ebee60ae09cbe95d20df9365e57933836a27514e538e76ba46e399b63dc83d254bec6206fbb02050ea08a77c6aed0619be2eb17dc250d2bda88cf14b8e4109b8d593909ecac17e5120c8bc49aacc4081215fce2bfba123f97215f9ffc3379204740ea7d5d356056889cf56464c7e43ff6e9380d0382ff91558ca73016087867d1afc6c1a3d18b91cd21703e2822b75818ec6334f9686a4ef635810c0445d6cd50d7420cb3453d000332598c077602076ab23ad1806be112e49229034bacec6c927df2162e3440af2610e1a52189808c4adb1e88107eba85afa769921d8c1b0a20aa046403eb5b52376640e77305e460b344ec46180497e4d1533f021aae78203658b101e625e79a29fc3627501b6eeefc1df5c13702d1bcc141a6e83bc65901bd717c1f02c03aaeaba92e21ac74d63c20352c4509502367c7ddfb06f7cc3d823c68f7bb545621bc8dd892b433b19ffa37bd838b856b6e10164489a924bcf16147d68bdb08b2815b99f9a389a3f73d009966042b83573a6050d7031100a5a10a63213721d351ced5b96b356cbecaa189bcd1a16d801714734285469349b3cb00b94d3bb094eb201b6bda89e9b471d9875339e6b87538afe2577ed88241e5a248f096341ce41e783ab9e34a855c33067a36acd109380d86a474fa40b97cc8f388769be019a1e76f6ce96fdd886211f721724527f98a93fa786b4664145c3285202c114db836eecacbdd90164215b03096b140f399f2fa783d74165061a9a4e83b8f7528cdd60b2ed15319d5590cc6370a92c415a881b0bb1008050053335bb0778a87c58d8786c04af67cf19bc1ad67355944b925f85bbda0836754c99452c066c4c5bb2acb51ba0e71083329d040c31206beb9ca28836b48d05683f9469799aa13a97503aca67cf97abd348d2e1000d5c83a64701e05256a60e303ee86c5970bbb6003675d3712a6c3141b50a4d4352144114ae735cb1994bbda284fb10c7a155c7adc3ba986d52e700929fb39ba520937f611353db24104882034e7aa2a9c23d9828ebc0cabcc0b8524784782cc9165324f2b37c11e851fed2850baa671963858ebd6196ec4b3a795764e930eb02c810b6a2f833866f0f25f5b854cfeb90b3b264f6aa46a064cc13979bba1e44a07fc6f29cc20ed5cb1bb518e8f647cace7ae32e67a48e984417c9b5e8a4e72aaa379939796b2070ba5461bb0060630145f8214dc61c85ec792a3296f732a66ee94016a90cae9f11dcd03d03ad65ebba9adf5aa5e81493dd70a69fd354595e3be2d70ab3f295b3beba0886c0726e6579379b70da3c938667c0cea0824644bcf8857333ab6c0b9329485bef33b9b0398b607169a454b2663f73c575bb67755c4096c13d6d3bf86f0ce3f868a4160262f247d5a808f849acf90566e8e59ca27b59606ca7c286a93d19504271917f1a2c79aca8cc9c51ff6c135d6850eaf36b98703603f86648a51b8e73a9b8fe693ed659324142c103c1ad9626bca12c2e45232aab6bfecab23c5f6c76954183fd8c25d3c758bb3448b25910a90765576556e26c4710196d074b0b7364fde35571c25a07c26c0c7b376fd3b7dfe677fc623a925d04f6e71c3d112275e6b2b31459d27fb80a6609347abcf1aabb1c3283e9bfa6dbc9311c3630f99b09da61778351492c50a3212962f6dac1442cb3d6119a7891523260800f971b5e41c859e4a25cd214090cb16f8d0b42e77403900785821a349940088fcaaad0b4cb3046965a58842829a9f53aa6ac0978834200015c881817fcb06113118cbded1ae7148066615a6430a6382094323076d9e076156052bfca392d3f23749499fb1f220c779101aa36a1024ad221605a13627e3665487e18be8b089cbe7b64f5116ba5b7cd9032ca49787edc09dbc9305fa17ca149c1df0698378db32a4287193d58d48db13685a69cec061eaa4251ee17bb5063ec8b34a75aa089deb6965c83d6c4cb2f40c375abb3afddb32c8003673692a49bb10c01528d708bc2d108ba0e935f723a772d0306423b55181bb3e1308406387fd6c3011d59e2572abaed1760aa8cf85fa349236a0a43b7e33f0b093b54e9b67600b7cce542c937aab77ffca6f953624094062d7bb669b40a97344cf88c44328c0bb8a04c035c1bcbb77cdcff27b952c1d81ac30e29387b335182bd4a4e079bec3c0ab894aaf3a2c9ffafb715af3237864805875c4f7b5aa7faac089694e2dfc201c692d4fc5b258d6cf2857cfbd041f768b0f2022bd14e9b3a05827c7089a10311bfdd598dd105b899b159a1438d891128431b1e9c7068a663122094ff29b76e3548d9c4206f5895f1dc1754261557cd414505c1939217087da8aaf4b1dc027c7a19205add2a9c3f74cfbc4b313a85bd1e35886db274a130593f9b02e98a89edcc342c0423359bbd930b49fa392d39679cc217502e3c3655b1acd25aff73b2e99f19f0fb96af5503bec374a35b4c2bc88816d435b805478a5523f451b0ee2252c8deb86e8c14ac36201fbd263c568548bfa3917db019a3316512c44b379503a3a496d8a8b73c9b8999b74d1996fe576b96aaa9642fb3fb71747972c79e61c9d7ce46cd011a24cca5db3b29b6ec2a6c750b5e12b9dc9d8506a386986db100c76381d1841fd232a293079bd1bb7aa2c07392bc94b9395ec858ce80292fa71cec5f280ecf5be7e95a09bbc7d1b0c247ac926a4f566de12809877c1ab3920a45a8579a560d5b39242f618c2b6bbe6c99d03701e76d9018ad4b0933c661b7757c85b2dd730c0f4c03723e61f016a9303e01014fc1cdb025342f3b8d1d82339f766548b85c8325b0c509ea26026fc35bf1d442c445c056ac0ac12ca4057d2730e00c2b56c9c79e896b3f0768cb2c678291e01e41f4e0a2e5214aa2f48070d015b1632147ba0532de19d8452aec9fb078713816341acf8b807dfba0f31445e37c316044693aaca34d6bca20e9a3395f7487d3b9ec43591a17c660594a974bc775e3301de214b2e5111d8e68e42c40ef7804a3cb079bf9b4ac1d0b9131693465c5e3c55c695541c7fa00907c40d4951904b0b29a21b58d5bc501633691238ce91b4093be78f95db022e3c5bfa01805b03c0e536c30e9cae3a02a223b4124b3c28bd8b5b3d2119917194f717095e5acc8c7457a1b16b43c591ab201c96349c6c651fd900b0e3f19afd636f20290ddc6361bdb72aa63ef39d7bf513b06a808f0140da06934f3175dde17f994ac4c21ed7d2ca695e7121cdcb007cbbaa3798d1bd931998e2b63c2e9e05f5a976c845cafe467b990dfc03f42284a4a9d5cf73fae1a8f7",
-    "server_login_state": "5a28fd70a40c1ab6cbc5713b3ce8961646a41646cc1edd7ddde89aa857608ede594076a5796a92ec5e95dc1765f3e11d64931bc4284c17bc38b34ae1a9984c1c028f4025b8478259ea2a56a583b75b27f94cefd531bf92ea5c181d1ae9bd6339cd378e19ae4366a208d175cdd6768cd188a72410d7d7896bdbe956092c694cae0a90765576556e26c4710196d074b0b7364fde35571c25a07c26c0c7b376fd3b7dfe677fc623a925d04f6e71c3d112275e6b2b31459d27fb80a6609347abcf1aabb1c3283e9bfa6dbc9311c3630f99b09da61778351492c50a3212962f6dac1442cb3d6119a7891523260800f971b5e41c859e4a25cd214090cb16f8d0b42e77403900785821a349940088fcaaad0b4cb3046965a58842829a9f53aa6ac0978834200015c881817fcb06113118cbded1ae7148066615a6430a6382094323076d9e076156052bfca392d3f23749499fb1f220c779101aa36a1024ad221605a13627e3665487e18be8b089cbe7b64f5116ba5b7cd9032ca49787edc09dbc9305fa17ca149c1df0698378db32a4287193d58d48db13685a69cec061eaa4251ee17bb5063ec8b34a75aa089deb6965c83d6c4cb2f40c375abb3afddb32c8003673692a49bb10c01528d708bc2d108ba0e935f723a772d0306423b55181bb3e1308406387fd6c3011d59e2572abaed1760aa8cf85fa349236a0a43b7e33f0b093b54e9b67600b7cce542c937aab77ffca6f953624094062d7bb669b40a97344cf88c44328c0bb8a04c035c1bcbb77cdcff27b952c1d81ac30e29387b335182bd4a4e079bec3c0ab894aaf3a2c9ffafb715af3237864805875c4f7b5aa7faac089694e2dfc201c692d4fc5b258d6cf2857cfbd041f768b0f2022bd14e9b3a05827c7089a10311bfdd598dd105b899b159a1438d891128431b1e9c7068a663122094ff29b76e3548d9c4206f5895f1dc1754261557cd414505c1939217087da8aaf4b1dc027c7a19205add2a9c3f74cfbc4b313a85bd1e35886db274a130593f9b02e98a89edcc342c0423359bbd930b49fa392d39679cc217502e3c3655b1acd25aff73b2e99f19f0fb96af5503bec374a35b4c2bc88816d435b805478a5523f451b0ee2252c8deb86e8c14ac36201fbd263c568548bfa3917db019a3316512c44b379503a3a496d8a8b73c9b8999b74d1996fe576b96aaa9642fb3fb71747972c79e61c9d7ce46cd011a24cca5db3b29b6ec2a6c750b5e12b9dc9d8506a386986db100c76381d1841fd232a293079bd1bb7aa2c07392bc94b9395ec858ce80292fa71cec5f280ecf5be7e95a09bbc7d1b0c247ac926a4f566de12809877c1ab3920a45a8579a560d5b39242f618c2b6bbe6c99d03701e76d9018ad4b0933c661b7757c85b2dd730c0f4c03723e61f016a9303e01014fc1cdb025342f3b8d1d82339f766548b85c8325b0c509ea26026fc35bf1d442c445c056ac0ac12ca4057d2730e00c2b56c9c79e896b3f0768cb2c678291e01e41f4e0a2e5214aa2f48070d015b1632147ba0532de19d8452aec9fb078713816341acf8b807dfba0f31445e37c316044693aaca34d6bca20e9a3395f7487d3b9ec43591a17c660594a974bc775e3301de214b2e5111d8e68e42c40ef7804a3cb079bf9b4ac1d0b9131693465c5e3c55c695541c7fa00907c40d4951904b0b29a21b58d5bc501633691238ce91b4093be78f95db022e3c5bfa01805b03c0e536c30e9cae3a02a223b4124b3c28bd8b5b3d2119917194f717095e5acc8c7457a1b16b43c591ab201c96349c6c651fd900b0e3f19afd636f20290ddc6361bdb72aa63ef39d7bf513b06a808f0140da5fdab17ebd735c10f46109f3c277c1291bb9508916c99888c3bf7c1e0ee0337c9507ccade52de9434ef4c4e14638b219eb6f6e066afca97ecc8319121851bdc329b0c92b3d6ae724e801aae071e897862031e7b83dfa2610d63b597b675e945df346dd049c77cca2feddb40f10ee0f43de4df82ad99e35ddb82bba776d1f08e6c4ce776d246a825f9106819bf203e90b84f6db111af4bf3674f171b758692fcb12b4379d5b02051a456a86b18196790a4818948b226895a61236b2e1791e6e9c8c6ba56673ac2bfd7158605a71e11c5df28cae8047e25ce488daa5587aa89a158deac89d3aa292610b315d7cfa81b2741f2a0b7ee7f253491d76cc9070c82f333f997a302bf9fc4d28fbf8f7707cff65d70e486aef8029a13ee482a531851bec245de9bfc4698ca8c9850c8e82b37bfaed99db864d3b86ca87c8ca8fd5b3188d12a920875be70e74b6559ece045ae69ee68ce1c351bb33365973f21a2ac919f56064ef584464263e58cfbdc0c6e45159533674c4b6886e2f490c753880bffc75dd40b602457989ad65f317113f3946be0403a7516a32058c862a94121947fb29c1319789a9a945c8c58be23068604131775f31e183e447ad144f595952e9d0b02d20f968a9cbe8fe1ec834b0344bf67944a3a2cddff21ce4fa8b4e6b753d75673c79a05e1177649d7b39dc4434a5f0b758845f04dab7f458db2b5bfb1b5f6c5a154d38e63c377af57c4601d9779b46321780debb134bc475cbbf185fe925a054132942be626e98c0cfe5488dbecf31095cb3388c1cde14dafde391d8500c540c1ec034fe354738d29bc07d77b464c2ba1c03bec3402d8177d77a52ed9fd818ae6ed1db63d14185323358b6772b4b3810c9e125fd5e4d46d55b481843e5fd5f33faf58f5ac2e0ae45f2f28c7a43feb247fd24c7cf3545b5368626cae65e843fa6f1f62b742eb5d226a875f288df64280da803690da7345c0b0b3131c25e5897ab4b16377f8edd3b8f8c890c5c7f234782d9d8ef10f0fcc66724aa9dc5ebd5f8798d501ced12abf9df9687ed47210dfd064cf5b15373480f98aded3e77e517bad05915b208057371772793a8197466208d70494547f3fc3a0337030da87e1118028d4ff415a8a858fd069d7b33ec1ca3369e7e2a0e0e4463322d4e24b3beae49202408cadc8f1d8e6ad4d6e903123023360c169dd9b59edee16c2a5daee12689980ff34efe56e020a9fbae7c894d5bdbccdeb3973ff434cf178903b9b84af3045d9db257d3a9f05e0a7053be758342f230986f7bcdd05ff7e319e570310e14de1dc7a7b2696b5d4608a20d55545a86f052383fc193558ce2d264e392e4436d2f48594f14e3f19a2205179e3337cd0333e6b9b6de2d3bb95ec9c6321bda96a6fecd45f38b2e53aea7cf1c29a275d5bcf33a4096ef05839348a81791bcb316d3ae9c47e96dc067040716dde4e1ae640e4eb59f74ed336ee43d4f52642e68a0456bdd7a6532b481799db0b9ecdbae93918fb8eac190cb94e6dbabc647eb1a59b22a38",
+    "server_login_state": "5a28fd70a40c1ab6cbc5713b3ce8961646a41646cc1edd7ddde89aa857608ede594076a5796a92ec5e95dc1765f3e11d64931bc4284c17bc38b34ae1a9984c1c37c135262dce64ac0b468f1037b3e72f9f5cdcc875af60c065a2b8ff81ac499746a67241d0e58cc9b2dc231221312df26fdcbef796b3fab59eb100f3f52b34a9028f4025b8478259ea2a56a583b75b27f94cefd531bf92ea5c181d1ae9bd6339cd378e19ae4366a208d175cdd6768cd188a72410d7d7896bdbe956092c694cae0a90765576556e26c4710196d074b0b7364fde35571c25a07c26c0c7b376fd3b7dfe677fc623a925d04f6e71c3d112275e6b2b31459d27fb80a6609347abcf1aabb1c3283e9bfa6dbc9311c3630f99b09da61778351492c50a3212962f6dac1442cb3d6119a7891523260800f971b5e41c859e4a25cd214090cb16f8d0b42e77403900785821a349940088fcaaad0b4cb3046965a58842829a9f53aa6ac0978834200015c881817fcb06113118cbded1ae7148066615a6430a6382094323076d9e076156052bfca392d3f23749499fb1f220c779101aa36a1024ad221605a13627e3665487e18be8b089cbe7b64f5116ba5b7cd9032ca49787edc09dbc9305fa17ca149c1df0698378db32a4287193d58d48db13685a69cec061eaa4251ee17bb5063ec8b34a75aa089deb6965c83d6c4cb2f40c375abb3afddb32c8003673692a49bb10c01528d708bc2d108ba0e935f723a772d0306423b55181bb3e1308406387fd6c3011d59e2572abaed1760aa8cf85fa349236a0a43b7e33f0b093b54e9b67600b7cce542c937aab77ffca6f953624094062d7bb669b40a97344cf88c44328c0bb8a04c035c1bcbb77cdcff27b952c1d81ac30e29387b335182bd4a4e079bec3c0ab894aaf3a2c9ffafb715af3237864805875c4f7b5aa7faac089694e2dfc201c692d4fc5b258d6cf2857cfbd041f768b0f2022bd14e9b3a05827c7089a10311bfdd598dd105b899b159a1438d891128431b1e9c7068a663122094ff29b76e3548d9c4206f5895f1dc1754261557cd414505c1939217087da8aaf4b1dc027c7a19205add2a9c3f74cfbc4b313a85bd1e35886db274a130593f9b02e98a89edcc342c0423359bbd930b49fa392d39679cc217502e3c3655b1acd25aff73b2e99f19f0fb96af5503bec374a35b4c2bc88816d435b805478a5523f451b0ee2252c8deb86e8c14ac36201fbd263c568548bfa3917db019a3316512c44b379503a3a496d8a8b73c9b8999b74d1996fe576b96aaa9642fb3fb71747972c79e61c9d7ce46cd011a24cca5db3b29b6ec2a6c750b5e12b9dc9d8506a386986db100c76381d1841fd232a293079bd1bb7aa2c07392bc94b9395ec858ce80292fa71cec5f280ecf5be7e95a09bbc7d1b0c247ac926a4f566de12809877c1ab3920a45a8579a560d5b39242f618c2b6bbe6c99d03701e76d9018ad4b0933c661b7757c85b2dd730c0f4c03723e61f016a9303e01014fc1cdb025342f3b8d1d82339f766548b85c8325b0c509ea26026fc35bf1d442c445c056ac0ac12ca4057d2730e00c2b56c9c79e896b3f0768cb2c678291e01e41f4e0a2e5214aa2f48070d015b1632147ba0532de19d8452aec9fb078713816341acf8b807dfba0f31445e37c316044693aaca34d6bca20e9a3395f7487d3b9ec43591a17c660594a974bc775e3301de214b2e5111d8e68e42c40ef7804a3cb079bf9b4ac1d0b9131693465c5e3c55c695541c7fa00907c40d4951904b0b29a21b58d5bc501633691238ce91b4093be78f95db022e3c5bfa01805b03c0e536c30e9cae3a02a223b4124b3c28bd8b5b3d2119917194f717095e5acc8c7457a1b16b43c591ab201c96349c6c651fd900b0e3f19afd636f20290ddc6361bdb72aa63ef39d7bf513b06a808f0140da5fdab17ebd735c10f46109f3c277c1291bb9508916c99888c3bf7c1e0ee0337c9507ccade52de9434ef4c4e14638b219eb6f6e066afca97ecc8319121851bdc329b0c92b3d6ae724e801aae071e897862031e7b83dfa2610d63b597b675e945df346dd049c77cca2feddb40f10ee0f43de4df82ad99e35ddb82bba776d1f08e6c4ce776d246a825f9106819bf203e90b84f6db111af4bf3674f171b758692fcb12b4379d5b02051a456a86b18196790a4818948b226895a61236b2e1791e6e9c8c6ba56673ac2bfd7158605a71e11c5df28cae8047e25ce488daa5587aa89a158deac89d3aa292610b315d7cfa81b2741f2a0b7ee7f253491d76cc9070c82f333f997a302bf9fc4d28fbf8f7707cff65d70e486aef8029a13ee482a531851bec245de9bfc4698ca8c9850c8e82b37bfaed99db864d3b86ca87c8ca8fd5b3188d12a920875be70e74b6559ece045ae69ee68ce1c351bb33365973f21a2ac919f56064ef584464263e58cfbdc0c6e45159533674c4b6886e2f490c753880bffc75dd40b602457989ad65f317113f3946be0403a7516a32058c862a94121947fb29c1319789a9a945c8c58be23068604131775f31e183e447ad144f595952e9d0b02d20f968a9cbe8fe1ec834b0344bf67944a3a2cddff21ce4fa8b4e6b753d75673c79a05e1177649d7b39dc4434a5f0b758845f04dab7f458db2b5bfb1b5f6c5a154d38e63c377af57c4601d9779b46321780debb134bc475cbbf185fe925a054132942be626e98c0cfe5488dbecf31095cb3388c1cde14dafde391d8500c540c1ec034fe354738d29bc07d77b464c2ba1c03bec3402d8177d77a52ed9fd818ae6ed1db63d14185323358b6772b4b3810c9e125fd5e4d46d55b481843e5fd5f33faf58f5ac2e0ae45f2f28c7a43feb247fd24c7cf3545b5368626cae65e843fa6f1f62b742eb5d226a875f288df64280da803690da7345c0b0b3131c25e5897ab4b16377f8edd3b8f8c890c5c7f234782d9d8ef10f0fcc66724aa9dc5ebd5f8798d501ced12abf9df9687ed47210dfd064cf5b15373480f98aded3e77e517bad05915b208057371772793a8197466208d70494547f3fc3a0337030da87e1118028d4ff415a8a858fd069d7b33ec1ca3369e7e2a0e0e4463322d4e24b3beae49202408cadc8f1d8e6ad4d6e903123023360c169dd9b59edee16c2a5daee12689980ff34efe56e020a9fbae7c894d5bdbccdeb3973ff434cf178903b9b84af3045d9db257d3a9f05e0a7053be758342f230986f7bcdd05ff7e319e570310e14de1dc7a7b2696b5d4608a20d55545a86f052383fc193558ce2d264e392e4436d2f48594f14e3f19a2205179e3337cd0333e6b9b6de2d3bb95ec9c6321bda96a6fecd45f38b2e53aea7cf1c29a275d5bcf33a4096ef05839348a81791bcb316d3ae9c47e96dc067040716dde4e1ae640e4eb59f74ed336ee43d4f52642e68a0456bdd7a6532b481799db0b9ecdbae93918fb8eac190cb94e6dbabc647eb1a59b22a38",
     "password_file": "8491d48aca36c45f7ab2d76171ffc439fb2b489306e4bc53e15901ec637c4d694f6739dc7acf68899b8a142cfc20d9477f5f78d8c468b6c42769173f9a47e0c49ba5fe59121a9c4abdb8b51aaf2527a96c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f22e0d7030455cce24f8ecb61c9fa3455a36f064941e4d47d1392d6ba1665c13be6164dd0b0f459b29cc8245c15fbae98",
     "export_key": "38aad036f28a5570a99f86709f81283857fe62767fa06ad05cfd4630dedd235acd0df99a7a2d5454f4be8a829ee9c0e0",
     "session_key": "5a28fd70a40c1ab6cbc5713b3ce8961646a41646cc1edd7ddde89aa857608ede594076a5796a92ec5e95dc1765f3e11d64931bc4284c17bc38b34ae1a9984c1c"
@@ -959,7 +1120,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_KEM_RISTRETTO255: &str = r#"
     "credential_finalization": "aa014fcd310b79874c65b05b2fdd9213eefefbe97f78ae4c9f456cea9d1d7f394a0735c673d02c74df29d19637f7cf54bd49d50db6cdab491734ff6dd429267f",
     "client_registration_state": "01e8e8b444c2187242d7d6068d47af4e3c3dda297a7f66bdef4f3f61ea1f1eb7fe07eb830b6fc1c964481252595e17a3fdd274c8eea80e720ff035545c056c2a4e6f03008fa98ec77ed88056a620d86dc9561600864dbf5b0c5cdcb26dabb9677e58baa71a4a41e0a912bad39e3c374222909bfae21c4edf60645de1477e797b9f02597880",
     "client_login_state": "01e8e8b444c2187242d7d6068d47af4e3c3dda297a7f66bdef4f3f61ea1f1eb7fe07eb830b6fc1c964481252595e17a3fdd274c8eea80e720ff035545c056c2a4e6f03008fa98ec77ed88056a620d86dc9561600864dbf5b0c5cdcb26dabb9677e58baa71a4a41e0a912bad39e3c374222909bfae21c4edf60645de1477e797b9f02597880d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182701da2b287adf9f808b0ba41bca08cd5b4af452092db4b30b682de57f7715e5550a782adc040918712913c8731f48574a80711780da5785a9c0792976c605fd99d2f57c8e67c6126b4bec5b817194787114b073ef99221880c81483c6ea239158015f39c0499e358fb12c527a89c98f545ef7a37d9c30a2f1349d48333f87b213efc9cae126626a4badb90c20d2a56f5688d1e36b995b00f7008631b343db2429fa02a141d704af2f07c1ecb702a601645e5c81ad78e5207cfff079180fcace2e5927e97aab389824f729548e88845469c47aa32fc15c33a2c82aab50751fb1f4ed2026388531dea45a8e4c1448779bbc80a55a0b4f7642c3a9397b8b3264c825c08b1372ed123dfca13a60a4d350a933c87268c4a8202aaac9f7b001bc271cf16bc1d36a6a1e228974a119cbcbad12546ada537bc96c2ca5c594b22b29441547d404f45717274cab38ee9aa9fe79c47f01cffb1b402c1bb82c1276fd1644314a6d58b40237a3bc3cc7ebc3aa65c09c7a23230b2b631b18c4a3aeb32c8443002dbb75e3b77d33bbea87c634379c586c70720f760c079abf2782554560e9bdccda4776937244b6385653fe1415edaa5537a8f54903abac4c58743089d1abc816a578be5c678928cdb88673ab4c32513858c650842e029a8340a5eb27bec6227ff58786d66302bd1c990f596beb27384ea01e67a348ee96149309f8fc4c6a7d6b5e982469bc8518f34aebcecc5028896c2d2229c2568c1f3cc423454384c7f612061b98764facca9b62a8ee7c280f8d2230c607b6d57cd9324101bd0307c090da2d5231b3bbd99d420b831c622f8cfed0686fbc7a8c9c8655c07453653366c5c0a08c1bc8f16921485c6f7729a82752d5ad663f1784e9e97bea2cc24ad163bf6999fbbe57e93b8986d95cabb7700fc8661c62b963b073596626687d3c0ac809d01d5c8a4100f19f8345aa58d6aab1ca154482f8c6d43e8b2b43bab8e159481d89d916948c3556911594268cc7811c4116aeabe9b753a00c37509b5021e5185370003d0ca56a137b5d626339ef09a1d8b184a0697625964420c91f2e212ddc9764d804664481842684b6c1c728eb793b9812c08490fe4c731cb06b3a077939b83aa96965153611fe3c72e8558b7d74b31cfbabd86db1c91e408d4253942c2671ed91023a96cbc63661555bc20555a39ca49eff98c2a24901854c1b07a8fb6f7c5bc6a22033a8b03592661b485804114ba2708307c538a16c24db5abb7a1be616428bc464b4867a54dd47fcee6708e8badd55cbb00e142e5a68bcc4b6874eb3705931e17558fd975ad8658aa034505092257044207b95b7b786164e589a2a100269ee136538cba5b866c5358838b9608e41b6e5970037c054503c24db69b636edb4300e9ac1b4482132109c9bc9528c17471632e01755e00712bd37518eabc61f0579b1bf511093a85417b3e96a236b0d035ea810b9bd81dbab6bd946275aa6c98562b5e408443b2921cb0c705bc99703345c39f55bef13a7d9f216598fbbacc5548d6408c2fc664d72344480a58fe30358966363fb05612d20c217b6e8d128d223c07f3ac23d0a12bb135bad01a29ec40984b1234382cae377373827b582e0499f3958ee1194d162a67d005b592d8319e04749a67bf47696fc7e1cf5911aea743c3933e833beacea8409a36bb85110ea316c71d89c9d59cfed2a7373d95239aabd2fd8b82459a9fca09a70ac50ba5cbc0f22939a9d95b98c8e9b356ef294f593b00d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e18215f41b79baa8c6928b5471789b5c978ff327818bc7ee9c4984a302aa858e5787491f987cda1a70dfe15624276c9b8b5ab2b59584655327460d0d789db7b568ead23e06413fc81899fe4327d3520529f25ab7da8cf7c93a093198868469057ba74c2c3fdf27c2ca8c67ebd91d275a9252911c5ce3aa5123113d58121a9a95ea189633c4c70f0367aa92c418252150b3309d023dafb0bb0cb71ecf6391ea45a839346d09d46639bc123551c6a9072f2e88372f4a29d19410d956cda8b1885ab74363e53fedf935ffcb6c6ac0574618b9b2a37b5b400aea818263796ddae4aad1a92dd5a7b3c04b93792c423d4755dd18ab654009661236145cc3ef15a4baab39530b5bff1836338b2831845de239811c1cab96958663cbc2750a6f84e2b007b501b26751aa2a143c9269898c4cb455c024d1c761cc15c99544e30692bce7cd6fe16e755c090be88ee1a3a77e04c19abb29ff999931b603ec8358642a393c4757dce31b104979dcb95b92598040224e64574e1a4222c16715d47a48c0d2238ea1ad4ed1214367b82d78667eb5c1b8ac0306e73bfc7930545b51097b3fc43624c637b34cb2c5b7f751579441a580accd1431d2d164715b51999a405ea30977f75a51d75780f526d3974f38a8a0f563374751a562c2a05e904435b3b7c65a1a69181ec66bcc038545f0ac095f39145994c2cb54313f9220a065777b77a183c875edd1ab2203438b695012c5274e443bce1150662b9a8e71237596877c0a9628034547926a96f7c013f6aa33b00f069b9be661a3016527ea1410d1f44b0f302700bd8552466452864d4d568483ab74d50989abe11a244a2da55b28d07b2fa50b337a715af1b78cc661c2f7465939c57ec1c955b8280c10ea74748b25a25917f1352d3f78c41cd5b25abb9ffe527dcdc6c2932361647b312409754659b63338502eb5cdaa7c4b0d90ad0d590266e85372766af80053c20c0373ac6e330566a0810b1b1a25e21bcfddc228ee44bb2d64b249124344390b62482b43fa6d71c531f6784e94780190bb1093fb9b815a84a6e87ee2d4952cb19d81673f26c7c1cb1a8678a3bab62a07e7e7a7d0fb710a107ca8f8752c48928d5080e66502ac41acb346316aa2465ca1abed921c7a27b1c48410ed659192377719a88e599c604c26b93c7c60c6d58c41bc343bebc0a774494aebbaf4e59aacd72ebe7b1f7e36199c2cb7dee37ce4203afb3b3da320250de91c368a65995a233ac51a3c34475135260b448d94378f2d5bb922921d1e486a19f551f13819c2f91002f61cb3c22726784016c1b34ac1a1c5f85eb54c244fe9c3ebe4159a35959669704551a122a0c3d77ab916788ef371a7e69600e90b46f30487e45512c17882a0d57b99f89ee8980f96622345440697f708306a49e9a0395a0c3588d04ee30c201b0cb5e987133551169b67600b669e13842188650689084d86019dbb11b17acc8759d50dfbf8af3ed273d51aa3e461b10288418a005883045d129c8964eacd7311a5759565be8b504dc0300a42036889a4a57945ce058274e360a933037e2144c7da62cdb03d031063f6297f2fc1031ce7bf79c54ada116dff607af10672d956cb2f964f3906cd405613d6407f50a782adc040918712913c8731f48574a80711780da5785a9c0792976c605fd99d2f57c8e67c6126b4bec5b817194787114b073ef99221880c81483c6ea239158015f39c0499e358fb12c527a89c98f545ef7a37d9c30a2f1349d48333f87b213efc9cae126626a4badb90c20d2a56f5688d1e36b995b00f7008631b343db2429fa02a141d704af2f07c1ecb702a601645e5c81ad78e5207cfff079180fcace2e5927e97aab389824f729548e88845469c47aa32fc15c33a2c82aab50751fb1f4ed2026388531dea45a8e4c1448779bbc80a55a0b4f7642c3a9397b8b3264c825c08b1372ed123dfca13a60a4d350a933c87268c4a8202aaac9f7b001bc271cf16bc1d36a6a1e228974a119cbcbad12546ada537bc96c2ca5c594b22b29441547d404f45717274cab38ee9aa9fe79c47f01cffb1b402c1bb82c1276fd1644314a6d58b40237a3bc3cc7ebc3aa65c09c7a23230b2b631b18c4a3aeb32c8443002dbb75e3b77d33bbea87c634379c586c70720f760c079abf2782554560e9bdccda4776937244b6385653fe1415edaa5537a8f54903abac4c58743089d1abc816a578be5c678928cdb88673ab4c32513858c650842e029a8340a5eb27bec6227ff58786d66302bd1c990f596beb27384ea01e67a348ee96149309f8fc4c6a7d6b5e982469bc8518f34aebcecc5028896c2d2229c2568c1f3cc423454384c7f612061b98764facca9b62a8ee7c280f8d2230c607b6d57cd9324101bd0307c090da2d5231b3bbd99d420b831c622f8cfed0686fbc7a8c9c8655c07453653366c5c0a08c1bc8f16921485c6f7729a82752d5ad663f1784e9e97bea2cc24ad163bf6999fbbe57e93b8986d95cabb7700fc8661c62b963b073596626687d3c0ac809d01d5c8a4100f19f8345aa58d6aab1ca154482f8c6d43e8b2b43bab8e159481d89d916948c3556911594268cc7811c4116aeabe9b753a00c37509b5021e5185370003d0ca56a137b5d626339ef09a1d8b184a0697625964420c91f2e212ddc9764d804664481842684b6c1c728eb793b9812c08490fe4c731cb06b3a077939b83aa96965153611fe3c72e8558b7d74b31cfbabd86db1c91e408d4253942c2671ed91023a96cbc63661555bc20555a39ca49eff98c2a24901854c1b07a8fb6f7c5bc6a22033a8b03592661b485804114ba2708307c538a16c24db5abb7a1be616428bc464b4867a54dd47fcee6708e8badd55cbb00e142e5a68bcc4b6874eb3705931e17558fd975ad8658aa034505092257044207b95b7b786164e589a2a100269ee136538cba5b866c5358838b9608e41b6e5970037c054503c24db69b636edb4300e9ac1b4482132109c9bc9528c17471632e01755e00712bd37518eabc61f0579b1bf511093a85417b3e96a236b0d035ea810b9bd81dbab6bd946275aa6c98562b5e408443b2921cb0c705bc99703345c39f55bef13a7d9f216598fbbacc5548d6408c2fc664d72344480a58fe30358966363fb05612d20c217b6e8d128d223c07f3ac23d0a12bb135bad01a29ec40984b1234382cae377373827b582e0499f3958ee1194d162a67d005b592d8319e04749a67bf47696fc7e1cf5911aea743c3933e833beacea8409a36bb85110ea316c71d89c9d59cfed2a7373d95239aabd2c097f4a045567c382944cb2d4bd2c490dd148bf501ca738083229873a5eee55efe07eb830b6fc1c964481252595e17a3fdd274c8eea80e720ff035545c056c2a",
-    "server_login_state": "45dec53edb0766b57dad858459bb2c6ae6e1fb3ade8d466323214018a258874f246deb7de77fb15068d6ae2a9afefc0f4b6c0d63da47e5e755a6f3febe640baeaa014fcd310b79874c65b05b2fdd9213eefefbe97f78ae4c9f456cea9d1d7f394a0735c673d02c74df29d19637f7cf54bd49d50db6cdab491734ff6dd429267f50a782adc040918712913c8731f48574a80711780da5785a9c0792976c605fd99d2f57c8e67c6126b4bec5b817194787114b073ef99221880c81483c6ea239158015f39c0499e358fb12c527a89c98f545ef7a37d9c30a2f1349d48333f87b213efc9cae126626a4badb90c20d2a56f5688d1e36b995b00f7008631b343db2429fa02a141d704af2f07c1ecb702a601645e5c81ad78e5207cfff079180fcace2e5927e97aab389824f729548e88845469c47aa32fc15c33a2c82aab50751fb1f4ed2026388531dea45a8e4c1448779bbc80a55a0b4f7642c3a9397b8b3264c825c08b1372ed123dfca13a60a4d350a933c87268c4a8202aaac9f7b001bc271cf16bc1d36a6a1e228974a119cbcbad12546ada537bc96c2ca5c594b22b29441547d404f45717274cab38ee9aa9fe79c47f01cffb1b402c1bb82c1276fd1644314a6d58b40237a3bc3cc7ebc3aa65c09c7a23230b2b631b18c4a3aeb32c8443002dbb75e3b77d33bbea87c634379c586c70720f760c079abf2782554560e9bdccda4776937244b6385653fe1415edaa5537a8f54903abac4c58743089d1abc816a578be5c678928cdb88673ab4c32513858c650842e029a8340a5eb27bec6227ff58786d66302bd1c990f596beb27384ea01e67a348ee96149309f8fc4c6a7d6b5e982469bc8518f34aebcecc5028896c2d2229c2568c1f3cc423454384c7f612061b98764facca9b62a8ee7c280f8d2230c607b6d57cd9324101bd0307c090da2d5231b3bbd99d420b831c622f8cfed0686fbc7a8c9c8655c07453653366c5c0a08c1bc8f16921485c6f7729a82752d5ad663f1784e9e97bea2cc24ad163bf6999fbbe57e93b8986d95cabb7700fc8661c62b963b073596626687d3c0ac809d01d5c8a4100f19f8345aa58d6aab1ca154482f8c6d43e8b2b43bab8e159481d89d916948c3556911594268cc7811c4116aeabe9b753a00c37509b5021e5185370003d0ca56a137b5d626339ef09a1d8b184a0697625964420c91f2e212ddc9764d804664481842684b6c1c728eb793b9812c08490fe4c731cb06b3a077939b83aa96965153611fe3c72e8558b7d74b31cfbabd86db1c91e408d4253942c2671ed91023a96cbc63661555bc20555a39ca49eff98c2a24901854c1b07a8fb6f7c5bc6a22033a8b03592661b485804114ba2708307c538a16c24db5abb7a1be616428bc464b4867a54dd47fcee6708e8badd55cbb00e142e5a68bcc4b6874eb3705931e17558fd975ad8658aa034505092257044207b95b7b786164e589a2a100269ee136538cba5b866c5358838b9608e41b6e5970037c054503c24db69b636edb4300e9ac1b4482132109c9bc9528c17471632e01755e00712bd37518eabc61f0579b1bf511093a85417b3e96a236b0d035ea810b9bd81dbab6bd946275aa6c98562b5e408443b2921cb0c705bc99703345c39f55bef13a7d9f216598fbbacc5548d6408c2fc664d72344480a58fe30358966363fb05612d20c217b6e8d128d223c07f3ac23d0a12bb135bad01a29ec40984b1234382cae377373827b582e0499f3958ee1194d162a67d005b592d8319e04749a67bf47696fc7e1cf5911aea743c3933e833beacea8409a36bb85110ea316c71d89c9d59cfed2a7373d95239aabd206a0cbd423f75c3d42e8eb376265d9f3c12147b4cd42ca47d63d968aa41e6ecd57ec6f888fe569692024558472f8606f54aa782892204aae9fb8bd531151a624e723213fff42abc51178bd4bf8737b3d8b36b7df491813e3aa37601622f7c782a016a61549d217983e8ac2a376deb4d2475decae28081992c1597e2d749c2085629e26489c1cf662ea7e12844ee8dcc58bc1c296ddeebafa5f4b5cea9b643a890d888857f3a6ae6fcf7be3a7b7db7a4dd4b227bb60a2562c3e4cbd8d62e6d3bcb7b4cdba077d469107edc05a063383c6d9914f86b7c2c936d0739afb058a8c7d3e460db21844cde960a85671cc9b021588070bfba64e3798e6dd85598a9d6f4ec23d7efede1895d2ab9e95f99cc658cc0f6ca1cdb2c2dd64f17f4129d1283446da25e4fa4cd5e541bd6d50b3d43d706c86f5f3a07689127d40163e8588bd8611db30faa93c4c32528498b0a53f44581febe146c8b0b91bd56c945ebd9b811bdbc2f9cd74378f9b35bc8390a1eee1674167d29841df51b57d1ce8f1cd1fe2ba370c443900aaf6c0c99fc9e71a00c106978e501b920b9b71e80843a464676f1a5f24791299e688efef3746265ec3a11ba9347c4be99f8c1a23a76dc2f3f3a9564067827bf3372e7a080a01971c20f65dfd9850bec0beda9637649bace8c4f66331684bf5fae0abbf1ad18433ae7f80598d594aef59519af14dc1ba1bb29ac3894c2efd4a3edb1044b323bec3acc706f4fe6a940d99217cf97925a37170b10bc89a3e06df5d3c23ee5e3fa1fc2ad6b303d24854c4a6174ae01df45fe0d0d913d01c799034b3973f1a40294a44fd2bf6a5bc90d3292779b97f2039d1ec6731c41e115a53f94a6385c8edce899f09a6f40cd61f51ac78d7a9c2467476b115f9bc1081362aaf3cf9a8b65302c7f1ca2f8f2f70dd7a2e5efd8b8163ec360b53c87283b1b2097585c78a35b90fbb85b4176a50f56250fc42bc7a1451e97b661ff4853d9fe40f927ccd4e3d50e1253333b68912e040c338284a91d7e7044f6a5fd0a6b0806a94281b87a565f377221d54407a05d5d1bf1103805bb0d7b85186bb60206a5024d7ba48b138091fc74cc9dee261064d6f9f2ae831ee067f8b2238440312084dbcc9647c41b3dd557c4b6526702894970ab543846f90a53ed3c4bbfe5c52448df3dede3621978168a79c63c19674655b80f8d4c082a851d172ba16a5c3ad47d542083ba91342267594b055353954fa251b236e2a336b3ee68719f0af7371ec0d51554a8a0259fce59fae1144efe74f26c7eb8e82735204c5030f0cbdac45175b09fbbcd9df8dbb31eb9839759cb2b82508ac45dfb37616d904a1fd1b881e29ba87cd55f67e7d8529ddbcc1982dfd942e87c8cd6a89b51b8c8a17de40fa7ac744e975113573d11bebd24705ab4c4591e7f234a1c4e48a175489250829c8c4044d31f7845d2d2f7a448bf21544a275ec96f2435feafa6a74ab2863e1ab3376e64e1c75fe81200be47de5836d7d9bc6446d40743d249cf5f7927866ffcb3d266b63",
+    "server_login_state": "45dec53edb0766b57dad858459bb2c6ae6e1fb3ade8d466323214018a258874f246deb7de77fb15068d6ae2a9afefc0f4b6c0d63da47e5e755a6f3febe640bae485e9c4e0bb0070f5f0f9f074c68af6490e78c12fab970c7b42efaed6b8a02e148efb51942502b9029dbfa2e5a39faa70776280344f61cd6af080220d59e0455aa014fcd310b79874c65b05b2fdd9213eefefbe97f78ae4c9f456cea9d1d7f394a0735c673d02c74df29d19637f7cf54bd49d50db6cdab491734ff6dd429267f50a782adc040918712913c8731f48574a80711780da5785a9c0792976c605fd99d2f57c8e67c6126b4bec5b817194787114b073ef99221880c81483c6ea239158015f39c0499e358fb12c527a89c98f545ef7a37d9c30a2f1349d48333f87b213efc9cae126626a4badb90c20d2a56f5688d1e36b995b00f7008631b343db2429fa02a141d704af2f07c1ecb702a601645e5c81ad78e5207cfff079180fcace2e5927e97aab389824f729548e88845469c47aa32fc15c33a2c82aab50751fb1f4ed2026388531dea45a8e4c1448779bbc80a55a0b4f7642c3a9397b8b3264c825c08b1372ed123dfca13a60a4d350a933c87268c4a8202aaac9f7b001bc271cf16bc1d36a6a1e228974a119cbcbad12546ada537bc96c2ca5c594b22b29441547d404f45717274cab38ee9aa9fe79c47f01cffb1b402c1bb82c1276fd1644314a6d58b40237a3bc3cc7ebc3aa65c09c7a23230b2b631b18c4a3aeb32c8443002dbb75e3b77d33bbea87c634379c586c70720f760c079abf2782554560e9bdccda4776937244b6385653fe1415edaa5537a8f54903abac4c58743089d1abc816a578be5c678928cdb88673ab4c32513858c650842e029a8340a5eb27bec6227ff58786d66302bd1c990f596beb27384ea01e67a348ee96149309f8fc4c6a7d6b5e982469bc8518f34aebcecc5028896c2d2229c2568c1f3cc423454384c7f612061b98764facca9b62a8ee7c280f8d2230c607b6d57cd9324101bd0307c090da2d5231b3bbd99d420b831c622f8cfed0686fbc7a8c9c8655c07453653366c5c0a08c1bc8f16921485c6f7729a82752d5ad663f1784e9e97bea2cc24ad163bf6999fbbe57e93b8986d95cabb7700fc8661c62b963b073596626687d3c0ac809d01d5c8a4100f19f8345aa58d6aab1ca154482f8c6d43e8b2b43bab8e159481d89d916948c3556911594268cc7811c4116aeabe9b753a00c37509b5021e5185370003d0ca56a137b5d626339ef09a1d8b184a0697625964420c91f2e212ddc9764d804664481842684b6c1c728eb793b9812c08490fe4c731cb06b3a077939b83aa96965153611fe3c72e8558b7d74b31cfbabd86db1c91e408d4253942c2671ed91023a96cbc63661555bc20555a39ca49eff98c2a24901854c1b07a8fb6f7c5bc6a22033a8b03592661b485804114ba2708307c538a16c24db5abb7a1be616428bc464b4867a54dd47fcee6708e8badd55cbb00e142e5a68bcc4b6874eb3705931e17558fd975ad8658aa034505092257044207b95b7b786164e589a2a100269ee136538cba5b866c5358838b9608e41b6e5970037c054503c24db69b636edb4300e9ac1b4482132109c9bc9528c17471632e01755e00712bd37518eabc61f0579b1bf511093a85417b3e96a236b0d035ea810b9bd81dbab6bd946275aa6c98562b5e408443b2921cb0c705bc99703345c39f55bef13a7d9f216598fbbacc5548d6408c2fc664d72344480a58fe30358966363fb05612d20c217b6e8d128d223c07f3ac23d0a12bb135bad01a29ec40984b1234382cae377373827b582e0499f3958ee1194d162a67d005b592d8319e04749a67bf47696fc7e1cf5911aea743c3933e833beacea8409a36bb85110ea316c71d89c9d59cfed2a7373d95239aabd206a0cbd423f75c3d42e8eb376265d9f3c12147b4cd42ca47d63d968aa41e6ecd57ec6f888fe569692024558472f8606f54aa782892204aae9fb8bd531151a624e723213fff42abc51178bd4bf8737b3d8b36b7df491813e3aa37601622f7c782a016a61549d217983e8ac2a376deb4d2475decae28081992c1597e2d749c2085629e26489c1cf662ea7e12844ee8dcc58bc1c296ddeebafa5f4b5cea9b643a890d888857f3a6ae6fcf7be3a7b7db7a4dd4b227bb60a2562c3e4cbd8d62e6d3bcb7b4cdba077d469107edc05a063383c6d9914f86b7c2c936d0739afb058a8c7d3e460db21844cde960a85671cc9b021588070bfba64e3798e6dd85598a9d6f4ec23d7efede1895d2ab9e95f99cc658cc0f6ca1cdb2c2dd64f17f4129d1283446da25e4fa4cd5e541bd6d50b3d43d706c86f5f3a07689127d40163e8588bd8611db30faa93c4c32528498b0a53f44581febe146c8b0b91bd56c945ebd9b811bdbc2f9cd74378f9b35bc8390a1eee1674167d29841df51b57d1ce8f1cd1fe2ba370c443900aaf6c0c99fc9e71a00c106978e501b920b9b71e80843a464676f1a5f24791299e688efef3746265ec3a11ba9347c4be99f8c1a23a76dc2f3f3a9564067827bf3372e7a080a01971c20f65dfd9850bec0beda9637649bace8c4f66331684bf5fae0abbf1ad18433ae7f80598d594aef59519af14dc1ba1bb29ac3894c2efd4a3edb1044b323bec3acc706f4fe6a940d99217cf97925a37170b10bc89a3e06df5d3c23ee5e3fa1fc2ad6b303d24854c4a6174ae01df45fe0d0d913d01c799034b3973f1a40294a44fd2bf6a5bc90d3292779b97f2039d1ec6731c41e115a53f94a6385c8edce899f09a6f40cd61f51ac78d7a9c2467476b115f9bc1081362aaf3cf9a8b65302c7f1ca2f8f2f70dd7a2e5efd8b8163ec360b53c87283b1b2097585c78a35b90fbb85b4176a50f56250fc42bc7a1451e97b661ff4853d9fe40f927ccd4e3d50e1253333b68912e040c338284a91d7e7044f6a5fd0a6b0806a94281b87a565f377221d54407a05d5d1bf1103805bb0d7b85186bb60206a5024d7ba48b138091fc74cc9dee261064d6f9f2ae831ee067f8b2238440312084dbcc9647c41b3dd557c4b6526702894970ab543846f90a53ed3c4bbfe5c52448df3dede3621978168a79c63c19674655b80f8d4c082a851d172ba16a5c3ad47d542083ba91342267594b055353954fa251b236e2a336b3ee68719f0af7371ec0d51554a8a0259fce59fae1144efe74f26c7eb8e82735204c5030f0cbdac45175b09fbbcd9df8dbb31eb9839759cb2b82508ac45dfb37616d904a1fd1b881e29ba87cd55f67e7d8529ddbcc1982dfd942e87c8cd6a89b51b8c8a17de40fa7ac744e975113573d11bebd24705ab4c4591e7f234a1c4e48a175489250829c8c4044d31f7845d2d2f7a448bf21544a275ec96f2435feafa6a74ab2863e1ab3376e64e1c75fe81200be47de5836d7d9bc6446d40743d249cf5f7927866ffcb3d266b63",
     "password_file": "78a92cf7d33838b10a2066d6e26226930ef7814b962b7a04e9a2348f34754d44caaa49418d001ab4727288be44e8fc834ced9ccb4598b8180306295ba6ad41de1bc89d4c31f3910ab398928cb73c8c24048169ec6a6379a4d400addd745856736c6562fbd0dd0b74ae36123914d923e6aeea1de70fe715f2c88ad5e950a3dc0f5e9b99bc244e39cf575ecb0465e74b7596dc082b6f738025ebce9908094635243ca4fdb8b730d9d1ffd67c34f2930ff333c4bbd078109e2ec0b3a135a4e3be28",
     "export_key": "76717386597491f4bc35bb18329ffcd593677e0add6b4e37b85cde7c7d9410f8da105d3eaea4e93a644c6c44650261bd80066e196f7a74d96a275e751c1130bd",
     "session_key": "45dec53edb0766b57dad858459bb2c6ae6e1fb3ade8d466323214018a258874f246deb7de77fb15068d6ae2a9afefc0f4b6c0d63da47e5e755a6f3febe640bae"
@@ -999,7 +1160,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_P256: &str = r#"
     "credential_finalization": "ef9cb088ba3039bdaee6887e31197a1d3e033644f18542676515696792e5d9b8",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026f99832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420bf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffcf7672f280b24c1c009b4930a8871b60abb164b59f93b094647c5e1c57a47ce0c11de93c58c322572b43c926a3b6c0b961625c7cd7c1e2f0caed53a781a8cd37842a5f7812a5f276d186821dc26540d40d4d7c99ffe4179d4b13a412653570054ce562730aacd8c3b93312c760d4b79c61c31fcc0fdaa3268f6084f3e979e1c90f335aa0b1d558b3a32b94f2a38fd9a2c530c449aaa77695461c1897a12b5a95ea4611eba76851a33f7822a209aae1230f255585733a01b5ba4d2283881e94c95ebab2e2b063016caeb71a6d0474b8e15592e175c829a246651765170b299006045a2acbf642aa3f235b9c2a39ca5a80bd6a9114764ee139b84d3b2686b542b985892d179b9c705f2a43c54ce708129323b9b450dd4602662956f925322da18a5154230722a43b95273a05a3cb8b1b69bc776d29c6afa8aa197a181efb9672f2cba9b24653349d3f88946a7c2228c68ee329763346230ba9ab5398867f22870f7697b20a3f56d8796aa8506dbb3d69295b866b2033013106f2961aba89b3dc4ab43a657146cf6517611d7226239742195b5100065bbcdb2a79127a38d20ddef447b45ca5ada9c4d5e0a19aeb32d2b4485091146e209916804886c975f47b1a99408b824c22ffc959cd5710fd8c841bfb5fe7c437ce232f345286e5cb778dd51c9fd49c724c924c951803964394d5c3b45a6bb2a23610bbb1560cb438124945c3845fbc8464d98f23d92e696b207900bc24d003f5676adacb4bfd7664060481ab8896be52738ed3a55d19206336c43993c43892955b42783c3840a989c546c421e71baf3c66a26629a21fa73e5e78093603c9c90329a54c1c3e2c4eedd08096c29b7650c214d23d7e03932f99aefe367640f238a106994e662f2aea1785045bef0075d4889da2bcc6b043168b3845574c51dfa60d2ed28900b0526511122832021ffa68b7c450a1089c3bda40ce986d492b7da2baa0bb31737d2cc0127353f6a5c85281442fcb4965f3cafef30463805a4955509ad2c63bea6487c42152442204bc2bd750a0e5f85a6ccb60af1a10c3b44700b65db2d0ad1d6601b5e9b505e7a83cc773ff354d6f87c2ce774fda4263c88b58b116857dc4821077960f9767d180c9c8f7b1e6280f5cf52fa6139428ecb2c796a498a55392c4a4a053b13534cab66973270ad0a41b42415896c4d292616b27cbc1756307c2ff38850b8929fd0a11cd4741344852ac96b325496309b3bfd9316ec81c7418a71843a8709341478f35187b38b5f4f38a5637278513c78bfc179cb4c4b0608fa0b6a5bfc0ba4777b62914848a3388c918056c2929e4c2034fc9719b8b471c341852436fed848e1df20a87b96add836a88a4ac86586b290abc560c71e8954102662e525258d7b76ceef0916bc59a56f909fb9b59a6494636a79f0d60cc7e047159d03f32987c1eebc39993c10ac43d664339a5d4601d33438ba5b31fd128ee5499caa7773c675396f46196994e5c29a5fd4941cde2bd7929690269602d5a3ed92b73766369fd526a0315acab08c97f49b461033338071e05cc3ccc704b74009755409f2479578c498112452c950375c9508c06e3478c4476371346d546744ccc908e6192efd68e8a67cd99832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420253ceba52f73dc442f96d810d8c3e9a6689c649661627060cf9e44657aa792bf7c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
-    "server_login_state": "9d7770fcb3500221789235c345dd807a7b90eb731cdfbbf7836b5b9c353641a8ef9cb088ba3039bdaee6887e31197a1d3e033644f18542676515696792e5d9b899832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420ae7c1a3768d3677d2efc4f5d4a87c815cbba26e2e8ebade34a6438f3d08cdcc51b3f6337f3eae77fcfc0bf7ec43be7c5529cc6e4d42fb0c8f14f3de7c2a799cebc535aaa57ce99d62e8ed634a26b71b11e3c7a9b8a893b3a474943c352105133cedef3f152743621f7c40f68207d68975d90491342fe8be3eb68841bbfabaca51d21798ef50389a569fb16c65af61e0f34d7d03f5daa873a56cda2c81432e101385a8695dcf2312505bd1b704bec5d9c3543580c8b5229affab327d6e36f39aa9536262f2bef0e10fe2bfda7a5513865e22d7fd7e87147eeb1cc4bcfafd2073797f66d0edae73b6192b70beae90751f1e39df1f7b05f70e3189697492ff16728828ae51c9f65727e153e3c842227f7501a1f65c3bd109ec0c67ca0a089d628bfe0d77e2c4c863c9d6e55c831459786ac5ea3b18abf9d425d83d6c04a30ec69d5640864f237f29f8c2edf15ee9a70ed029398579da3cd2fe99672ad74645d262c0ade4bdf7e56fe65cad3d693c44ea9e5cc3ff16ccafccf30f685dfdeb406445ee56529b40833987e78c9149e23260af76fe6efc932abb69af42ae4eaea079a7577f2efdfc1e83e7b910a17335cfe1f10020ecf66cec08bc3242b4e192a55bda9686c09c83f710e93a0d60e7dbdccaf6e5192e5d5d4beef621ddb5676fc4bf8df1b268b97a64fcf4e221a0155751d7c977521a5e48bb0d638678acf990a728f0cebf9715a9f63e437b081e0d0cb49098ab8d6166e6ca37063084a10a53196e12509b673f7266bc4e2b5699231cd5202c07b07ac1be0adf0e4d472e6f028b5228edee1b5f92eaceb8dde96e0f6483ddfed704b58f7f9ef133315e8b1f3bab8593b3c945fc940fd74318636e61fd3fc623e353fc6136ad80a13168acc4458523a856bf561b5857c77ccb13dbd5f3aeb0afeefc14047e0c2b98aa999473d5a678a5e7bea90ad6b4c893d4d819b659d4ec10a984e41635879a720f305c170275d2437da2f5a95636e5991e7d83725e0598bb4556e898f0383e035f06a7991365bb6f6326a260ed1602fe780082056d49534ce16430314c904db54bfbe2fb2d9ebdc7cbb3ac91b7f8a14d45e688e90e2521c54bba31160096801571eb290d700e770aa32c4afa25d3160910adb3aab4b2a71b12b3a9516e59da1d2ab681d84247358a4910f9396944e030013dd2f5b47dfeeef8f17fe6b8dde5c9b8107694a565155388ffe00eb0e7a7212da0fa1cd663030bd9c5d7c270aae4b0aa6c82f9d85c1ad493e65948c3f318c000952c0d05ee0f66c6a83f9e31f2507b019ae8cafa49766413460017f2f29b15e1f2dfb48dc5c5803c2f22b88e7dd999251cd32fb549b53b7fcbc412fe2cac33e653a1dfc7c9e0a98e358de263958fe9ea369f86126388bd5d6b09083b5831024c5fc3579a6cf61ee0d801f9d9405bc3adadcddb2b2e41f55e8576e8175407f49dc0089b46220a307eb4a691fe7b8ae22f91715f15b94111900374d535b53f77ed32dbc83b00a2967edc97cd3f0481f0b0521b1afaa1bbaf9",
+    "server_login_state": "9d7770fcb3500221789235c345dd807a7b90eb731cdfbbf7836b5b9c353641a88edce1ea847cc8d4c08aa2cbae23eeacdd2a790586fdaf53c58e6ff6ad8f54fcef9cb088ba3039bdaee6887e31197a1d3e033644f18542676515696792e5d9b899832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420ae7c1a3768d3677d2efc4f5d4a87c815cbba26e2e8ebade34a6438f3d08cdcc51b3f6337f3eae77fcfc0bf7ec43be7c5529cc6e4d42fb0c8f14f3de7c2a799cebc535aaa57ce99d62e8ed634a26b71b11e3c7a9b8a893b3a474943c352105133cedef3f152743621f7c40f68207d68975d90491342fe8be3eb68841bbfabaca51d21798ef50389a569fb16c65af61e0f34d7d03f5daa873a56cda2c81432e101385a8695dcf2312505bd1b704bec5d9c3543580c8b5229affab327d6e36f39aa9536262f2bef0e10fe2bfda7a5513865e22d7fd7e87147eeb1cc4bcfafd2073797f66d0edae73b6192b70beae90751f1e39df1f7b05f70e3189697492ff16728828ae51c9f65727e153e3c842227f7501a1f65c3bd109ec0c67ca0a089d628bfe0d77e2c4c863c9d6e55c831459786ac5ea3b18abf9d425d83d6c04a30ec69d5640864f237f29f8c2edf15ee9a70ed029398579da3cd2fe99672ad74645d262c0ade4bdf7e56fe65cad3d693c44ea9e5cc3ff16ccafccf30f685dfdeb406445ee56529b40833987e78c9149e23260af76fe6efc932abb69af42ae4eaea079a7577f2efdfc1e83e7b910a17335cfe1f10020ecf66cec08bc3242b4e192a55bda9686c09c83f710e93a0d60e7dbdccaf6e5192e5d5d4beef621ddb5676fc4bf8df1b268b97a64fcf4e221a0155751d7c977521a5e48bb0d638678acf990a728f0cebf9715a9f63e437b081e0d0cb49098ab8d6166e6ca37063084a10a53196e12509b673f7266bc4e2b5699231cd5202c07b07ac1be0adf0e4d472e6f028b5228edee1b5f92eaceb8dde96e0f6483ddfed704b58f7f9ef133315e8b1f3bab8593b3c945fc940fd74318636e61fd3fc623e353fc6136ad80a13168acc4458523a856bf561b5857c77ccb13dbd5f3aeb0afeefc14047e0c2b98aa999473d5a678a5e7bea90ad6b4c893d4d819b659d4ec10a984e41635879a720f305c170275d2437da2f5a95636e5991e7d83725e0598bb4556e898f0383e035f06a7991365bb6f6326a260ed1602fe780082056d49534ce16430314c904db54bfbe2fb2d9ebdc7cbb3ac91b7f8a14d45e688e90e2521c54bba31160096801571eb290d700e770aa32c4afa25d3160910adb3aab4b2a71b12b3a9516e59da1d2ab681d84247358a4910f9396944e030013dd2f5b47dfeeef8f17fe6b8dde5c9b8107694a565155388ffe00eb0e7a7212da0fa1cd663030bd9c5d7c270aae4b0aa6c82f9d85c1ad493e65948c3f318c000952c0d05ee0f66c6a83f9e31f2507b019ae8cafa49766413460017f2f29b15e1f2dfb48dc5c5803c2f22b88e7dd999251cd32fb549b53b7fcbc412fe2cac33e653a1dfc7c9e0a98e358de263958fe9ea369f86126388bd5d6b09083b5831024c5fc3579a6cf61ee0d801f9d9405bc3adadcddb2b2e41f55e8576e8175407f49dc0089b46220a307eb4a691fe7b8ae22f91715f15b94111900374d535b53f77ed32dbc83b00a2967edc97cd3f0481f0b0521b1afaa1bbaf9",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "9d7770fcb3500221789235c345dd807a7b90eb731cdfbbf7836b5b9c353641a8"
@@ -1038,7 +1199,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_KEM_P256: &str = r#"
     "credential_finalization": "1d32efd27564880782baf5983607fcf3bc1620a20834d2158e9a60d1e532353c",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026f238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2bf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ee3b2a983a47952f1763d43378f71882951ac98c8b2420833a070b01f38634bdab41bbbaf2cfcce824a9ee521223835796bbbbc9bd443e195396e9348da3b10ca213bbef380bad8a55cc571c6f734eaf5adb3529f76c6057d9b1a7d01956ea810e4240d57c457923b4cc72054a9898d6dd9385cd51da69104461c5ad2a65264c52e368c6d28820a91a640019714d9b5255f0755f2b80475db54d495650684cdbc544fe9083b8ec00e46197c8aca84c3db434ef5780e608a39db1b48c888868cb7824ac666c5445c264af06b63db3728d15784401a9f4053819051c145472923e9a3ace13dc31bc0922a88dca846924c0a4e1501c21a51a078b6ea1c822a982d042aa3239339eb9c2f3bd08e1e63288efc5d3b039af2dc3fecf2b343abc3bdc4bfa0cc0ec87062361457b20014e27b42968959653b4ccce7b631321047f8849208b526560aecc4acad065b3b086bd7962c7e080fd40305e2c339befbb986119e13d5218a307c4f0905b63861f40b3f9db6b4a6ac0a469aa20e804e31182abef1b530f86d89182e8a51486c507bbdfbb395f0a4e81357c1a3c5ed37c8b4aa65c7388803062eed63b19af2b89eb75d3d08b86f2914292bb888d8505d196d283b90bfa56a5cdba7cf41a815e3318f54465bc53596dc5f9874c248c266f20640421570580989713681f4c0057197b79fd51e6d5686e3d2a9d35875458a47d9059fa4ea5c0e2c5b2c0ca1f104909f41279675630ae75111f728f8260683c45bc8e5c6195b69c9d1ccccc3b9ec14aee035aee3c12dbf1cc80dd33cca3a3fe98c49c7061e887a0af5b26a69610245871a942a868c768aaa37c665a50698887f2790583c8a28fcf48d3e299d381731eefb65fee790ddf9c36ce6a61ae60b35c718c530696b3081da31b31d6575629216bf7744f5a82d7ce824df449852d84d395bb29263cef6b4a57678a5dbecc4acd6b58d30290d39ad72b63204fc6fd38cb149a2c2cf78a82a132c81cbcb16525c3a96bc2047c7c058b9144a023e8453033b1e5ec54e5516254f655326915bb60b1fd48829b5d82329a98656f8a22ec252fd7847d14073b24abdee277e2444ad8dd1a601b24ada9abeb86abda9737b5c266b73869baeb5ae28b246a7c11f1604023718cf6ccc87057168c569b130f3a65a4268b678289b404995d320f7ca4d2cb22f0a4cc5c27c709a36acd879074230c515aa98338031ad7ac920eb2cb19a0a8cd31a4f13b925a933ea1900c1f0831a3a2850b82877d742d6144c81c2ac811ac5d060790226bf2409d0a12c9276eab7553c6a0e4124b0951b6600718296126ac21f2d138c29a04ae71204bd4c66b535ac99ac3debe53159212a749b722782bf9df8139a16233d89a044875312b8ade1855fde7b5d0177204921289fbb0c0ac19cbf4ac8d4603fbbb28492c2c125e9012a2cac330c91227a72312184453b0b6680692fa80a4f553be8b8118890c616120c1d583d0fb5731cab2fb1d4abbaa8627e415ca4cb9bd1b4693e4537074c152700a85aa529675ca76eba4978a4230c525800e2785c20992dc75233d198a4aa6f0af67b22dc95c7c5460a563c204533b653876195c176a563e357640b082a63e4b7fb43ac11957b238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2da2b22bbb9275070fd5a3be2b4c4b3ce48f3ad55cbe0ce3710f8f174e4ba72737c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
-    "server_login_state": "97e11e27f937bba60443c4061fc56d474e9439422b637b1d5701b950298f94181d32efd27564880782baf5983607fcf3bc1620a20834d2158e9a60d1e532353c238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2b2bf35ea47341671262e96a73bd7eaa52ee208dc28428e3fef8f62364be33dd9fbd6c65e9e4170882feb9a0ebf48db239cdabc0c247b2827d68f56c3443e30b82ecef30fb33e3ce495bd6b65bdc29d3ca2df061f40b27d27c7c9be391e8fdc4e57ddc10174e2e593d9b9771c88fa7b71f475b57e62da293d3f950e11b80cbf52be703af32b071dbed03b2f432ea71c12dcf93852e6b1ce7841fe6a1e754bf57af4577a05e980269b066a78b49dc279427885e367075765e5abc8a33721b2943fe041461c3692d3da64824a52bb59a03cbf15042be35382de45d6eaf624c874268613d184d08caa3dead079d49098498aa449c13663bb900c437fcb47755f8b2c6069cbbcbc67b37402ced1d86cef06450b17e1701a8907271a77bab655e351ff0358c0a7d38f85f8d33b7f6fdd02976959f039d192f575ea1911df19adb17efbdea3b292cc81ef1bf1b3fb2b53c40cca00a0aea54e70f0f627694fa2dd9dadcd1f3aa5d3c94558e2a73b11dfa9601c3e1a40398daab2c4916447adfa1ee56ceca110c29eb14e343574e9be4dda6f857f59d19f4dbc4f79d11689c1377b5896c2503c42204fdbe5681457e614d78cfa5393d48468491bcbd01f920b88088953c269a68827f3ffd87355494b5268fdfb08065d948006bea90d116fb58a4d272294fa1c93e400f19a9870c16505eb42b5cc67cd1cc80c786b287f441cc0594b763faa1e2b1ad52df54713a095914bde3f8be40e98cc5669d2e050ff23665c305bd9cc95e2cf14145e44d4f7014619468fad1000fef399ed3a0460f5bae59550dc66f02eab4d509338a7b286cf59dd1d391b9fe12252921086854203575239f0738c0b18a20e4bdc61d9eceb7128431523d98d0120577009c4b713b903e40d9a79506403b64e47e25aff596ef1c812abf449364478d3b2be3703991f4f1fefb31c7208aff0c284ae5a235b51c9f227902effde54dc65c31d9d06b532f3e514354c4ce3828e045dd2457d9457379d654edb54def5c8ee275294b2a6be58ac6b82e950864a5be9c990c7314dc6009a13efcf91f4f01e1baf4542e6570cb25e65a18a0d9f163a028686dbb1710d0f7191d4aebf6a3d6a2a810fec156fd28b4d0d4667e74912cbae62b79721d0c1634bb98b6a50287ecab95608716afc75f5e7589010b0fefc7650e759e35faf3624dedde8150f3c730f8803a01ba4aabba2dc9de4b320aaa6a815bc4be1aa41a01ca44fd98adf2fb6e7cb266ae52c93f08b79789b1d0f0703aac04e2bef3953f7fd06cfc5ef1ec17fda547a6d59b66216c899288ca329801e46f4b81c7c74483c076367acc636bc2b204798a107ba03a108856656a09f9158fc661a3468f6e171014f7034f7bbcd446977c314da7a6eb0202d1e0d9cd92ce88a03b84f6fd4109b806f9aab4bdebec429147b81c052d39f4d5037dbd0cbd61709f146bb178fd8372b4dea12c7a3b5f95522e5ca909323ec03f3067e79ea8ed9f464f7707a7444483439eabe27c366e5cfa0fd31c4bce694f89a71208a44",
+    "server_login_state": "97e11e27f937bba60443c4061fc56d474e9439422b637b1d5701b950298f9418aca652ca3f9ed40f92353451064576c1663ab328b68544cfe13dff9e45f4c1271d32efd27564880782baf5983607fcf3bc1620a20834d2158e9a60d1e532353c238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2b2bf35ea47341671262e96a73bd7eaa52ee208dc28428e3fef8f62364be33dd9fbd6c65e9e4170882feb9a0ebf48db239cdabc0c247b2827d68f56c3443e30b82ecef30fb33e3ce495bd6b65bdc29d3ca2df061f40b27d27c7c9be391e8fdc4e57ddc10174e2e593d9b9771c88fa7b71f475b57e62da293d3f950e11b80cbf52be703af32b071dbed03b2f432ea71c12dcf93852e6b1ce7841fe6a1e754bf57af4577a05e980269b066a78b49dc279427885e367075765e5abc8a33721b2943fe041461c3692d3da64824a52bb59a03cbf15042be35382de45d6eaf624c874268613d184d08caa3dead079d49098498aa449c13663bb900c437fcb47755f8b2c6069cbbcbc67b37402ced1d86cef06450b17e1701a8907271a77bab655e351ff0358c0a7d38f85f8d33b7f6fdd02976959f039d192f575ea1911df19adb17efbdea3b292cc81ef1bf1b3fb2b53c40cca00a0aea54e70f0f627694fa2dd9dadcd1f3aa5d3c94558e2a73b11dfa9601c3e1a40398daab2c4916447adfa1ee56ceca110c29eb14e343574e9be4dda6f857f59d19f4dbc4f79d11689c1377b5896c2503c42204fdbe5681457e614d78cfa5393d48468491bcbd01f920b88088953c269a68827f3ffd87355494b5268fdfb08065d948006bea90d116fb58a4d272294fa1c93e400f19a9870c16505eb42b5cc67cd1cc80c786b287f441cc0594b763faa1e2b1ad52df54713a095914bde3f8be40e98cc5669d2e050ff23665c305bd9cc95e2cf14145e44d4f7014619468fad1000fef399ed3a0460f5bae59550dc66f02eab4d509338a7b286cf59dd1d391b9fe12252921086854203575239f0738c0b18a20e4bdc61d9eceb7128431523d98d0120577009c4b713b903e40d9a79506403b64e47e25aff596ef1c812abf449364478d3b2be3703991f4f1fefb31c7208aff0c284ae5a235b51c9f227902effde54dc65c31d9d06b532f3e514354c4ce3828e045dd2457d9457379d654edb54def5c8ee275294b2a6be58ac6b82e950864a5be9c990c7314dc6009a13efcf91f4f01e1baf4542e6570cb25e65a18a0d9f163a028686dbb1710d0f7191d4aebf6a3d6a2a810fec156fd28b4d0d4667e74912cbae62b79721d0c1634bb98b6a50287ecab95608716afc75f5e7589010b0fefc7650e759e35faf3624dedde8150f3c730f8803a01ba4aabba2dc9de4b320aaa6a815bc4be1aa41a01ca44fd98adf2fb6e7cb266ae52c93f08b79789b1d0f0703aac04e2bef3953f7fd06cfc5ef1ec17fda547a6d59b66216c899288ca329801e46f4b81c7c74483c076367acc636bc2b204798a107ba03a108856656a09f9158fc661a3468f6e171014f7034f7bbcd446977c314da7a6eb0202d1e0d9cd92ce88a03b84f6fd4109b806f9aab4bdebec429147b81c052d39f4d5037dbd0cbd61709f146bb178fd8372b4dea12c7a3b5f95522e5ca909323ec03f3067e79ea8ed9f464f7707a7444483439eabe27c366e5cfa0fd31c4bce694f89a71208a44",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "97e11e27f937bba60443c4061fc56d474e9439422b637b1d5701b950298f9418"
@@ -1077,7 +1238,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_KEM_P256: &str = r#"
     "credential_finalization": "eebb945f5b4ad5255404a4ea8364e3b4c8a63dd4b274867d87f3ee9f1589d94b",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026f0df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7bf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1e234b6047353e07437a54b0b4ce1784896bfa3a52fabca52c7222f3414623f9a4fbbf7447c04aaea852adeb839539560148ca4a89a8f8c2b8582ac29f4867a6bc9135aba83027a61189b3581a18184b1802a88463ea8cd41c40f2e344707314ba01c4e320292ce440c8081cb0960832cd03800dbcf333563218084a042b0f05c2f09936847d4bd391962b6860808a1a262bc7af6f11de67b98a7a1104cf6b434b86a23f15ceb019e197790c8f0116a551f04664f72286ff4c437288ab9b5b27366c8ab7d15327b85cba340713c67baab684edeb251fcd70a000c15b90cbf41b36259f37a7f743e47c525e5436e19815d91d452cd4c8e0cc70e069b491176adcf8ac58a349f98d669a1e511aa59bdaf34a0aea104ce6ca27a2347de406ea5a26bf4924fb5a27132113768f40573016867e5161251a09e1513a79b98907864c7b3b14fd350b6d5c6dda6025b4c6a5c7841bf4407fc3c780ff7866ca89715ab18317cb67ba78198312ca3ac7218b44d45b593948a6585d2836927b27418cf38a3cf5091579279c514356b43b0014ad53f20377de1dc32c89031e8ac94cc4c3caf4513edfb6e8a634866e78a86c8c0296275c873858e985dbb505c279145741807f0c419a10345825b7a3394cda9512920f49a8cf37709b0bddc10bd1f96587843b5be795ab8642c34506b285c70788a660fc73f96198ebb16c67ad11316124aa5457c972cc8192553ea450704cb0730ba9814326b083c14a23962ced1bcdbb790985bbfe9f323e4b463a386866b546a24d06d9b7704cba29eaf437016180d2b1b406e2a4eafd87b6b4a5e811734e47bbc2be6cefeda8c9ef09b37ec9df84086e21b352e7293eb508143fbb09f142cea7515e99baa730a12c7b916c21941eddc5a6a8c32312c3a7e2c5dfec934ba33cced8206b54a1785442c11044805699f991153880628d0032acb84bc62ca2e256c4ad44459bdbb0baf5b62ce1c37561c517765947c195656ccce8fb5269981b70f0c79c44c8ebcf5c46a007177e9c62df67531ba8f23253539371466215745181c25c3c74147861190c833ecbecf02d0552cc5265a395f892a191a76c861bdf5c2966c5ba0f5f8c414555ca1c7a95f264cd56ba40a454522885e57e2b7e03428d75ba7edb44c9d183a2a94bc48f6c7bba360ac242475da225c42c1430ac5da6b3a518ba0e378700699a3cef48744589295092623647110e461a8aa39b2919fc1f257e4d49cb69a8f4933109770bf9ab7a1e54c89ed1387aee61cbef99b1348834d2c7d33837767512b4ec3c38dfc89d0129f29f171f9fac246aa7537150f62a60c6f4945360823c95c585c4a09451029baf86e1f25b6189266338c862648a746f4590022bf70d59dbe67b1a9f14362e08181d4183b3a48c867147eb453124488f94173f8e78495302290874b305659084a4cc983ce51372895e054117082914ca9fc4040d6c66ac50164e813314ac4284339031ef27c217c843022260b462e7c92204f72a5b3a48bdee23e080b7a6eab7fc92a3d34a952b209c0a671257c051249137154060c1605034d10c3004a42302715010184de80cca2d2819dbc5caed72888d38ae6eb043b663b3de1c8e6955deb8b900df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7c43e8ded31c09c3034e2803679bcb56541de9c8492c916517cc1a84a7bba3e3cd198aa66d474460a0959f960309cf1807c1a6aa69ca785116ed21e536b39b0c1",
-    "server_login_state": "31a05a1aa7ca421d6ee9c96e98f2f2fe4ab4f3c607c1eefd1d972f4268d4ce3deebb945f5b4ad5255404a4ea8364e3b4c8a63dd4b274867d87f3ee9f1589d94b0df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
+    "server_login_state": "31a05a1aa7ca421d6ee9c96e98f2f2fe4ab4f3c607c1eefd1d972f4268d4ce3d7de1cddda3a8998faea5211af13e67c3767ce4d55e954736430592cb18d196f0eebb945f5b4ad5255404a4ea8364e3b4c8a63dd4b274867d87f3ee9f1589d94b0df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "31a05a1aa7ca421d6ee9c96e98f2f2fe4ab4f3c607c1eefd1d972f4268d4ce3d"
@@ -1116,7 +1277,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_KEM_P256: &str = r#"
     "credential_finalization": "492d70f023925acce68a36411ffddace57a6a9b3932fcc2a78fc4f0de51fef50",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026f0d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3bf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff67a85f7348281ee627c11419c80c218a028d4a1a5fceba07fceaa7fcab748bc504ffe39e6f77084c00b0c606aa23a2b31de083e0e105b39a2d605248a2973ab5694f7c4833b8bc319bf71b8aba425de972b75706a1a52078ec12e38c60b9405b3d853bc1994b19bbb69bf7481fecc07b7a697a7513724c3c60f19a8e500a5672ce92f01b07315907d05890d69c85b2288355baad3a34edab60b1356e9ba47d34110fa9667b869a68c85a0e8b785169f2c9dc17c858a07c62304368e21517435939165f45162424591489c01881284858e4067bfaa146998521a22bcf120abcb3b3f2615c135358058cbbfc7a634fe06d062c137579ba3739592f018cd3c99ef0cb92d1566bc00b23883148119450fe0a9cb75525583199bb01125b584a192a4ea93a1f7c48be5f32cb2c1957de98237bc68804a27d06989388a92facc94b1239245cdcce57bb34da622381c358e7c01428b94297775fae2c8374931975025e714899a3a2ad3c614a9bd28ac74aa1e6d84bf0006a7dd322c260b9fd823967314baf27b14ee6af7e3450caf481d07238a91a1f1334c4130414e820b2df586418d603cac8782dbcb6f1d7c6c41b3b0e7b9c0386376a86c6a2f93bf096690f58c67194130266bf9c24786df97f3e169aca9a4bb56016b3283606d3a2ff5983b6c7533a388effd37b9c113242a55cccb11bc618c16ca64a55422e56bbca8f767c8e63b321b97228636a77a4202c364e8eec82af9569512723a3953918423e9162b89b234f808a107127b9d5da55a022402cb957b62c0d1f147971b311a3817913207cf6c4c8ae5c4f6d316505ec1d8714055535abfa41014a554049f540dce357b3027dd827af763bc0b3778bbd939514145b34d30f864500d716360cb34188b0889c77736c34a964701e09b6876d9740d6504e0bf4127513392f6cab9de98ed4e05f8e48356b90790eda565ebcadf32218a2f6aa64e56cc4d230fda2ab6b294211691e68ac2bea0b5a0e830d10875bcae4511fa25b21c5162ab68b9219c9df174ce20cce707276cb24c58ef90b8ea03fc661396fa9b3fcc688f2b83c289112ad93cb1a671e4e846769b3b28ff8267436324efc07d8b831782076831630a9a199bce50a26792972d9a77fd04f4df9c349fa4d6ab510e0237ec455408c01cce58560e7e931f986705795a1fcb91750cc2a9fe79ff77cb74d0c5998509705319978ac7a0fc7c2ad28793d622afea5b740c6a2f901d0576734f9934427977b85cc9924a487b28cc0c1cba64a6436f9150b7f872cda1a79e6576d42a655ae2a74a3898e6a999b185200cbc7566773c6028b2c61e47b5ee53a40f99297a9a2c2e7b71db935b1e0a76e57a2ab07416db633f2272c7f5c7b59164c17c424693585b45611104100f1e577dae178e45794e8204cecbaa3caa84d72520db9928e12b622c7243ca65271dcf326cad46ca5e48cc386b0c989b19fb9a2ed01a15dd6b391f6b629b4b788c86741a61b137c637a71cc1740addbe55ea78b1e2f3c55980c36074846a1ac089a2189e9fc23cc138ce672c70e514f8a46ca69244f5fd10c01877ec19b54a262c3f57500f2769134c7a3ff6511fc242aca8688c6747e0f5b6c5b133420e9010d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c327303bbdb9f2f6d263a15cd1f85cc8ddf1f7aa098c4db7408cf6355fdd2af70877bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e076",
-    "server_login_state": "08d3a1bd61e851947c6eb92158f5ae724dfb3380296a7e982d43b56358b8b32c492d70f023925acce68a36411ffddace57a6a9b3932fcc2a78fc4f0de51fef500d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3914cdc5a2a4e246f796d132148213c6d8f7b564ebdef20809c4d0fb109e4115be464f24d0f342dfea83b38f01444760066227cbf83bc02d2587c0a94f1d9bee3eba3e09d08252f7cd9911470b7dd2dcd6c9bbe32fcb4628a1777617f628c33539eb7365a93c9dd0d37d1f5dea544518a978af987144f5c69fc12087f44519106dc1c0f2433c2bcfbcd5eddc7aa87a01c67438c7d1505f5ca2b84bfbbda6705bfec8d9152d58b3b15be3ece14925746469439feeb88adf52883b006a67f29adb32b2dd89b77f390a2d32a488c740df692f5c5f4d199eeee1410096ed01d514ad9d38a311765b38a776ba55817ddf4230073f0165e04e7560a57c2a61f590e1cc94dfd213f39c23e35a0393e439a68d6a0fc274afb67b8f0ca26f94a45b264c824d83816c7242800f26615f1aef081d94062b7609c4078ba5b4fc5e32cc48fe84fd1de285723199bfb16a852cf1b67e20fbb64b8e0aa3fd425b8f7ed93c7b304dd7b8884ce3fd3783386a740a0c4331f941eaa32d4536f6d7cb91e9279e24c2a66189dc144d88396efe63ad3d96c1f5bf3422de40096ab8eb8e7b466ceb14356c9aca487718357bf740fa0f614c1062a6bf9e41c019dbdcc7f877eb8514be2a1cc93ea381e0262235e47d412e17cc28b248106b9f7c86a52842298868802b295f3f0581852fb99746cda24541f0c4b64a19a35aa6452b68a5d040188ba457b274f5fa20325fbe0f60ff5eb10e30eb845ab4d84aa5a2454afe6e6f9edb285e4aee9cf6301c1f36ef553b61e360b22f9c3aa597fae5ee7d6959684bef0e085ac2703f50610ef01e1ba0fadcc3118c8f1d663fb3f7e4269df8b3c36a4232dc0471dafc05993e1dd6c1176250c9849d7b237fdb7e241113ed88964c50524449f673ed29b534109b3ca4dc1be201383a787d9c6334a5447fde4d35d880094678c8e371eadf92f14e4612f1939443e29bcc777d6dc92b392ba2860f877d195201ff3ea338a3204bc70566350202b0629df31a4a4b7ba9286b4494fe01e84f0ed9e94515114dd5664e6657152094f6fc08ac90c1d586a3dc76654c311347755fb952d668adc4e4b9dbff21defc2140638fb62a19d83850b7d40e976667be16b1030c0926a362d4437ec630193b1ce010b4c07c470710b22ec1c3452944179e9a01065966aab29627e9731abc97b1b6a1874dbf89adb2cb6b5517fb047997d63f9482ee4f35ac2537fedca355ebbba7b36a4a5be1ebe3eb68f10212501545f1f339205562b15eeafc551a6616423a9903245baeeb1326fe9d6b89421210267d99ffc6f23ed5ef28c772bd177017b92f1af6d0c4db7d58e22571e05d9dd5a69136dcfc4689d0aa2bb6a1210bb0ae8806c79d892758ae0372f3ad5f1a016fef3f67f030cbe8a1a28473035c428f13dac7b4d044bd9b9c54e0fd88be9b60867e183f718913b6e9d657dc41e0c47ffd8856e143d2451fb139865790d9d2bf7db493b58f2b0ac94c1a1d2456909d3526ce10870bc63298ac5b908d2043618a9841e06d953c76d4f",
+    "server_login_state": "08d3a1bd61e851947c6eb92158f5ae724dfb3380296a7e982d43b56358b8b32cd74572366d98a30a88c86b3d73144c0d7761c64c391130688171384a58ade3a6492d70f023925acce68a36411ffddace57a6a9b3932fcc2a78fc4f0de51fef500d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3914cdc5a2a4e246f796d132148213c6d8f7b564ebdef20809c4d0fb109e4115be464f24d0f342dfea83b38f01444760066227cbf83bc02d2587c0a94f1d9bee3eba3e09d08252f7cd9911470b7dd2dcd6c9bbe32fcb4628a1777617f628c33539eb7365a93c9dd0d37d1f5dea544518a978af987144f5c69fc12087f44519106dc1c0f2433c2bcfbcd5eddc7aa87a01c67438c7d1505f5ca2b84bfbbda6705bfec8d9152d58b3b15be3ece14925746469439feeb88adf52883b006a67f29adb32b2dd89b77f390a2d32a488c740df692f5c5f4d199eeee1410096ed01d514ad9d38a311765b38a776ba55817ddf4230073f0165e04e7560a57c2a61f590e1cc94dfd213f39c23e35a0393e439a68d6a0fc274afb67b8f0ca26f94a45b264c824d83816c7242800f26615f1aef081d94062b7609c4078ba5b4fc5e32cc48fe84fd1de285723199bfb16a852cf1b67e20fbb64b8e0aa3fd425b8f7ed93c7b304dd7b8884ce3fd3783386a740a0c4331f941eaa32d4536f6d7cb91e9279e24c2a66189dc144d88396efe63ad3d96c1f5bf3422de40096ab8eb8e7b466ceb14356c9aca487718357bf740fa0f614c1062a6bf9e41c019dbdcc7f877eb8514be2a1cc93ea381e0262235e47d412e17cc28b248106b9f7c86a52842298868802b295f3f0581852fb99746cda24541f0c4b64a19a35aa6452b68a5d040188ba457b274f5fa20325fbe0f60ff5eb10e30eb845ab4d84aa5a2454afe6e6f9edb285e4aee9cf6301c1f36ef553b61e360b22f9c3aa597fae5ee7d6959684bef0e085ac2703f50610ef01e1ba0fadcc3118c8f1d663fb3f7e4269df8b3c36a4232dc0471dafc05993e1dd6c1176250c9849d7b237fdb7e241113ed88964c50524449f673ed29b534109b3ca4dc1be201383a787d9c6334a5447fde4d35d880094678c8e371eadf92f14e4612f1939443e29bcc777d6dc92b392ba2860f877d195201ff3ea338a3204bc70566350202b0629df31a4a4b7ba9286b4494fe01e84f0ed9e94515114dd5664e6657152094f6fc08ac90c1d586a3dc76654c311347755fb952d668adc4e4b9dbff21defc2140638fb62a19d83850b7d40e976667be16b1030c0926a362d4437ec630193b1ce010b4c07c470710b22ec1c3452944179e9a01065966aab29627e9731abc97b1b6a1874dbf89adb2cb6b5517fb047997d63f9482ee4f35ac2537fedca355ebbba7b36a4a5be1ebe3eb68f10212501545f1f339205562b15eeafc551a6616423a9903245baeeb1326fe9d6b89421210267d99ffc6f23ed5ef28c772bd177017b92f1af6d0c4db7d58e22571e05d9dd5a69136dcfc4689d0aa2bb6a1210bb0ae8806c79d892758ae0372f3ad5f1a016fef3f67f030cbe8a1a28473035c428f13dac7b4d044bd9b9c54e0fd88be9b60867e183f718913b6e9d657dc41e0c47ffd8856e143d2451fb139865790d9d2bf7db493b58f2b0ac94c1a1d2456909d3526ce10870bc63298ac5b908d2043618a9841e06d953c76d4f",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "08d3a1bd61e851947c6eb92158f5ae724dfb3380296a7e982d43b56358b8b32c"
@@ -1156,7 +1317,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_P384: &str = r#"
     "credential_finalization": "74b116185a669768eb270635243507a37968b70ff4d47fd271d099accfbce8f5f4bc250f013476bb9ea8e7f4c84b09e0",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c20550cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015afbf18cf86400ff0442cccc8fa2243b114c2d54b5ac512a0a2fc0117e78b373dbce933a2304346b82a7267ef929b73370970043a1fc023b2817b38a1282d822225bcad6c1a10a620753222e8c5a989424b1fdc90e0dd63611180b5f8a70102788f1158b7ff0066b33cbd88a0bfcea348c97b880a553dc6a7f4bd719a2e06f1baa92b6b21c29443872b35597e43e9210105e34abb48bc121864b9ba3247e6ba54d71515236b1948788b1fbbb0e2c582df32bb904b995853cdce8101c91c0cdacb5a5fb2920830443c4b79481665a4052d4fa313f6830cdea8185e20ffd0b923f5c11de330efde00afdfb5730a98a2a051452b33ae0066901da2a748a5dfb999d1edc3bc0389d3e9b8e80db3ed954c19ec67cedf67a59b6292d5a040d9a018c263ab1f275fbba68286ac57aec83b0a127015a9ee8e7101d01ad732a0e008b682a479400a10f474a02ace35fb5f60bf2887541867776bb2b967b021d63a47f7ac137607ebd14b18306b5f18160134b19dd600318e6c45f95170ea94825a336d81a3c1440cb10e21abef14e83887e7e4143b58682f6d1bda54a4ad105334bc546489241748537f8da97a8332a522b492f67017ab00f7e09409e133b2cf933d5e801bd828b5eb667fd8b2a84b4930aa9c121a61b89744b1e897ce9d946b3e3cc80a6a934a7b65689534d3b60e52587990c4812f47d06b2433602b110b611748482dde11cd4ca4bad868aeb761c90b99b9ed623d3b3abdedbc073075ac815682b6b75de3a2fbb08c3f60285b39ac86587c051e12fb7957476d3181d074347a81b593317acd505764ba94b08740af027eda2617c8a00c7812f62359eb0683f3ca37e0d92a3965b2c33134348697fe998034b26850e505594537720b9756654a50e3177e76a951ae9cfed44074255913f934b67ebc832032b5ed522285288d73a9c03fab2db5907d54b76edc2a32b42cb0263c2ed05a23ab5a507ca47206b764dc41416826e230703f3a9cb0144158544c8d178b1793cc02c60b3aaec4767c3ad37954cc6907faf64c6739701cb933d4acbadbd792ebef29f30d5757cbc4b1f7bada7ab315cb8213d7091d3e681afe7970c256803348d182856c8777f7432064c05c5012c1cf68299c8d6bc44425896b99662819cb0814fa1729847f9856c621d37a2ad16225cc8760ee7fc2d2bc4914ba71666d0105adbaf8c8a5efee05141b2ca8bec85672815e1f42e02d36474f1ba29bb5485a156bbe403da14bf29d0a63c1213421c3112d59c411bbf5d3a86bfeb1e988c178ba6a0f879962c7a0cac5c1d9dd205a7f5456b0b1e637b5fc196a3f9115a5ce9995d348b69f950614aab32fb632d52546a8344c316bf5101b9a575530f262c8380aaecfb7112dac4088337fe69493d2803519583ba706b9ffb91f605a7eeb768fcd9b57bd005de4abafc22cae11aa73341bf2f383a2582b863922d14183f5092652c769e9599cbb626155e58c5cdb2a8f942b935d02bcb462dd55553b53295b92577689a458776c2d7e714f54255f5a06c4ab03b7fbb24042c804f1c6a00511ccfe0843984bb990a08eff37489889570042fd9fc9e8536a255e4a3c2b81f770b4d2e0198376baa32316379e637a1602cbc513da305fc24f03f2233dcf510e65a98fc2318bfca1914f0700ab48e7ecfb13295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190ffa3b70bfd704806bb5c33460a57229577ac326f9c731e05093e9812c64c4f9a5443f3a35133c4a16967e4e268cc7ab5b584704f25088ac739158c79ef2d30588cc28086b320e54612dd52f57a9cb4ab37cef63a38e54ce3681873ab6c26e46a0146a675b001374ea0e85d370d78037b8ca167d599ed558337b1bba0782c037fc877b653d2ff02a53bc780434101e7a0e9b6baa8dbac716d9a0590c38844310ee4200eaab642f7492f55b97ff13455044c9f27a858965b166fa5c457554ada2b709258ea4cb913d35c9b12452a90ac97ab860c6db39fb1268f0a1a716ac81fcf96686f21631212d6cda6c48aa964f341aa60689ec11c435588fde5a79950c669a5c2235595023419842a51d2868a63c017346b1127a9a8d20929dd7f734d230b9a1392f9529355da2863301956500b48fc74427c66d86caa9f7254a5b469df8b598e3cc2ca997b4c0fb24bd44907c915a07a540966b44539723e7063d2c37963c78520aa40abeea5d05189f5b321794c757a8d2ab6e3045b4763fc857cf05483cb0b028641b38a218a5bae9844fa6b4ffb424923ca429cc6d20d0602962b1c0eb075f33b2aea81f42f6a28204596d16ce98e88a0871b3231945c991a10d1c18e700c597e993b6bc4363a6c6381a1517f11fbafb1df4225ea4b85ff3c867f505b3b64bb9f591c64ec043d6417c4105ac4b95260a2a7440a2ac91c32c7262069fd6c1aa8b1d26677a1fc444fda48946c388c3aa39cbf88c8b280985ec1513b3685204a9767933f7909b18d121321569df5b170c89779a746d03b467b8937833e1954f4536a2367dfce5a54565980b056a9c7c9e4ae978618c7fa387b87d9329e139071a96ab769b47f545a0c2ba9c3719768c554de79809f7e18c91184299548d9e0cbbfb1ca9b29b6a7862a9dfd7204ec98ad58a8b91e7916ce25623421365a09a1b7c03f1c64140c79b97b581dea3431345890bec38c182a785c1753dd5cbe0630632a39274b375604a114cc99e0b878ceff74783245a267a811f6ca5ed6670f0164897c0ca4f390ca748ac438375f268339ab7b2ff070ae06590bc206417442c6125c81ac876d4719d4b411e63b04670477b3f3181d0859851d6cf5ffb1842d86d05ac5b1df42600cb4f3791bd64a71655f7c90613867453286bb228e0865e977177789483cb4b438983441a56b180811939580824471f3600968c5c1abcb368bce18e01f5402528ae69f4116be0575825921bc938428674a482c2dab1c147e5767d3136699c60d16258de976658a4a5b9e43ed1ac496f159a209882c41aab088b269dbcb16afb871e4462998b63bd1b8b1974b8ea430cc6865b0b0b2fcf369381020044917f0b240b438612ac279acfb28f23db6a266446a3682668095b5f71a9266044d237b03195c4763aa92e51b3c60569cdb67c5aa864df65aea2b008a30931793628cba3959f117fa68325a2e45333e44ca5659295c2bb6026c8e6c098424061a6040e73a17e42b42a3b0c51bba3127cc37b23b34699ab6fdfc17b5342c8b9e36517668d18f7795d969ed91c7e27f4978da0098bcb5018207e167b60d74851c605a4700ca73322c564392bd2d9b8e65945bd58a6c1874dacc605f2f5cbfbf18cf86400ff0442cccc8fa2243b114c2d54b5ac512a0a2fc0117e78b373dbce933a2304346b82a7267ef929b73370970043a1fc023b2817b38a1282d822225bcad6c1a10a620753222e8c5a989424b1fdc90e0dd63611180b5f8a70102788f1158b7ff0066b33cbd88a0bfcea348c97b880a553dc6a7f4bd719a2e06f1baa92b6b21c29443872b35597e43e9210105e34abb48bc121864b9ba3247e6ba54d71515236b1948788b1fbbb0e2c582df32bb904b995853cdce8101c91c0cdacb5a5fb2920830443c4b79481665a4052d4fa313f6830cdea8185e20ffd0b923f5c11de330efde00afdfb5730a98a2a051452b33ae0066901da2a748a5dfb999d1edc3bc0389d3e9b8e80db3ed954c19ec67cedf67a59b6292d5a040d9a018c263ab1f275fbba68286ac57aec83b0a127015a9ee8e7101d01ad732a0e008b682a479400a10f474a02ace35fb5f60bf2887541867776bb2b967b021d63a47f7ac137607ebd14b18306b5f18160134b19dd600318e6c45f95170ea94825a336d81a3c1440cb10e21abef14e83887e7e4143b58682f6d1bda54a4ad105334bc546489241748537f8da97a8332a522b492f67017ab00f7e09409e133b2cf933d5e801bd828b5eb667fd8b2a84b4930aa9c121a61b89744b1e897ce9d946b3e3cc80a6a934a7b65689534d3b60e52587990c4812f47d06b2433602b110b611748482dde11cd4ca4bad868aeb761c90b99b9ed623d3b3abdedbc073075ac815682b6b75de3a2fbb08c3f60285b39ac86587c051e12fb7957476d3181d074347a81b593317acd505764ba94b08740af027eda2617c8a00c7812f62359eb0683f3ca37e0d92a3965b2c33134348697fe998034b26850e505594537720b9756654a50e3177e76a951ae9cfed44074255913f934b67ebc832032b5ed522285288d73a9c03fab2db5907d54b76edc2a32b42cb0263c2ed05a23ab5a507ca47206b764dc41416826e230703f3a9cb0144158544c8d178b1793cc02c60b3aaec4767c3ad37954cc6907faf64c6739701cb933d4acbadbd792ebef29f30d5757cbc4b1f7bada7ab315cb8213d7091d3e681afe7970c256803348d182856c8777f7432064c05c5012c1cf68299c8d6bc44425896b99662819cb0814fa1729847f9856c621d37a2ad16225cc8760ee7fc2d2bc4914ba71666d0105adbaf8c8a5efee05141b2ca8bec85672815e1f42e02d36474f1ba29bb5485a156bbe403da14bf29d0a63c1213421c3112d59c411bbf5d3a86bfeb1e988c178ba6a0f879962c7a0cac5c1d9dd205a7f5456b0b1e637b5fc196a3f9115a5ce9995d348b69f950614aab32fb632d52546a8344c316bf5101b9a575530f262c8380aaecfb7112dac4088337fe69493d2803519583ba706b9ffb91f605a7eeb768fcd9b57bd005de4abafc22cae11aa73341bf2f383a2582b863922d14183f5092652c769e9599cbb626155e58c5cdb2a8f942b935d02bcb462dd55553b53295b92577689a458776c2d7e714f54255f5a06c4ab03b7fbb24042c804f1c6a00511ccfe0843984bb990a08eff37489889570042fd9fc9e8536a255e4a3c2b81f770b4d2e0198376baa32316379e637a1602cbc513da305fc24f03f2233dcf510e65a98fc2318bfca1914f0700ab48e7ecfb18b3964cf2a549545f4fcacf928f5bcf9b421c74ddfec3d0f33873a6a79c16aa3883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e35587",
-    "server_login_state": "75e6c959a85f0fa046d1876b384800e5b962a1238273ebce19b06fa02ac2794f46c15308006ba4756b99e249e019f5e374b116185a669768eb270635243507a37968b70ff4d47fd271d099accfbce8f5f4bc250f013476bb9ea8e7f4c84b09e0fbf18cf86400ff0442cccc8fa2243b114c2d54b5ac512a0a2fc0117e78b373dbce933a2304346b82a7267ef929b73370970043a1fc023b2817b38a1282d822225bcad6c1a10a620753222e8c5a989424b1fdc90e0dd63611180b5f8a70102788f1158b7ff0066b33cbd88a0bfcea348c97b880a553dc6a7f4bd719a2e06f1baa92b6b21c29443872b35597e43e9210105e34abb48bc121864b9ba3247e6ba54d71515236b1948788b1fbbb0e2c582df32bb904b995853cdce8101c91c0cdacb5a5fb2920830443c4b79481665a4052d4fa313f6830cdea8185e20ffd0b923f5c11de330efde00afdfb5730a98a2a051452b33ae0066901da2a748a5dfb999d1edc3bc0389d3e9b8e80db3ed954c19ec67cedf67a59b6292d5a040d9a018c263ab1f275fbba68286ac57aec83b0a127015a9ee8e7101d01ad732a0e008b682a479400a10f474a02ace35fb5f60bf2887541867776bb2b967b021d63a47f7ac137607ebd14b18306b5f18160134b19dd600318e6c45f95170ea94825a336d81a3c1440cb10e21abef14e83887e7e4143b58682f6d1bda54a4ad105334bc546489241748537f8da97a8332a522b492f67017ab00f7e09409e133b2cf933d5e801bd828b5eb667fd8b2a84b4930aa9c121a61b89744b1e897ce9d946b3e3cc80a6a934a7b65689534d3b60e52587990c4812f47d06b2433602b110b611748482dde11cd4ca4bad868aeb761c90b99b9ed623d3b3abdedbc073075ac815682b6b75de3a2fbb08c3f60285b39ac86587c051e12fb7957476d3181d074347a81b593317acd505764ba94b08740af027eda2617c8a00c7812f62359eb0683f3ca37e0d92a3965b2c33134348697fe998034b26850e505594537720b9756654a50e3177e76a951ae9cfed44074255913f934b67ebc832032b5ed522285288d73a9c03fab2db5907d54b76edc2a32b42cb0263c2ed05a23ab5a507ca47206b764dc41416826e230703f3a9cb0144158544c8d178b1793cc02c60b3aaec4767c3ad37954cc6907faf64c6739701cb933d4acbadbd792ebef29f30d5757cbc4b1f7bada7ab315cb8213d7091d3e681afe7970c256803348d182856c8777f7432064c05c5012c1cf68299c8d6bc44425896b99662819cb0814fa1729847f9856c621d37a2ad16225cc8760ee7fc2d2bc4914ba71666d0105adbaf8c8a5efee05141b2ca8bec85672815e1f42e02d36474f1ba29bb5485a156bbe403da14bf29d0a63c1213421c3112d59c411bbf5d3a86bfeb1e988c178ba6a0f879962c7a0cac5c1d9dd205a7f5456b0b1e637b5fc196a3f9115a5ce9995d348b69f950614aab32fb632d52546a8344c316bf5101b9a575530f262c8380aaecfb7112dac4088337fe69493d2803519583ba706b9ffb91f605a7eeb768fcd9b57bd005de4abafc22cae11aa73341bf2f383a2582b863922d14183f5092652c769e9599cbb626155e58c5cdb2a8f942b935d02bcb462dd55553b53295b92577689a458776c2d7e714f54255f5a06c4ab03b7fbb24042c804f1c6a00511ccfe0843984bb990a08eff37489889570042fd9fc9e8536a255e4a3c2b81f770b4d2e0198376baa32316379e637a1602cbc513da305fc24f03f2233dcf510e65a98fc2318bfca1914f0700ab48e7ecfb1c8f8bfec40deea39f7f4138e2ba495b131fa71a93d34dc4e7199bed4749793a7116e8e5a128dc54e238783fa3a38b8fe4b372b77b30c6fd668d6f43f78f2a00c97bc6b593dd63106a7bfbd9ce241bcb1b4da994a500b3f22cecb6f4897bbb540e3d63ea56fc2af65bc7b5fdbe6a1beda1b2d98791f0ce9c37bc6140c511cfa9e6700519fbd38c8cdb99a838ad13323a463303bb843b3fad3777ba60a2ce4401a3bafeab958e3490a82b80c927a98815e3864674fcf61d24c2b2a5a0ca6d55f8207af6b4a08ab04b7292b3b92698f14e5d9e40e7ceacc7c2295be2a7bae859249055b640627fddd8cbba9aed914245129bb9e6cb2e4bff80033b3bfec8ea9199a457f17df2cc83200ee54b12cabb2fc6071cb8f98c322f55c3fd3e2263acab036876c06ffad4f55764f7a6d20e18e6e4f53bb687d840c0b5185e3162df28c6366c78ea9c8890bf16b9b5362c52362bf0852df960c627332534d0e5a180f0099a0b48112307969b47d11e6ffaa16810f7463ddd7679f88f94b11d9f72d7e9535a9030d33490829185ac15fee22fe0f0b3d868330b1ebd9529f1cbd4ca2b660231e8ad09a1c3eb4b4a6bc8d0588c5383a101c4b0718ef0fbe57e349413b74854361dff67d928155aa0200f8aeb7ae7660eaeb79cecbc4f634bf2046c5ef81c1a30f7043a6b31dede1ac3e68af9f8be99be3dcbdb12079e84ac933f4912902dd5a04a39dfa4bd6f21cae710a34acb771ccc48f2c678c8686a8ce273a14a83a5767c1403f4bed4e57e3d0712e119664130e464bcb3fad454e8aeec5b94571b0e3052e76f6491b86bd6619cf2f0ed488a284513041683678720ad95bbb97f3814090385d5348ff56d283275015aef893044b9eec8421c9e6d3710b33669412d27cc4ee7cf33a03a9f85762fb4a35415a6ea84a9dcc1692e6a08cd03123ffdbc12dc143786b6b3fd93fd3bda5a095b85a6d7be08a7ea7f605169afccea46af2ac0401487397cd6087047d2504e40cc04cddc06d5e53f6bc1152a27a3e1bd4e020816b4409af90f2cce420edd7cc185579754492a5a124aa9050b8416ec3f402b82d4db4c4f8e592b23fb962fc6e58344cd7baeb0142fe8a3f980e6c7ebac48cf2f39a5253883b81798159243c35e1e2d5d5da3e1af0dfaa556d92458691dbb00b3365b9b2f7f24d92c420570d2a2e1494ab0bc118727aa746bd192bd498b9c8dd86b036cf9141217040055527fd3bdcfd6bab5271692abc54b7aa61bb460333c96952e69241e24cd544e9c0a253434a1a74a0b01e5a5ed21aa03ec411af6ed7c0671bc2c49ab143e6c49da598316c9874913a29f993017941b1f2aed513caf6811affb6bc5578965a2ed46548a66a6743a73bac1d70494ec68b19a72e90fc567e2ea1c0f610e51deb50ffe65959c680017b77b59781b127f3c26f9479c715427ee7af680423816720ca58d5746b2d6fc5aa70004380d8b2a343a039a445eb712f68ba87c3d23f0ff7efcc3da2b4a947f0a8c738cd152d9a07b59d894d1c0f4b5cfe4406",
+    "server_login_state": "75e6c959a85f0fa046d1876b384800e5b962a1238273ebce19b06fa02ac2794f46c15308006ba4756b99e249e019f5e3309593a78915997ad495b313630cf4484f8048796dc7c9928ed860aee105f2fb31d90a9356d331332c7f000932aeaa6f74b116185a669768eb270635243507a37968b70ff4d47fd271d099accfbce8f5f4bc250f013476bb9ea8e7f4c84b09e0fbf18cf86400ff0442cccc8fa2243b114c2d54b5ac512a0a2fc0117e78b373dbce933a2304346b82a7267ef929b73370970043a1fc023b2817b38a1282d822225bcad6c1a10a620753222e8c5a989424b1fdc90e0dd63611180b5f8a70102788f1158b7ff0066b33cbd88a0bfcea348c97b880a553dc6a7f4bd719a2e06f1baa92b6b21c29443872b35597e43e9210105e34abb48bc121864b9ba3247e6ba54d71515236b1948788b1fbbb0e2c582df32bb904b995853cdce8101c91c0cdacb5a5fb2920830443c4b79481665a4052d4fa313f6830cdea8185e20ffd0b923f5c11de330efde00afdfb5730a98a2a051452b33ae0066901da2a748a5dfb999d1edc3bc0389d3e9b8e80db3ed954c19ec67cedf67a59b6292d5a040d9a018c263ab1f275fbba68286ac57aec83b0a127015a9ee8e7101d01ad732a0e008b682a479400a10f474a02ace35fb5f60bf2887541867776bb2b967b021d63a47f7ac137607ebd14b18306b5f18160134b19dd600318e6c45f95170ea94825a336d81a3c1440cb10e21abef14e83887e7e4143b58682f6d1bda54a4ad105334bc546489241748537f8da97a8332a522b492f67017ab00f7e09409e133b2cf933d5e801bd828b5eb667fd8b2a84b4930aa9c121a61b89744b1e897ce9d946b3e3cc80a6a934a7b65689534d3b60e52587990c4812f47d06b2433602b110b611748482dde11cd4ca4bad868aeb761c90b99b9ed623d3b3abdedbc073075ac815682b6b75de3a2fbb08c3f60285b39ac86587c051e12fb7957476d3181d074347a81b593317acd505764ba94b08740af027eda2617c8a00c7812f62359eb0683f3ca37e0d92a3965b2c33134348697fe998034b26850e505594537720b9756654a50e3177e76a951ae9cfed44074255913f934b67ebc832032b5ed522285288d73a9c03fab2db5907d54b76edc2a32b42cb0263c2ed05a23ab5a507ca47206b764dc41416826e230703f3a9cb0144158544c8d178b1793cc02c60b3aaec4767c3ad37954cc6907faf64c6739701cb933d4acbadbd792ebef29f30d5757cbc4b1f7bada7ab315cb8213d7091d3e681afe7970c256803348d182856c8777f7432064c05c5012c1cf68299c8d6bc44425896b99662819cb0814fa1729847f9856c621d37a2ad16225cc8760ee7fc2d2bc4914ba71666d0105adbaf8c8a5efee05141b2ca8bec85672815e1f42e02d36474f1ba29bb5485a156bbe403da14bf29d0a63c1213421c3112d59c411bbf5d3a86bfeb1e988c178ba6a0f879962c7a0cac5c1d9dd205a7f5456b0b1e637b5fc196a3f9115a5ce9995d348b69f950614aab32fb632d52546a8344c316bf5101b9a575530f262c8380aaecfb7112dac4088337fe69493d2803519583ba706b9ffb91f605a7eeb768fcd9b57bd005de4abafc22cae11aa73341bf2f383a2582b863922d14183f5092652c769e9599cbb626155e58c5cdb2a8f942b935d02bcb462dd55553b53295b92577689a458776c2d7e714f54255f5a06c4ab03b7fbb24042c804f1c6a00511ccfe0843984bb990a08eff37489889570042fd9fc9e8536a255e4a3c2b81f770b4d2e0198376baa32316379e637a1602cbc513da305fc24f03f2233dcf510e65a98fc2318bfca1914f0700ab48e7ecfb1c8f8bfec40deea39f7f4138e2ba495b131fa71a93d34dc4e7199bed4749793a7116e8e5a128dc54e238783fa3a38b8fe4b372b77b30c6fd668d6f43f78f2a00c97bc6b593dd63106a7bfbd9ce241bcb1b4da994a500b3f22cecb6f4897bbb540e3d63ea56fc2af65bc7b5fdbe6a1beda1b2d98791f0ce9c37bc6140c511cfa9e6700519fbd38c8cdb99a838ad13323a463303bb843b3fad3777ba60a2ce4401a3bafeab958e3490a82b80c927a98815e3864674fcf61d24c2b2a5a0ca6d55f8207af6b4a08ab04b7292b3b92698f14e5d9e40e7ceacc7c2295be2a7bae859249055b640627fddd8cbba9aed914245129bb9e6cb2e4bff80033b3bfec8ea9199a457f17df2cc83200ee54b12cabb2fc6071cb8f98c322f55c3fd3e2263acab036876c06ffad4f55764f7a6d20e18e6e4f53bb687d840c0b5185e3162df28c6366c78ea9c8890bf16b9b5362c52362bf0852df960c627332534d0e5a180f0099a0b48112307969b47d11e6ffaa16810f7463ddd7679f88f94b11d9f72d7e9535a9030d33490829185ac15fee22fe0f0b3d868330b1ebd9529f1cbd4ca2b660231e8ad09a1c3eb4b4a6bc8d0588c5383a101c4b0718ef0fbe57e349413b74854361dff67d928155aa0200f8aeb7ae7660eaeb79cecbc4f634bf2046c5ef81c1a30f7043a6b31dede1ac3e68af9f8be99be3dcbdb12079e84ac933f4912902dd5a04a39dfa4bd6f21cae710a34acb771ccc48f2c678c8686a8ce273a14a83a5767c1403f4bed4e57e3d0712e119664130e464bcb3fad454e8aeec5b94571b0e3052e76f6491b86bd6619cf2f0ed488a284513041683678720ad95bbb97f3814090385d5348ff56d283275015aef893044b9eec8421c9e6d3710b33669412d27cc4ee7cf33a03a9f85762fb4a35415a6ea84a9dcc1692e6a08cd03123ffdbc12dc143786b6b3fd93fd3bda5a095b85a6d7be08a7ea7f605169afccea46af2ac0401487397cd6087047d2504e40cc04cddc06d5e53f6bc1152a27a3e1bd4e020816b4409af90f2cce420edd7cc185579754492a5a124aa9050b8416ec3f402b82d4db4c4f8e592b23fb962fc6e58344cd7baeb0142fe8a3f980e6c7ebac48cf2f39a5253883b81798159243c35e1e2d5d5da3e1af0dfaa556d92458691dbb00b3365b9b2f7f24d92c420570d2a2e1494ab0bc118727aa746bd192bd498b9c8dd86b036cf9141217040055527fd3bdcfd6bab5271692abc54b7aa61bb460333c96952e69241e24cd544e9c0a253434a1a74a0b01e5a5ed21aa03ec411af6ed7c0671bc2c49ab143e6c49da598316c9874913a29f993017941b1f2aed513caf6811affb6bc5578965a2ed46548a66a6743a73bac1d70494ec68b19a72e90fc567e2ea1c0f610e51deb50ffe65959c680017b77b59781b127f3c26f9479c715427ee7af680423816720ca58d5746b2d6fc5aa70004380d8b2a343a039a445eb712f68ba87c3d23f0ff7efcc3da2b4a947f0a8c738cd152d9a07b59d894d1c0f4b5cfe4406",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "75e6c959a85f0fa046d1876b384800e5b962a1238273ebce19b06fa02ac2794f46c15308006ba4756b99e249e019f5e3"
@@ -1195,7 +1356,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_KEM_P384: &str = r#"
     "credential_finalization": "9f2021f9eccf2f3510136ba9fde1bfd0736c554f0c35c007209e4e1c9fed864a7489fdf84f1e9ef9427b1b755cfb14af",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe15ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f604203066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015acfd6ce9b11a720b7200d64aa95f47555bc0136d13c0f91bf8c7b506ceac976aba9a6e895e1519547237efbd01043245e3c5a3b96b53002b5183be14d8f76a6f105a74f312d70f6b5f0111acd44591a57a9d2dc04fc954fab11796ea2165ffb47f7b9c9c85973f3a4156f659936fcc145a900da46caddc62247e45e820c712d08a66370b58e1ba0c23380d33c5091675bee143e920c5ed44b0341e5c542a158248b1604969b26d7075869aeb6171cd7283196dcb1d17a5e46777d3fe27cce59a82fa0b42182aa1a54b936f896391a214feb3064454c417094ac536c1ae6cce3961a4ad594101b01fce2bf0872569f68508f4b0000c1a33db569f0f532fee18be50240cfa07cc1724515220a80a54038838ffe376a7fc244bc25641991069fe061ea933fd26a3e3783303ef8c67159a040d673560992cfa06bb9e141ce07521fa1618d9796a9d621ade8a202a238fdd179b4d16a0d8401db067adddb97867645552422b244c10f80c592b443b0da891d9716008225d0ca24e2a5886cf5b0c6ab7aff359ac3507e5df6554263b7f082164407b1e1e50430c96c91519f9bb249bc4102c86cc8198179e35c8513fa14c988cecb08610f81acc188147c0103d8109e537bc132c7bfb581a1ee58c71838aaa00b5aa28938f911bd589a41d7a34849c9a8b0c47f01d870242a15f6aa6e5b238fe2cc988e51453b269b748522e819befc1a26d78936bd12cca0250319f7be2a24837f83626e0bb8d95b2048ec8d49430cdf5abd0388b18adb07b446c176d010198b6354110e20d80a3d3a969c938b5ba531a3fc777233931daa1059771651a91caa5b3a8991a20652828010408d89cbbe0308cbc41fdfeb2dfe280a51950699b0bc9003bfaaf9c8c7352ef58097b8c9432f8b72f94021c8c5557f1c0f8da72cb24346a91a8df5276820da6401065e54c2ce9738cdb800b814273ec401cfeb223d05d4b8347678523889b6082c336c339bacc8c1c19a6858bc15090428b40ebbe16682d47666d9bcdad3c3a7aa2ebc55311bd0baf0447cd80c974bec8c6301194015537baa9350acb5b79c7e26d9a75a5c916a50bb699468ae959ce6082b74380498094160c7af3a90ac8888c1595b0b131a28249a9a7bf23f8011215b903ee7f927a1751a7a0aa34bca05e9764ab7a34e24b3c4a576169ba851dfc198797027b569ba17c8c7176c2b0bf399656a4d8dca3d6b8a382baa81a01a57975b16ef3059ccc85b6910890b59660491932e46210390a1ddf507e70163fdc7a459852888781d544b87f75c0ad2796cfa62333c3921804c1724fa396579a1dd0b2ced631b2991386c2716302a7d6167737ee750b1f928f61785f733a1bf2b65c47b6e76834252a9c1f486407467a06653a2d7ca775e359256f6187e2c05e8d8604af3258edc4fedfbc430153a9519b5d78703ab669779d98baa011210f48842421dda34296515610331b936d34f7f1961d71b689cdba840a51dcc5246b546483526a66613cdeae04e67818ea7e17211786394a75395a15ecab78211d24ceacc8ecd066a1bd89500ad00c6b67ceee86edad406684467a5bb55e39c6167b8b306bcb5c4bcb8d85836afb00bf940bf3d8b950740116e44b4266556e717acdd16e13688b69d8c04e2136b70f682064eac3c078a56c39268dd3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c015ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f60427e94a8f36a5d1aa73897ea1bb2961c97e5a01d269fe6fca22088036fb6a203ba5c16ac57f7dab0a9908bbf603778839b915c71097947447a183ab35ea044c0c8f18a73655b2a47521b555850592882a293bdc2175d3238cde12d3b281500f778f751ca03f32e5896093c5b3e5401382ac29fbeb1290ef23bb6121bb65b0bd283a0f3e11daecc2cb797668e53bcb1442846187ea2e4ac8d81be63a2821fc352116c0fac8b0923e7517070a108a7ac16cc3a16342005417ce28b4af8250540b13f5aa15b7c8b1785bb918b3133bb8acffd650be1dbbd414bc6b517c35d51aafaf71b18eb148565b1c875b010287708367051e986a474648f1903b9b2bc8b0a6b96558e03d5232ac252e3518d4e368dafb337cb76a03639bfde88402d569f97c49e676aa278ea62d8ba8f8b1611bfb6bc35302f627b6fe774b503f95c94d7cc30f5870b7b04ec224181905178d0302c97b8c3a9406bd46f2cb355905056128cb4424c3e1807874906a86e4a54d01686ce3989338c71c8403aceec980a8c3aa9e5623481aa62d13f15a820141100137a239910cef424a5c436b989475f0a5b9b1751ca7fe8826dc08a81502d667508459b35072c412e6c8e4be18be04517264831a2414773355a293c5fcc283e7df67f879096b16a121fb5648ce41a8b156f7ea9970ff16f53723d5ada12748a05d2a07e6f8884bacb71372550a7abac1162b972439d96101e3cb73ffa2bb12e1b59b82cac96e888e641bc0c19b4e61b6bb520a11db4074d622354605156ac441085193706946aa4b1df9c80c2c686f72acf22b70fe9e53e8b70151ac9483ec5c92479892526052ed1911bc62f9277c99dc69734a5cce2d20191306d41389fa3138c3ef0049b9792ae9b9dabf930fde62c15e9a193f7a7f3163dc005b9142a1d3d991b9f6c161526c091cb4cf35a47299ab06e8b136450abc1d4b0f0427eb0b30e7b959bad9ac2c4cb97d3cba2e73b47f6836729317a3c0c204f5ab83fbb490ea2565f4b67cb233752dca96518aa5581500e511a207bc3a5d23fb6e323143424c041ba71d3577d7b4b83b283aed80910538d9c043f1e16765b1c62aca4095af49be9a8c70f2cc83a7281d6534a45e295a55b68671a886a0a2bc0873bc2fc4d08a100e092cc9b034aca55af4c500ab416b07270652a693f3cb885de74588fd08591c2c0c71bcdfdc640b3945a8f5903a9e4845ba109843c6a6471160ee7c1a11061fd72bf71e07d6d896b0972365b0b36b22c21795933878c3f89f43e85d29425db3635dbc90fb4a1f70399d50a683d54503b89a16aa37f3c58c8104cb464530dfd44699531b9a570b03f0763a5e9110fc2b86904bc8095670368b10e4a7ee7188387daa73840479e13cf61c142dbaaa1d8e61ade94550146623f079d81943bcb46aa0bd49eac8608639c391f1a864d427905da0ac7ab11d803a0b28b88f12326e762c44cdaaf6fb2895bf45f3ebbcb55eac64e5552035c58d80205f25093fa53a665c0954397587720c0d3a6af99a1356b4c1b172336eed8a9c4106b3d0a47219b1b5331b5a60caba9420408c383e3b3a13af9172bc40354e99b0b3c8b0aeb53374c02eddb9ad1f80456042c61fc3f691c7c0d0262cfd6ce9b11a720b7200d64aa95f47555bc0136d13c0f91bf8c7b506ceac976aba9a6e895e1519547237efbd01043245e3c5a3b96b53002b5183be14d8f76a6f105a74f312d70f6b5f0111acd44591a57a9d2dc04fc954fab11796ea2165ffb47f7b9c9c85973f3a4156f659936fcc145a900da46caddc62247e45e820c712d08a66370b58e1ba0c23380d33c5091675bee143e920c5ed44b0341e5c542a158248b1604969b26d7075869aeb6171cd7283196dcb1d17a5e46777d3fe27cce59a82fa0b42182aa1a54b936f896391a214feb3064454c417094ac536c1ae6cce3961a4ad594101b01fce2bf0872569f68508f4b0000c1a33db569f0f532fee18be50240cfa07cc1724515220a80a54038838ffe376a7fc244bc25641991069fe061ea933fd26a3e3783303ef8c67159a040d673560992cfa06bb9e141ce07521fa1618d9796a9d621ade8a202a238fdd179b4d16a0d8401db067adddb97867645552422b244c10f80c592b443b0da891d9716008225d0ca24e2a5886cf5b0c6ab7aff359ac3507e5df6554263b7f082164407b1e1e50430c96c91519f9bb249bc4102c86cc8198179e35c8513fa14c988cecb08610f81acc188147c0103d8109e537bc132c7bfb581a1ee58c71838aaa00b5aa28938f911bd589a41d7a34849c9a8b0c47f01d870242a15f6aa6e5b238fe2cc988e51453b269b748522e819befc1a26d78936bd12cca0250319f7be2a24837f83626e0bb8d95b2048ec8d49430cdf5abd0388b18adb07b446c176d010198b6354110e20d80a3d3a969c938b5ba531a3fc777233931daa1059771651a91caa5b3a8991a20652828010408d89cbbe0308cbc41fdfeb2dfe280a51950699b0bc9003bfaaf9c8c7352ef58097b8c9432f8b72f94021c8c5557f1c0f8da72cb24346a91a8df5276820da6401065e54c2ce9738cdb800b814273ec401cfeb223d05d4b8347678523889b6082c336c339bacc8c1c19a6858bc15090428b40ebbe16682d47666d9bcdad3c3a7aa2ebc55311bd0baf0447cd80c974bec8c6301194015537baa9350acb5b79c7e26d9a75a5c916a50bb699468ae959ce6082b74380498094160c7af3a90ac8888c1595b0b131a28249a9a7bf23f8011215b903ee7f927a1751a7a0aa34bca05e9764ab7a34e24b3c4a576169ba851dfc198797027b569ba17c8c7176c2b0bf399656a4d8dca3d6b8a382baa81a01a57975b16ef3059ccc85b6910890b59660491932e46210390a1ddf507e70163fdc7a459852888781d544b87f75c0ad2796cfa62333c3921804c1724fa396579a1dd0b2ced631b2991386c2716302a7d6167737ee750b1f928f61785f733a1bf2b65c47b6e76834252a9c1f486407467a06653a2d7ca775e359256f6187e2c05e8d8604af3258edc4fedfbc430153a9519b5d78703ab669779d98baa011210f48842421dda34296515610331b936d34f7f1961d71b689cdba840a51dcc5246b546483526a66613cdeae04e67818ea7e17211786394a75395a15ecab78211d24ceacc8ecd066a1bd89500ad00c6b67ceee86edad406684467a5bb55e39c6167b8b306bcb5c4bcb8d85836afb00bf940bf3d8b950740116e44b4266556e717acdd16e13688b69d8c04e2136b70f682064eac3c078a56c39268ddbe1681f5e8ca1ae3df58646af76e49d67d3fd8772418185cd32159d069404f51883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e35587",
-    "server_login_state": "b67f664a1f04cc9878e057d9ae5b7598756827cab0e19847623b259dd3969b674c72c57709c8d9f4efed32b08263f9f89f2021f9eccf2f3510136ba9fde1bfd0736c554f0c35c007209e4e1c9fed864a7489fdf84f1e9ef9427b1b755cfb14afcfd6ce9b11a720b7200d64aa95f47555bc0136d13c0f91bf8c7b506ceac976aba9a6e895e1519547237efbd01043245e3c5a3b96b53002b5183be14d8f76a6f105a74f312d70f6b5f0111acd44591a57a9d2dc04fc954fab11796ea2165ffb47f7b9c9c85973f3a4156f659936fcc145a900da46caddc62247e45e820c712d08a66370b58e1ba0c23380d33c5091675bee143e920c5ed44b0341e5c542a158248b1604969b26d7075869aeb6171cd7283196dcb1d17a5e46777d3fe27cce59a82fa0b42182aa1a54b936f896391a214feb3064454c417094ac536c1ae6cce3961a4ad594101b01fce2bf0872569f68508f4b0000c1a33db569f0f532fee18be50240cfa07cc1724515220a80a54038838ffe376a7fc244bc25641991069fe061ea933fd26a3e3783303ef8c67159a040d673560992cfa06bb9e141ce07521fa1618d9796a9d621ade8a202a238fdd179b4d16a0d8401db067adddb97867645552422b244c10f80c592b443b0da891d9716008225d0ca24e2a5886cf5b0c6ab7aff359ac3507e5df6554263b7f082164407b1e1e50430c96c91519f9bb249bc4102c86cc8198179e35c8513fa14c988cecb08610f81acc188147c0103d8109e537bc132c7bfb581a1ee58c71838aaa00b5aa28938f911bd589a41d7a34849c9a8b0c47f01d870242a15f6aa6e5b238fe2cc988e51453b269b748522e819befc1a26d78936bd12cca0250319f7be2a24837f83626e0bb8d95b2048ec8d49430cdf5abd0388b18adb07b446c176d010198b6354110e20d80a3d3a969c938b5ba531a3fc777233931daa1059771651a91caa5b3a8991a20652828010408d89cbbe0308cbc41fdfeb2dfe280a51950699b0bc9003bfaaf9c8c7352ef58097b8c9432f8b72f94021c8c5557f1c0f8da72cb24346a91a8df5276820da6401065e54c2ce9738cdb800b814273ec401cfeb223d05d4b8347678523889b6082c336c339bacc8c1c19a6858bc15090428b40ebbe16682d47666d9bcdad3c3a7aa2ebc55311bd0baf0447cd80c974bec8c6301194015537baa9350acb5b79c7e26d9a75a5c916a50bb699468ae959ce6082b74380498094160c7af3a90ac8888c1595b0b131a28249a9a7bf23f8011215b903ee7f927a1751a7a0aa34bca05e9764ab7a34e24b3c4a576169ba851dfc198797027b569ba17c8c7176c2b0bf399656a4d8dca3d6b8a382baa81a01a57975b16ef3059ccc85b6910890b59660491932e46210390a1ddf507e70163fdc7a459852888781d544b87f75c0ad2796cfa62333c3921804c1724fa396579a1dd0b2ced631b2991386c2716302a7d6167737ee750b1f928f61785f733a1bf2b65c47b6e76834252a9c1f486407467a06653a2d7ca775e359256f6187e2c05e8d8604af3258edc4fedfbc430153a9519b5d78703ab669779d98baa011210f48842421dda34296515610331b936d34f7f1961d71b689cdba840a51dcc5246b546483526a66613cdeae04e67818ea7e17211786394a75395a15ecab78211d24ceacc8ecd066a1bd89500ad00c6b67ceee86edad406684467a5bb55e39c6167b8b306bcb5c4bcb8d85836afb00bf940bf3d8b950740116e44b4266556e717acdd16e13688b69d8c04e2136b70f682064eac3c078a56c39268dd0159abf02a2a52470f2f6bf1d41c2b1b6f756eb6a38b3190c9640f774a14be105e2a62046d2b46afc8f21af0447607497949a67783cc273d3e1caaff537c38f5c500fee8348975403868cca9888f26f2494c6e3df297ae53ce1b3482e2fa8ec9a8a01356268a5e82400cfb29b5f8b139df254f1b06cb3ee7dbf02dc1e16b87d052dedcf9f03e37d895bc4a5224bfb4b027a8295fe043edea775990f9c5c2901543082e5c408f2a047730fd089400e9bd5bdd5ffe9e495add1e038c0ff8cece217ce825734d9bf24982d4ce373acad113069c1ef4f8cadc77f531ea6377b516bba31d0646a6a912ed4f49703d38841d1f0c61871901c68672e6da5c00f58eeb2fcc1b62a52c513a50078c4abd985af5b714482591186870fa878da7fdcd23e648a5c6fde973baaa5220a114ce14c60e9f26a67a4a0a8d40841a0d61e8a5783a63c556ada044f88e94baf7a94ad4fdf098f72ad3eb8031e7d130a09864654d877e98c3506ec1d76637d351c9b6e7a7a55d4285893474b822bf795916931ff7ed49bad6ea7f7bddabcfdd0ba22bfb92f73c92e884bea7c534195c4e0d45a24ab6d57eb555780f26cb275d92d769c4a42824185e5bccb908064aeab96814dc68d9d1e24a307b533ce2b7a7bce9fd1e0cc2b4c2ccb94d9383f0a5580996e029e2367ba8ff951f51477b51596ee6043e3081c54559956ac92b26a0513dca7da792350bd0a5a3ecd976a5f886ff4a7a884a58330c2f4c3ff27d6c91cc966a2480a731502cf5dae11dabf0ea599f2e8fc45fbabcf381e5c4582f76cd10948bc9ad96bb47b9b162c1b4741ea9a342c1cd322048f3537fda5652383d1c2bacfa2d172de5645ec8e066d3b19ea626853965da4792f9278e0a315c8bb2edfa9e61f3f3713247ff53327c3902787e0e634b670eb065d9cc15e4217e9d9c608bb9094d9027e941a0bc8221b62f1a2ea2062ce0dfcb52b9e7518f3f291a01c169b34975e2636e226f80bc1a3b0e9d062a4fbb6e18cccaa848aee21f112408990faa280c82cd81baf1a3a84640b594fe8c630b6286e14959cd7de7d41c1a8ba3238956118af1139f7cf8661c5822aa9a5f02688767b793e181127ecfbfb6ee101ea510e51a9ba1e03f6862c205459fd2b70aedcfa1069d1c741f2aea80c85b531c3353ddb2c27ba8c81dc604fd433dfb6e72581ab714da86db656b3c83f6b4ff5b10bd5dad4b41a479c9d384baab8430179b79dce3cbdc09061ccae6c28eb32ad96bee7f164d6c7de7e343fbdc741a0065e386cc97f1ae18ab9ee91ef3d4e378ef5f0b97fda4cce06ecd5332adfbf9d8a9a3f85bca268316622d408440d58754efdc2a5fdbdc15448a8358ded2fedb63815069597c0cb0efda4c8286fdeeed2e966fcd0fb309cb2ff94fb2f1acd894cd257dcef7aaf8d4f6ea3492a68794f7e40cfb06f0f9d1e21f156948bf0c1eddb0ac491aecfc887549e649b8da0baf6873982f9b4f8af4e907ed0e58c02ebb2296fe198a3d0783b4992a35b43d1fae08f53241983370cf91c0",
+    "server_login_state": "b67f664a1f04cc9878e057d9ae5b7598756827cab0e19847623b259dd3969b674c72c57709c8d9f4efed32b08263f9f8334a1ba215af7aa261045af50bf27dbd0d6032a95dfdb77c7a191c08872e8fbbbcdef871fe37d748318d6586e804cf729f2021f9eccf2f3510136ba9fde1bfd0736c554f0c35c007209e4e1c9fed864a7489fdf84f1e9ef9427b1b755cfb14afcfd6ce9b11a720b7200d64aa95f47555bc0136d13c0f91bf8c7b506ceac976aba9a6e895e1519547237efbd01043245e3c5a3b96b53002b5183be14d8f76a6f105a74f312d70f6b5f0111acd44591a57a9d2dc04fc954fab11796ea2165ffb47f7b9c9c85973f3a4156f659936fcc145a900da46caddc62247e45e820c712d08a66370b58e1ba0c23380d33c5091675bee143e920c5ed44b0341e5c542a158248b1604969b26d7075869aeb6171cd7283196dcb1d17a5e46777d3fe27cce59a82fa0b42182aa1a54b936f896391a214feb3064454c417094ac536c1ae6cce3961a4ad594101b01fce2bf0872569f68508f4b0000c1a33db569f0f532fee18be50240cfa07cc1724515220a80a54038838ffe376a7fc244bc25641991069fe061ea933fd26a3e3783303ef8c67159a040d673560992cfa06bb9e141ce07521fa1618d9796a9d621ade8a202a238fdd179b4d16a0d8401db067adddb97867645552422b244c10f80c592b443b0da891d9716008225d0ca24e2a5886cf5b0c6ab7aff359ac3507e5df6554263b7f082164407b1e1e50430c96c91519f9bb249bc4102c86cc8198179e35c8513fa14c988cecb08610f81acc188147c0103d8109e537bc132c7bfb581a1ee58c71838aaa00b5aa28938f911bd589a41d7a34849c9a8b0c47f01d870242a15f6aa6e5b238fe2cc988e51453b269b748522e819befc1a26d78936bd12cca0250319f7be2a24837f83626e0bb8d95b2048ec8d49430cdf5abd0388b18adb07b446c176d010198b6354110e20d80a3d3a969c938b5ba531a3fc777233931daa1059771651a91caa5b3a8991a20652828010408d89cbbe0308cbc41fdfeb2dfe280a51950699b0bc9003bfaaf9c8c7352ef58097b8c9432f8b72f94021c8c5557f1c0f8da72cb24346a91a8df5276820da6401065e54c2ce9738cdb800b814273ec401cfeb223d05d4b8347678523889b6082c336c339bacc8c1c19a6858bc15090428b40ebbe16682d47666d9bcdad3c3a7aa2ebc55311bd0baf0447cd80c974bec8c6301194015537baa9350acb5b79c7e26d9a75a5c916a50bb699468ae959ce6082b74380498094160c7af3a90ac8888c1595b0b131a28249a9a7bf23f8011215b903ee7f927a1751a7a0aa34bca05e9764ab7a34e24b3c4a576169ba851dfc198797027b569ba17c8c7176c2b0bf399656a4d8dca3d6b8a382baa81a01a57975b16ef3059ccc85b6910890b59660491932e46210390a1ddf507e70163fdc7a459852888781d544b87f75c0ad2796cfa62333c3921804c1724fa396579a1dd0b2ced631b2991386c2716302a7d6167737ee750b1f928f61785f733a1bf2b65c47b6e76834252a9c1f486407467a06653a2d7ca775e359256f6187e2c05e8d8604af3258edc4fedfbc430153a9519b5d78703ab669779d98baa011210f48842421dda34296515610331b936d34f7f1961d71b689cdba840a51dcc5246b546483526a66613cdeae04e67818ea7e17211786394a75395a15ecab78211d24ceacc8ecd066a1bd89500ad00c6b67ceee86edad406684467a5bb55e39c6167b8b306bcb5c4bcb8d85836afb00bf940bf3d8b950740116e44b4266556e717acdd16e13688b69d8c04e2136b70f682064eac3c078a56c39268dd0159abf02a2a52470f2f6bf1d41c2b1b6f756eb6a38b3190c9640f774a14be105e2a62046d2b46afc8f21af0447607497949a67783cc273d3e1caaff537c38f5c500fee8348975403868cca9888f26f2494c6e3df297ae53ce1b3482e2fa8ec9a8a01356268a5e82400cfb29b5f8b139df254f1b06cb3ee7dbf02dc1e16b87d052dedcf9f03e37d895bc4a5224bfb4b027a8295fe043edea775990f9c5c2901543082e5c408f2a047730fd089400e9bd5bdd5ffe9e495add1e038c0ff8cece217ce825734d9bf24982d4ce373acad113069c1ef4f8cadc77f531ea6377b516bba31d0646a6a912ed4f49703d38841d1f0c61871901c68672e6da5c00f58eeb2fcc1b62a52c513a50078c4abd985af5b714482591186870fa878da7fdcd23e648a5c6fde973baaa5220a114ce14c60e9f26a67a4a0a8d40841a0d61e8a5783a63c556ada044f88e94baf7a94ad4fdf098f72ad3eb8031e7d130a09864654d877e98c3506ec1d76637d351c9b6e7a7a55d4285893474b822bf795916931ff7ed49bad6ea7f7bddabcfdd0ba22bfb92f73c92e884bea7c534195c4e0d45a24ab6d57eb555780f26cb275d92d769c4a42824185e5bccb908064aeab96814dc68d9d1e24a307b533ce2b7a7bce9fd1e0cc2b4c2ccb94d9383f0a5580996e029e2367ba8ff951f51477b51596ee6043e3081c54559956ac92b26a0513dca7da792350bd0a5a3ecd976a5f886ff4a7a884a58330c2f4c3ff27d6c91cc966a2480a731502cf5dae11dabf0ea599f2e8fc45fbabcf381e5c4582f76cd10948bc9ad96bb47b9b162c1b4741ea9a342c1cd322048f3537fda5652383d1c2bacfa2d172de5645ec8e066d3b19ea626853965da4792f9278e0a315c8bb2edfa9e61f3f3713247ff53327c3902787e0e634b670eb065d9cc15e4217e9d9c608bb9094d9027e941a0bc8221b62f1a2ea2062ce0dfcb52b9e7518f3f291a01c169b34975e2636e226f80bc1a3b0e9d062a4fbb6e18cccaa848aee21f112408990faa280c82cd81baf1a3a84640b594fe8c630b6286e14959cd7de7d41c1a8ba3238956118af1139f7cf8661c5822aa9a5f02688767b793e181127ecfbfb6ee101ea510e51a9ba1e03f6862c205459fd2b70aedcfa1069d1c741f2aea80c85b531c3353ddb2c27ba8c81dc604fd433dfb6e72581ab714da86db656b3c83f6b4ff5b10bd5dad4b41a479c9d384baab8430179b79dce3cbdc09061ccae6c28eb32ad96bee7f164d6c7de7e343fbdc741a0065e386cc97f1ae18ab9ee91ef3d4e378ef5f0b97fda4cce06ecd5332adfbf9d8a9a3f85bca268316622d408440d58754efdc2a5fdbdc15448a8358ded2fedb63815069597c0cb0efda4c8286fdeeed2e966fcd0fb309cb2ff94fb2f1acd894cd257dcef7aaf8d4f6ea3492a68794f7e40cfb06f0f9d1e21f156948bf0c1eddb0ac491aecfc887549e649b8da0baf6873982f9b4f8af4e907ed0e58c02ebb2296fe198a3d0783b4992a35b43d1fae08f53241983370cf91c0",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "b67f664a1f04cc9878e057d9ae5b7598756827cab0e19847623b259dd3969b674c72c57709c8d9f4efed32b08263f9f8"
@@ -1234,7 +1395,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_KEM_P384: &str = r#"
     "credential_finalization": "5819a28c47d01d3271caaf6893973174cab362b1fba4870d2d4f865342061676b3fe333dd33c0aefd4b7d489a1237b55",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b835295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a862b28d8e1b2e6b6c7c4870d03d71f40b65a736203ed6216a1ca070a0c3d75ec75973b54f6a236ea967f552b264bc53f2e57a90ff6340700c333bb560261a67a67cbbe1032cb58304d550326896583e112078229ebf8977674c5f790b208c37cb3a13cb63b35edf082c9f0ab4c9949b3da2decd01df10867be816dbb32aa392bc797b037a0d463299b87f241abc54530fce00d7af479a2b60c0da08c07d3baf57892d4da18496172adc85ab80184078c7b51c20bf703028aca83585a4a806736278a3d765c640c0cae54fc36f4694149961202e3bcab769ce6dc88be13b331fc8c6e03066cc92680bb5ec4292766a5827737a197940676fcbc79e2aaf527a59f640b52949a1d52cccbb220f73aabf8493c05b32736284c97a0c910146f41874692968a220739037a3bbed45b5ad43d5c9013356bbb0eec1b562664844aa6141793df4b8f851b03b17c91e1574c2a2153589a5813566012d6313782cc0b94995ba79a75bb1c855a3d3571abe067ab507051d2ab5b343b0903d410ee801cb4310da430609a858073311e5015cc3b80ad06a63a9301475dd1004526a3d257b51327b1c38824aea132454426b5e1871eca6375726ce72a2c6b9c31b7eb07961c4328e47cdf5b05f1076cacb5ac9b516ae2218680930d66cb32c8865a1f03a6afd9ba65e3284ec56f78ca30aba62ecc02066ec23afe2826d0bca4a2477510e7a8ada4213303cbd61c2c47f1a371c2a159b389bf7544f607abee2a8dcef08583765f016784f86169fb2bc0018a0c3db72aa8dc1111040420d76bfc290aa4e350b76b23f736610f99c9196a3b30bab3b284825e3c963f548105a459bcc16ceefa5f5b3c7a13a3173ad57520e847196272f2fcc6983b8157d47dfa814bf569bd89652bb5a46d353791b6a0a961b516b0c631fa8542ef10a4fe03aa7d43419dc09951dc798d4929e1037f46f980c43c684dd95a9ac691ee3a98bb8b6f1f575c818314fb5a68c2898b8b69561ba134633aa50fc6964231b5cee273ca0c29fc830a196ab9873278101243b26a330ab806e3e88d42b15232917a98a033f796b6e5c92f7d36ba257623491b44d0171b079a1191491e1d4549b8b87177b7cc427c4e39061df61190313220c611054d0bbbd9e16674b5c84e83ce65316027105dfb667916f1caad32c9c066ba363aba111a6811d41d7862a45ed41b7e54cef3fa3c08b0a297e24bd6436e8f8492ccf7ae99613a604570e7016ac9a3a8b930245704659959afff2bad4c95a6e30b910023be629a08d6ca6f23877808b0a317fc65de1b990b07640adb5b124bc631d82ab7e1b9f6494fe8a039dbb5c6b6b66c0cd54dcb2016944c2b9e46bbb3a8acd190a3356377c9285041081b5f959e13586758d036c40aa40bf40ec6f01246bcaf492b5f289979587a69ca0cb889aa5524c3bf0b503b2ae4970ecc66bc0c7b1aa7ca0a20b2949cac4fb561cfcc4c76433e58eb284a86368da96872492fcc50277742205654b7bcb624287c8cd13400ef876498c1c7e30991e7753db219cafdd62c07c89f7eb1734d4011150b1841271d6d648144fcaaed2745e30948680a54cd83ab91f3ad102cc1bac8a43859379a3c128f572589ccca085718a7e4cd0ef8cf8c157573e58f060f3f25546ca5e19f31959b9b2bfc77db7d686c3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c05295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98ef432644d90cf1c98b44338379573b7591a3deee6a1285410a34cb92b334995eb5edee767586b02fa50bdbb831cd5f7a996f10773384e89e538b5286d13db15e32433f68512c5534fb027812f22665684c79298b4566a714ed1a317068cee2b5f85352fc0ab8bbcf24ee4f4bb4fc8a6ca5406136a0dc141cc3d83849fb573068c15bec93da89bb86645cb4f38ad35b624847b95644442daebc423a0784386100bd7cc39972170656a6c35514f6c13cb60ccebb20402d5121cda0f4d034273eaa9a2d4c031d115d44c60e03a7ecb76c19bf1ce405a236da1be768a5b6f24cb556ca030eb495a5301e53173dfd3a4a022a0cf66400fb66f0db128558419910247e4a7bc23cb030d09860f774a0c477df529527e599b546c3f2db5a114d7558fd87769977a6e10068c00ca46b86edfd491b3f806e7e77eeb4240c06c596325775a1ccee93b5901024c3cc19d00b90a2d7754f78c1741d1b0b64931c3f72d236c4835cac50bea4f3797c4b5989114502180d0280ea6529f8b4b3b91a28daa8aa5f71bd7f8a9dbc99e80c08ac0c1bfcf7b3f6e919eb316009d0a09092c4d170b0131d66f44298919f210578478b8c32081db011d7794c3e41ca52585aa180ad502cb54a42c4c1a5cdda29240da2bbf95517643727bfcc9087214d94bc2e61a61bbeb3037812ecee67612bc81932329dd4b492fbc6bd7db93ec5b21e0480a3a76176cfc8291a7a18c4a69907667c3b26ffa021d816230ec77184be7a79c8ba3e5e2aef601a87cb9c145b91ec595b521b35d8d133f3b063a8e803264bc86d374ac464cbee889c911cbb15646abe3e11b9bb79aa0b3c5a1e1ccff45b5a4e9cb1fa27412ec7001913ca3167888aa896b57a61936ac8810204b87037c9a93203c369b947ff6fcb343e2a8bdab6c5b28c277e1983da07a552373e3d7143e205664b9c214a12ac1b19fccc01123eb929090b161e208f4055ffcaac88c4462fbfb42aebc41178999176b54d84bc0006750a3a7676b47abf03076c16b777207509e627ef4604f64f94230a40871ebc084817a72f0596c24267bd70af5454f18a75d20873b53e2a9e9b066df9456ca2c91ab1a0a4efb3713cb8eaceb196a42cd9e05cf91bb460cd869f927c0387aa8d7f52eb0f8229ad67681bb1f16bc8917ca14b0100364aa1cd8454943908067f38baff01220c17a4771c7ae20c958e58c36e34c04377cef987af54b3f1098482be8ab4980cf4b26a29b431769b4954b6b4f1a3cc6bbdc8a3a30b391a891399406e027462947463ad8315ba00fabd089fb02b756a800f00777b7094b7b8b545c9c763b2167cc860c9c649c8468b1f7ca79ba2a876f063d4f05b3333a8681517814c5703687272a2988275c6e42906318766daee59faec1629bd3902e051c98acaa83f72d9c91a6b891043675a025ebcf0ef31c521c69955bbfa53a502595c4c74905e362968841a5e9ebb9f7c9119ce92dc0a7bc5f27a3e4877c2d15cc848a9e83b37ad29454afc1900e978979db98b5c77079377701f138d894a712f83a48470ec53536ac4285f3346e5f11265f55169c104dd128829a264897645535a3ad31a523640849f32282a76108918350d8f8432babc1d8f5c7a7f537862b28d8e1b2e6b6c7c4870d03d71f40b65a736203ed6216a1ca070a0c3d75ec75973b54f6a236ea967f552b264bc53f2e57a90ff6340700c333bb560261a67a67cbbe1032cb58304d550326896583e112078229ebf8977674c5f790b208c37cb3a13cb63b35edf082c9f0ab4c9949b3da2decd01df10867be816dbb32aa392bc797b037a0d463299b87f241abc54530fce00d7af479a2b60c0da08c07d3baf57892d4da18496172adc85ab80184078c7b51c20bf703028aca83585a4a806736278a3d765c640c0cae54fc36f4694149961202e3bcab769ce6dc88be13b331fc8c6e03066cc92680bb5ec4292766a5827737a197940676fcbc79e2aaf527a59f640b52949a1d52cccbb220f73aabf8493c05b32736284c97a0c910146f41874692968a220739037a3bbed45b5ad43d5c9013356bbb0eec1b562664844aa6141793df4b8f851b03b17c91e1574c2a2153589a5813566012d6313782cc0b94995ba79a75bb1c855a3d3571abe067ab507051d2ab5b343b0903d410ee801cb4310da430609a858073311e5015cc3b80ad06a63a9301475dd1004526a3d257b51327b1c38824aea132454426b5e1871eca6375726ce72a2c6b9c31b7eb07961c4328e47cdf5b05f1076cacb5ac9b516ae2218680930d66cb32c8865a1f03a6afd9ba65e3284ec56f78ca30aba62ecc02066ec23afe2826d0bca4a2477510e7a8ada4213303cbd61c2c47f1a371c2a159b389bf7544f607abee2a8dcef08583765f016784f86169fb2bc0018a0c3db72aa8dc1111040420d76bfc290aa4e350b76b23f736610f99c9196a3b30bab3b284825e3c963f548105a459bcc16ceefa5f5b3c7a13a3173ad57520e847196272f2fcc6983b8157d47dfa814bf569bd89652bb5a46d353791b6a0a961b516b0c631fa8542ef10a4fe03aa7d43419dc09951dc798d4929e1037f46f980c43c684dd95a9ac691ee3a98bb8b6f1f575c818314fb5a68c2898b8b69561ba134633aa50fc6964231b5cee273ca0c29fc830a196ab9873278101243b26a330ab806e3e88d42b15232917a98a033f796b6e5c92f7d36ba257623491b44d0171b079a1191491e1d4549b8b87177b7cc427c4e39061df61190313220c611054d0bbbd9e16674b5c84e83ce65316027105dfb667916f1caad32c9c066ba363aba111a6811d41d7862a45ed41b7e54cef3fa3c08b0a297e24bd6436e8f8492ccf7ae99613a604570e7016ac9a3a8b930245704659959afff2bad4c95a6e30b910023be629a08d6ca6f23877808b0a317fc65de1b990b07640adb5b124bc631d82ab7e1b9f6494fe8a039dbb5c6b6b66c0cd54dcb2016944c2b9e46bbb3a8acd190a3356377c9285041081b5f959e13586758d036c40aa40bf40ec6f01246bcaf492b5f289979587a69ca0cb889aa5524c3bf0b503b2ae4970ecc66bc0c7b1aa7ca0a20b2949cac4fb561cfcc4c76433e58eb284a86368da96872492fcc50277742205654b7bcb624287c8cd13400ef876498c1c7e30991e7753db219cafdd62c07c89f7eb1734d4011150b1841271d6d648144fcaaed2745e30948680a54cd83ab91f3ad102cc1bac8a43859379a3c128f572589ccca085718a7e4cd0ef8cf8c157573e58f060f3f25546ca5e19f31959b9b2bfc77db7d686c9fbee0e9c73c94bfb05a11d81e3e390e00ed9db63c6d8501855323992f30282d6a1f10ae17d863e90b5f0e82d4a37035883ac3fe2bb01246f4c0b2b29f4c21f9",
-    "server_login_state": "92bdf837d09b411f74e5d8cfcf5f337014b6f1068357a4f2218dc7837b68fe55c2013e85c2c4907894c9a5e55cce238e5819a28c47d01d3271caaf6893973174cab362b1fba4870d2d4f865342061676b3fe333dd33c0aefd4b7d489a1237b55862b28d8e1b2e6b6c7c4870d03d71f40b65a736203ed6216a1ca070a0c3d75ec75973b54f6a236ea967f552b264bc53f2e57a90ff6340700c333bb560261a67a67cbbe1032cb58304d550326896583e112078229ebf8977674c5f790b208c37cb3a13cb63b35edf082c9f0ab4c9949b3da2decd01df10867be816dbb32aa392bc797b037a0d463299b87f241abc54530fce00d7af479a2b60c0da08c07d3baf57892d4da18496172adc85ab80184078c7b51c20bf703028aca83585a4a806736278a3d765c640c0cae54fc36f4694149961202e3bcab769ce6dc88be13b331fc8c6e03066cc92680bb5ec4292766a5827737a197940676fcbc79e2aaf527a59f640b52949a1d52cccbb220f73aabf8493c05b32736284c97a0c910146f41874692968a220739037a3bbed45b5ad43d5c9013356bbb0eec1b562664844aa6141793df4b8f851b03b17c91e1574c2a2153589a5813566012d6313782cc0b94995ba79a75bb1c855a3d3571abe067ab507051d2ab5b343b0903d410ee801cb4310da430609a858073311e5015cc3b80ad06a63a9301475dd1004526a3d257b51327b1c38824aea132454426b5e1871eca6375726ce72a2c6b9c31b7eb07961c4328e47cdf5b05f1076cacb5ac9b516ae2218680930d66cb32c8865a1f03a6afd9ba65e3284ec56f78ca30aba62ecc02066ec23afe2826d0bca4a2477510e7a8ada4213303cbd61c2c47f1a371c2a159b389bf7544f607abee2a8dcef08583765f016784f86169fb2bc0018a0c3db72aa8dc1111040420d76bfc290aa4e350b76b23f736610f99c9196a3b30bab3b284825e3c963f548105a459bcc16ceefa5f5b3c7a13a3173ad57520e847196272f2fcc6983b8157d47dfa814bf569bd89652bb5a46d353791b6a0a961b516b0c631fa8542ef10a4fe03aa7d43419dc09951dc798d4929e1037f46f980c43c684dd95a9ac691ee3a98bb8b6f1f575c818314fb5a68c2898b8b69561ba134633aa50fc6964231b5cee273ca0c29fc830a196ab9873278101243b26a330ab806e3e88d42b15232917a98a033f796b6e5c92f7d36ba257623491b44d0171b079a1191491e1d4549b8b87177b7cc427c4e39061df61190313220c611054d0bbbd9e16674b5c84e83ce65316027105dfb667916f1caad32c9c066ba363aba111a6811d41d7862a45ed41b7e54cef3fa3c08b0a297e24bd6436e8f8492ccf7ae99613a604570e7016ac9a3a8b930245704659959afff2bad4c95a6e30b910023be629a08d6ca6f23877808b0a317fc65de1b990b07640adb5b124bc631d82ab7e1b9f6494fe8a039dbb5c6b6b66c0cd54dcb2016944c2b9e46bbb3a8acd190a3356377c9285041081b5f959e13586758d036c40aa40bf40ec6f01246bcaf492b5f289979587a69ca0cb889aa5524c3bf0b503b2ae4970ecc66bc0c7b1aa7ca0a20b2949cac4fb561cfcc4c76433e58eb284a86368da96872492fcc50277742205654b7bcb624287c8cd13400ef876498c1c7e30991e7753db219cafdd62c07c89f7eb1734d4011150b1841271d6d648144fcaaed2745e30948680a54cd83ab91f3ad102cc1bac8a43859379a3c128f572589ccca085718a7e4cd0ef8cf8c157573e58f060f3f25546ca5e19f31959b9b2bfc77db7d686c687bef14177a7ae8adda3d39ece25557fd11f3e3c0c7858736462ba2ed505d4c8f6cbfacca43df717638a048a4f2a75bb0e67a1a7fad9fc35c0a611fe9d87e3048f9ce636703c9bca8877f016d9175c8205cfa8521ba8f1cbb2e0db886f50a84f3860410cac1059c762ef159823fadac92b02a418822c4713a1146a5f2a05fc57b52d32af468d969802b30596edc43279fe8166f9b210646c34e552bbb1e4ec0d5881c73c2363d687f4d05406c45ec88387f118ef3fef9109ecabbe09d59fb585f4dd0de6b426a7b6e5c2324f23bb119b196c9e3edb5e951fa4a6d6e6d89398c29190e7c14da898ce8d0e06f21964b162d85b0d6c0fe83c9f212bf3bd08d9af731637a13b776144dea2831286f9a5092c763eaa82191a0af06012417727440e36343dcaa6f2514ca5586cb472c0b83be38cc11454dc7759e661653f0e446c13b885877b21584192d87b8f49bcae01646297ffd91e643579b5d70fdf0567a3d902fe678cdb3ab5f2c5fa0a6b51ceae7961a69214e3a6ee6fea5ec564137067cdbf516c5e7439ba9c5e3cc3e1a303a048703416c0a68c440d9f0e8f394c2d972fb7f7a22d5f76abe2d7bfa6cbda3bee59e186faa0b8946e52b407b9d58554efcd01b4ea5a51d9f331fd602fe383d0659b24a9cd5dd55e1dd5a929d10d163d32fcf017f63cf441d4d33095c896efccf15d83a7ce82c8689f2c3ce7c1eced727c892fe3e0840aa0a41cd783d58ad213c84c0903f6d5666107a56df53ae90053bb67e37fa6ef8bafbc697c410b88e811b82806a25d0d0d37ffb11acfc4ec820c54bc75401a63f1ae4af15e99d6a97cd0893061d50f05797de0b8c2a3f6929aae286c31b2265bbe0c36c304a1c16c92d5396c224b314d478a90eef94a7ba4506432a5325b638fc33c01513e583d40b93505dc3e9f435a26665212416974db46a5098c449253b1a7d97e040753bc47a547dd0906ed547b77dc5bad8559b07b7e7986e1166b73ac35af1b5c55ad1c79c168b89fc7f39b5552605f6249bc8860142ab4df2480425d537282b6b6e06211975bf9f1992a075dca94f474b0ca6532c7265170d659dac23d01eb04e33e7c6b17879397239f28cfba4ae71cf318586a3dabbf0de151a3f0e5cd41dedc89ffde4392067aa5c19c85709b1dc1d8784ed703de0c457a710e9cf4b21af4a87cf19a4af0429c77727614a94b15013de03073fa0d8582f86dd30c4d6b116b2298c4cfb10ad33f42672ff9a3cf72992a647e21a1fc0f59845b3f3e656cf96b240d3ab7fea3fbe751213820b8aee7aa528ebadc5ece10243da651b30b262e8a3b64abffa690effaf3342aa7f69e2727b82cd9422c59b07cb6dea471c9bab906babe7a1ebf9e8cdf85bddbe94ad09cfe99a4fce790381507445167bf13633147db2cc6d77b076769b8361d97870d1919166264639e46b60e7a177c0c5db5c163710dc80cf2c47e4ad9a508d4b4c399ac3cd4b9580af45c7443ca094d1e9a3f49b5bb982f00d08104b019136c4ca64f6c1af7457ff008010ad",
+    "server_login_state": "92bdf837d09b411f74e5d8cfcf5f337014b6f1068357a4f2218dc7837b68fe55c2013e85c2c4907894c9a5e55cce238ecd6fb6a1dd5496b9157358b857fd05039fd1d892d6a3a4a0dde527d9f60a33b0effdeb3caf710e0ea0041d3c5c9f2d055819a28c47d01d3271caaf6893973174cab362b1fba4870d2d4f865342061676b3fe333dd33c0aefd4b7d489a1237b55862b28d8e1b2e6b6c7c4870d03d71f40b65a736203ed6216a1ca070a0c3d75ec75973b54f6a236ea967f552b264bc53f2e57a90ff6340700c333bb560261a67a67cbbe1032cb58304d550326896583e112078229ebf8977674c5f790b208c37cb3a13cb63b35edf082c9f0ab4c9949b3da2decd01df10867be816dbb32aa392bc797b037a0d463299b87f241abc54530fce00d7af479a2b60c0da08c07d3baf57892d4da18496172adc85ab80184078c7b51c20bf703028aca83585a4a806736278a3d765c640c0cae54fc36f4694149961202e3bcab769ce6dc88be13b331fc8c6e03066cc92680bb5ec4292766a5827737a197940676fcbc79e2aaf527a59f640b52949a1d52cccbb220f73aabf8493c05b32736284c97a0c910146f41874692968a220739037a3bbed45b5ad43d5c9013356bbb0eec1b562664844aa6141793df4b8f851b03b17c91e1574c2a2153589a5813566012d6313782cc0b94995ba79a75bb1c855a3d3571abe067ab507051d2ab5b343b0903d410ee801cb4310da430609a858073311e5015cc3b80ad06a63a9301475dd1004526a3d257b51327b1c38824aea132454426b5e1871eca6375726ce72a2c6b9c31b7eb07961c4328e47cdf5b05f1076cacb5ac9b516ae2218680930d66cb32c8865a1f03a6afd9ba65e3284ec56f78ca30aba62ecc02066ec23afe2826d0bca4a2477510e7a8ada4213303cbd61c2c47f1a371c2a159b389bf7544f607abee2a8dcef08583765f016784f86169fb2bc0018a0c3db72aa8dc1111040420d76bfc290aa4e350b76b23f736610f99c9196a3b30bab3b284825e3c963f548105a459bcc16ceefa5f5b3c7a13a3173ad57520e847196272f2fcc6983b8157d47dfa814bf569bd89652bb5a46d353791b6a0a961b516b0c631fa8542ef10a4fe03aa7d43419dc09951dc798d4929e1037f46f980c43c684dd95a9ac691ee3a98bb8b6f1f575c818314fb5a68c2898b8b69561ba134633aa50fc6964231b5cee273ca0c29fc830a196ab9873278101243b26a330ab806e3e88d42b15232917a98a033f796b6e5c92f7d36ba257623491b44d0171b079a1191491e1d4549b8b87177b7cc427c4e39061df61190313220c611054d0bbbd9e16674b5c84e83ce65316027105dfb667916f1caad32c9c066ba363aba111a6811d41d7862a45ed41b7e54cef3fa3c08b0a297e24bd6436e8f8492ccf7ae99613a604570e7016ac9a3a8b930245704659959afff2bad4c95a6e30b910023be629a08d6ca6f23877808b0a317fc65de1b990b07640adb5b124bc631d82ab7e1b9f6494fe8a039dbb5c6b6b66c0cd54dcb2016944c2b9e46bbb3a8acd190a3356377c9285041081b5f959e13586758d036c40aa40bf40ec6f01246bcaf492b5f289979587a69ca0cb889aa5524c3bf0b503b2ae4970ecc66bc0c7b1aa7ca0a20b2949cac4fb561cfcc4c76433e58eb284a86368da96872492fcc50277742205654b7bcb624287c8cd13400ef876498c1c7e30991e7753db219cafdd62c07c89f7eb1734d4011150b1841271d6d648144fcaaed2745e30948680a54cd83ab91f3ad102cc1bac8a43859379a3c128f572589ccca085718a7e4cd0ef8cf8c157573e58f060f3f25546ca5e19f31959b9b2bfc77db7d686c687bef14177a7ae8adda3d39ece25557fd11f3e3c0c7858736462ba2ed505d4c8f6cbfacca43df717638a048a4f2a75bb0e67a1a7fad9fc35c0a611fe9d87e3048f9ce636703c9bca8877f016d9175c8205cfa8521ba8f1cbb2e0db886f50a84f3860410cac1059c762ef159823fadac92b02a418822c4713a1146a5f2a05fc57b52d32af468d969802b30596edc43279fe8166f9b210646c34e552bbb1e4ec0d5881c73c2363d687f4d05406c45ec88387f118ef3fef9109ecabbe09d59fb585f4dd0de6b426a7b6e5c2324f23bb119b196c9e3edb5e951fa4a6d6e6d89398c29190e7c14da898ce8d0e06f21964b162d85b0d6c0fe83c9f212bf3bd08d9af731637a13b776144dea2831286f9a5092c763eaa82191a0af06012417727440e36343dcaa6f2514ca5586cb472c0b83be38cc11454dc7759e661653f0e446c13b885877b21584192d87b8f49bcae01646297ffd91e643579b5d70fdf0567a3d902fe678cdb3ab5f2c5fa0a6b51ceae7961a69214e3a6ee6fea5ec564137067cdbf516c5e7439ba9c5e3cc3e1a303a048703416c0a68c440d9f0e8f394c2d972fb7f7a22d5f76abe2d7bfa6cbda3bee59e186faa0b8946e52b407b9d58554efcd01b4ea5a51d9f331fd602fe383d0659b24a9cd5dd55e1dd5a929d10d163d32fcf017f63cf441d4d33095c896efccf15d83a7ce82c8689f2c3ce7c1eced727c892fe3e0840aa0a41cd783d58ad213c84c0903f6d5666107a56df53ae90053bb67e37fa6ef8bafbc697c410b88e811b82806a25d0d0d37ffb11acfc4ec820c54bc75401a63f1ae4af15e99d6a97cd0893061d50f05797de0b8c2a3f6929aae286c31b2265bbe0c36c304a1c16c92d5396c224b314d478a90eef94a7ba4506432a5325b638fc33c01513e583d40b93505dc3e9f435a26665212416974db46a5098c449253b1a7d97e040753bc47a547dd0906ed547b77dc5bad8559b07b7e7986e1166b73ac35af1b5c55ad1c79c168b89fc7f39b5552605f6249bc8860142ab4df2480425d537282b6b6e06211975bf9f1992a075dca94f474b0ca6532c7265170d659dac23d01eb04e33e7c6b17879397239f28cfba4ae71cf318586a3dabbf0de151a3f0e5cd41dedc89ffde4392067aa5c19c85709b1dc1d8784ed703de0c457a710e9cf4b21af4a87cf19a4af0429c77727614a94b15013de03073fa0d8582f86dd30c4d6b116b2298c4cfb10ad33f42672ff9a3cf72992a647e21a1fc0f59845b3f3e656cf96b240d3ab7fea3fbe751213820b8aee7aa528ebadc5ece10243da651b30b262e8a3b64abffa690effaf3342aa7f69e2727b82cd9422c59b07cb6dea471c9bab906babe7a1ebf9e8cdf85bddbe94ad09cfe99a4fce790381507445167bf13633147db2cc6d77b076769b8361d97870d1919166264639e46b60e7a177c0c5db5c163710dc80cf2c47e4ad9a508d4b4c399ac3cd4b9580af45c7443ca094d1e9a3f49b5bb982f00d08104b019136c4ca64f6c1af7457ff008010ad",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "92bdf837d09b411f74e5d8cfcf5f337014b6f1068357a4f2218dc7837b68fe55c2013e85c2c4907894c9a5e55cce238e"
@@ -1273,7 +1434,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_KEM_P384: &str = r#"
     "credential_finalization": "dd12c4848ad648875244e0c615f46f3ce7a42de8316821da47cbbf0f22e22fd2be942dab9f03421ed1a126b14f55c3af",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b690cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015ac35b2c8b4020e1c337a1059cda5b2abd46b7a1c11334173263b5a879d5052b9c6dc5849236821dec9a93dec9a958f79322c02739abbb117755f9f80266a75427278b5032c284a347be251b7a111e08790eef147118a66a9d05b56ab600e5d959dbe4199e5a474d922bfa442acc057872e314df19ccf609243276b2636b8d11283af294224ca40fdd0ab18e8a7cd397ca3758231d916ec398cf37584ebd615887d95fc2ecaa394a144a757ed151b76bca774ea1b80c48bce42bae7efc6c9836612ae3ae656bcb5f0cbb006d32dd5951ebd0901e96ad2a50c461c90d4c3817ab6cccfc84cb3788b563969a6a26cf37cab3277a5438ca58e5769148b383fa6b6cca9a0d26c7a14793416b54298db8478a47ca09748d97a7231794ada3c629f72b8f7dd45c2c5781b9437d53e9a44727b3136ba3b7089512bb4312f4b7d29309e1e713d9fca0a3f78439a093f9a128199c2444b3371a36a367cb9312f09c6cd1156fec77a771814380bd3161554c0a26b0c6bf56eb6062468416095f8d15930cf9b4aac609a4a62ca35086c76417f87c766c799bbcb89a795496cf73a897062008231450215785025373c298f1374a97453926038966146882b07d3d8012cc4ba916bc4fbed581ce288b2e797222cb165e650063274caf682e2af26a09a97655d373795024c4c94508c8bfae8523883c77bc378a90626e1777cda227305836ca173143087250399b5fb7c38de58a2c866221d19125fa3a4f6c7c7cdc805a8cc27146e079f8d67d34316679225bc9bc5541595de7252e866c711921c1f006b1121c010cd95c70978eede93ecb8b6f8e72b02f67b3bb8c07c7742a0c910de88c0d62f963d90281ec037556d2cfcc034611536b717c0ac24265d7913f0755adcf905fbdea73fc7b7b70e74fa5611c3e44a357592d7849495e7cc39338296dc30e5119a1b627b16790263185b9e9cb69329454af0b3067123514a3c1356761d8243457332f7c07549973673a32396dd257e23b8f21204acba82ed76280bee9430de6606329461c1146a1405237f12db2305707975c8a533b2dac3da258ba116ab5dd11b2a42907dd274ea01c3400630f278c053db80a6411005609356dd78096486960bc96ff63814d5bb10999ac65001d2b9c86d2e146a53a722cd990e47c4c804aaebd106eb17c82944368dc1b972c0b6344a49d2ca73137f25fe8796d3ec223d24a52324197f8c4bb0d2ab9b3a7512c697d0da992fde16def4bcef5232db72bbead31c168c27c59c4478f304a0a149bfd10ab7e09ca79121a1fc7a7cec963d22c6cfcda4e35d2b4d9278f298854167781fc311cb698500324b184947c731439fd4a9edab044e6cb9d1aeac2723c79a4bc4a29a647bba9265da53694fac744b7ce25747238911e8b78c388980072b11466d00ce7e98dee386659b84551f6adaa228cd8e932a26ab8f34144ba126cb7e04fce145ba2e39715386fd9056ce3c861524b48ed8030676b30f46b937b71a872c6aa91c76026d081ce041957d028ce29016b9251dd4243c3860ffd57b0bda5978d5b1122181fafc89e6110281c24504fc508cca269b57438d8ab288fd2bbbe964a0a088fb911387fc84db577b2b3275a1b71a30ce5819b39b8c74ffa6e5e40accb410d77ae8817e413360272926f22b23def203295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f879523c66b9f90d38d05c4463f17048b909fbdb43fc10234e9c7708b770741f96954874a4adc92c757620af59b6575214fc46c1ad3949045769faa31807974a87cb36e1203202c822c4b9456b9305ac9250aca6fa419be54c65c977b408b518e9606b8c9f6931076b90d83b8a8f19484d76e5faa091d5c0533c67b6dcaaa3a36b4c0fca13ac4a39a8c6dc1e218448b7ce1302831ac56f9812256c59e54e3b25040ae85a061d9348454d3532e0b515a33a79ebb80b3ea2ab680c0eeeb9396f7ae209c69c17677b731773f8220cd7c0d0907436eec5599e8284145810999c428c3a21e63551c1b788e66aa1fb3b7ac12421eb559fc5a31f05c31eb0c9b1ca0b09860cfff0498018c790863298db32e78492272d5c56b3c993a1164d0c223a930b605630b35146777432079d3918ca65fd3e931fbfc300810881ed58ba685477aa75dabe0b3f682976c1c80c21b181c4c8c6e3b186745411924cb9407156741c542d5a723e9280e798a91465a86735ec5c019da274bd2a0483e38a9d96abcb08326419a7db1a05fcbf001a2cb512d199f0d19c58e77b79ebc9a0262c79ae24e29f83b00da9f6da86af905bf2c354f50133251f2c191f89b4ff5bcfad0c6fb686d04880ef5285f430b37f4ab06bb50243ce979c7c326f8f96b8b967b796440d66c3b8ea1b1a85a82f3924cd268335a6b76a173849bb3ac91c42c369283a400bacb442cf1238ba8e7abd111654882419720193bac463cc5c3335b94e84644932a27eb8ab662371a1e29ccf55755b2687d85e8a745aa46d9cb1597f2347f241bee1ba3a3dc8bfe969c850a1da9e2578d1207e1293ea5e942b6ec7f3f77ad11641791e4bc8ad7147736495e76420138a1d663588222a39ba6501c8cc25ce75c2c8b4f8d16574f194af7707335883d3f35c4ddc42cde109bc4486a9b244690677ea04a27c9ccb682720d195b6dad2628c0394eb350095db1ab5bc359b5ab4eb1435ae5ab27a0888d17b535be5a7d778879102042571454bdd8547db124bb88b70117827a17abba726605238f5065bf0905537358c530d87f88e2a6aea07ef650b5cf4617ec8a0df310849e9b1c88dcc141c53480b20656c643f76834fc8a51dce3cc75f5b5b18a526d363241f749be7a2a94f31eb8a29e9c0c217114438ecc977e2514a5faa83e06ae60a16363b820308807400766d0156bb5bc57277aa6d953aa875b9348b5bc9d2492fb7133c9200024f126bf3c7c34c38a6fa540fdf3a36b9a1da31731b111811fb9beb5f820c01c8864544f0993bd2d1c01d7c17406090a3744669898176191a3522c4604b00c17bc3c5c8996bd276f21f685df4729fe340455221b31e908b0984320c53cb592abc1468121a9a1e7744a66c772920602411b4ad1d14142c743fef32c614824b419007de459374b836d8b6aacf97f3647aa25a010e84c06dc2c7aa1e117eac0acf21996bf827df6939f22a54f6ffa716c38c2f6e7a99a87270112141d26a61a163dc2c3a9c1e31de04796b993b588e9cdcb135668d1520b1884565c49da5cb7ed52b2e6221098762e521271ff47b71c7a1bc01247fb438ca551921e4acd74e73e1aa0c952a82a4a901407947bfdc06edbcb9d0c291bc35b2c8b4020e1c337a1059cda5b2abd46b7a1c11334173263b5a879d5052b9c6dc5849236821dec9a93dec9a958f79322c02739abbb117755f9f80266a75427278b5032c284a347be251b7a111e08790eef147118a66a9d05b56ab600e5d959dbe4199e5a474d922bfa442acc057872e314df19ccf609243276b2636b8d11283af294224ca40fdd0ab18e8a7cd397ca3758231d916ec398cf37584ebd615887d95fc2ecaa394a144a757ed151b76bca774ea1b80c48bce42bae7efc6c9836612ae3ae656bcb5f0cbb006d32dd5951ebd0901e96ad2a50c461c90d4c3817ab6cccfc84cb3788b563969a6a26cf37cab3277a5438ca58e5769148b383fa6b6cca9a0d26c7a14793416b54298db8478a47ca09748d97a7231794ada3c629f72b8f7dd45c2c5781b9437d53e9a44727b3136ba3b7089512bb4312f4b7d29309e1e713d9fca0a3f78439a093f9a128199c2444b3371a36a367cb9312f09c6cd1156fec77a771814380bd3161554c0a26b0c6bf56eb6062468416095f8d15930cf9b4aac609a4a62ca35086c76417f87c766c799bbcb89a795496cf73a897062008231450215785025373c298f1374a97453926038966146882b07d3d8012cc4ba916bc4fbed581ce288b2e797222cb165e650063274caf682e2af26a09a97655d373795024c4c94508c8bfae8523883c77bc378a90626e1777cda227305836ca173143087250399b5fb7c38de58a2c866221d19125fa3a4f6c7c7cdc805a8cc27146e079f8d67d34316679225bc9bc5541595de7252e866c711921c1f006b1121c010cd95c70978eede93ecb8b6f8e72b02f67b3bb8c07c7742a0c910de88c0d62f963d90281ec037556d2cfcc034611536b717c0ac24265d7913f0755adcf905fbdea73fc7b7b70e74fa5611c3e44a357592d7849495e7cc39338296dc30e5119a1b627b16790263185b9e9cb69329454af0b3067123514a3c1356761d8243457332f7c07549973673a32396dd257e23b8f21204acba82ed76280bee9430de6606329461c1146a1405237f12db2305707975c8a533b2dac3da258ba116ab5dd11b2a42907dd274ea01c3400630f278c053db80a6411005609356dd78096486960bc96ff63814d5bb10999ac65001d2b9c86d2e146a53a722cd990e47c4c804aaebd106eb17c82944368dc1b972c0b6344a49d2ca73137f25fe8796d3ec223d24a52324197f8c4bb0d2ab9b3a7512c697d0da992fde16def4bcef5232db72bbead31c168c27c59c4478f304a0a149bfd10ab7e09ca79121a1fc7a7cec963d22c6cfcda4e35d2b4d9278f298854167781fc311cb698500324b184947c731439fd4a9edab044e6cb9d1aeac2723c79a4bc4a29a647bba9265da53694fac744b7ce25747238911e8b78c388980072b11466d00ce7e98dee386659b84551f6adaa228cd8e932a26ab8f34144ba126cb7e04fce145ba2e39715386fd9056ce3c861524b48ed8030676b30f46b937b71a872c6aa91c76026d081ce041957d028ce29016b9251dd4243c3860ffd57b0bda5978d5b1122181fafc89e6110281c24504fc508cca269b57438d8ab288fd2bbbe964a0a088fb911387fc84db577b2b3275a1b71a30ce5819b39b8c74ffa6e5e40accb410d77ae8817e413360272926f22b23def203903d51752a17f1b8cacf338ecccca9536cc6adef9aa298c18252c2381c3b9e502dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe207965539",
-    "server_login_state": "e04f69dfdb144a0b6ffdb758f956559cf48850243b25501349c6854c5a3116bd50e2e818d1861285f5637dfe8fc8b3a0dd12c4848ad648875244e0c615f46f3ce7a42de8316821da47cbbf0f22e22fd2be942dab9f03421ed1a126b14f55c3afc35b2c8b4020e1c337a1059cda5b2abd46b7a1c11334173263b5a879d5052b9c6dc5849236821dec9a93dec9a958f79322c02739abbb117755f9f80266a75427278b5032c284a347be251b7a111e08790eef147118a66a9d05b56ab600e5d959dbe4199e5a474d922bfa442acc057872e314df19ccf609243276b2636b8d11283af294224ca40fdd0ab18e8a7cd397ca3758231d916ec398cf37584ebd615887d95fc2ecaa394a144a757ed151b76bca774ea1b80c48bce42bae7efc6c9836612ae3ae656bcb5f0cbb006d32dd5951ebd0901e96ad2a50c461c90d4c3817ab6cccfc84cb3788b563969a6a26cf37cab3277a5438ca58e5769148b383fa6b6cca9a0d26c7a14793416b54298db8478a47ca09748d97a7231794ada3c629f72b8f7dd45c2c5781b9437d53e9a44727b3136ba3b7089512bb4312f4b7d29309e1e713d9fca0a3f78439a093f9a128199c2444b3371a36a367cb9312f09c6cd1156fec77a771814380bd3161554c0a26b0c6bf56eb6062468416095f8d15930cf9b4aac609a4a62ca35086c76417f87c766c799bbcb89a795496cf73a897062008231450215785025373c298f1374a97453926038966146882b07d3d8012cc4ba916bc4fbed581ce288b2e797222cb165e650063274caf682e2af26a09a97655d373795024c4c94508c8bfae8523883c77bc378a90626e1777cda227305836ca173143087250399b5fb7c38de58a2c866221d19125fa3a4f6c7c7cdc805a8cc27146e079f8d67d34316679225bc9bc5541595de7252e866c711921c1f006b1121c010cd95c70978eede93ecb8b6f8e72b02f67b3bb8c07c7742a0c910de88c0d62f963d90281ec037556d2cfcc034611536b717c0ac24265d7913f0755adcf905fbdea73fc7b7b70e74fa5611c3e44a357592d7849495e7cc39338296dc30e5119a1b627b16790263185b9e9cb69329454af0b3067123514a3c1356761d8243457332f7c07549973673a32396dd257e23b8f21204acba82ed76280bee9430de6606329461c1146a1405237f12db2305707975c8a533b2dac3da258ba116ab5dd11b2a42907dd274ea01c3400630f278c053db80a6411005609356dd78096486960bc96ff63814d5bb10999ac65001d2b9c86d2e146a53a722cd990e47c4c804aaebd106eb17c82944368dc1b972c0b6344a49d2ca73137f25fe8796d3ec223d24a52324197f8c4bb0d2ab9b3a7512c697d0da992fde16def4bcef5232db72bbead31c168c27c59c4478f304a0a149bfd10ab7e09ca79121a1fc7a7cec963d22c6cfcda4e35d2b4d9278f298854167781fc311cb698500324b184947c731439fd4a9edab044e6cb9d1aeac2723c79a4bc4a29a647bba9265da53694fac744b7ce25747238911e8b78c388980072b11466d00ce7e98dee386659b84551f6adaa228cd8e932a26ab8f34144ba126cb7e04fce145ba2e39715386fd9056ce3c861524b48ed8030676b30f46b937b71a872c6aa91c76026d081ce041957d028ce29016b9251dd4243c3860ffd57b0bda5978d5b1122181fafc89e6110281c24504fc508cca269b57438d8ab288fd2bbbe964a0a088fb911387fc84db577b2b3275a1b71a30ce5819b39b8c74ffa6e5e40accb410d77ae8817e413360272926f22b23def20e66e17b386fa77fcea9120debb819d329acf18f582ffbb07b86151f20ed8b77a7bf72ea12ba270023b9f88ea9cc9988583a8936667615396e21ebd25df58113423b57e71c918121f822312f122c63a96a2a728958dbeea96f102bb12d4729b00e4444594c036be66a16f63f8551bd583b96d0d2029b3d3554620fb825e2984824ecad24fbfcd450300be26e0768429c1c97041141617f8cc545f2cc0ff958206c67f40ea888dfd36695ecce47c0f88a8e4c86aee682f224d13afc90419c3a5c7d50d40efe650159fd570a7b4988e323800dbd4e404f37ec8d021eda32d4c74723f63af2ccef6f8328af5a42bd02a1a761cc205eff13875a97358e28729006dce363eab09a7421742f5323c5dff85fe9db3ce6f292aa5c3fe5b9eb5826e5c8359801191ce60326e8f1e9ecf5c5556e407a0bcc9788faa2cdc1c367e5b92a73a85aa8c3ea143415392de6c2096d071b373269bfbf5578311efe828e0b6f57aaf5a35ec364c5d1ee6471c8e2de6e6eacb8e65019bdcab198581e1ecc55ad3166123133717aa3b05f3b9b73f0f230d29f9a1b632b91079da4d45baebed10d2974e2f7a34e9faf10bd323ef72f6e757272e83a33cda747c272d744b0ba0e37448f2b3c596f4102e0860e7a91d9bfea4b90cab8ff7e2dd9fe64d8d67acde7fe73382c2ff0ca88891bac2e383ef62a17581b1de39b84a3748df2035268c2e48262235b5af46b8423550d2e0a85ebd10608b1f5e7d64db21200b25d73668a2354f784f9341bda3014cb1d0ad1d04ae46a826a70fe221351f08d2afe7f19dd4cefa5119f2060f69e303c8208575f768d5c8a31d2cecade2104dcadbf8d72ccc76efe891896a1878d2f12b5218638a00a2d5bbd034ebab0694a5c3c6cea1f9bec9d19b54f8895693af7b7d0153bee57526935ed67ce154a7c2b6df4186e9925bd1a6cb87d386bc0f87f260b494907542dba9cdc890660f6f66d477935359ab0369c3454db443811812b030c4d6445b04e46107ee25a90b206e7662e43c837a83cced312dd903a8f921f6a7e2efc7d4700b9f05f031779a25f6b954c12a130bfcf250d58673d9447b2e7bac4a9cd8ec999008e6b6586557741af940b6de8327ca2dc15970208ab7e1497455030f087734567f4ca6b345fccdb311e07c3bdfca081af1a5446105872f4d9185186c7b62472a86209774f7a216c0fb0699d05f0c43f5b41199dde6826bc66490d968c0ec0c5da2fa6146778629aa4d5bfb8390ee80704257449ffe9e1c8c4e316c0f7012c93a122d495e4a1c187d8a0802d30920f0ccd48c0a81a8c7622fa17d452a928f184758717870d5f07f2202654e4c9a157e85de62763bb6b9a4095a483f02e73717bdb24f26d4b5a4e936d6f8e620e9f9c94cfc8e670861e013c4ae2ecb40a2c0128c3f63bbf0ca9329b96b622960a00835dcc0eccdc9d515a2d6ea318e4c856d107193eca33d24ac1636c84c3c8b4802532f4648292a26631c94e269ad4d31e6dcf60cca7d406c997fa7a27f960acaa5e8f767e157d0",
+    "server_login_state": "e04f69dfdb144a0b6ffdb758f956559cf48850243b25501349c6854c5a3116bd50e2e818d1861285f5637dfe8fc8b3a0803cc5580111c3ae22ba774e978ed57bb99aa8ff79df681deaa6d882f2d9e61d45b4255617caadba6459fa8174b432a6dd12c4848ad648875244e0c615f46f3ce7a42de8316821da47cbbf0f22e22fd2be942dab9f03421ed1a126b14f55c3afc35b2c8b4020e1c337a1059cda5b2abd46b7a1c11334173263b5a879d5052b9c6dc5849236821dec9a93dec9a958f79322c02739abbb117755f9f80266a75427278b5032c284a347be251b7a111e08790eef147118a66a9d05b56ab600e5d959dbe4199e5a474d922bfa442acc057872e314df19ccf609243276b2636b8d11283af294224ca40fdd0ab18e8a7cd397ca3758231d916ec398cf37584ebd615887d95fc2ecaa394a144a757ed151b76bca774ea1b80c48bce42bae7efc6c9836612ae3ae656bcb5f0cbb006d32dd5951ebd0901e96ad2a50c461c90d4c3817ab6cccfc84cb3788b563969a6a26cf37cab3277a5438ca58e5769148b383fa6b6cca9a0d26c7a14793416b54298db8478a47ca09748d97a7231794ada3c629f72b8f7dd45c2c5781b9437d53e9a44727b3136ba3b7089512bb4312f4b7d29309e1e713d9fca0a3f78439a093f9a128199c2444b3371a36a367cb9312f09c6cd1156fec77a771814380bd3161554c0a26b0c6bf56eb6062468416095f8d15930cf9b4aac609a4a62ca35086c76417f87c766c799bbcb89a795496cf73a897062008231450215785025373c298f1374a97453926038966146882b07d3d8012cc4ba916bc4fbed581ce288b2e797222cb165e650063274caf682e2af26a09a97655d373795024c4c94508c8bfae8523883c77bc378a90626e1777cda227305836ca173143087250399b5fb7c38de58a2c866221d19125fa3a4f6c7c7cdc805a8cc27146e079f8d67d34316679225bc9bc5541595de7252e866c711921c1f006b1121c010cd95c70978eede93ecb8b6f8e72b02f67b3bb8c07c7742a0c910de88c0d62f963d90281ec037556d2cfcc034611536b717c0ac24265d7913f0755adcf905fbdea73fc7b7b70e74fa5611c3e44a357592d7849495e7cc39338296dc30e5119a1b627b16790263185b9e9cb69329454af0b3067123514a3c1356761d8243457332f7c07549973673a32396dd257e23b8f21204acba82ed76280bee9430de6606329461c1146a1405237f12db2305707975c8a533b2dac3da258ba116ab5dd11b2a42907dd274ea01c3400630f278c053db80a6411005609356dd78096486960bc96ff63814d5bb10999ac65001d2b9c86d2e146a53a722cd990e47c4c804aaebd106eb17c82944368dc1b972c0b6344a49d2ca73137f25fe8796d3ec223d24a52324197f8c4bb0d2ab9b3a7512c697d0da992fde16def4bcef5232db72bbead31c168c27c59c4478f304a0a149bfd10ab7e09ca79121a1fc7a7cec963d22c6cfcda4e35d2b4d9278f298854167781fc311cb698500324b184947c731439fd4a9edab044e6cb9d1aeac2723c79a4bc4a29a647bba9265da53694fac744b7ce25747238911e8b78c388980072b11466d00ce7e98dee386659b84551f6adaa228cd8e932a26ab8f34144ba126cb7e04fce145ba2e39715386fd9056ce3c861524b48ed8030676b30f46b937b71a872c6aa91c76026d081ce041957d028ce29016b9251dd4243c3860ffd57b0bda5978d5b1122181fafc89e6110281c24504fc508cca269b57438d8ab288fd2bbbe964a0a088fb911387fc84db577b2b3275a1b71a30ce5819b39b8c74ffa6e5e40accb410d77ae8817e413360272926f22b23def20e66e17b386fa77fcea9120debb819d329acf18f582ffbb07b86151f20ed8b77a7bf72ea12ba270023b9f88ea9cc9988583a8936667615396e21ebd25df58113423b57e71c918121f822312f122c63a96a2a728958dbeea96f102bb12d4729b00e4444594c036be66a16f63f8551bd583b96d0d2029b3d3554620fb825e2984824ecad24fbfcd450300be26e0768429c1c97041141617f8cc545f2cc0ff958206c67f40ea888dfd36695ecce47c0f88a8e4c86aee682f224d13afc90419c3a5c7d50d40efe650159fd570a7b4988e323800dbd4e404f37ec8d021eda32d4c74723f63af2ccef6f8328af5a42bd02a1a761cc205eff13875a97358e28729006dce363eab09a7421742f5323c5dff85fe9db3ce6f292aa5c3fe5b9eb5826e5c8359801191ce60326e8f1e9ecf5c5556e407a0bcc9788faa2cdc1c367e5b92a73a85aa8c3ea143415392de6c2096d071b373269bfbf5578311efe828e0b6f57aaf5a35ec364c5d1ee6471c8e2de6e6eacb8e65019bdcab198581e1ecc55ad3166123133717aa3b05f3b9b73f0f230d29f9a1b632b91079da4d45baebed10d2974e2f7a34e9faf10bd323ef72f6e757272e83a33cda747c272d744b0ba0e37448f2b3c596f4102e0860e7a91d9bfea4b90cab8ff7e2dd9fe64d8d67acde7fe73382c2ff0ca88891bac2e383ef62a17581b1de39b84a3748df2035268c2e48262235b5af46b8423550d2e0a85ebd10608b1f5e7d64db21200b25d73668a2354f784f9341bda3014cb1d0ad1d04ae46a826a70fe221351f08d2afe7f19dd4cefa5119f2060f69e303c8208575f768d5c8a31d2cecade2104dcadbf8d72ccc76efe891896a1878d2f12b5218638a00a2d5bbd034ebab0694a5c3c6cea1f9bec9d19b54f8895693af7b7d0153bee57526935ed67ce154a7c2b6df4186e9925bd1a6cb87d386bc0f87f260b494907542dba9cdc890660f6f66d477935359ab0369c3454db443811812b030c4d6445b04e46107ee25a90b206e7662e43c837a83cced312dd903a8f921f6a7e2efc7d4700b9f05f031779a25f6b954c12a130bfcf250d58673d9447b2e7bac4a9cd8ec999008e6b6586557741af940b6de8327ca2dc15970208ab7e1497455030f087734567f4ca6b345fccdb311e07c3bdfca081af1a5446105872f4d9185186c7b62472a86209774f7a216c0fb0699d05f0c43f5b41199dde6826bc66490d968c0ec0c5da2fa6146778629aa4d5bfb8390ee80704257449ffe9e1c8c4e316c0f7012c93a122d495e4a1c187d8a0802d30920f0ccd48c0a81a8c7622fa17d452a928f184758717870d5f07f2202654e4c9a157e85de62763bb6b9a4095a483f02e73717bdb24f26d4b5a4e936d6f8e620e9f9c94cfc8e670861e013c4ae2ecb40a2c0128c3f63bbf0ca9329b96b622960a00835dcc0eccdc9d515a2d6ea318e4c856d107193eca33d24ac1636c84c3c8b4802532f4648292a26631c94e269ad4d31e6dcf60cca7d406c997fa7a27f960acaa5e8f767e157d0",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "e04f69dfdb144a0b6ffdb758f956559cf48850243b25501349c6854c5a3116bd50e2e818d1861285f5637dfe8fc8b3a0"
@@ -1313,7 +1474,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_P521: &str = r#"
     "credential_finalization": "97ee42a170ab99c968904ad1779ccb0594a611b6f3fc996b74246b1766cd0aa1c482206cf79143d9c89461df3ae43b0da554194ce9eb29d5dc86badadc573533",
     "client_registration_state": "6eafbb4b709675bdd22a9e289048b20053b0ac0ab8853a1034ee431cd3353a0c70426f744606f980fc25b993b26bed3e5b7de20f73b2d17c0d67757581985255",
     "client_login_state": "6eafbb4b709675bdd22a9e289048b20053b0ac0ab8853a1034ee431cd3353a0c70426f744606f980fc25b993b26bed3e5b7de20f73b2d17c0d677575819852554239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd14ee9892b97393ccab8358b2f68e513f3387ce7d605f2c2ceb714187a16ce4b716127899a9b2a2c72b950929a0232ebc951bb99acf6bdc1d78c68313b8ff4b3d26363ba177eda4775cab88541f53cb847bd7e09731de4176887a12624c9c2417331ca74bc6ca8ee82b950e16bd9511ffff16cc1696952839949a5b1d4f36a148b6e61cb336edab70be8ace1c79084fba0fecc7ad7159e5d734eb83205b2c8347da809617076635aa58412180f61830ed5601d250f4b3497492327177c4cb4864af84193f546bc5dc510375151a516198eb308a989b825cb002e104b2772a6008c2e0765857526cbcd46c7786027f1530ea7dc71c30b3585004420715f7ca8bea1228107828443b20e34432d88194f67150eadc82522ea2f7248ac98318c70016adedc7c761438feab8e2ae83af1db065960861930ac6f41bda7fc5182086e9d152ec5b990ed83859ee5894c5883ce58389bd05985d6b162b828d81436ce9785311b31b209b488c6200688b47e51600a07b49e150c6a20ab46711d26f04ddda07daa735e57e461d05bcc4c721ede2a5b59b9467c6555e544c74ca8a4d1dc2c50307d4f3c34fa203fc87402b1981c45c05067b20aa0547763fb8fbdd350966c4baea17261973194eb54f9786c5c2068a55347e7a39225a34dad4936a1a2a5cc90a829617d3ee499884cc684904afa20c773413be36956ca557f90e1a667819d616c23b2bca1fce42a7baa3b45c2c01fd004d710715f7c72cbabcf2489cee5a9c00b17a7d3c9a09171ab6b04a8dfb8837f1c7ed48693ab1915a37996607a3c8064a447a030d6618453c1a34cb6b2a87cb98da47703538a1e6a33904467adf676ff5b4e79765b5b6c9a9e61c9f8863eb663407957742164134f18c8d073b172eb0b173793b8228272738f853444dd88399121c5ff7951d2109b00972359141f1c9627601c5ae61c66f7057f73621dd878947e006ee1d85eb2423793eb1800207547fbc331cb421d8285ca8607cfb7bd1b746a635a6b844b5ddd1c800002c45f45ba7ae94af3426382968792726719280fc5e799feb96d223a4715ec5a24ab1486fcbf46c733d0539eb7643b0674b9f9c09ab35272abe79eab65443cc84eafcb4997fbc5280173d1c09eb551184061235579393e7c89e8486a64e7ce7a2256b3b97e90db5553f4728a6b935c9c0de431bd3d261bfb5900da270cb5b22a805c928a7baef967c3ef4a5439a075652175a1a36fd5a2478c622fc84c8d3e23c61ff415a62c4728f676172b2c0bcca6a97094e1c1880b8746087cc1d8bc8f26969c462bb5640bcdd2584146b982859349605b228ee067bfb08ba1681f14024e0b87a83ee0c3788c9087f05894551a340917582badc8ec15a6b861e4b58d89f23253c8bc27a96030825a1af968483926b0c9a24bb75209d9903ff51720622903c688efdb619f8947d001506440c936e68694ec16b3c2c3139897a48372874909ffd116f9d703a41b6cf3b4883b65541c5a125086a08b33385f03b8c494c2063a7fbb3b390cd4709118438a02bb59fb6c27443dfe0303fbd82be46424dd4185fc1444c131c5916224a7a43df3f784e6b0bbf1c2434a050981f76bcec9334454a2abc753aab01bfe6ba6090bd443a4ea7c761a5c95f94fead1bab27d62a817854ea3874799620a01b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a74239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b3b6a06bcd07dfd16c005533f66d02833b147de951d473b21f559bc52976ea2166952180a2e4b42b26002a8291f87dc2e11510c4da633c2b07761a6b7d05211e8c4a261e68216c057627a36ea81229d9bb337758eb9913a4cdba2af930b69013546c2af99841f051a4796126b6e345a96d0906e259e5847a4f8b120e2f4cb88b2b411fbc0d123378501898855371336a3d5a7c7467763d8cb2fe763c7a312404ce53a2c742d1c1b48ab8851d9e91383bc419984a62505185b769994b78482ab6bc0a836283576c01c2380609568c04c400391676b56b8d2bd53cb5a6da1bc62589df9966cb7090e98258efaf78e2d431555343e402a0507359b0ef540dfe62d95812bfc56b14e503645921d7d689eaa8a7d3c5738622b046e428ed6f56ab9c6a98662b663333222ccb76a7159664126319790f9055882724a42a351149398e069859d8a9870ac97e1543cac47a3c16c4a055744e8fa4ecf8a72b511bf8d751fb44a5b5aa1bfa04377e3c5c49d277235a405bf381cbb30c8871b76d0a01ea582598b20c22730b03f2579f5418165dc27b25c46de188e509b34849342acf528461ac94aa2114c5a5176f14575382ca6a146fd71b09101876d798d035b5c7c2a8573d208fc8a6049497cc41a1810cc3f7a068e40251bc04c38b2d03c67183f90da07af66781d1c274abb18c2c0904d9960c0f7581811ac5615b5cd2b5ada9693f811accec83203455e19bbb6fcd47bb4d1b1a25184da201dde342153588992d916e89a3efe036ed667759cf96513263ff84902edca1509a49908f52cb86b6c0d296c312409baf6986256c8106c1beee4673f0713b4dc2c2bb46bcbac8f4e4519324a7ad1759469493326912298a3cbb78bc1d04ba4d33baaac69592dc93617c50e5baa21bee425036a94ab88068ef770d7167d74ca6b6c381e0deaca3f97bba6b95168111ce3d99cdad734463c6ed06c9f73c5487be966db8514650a2edebb92f022883667c1cd0ac00784c654c1af4cf4c98e86c868a1814842018ba41fc6da533b32561ad978ec10a136960d5a0949bb5796141224218219382a1ae6b481a1b9173d4834f3f1913f6c20d553573e7180c05bbe2a01cc18b59650430428d57a55f6570dcc3ab2f562178307420811a3a761f8e7064c9ca887644c3547cbe322a9a6e167ce1843091765de26260d220ece010eed72b9daa4212a692ab1499588385d00b91f37f81ef39b7e5f091016d722b3213fbeb58f5ef983dd294880d9bce439ab94f6c34933a6ea106dfd3b5967f51b38181201816003f410f619b1a445936b2bb91ffb4b43285b026b8a370ca52e4b881ef305a08c6ce33c071bd164b32308320c4bdf99ca9eb5918c487ab749257e98489ec053b98aa5b3b4533b40a1f0f1cce064178230b177885983d5965acbb0660c32c6759044767f06543d39f759f7a689670b0efa357833d881e7cba2ac70732dc315068a33c3b5c767cb84cc6654f92a626754acd9eb973c9427bb968ee90c067559c52d028bb160c01b5526ed311b1febc10e0a69870951ff330a2cc38eb088b31d971b601c046a0c2b6e2a35ea6b3a0038c2849a2d9e0aba215a908f8b16fb2aab97d595015b5e68e5b34555a44ee9892b97393ccab8358b2f68e513f3387ce7d605f2c2ceb714187a16ce4b716127899a9b2a2c72b950929a0232ebc951bb99acf6bdc1d78c68313b8ff4b3d26363ba177eda4775cab88541f53cb847bd7e09731de4176887a12624c9c2417331ca74bc6ca8ee82b950e16bd9511ffff16cc1696952839949a5b1d4f36a148b6e61cb336edab70be8ace1c79084fba0fecc7ad7159e5d734eb83205b2c8347da809617076635aa58412180f61830ed5601d250f4b3497492327177c4cb4864af84193f546bc5dc510375151a516198eb308a989b825cb002e104b2772a6008c2e0765857526cbcd46c7786027f1530ea7dc71c30b3585004420715f7ca8bea1228107828443b20e34432d88194f67150eadc82522ea2f7248ac98318c70016adedc7c761438feab8e2ae83af1db065960861930ac6f41bda7fc5182086e9d152ec5b990ed83859ee5894c5883ce58389bd05985d6b162b828d81436ce9785311b31b209b488c6200688b47e51600a07b49e150c6a20ab46711d26f04ddda07daa735e57e461d05bcc4c721ede2a5b59b9467c6555e544c74ca8a4d1dc2c50307d4f3c34fa203fc87402b1981c45c05067b20aa0547763fb8fbdd350966c4baea17261973194eb54f9786c5c2068a55347e7a39225a34dad4936a1a2a5cc90a829617d3ee499884cc684904afa20c773413be36956ca557f90e1a667819d616c23b2bca1fce42a7baa3b45c2c01fd004d710715f7c72cbabcf2489cee5a9c00b17a7d3c9a09171ab6b04a8dfb8837f1c7ed48693ab1915a37996607a3c8064a447a030d6618453c1a34cb6b2a87cb98da47703538a1e6a33904467adf676ff5b4e79765b5b6c9a9e61c9f8863eb663407957742164134f18c8d073b172eb0b173793b8228272738f853444dd88399121c5ff7951d2109b00972359141f1c9627601c5ae61c66f7057f73621dd878947e006ee1d85eb2423793eb1800207547fbc331cb421d8285ca8607cfb7bd1b746a635a6b844b5ddd1c800002c45f45ba7ae94af3426382968792726719280fc5e799feb96d223a4715ec5a24ab1486fcbf46c733d0539eb7643b0674b9f9c09ab35272abe79eab65443cc84eafcb4997fbc5280173d1c09eb551184061235579393e7c89e8486a64e7ce7a2256b3b97e90db5553f4728a6b935c9c0de431bd3d261bfb5900da270cb5b22a805c928a7baef967c3ef4a5439a075652175a1a36fd5a2478c622fc84c8d3e23c61ff415a62c4728f676172b2c0bcca6a97094e1c1880b8746087cc1d8bc8f26969c462bb5640bcdd2584146b982859349605b228ee067bfb08ba1681f14024e0b87a83ee0c3788c9087f05894551a340917582badc8ec15a6b861e4b58d89f23253c8bc27a96030825a1af968483926b0c9a24bb75209d9903ff51720622903c688efdb619f8947d001506440c936e68694ec16b3c2c3139897a48372874909ffd116f9d703a41b6cf3b4883b65541c5a125086a08b33385f03b8c494c2063a7fbb3b390cd4709118438a02bb59fb6c27443dfe0303fbd82be46424dd4185fc1444c131c5916224a7a43df3f784e6b0bbf1c2434a050981f76bcec9334454a2abc753aab01bfe6ba6090bd443a4ea7c761a5c95f94fead1bab27d62a817854ea3874799620ad7b43092f3074c35f169ce61720140e033cf2d49caba2ca2ebb07e14f509510601826e767f2960694852b4231c7a4b4e5f442ab2a6c7f85a14e0548f035c62fc",
-    "server_login_state": "f6384d8bc5473ee8e011d0a6cdf2d526a86497e369018cf0490afa4ed05f0acda66e9109e7bc155b11626e0ad3b18a6dd0e4a7b0c8dd87273ff837a7eecf052297ee42a170ab99c968904ad1779ccb0594a611b6f3fc996b74246b1766cd0aa1c482206cf79143d9c89461df3ae43b0da554194ce9eb29d5dc86badadc5735334ee9892b97393ccab8358b2f68e513f3387ce7d605f2c2ceb714187a16ce4b716127899a9b2a2c72b950929a0232ebc951bb99acf6bdc1d78c68313b8ff4b3d26363ba177eda4775cab88541f53cb847bd7e09731de4176887a12624c9c2417331ca74bc6ca8ee82b950e16bd9511ffff16cc1696952839949a5b1d4f36a148b6e61cb336edab70be8ace1c79084fba0fecc7ad7159e5d734eb83205b2c8347da809617076635aa58412180f61830ed5601d250f4b3497492327177c4cb4864af84193f546bc5dc510375151a516198eb308a989b825cb002e104b2772a6008c2e0765857526cbcd46c7786027f1530ea7dc71c30b3585004420715f7ca8bea1228107828443b20e34432d88194f67150eadc82522ea2f7248ac98318c70016adedc7c761438feab8e2ae83af1db065960861930ac6f41bda7fc5182086e9d152ec5b990ed83859ee5894c5883ce58389bd05985d6b162b828d81436ce9785311b31b209b488c6200688b47e51600a07b49e150c6a20ab46711d26f04ddda07daa735e57e461d05bcc4c721ede2a5b59b9467c6555e544c74ca8a4d1dc2c50307d4f3c34fa203fc87402b1981c45c05067b20aa0547763fb8fbdd350966c4baea17261973194eb54f9786c5c2068a55347e7a39225a34dad4936a1a2a5cc90a829617d3ee499884cc684904afa20c773413be36956ca557f90e1a667819d616c23b2bca1fce42a7baa3b45c2c01fd004d710715f7c72cbabcf2489cee5a9c00b17a7d3c9a09171ab6b04a8dfb8837f1c7ed48693ab1915a37996607a3c8064a447a030d6618453c1a34cb6b2a87cb98da47703538a1e6a33904467adf676ff5b4e79765b5b6c9a9e61c9f8863eb663407957742164134f18c8d073b172eb0b173793b8228272738f853444dd88399121c5ff7951d2109b00972359141f1c9627601c5ae61c66f7057f73621dd878947e006ee1d85eb2423793eb1800207547fbc331cb421d8285ca8607cfb7bd1b746a635a6b844b5ddd1c800002c45f45ba7ae94af3426382968792726719280fc5e799feb96d223a4715ec5a24ab1486fcbf46c733d0539eb7643b0674b9f9c09ab35272abe79eab65443cc84eafcb4997fbc5280173d1c09eb551184061235579393e7c89e8486a64e7ce7a2256b3b97e90db5553f4728a6b935c9c0de431bd3d261bfb5900da270cb5b22a805c928a7baef967c3ef4a5439a075652175a1a36fd5a2478c622fc84c8d3e23c61ff415a62c4728f676172b2c0bcca6a97094e1c1880b8746087cc1d8bc8f26969c462bb5640bcdd2584146b982859349605b228ee067bfb08ba1681f14024e0b87a83ee0c3788c9087f05894551a340917582badc8ec15a6b861e4b58d89f23253c8bc27a96030825a1af968483926b0c9a24bb75209d9903ff51720622903c688efdb619f8947d001506440c936e68694ec16b3c2c3139897a48372874909ffd116f9d703a41b6cf3b4883b65541c5a125086a08b33385f03b8c494c2063a7fbb3b390cd4709118438a02bb59fb6c27443dfe0303fbd82be46424dd4185fc1444c131c5916224a7a43df3f784e6b0bbf1c2434a050981f76bcec9334454a2abc753aab01bfe6ba6090bd443a4ea7c761a5c95f94fead1bab27d62a817854ea3874799620ac00372f0da952c5a484074ea19df604caf1aa6b3f715f1782b61a3d3d9cadd8772f7128950841a9ab035795f4c463db3548c279fdf9e9ec4b57871029025094f64f5ffd70e1de58a08b3a95ccf432213d02c16ee0eade0526ece9ada14566fb1ad3d7a65af54c7a379262b2b001042c08b1aff3b25adc50baecdce0db109c9089e10737b6db4022263694c034335716071db73ce62bed9ee88efaace3238cce0278014860fa274c5d563e137b6bee402d455ce65b8b1b5fbceb9fc0f487eebad166679ab72ac427fb585e70e52fb56e9f52eacd098604494b017d6114ca1dd9e891506aaf93c246dbbab6717754b921dbd5600aa10fd1f0357611120fd51a5befbed242ab99e42a9f21350091b8f5969f46283d5fdcd6673e9cb995657785e9e53900e9ce57a1440648a9ff606c6951c150962eb06d438ad2e44ce9a6230a4780be4317bd3d6ca1073dabdb1ea8edcde8ade8af9166ce7eadb0b23ce17c0c29a013c21bec20f9f1df473b0e2d4f4dc86e1618cf9f671c93acbda32afec0c8988ee87b74fa16ef9a59fb6edb7264db06a86ed52e8fc6bb8dfbaf0d4e7da1a94d6ec0bb337813d7afe5a2c8b83ae50c23a3e7722b7e71113820cf285613f089fbb21d9ffe8097e81bceb2919916893329221c5d8f487f798ad3879eb30a846ac71f125d1bb6784d19304c399e51db91df054b0d2adb2439828a5edb541548040d1f32fa0da3b962afb1d84d1460de326852d44bed2015f29a435eb58fbbf29c27e4a2f67c3b914a6dba3d1b91eaec0fdcb9810426e43183e8d5a4ab4523c6e038c3d3a694e7fd93192bf11a6c95c7a6c31052bd502c9de36679f4eb4b51ca22d4a022de45179f15cf6ad44fe2ce6bf1c021ec391ad47dd3c03c6e4a09702a374a138a773af7cf8b58f5c4354b853165e8c8d9fcafa5edd0ee0871bc5b4a8502652f5f73cced7553c51d8641bcc68fe080b82b0d9b837b097d6c28a5f3beb7dbfb493acb53428d59fd022cf8681f64e9ded9cf5869bf65fbe7adf4382fe58d434cb57e2713580d9e98da5a92bdf74e22697c94a8cc12d5b8e962744504c24e8c77549c3faae7754562667e4fb8350440acf66ca4d377ae097dea0e67bce11c14959b7e9e9346311a0d6851f4ba0443134922b69ee475ea882ce5dfc5799deac0a83f7d629c4bfd718a6406de51b76b26de33b385eb6b7ce7cbdf3b7ab5dece8427f423b4d4db10c64ffb456d3f5ecdd3c92776699c5e950915dc4228db8ee399f1663c7c6f5047ca71e8be4b43d3de86fdb03144593d4ee50511680215c3d1df49c55ede168a08f5b1f639819b8f653d4644ce7b05067baf642b1474727390ee926329cd9ac39848f843817ad81d6ceba73b9e4f306830ae2e4719116b53a490a84087a3c4e8cf439eb93f81ea70bfb962a3a4a374bf50495bbb593f6b942072d25bcf847a5aa32d6750ef599f860908b5d7207d2a8f52d2f8412dbf4bf61917a4075e933e713728d4c13db31fb676983b7bf34db8385fca21c12e828f33f4e8f1a",
+    "server_login_state": "f6384d8bc5473ee8e011d0a6cdf2d526a86497e369018cf0490afa4ed05f0acda66e9109e7bc155b11626e0ad3b18a6dd0e4a7b0c8dd87273ff837a7eecf0522545138cc6bce889bcee534e0dfb766f520a28dbf920243ea04d09c15fd26c5eb465a21cf0b6853b88cea04ec7ec3e2d5c538cd65b183c1e893f122d18279b9c597ee42a170ab99c968904ad1779ccb0594a611b6f3fc996b74246b1766cd0aa1c482206cf79143d9c89461df3ae43b0da554194ce9eb29d5dc86badadc5735334ee9892b97393ccab8358b2f68e513f3387ce7d605f2c2ceb714187a16ce4b716127899a9b2a2c72b950929a0232ebc951bb99acf6bdc1d78c68313b8ff4b3d26363ba177eda4775cab88541f53cb847bd7e09731de4176887a12624c9c2417331ca74bc6ca8ee82b950e16bd9511ffff16cc1696952839949a5b1d4f36a148b6e61cb336edab70be8ace1c79084fba0fecc7ad7159e5d734eb83205b2c8347da809617076635aa58412180f61830ed5601d250f4b3497492327177c4cb4864af84193f546bc5dc510375151a516198eb308a989b825cb002e104b2772a6008c2e0765857526cbcd46c7786027f1530ea7dc71c30b3585004420715f7ca8bea1228107828443b20e34432d88194f67150eadc82522ea2f7248ac98318c70016adedc7c761438feab8e2ae83af1db065960861930ac6f41bda7fc5182086e9d152ec5b990ed83859ee5894c5883ce58389bd05985d6b162b828d81436ce9785311b31b209b488c6200688b47e51600a07b49e150c6a20ab46711d26f04ddda07daa735e57e461d05bcc4c721ede2a5b59b9467c6555e544c74ca8a4d1dc2c50307d4f3c34fa203fc87402b1981c45c05067b20aa0547763fb8fbdd350966c4baea17261973194eb54f9786c5c2068a55347e7a39225a34dad4936a1a2a5cc90a829617d3ee499884cc684904afa20c773413be36956ca557f90e1a667819d616c23b2bca1fce42a7baa3b45c2c01fd004d710715f7c72cbabcf2489cee5a9c00b17a7d3c9a09171ab6b04a8dfb8837f1c7ed48693ab1915a37996607a3c8064a447a030d6618453c1a34cb6b2a87cb98da47703538a1e6a33904467adf676ff5b4e79765b5b6c9a9e61c9f8863eb663407957742164134f18c8d073b172eb0b173793b8228272738f853444dd88399121c5ff7951d2109b00972359141f1c9627601c5ae61c66f7057f73621dd878947e006ee1d85eb2423793eb1800207547fbc331cb421d8285ca8607cfb7bd1b746a635a6b844b5ddd1c800002c45f45ba7ae94af3426382968792726719280fc5e799feb96d223a4715ec5a24ab1486fcbf46c733d0539eb7643b0674b9f9c09ab35272abe79eab65443cc84eafcb4997fbc5280173d1c09eb551184061235579393e7c89e8486a64e7ce7a2256b3b97e90db5553f4728a6b935c9c0de431bd3d261bfb5900da270cb5b22a805c928a7baef967c3ef4a5439a075652175a1a36fd5a2478c622fc84c8d3e23c61ff415a62c4728f676172b2c0bcca6a97094e1c1880b8746087cc1d8bc8f26969c462bb5640bcdd2584146b982859349605b228ee067bfb08ba1681f14024e0b87a83ee0c3788c9087f05894551a340917582badc8ec15a6b861e4b58d89f23253c8bc27a96030825a1af968483926b0c9a24bb75209d9903ff51720622903c688efdb619f8947d001506440c936e68694ec16b3c2c3139897a48372874909ffd116f9d703a41b6cf3b4883b65541c5a125086a08b33385f03b8c494c2063a7fbb3b390cd4709118438a02bb59fb6c27443dfe0303fbd82be46424dd4185fc1444c131c5916224a7a43df3f784e6b0bbf1c2434a050981f76bcec9334454a2abc753aab01bfe6ba6090bd443a4ea7c761a5c95f94fead1bab27d62a817854ea3874799620ac00372f0da952c5a484074ea19df604caf1aa6b3f715f1782b61a3d3d9cadd8772f7128950841a9ab035795f4c463db3548c279fdf9e9ec4b57871029025094f64f5ffd70e1de58a08b3a95ccf432213d02c16ee0eade0526ece9ada14566fb1ad3d7a65af54c7a379262b2b001042c08b1aff3b25adc50baecdce0db109c9089e10737b6db4022263694c034335716071db73ce62bed9ee88efaace3238cce0278014860fa274c5d563e137b6bee402d455ce65b8b1b5fbceb9fc0f487eebad166679ab72ac427fb585e70e52fb56e9f52eacd098604494b017d6114ca1dd9e891506aaf93c246dbbab6717754b921dbd5600aa10fd1f0357611120fd51a5befbed242ab99e42a9f21350091b8f5969f46283d5fdcd6673e9cb995657785e9e53900e9ce57a1440648a9ff606c6951c150962eb06d438ad2e44ce9a6230a4780be4317bd3d6ca1073dabdb1ea8edcde8ade8af9166ce7eadb0b23ce17c0c29a013c21bec20f9f1df473b0e2d4f4dc86e1618cf9f671c93acbda32afec0c8988ee87b74fa16ef9a59fb6edb7264db06a86ed52e8fc6bb8dfbaf0d4e7da1a94d6ec0bb337813d7afe5a2c8b83ae50c23a3e7722b7e71113820cf285613f089fbb21d9ffe8097e81bceb2919916893329221c5d8f487f798ad3879eb30a846ac71f125d1bb6784d19304c399e51db91df054b0d2adb2439828a5edb541548040d1f32fa0da3b962afb1d84d1460de326852d44bed2015f29a435eb58fbbf29c27e4a2f67c3b914a6dba3d1b91eaec0fdcb9810426e43183e8d5a4ab4523c6e038c3d3a694e7fd93192bf11a6c95c7a6c31052bd502c9de36679f4eb4b51ca22d4a022de45179f15cf6ad44fe2ce6bf1c021ec391ad47dd3c03c6e4a09702a374a138a773af7cf8b58f5c4354b853165e8c8d9fcafa5edd0ee0871bc5b4a8502652f5f73cced7553c51d8641bcc68fe080b82b0d9b837b097d6c28a5f3beb7dbfb493acb53428d59fd022cf8681f64e9ded9cf5869bf65fbe7adf4382fe58d434cb57e2713580d9e98da5a92bdf74e22697c94a8cc12d5b8e962744504c24e8c77549c3faae7754562667e4fb8350440acf66ca4d377ae097dea0e67bce11c14959b7e9e9346311a0d6851f4ba0443134922b69ee475ea882ce5dfc5799deac0a83f7d629c4bfd718a6406de51b76b26de33b385eb6b7ce7cbdf3b7ab5dece8427f423b4d4db10c64ffb456d3f5ecdd3c92776699c5e950915dc4228db8ee399f1663c7c6f5047ca71e8be4b43d3de86fdb03144593d4ee50511680215c3d1df49c55ede168a08f5b1f639819b8f653d4644ce7b05067baf642b1474727390ee926329cd9ac39848f843817ad81d6ceba73b9e4f306830ae2e4719116b53a490a84087a3c4e8cf439eb93f81ea70bfb962a3a4a374bf50495bbb593f6b942072d25bcf847a5aa32d6750ef599f860908b5d7207d2a8f52d2f8412dbf4bf61917a4075e933e713728d4c13db31fb676983b7bf34db8385fca21c12e828f33f4e8f1a",
     "password_file": "0301af3d14ba5f304c4af480ec7841579f114a6bae3689bb8592fc2cde2b7a77c07bd6ea1ad0c90e88fee416652ade13b124d7ee36b8dc704cc88adbffe670e3bc9ff9278cfd16bb338664586c6d0a0f7eaf309417dce66568b13475ab028af0f2b5c1c96d9e1c4e06d7724ec5a3f9559fabcfcba3c673c9e6f846c3f1aa74042ef3430191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c385843e261f2b8813ed4e7b6b824570581275b0512380953244e66066e7a7049a9199107824f3d041f5e8f9af9a196c39b163b3416dc81dc4f456570c94fd19d93",
     "export_key": "2d8bd543482be9dba0b9a2d1a92d2a26bddcd7a64bbbef1eb9b6f9426c9428620d0034c2e184e48e5ea02155562e7ea6cfc10a03bb7d9c222588b6134839065c",
     "session_key": "f6384d8bc5473ee8e011d0a6cdf2d526a86497e369018cf0490afa4ed05f0acda66e9109e7bc155b11626e0ad3b18a6dd0e4a7b0c8dd87273ff837a7eecf0522"
@@ -1352,7 +1513,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_KEM_P521: &str = r#"
     "credential_finalization": "3a20788abbcd7003e68623f8fe33228309be205f547bd40a8cb67fd9bf2573b2a934ec1f92594d34f94a1ed21a7f17c22bc86f03cfcceeced4938d8a8b581fd8",
     "client_registration_state": "7296a3d1056cec20cca5f153e55fbffc96b85b8fa86d5b47edb354ea465314ed03127f88d68a28399e27c35dad8310a60019544b3d4673922e2ab5f060d834423b",
     "client_login_state": "7296a3d1056cec20cca5f153e55fbffc96b85b8fa86d5b47edb354ea465314ed03127f88d68a28399e27c35dad8310a60019544b3d4673922e2ab5f060d834423b2f98027f49cecdece384850929ce9d3b0fd624860acda513da8ab39bc36f782102000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd1c579402bac8807113858662a6773aafae4873e908719921726944703e705bc8514362a8fe028966c549280645c2c373a8ad216345abc690a0b9e5089ba0437c5a6b934046ab0e6792f7169562083522a44cba4415b74ccf3cb032a656ce94c924147443ebbc5d8f73eadf736d3b50b74f347b6090aa95619d93724fac6cbba8494ac863c3cda820435a6a89b4917d75141bb1c1b322d69e54a9664a8e9308b4072a1d07662270c21906018d83806d50bcfaf6c693c914e8ea967a4e324b1d62efac910eeaace6d8b68af6537066a00b029287da2b042d1ad61224b77b2b80e26a168b1c6bab156b423c4081758b15ab6a291bb3be1c4c43670044c087c178548c94638d4a2e73145bea7b0cbaa6ebfc78b46e485bea492ea9c7e8f639ce0a1956d876014fc1a24c6bd538747504c171497142c1ac712f4be67573d08805cedfc6f105895329a5465a62694494e66abafcc864e9a982fd110c72ec590904382c7bb9723b990fa7c42b3b6bbb221779e87482c71737150b53873cbb0f35c6dc9a991e26b3505c2ee6a5d31b95b069797b9d300395162cf31311da835243b56d68b7a4ee76746f71ac9c5504583935dc75bbec992b5e4791407c53f0a605dac7f636b8518fb2007c33fe5a03b79fc7349921fc4e5ac79aa1aa8462f93e98e7f9118b91c11baec561c8181185b0f16e183c3e005e68720365b999ccac3d9296cc15b6f93332c8954300df5b4377b64999c26da179632d6a8f299afb899023bbac20c0a0957b3cb4df05298781e354a6c0632a8be351e94ec24d857550bc08b082880283c3bcc182273fc86bd838da5d34a9dc829a860533170745c0b9521ec91f12b03aefc9fcee605f12b5bcf22a1ed27a6bdf0ac7a235fdf6289023757cbfcc2824b124092a5c8f76b32f54073828efa14c28fa1cfcd407feb687e10287112f9cf453497f654026fd662fb21c956db4b35a00b06bb84a538c0cc99009e369c2dd725b897408b38a3fc6400070303759c14cae702902c03279a6a9c50b5be20a3df144d59b72d78e1612abc7d3ed81db8754d07120a9ed50e8a4942b572478521ba739c4aecc8b2c9d4aadf063d005442b3445a33887272715afcf12ed570b8965811d0d650f5a68f272a9e45c78d16f243d07322d7330f04c10607570c78e08aa0a8324dd13ae3c48a5830b6a6da90fa69a0ef8254aac3b7a9859c93d89aeb22263a4777e3d851d92c70affa2611b1533c548bde3160036c39fc804846b14707189c10859fe17b3be4803036bb8b60d075b8d53e84615df2007db1481c9c312e7de149b68b04d0462d9612c171b03262095b98c586c679727cd1960d13a04a3c0381930a0acbc9ae1c8a2f798417f386cef51aa81853c3259680938b28aa8b53b881ef556e769a373964c77192c9c2138e3447b9f7c3387f042baf4c7439b1b951e0049a124331a2af82cb928dcb7059b944c55800d16b3c47674c39176bcc105eb7f41a1d9b7ed0198c10e4921d5220860c4c20c728b851b9dae4747a9c995e411ba375580a1b6a05b8830c699dcfc84f26e2084c79c0b5c2352e97718ac0bcca3568b0575a20c8c202ba0cb3fac3e3c0cb69389e42927b832084d460593d3402680ff49fde8a2d619bdfae52f3e49f5ff4e19daf3f03112a0ee396b367c4e5bb01b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a72f98027f49cecdece384850929ce9d3b0fd624860acda513da8ab39bc36f7821d7da1a42d9af7cf9c423ca081923b94441870aa479d5652b20fa0ade42c960a8c70a561f361a5082510769930f84f87aa2727c6ccb140fdc7182fbbfa4e2a77c2a952376841d7c3fba097f8808b198f313db5a3d51272862b8028216206f52555911abd6b628b2d50116a5463b9a125440a0a8a00b000c3460229e6a040e704449940640e4a2aad46785ba646d22cb556bf654ba7214e3fb9b7d042f56e149b05043f0e089f8c74936e0ad85458e0bd2c8b677ad4ce274cf0c1fbef5922e9c8a93f9ab0bda3267e67baf0b341db89c5a9997e16b4701945166d40bc6cb7e5b7480f8749c19967c42b4bb6b07cc778a25fec47d6a67221a23079edc7801e2533db849653c5cdba367c882660c082ee76ac50a284f98b3b9242a05228582e8c04293fb7fb60c308ac9313285279e22be4268157dcb6addd5655f45ba39fa7c1ff87b517047f8ba6c72483edbeb6409443f8337baddb452ac26526e1792d498210c32313d046ade3c758e8b3fa53c02afe0946f0903cec8c2b9b380e551332b294e3ab6a894b3829190702b954a82b23a83131b1444a95361b4a677b4c5ec66ffd63e2677b83ec30ad9f454fd9aa6f8b1b809b24cebd2aff227b191487f86da95e410070696c2f2e8767c5615bf4b642c8b86cbd1820dc20b7a6ca24bc43e9fb141e92c2ac67078ce01385a419ec9c3b563430241ab1b246767fb155378d73c1af2b5eff6c67a46695bd6245ee87d2dc228f5b19a96006214a75fd23727c86463d0344478e22d9465a4117c6ddd94808f4187f37b637929a4415969e79595a4c3714295040b23bae03685200380dba2013c1b41523149873a8afa206753f032c3c9023d2932d878cdfc0302cbd22bb00a7037163fc1b18c182b0a0572368c2446ff5ca18cea2ef29bb3ff609b2c483bf43641f1027afa59bd936c01a580bfd6b036dcb7a0393500a001349469acf2370cb326429a4a3a2687a3e6f11a9be71011d12b8e5a7d4d702d6a529be08cba33549621409171dca649542457030131b31917b3774b97644e1050321494c4b6a5db4485a8890750c63f5f5a349aa885a27c5baf6b782bd69957f873b0da921fbb7a70b6a053c9430aaaa4dd24b29f059f8a7c0505e84104667b6ddba514670023a9c5929c22a2b92c812ca0fa6565b0320bbec42651928f0d955a95b8b5c248761ed8792b4b1bce87cde36830ed84b4831076ad7b659a67031ae1c486c9313936196a4b2a2b03693cd759ede5ba881989c0ba9d4d863031cc4dbb1086b2c0a318da8861caaac19b9f11b60be92125a07b3dce839b490665eb9032ba82c676b082d7816fb7a53ab7dc8b66840c2e634896c77137163b2fb70051b140c34ac15cf27af3d296a348931b6025675375088b0f18954287f9416c1791f4c17e04a2811a7b1d64415ec5f73ddd3b139b05ceaa1649b01868d28451fd035021e194deea4f2573c5df274be7d8116da690d155bf61a37a212501d886698aa33081e0082944348d23cf624472f1984a54b9c83706b2862a674a9423509b5f59222350ac80a1c08337121cda2b1dd62c6d137a7f0bb47f25613e7a008aa8965f04c17293763fa7e157d850a58af377b8fc567348197f360cc579402bac8807113858662a6773aafae4873e908719921726944703e705bc8514362a8fe028966c549280645c2c373a8ad216345abc690a0b9e5089ba0437c5a6b934046ab0e6792f7169562083522a44cba4415b74ccf3cb032a656ce94c924147443ebbc5d8f73eadf736d3b50b74f347b6090aa95619d93724fac6cbba8494ac863c3cda820435a6a89b4917d75141bb1c1b322d69e54a9664a8e9308b4072a1d07662270c21906018d83806d50bcfaf6c693c914e8ea967a4e324b1d62efac910eeaace6d8b68af6537066a00b029287da2b042d1ad61224b77b2b80e26a168b1c6bab156b423c4081758b15ab6a291bb3be1c4c43670044c087c178548c94638d4a2e73145bea7b0cbaa6ebfc78b46e485bea492ea9c7e8f639ce0a1956d876014fc1a24c6bd538747504c171497142c1ac712f4be67573d08805cedfc6f105895329a5465a62694494e66abafcc864e9a982fd110c72ec590904382c7bb9723b990fa7c42b3b6bbb221779e87482c71737150b53873cbb0f35c6dc9a991e26b3505c2ee6a5d31b95b069797b9d300395162cf31311da835243b56d68b7a4ee76746f71ac9c5504583935dc75bbec992b5e4791407c53f0a605dac7f636b8518fb2007c33fe5a03b79fc7349921fc4e5ac79aa1aa8462f93e98e7f9118b91c11baec561c8181185b0f16e183c3e005e68720365b999ccac3d9296cc15b6f93332c8954300df5b4377b64999c26da179632d6a8f299afb899023bbac20c0a0957b3cb4df05298781e354a6c0632a8be351e94ec24d857550bc08b082880283c3bcc182273fc86bd838da5d34a9dc829a860533170745c0b9521ec91f12b03aefc9fcee605f12b5bcf22a1ed27a6bdf0ac7a235fdf6289023757cbfcc2824b124092a5c8f76b32f54073828efa14c28fa1cfcd407feb687e10287112f9cf453497f654026fd662fb21c956db4b35a00b06bb84a538c0cc99009e369c2dd725b897408b38a3fc6400070303759c14cae702902c03279a6a9c50b5be20a3df144d59b72d78e1612abc7d3ed81db8754d07120a9ed50e8a4942b572478521ba739c4aecc8b2c9d4aadf063d005442b3445a33887272715afcf12ed570b8965811d0d650f5a68f272a9e45c78d16f243d07322d7330f04c10607570c78e08aa0a8324dd13ae3c48a5830b6a6da90fa69a0ef8254aac3b7a9859c93d89aeb22263a4777e3d851d92c70affa2611b1533c548bde3160036c39fc804846b14707189c10859fe17b3be4803036bb8b60d075b8d53e84615df2007db1481c9c312e7de149b68b04d0462d9612c171b03262095b98c586c679727cd1960d13a04a3c0381930a0acbc9ae1c8a2f798417f386cef51aa81853c3259680938b28aa8b53b881ef556e769a373964c77192c9c2138e3447b9f7c3387f042baf4c7439b1b951e0049a124331a2af82cb928dcb7059b944c55800d16b3c47674c39176bcc105eb7f41a1d9b7ed0198c10e4921d5220860c4c20c728b851b9dae4747a9c995e411ba375580a1b6a05b8830c699dcfc84f26e2084c79c0b5c2352e97718ac0bcca3568b0575a20c8c202ba0cb3fac3e3c0cb69389e42927b832084d460593d3402680ff49fde8a2d619bdfae52f3e49f5ff4e19daf3f03112a0ee396b367c4e5bbb3d826531f94607f183316acfa420d1b5e3b97545add80cd556753480c21c76201826e767f2960694852b4231c7a4b4e5f442ab2a6c7f85a14e0548f035c62fc",
-    "server_login_state": "1acf0252ecb0e3e81235f5c87eb0e30c9decd435e57c344258cf1044946e991f75e55d9d4c023b39abb1e17aa3c081dcd7879058aa7b7a8032da1079be52c37c3a20788abbcd7003e68623f8fe33228309be205f547bd40a8cb67fd9bf2573b2a934ec1f92594d34f94a1ed21a7f17c22bc86f03cfcceeced4938d8a8b581fd8c579402bac8807113858662a6773aafae4873e908719921726944703e705bc8514362a8fe028966c549280645c2c373a8ad216345abc690a0b9e5089ba0437c5a6b934046ab0e6792f7169562083522a44cba4415b74ccf3cb032a656ce94c924147443ebbc5d8f73eadf736d3b50b74f347b6090aa95619d93724fac6cbba8494ac863c3cda820435a6a89b4917d75141bb1c1b322d69e54a9664a8e9308b4072a1d07662270c21906018d83806d50bcfaf6c693c914e8ea967a4e324b1d62efac910eeaace6d8b68af6537066a00b029287da2b042d1ad61224b77b2b80e26a168b1c6bab156b423c4081758b15ab6a291bb3be1c4c43670044c087c178548c94638d4a2e73145bea7b0cbaa6ebfc78b46e485bea492ea9c7e8f639ce0a1956d876014fc1a24c6bd538747504c171497142c1ac712f4be67573d08805cedfc6f105895329a5465a62694494e66abafcc864e9a982fd110c72ec590904382c7bb9723b990fa7c42b3b6bbb221779e87482c71737150b53873cbb0f35c6dc9a991e26b3505c2ee6a5d31b95b069797b9d300395162cf31311da835243b56d68b7a4ee76746f71ac9c5504583935dc75bbec992b5e4791407c53f0a605dac7f636b8518fb2007c33fe5a03b79fc7349921fc4e5ac79aa1aa8462f93e98e7f9118b91c11baec561c8181185b0f16e183c3e005e68720365b999ccac3d9296cc15b6f93332c8954300df5b4377b64999c26da179632d6a8f299afb899023bbac20c0a0957b3cb4df05298781e354a6c0632a8be351e94ec24d857550bc08b082880283c3bcc182273fc86bd838da5d34a9dc829a860533170745c0b9521ec91f12b03aefc9fcee605f12b5bcf22a1ed27a6bdf0ac7a235fdf6289023757cbfcc2824b124092a5c8f76b32f54073828efa14c28fa1cfcd407feb687e10287112f9cf453497f654026fd662fb21c956db4b35a00b06bb84a538c0cc99009e369c2dd725b897408b38a3fc6400070303759c14cae702902c03279a6a9c50b5be20a3df144d59b72d78e1612abc7d3ed81db8754d07120a9ed50e8a4942b572478521ba739c4aecc8b2c9d4aadf063d005442b3445a33887272715afcf12ed570b8965811d0d650f5a68f272a9e45c78d16f243d07322d7330f04c10607570c78e08aa0a8324dd13ae3c48a5830b6a6da90fa69a0ef8254aac3b7a9859c93d89aeb22263a4777e3d851d92c70affa2611b1533c548bde3160036c39fc804846b14707189c10859fe17b3be4803036bb8b60d075b8d53e84615df2007db1481c9c312e7de149b68b04d0462d9612c171b03262095b98c586c679727cd1960d13a04a3c0381930a0acbc9ae1c8a2f798417f386cef51aa81853c3259680938b28aa8b53b881ef556e769a373964c77192c9c2138e3447b9f7c3387f042baf4c7439b1b951e0049a124331a2af82cb928dcb7059b944c55800d16b3c47674c39176bcc105eb7f41a1d9b7ed0198c10e4921d5220860c4c20c728b851b9dae4747a9c995e411ba375580a1b6a05b8830c699dcfc84f26e2084c79c0b5c2352e97718ac0bcca3568b0575a20c8c202ba0cb3fac3e3c0cb69389e42927b832084d460593d3402680ff49fde8a2d619bdfae52f3e49f5ff4e19daf3f03112a0ee396b367c4e5bb8e0c2f248736ed0e90bf1b1fbf13498bf6551a454816df1f769d85a1f0d89263be08a4cc3d38f890428671eac2be34c9ac82db9f97c70755bb00ccad61cd2cddb778fdb16e813b067536719ab90fc7fc314f3803bdc7ea7946f3aa8716628e8aa9c76ea07569d768d6a91131ee04fb68afcaeb0b197d13abbcc4a28884bc21e8e9dd22b1ae9e13c09f5d3058abb99700aaf3a62a17a5d8077d310c614ae15029bd1081c2bdf7661d58749ee90776aa204f689dccbad6c6ec1667a3dbfe0debbcf6a4970087505e9b59f928bdef4f8065b60726584dd3f8d336e5aef232912bfbc0b502a2960d09024420d1f8cbe1e2f1239d7c788c363efd7ea0722e5d21dfb3ac9e40f8643cb742fb0b6f8ae5cc73cedd69779cd13d8a0486a594393bf8192fffc2402d7c73488cc8bd099106d5d9c781fc6b8bd8f09dbd093a942a353ec6d437575715797dc1ee341b5ff9289fc73ef323566d7c2c2196ef291decb9482d1a7e4be3f427ca2d96e37cf8f54e6aa312818644ec414aedf30db1e643886222dfe1cd7f9d84f596271b8e0705e91966126f64c52b89ed24595397d5187a37ef26539f7c3a534d631548fa93fc73fca9d1c6f5bbb3ce1b3d8040094c29fe891742101e7e5d35bed43e81026e858e60384239e346a345d9d519446a2803e54bf0a0257416e7da00c8d16f478e5ec2b19b74b39c7c2bd1102099fe8627f4cb7f57325dd910d0d5c3766b907096d9a749ef8c88062579002468be82f656cfdc1cab40c2f337f9ea894beff57f2fa315bbbeb0a38d6b7e61ce0fa495f75871d0d606133e755300767840b880d62e6f1d01a45bb83d59d294f9e752743ec656c2e7e48d56c8843a619bf74ffbbc58c741ef9be129463dc803d330aed24d3d676f86f8fbd0eccc449af0369c4c8b92c7a825f3d1294e0e68131a2af9735240c9cbde3099e1eb0454e2dcd01073ef59115886540820968d7143657aa8bf6eba23a429e7b3e7346ee1bcd1da8acbfb04b474a35b1f7022055245298de3a6f2361060f459d65b7b1d0e5201148792ac346077aae00252de56883278efc5d15398f95520f0b87c44d6a30fdbacb396b697a8701bf1576b4e36d57f2573e568891bc6ca561c10a86fe8a39ec6d54dcbf8b789b1592364fdc2dc65a58cfaefb6e4c1d2316ae072cb8bdf61adf21f22eaa1cefef2d0134099020d9de92df8e2ea087e2d855512fa4c544b42a5d200d2ccc115d1ee72b9cff42520daf3dc9f411732d7443418c31e68965ea2b67217ace219a4a51e350fca141a243d906f7c73dbac4de3d4f3bed56e0f7eab975a7bf67ed21f2e4dfc80a0cd0bb78f83b4f19e73e7e7ff223dcdd6d336b99bf01c60faae7ce88ee3e551f35deb7b8b3e70b758b940d644673b5d53837b902f1d88f236d94467588d7045cd9b0ce35c72579c6176900fe78a92d11217634bdebd8fbb6338c905fc489e17bfe1b277d36851bd3a5768691deab4076254a469dde8712190243e693ebb4830521c1860fb3e3f30dc249df09bfa51eb6f",
+    "server_login_state": "1acf0252ecb0e3e81235f5c87eb0e30c9decd435e57c344258cf1044946e991f75e55d9d4c023b39abb1e17aa3c081dcd7879058aa7b7a8032da1079be52c37c656e78c4ed49fc7442995708811e2db963b94f1e01d44342f0f1358893f0a08748b64a330a0a23e5cb4cbcb5c69f02a0fe26f1bc863fbc997c0362c1bd49aa9c3a20788abbcd7003e68623f8fe33228309be205f547bd40a8cb67fd9bf2573b2a934ec1f92594d34f94a1ed21a7f17c22bc86f03cfcceeced4938d8a8b581fd8c579402bac8807113858662a6773aafae4873e908719921726944703e705bc8514362a8fe028966c549280645c2c373a8ad216345abc690a0b9e5089ba0437c5a6b934046ab0e6792f7169562083522a44cba4415b74ccf3cb032a656ce94c924147443ebbc5d8f73eadf736d3b50b74f347b6090aa95619d93724fac6cbba8494ac863c3cda820435a6a89b4917d75141bb1c1b322d69e54a9664a8e9308b4072a1d07662270c21906018d83806d50bcfaf6c693c914e8ea967a4e324b1d62efac910eeaace6d8b68af6537066a00b029287da2b042d1ad61224b77b2b80e26a168b1c6bab156b423c4081758b15ab6a291bb3be1c4c43670044c087c178548c94638d4a2e73145bea7b0cbaa6ebfc78b46e485bea492ea9c7e8f639ce0a1956d876014fc1a24c6bd538747504c171497142c1ac712f4be67573d08805cedfc6f105895329a5465a62694494e66abafcc864e9a982fd110c72ec590904382c7bb9723b990fa7c42b3b6bbb221779e87482c71737150b53873cbb0f35c6dc9a991e26b3505c2ee6a5d31b95b069797b9d300395162cf31311da835243b56d68b7a4ee76746f71ac9c5504583935dc75bbec992b5e4791407c53f0a605dac7f636b8518fb2007c33fe5a03b79fc7349921fc4e5ac79aa1aa8462f93e98e7f9118b91c11baec561c8181185b0f16e183c3e005e68720365b999ccac3d9296cc15b6f93332c8954300df5b4377b64999c26da179632d6a8f299afb899023bbac20c0a0957b3cb4df05298781e354a6c0632a8be351e94ec24d857550bc08b082880283c3bcc182273fc86bd838da5d34a9dc829a860533170745c0b9521ec91f12b03aefc9fcee605f12b5bcf22a1ed27a6bdf0ac7a235fdf6289023757cbfcc2824b124092a5c8f76b32f54073828efa14c28fa1cfcd407feb687e10287112f9cf453497f654026fd662fb21c956db4b35a00b06bb84a538c0cc99009e369c2dd725b897408b38a3fc6400070303759c14cae702902c03279a6a9c50b5be20a3df144d59b72d78e1612abc7d3ed81db8754d07120a9ed50e8a4942b572478521ba739c4aecc8b2c9d4aadf063d005442b3445a33887272715afcf12ed570b8965811d0d650f5a68f272a9e45c78d16f243d07322d7330f04c10607570c78e08aa0a8324dd13ae3c48a5830b6a6da90fa69a0ef8254aac3b7a9859c93d89aeb22263a4777e3d851d92c70affa2611b1533c548bde3160036c39fc804846b14707189c10859fe17b3be4803036bb8b60d075b8d53e84615df2007db1481c9c312e7de149b68b04d0462d9612c171b03262095b98c586c679727cd1960d13a04a3c0381930a0acbc9ae1c8a2f798417f386cef51aa81853c3259680938b28aa8b53b881ef556e769a373964c77192c9c2138e3447b9f7c3387f042baf4c7439b1b951e0049a124331a2af82cb928dcb7059b944c55800d16b3c47674c39176bcc105eb7f41a1d9b7ed0198c10e4921d5220860c4c20c728b851b9dae4747a9c995e411ba375580a1b6a05b8830c699dcfc84f26e2084c79c0b5c2352e97718ac0bcca3568b0575a20c8c202ba0cb3fac3e3c0cb69389e42927b832084d460593d3402680ff49fde8a2d619bdfae52f3e49f5ff4e19daf3f03112a0ee396b367c4e5bb8e0c2f248736ed0e90bf1b1fbf13498bf6551a454816df1f769d85a1f0d89263be08a4cc3d38f890428671eac2be34c9ac82db9f97c70755bb00ccad61cd2cddb778fdb16e813b067536719ab90fc7fc314f3803bdc7ea7946f3aa8716628e8aa9c76ea07569d768d6a91131ee04fb68afcaeb0b197d13abbcc4a28884bc21e8e9dd22b1ae9e13c09f5d3058abb99700aaf3a62a17a5d8077d310c614ae15029bd1081c2bdf7661d58749ee90776aa204f689dccbad6c6ec1667a3dbfe0debbcf6a4970087505e9b59f928bdef4f8065b60726584dd3f8d336e5aef232912bfbc0b502a2960d09024420d1f8cbe1e2f1239d7c788c363efd7ea0722e5d21dfb3ac9e40f8643cb742fb0b6f8ae5cc73cedd69779cd13d8a0486a594393bf8192fffc2402d7c73488cc8bd099106d5d9c781fc6b8bd8f09dbd093a942a353ec6d437575715797dc1ee341b5ff9289fc73ef323566d7c2c2196ef291decb9482d1a7e4be3f427ca2d96e37cf8f54e6aa312818644ec414aedf30db1e643886222dfe1cd7f9d84f596271b8e0705e91966126f64c52b89ed24595397d5187a37ef26539f7c3a534d631548fa93fc73fca9d1c6f5bbb3ce1b3d8040094c29fe891742101e7e5d35bed43e81026e858e60384239e346a345d9d519446a2803e54bf0a0257416e7da00c8d16f478e5ec2b19b74b39c7c2bd1102099fe8627f4cb7f57325dd910d0d5c3766b907096d9a749ef8c88062579002468be82f656cfdc1cab40c2f337f9ea894beff57f2fa315bbbeb0a38d6b7e61ce0fa495f75871d0d606133e755300767840b880d62e6f1d01a45bb83d59d294f9e752743ec656c2e7e48d56c8843a619bf74ffbbc58c741ef9be129463dc803d330aed24d3d676f86f8fbd0eccc449af0369c4c8b92c7a825f3d1294e0e68131a2af9735240c9cbde3099e1eb0454e2dcd01073ef59115886540820968d7143657aa8bf6eba23a429e7b3e7346ee1bcd1da8acbfb04b474a35b1f7022055245298de3a6f2361060f459d65b7b1d0e5201148792ac346077aae00252de56883278efc5d15398f95520f0b87c44d6a30fdbacb396b697a8701bf1576b4e36d57f2573e568891bc6ca561c10a86fe8a39ec6d54dcbf8b789b1592364fdc2dc65a58cfaefb6e4c1d2316ae072cb8bdf61adf21f22eaa1cefef2d0134099020d9de92df8e2ea087e2d855512fa4c544b42a5d200d2ccc115d1ee72b9cff42520daf3dc9f411732d7443418c31e68965ea2b67217ace219a4a51e350fca141a243d906f7c73dbac4de3d4f3bed56e0f7eab975a7bf67ed21f2e4dfc80a0cd0bb78f83b4f19e73e7e7ff223dcdd6d336b99bf01c60faae7ce88ee3e551f35deb7b8b3e70b758b940d644673b5d53837b902f1d88f236d94467588d7045cd9b0ce35c72579c6176900fe78a92d11217634bdebd8fbb6338c905fc489e17bfe1b277d36851bd3a5768691deab4076254a469dde8712190243e693ebb4830521c1860fb3e3f30dc249df09bfa51eb6f",
     "password_file": "020173db9eeec10ccfc4946c5674d3f5a9b76738cc0d5a68b108bd334cde85b49e29a68973cb485f88cb2a2f204ff9ce71f80bf27380bbc707798c4ed7b88e2c85d33280fa66615afa29e71cc76873a2fea65d54b20425ed3c40671c4f7a2c645af8ed0191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c3897be3be74cfc2db573d5937c7008b347a60e6c6edb356164a661d28a33eac73c",
     "export_key": "b107a66f22beb9b180e6526edc62ccf79b02ec10a5caf6e079ed67cdbfd81d53",
     "session_key": "1acf0252ecb0e3e81235f5c87eb0e30c9decd435e57c344258cf1044946e991f75e55d9d4c023b39abb1e17aa3c081dcd7879058aa7b7a8032da1079be52c37c"
@@ -1391,7 +1552,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_KEM_P521: &str = r#"
     "credential_finalization": "e1f20d16260246ce40f328f06eae70728c54f77e5ddcccd68daccaf0177f9b37a1bedcbb99db8b40e57264e35fbce4e1d9c9ff4f1aeccb48f52ea089bc9a4b18",
     "client_registration_state": "34786051c54dfe7a8386928e812386cc83d84dc595bc9efd6d82fee5d5be24109d2508e3fa5575483e8973eb72f59f7503510d65a177ad3d0db25e4c62c8d34eaea0516ab6cc8967b2d25e597af2e33f619e9bc4312059cb68faeb1aec655cee76",
     "client_login_state": "34786051c54dfe7a8386928e812386cc83d84dc595bc9efd6d82fee5d5be24109d2508e3fa5575483e8973eb72f59f7503510d65a177ad3d0db25e4c62c8d34eaea0516ab6cc8967b2d25e597af2e33f619e9bc4312059cb68faeb1aec655cee76d7d6ba25df2b8fe865bb2f3a94db41b56d7275384017c033e1a62d5536d39d8f02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd14efb801fc42866818b18dc08d7153b5e337e10c5628d3a83ca55067f6bc294a53ac10a2ee4c122c5e53ba1cac538c4826e7bb23a649559c4c7b771b832f649f9a15591a707c05c517b668d20a20bfa7393fc46a8ee8b7ed46365748b28b536827bc670580871bfe4cef0a32892a1ae2c160271ec6d850c863ba2470ba0cad56c195762bdcbd8461a9474e1b62a1276134c910ac18b7cb6f81c7ef76a31fcb84fcb9f8cd5027f9b0b2bbc22ebfc1373141d5515bf8a1572a9c084f7e730919a15c6a66d7862838a15619c548d33269e6e608ca94020d520c1bba474428b79b9e4b5622131f7ec8b0fbc70253c35d4f9c55a40c04d6b5820a62382b57920c5598f769276780255e67c563773f2384d053a6aac0a9e16d090040830794cc9eebc0f8b5866def1ac25982a9f2057ae4b537b043dfdfa07df8668c9470d0302c5b2212c16a731f604c5d04ab252241d9ca07767ca5cea52bb3c4547f73163c0b4055927730e63ab7ce92de7fb2f3d9a59a833bdf023636ab8aa44da9b03791074f0510bd80b93b9c072729329519f04c91eed099ed621414045789edc1737ba6e3ed7640a18b0c38bb945178d9e404825575b1ac8433138178b58a3e820b6f4b5bc67f4a1ad72b7fc4392e4d96ed636a0e1793cbe214e03815633531fb659c92d339e3353840d33a0422947194ca40a33be96196fcf080a68d7a0050292cd3a7ebc69a0d88817abf07459aa3ce0999018109a787a6980ec44707871d8dc2fe2b335210386424abfdf049f0b1680e6303384409cd484c8071c1529bb3d3839c09718641ca41fb8aa9cdd354ba50351dd836426b0c38845042d2b2212734a45232f1c86a4befa4b303354f50cc3f6390aeba5caa9bb5ef1bc7b6f9605cbe29a09695e85c5c8464caa46e5259ab95930ac2219c48ac5ac7362326d22b43041c3c567ca527e038c37d62554991b4b17ae3b8857be02b6c4aa70719bb0e4e89755662c7428ced88359309a986b6464ebc6cbe3062ae3057028489daafc530ca2613f975dd44313a0175aeda94ad106c3392864fcc906080cbfb58a2b9257b742a0874ee76749f0640eec02ed201f55b50fc27740d0a54929271855f72bc3e37c5d615696d0a4f4e9954a90a748482126927951e5c2451a436592283c325d497cc7135c702fb54703eb6d5c220ecbb97574b932cfcc970cb65d4a43c5f285630e55644e098d4403bda37a5c02a4a4c1d341ad8087411a8fdabb7346132bebc5be668461b99a76fe453ebf746e7d3774767298619aab0b02697361c0f6e88309618403586988f2110b5cc8de9380f1a2c86b999c726202f0ea4932b7a07320b147c30dc71572628608a65b39315b405bb63649b67594d7a1c7fa1f2f0a66dfea832da4a2f2639116278cef9438b4db24f7e937d15c834cbba531c54cad85254b44a42062221f1467f42cc5a254628e66801028aab999870a80af7c9a1bb260baf3fc3fc6ec8c0354cfacf3c2a0b5bed8b77e23806a287823b515a84e9b55c17a35d3bc28eb138bb982b6178c1162db1f0859267094924ea0cf942918e2504802ac883f59479069c8b0d75fe067a39b9341fb33ad7c53677f58bd00dd11e59b93f8050182d8275e76b2c23fa6a563c7a1d9c0dacbf4876f4cec049f0ecb60fbdb73aca8d4522b01b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a7d7d6ba25df2b8fe865bb2f3a94db41b56d7275384017c033e1a62d5536d39d8f6b5546a5c40b0f91c1fb6848e480a7acb6cc4e4927f55323cf088da41537c3c36379375ef4833489492f4a579abd4a29a2f863f29b03e8ca3e21d528eb651d3774c5f8fcbec28054c36938f30b756cf06fa5809f29157ae33608f31728f843bdd4bbc6e059201a542425595fca152d9f98c8b8119d5169cfce919ea39cbe59490ba4f982c4dbb6934b12cdfba3657554b0c56563a33814145a911bad7a624d31e1992c3199afd2935f70b6179664571772f75002fc053e4ab742f50c29f0f21ba3b8b9084819fa3806f0c4ad46c451faf97aaed931e5731ab53464c1a363cfba51b13267cd46228a0936c0747dc31a139c8c640e3448126a558b9bc6bbeb39a188102de989017817aeb0b880641d0f344011071b072093dc3815f164a7c77a09db7b829642546beaa8748b0909a0205e9b44d3835d0c1985c0a961e219b995e95e0c66ab3699c2f560b06236caf05035c49abd7f01554662c799b9672ed1a201caa45746a15280bbc2c752efd990950a40d8a10acff90edccb6c62f263751396bada632a15964b2312d0d8a8d6c8396e7317f7c844c725b1774264fc8744ecdb18c1c3cc08f27a336144a362a7fed3481aa1c7662b78c9e12b2e0c866acc62ae7a00dc663f11443887247b2365bbd0c071745723a0c93bd9f36d9431807a62b6bfd34ca4eccbc900997b1c24fa89789fd389b2917404525a84602777f73540c637a9921db3c88503a57cee46753da3c3fe44501be1c38037875a95b7d619bc4e608a5a5c794e840c1ee691ad3a2b4d473cea7265e9e26eaf7475e071b5faa6a50fe13496269b1aa65a0b7c6f6d5b2a48eac78bf7b11e39c04c4baf173b19ac1a76f4a6757dda28eaf38b36cc91a931141f4c3223c1a1ddebbfdf64ccb83a9f65358fc4cb07ce193c435c5300d006ce1b9e94f682b2d61cedd57937b3c4f3126a471610924b096d386269c0b00fd9a15ea8b682fb3a545502c7b697a9b36dc5b1193a7ace69c07fdea11bd7755ac2c233d17745ba673087938aa1f2c661ec30f9fb05e54b7aa8395823ec6824e704bb73c340583c1193bcd7ab57fec7cf5502850cb91f4a94a246382a4f99389db981a6337922e9450bd7b00f5a798d5887ed5043f609679779b4d2036e62c566cce94adbd8afa9e69ad8da1de39aa071e9b8de336902b18111240d9bb45fbd3575a3467ec1352739d85b21e49d331156c6f70ca6c4abea03cbc5f4beba89a4062c16c1044072ca10d9350b409b960c080426b0409a628d52e6b5b1b24bce42c90a4ca95aa6ce21ca7befa464f39a5ab5ca65cb1bb108eb0b7ec6518c95610e03aea1c78ecf0b97b43393ced4a2676833c41104f8c4a20d58b874bc2eda6abbf6b5bafc675e0ceb3b08b9555ed298fff7ab7ef8be581a336c79502abaa9c7c77e5335234c25b4b72a9b9836548139c20f0499ef912dde537989812ded1aa9cb8905f4c52f93d53e925a6c33bb280076051af9b4abb0b75c6949c9b01b682cc921db9004c4409570156b47b5d99023b88807fa368c60eaca27158904fb459aba583edb709e8c70aba26edf888ee0a9285cd008ace73c68c69187948d7aa899363103067070a7b21e30b32c0bca9372874252036b4efb801fc42866818b18dc08d7153b5e337e10c5628d3a83ca55067f6bc294a53ac10a2ee4c122c5e53ba1cac538c4826e7bb23a649559c4c7b771b832f649f9a15591a707c05c517b668d20a20bfa7393fc46a8ee8b7ed46365748b28b536827bc670580871bfe4cef0a32892a1ae2c160271ec6d850c863ba2470ba0cad56c195762bdcbd8461a9474e1b62a1276134c910ac18b7cb6f81c7ef76a31fcb84fcb9f8cd5027f9b0b2bbc22ebfc1373141d5515bf8a1572a9c084f7e730919a15c6a66d7862838a15619c548d33269e6e608ca94020d520c1bba474428b79b9e4b5622131f7ec8b0fbc70253c35d4f9c55a40c04d6b5820a62382b57920c5598f769276780255e67c563773f2384d053a6aac0a9e16d090040830794cc9eebc0f8b5866def1ac25982a9f2057ae4b537b043dfdfa07df8668c9470d0302c5b2212c16a731f604c5d04ab252241d9ca07767ca5cea52bb3c4547f73163c0b4055927730e63ab7ce92de7fb2f3d9a59a833bdf023636ab8aa44da9b03791074f0510bd80b93b9c072729329519f04c91eed099ed621414045789edc1737ba6e3ed7640a18b0c38bb945178d9e404825575b1ac8433138178b58a3e820b6f4b5bc67f4a1ad72b7fc4392e4d96ed636a0e1793cbe214e03815633531fb659c92d339e3353840d33a0422947194ca40a33be96196fcf080a68d7a0050292cd3a7ebc69a0d88817abf07459aa3ce0999018109a787a6980ec44707871d8dc2fe2b335210386424abfdf049f0b1680e6303384409cd484c8071c1529bb3d3839c09718641ca41fb8aa9cdd354ba50351dd836426b0c38845042d2b2212734a45232f1c86a4befa4b303354f50cc3f6390aeba5caa9bb5ef1bc7b6f9605cbe29a09695e85c5c8464caa46e5259ab95930ac2219c48ac5ac7362326d22b43041c3c567ca527e038c37d62554991b4b17ae3b8857be02b6c4aa70719bb0e4e89755662c7428ced88359309a986b6464ebc6cbe3062ae3057028489daafc530ca2613f975dd44313a0175aeda94ad106c3392864fcc906080cbfb58a2b9257b742a0874ee76749f0640eec02ed201f55b50fc27740d0a54929271855f72bc3e37c5d615696d0a4f4e9954a90a748482126927951e5c2451a436592283c325d497cc7135c702fb54703eb6d5c220ecbb97574b932cfcc970cb65d4a43c5f285630e55644e098d4403bda37a5c02a4a4c1d341ad8087411a8fdabb7346132bebc5be668461b99a76fe453ebf746e7d3774767298619aab0b02697361c0f6e88309618403586988f2110b5cc8de9380f1a2c86b999c726202f0ea4932b7a07320b147c30dc71572628608a65b39315b405bb63649b67594d7a1c7fa1f2f0a66dfea832da4a2f2639116278cef9438b4db24f7e937d15c834cbba531c54cad85254b44a42062221f1467f42cc5a254628e66801028aab999870a80af7c9a1bb260baf3fc3fc6ec8c0354cfacf3c2a0b5bed8b77e23806a287823b515a84e9b55c17a35d3bc28eb138bb982b6178c1162db1f0859267094924ea0cf942918e2504802ac883f59479069c8b0d75fe067a39b9341fb33ad7c53677f58bd00dd11e59b93f8050182d8275e76b2c23fa6a563c7a1d9c0dacbf4876f4cec049f0ecb60fbdb73aca8d4522b0af81d419605c8d57632d99b37c1a0c129428b57371d31bc501da391a6afdb769d2508e3fa5575483e8973eb72f59f7501826e767f2960694852b4231c7a4b4e",
-    "server_login_state": "7d926466f9e143973e959f5870248bc6d85a8669bc33df13078657e6827515c5dcab7d29e7eb884d95de7a3814d7886a6749970636968cc35057811513d629b2e1f20d16260246ce40f328f06eae70728c54f77e5ddcccd68daccaf0177f9b37a1bedcbb99db8b40e57264e35fbce4e1d9c9ff4f1aeccb48f52ea089bc9a4b184efb801fc42866818b18dc08d7153b5e337e10c5628d3a83ca55067f6bc294a53ac10a2ee4c122c5e53ba1cac538c4826e7bb23a649559c4c7b771b832f649f9a15591a707c05c517b668d20a20bfa7393fc46a8ee8b7ed46365748b28b536827bc670580871bfe4cef0a32892a1ae2c160271ec6d850c863ba2470ba0cad56c195762bdcbd8461a9474e1b62a1276134c910ac18b7cb6f81c7ef76a31fcb84fcb9f8cd5027f9b0b2bbc22ebfc1373141d5515bf8a1572a9c084f7e730919a15c6a66d7862838a15619c548d33269e6e608ca94020d520c1bba474428b79b9e4b5622131f7ec8b0fbc70253c35d4f9c55a40c04d6b5820a62382b57920c5598f769276780255e67c563773f2384d053a6aac0a9e16d090040830794cc9eebc0f8b5866def1ac25982a9f2057ae4b537b043dfdfa07df8668c9470d0302c5b2212c16a731f604c5d04ab252241d9ca07767ca5cea52bb3c4547f73163c0b4055927730e63ab7ce92de7fb2f3d9a59a833bdf023636ab8aa44da9b03791074f0510bd80b93b9c072729329519f04c91eed099ed621414045789edc1737ba6e3ed7640a18b0c38bb945178d9e404825575b1ac8433138178b58a3e820b6f4b5bc67f4a1ad72b7fc4392e4d96ed636a0e1793cbe214e03815633531fb659c92d339e3353840d33a0422947194ca40a33be96196fcf080a68d7a0050292cd3a7ebc69a0d88817abf07459aa3ce0999018109a787a6980ec44707871d8dc2fe2b335210386424abfdf049f0b1680e6303384409cd484c8071c1529bb3d3839c09718641ca41fb8aa9cdd354ba50351dd836426b0c38845042d2b2212734a45232f1c86a4befa4b303354f50cc3f6390aeba5caa9bb5ef1bc7b6f9605cbe29a09695e85c5c8464caa46e5259ab95930ac2219c48ac5ac7362326d22b43041c3c567ca527e038c37d62554991b4b17ae3b8857be02b6c4aa70719bb0e4e89755662c7428ced88359309a986b6464ebc6cbe3062ae3057028489daafc530ca2613f975dd44313a0175aeda94ad106c3392864fcc906080cbfb58a2b9257b742a0874ee76749f0640eec02ed201f55b50fc27740d0a54929271855f72bc3e37c5d615696d0a4f4e9954a90a748482126927951e5c2451a436592283c325d497cc7135c702fb54703eb6d5c220ecbb97574b932cfcc970cb65d4a43c5f285630e55644e098d4403bda37a5c02a4a4c1d341ad8087411a8fdabb7346132bebc5be668461b99a76fe453ebf746e7d3774767298619aab0b02697361c0f6e88309618403586988f2110b5cc8de9380f1a2c86b999c726202f0ea4932b7a07320b147c30dc71572628608a65b39315b405bb63649b67594d7a1c7fa1f2f0a66dfea832da4a2f2639116278cef9438b4db24f7e937d15c834cbba531c54cad85254b44a42062221f1467f42cc5a254628e66801028aab999870a80af7c9a1bb260baf3fc3fc6ec8c0354cfacf3c2a0b5bed8b77e23806a287823b515a84e9b55c17a35d3bc28eb138bb982b6178c1162db1f0859267094924ea0cf942918e2504802ac883f59479069c8b0d75fe067a39b9341fb33ad7c53677f58bd00dd11e59b93f8050182d8275e76b2c23fa6a563c7a1d9c0dacbf4876f4cec049f0ecb60fbdb73aca8d4522b516c9af4a6b1e9e8d88b68c04f07e87b0a615c7fed7f257a20376cfbfb472a27f48c58d9d96a3c883d35c21d9d4b25a251b17f3aa9e53972299fb861f191602829bbc2b0f4e95a65b564c375d11912dd13224d1a92832e41a0938950800e1082bf263cc13d84aba92d141e9bcb0e6dd676aef3e67c9e82452b1b03832a35e27352b00faf381d288599e2cde993d5df5527b0f423f76a46d9ea23515a877d8a156c3cddd6d62c956e1d74769a584d4438f0d78b4120c60268297fabafe2259d5007daa1c7391ec3d3f074ae32f7a38e1fd918751b6d001b2b5dff3c0b2b158125bf3fa4bcc5baf85cbbbb37f18fe4de7e437c1a9a4fc4d2a51a2d8d271296e3b63712a879c5663fe7499c69835bb90170e92703adc96537df29421895e50e32e48979cb2b644a1bcd39806b2e5165dada17df6aa2577992c0f49f46b6b63744dc2fef9eef67009b50a51b9fb1501abfd22dcda691805e63f1742b145e148cf1ae41d59a05a1182bf2c0f807d5a166cf3c5bd08202f0bd94376372a49094430af10893fade192224dac19beeb17b09ea00b1888e5bf6777e802692fd1772087a66f108d9b27fe0b32ccf0750ed284bd354f60e9880bae765f91f8926f5e7c1992d0d25f5f379b52d4013585a78d8b9f5b093549759a3e03ea74e33356e1a30331c96e820cd9be9d96565b31cfee5529f3723d1f929521b40bcdcf053cb5954f58c8676e7ab5e8da1fcc5c0cab4fa7ebc15c96597a049adabe25721af524e06df6187cf184a7101dca867278e251f310399e55f1c09cebed0463856b8c40121c083cccf383a238afe3999e938ef12db61f5e7aed12883264b994581b7ecdcaae379955ddcd4b686d618903da25fefe333668dc8793f8705f811f466f820616d592e32f28f32d28ac7bb37640e23b7b4c8e474886c322a295d6d1269ec2685b99b2b66403d7222a22e1e5746553bdd7badda01a8b192558f0e81daa2e43533e5004841ea7a9bfbf5a45f8cfc95daaa776362a96c41e9cba99ed6e2f98180eda016af839e933e525ad0c0a9b033dfad448b5d17ba2ea971598a1899692f33930d59c090f6200da0c64794be2d2023e0fce940d725cde5d5ca8b82b9c5f05af98fc1496aa9d498c1b359a6e8083db260ec3b2faaec7ede9276a25e51d8346a70299e003f14ca3747e902700eacfc92349e7e3174bf5fb5e4452b5f62d2f6a2a4c7dc0ab1e9e8ac4622799af931f2bea6bee69127fd880f82ab75400634d6c342d285756722aae7cb9f1be6a5474ab7f783f1cc9cfac99158da092bc131d6d6bf08b2603820811348e6f8d9c35c7885fb76fedead99dd50be49ef466508f2c2293bfcedb29e9aa6bd6e289da961be235bd81527370723e6d9bcf06b26265847a5715b9f3651c252b21cf7a77ee7c2ae22e4ac97576833630e37796ce0fc572ee37d275282d6b8dd1ce5edaa298baa9b948432417c4d6277206bb1356c26732f9c532abe6c2e369338559850236621d9b4a116411e8f9d2fd2e4072380c2336fb1f88e22",
+    "server_login_state": "7d926466f9e143973e959f5870248bc6d85a8669bc33df13078657e6827515c5dcab7d29e7eb884d95de7a3814d7886a6749970636968cc35057811513d629b2beebc340c3a01d0736112013198f557217c6108e824c67b9363a32bbaa4df226d0be852efff76c0a5eede6c8cb351dec36e4ca450d33c8d2960d8c2f169be219e1f20d16260246ce40f328f06eae70728c54f77e5ddcccd68daccaf0177f9b37a1bedcbb99db8b40e57264e35fbce4e1d9c9ff4f1aeccb48f52ea089bc9a4b184efb801fc42866818b18dc08d7153b5e337e10c5628d3a83ca55067f6bc294a53ac10a2ee4c122c5e53ba1cac538c4826e7bb23a649559c4c7b771b832f649f9a15591a707c05c517b668d20a20bfa7393fc46a8ee8b7ed46365748b28b536827bc670580871bfe4cef0a32892a1ae2c160271ec6d850c863ba2470ba0cad56c195762bdcbd8461a9474e1b62a1276134c910ac18b7cb6f81c7ef76a31fcb84fcb9f8cd5027f9b0b2bbc22ebfc1373141d5515bf8a1572a9c084f7e730919a15c6a66d7862838a15619c548d33269e6e608ca94020d520c1bba474428b79b9e4b5622131f7ec8b0fbc70253c35d4f9c55a40c04d6b5820a62382b57920c5598f769276780255e67c563773f2384d053a6aac0a9e16d090040830794cc9eebc0f8b5866def1ac25982a9f2057ae4b537b043dfdfa07df8668c9470d0302c5b2212c16a731f604c5d04ab252241d9ca07767ca5cea52bb3c4547f73163c0b4055927730e63ab7ce92de7fb2f3d9a59a833bdf023636ab8aa44da9b03791074f0510bd80b93b9c072729329519f04c91eed099ed621414045789edc1737ba6e3ed7640a18b0c38bb945178d9e404825575b1ac8433138178b58a3e820b6f4b5bc67f4a1ad72b7fc4392e4d96ed636a0e1793cbe214e03815633531fb659c92d339e3353840d33a0422947194ca40a33be96196fcf080a68d7a0050292cd3a7ebc69a0d88817abf07459aa3ce0999018109a787a6980ec44707871d8dc2fe2b335210386424abfdf049f0b1680e6303384409cd484c8071c1529bb3d3839c09718641ca41fb8aa9cdd354ba50351dd836426b0c38845042d2b2212734a45232f1c86a4befa4b303354f50cc3f6390aeba5caa9bb5ef1bc7b6f9605cbe29a09695e85c5c8464caa46e5259ab95930ac2219c48ac5ac7362326d22b43041c3c567ca527e038c37d62554991b4b17ae3b8857be02b6c4aa70719bb0e4e89755662c7428ced88359309a986b6464ebc6cbe3062ae3057028489daafc530ca2613f975dd44313a0175aeda94ad106c3392864fcc906080cbfb58a2b9257b742a0874ee76749f0640eec02ed201f55b50fc27740d0a54929271855f72bc3e37c5d615696d0a4f4e9954a90a748482126927951e5c2451a436592283c325d497cc7135c702fb54703eb6d5c220ecbb97574b932cfcc970cb65d4a43c5f285630e55644e098d4403bda37a5c02a4a4c1d341ad8087411a8fdabb7346132bebc5be668461b99a76fe453ebf746e7d3774767298619aab0b02697361c0f6e88309618403586988f2110b5cc8de9380f1a2c86b999c726202f0ea4932b7a07320b147c30dc71572628608a65b39315b405bb63649b67594d7a1c7fa1f2f0a66dfea832da4a2f2639116278cef9438b4db24f7e937d15c834cbba531c54cad85254b44a42062221f1467f42cc5a254628e66801028aab999870a80af7c9a1bb260baf3fc3fc6ec8c0354cfacf3c2a0b5bed8b77e23806a287823b515a84e9b55c17a35d3bc28eb138bb982b6178c1162db1f0859267094924ea0cf942918e2504802ac883f59479069c8b0d75fe067a39b9341fb33ad7c53677f58bd00dd11e59b93f8050182d8275e76b2c23fa6a563c7a1d9c0dacbf4876f4cec049f0ecb60fbdb73aca8d4522b516c9af4a6b1e9e8d88b68c04f07e87b0a615c7fed7f257a20376cfbfb472a27f48c58d9d96a3c883d35c21d9d4b25a251b17f3aa9e53972299fb861f191602829bbc2b0f4e95a65b564c375d11912dd13224d1a92832e41a0938950800e1082bf263cc13d84aba92d141e9bcb0e6dd676aef3e67c9e82452b1b03832a35e27352b00faf381d288599e2cde993d5df5527b0f423f76a46d9ea23515a877d8a156c3cddd6d62c956e1d74769a584d4438f0d78b4120c60268297fabafe2259d5007daa1c7391ec3d3f074ae32f7a38e1fd918751b6d001b2b5dff3c0b2b158125bf3fa4bcc5baf85cbbbb37f18fe4de7e437c1a9a4fc4d2a51a2d8d271296e3b63712a879c5663fe7499c69835bb90170e92703adc96537df29421895e50e32e48979cb2b644a1bcd39806b2e5165dada17df6aa2577992c0f49f46b6b63744dc2fef9eef67009b50a51b9fb1501abfd22dcda691805e63f1742b145e148cf1ae41d59a05a1182bf2c0f807d5a166cf3c5bd08202f0bd94376372a49094430af10893fade192224dac19beeb17b09ea00b1888e5bf6777e802692fd1772087a66f108d9b27fe0b32ccf0750ed284bd354f60e9880bae765f91f8926f5e7c1992d0d25f5f379b52d4013585a78d8b9f5b093549759a3e03ea74e33356e1a30331c96e820cd9be9d96565b31cfee5529f3723d1f929521b40bcdcf053cb5954f58c8676e7ab5e8da1fcc5c0cab4fa7ebc15c96597a049adabe25721af524e06df6187cf184a7101dca867278e251f310399e55f1c09cebed0463856b8c40121c083cccf383a238afe3999e938ef12db61f5e7aed12883264b994581b7ecdcaae379955ddcd4b686d618903da25fefe333668dc8793f8705f811f466f820616d592e32f28f32d28ac7bb37640e23b7b4c8e474886c322a295d6d1269ec2685b99b2b66403d7222a22e1e5746553bdd7badda01a8b192558f0e81daa2e43533e5004841ea7a9bfbf5a45f8cfc95daaa776362a96c41e9cba99ed6e2f98180eda016af839e933e525ad0c0a9b033dfad448b5d17ba2ea971598a1899692f33930d59c090f6200da0c64794be2d2023e0fce940d725cde5d5ca8b82b9c5f05af98fc1496aa9d498c1b359a6e8083db260ec3b2faaec7ede9276a25e51d8346a70299e003f14ca3747e902700eacfc92349e7e3174bf5fb5e4452b5f62d2f6a2a4c7dc0ab1e9e8ac4622799af931f2bea6bee69127fd880f82ab75400634d6c342d285756722aae7cb9f1be6a5474ab7f783f1cc9cfac99158da092bc131d6d6bf08b2603820811348e6f8d9c35c7885fb76fedead99dd50be49ef466508f2c2293bfcedb29e9aa6bd6e289da961be235bd81527370723e6d9bcf06b26265847a5715b9f3651c252b21cf7a77ee7c2ae22e4ac97576833630e37796ce0fc572ee37d275282d6b8dd1ce5edaa298baa9b948432417c4d6277206bb1356c26732f9c532abe6c2e369338559850236621d9b4a116411e8f9d2fd2e4072380c2336fb1f88e22",
     "password_file": "030095f619e2660b78bf4ddef4f1ff23b1839366f9a214b7aa9b15a5f4b94994c45aab08be769a4d1e37d1bbd32ea3247914007487afbc34face786b9dcb326002baa55a1eca0bf52b6faff5330bf6cd1f5d2c6511da4c920da9f2da98beeec1c125926850846d29c84775872e777362b73fd00191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c384216604c9da2f064937780896e19b83417592b478f7440348b524e9a1fad82f631a3f8ae1d1702a12d927e67f037b961",
     "export_key": "fdef7f35937b70422146685688e870eb110a37ec6d0b516362e3df8b0e3c60614f4e69f06efb85a7266d80245e36e0d8",
     "session_key": "7d926466f9e143973e959f5870248bc6d85a8669bc33df13078657e6827515c5dcab7d29e7eb884d95de7a3814d7886a6749970636968cc35057811513d629b2"
@@ -1430,7 +1591,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_KEM_P521: &str = r#"
     "credential_finalization": "2e040406409468152b6265ea437a6ae3346d0b329d76d8f7df88a7067280c03fa4ff6b63a4b56e90212c103124e7d03572fd980132851a6b50c26e8175deda46",
     "client_registration_state": "012be71e97d83cdb4b933911353bc06e41094bbc2d3d2b94cd3ace502fff999b7a54c5c767fe565cb3fe0390fa94fffecd8d026d6e1a31d6637867fab48183342c9a02017a4ba32aaef77ffcde95ee4cb6162b44c3bb1f3b09fecd19b03117d2ff58501773ec01c5f9a642c3defdeeeb3611a64dbe7b626ac4debb77b90acd9a1b605aea27",
     "client_login_state": "012be71e97d83cdb4b933911353bc06e41094bbc2d3d2b94cd3ace502fff999b7a54c5c767fe565cb3fe0390fa94fffecd8d026d6e1a31d6637867fab48183342c9a02017a4ba32aaef77ffcde95ee4cb6162b44c3bb1f3b09fecd19b03117d2ff58501773ec01c5f9a642c3defdeeeb3611a64dbe7b626ac4debb77b90acd9a1b605aea274239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158b02000bc84c5e5821871038d879ba46b9b37078dc192612a094a088990f193ae1cbac240638c46dd5b743e409e936efc4147a271ee11b9e7fe606864393c245883e9cd1b1d6c5272bc3ffa6a93a46621ed6b25b19c53d47c78606b55555417791187c83318055489f32551925c98dc11bb7d08170bc46e1ab2e1cf87b6b2777acd21b4939489a3acd9619210edcac3a1a04e6837f364c8f27616f5c8b220fcbbe741b684b591b1f94aecd587ab8d1aca50c901e7b4c7d89ae2c9448f2703f9d225eecfaabf5f2c69215b37f9bc23a480a9c307bca3bcafb097463b9440e4bcb68637c69c960d886133002b6a142af82bca0abc1bb1952c5d48aa8bd318750fb8a52684f00adb038121ceab20955970d35801f89611f8f6923bbd06a231786bb25536dcb2dc8d60541abcc4bfa86964c0a1f723bdf260b5190417279118f863a8cf24a46852ab810069664b4e97c7809022fb8b606788075e7b45839f873c830a729a2b293849b3d704b7a705fce39c09de34d9a8c0f28c9236504053974167f26a461557f2c4a8f19558497a89885f6ac55610829e45fdbf30b745b31a2922ca43a10b8746097083808a7383f8b420fd616eef86b550188ae052ff56450c5fa097eb802e369a094949b5a896af5a71dd0056108e7085a32a38d03d016918c2d327e601a19dc53ab30195721955789819d04fc71ebc18eb9c3750a643b1639740708702a20c3b8d88336e75d9f584903c61cda995282511e9e4936784652a2659f15b85d7bd33d25f4365a6121b36b7cfd2721ee119f18a835d578364bdbb2d8009762c344bb89a3d3e05224e03296f15a037cc58cc914f4aba2e9d00ab6d822f968468a835a6f3a90c02ca0f30592ddba4fe353c8e1da45ff220dfba210d9347e2a48599d6562e6f0cacc6436ba15bd3aa45ae23146774b831703d08f69b9bee618dd0a4f24f6557cda7192dbba9a663daf9a5954ac6c184688b9aa5f6f046d5340cfe00c6ac3430f56e3ac80439d7a711680e28a9caa5c288499c1679c4b8473646a782459a77c4973dd93ab3d51c6acc08ec48c223911cc4e7c76f6714e3cb1100de2543b592412a3a318a9a0290b99d79ba84318638733297996c426486798a0a6d16c0b0c5aac7d380c5e218adbac03e5da6f1ee980455a3792b420c4976376ca91614c5ba8d1c52c504c96078de4846c464b34a3b78ba7686b7a718e43f3a692a83437804e113babd0902e91643bb384c420bb81cac32cd54027021980b046c8e4a1b20121c84485a83df26388b9c59487b242448a1f063ed135653220830faa1c7c830a6f548e04888577971f4253b989f67102e0c3e0694547950b5dc91bc21c65abd7a16dc771ec254221e7acaa42160e8ca74177c272c49e70264d59135fb7db0f5cac18a0194f7fd589ef40a8b7e46a4db8c1ff945da5785edcd695cd77887591c5a471b100bd19ead7af9122c9dc554484cc6940ba0982232e99207adb452f185a5ee194c163fcbe904749dbc6b6a7c0cadba495bcd623c219c26c8cb24ac039ad953915426b2510b4803cb9ee950535d7894aabaea0b45e9364a1a5d023533522336031d0db291299022c1955499bb0ee71ac237777562b9af2c1a85c141292a97a41c12b50236ffb781ce56c852847abaecac5a144498249924ed5b36f77577089cc0bca0c7d54b2f2cc111e13b1b861402e23b66752aa8501849221493d2d4d1f4c81cc82e55413667a2cb5883ba4a5ca93754f8b9ba773ebe081ab2801b7ce737df54c52a2721a8d8241d5965a39f6f0c9e28197627275769f1844bacd4e680154752659029f81f046e86d81c365d1ebf22b59d2476a7ff176f9626858a74239a9fcfccacc5623588c32c0f99bec53243d8963dd7fbcfe8a2afd0034158bb7fa46020437c9652810a60f8611a9ab35c5fa2a82074455df72b7294c2f95c844a75cc2fb08a52a4906e9e69b4e557e73805a41345a105a21efb12e73f6cf5bbbca1d87ce9b41449c6b527674814a8192bf0678de909b5ad265222969878cc22a199578a05813336405eb0b9e690d9a097625e9712729645a056f82956e757c393cab0c47d70ddb0540149a006f942007d859c825c605723ccde624c297c5ea5b8cace17356790e20f32e8a1acb3920a644f111116b70f83b1eb43829c7f782fdc7002e728998c70ccee444c542055b3a551dd144df107a8d979d176095148b8df6582bbb16ceb598a0996c0b59f23d666845c713a2b0fb7eb62ab5ba593f79d38c081371d4d0435533be1dfc819c32035f3a237e4c97efd73ac9d473b6c925861722a25a652fb11438fa3d4ed08301b32c63f084df79cbada293714b3fd13c37ec549f6e6a7a6537246c01a4636114eb5206a743b12e7186ac35a02be05ce4da8bfddcba87ab8d8de180b15ac4b2d1a0cba04339b8b5432caee959b7ca55c5d7c037098ba9132ccc79450788e233be367408e3ba51f457d21843f01b414b6a70e1d042fc78b6168cb62d53727ca81c058666dc25170373a552b600967584cac41da1cb97e43a40b83382d95a6fd9342d655b3ccfb77b22a0ce8b28154e992d4f06bfffc70e8db1ccf791a3d5b25124522722e73a3a98942c9062cc491965d04d832989334aa4e905018cfbb99c5624e7653e3a58ca9d1cc2b6e806b9cb1394a05b7c7c339721405c675c81258c8aec3e6fc378a45ab7e065c56df13cb2893a28076361531be2b688f6833c0f2007a5b5968f59819a197778294fde4a6961932c81e60bcb8402c5171e80d2a81e4b9e9cd12cf5025f4084b471e60228d563afa4550d2b1fd58644a9b393f5b071d68c090112c0fc61a3a32171f725729cd42a1ba861df2240fee567ec059ae2ba3014946790a481e78320cc7c0773e79f4f0b134ba551d2760e67f319015ba7d9356aca96202c07685f676168447860203acdbb3093363b0c34948650515895340b264e13287824c50ce0f87b8e48742586693551929b02360c021ba381beb4d727832653fd310074f7c2a86aa9019c9004dbcb12a66aab3c36f30015c6e790be85952b59046428627b90634193235e4a025aa38b76e6390f50c7b31112e782447dd78953c7c2794100c315575079ce78137fbb882630104f796b3fe2892efa396575a92f1191720287b51d395002b1a309faaf862a96c5472333b4597c3ba877c1c18fe5a00ef97efc2921a57b0bc508a5aaec511e78a7e238cd4dd402cb0257c864706039a648898855a0c01b7b86fdb3c0337a3a215b1efae845fa620920642942c25995bc1775c460aa65190a9c6c249409c427abd6653967475947395400c57bb712b910b89c393791296184d850bc4c349fc739c52ab345bdf7065b712315c884102b21fecb8321f066669408e40988148713588519676c7a8a6b835ab3ba9a10276971809ceb0289ec6c8cb86d8a6a4a3588b66c272c89715716d906c2bbc35e5cb8151c1c7a8b9feebc13361b90e1e58c11c5366a46923c158ea8690049828c9af988dd4b6d8ef08eaeaaba46824cb1d6c5272bc3ffa6a93a46621ed6b25b19c53d47c78606b55555417791187c83318055489f32551925c98dc11bb7d08170bc46e1ab2e1cf87b6b2777acd21b4939489a3acd9619210edcac3a1a04e6837f364c8f27616f5c8b220fcbbe741b684b591b1f94aecd587ab8d1aca50c901e7b4c7d89ae2c9448f2703f9d225eecfaabf5f2c69215b37f9bc23a480a9c307bca3bcafb097463b9440e4bcb68637c69c960d886133002b6a142af82bca0abc1bb1952c5d48aa8bd318750fb8a52684f00adb038121ceab20955970d35801f89611f8f6923bbd06a231786bb25536dcb2dc8d60541abcc4bfa86964c0a1f723bdf260b5190417279118f863a8cf24a46852ab810069664b4e97c7809022fb8b606788075e7b45839f873c830a729a2b293849b3d704b7a705fce39c09de34d9a8c0f28c9236504053974167f26a461557f2c4a8f19558497a89885f6ac55610829e45fdbf30b745b31a2922ca43a10b8746097083808a7383f8b420fd616eef86b550188ae052ff56450c5fa097eb802e369a094949b5a896af5a71dd0056108e7085a32a38d03d016918c2d327e601a19dc53ab30195721955789819d04fc71ebc18eb9c3750a643b1639740708702a20c3b8d88336e75d9f584903c61cda995282511e9e4936784652a2659f15b85d7bd33d25f4365a6121b36b7cfd2721ee119f18a835d578364bdbb2d8009762c344bb89a3d3e05224e03296f15a037cc58cc914f4aba2e9d00ab6d822f968468a835a6f3a90c02ca0f30592ddba4fe353c8e1da45ff220dfba210d9347e2a48599d6562e6f0cacc6436ba15bd3aa45ae23146774b831703d08f69b9bee618dd0a4f24f6557cda7192dbba9a663daf9a5954ac6c184688b9aa5f6f046d5340cfe00c6ac3430f56e3ac80439d7a711680e28a9caa5c288499c1679c4b8473646a782459a77c4973dd93ab3d51c6acc08ec48c223911cc4e7c76f6714e3cb1100de2543b592412a3a318a9a0290b99d79ba84318638733297996c426486798a0a6d16c0b0c5aac7d380c5e218adbac03e5da6f1ee980455a3792b420c4976376ca91614c5ba8d1c52c504c96078de4846c464b34a3b78ba7686b7a718e43f3a692a83437804e113babd0902e91643bb384c420bb81cac32cd54027021980b046c8e4a1b20121c84485a83df26388b9c59487b242448a1f063ed135653220830faa1c7c830a6f548e04888577971f4253b989f67102e0c3e0694547950b5dc91bc21c65abd7a16dc771ec254221e7acaa42160e8ca74177c272c49e70264d59135fb7db0f5cac18a0194f7fd589ef40a8b7e46a4db8c1ff945da5785edcd695cd77887591c5a471b100bd19ead7af9122c9dc554484cc6940ba0982232e99207adb452f185a5ee194c163fcbe904749dbc6b6a7c0cadba495bcd623c219c26c8cb24ac039ad953915426b2510b4803cb9ee950535d7894aabaea0b45e9364a1a5d023533522336031d0db291299022c1955499bb0ee71ac237777562b9af2c1a85c141292a97a41c12b50236ffb781ce56c852847abaecac5a144498249924ed5b36f77577089cc0bca0c7d54b2f2cc111e13b1b861402e23b66752aa8501849221493d2d4d1f4c81cc82e55413667a2cb5883ba4a5ca93754f8b9ba773ebe081ab28b5c70e43212561d714c0bbc937e088f51943f27c0d71394085ac354b1c3eaee67a54c5c767fe565cb3fe0390fa94fffecd8d026d6e1a31d6637867fab4818334",
-    "server_login_state": "bc382cb8d96de82ba1e2df4d93a5afa18c205344d0d0a171a54c91091d0a393922652f2c9e1e7e2591779a45d41926549abbac14d49fd5a2d3c84304c81f6ab92e040406409468152b6265ea437a6ae3346d0b329d76d8f7df88a7067280c03fa4ff6b63a4b56e90212c103124e7d03572fd980132851a6b50c26e8175deda46b1d6c5272bc3ffa6a93a46621ed6b25b19c53d47c78606b55555417791187c83318055489f32551925c98dc11bb7d08170bc46e1ab2e1cf87b6b2777acd21b4939489a3acd9619210edcac3a1a04e6837f364c8f27616f5c8b220fcbbe741b684b591b1f94aecd587ab8d1aca50c901e7b4c7d89ae2c9448f2703f9d225eecfaabf5f2c69215b37f9bc23a480a9c307bca3bcafb097463b9440e4bcb68637c69c960d886133002b6a142af82bca0abc1bb1952c5d48aa8bd318750fb8a52684f00adb038121ceab20955970d35801f89611f8f6923bbd06a231786bb25536dcb2dc8d60541abcc4bfa86964c0a1f723bdf260b5190417279118f863a8cf24a46852ab810069664b4e97c7809022fb8b606788075e7b45839f873c830a729a2b293849b3d704b7a705fce39c09de34d9a8c0f28c9236504053974167f26a461557f2c4a8f19558497a89885f6ac55610829e45fdbf30b745b31a2922ca43a10b8746097083808a7383f8b420fd616eef86b550188ae052ff56450c5fa097eb802e369a094949b5a896af5a71dd0056108e7085a32a38d03d016918c2d327e601a19dc53ab30195721955789819d04fc71ebc18eb9c3750a643b1639740708702a20c3b8d88336e75d9f584903c61cda995282511e9e4936784652a2659f15b85d7bd33d25f4365a6121b36b7cfd2721ee119f18a835d578364bdbb2d8009762c344bb89a3d3e05224e03296f15a037cc58cc914f4aba2e9d00ab6d822f968468a835a6f3a90c02ca0f30592ddba4fe353c8e1da45ff220dfba210d9347e2a48599d6562e6f0cacc6436ba15bd3aa45ae23146774b831703d08f69b9bee618dd0a4f24f6557cda7192dbba9a663daf9a5954ac6c184688b9aa5f6f046d5340cfe00c6ac3430f56e3ac80439d7a711680e28a9caa5c288499c1679c4b8473646a782459a77c4973dd93ab3d51c6acc08ec48c223911cc4e7c76f6714e3cb1100de2543b592412a3a318a9a0290b99d79ba84318638733297996c426486798a0a6d16c0b0c5aac7d380c5e218adbac03e5da6f1ee980455a3792b420c4976376ca91614c5ba8d1c52c504c96078de4846c464b34a3b78ba7686b7a718e43f3a692a83437804e113babd0902e91643bb384c420bb81cac32cd54027021980b046c8e4a1b20121c84485a83df26388b9c59487b242448a1f063ed135653220830faa1c7c830a6f548e04888577971f4253b989f67102e0c3e0694547950b5dc91bc21c65abd7a16dc771ec254221e7acaa42160e8ca74177c272c49e70264d59135fb7db0f5cac18a0194f7fd589ef40a8b7e46a4db8c1ff945da5785edcd695cd77887591c5a471b100bd19ead7af9122c9dc554484cc6940ba0982232e99207adb452f185a5ee194c163fcbe904749dbc6b6a7c0cadba495bcd623c219c26c8cb24ac039ad953915426b2510b4803cb9ee950535d7894aabaea0b45e9364a1a5d023533522336031d0db291299022c1955499bb0ee71ac237777562b9af2c1a85c141292a97a41c12b50236ffb781ce56c852847abaecac5a144498249924ed5b36f77577089cc0bca0c7d54b2f2cc111e13b1b861402e23b66752aa8501849221493d2d4d1f4c81cc82e55413667a2cb5883ba4a5ca93754f8b9ba773ebe081ab28fc69f6bc7768d56f899c147823fa886e1553710206ecda3a702ff741f390b31355fc9a17fb0d5db66e8af96c91e81e51c71939d20227f1a2a6bc1a916afe588014454e6dc0d25bee911717cad0842e68b38e85a7fd69aedc778c8d54bdf036a5e1991ebda1ad9da7b3b2fcb9394a45dd2a93523125604a7c4d5474775a11d362507f5e9dd7fffd2da11b81f7ca16183ff63e3e57f79aa9d4435ae09e50ee8e2e7c2f8ed70ab5d5abfb959802867ce86e04d1d0af3995c00e26985c000a7b249a88e2a3acc849431df970f2e4947725212575d2df99396a63f285ad9733634b905748785b1e03cb9111c7f1ea90889ff1f7a46e01b605a369e9924c40124ef6507d879264f0f3596043308836e5e2ddfff906073ace8d88f1167fab16c52dce6037e3712f912d8fcfffca69014422f81c1c6e74dff415f478584b213fdc3af3aa1bf9e2c633f7e5ab4bc1d954798004c64361661f3f8a98b37cb8be3d3bbf8c18af5e23daec10060cc15ab458a08b7dde4114d003e47f3b061363a08a5f015396ae339b899a155d91bac0a21d45d734926667233396ca2cd7c442e3e4f32b4f52587f32d9e4be88c9961b8189526988e2d79740c356960b1d90f2e470fba839de09af05af6a6b74a43076bc2bb973e8614416a53de265ddd4e58b3a66a47a35a0247ca80f96f4b0e40ec3b0419b93ce696d00b11f986d81dafe34f1a103fa1578b31d7724a9ceddc202fec936f7f225164e81715d17bc0399a850d9f459cae07034179369d756f4e15ed7ea548c7cf43db097af316c637fe738191f595447679e6374a7e8e18edb5bfbbf050d8e0b949cbb78310a875e1a0d1fee1c3351591f096a7dc65e9b759843e5ddecdc596585116a772b3c5a0c946433cf69d862e9447e4756ec0804b2ba6ee09e79d61f6f1e8d29bc7f5bf8501d57c0d78a454eb0dec61a4ed1d6a7ea1ba397682937f245f77d0b690e033c2a93022761af4fcf5edc4e8ebe239fab5fdab6f03a2e806ea5e6f59738398518aa048e794ddfab1b9747a2dbadc0c8309a8c9f149e136f18aef1b80fcf9ce09b47732b550a31333eff86db3ccbfafe3cba9a8f7761f82aa4202e4f4af47f1d6d0bd5e8edcddc9ae54652be2c912cf8ff79d575243f9071d71ebcbe0c594fc62126903685ea9785a4d24d9504dd2914272bdccd17eb8f561513d0bb31562ab037160f868b25f6087fc17b135bb1b41e7a9884ffaccba8924773b06593334a84fd0de1e1c08e4a69dbf94b50310b8b0e431234bb9dfcc271d82694f3e61d3890c1393d5b1aca8a1f8fc7c6785cc5bf21111f84a93ecf131fc54f3eaed53369007b9e03cdf561b143f71cdd6ef2d4a7eb134c3a3d4c6b7d07e20d16fc5934f7c58ffa9c5e729ac40560fbfea8df3c27185420c4a0180b46b688e6395825e3939aa092347099fdcf701857299301cb56e75e1db263238015622d8aca61f41cc7d8658d6485a829e16008fa722587ae3c28421b8a0ae68a8484aaf724365e031362df75496a8b07f704c398b313",
+    "server_login_state": "bc382cb8d96de82ba1e2df4d93a5afa18c205344d0d0a171a54c91091d0a393922652f2c9e1e7e2591779a45d41926549abbac14d49fd5a2d3c84304c81f6ab9beb507e09e6889f1cadd0fd8f6c075afec1981c1a35a472af6ee064b8a191ec0082c3fea1b84ba01458142bcd03a9b0882b1648a7ecd55d7b1ede69f128f1d0e2e040406409468152b6265ea437a6ae3346d0b329d76d8f7df88a7067280c03fa4ff6b63a4b56e90212c103124e7d03572fd980132851a6b50c26e8175deda46b1d6c5272bc3ffa6a93a46621ed6b25b19c53d47c78606b55555417791187c83318055489f32551925c98dc11bb7d08170bc46e1ab2e1cf87b6b2777acd21b4939489a3acd9619210edcac3a1a04e6837f364c8f27616f5c8b220fcbbe741b684b591b1f94aecd587ab8d1aca50c901e7b4c7d89ae2c9448f2703f9d225eecfaabf5f2c69215b37f9bc23a480a9c307bca3bcafb097463b9440e4bcb68637c69c960d886133002b6a142af82bca0abc1bb1952c5d48aa8bd318750fb8a52684f00adb038121ceab20955970d35801f89611f8f6923bbd06a231786bb25536dcb2dc8d60541abcc4bfa86964c0a1f723bdf260b5190417279118f863a8cf24a46852ab810069664b4e97c7809022fb8b606788075e7b45839f873c830a729a2b293849b3d704b7a705fce39c09de34d9a8c0f28c9236504053974167f26a461557f2c4a8f19558497a89885f6ac55610829e45fdbf30b745b31a2922ca43a10b8746097083808a7383f8b420fd616eef86b550188ae052ff56450c5fa097eb802e369a094949b5a896af5a71dd0056108e7085a32a38d03d016918c2d327e601a19dc53ab30195721955789819d04fc71ebc18eb9c3750a643b1639740708702a20c3b8d88336e75d9f584903c61cda995282511e9e4936784652a2659f15b85d7bd33d25f4365a6121b36b7cfd2721ee119f18a835d578364bdbb2d8009762c344bb89a3d3e05224e03296f15a037cc58cc914f4aba2e9d00ab6d822f968468a835a6f3a90c02ca0f30592ddba4fe353c8e1da45ff220dfba210d9347e2a48599d6562e6f0cacc6436ba15bd3aa45ae23146774b831703d08f69b9bee618dd0a4f24f6557cda7192dbba9a663daf9a5954ac6c184688b9aa5f6f046d5340cfe00c6ac3430f56e3ac80439d7a711680e28a9caa5c288499c1679c4b8473646a782459a77c4973dd93ab3d51c6acc08ec48c223911cc4e7c76f6714e3cb1100de2543b592412a3a318a9a0290b99d79ba84318638733297996c426486798a0a6d16c0b0c5aac7d380c5e218adbac03e5da6f1ee980455a3792b420c4976376ca91614c5ba8d1c52c504c96078de4846c464b34a3b78ba7686b7a718e43f3a692a83437804e113babd0902e91643bb384c420bb81cac32cd54027021980b046c8e4a1b20121c84485a83df26388b9c59487b242448a1f063ed135653220830faa1c7c830a6f548e04888577971f4253b989f67102e0c3e0694547950b5dc91bc21c65abd7a16dc771ec254221e7acaa42160e8ca74177c272c49e70264d59135fb7db0f5cac18a0194f7fd589ef40a8b7e46a4db8c1ff945da5785edcd695cd77887591c5a471b100bd19ead7af9122c9dc554484cc6940ba0982232e99207adb452f185a5ee194c163fcbe904749dbc6b6a7c0cadba495bcd623c219c26c8cb24ac039ad953915426b2510b4803cb9ee950535d7894aabaea0b45e9364a1a5d023533522336031d0db291299022c1955499bb0ee71ac237777562b9af2c1a85c141292a97a41c12b50236ffb781ce56c852847abaecac5a144498249924ed5b36f77577089cc0bca0c7d54b2f2cc111e13b1b861402e23b66752aa8501849221493d2d4d1f4c81cc82e55413667a2cb5883ba4a5ca93754f8b9ba773ebe081ab28fc69f6bc7768d56f899c147823fa886e1553710206ecda3a702ff741f390b31355fc9a17fb0d5db66e8af96c91e81e51c71939d20227f1a2a6bc1a916afe588014454e6dc0d25bee911717cad0842e68b38e85a7fd69aedc778c8d54bdf036a5e1991ebda1ad9da7b3b2fcb9394a45dd2a93523125604a7c4d5474775a11d362507f5e9dd7fffd2da11b81f7ca16183ff63e3e57f79aa9d4435ae09e50ee8e2e7c2f8ed70ab5d5abfb959802867ce86e04d1d0af3995c00e26985c000a7b249a88e2a3acc849431df970f2e4947725212575d2df99396a63f285ad9733634b905748785b1e03cb9111c7f1ea90889ff1f7a46e01b605a369e9924c40124ef6507d879264f0f3596043308836e5e2ddfff906073ace8d88f1167fab16c52dce6037e3712f912d8fcfffca69014422f81c1c6e74dff415f478584b213fdc3af3aa1bf9e2c633f7e5ab4bc1d954798004c64361661f3f8a98b37cb8be3d3bbf8c18af5e23daec10060cc15ab458a08b7dde4114d003e47f3b061363a08a5f015396ae339b899a155d91bac0a21d45d734926667233396ca2cd7c442e3e4f32b4f52587f32d9e4be88c9961b8189526988e2d79740c356960b1d90f2e470fba839de09af05af6a6b74a43076bc2bb973e8614416a53de265ddd4e58b3a66a47a35a0247ca80f96f4b0e40ec3b0419b93ce696d00b11f986d81dafe34f1a103fa1578b31d7724a9ceddc202fec936f7f225164e81715d17bc0399a850d9f459cae07034179369d756f4e15ed7ea548c7cf43db097af316c637fe738191f595447679e6374a7e8e18edb5bfbbf050d8e0b949cbb78310a875e1a0d1fee1c3351591f096a7dc65e9b759843e5ddecdc596585116a772b3c5a0c946433cf69d862e9447e4756ec0804b2ba6ee09e79d61f6f1e8d29bc7f5bf8501d57c0d78a454eb0dec61a4ed1d6a7ea1ba397682937f245f77d0b690e033c2a93022761af4fcf5edc4e8ebe239fab5fdab6f03a2e806ea5e6f59738398518aa048e794ddfab1b9747a2dbadc0c8309a8c9f149e136f18aef1b80fcf9ce09b47732b550a31333eff86db3ccbfafe3cba9a8f7761f82aa4202e4f4af47f1d6d0bd5e8edcddc9ae54652be2c912cf8ff79d575243f9071d71ebcbe0c594fc62126903685ea9785a4d24d9504dd2914272bdccd17eb8f561513d0bb31562ab037160f868b25f6087fc17b135bb1b41e7a9884ffaccba8924773b06593334a84fd0de1e1c08e4a69dbf94b50310b8b0e431234bb9dfcc271d82694f3e61d3890c1393d5b1aca8a1f8fc7c6785cc5bf21111f84a93ecf131fc54f3eaed53369007b9e03cdf561b143f71cdd6ef2d4a7eb134c3a3d4c6b7d07e20d16fc5934f7c58ffa9c5e729ac40560fbfea8df3c27185420c4a0180b46b688e6395825e3939aa092347099fdcf701857299301cb56e75e1db263238015622d8aca61f41cc7d8658d6485a829e16008fa722587ae3c28421b8a0ae68a8484aaf724365e031362df75496a8b07f704c398b313",
     "password_file": "020197c811fdaa93a80cb94bb8be013424acfc2767b93f554bb5e70c28b1f77829cb5570f60e9eb490159f799324e017afba0c04f843a013da6a00290166b80eb678240ea7f95f3016c49752e34ab060ae19ad19cc24212ddd005f4bae4fb6533a4690550b3feb300768e6c7dffb0f238230bd8886fb7142153485c67f260baea0a2930191d6e31507e9e3af8e611afcc4c542697ee8bf67f4135e81cf8e9f1d528c3846621e4d65ddf941eaf2f1b68bda57f81b968c24190bc5744a58f0be15bfcc634facbd2d9432ae426cc67ec826dc1fc1ae1023613ccda8f46d3566ec8f380763",
     "export_key": "bda7c3ff38c8733d2ae136597cf10a0371ff3aac919e1b3ef5d52f0b4a6093abd5e9784f6cb688099d11e77a7e22530eb33c1d3d24dcf8b1ce69e3a1382a2a8c",
     "session_key": "bc382cb8d96de82ba1e2df4d93a5afa18c205344d0d0a171a54c91091d0a393922652f2c9e1e7e2591779a45d41926549abbac14d49fd5a2d3c84304c81f6ab9"
@@ -1471,7 +1632,7 @@ pub static TEST_VECTOR_RISTRETTO255_TRIPLE_DH_KEM_CURVE25519: &str = r#"
     "credential_finalization": "2116c2c8a25bb8cdc0636d278c85fc54b93ae4a685860382262d39657360a3d28ebbae1b5b20cb9e7b46d85a50a1304bf13a64ba7c0cb61544eab650bfb7575e",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f22999832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffcf7672f280b24c1c009b4930a8871b60abb164b59f93b094647c5e1c57a47ce0c11de93c58c322572b43c926a3b6c0b961625c7cd7c1e2f0caed53a781a8cd37842a5f7812a5f276d186821dc26540d40d4d7c99ffe4179d4b13a412653570054ce562730aacd8c3b93312c760d4b79c61c31fcc0fdaa3268f6084f3e979e1c90f335aa0b1d558b3a32b94f2a38fd9a2c530c449aaa77695461c1897a12b5a95ea4611eba76851a33f7822a209aae1230f255585733a01b5ba4d2283881e94c95ebab2e2b063016caeb71a6d0474b8e15592e175c829a246651765170b299006045a2acbf642aa3f235b9c2a39ca5a80bd6a9114764ee139b84d3b2686b542b985892d179b9c705f2a43c54ce708129323b9b450dd4602662956f925322da18a5154230722a43b95273a05a3cb8b1b69bc776d29c6afa8aa197a181efb9672f2cba9b24653349d3f88946a7c2228c68ee329763346230ba9ab5398867f22870f7697b20a3f56d8796aa8506dbb3d69295b866b2033013106f2961aba89b3dc4ab43a657146cf6517611d7226239742195b5100065bbcdb2a79127a38d20ddef447b45ca5ada9c4d5e0a19aeb32d2b4485091146e209916804886c975f47b1a99408b824c22ffc959cd5710fd8c841bfb5fe7c437ce232f345286e5cb778dd51c9fd49c724c924c951803964394d5c3b45a6bb2a23610bbb1560cb438124945c3845fbc8464d98f23d92e696b207900bc24d003f5676adacb4bfd7664060481ab8896be52738ed3a55d19206336c43993c43892955b42783c3840a989c546c421e71baf3c66a26629a21fa73e5e78093603c9c90329a54c1c3e2c4eedd08096c29b7650c214d23d7e03932f99aefe367640f238a106994e662f2aea1785045bef0075d4889da2bcc6b043168b3845574c51dfa60d2ed28900b0526511122832021ffa68b7c450a1089c3bda40ce986d492b7da2baa0bb31737d2cc0127353f6a5c85281442fcb4965f3cafef30463805a4955509ad2c63bea6487c42152442204bc2bd750a0e5f85a6ccb60af1a10c3b44700b65db2d0ad1d6601b5e9b505e7a83cc773ff354d6f87c2ce774fda4263c88b58b116857dc4821077960f9767d180c9c8f7b1e6280f5cf52fa6139428ecb2c796a498a55392c4a4a053b13534cab66973270ad0a41b42415896c4d292616b27cbc1756307c2ff38850b8929fd0a11cd4741344852ac96b325496309b3bfd9316ec81c7418a71843a8709341478f35187b38b5f4f38a5637278513c78bfc179cb4c4b0608fa0b6a5bfc0ba4777b62914848a3388c918056c2929e4c2034fc9719b8b471c341852436fed848e1df20a87b96add836a88a4ac86586b290abc560c71e8954102662e525258d7b76ceef0916bc59a56f909fb9b59a6494636a79f0d60cc7e047159d03f32987c1eebc39993c10ac43d664339a5d4601d33438ba5b31fd128ee5499caa7773c675396f46196994e5c29a5fd4941cde2bd7929690269602d5a3ed92b73766369fd526a0315acab08c97f49b461033338071e05cc3ccc704b74009755409f2479578c498112452c950375c9508c06e3478c4476371346d546744ccc908e6192efd68e8a67cd99832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420253ceba52f73dc442f96d810d8c3e9a6689c649661627060cf9e44657aa792bfe84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b",
-    "server_login_state": "64fd474c171d2ded7d2024416eceda3fdc600244e97715aab122a57c36d88db5542322a7e381b8075a1fe8299c22151714701870ded72013d64ac83281c950f82116c2c8a25bb8cdc0636d278c85fc54b93ae4a685860382262d39657360a3d28ebbae1b5b20cb9e7b46d85a50a1304bf13a64ba7c0cb61544eab650bfb7575e99832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420ae7c1a3768d3677d2efc4f5d4a87c815cbba26e2e8ebade34a6438f3d08cdcc51b3f6337f3eae77fcfc0bf7ec43be7c5529cc6e4d42fb0c8f14f3de7c2a799cebc535aaa57ce99d62e8ed634a26b71b11e3c7a9b8a893b3a474943c352105133cedef3f152743621f7c40f68207d68975d90491342fe8be3eb68841bbfabaca51d21798ef50389a569fb16c65af61e0f34d7d03f5daa873a56cda2c81432e101385a8695dcf2312505bd1b704bec5d9c3543580c8b5229affab327d6e36f39aa9536262f2bef0e10fe2bfda7a5513865e22d7fd7e87147eeb1cc4bcfafd2073797f66d0edae73b6192b70beae90751f1e39df1f7b05f70e3189697492ff16728828ae51c9f65727e153e3c842227f7501a1f65c3bd109ec0c67ca0a089d628bfe0d77e2c4c863c9d6e55c831459786ac5ea3b18abf9d425d83d6c04a30ec69d5640864f237f29f8c2edf15ee9a70ed029398579da3cd2fe99672ad74645d262c0ade4bdf7e56fe65cad3d693c44ea9e5cc3ff16ccafccf30f685dfdeb406445ee56529b40833987e78c9149e23260af76fe6efc932abb69af42ae4eaea079a7577f2efdfc1e83e7b910a17335cfe1f10020ecf66cec08bc3242b4e192a55bda9686c09c83f710e93a0d60e7dbdccaf6e5192e5d5d4beef621ddb5676fc4bf8df1b268b97a64fcf4e221a0155751d7c977521a5e48bb0d638678acf990a728f0cebf9715a9f63e437b081e0d0cb49098ab8d6166e6ca37063084a10a53196e12509b673f7266bc4e2b5699231cd5202c07b07ac1be0adf0e4d472e6f028b5228edee1b5f92eaceb8dde96e0f6483ddfed704b58f7f9ef133315e8b1f3bab8593b3c945fc940fd74318636e61fd3fc623e353fc6136ad80a13168acc4458523a856bf561b5857c77ccb13dbd5f3aeb0afeefc14047e0c2b98aa999473d5a678a5e7bea90ad6b4c893d4d819b659d4ec10a984e41635879a720f305c170275d2437da2f5a95636e5991e7d83725e0598bb4556e898f0383e035f06a7991365bb6f6326a260ed1602fe780082056d49534ce16430314c904db54bfbe2fb2d9ebdc7cbb3ac91b7f8a14d45e688e90e2521c54bba31160096801571eb290d700e770aa32c4afa25d3160910adb3aab4b2a71b12b3a9516e59da1d2ab681d84247358a4910f9396944e030013dd2f5b47dfeeef8f17fe6b8dde5c9b8107694a565155388ffe00eb0e7a7212da0fa1cd663030bd9c5d7c270aae4b0aa6c82f9d85c1ad493e65948c3f318c000952c0d05ee0f66c6a83f9e31f2507b019ae8cafa49766413460017f2f29b15e1f2dfb48dc5c5803c2f22b88e7dd999251cd32fb549b53b7fcbc412fe2cac33e653a1dfc7c9e0a98e358de263958fe9ea369f86126388bd5d6b09083b5831024c5fc3579a6cf61ee0d801f9d9405bc3adadcddb2b2e41f55e8576e8175407f49dc0089b46220a307eb4a691fe7b8ae22f91715f15b94111900374d535b53f77ed32dbc83b00a2967edc97cd3f0481f0b0521b1afaa1bbaf9",
+    "server_login_state": "64fd474c171d2ded7d2024416eceda3fdc600244e97715aab122a57c36d88db5542322a7e381b8075a1fe8299c22151714701870ded72013d64ac83281c950f8cabc7dc95fe9acaf2be30bd5be2cb0b407889c3be720ca8cfba28cd04da9d82c7f0e8e4e1d148872aa00a56061707b59b8cc9b76a98b9d1e970f166bc280df712116c2c8a25bb8cdc0636d278c85fc54b93ae4a685860382262d39657360a3d28ebbae1b5b20cb9e7b46d85a50a1304bf13a64ba7c0cb61544eab650bfb7575e99832eae8a4051e05fbeb05ef8711aaac41bc160ca0dcc1a0f7b373c31696bd917bf4272ab752d145854ca17474e2ab0795c24c8c045f7e512b985a9e6231376ca4358f28fcc475f08079dd10a882a64ab2925106daa4e1c561662977e13c77873b132094b1f6331744e87cc2d50091d38a8d2eca50dc4c2f2e148b85cbdcb0c9d4089c1c782912563688181ba64e758c4d5627bd5a4c9236b475a4fcfacc8f359c180643308fb45de06130f501dd16cc214185c29987cf92a8c938309599276324bb90b1a07dd7830fb5ab36165358845bc25852221e7bd2b98c94fab7ea75a0e687c71f700b2523b0c1f9a6fbd1aa911c274bca1af2ee45ad1f858cd83546473c625388d0470b00991c38725bbb2c9bdd395213018663aa8b6a2c515aa527f137354a08084d9691e18568e90f8a0a3b1abd4a8577851a8cb670cf5639ce96184cfa1bed338b106da639af95a1ddc0d27e06f6b783b1b9276e1e20cfb484b89546242418027824939d68090f62e06473611382118b49336c30e8da56aa51796a6d407aef634a1f168cda2ad4fd05e38891a25049a0ef560a8232946c0bf0e99051e953b3db4c5d2112e8934447d98568c647d463c34e83512b639b3d603574b7330a45b0e430386f19bb4c63ab2217313a7a07284a3994d2012aab965d8379ba5872aea773d03d74ab8b28c6c6152fef81cacb5c21bb541b2349887cc31c0c046237283dc3570caf0ae1d016dd1b67b4c83c758f58bc8ab918e4a8cd4300acfccb00aa9518fea103d8b951b2c425278585c08676635a7d3472066e54f564bab496931def8b43790598cd766726a31aff701bcd222f56cb699b4089f3872fae6a6be544c081265d4808b6743aed64a1b0db8b1be1a2346dbb52dd48023ca6cff44c4668283591a2808bc3417133a6e376ce229c1e075186cd3018b83256ada96520ac8bcd8983e097eadd0387db14556d03e4705a3d007a203167a70273bdfeb7689d1b5aa47b49f70a83a4520be83b10f746014d6b3cdf233e1c3cfbe9bb9b91151b5a848c6976997ca3b8e94b173e32ef5124c2d0b83d0d0ca607071045469cc079e9e3783991a843e7804fcd56012b042b9fc05f05820af5275d33c74bf04201183c3ff73904332ace008b28e176f18c692304b98410a05e808704fbb94e567573cb389d03b1e5df953dcfa8a7bb245b8ea19b3cac9f9f67956a7aa9f2a5cd8c38e0c8b66c02c6df012402d717012ec99badc31174cccd3f0934f92a0cff5ae4f7caec8732ab14b0b33b66a2666c816a4a15b4a2f43c495ace90fb5755ef9b1716ef996cea27e2f654eaa08594937b22bf5a65bd57b34b7bc439827c3bbb074457022ccb96a384de4b2cf5c8b9e508455b6116a3a5a8dd129149bf8a66225555dd646f0f6a1bf12164e499258f558e590754c861cccf563b1a43be96266bcb697a8b6568d0027b0bc7d9d7589b3a723fa623847bc434623cce6f16063bba536e183f5171477f14bfbf5c34706cacc9384195116182765b183353a493baa73cc47b3552de5402f834954a3cdf6dc3fdf817465354fe510117bc1268c7a20800a9d66748324ac2a1b8986fa8ccbe3926f62a0776eba78121c23fd742e387c31fc62f3fd83ffd0f10efcc45fa8452dff757c419a1055ddea0a847490b40e7420ae7c1a3768d3677d2efc4f5d4a87c815cbba26e2e8ebade34a6438f3d08cdcc51b3f6337f3eae77fcfc0bf7ec43be7c5529cc6e4d42fb0c8f14f3de7c2a799cebc535aaa57ce99d62e8ed634a26b71b11e3c7a9b8a893b3a474943c352105133cedef3f152743621f7c40f68207d68975d90491342fe8be3eb68841bbfabaca51d21798ef50389a569fb16c65af61e0f34d7d03f5daa873a56cda2c81432e101385a8695dcf2312505bd1b704bec5d9c3543580c8b5229affab327d6e36f39aa9536262f2bef0e10fe2bfda7a5513865e22d7fd7e87147eeb1cc4bcfafd2073797f66d0edae73b6192b70beae90751f1e39df1f7b05f70e3189697492ff16728828ae51c9f65727e153e3c842227f7501a1f65c3bd109ec0c67ca0a089d628bfe0d77e2c4c863c9d6e55c831459786ac5ea3b18abf9d425d83d6c04a30ec69d5640864f237f29f8c2edf15ee9a70ed029398579da3cd2fe99672ad74645d262c0ade4bdf7e56fe65cad3d693c44ea9e5cc3ff16ccafccf30f685dfdeb406445ee56529b40833987e78c9149e23260af76fe6efc932abb69af42ae4eaea079a7577f2efdfc1e83e7b910a17335cfe1f10020ecf66cec08bc3242b4e192a55bda9686c09c83f710e93a0d60e7dbdccaf6e5192e5d5d4beef621ddb5676fc4bf8df1b268b97a64fcf4e221a0155751d7c977521a5e48bb0d638678acf990a728f0cebf9715a9f63e437b081e0d0cb49098ab8d6166e6ca37063084a10a53196e12509b673f7266bc4e2b5699231cd5202c07b07ac1be0adf0e4d472e6f028b5228edee1b5f92eaceb8dde96e0f6483ddfed704b58f7f9ef133315e8b1f3bab8593b3c945fc940fd74318636e61fd3fc623e353fc6136ad80a13168acc4458523a856bf561b5857c77ccb13dbd5f3aeb0afeefc14047e0c2b98aa999473d5a678a5e7bea90ad6b4c893d4d819b659d4ec10a984e41635879a720f305c170275d2437da2f5a95636e5991e7d83725e0598bb4556e898f0383e035f06a7991365bb6f6326a260ed1602fe780082056d49534ce16430314c904db54bfbe2fb2d9ebdc7cbb3ac91b7f8a14d45e688e90e2521c54bba31160096801571eb290d700e770aa32c4afa25d3160910adb3aab4b2a71b12b3a9516e59da1d2ab681d84247358a4910f9396944e030013dd2f5b47dfeeef8f17fe6b8dde5c9b8107694a565155388ffe00eb0e7a7212da0fa1cd663030bd9c5d7c270aae4b0aa6c82f9d85c1ad493e65948c3f318c000952c0d05ee0f66c6a83f9e31f2507b019ae8cafa49766413460017f2f29b15e1f2dfb48dc5c5803c2f22b88e7dd999251cd32fb549b53b7fcbc412fe2cac33e653a1dfc7c9e0a98e358de263958fe9ea369f86126388bd5d6b09083b5831024c5fc3579a6cf61ee0d801f9d9405bc3adadcddb2b2e41f55e8576e8175407f49dc0089b46220a307eb4a691fe7b8ae22f91715f15b94111900374d535b53f77ed32dbc83b00a2967edc97cd3f0481f0b0521b1afaa1bbaf9",
     "password_file": "ea0118578326d5c0ce0ccce4c836590e85610e646acc9092252ac84aca00d71d2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265d0e1f51f070336bcb2ff904a18092cb6542cf25a370088dcb4a0b5f2966f1ff16c507b79be408613f6320e05a30ea7470858e1020b228988386384cb46a039a2",
     "export_key": "501f39334fef4a701457c59ca3c4125f5e611e762f813f45fc89abeb6e4ae23f898bbad030623669251ee2c4a6d986266abb9c64182aab549942250f5f824401",
     "session_key": "64fd474c171d2ded7d2024416eceda3fdc600244e97715aab122a57c36d88db5542322a7e381b8075a1fe8299c22151714701870ded72013d64ac83281c950f8"
@@ -1511,7 +1672,7 @@ pub static TEST_VECTOR_P256_TRIPLE_DH_KEM_CURVE25519: &str = r#"
     "credential_finalization": "4534573a0895f26216a718a556eb83f8e1f500b7a3ac8219b7ea4baa42b47f9b6a4882b424f6ed7719b6726cb320a142d6e49d6bf867e7a472bb2cfdd08da283",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87bba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ee3b2a983a47952f1763d43378f71882951ac98c8b2420833a070b01f38634bdab41bbbaf2cfcce824a9ee521223835796bbbbc9bd443e195396e9348da3b10ca213bbef380bad8a55cc571c6f734eaf5adb3529f76c6057d9b1a7d01956ea810e4240d57c457923b4cc72054a9898d6dd9385cd51da69104461c5ad2a65264c52e368c6d28820a91a640019714d9b5255f0755f2b80475db54d495650684cdbc544fe9083b8ec00e46197c8aca84c3db434ef5780e608a39db1b48c888868cb7824ac666c5445c264af06b63db3728d15784401a9f4053819051c145472923e9a3ace13dc31bc0922a88dca846924c0a4e1501c21a51a078b6ea1c822a982d042aa3239339eb9c2f3bd08e1e63288efc5d3b039af2dc3fecf2b343abc3bdc4bfa0cc0ec87062361457b20014e27b42968959653b4ccce7b631321047f8849208b526560aecc4acad065b3b086bd7962c7e080fd40305e2c339befbb986119e13d5218a307c4f0905b63861f40b3f9db6b4a6ac0a469aa20e804e31182abef1b530f86d89182e8a51486c507bbdfbb395f0a4e81357c1a3c5ed37c8b4aa65c7388803062eed63b19af2b89eb75d3d08b86f2914292bb888d8505d196d283b90bfa56a5cdba7cf41a815e3318f54465bc53596dc5f9874c248c266f20640421570580989713681f4c0057197b79fd51e6d5686e3d2a9d35875458a47d9059fa4ea5c0e2c5b2c0ca1f104909f41279675630ae75111f728f8260683c45bc8e5c6195b69c9d1ccccc3b9ec14aee035aee3c12dbf1cc80dd33cca3a3fe98c49c7061e887a0af5b26a69610245871a942a868c768aaa37c665a50698887f2790583c8a28fcf48d3e299d381731eefb65fee790ddf9c36ce6a61ae60b35c718c530696b3081da31b31d6575629216bf7744f5a82d7ce824df449852d84d395bb29263cef6b4a57678a5dbecc4acd6b58d30290d39ad72b63204fc6fd38cb149a2c2cf78a82a132c81cbcb16525c3a96bc2047c7c058b9144a023e8453033b1e5ec54e5516254f655326915bb60b1fd48829b5d82329a98656f8a22ec252fd7847d14073b24abdee277e2444ad8dd1a601b24ada9abeb86abda9737b5c266b73869baeb5ae28b246a7c11f1604023718cf6ccc87057168c569b130f3a65a4268b678289b404995d320f7ca4d2cb22f0a4cc5c27c709a36acd879074230c515aa98338031ad7ac920eb2cb19a0a8cd31a4f13b925a933ea1900c1f0831a3a2850b82877d742d6144c81c2ac811ac5d060790226bf2409d0a12c9276eab7553c6a0e4124b0951b6600718296126ac21f2d138c29a04ae71204bd4c66b535ac99ac3debe53159212a749b722782bf9df8139a16233d89a044875312b8ade1855fde7b5d0177204921289fbb0c0ac19cbf4ac8d4603fbbb28492c2c125e9012a2cac330c91227a72312184453b0b6680692fa80a4f553be8b8118890c616120c1d583d0fb5731cab2fb1d4abbaa8627e415ca4cb9bd1b4693e4537074c152700a85aa529675ca76eba4978a4230c525800e2785c20992dc75233d198a4aa6f0af67b22dc95c7c5460a563c204533b653876195c176a563e357640b082a63e4b7fb43ac11957b238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2da2b22bbb9275070fd5a3be2b4c4b3ce48f3ad55cbe0ce3710f8f174e4ba7273e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b",
-    "server_login_state": "57d1fbdc9bc09e460d7e7bfeb690f08a682510c2d89afece537ad822bb1961ac6733a508b8f4a51045b36a29b0e54c5300aecf011c62dd79e3270f7283959f244534573a0895f26216a718a556eb83f8e1f500b7a3ac8219b7ea4baa42b47f9b6a4882b424f6ed7719b6726cb320a142d6e49d6bf867e7a472bb2cfdd08da283238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2b2bf35ea47341671262e96a73bd7eaa52ee208dc28428e3fef8f62364be33dd9fbd6c65e9e4170882feb9a0ebf48db239cdabc0c247b2827d68f56c3443e30b82ecef30fb33e3ce495bd6b65bdc29d3ca2df061f40b27d27c7c9be391e8fdc4e57ddc10174e2e593d9b9771c88fa7b71f475b57e62da293d3f950e11b80cbf52be703af32b071dbed03b2f432ea71c12dcf93852e6b1ce7841fe6a1e754bf57af4577a05e980269b066a78b49dc279427885e367075765e5abc8a33721b2943fe041461c3692d3da64824a52bb59a03cbf15042be35382de45d6eaf624c874268613d184d08caa3dead079d49098498aa449c13663bb900c437fcb47755f8b2c6069cbbcbc67b37402ced1d86cef06450b17e1701a8907271a77bab655e351ff0358c0a7d38f85f8d33b7f6fdd02976959f039d192f575ea1911df19adb17efbdea3b292cc81ef1bf1b3fb2b53c40cca00a0aea54e70f0f627694fa2dd9dadcd1f3aa5d3c94558e2a73b11dfa9601c3e1a40398daab2c4916447adfa1ee56ceca110c29eb14e343574e9be4dda6f857f59d19f4dbc4f79d11689c1377b5896c2503c42204fdbe5681457e614d78cfa5393d48468491bcbd01f920b88088953c269a68827f3ffd87355494b5268fdfb08065d948006bea90d116fb58a4d272294fa1c93e400f19a9870c16505eb42b5cc67cd1cc80c786b287f441cc0594b763faa1e2b1ad52df54713a095914bde3f8be40e98cc5669d2e050ff23665c305bd9cc95e2cf14145e44d4f7014619468fad1000fef399ed3a0460f5bae59550dc66f02eab4d509338a7b286cf59dd1d391b9fe12252921086854203575239f0738c0b18a20e4bdc61d9eceb7128431523d98d0120577009c4b713b903e40d9a79506403b64e47e25aff596ef1c812abf449364478d3b2be3703991f4f1fefb31c7208aff0c284ae5a235b51c9f227902effde54dc65c31d9d06b532f3e514354c4ce3828e045dd2457d9457379d654edb54def5c8ee275294b2a6be58ac6b82e950864a5be9c990c7314dc6009a13efcf91f4f01e1baf4542e6570cb25e65a18a0d9f163a028686dbb1710d0f7191d4aebf6a3d6a2a810fec156fd28b4d0d4667e74912cbae62b79721d0c1634bb98b6a50287ecab95608716afc75f5e7589010b0fefc7650e759e35faf3624dedde8150f3c730f8803a01ba4aabba2dc9de4b320aaa6a815bc4be1aa41a01ca44fd98adf2fb6e7cb266ae52c93f08b79789b1d0f0703aac04e2bef3953f7fd06cfc5ef1ec17fda547a6d59b66216c899288ca329801e46f4b81c7c74483c076367acc636bc2b204798a107ba03a108856656a09f9158fc661a3468f6e171014f7034f7bbcd446977c314da7a6eb0202d1e0d9cd92ce88a03b84f6fd4109b806f9aab4bdebec429147b81c052d39f4d5037dbd0cbd61709f146bb178fd8372b4dea12c7a3b5f95522e5ca909323ec03f3067e79ea8ed9f464f7707a7444483439eabe27c366e5cfa0fd31c4bce694f89a71208a44",
+    "server_login_state": "57d1fbdc9bc09e460d7e7bfeb690f08a682510c2d89afece537ad822bb1961ac6733a508b8f4a51045b36a29b0e54c5300aecf011c62dd79e3270f7283959f24659c84d683e150ce97f2a98b2a7ef0a88e23f4d2b0009462a8831647826f852e83a87f6f57df9904ec8b7e358fcf01878ecd3cd8526522789aeec93d1583d3ea4534573a0895f26216a718a556eb83f8e1f500b7a3ac8219b7ea4baa42b47f9b6a4882b424f6ed7719b6726cb320a142d6e49d6bf867e7a472bb2cfdd08da283238bb13a6b8dfb578b9f90765592a8a3bc5277ac76b04c4fa1386729f18705585e79c25174f7a6f740233d484166c615e9c924f0d6200dca1a30e46f3ef1827f36be43d8c6c17145332aac92d20b11776513b241cbb181b0b6ae8e596e84c08f09aa1a5cc07c1d4bb407d157809109f9861ba8c9bf85cb5ea153a862886d9db533bde592eb439bf821cde8689fa96c718f347e84d765ed681878f2679c43ae0fea6fe7914bd3887d69d2600cbc672d957948e479eaf9738b3b7faedb23bdb437c9378e05a60cb95b172d07d07a42bec3e55dcca06d09e811c849b63401b67e2679d9145a2eb344621328552410ad625e0822118ad988354257d2d9301cd77d2769bad0455ca5ca50782b1a65ab8a6b63b98f95b11b01ba6cd56abb09cd00475b6904b3293b3ff637637d538786eb9aeba4707f000226246defcb6af4da5cc3fbb550fa041e307ae1502b5db9145cf9790a201e88774f63885a3f7593b6c591d3671776cc0fa3029e7a1b809c570e1316c5a2a37c2bc05d9f13950aca715bd62e36702e3486b6242349e1fa247dc64cb3ab7dce631af71b2eb3f30017971a38c56f7d938ae38650cde095fdac9fc63aba15373d1d91a781cac9a9eb5728f00f855398c81a9db83b7e30d4c341566421461b2ec81e2794bcfcac7182e552e1e837b9705931601b55f312f3c143b4f32b904ab720738b396b4f28b92514c74c25118a5babc5e4f087ca56cb4783cd56cc3300fc5dd62c4a1e84af3c3424a20442b2e0b91a330fbf823e141415875c79b871050af5a1082b515b986dfd4ac54d006fc76818be8c011594a582086e22475042779d96c91ac11c29160a0ece9cb40a6993b4c059646b709c28b3ccc29c942413fac319556238c5632bcd30a86af6a72eaa3e6e2b3ab34629e5a744335caeeea2b157f89fa3320552b3c8f4dc4cfa540395fb70f48c471a457e11f6116481398596b54ff76d04a3add52121415a50d0538082a1715d066ba0aa351f789ae395658dfb6f4443bd5f69106597b12fb4a58d2151f6e211df8a5beb52158ed5626fc7331c813beb492cbc811fc5c004102c23d52a07dbe62358cc8ff8a5ca63ec1b6e234bd23c9b9099ca32a3b112460e4543890cd3c29e645088590f1260b76784738d7164789c8b5ec26aaba0428a565fb2dc75cab09418f848c889261b5989e8824ce5350267a7ceda0a7912211684abaf324a727eb0bf82c58f4587314cb7a478f781369273e9c9b1aea85b3a93037c56677858cff5ec09b01259c47691a56c9af3286a8e90964385cff7096be3e34b3c474d358cb683815076e8a1269cc1ac6bb4e38bb360ea966aac7dac609f95e46a8b4b3ed4b45eed584e1cb15dbdfaaa298b707522020f4461a0f8370f8bcc40945fee5036aef645319233037abd35235aaf9126a51724ea112c57b37806aa0a919b2eec1343677597ef13a1a6a3936aec305528358b3794423c6ee9f9763c8ca8a5442cffc0877e7a23f8e3a9a429238fa65e504cc92077a8503ace3a0abc8303275749687aa08495d183f7553eb4b72fbe5a5f74a319312c7bc58c90e633b391cab8bda4afc7265d79661ec2f54d488c166eb0467d53abcf5729701422de694408ad8f984750c73bf29292879e7da91cbf2baf147cc0ac8f31f10d7d2eb96bd2b2bf35ea47341671262e96a73bd7eaa52ee208dc28428e3fef8f62364be33dd9fbd6c65e9e4170882feb9a0ebf48db239cdabc0c247b2827d68f56c3443e30b82ecef30fb33e3ce495bd6b65bdc29d3ca2df061f40b27d27c7c9be391e8fdc4e57ddc10174e2e593d9b9771c88fa7b71f475b57e62da293d3f950e11b80cbf52be703af32b071dbed03b2f432ea71c12dcf93852e6b1ce7841fe6a1e754bf57af4577a05e980269b066a78b49dc279427885e367075765e5abc8a33721b2943fe041461c3692d3da64824a52bb59a03cbf15042be35382de45d6eaf624c874268613d184d08caa3dead079d49098498aa449c13663bb900c437fcb47755f8b2c6069cbbcbc67b37402ced1d86cef06450b17e1701a8907271a77bab655e351ff0358c0a7d38f85f8d33b7f6fdd02976959f039d192f575ea1911df19adb17efbdea3b292cc81ef1bf1b3fb2b53c40cca00a0aea54e70f0f627694fa2dd9dadcd1f3aa5d3c94558e2a73b11dfa9601c3e1a40398daab2c4916447adfa1ee56ceca110c29eb14e343574e9be4dda6f857f59d19f4dbc4f79d11689c1377b5896c2503c42204fdbe5681457e614d78cfa5393d48468491bcbd01f920b88088953c269a68827f3ffd87355494b5268fdfb08065d948006bea90d116fb58a4d272294fa1c93e400f19a9870c16505eb42b5cc67cd1cc80c786b287f441cc0594b763faa1e2b1ad52df54713a095914bde3f8be40e98cc5669d2e050ff23665c305bd9cc95e2cf14145e44d4f7014619468fad1000fef399ed3a0460f5bae59550dc66f02eab4d509338a7b286cf59dd1d391b9fe12252921086854203575239f0738c0b18a20e4bdc61d9eceb7128431523d98d0120577009c4b713b903e40d9a79506403b64e47e25aff596ef1c812abf449364478d3b2be3703991f4f1fefb31c7208aff0c284ae5a235b51c9f227902effde54dc65c31d9d06b532f3e514354c4ce3828e045dd2457d9457379d654edb54def5c8ee275294b2a6be58ac6b82e950864a5be9c990c7314dc6009a13efcf91f4f01e1baf4542e6570cb25e65a18a0d9f163a028686dbb1710d0f7191d4aebf6a3d6a2a810fec156fd28b4d0d4667e74912cbae62b79721d0c1634bb98b6a50287ecab95608716afc75f5e7589010b0fefc7650e759e35faf3624dedde8150f3c730f8803a01ba4aabba2dc9de4b320aaa6a815bc4be1aa41a01ca44fd98adf2fb6e7cb266ae52c93f08b79789b1d0f0703aac04e2bef3953f7fd06cfc5ef1ec17fda547a6d59b66216c899288ca329801e46f4b81c7c74483c076367acc636bc2b204798a107ba03a108856656a09f9158fc661a3468f6e171014f7034f7bbcd446977c314da7a6eb0202d1e0d9cd92ce88a03b84f6fd4109b806f9aab4bdebec429147b81c052d39f4d5037dbd0cbd61709f146bb178fd8372b4dea12c7a3b5f95522e5ca909323ec03f3067e79ea8ed9f464f7707a7444483439eabe27c366e5cfa0fd31c4bce694f89a71208a44",
     "password_file": "f57518dfd29789ada99a4c350ed95f10361855205a3cb98ea0dfc3e829dbf53e4226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265502af63ffb3f21e6610335d3ca3665a0ebeceed19fb2166c343f89f26e12f1d0",
     "export_key": "bde7d6a20eead70c96e22b28d5e979b04c990746145e96af3c9e05c1566d1d5a",
     "session_key": "57d1fbdc9bc09e460d7e7bfeb690f08a682510c2d89afece537ad822bb1961ac6733a508b8f4a51045b36a29b0e54c5300aecf011c62dd79e3270f7283959f24"
@@ -1551,7 +1712,7 @@ pub static TEST_VECTOR_P384_TRIPLE_DH_KEM_CURVE25519: &str = r#"
     "credential_finalization": "14bbadc7d21eb3399a10bae9c50ca09b2e11317426328ede767f207614d572f50bffe7555fbed4357ca96f1146d7b648d86fe3a32f306717c268920b57b99455",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f2290df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87ba4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1e234b6047353e07437a54b0b4ce1784896bfa3a52fabca52c7222f3414623f9a4fbbf7447c04aaea852adeb839539560148ca4a89a8f8c2b8582ac29f4867a6bc9135aba83027a61189b3581a18184b1802a88463ea8cd41c40f2e344707314ba01c4e320292ce440c8081cb0960832cd03800dbcf333563218084a042b0f05c2f09936847d4bd391962b6860808a1a262bc7af6f11de67b98a7a1104cf6b434b86a23f15ceb019e197790c8f0116a551f04664f72286ff4c437288ab9b5b27366c8ab7d15327b85cba340713c67baab684edeb251fcd70a000c15b90cbf41b36259f37a7f743e47c525e5436e19815d91d452cd4c8e0cc70e069b491176adcf8ac58a349f98d669a1e511aa59bdaf34a0aea104ce6ca27a2347de406ea5a26bf4924fb5a27132113768f40573016867e5161251a09e1513a79b98907864c7b3b14fd350b6d5c6dda6025b4c6a5c7841bf4407fc3c780ff7866ca89715ab18317cb67ba78198312ca3ac7218b44d45b593948a6585d2836927b27418cf38a3cf5091579279c514356b43b0014ad53f20377de1dc32c89031e8ac94cc4c3caf4513edfb6e8a634866e78a86c8c0296275c873858e985dbb505c279145741807f0c419a10345825b7a3394cda9512920f49a8cf37709b0bddc10bd1f96587843b5be795ab8642c34506b285c70788a660fc73f96198ebb16c67ad11316124aa5457c972cc8192553ea450704cb0730ba9814326b083c14a23962ced1bcdbb790985bbfe9f323e4b463a386866b546a24d06d9b7704cba29eaf437016180d2b1b406e2a4eafd87b6b4a5e811734e47bbc2be6cefeda8c9ef09b37ec9df84086e21b352e7293eb508143fbb09f142cea7515e99baa730a12c7b916c21941eddc5a6a8c32312c3a7e2c5dfec934ba33cced8206b54a1785442c11044805699f991153880628d0032acb84bc62ca2e256c4ad44459bdbb0baf5b62ce1c37561c517765947c195656ccce8fb5269981b70f0c79c44c8ebcf5c46a007177e9c62df67531ba8f23253539371466215745181c25c3c74147861190c833ecbecf02d0552cc5265a395f892a191a76c861bdf5c2966c5ba0f5f8c414555ca1c7a95f264cd56ba40a454522885e57e2b7e03428d75ba7edb44c9d183a2a94bc48f6c7bba360ac242475da225c42c1430ac5da6b3a518ba0e378700699a3cef48744589295092623647110e461a8aa39b2919fc1f257e4d49cb69a8f4933109770bf9ab7a1e54c89ed1387aee61cbef99b1348834d2c7d33837767512b4ec3c38dfc89d0129f29f171f9fac246aa7537150f62a60c6f4945360823c95c585c4a09451029baf86e1f25b6189266338c862648a746f4590022bf70d59dbe67b1a9f14362e08181d4183b3a48c867147eb453124488f94173f8e78495302290874b305659084a4cc983ce51372895e054117082914ca9fc4040d6c66ac50164e813314ac4284339031ef27c217c843022260b462e7c92204f72a5b3a48bdee23e080b7a6eab7fc92a3d34a952b209c0a671257c051249137154060c1605034d10c3004a42302715010184de80cca2d2819dbc5caed72888d38ae6eb043b663b3de1c8e6955deb8b900df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7c43e8ded31c09c3034e2803679bcb56541de9c8492c916517cc1a84a7bba3e3cd198aa66d474460a0959f960309cf180e84dd791cb2a3f5151577f1e26e3d959",
-    "server_login_state": "b5e8dcf90123a10f690e6fbdd45575aaf0687effc94d1679c10d4857cd04a1ddaab72830be4199687b064d7a322cb149a3a9c56b14c12b2e5e07d03bedfc71ad14bbadc7d21eb3399a10bae9c50ca09b2e11317426328ede767f207614d572f50bffe7555fbed4357ca96f1146d7b648d86fe3a32f306717c268920b57b994550df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
+    "server_login_state": "b5e8dcf90123a10f690e6fbdd45575aaf0687effc94d1679c10d4857cd04a1ddaab72830be4199687b064d7a322cb149a3a9c56b14c12b2e5e07d03bedfc71add53f478e7b89b877ed1655c4e22b932501a317e5038ceb1e8fe4e65932717475e0e3f70967533df4fd1712445f5174e5c36a5de1426ebfb9b8b9562f86aac77714bbadc7d21eb3399a10bae9c50ca09b2e11317426328ede767f207614d572f50bffe7555fbed4357ca96f1146d7b648d86fe3a32f306717c268920b57b994550df81f84e90dece4786ed29dbfe5254a32230515269cd368ba8c04c6b0a9a0f2c567e5634fb21cf50b9a13a2575e16aeb099b91d630ed25453ee08087173b6f11424ee394d6a2b08df69645fd58142e21056a64d7e011451a1b14ecc5e0f237e70fc94785cae8682563ec4a813403d702b2152373bb2579eb0ca52f8ab33a1185f630781ab53c5fd192de1a30e1e957c6ae3c843b2c5cab674afc4a458b7adfb1bc4716c1bbb489c60a86e15525d7c68a486d2244c91398ed4aeddd29892722ebbe97d7e9115ed50bf00a4148948a15bc1051cc69d62725103c9bf302b961c53b7dcb759f484256d169bc3e8b6e55a489efabb508ba9c7e11195103345699f444553b13896f5fa316292285693132e29b30f66a9a32614bbc61ab6e87679655ba6c62a6f3a3d05117b61862960b702669908a0947d57e58a426aa0deb39d8df5ab00cb6153016ba8b12e35c9a719d97ab1fa0cc7b6aca7c6a06efc8f30e67bdaa64f0cc61bfad50da463ae8e976af517615c015700ebbf016675ce5775e6667352c15eb916a22d174a6481683274a86813007bcb2ceda7b0dd983570cb7f5156794f438abac042b6e1b9312663cbf832d537b8884c63ed8c0124079d60a329487c595b2aa74f16bdb8050826110708895c6f7bcdbc8cc92a5cc4b4b61b4faba13af780466832c10430a1b801a7224e87fb0c15d5063688662017aa47832bb07a47f308bfda8b63a386ab23dac07d87711df72e6594c5c3d021ef541e77637c5e06c005db3093eaa86f978221a531984ca0d8439d0ec62404d23025cb6cb1f326afa193cb7076d27a13ed02b77b48aa183037f5d100e7eab8e67393c76262e2fb38270cac827359d5bab6399586aba69b4205567a6c81be83b654992c9b610b9330659ca66a3323463d70c1f3bb3efb61357922014fcc219f88ab3c0c8d9f5a1f7a5a76c8d38e3c349c95fa2c1df622c073cdeec9a9505a67f15ba2976695bf5acd63ab804aa3ada8d37428324678c71fa74410f8389fb5a9a591910c38d6bcaedb283d269227d36b4fa4c1a4aab86ef7ad58d66b01686790fa16ca5049e93751c3715458138019208ecdd46c7ae5a9826b61320c69b9dc88d5fb8b8999171aa79bc51a48faa44389192c2a15129b7a2be1c0b81f0574f89b51be80195e223fa6787c7b25207b017061b360c8927dcae773e0ca8088d33515aaa6a2fcbf0a530463a752a0ecb7d853acefa189481869d68995b97c2d75760914351c5a0319c945c5b085a665aa631dd08dca3648138c95a27319e732a60ef439001734b468c4234b8cc2198d88f48a57ab914f1a22159970c5251684e07ab4f0191549a26e553ac6c754f50944d1db2ec09954478487a6c5b721e23792e7704ea1844671a0ea43bfb7520e91103e6011646db5a9a3f113fffb012adab301239ab1b390a1401379104546b519b4f0a7bb90899fe816c815632ee122e2d920bfe4a2dbc8bb439ac3456a0843288f817701c7d5ba49b57f57ea97220b84f198b330fc6c345a7d29a13f32067ed1136240b0513bb600b4b9a619451474c9403a6404bf2a59654061ba8882ec0a39f103189e184681fa9ebb37832a5c4dfb1798b8f24581da5b359b8d9f8e86a79b0f8bab7052f06fb535d8372350f4a0c47fc0339849fb0b5a679ab7ca1be8a4f80417d4bcfdb074079d2317641528f8bb161b563b0f4cf4c4dc9bc6a1aefaf682d59d37d0633794e60b400d37de9b5bf393e219171c4553ace32ee6a8f3a975062eae6afef5737163f994d111fc8449ccac2f9aa964c324a81a6c919a5e1a1438e6cec521d4c207a6de49d9659e8655825602a4239bf3a611f4383734bcaa7e2b6a420aef25109150d6471d7f5f4b89e6a2b082c09718d0051dfe50f9d6a5e64ff04542f24a4707e331268250a93eb207b2f64a407965da7d269fb4b8877008e17135e49fd4e3bf18a291fb3f69b8bff4b0fdc85dde65484150d760e3bf4bc558450992e175320dcaeba9389b3ed7ab96cf85475e8b1117a3eaabc5147659f333dbe57f5c46cda6ec9fd0468334398fd3e030ac8cf9a5bca07876a9d26cba648ea7f4097755d2cf7e6bd85076bc8f5b893ef960b74a226d15cbbb3d38edbdd902dc92343c0bb7aefcac6abef2cbb77f249a140ca072cd1caea80c6c0d5547e3c7096177fc1bf859c94ed363414bd13863454800523467690518308e3d96caa2744fcb22188c262b0d69489aa0f6de477a7bf90e595cb15c1b242c3c855cb8884c359a55f10f6f62763fd1a0f3c3d9c39fedf0387bcb10011bedb32f82ad075ad800ffb53f77b5cc777c928e1a3534b3d6c6a9517bfe2e336220dd9a79fcc0ffcb3fc2ed75eac5eb92210c320022ab46de39b7f72d6604a3af264df80e5a7077b8a9067c7787547d3f001592721f7c0e1d0602e41178c937e835ba6d19c9359d8a1ce1eca6bdb40b0be77c1e36da07be1382364f3e4968dfeab9da96eeaa4b4f062492e7bb2efe24852ab805c304eae081d67d24d20b09724cf4cd6e3676bd56d26fcc7290d6b13cc347ed6c92fc6b967212b5a7a22287ce373c0b6a53d88a3cb0b27e5d0fcd2e5296f31ad27ff0a3531feef8b86afc1154bb9187ebb240295cb6cad40a0277cea0213452e88330602b0c435f9f1bcc35b4b02dd96a56fb3c113cfcd4005354e892acd69d45023ed4446678c9d42245c2866321b528e69bf0bec44bd2a4f67ce306d01ad49a3078166460c06f517592acc1712823ef0600a878a01d55aeaf57d1ff422f3a356621dd3e28df017d2c24cc7f1f65c1f56a3267b84c9652eb4c043c9e838f1ff97be7d8c57713bae0299c2e34b6e6522a88a5d4c39e9cc62fdffcc44a810834e04e237dbf9a5f714280da1e2b4b7aeaf9885b73bdf4432d445be949adaa05744244a1ad9ef900d6e54d1b24f2659b99a7ae354d64dbc052ce9ac1b9242bf8920f9fa3e11a026305245641c6a3050ab13bc4795de889661aa57c4aafa95776133e7bf270fdf694bf7f3f187069966dfb21637e6468a8e927baec83a07a5da4e3cab265d882df68c57060c0aacbdddb9b466aad8a255033d07e8c3de43256c2e334d6926cde7d4bb3aca5e58647582bd2da168a6555b546b5cb18308a7e9944e9c1cc5264f0d7484590e04bb7c57f2b635ea5a79f4c6ed89b90b9bf87e18eb53d4743383c5c24709aa4b8dc4423e10c7b0d",
     "password_file": "e214e4f49be85f32c071f9b24368fd564a9b8a264e9dc604906679a0cb488a014b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265ce46b56ef7a727d9f1702b81b61f20ff13d901cac4c6e56cd7ec15d4bc72508c86f23115e46e07731048f8ae1a2927ba",
     "export_key": "817e99936783cb333e726f759681ffe9781d976d8ba90f269356807e7d01c8017f98ff65314efac6a30dc40b08f84392",
     "session_key": "b5e8dcf90123a10f690e6fbdd45575aaf0687effc94d1679c10d4857cd04a1ddaab72830be4199687b064d7a322cb149a3a9c56b14c12b2e5e07d03bedfc71ad"
@@ -1591,7 +1752,7 @@ pub static TEST_VECTOR_P521_TRIPLE_DH_KEM_CURVE25519: &str = r#"
     "credential_finalization": "f396e1fb0f3249daa7b5f1d868b7ca6b37c29ed08f4dc21320d38ddc4996a38a83d9703953036bf49bd9ee8f9202bc160b80139fb85a3b6230de9edbc91861df",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f2290d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff67a85f7348281ee627c11419c80c218a028d4a1a5fceba07fceaa7fcab748bc504ffe39e6f77084c00b0c606aa23a2b31de083e0e105b39a2d605248a2973ab5694f7c4833b8bc319bf71b8aba425de972b75706a1a52078ec12e38c60b9405b3d853bc1994b19bbb69bf7481fecc07b7a697a7513724c3c60f19a8e500a5672ce92f01b07315907d05890d69c85b2288355baad3a34edab60b1356e9ba47d34110fa9667b869a68c85a0e8b785169f2c9dc17c858a07c62304368e21517435939165f45162424591489c01881284858e4067bfaa146998521a22bcf120abcb3b3f2615c135358058cbbfc7a634fe06d062c137579ba3739592f018cd3c99ef0cb92d1566bc00b23883148119450fe0a9cb75525583199bb01125b584a192a4ea93a1f7c48be5f32cb2c1957de98237bc68804a27d06989388a92facc94b1239245cdcce57bb34da622381c358e7c01428b94297775fae2c8374931975025e714899a3a2ad3c614a9bd28ac74aa1e6d84bf0006a7dd322c260b9fd823967314baf27b14ee6af7e3450caf481d07238a91a1f1334c4130414e820b2df586418d603cac8782dbcb6f1d7c6c41b3b0e7b9c0386376a86c6a2f93bf096690f58c67194130266bf9c24786df97f3e169aca9a4bb56016b3283606d3a2ff5983b6c7533a388effd37b9c113242a55cccb11bc618c16ca64a55422e56bbca8f767c8e63b321b97228636a77a4202c364e8eec82af9569512723a3953918423e9162b89b234f808a107127b9d5da55a022402cb957b62c0d1f147971b311a3817913207cf6c4c8ae5c4f6d316505ec1d8714055535abfa41014a554049f540dce357b3027dd827af763bc0b3778bbd939514145b34d30f864500d716360cb34188b0889c77736c34a964701e09b6876d9740d6504e0bf4127513392f6cab9de98ed4e05f8e48356b90790eda565ebcadf32218a2f6aa64e56cc4d230fda2ab6b294211691e68ac2bea0b5a0e830d10875bcae4511fa25b21c5162ab68b9219c9df174ce20cce707276cb24c58ef90b8ea03fc661396fa9b3fcc688f2b83c289112ad93cb1a671e4e846769b3b28ff8267436324efc07d8b831782076831630a9a199bce50a26792972d9a77fd04f4df9c349fa4d6ab510e0237ec455408c01cce58560e7e931f986705795a1fcb91750cc2a9fe79ff77cb74d0c5998509705319978ac7a0fc7c2ad28793d622afea5b740c6a2f901d0576734f9934427977b85cc9924a487b28cc0c1cba64a6436f9150b7f872cda1a79e6576d42a655ae2a74a3898e6a999b185200cbc7566773c6028b2c61e47b5ee53a40f99297a9a2c2e7b71db935b1e0a76e57a2ab07416db633f2272c7f5c7b59164c17c424693585b45611104100f1e577dae178e45794e8204cecbaa3caa84d72520db9928e12b622c7243ca65271dcf326cad46ca5e48cc386b0c989b19fb9a2ed01a15dd6b391f6b629b4b788c86741a61b137c637a71cc1740addbe55ea78b1e2f3c55980c36074846a1ac089a2189e9fc23cc138ce672c70e514f8a46ca69244f5fd10c01877ec19b54a262c3f57500f2769134c7a3ff6511fc242aca8688c6747e0f5b6c5b133420e9010d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c327303bbdb9f2f6d263a15cd1f85cc8ddf1f7aa098c4db7408cf6355fdd2af70877bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e076",
-    "server_login_state": "4da473675189e99303ded9136c2401adbe4737c52d60468e9283c28819327a809b20ffbb21dcdf17a217a8e06b860bc8963ed31efb3d26be31a5c5578a807cb6f396e1fb0f3249daa7b5f1d868b7ca6b37c29ed08f4dc21320d38ddc4996a38a83d9703953036bf49bd9ee8f9202bc160b80139fb85a3b6230de9edbc91861df0d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3914cdc5a2a4e246f796d132148213c6d8f7b564ebdef20809c4d0fb109e4115be464f24d0f342dfea83b38f01444760066227cbf83bc02d2587c0a94f1d9bee3eba3e09d08252f7cd9911470b7dd2dcd6c9bbe32fcb4628a1777617f628c33539eb7365a93c9dd0d37d1f5dea544518a978af987144f5c69fc12087f44519106dc1c0f2433c2bcfbcd5eddc7aa87a01c67438c7d1505f5ca2b84bfbbda6705bfec8d9152d58b3b15be3ece14925746469439feeb88adf52883b006a67f29adb32b2dd89b77f390a2d32a488c740df692f5c5f4d199eeee1410096ed01d514ad9d38a311765b38a776ba55817ddf4230073f0165e04e7560a57c2a61f590e1cc94dfd213f39c23e35a0393e439a68d6a0fc274afb67b8f0ca26f94a45b264c824d83816c7242800f26615f1aef081d94062b7609c4078ba5b4fc5e32cc48fe84fd1de285723199bfb16a852cf1b67e20fbb64b8e0aa3fd425b8f7ed93c7b304dd7b8884ce3fd3783386a740a0c4331f941eaa32d4536f6d7cb91e9279e24c2a66189dc144d88396efe63ad3d96c1f5bf3422de40096ab8eb8e7b466ceb14356c9aca487718357bf740fa0f614c1062a6bf9e41c019dbdcc7f877eb8514be2a1cc93ea381e0262235e47d412e17cc28b248106b9f7c86a52842298868802b295f3f0581852fb99746cda24541f0c4b64a19a35aa6452b68a5d040188ba457b274f5fa20325fbe0f60ff5eb10e30eb845ab4d84aa5a2454afe6e6f9edb285e4aee9cf6301c1f36ef553b61e360b22f9c3aa597fae5ee7d6959684bef0e085ac2703f50610ef01e1ba0fadcc3118c8f1d663fb3f7e4269df8b3c36a4232dc0471dafc05993e1dd6c1176250c9849d7b237fdb7e241113ed88964c50524449f673ed29b534109b3ca4dc1be201383a787d9c6334a5447fde4d35d880094678c8e371eadf92f14e4612f1939443e29bcc777d6dc92b392ba2860f877d195201ff3ea338a3204bc70566350202b0629df31a4a4b7ba9286b4494fe01e84f0ed9e94515114dd5664e6657152094f6fc08ac90c1d586a3dc76654c311347755fb952d668adc4e4b9dbff21defc2140638fb62a19d83850b7d40e976667be16b1030c0926a362d4437ec630193b1ce010b4c07c470710b22ec1c3452944179e9a01065966aab29627e9731abc97b1b6a1874dbf89adb2cb6b5517fb047997d63f9482ee4f35ac2537fedca355ebbba7b36a4a5be1ebe3eb68f10212501545f1f339205562b15eeafc551a6616423a9903245baeeb1326fe9d6b89421210267d99ffc6f23ed5ef28c772bd177017b92f1af6d0c4db7d58e22571e05d9dd5a69136dcfc4689d0aa2bb6a1210bb0ae8806c79d892758ae0372f3ad5f1a016fef3f67f030cbe8a1a28473035c428f13dac7b4d044bd9b9c54e0fd88be9b60867e183f718913b6e9d657dc41e0c47ffd8856e143d2451fb139865790d9d2bf7db493b58f2b0ac94c1a1d2456909d3526ce10870bc63298ac5b908d2043618a9841e06d953c76d4f",
+    "server_login_state": "4da473675189e99303ded9136c2401adbe4737c52d60468e9283c28819327a809b20ffbb21dcdf17a217a8e06b860bc8963ed31efb3d26be31a5c5578a807cb68e1f3e59d0fdebbfd737d06accdb4942076e2971352ae89be851e3f7b2dd882fe9538217115bb59938d268e1d1d83b1f347d10ff5964fe96fd74d3a6ee14e34df396e1fb0f3249daa7b5f1d868b7ca6b37c29ed08f4dc21320d38ddc4996a38a83d9703953036bf49bd9ee8f9202bc160b80139fb85a3b6230de9edbc91861df0d124317a31e57bc357cc6914d890b588548c91864fd224047a9c7b5f768919035ee8662eee85cb0d8745756a44df788f2a08f2c5058f6d8140b9a7ff2936345b39b543b069b58a927142e1e91289e41c056912965450a8f837a4e05362c0309fcf31469f5c32247147cf02d4a945eec467b24f8145312c26d941b6ed48a49c5b5c4967db2273d15999895d1bad62a8439cabc48b56f17e1ab7cd303d984ac26072326f81978b2ba2aba979436c35c10ad0f470ee46832a4a16b07e06b141584c2a7257a72cb2db0735e56bb4af146248770b546aa102b1da1305e4b28369100bbd90b7b48a37af00259bfca3091c9b7aec782f94474d8c41e2f87bc15f3350ca93b64847fefc8ab9f9a3a886c67658a3becb07548b4c8abb1cab357bdc15615a325c8a1da073264293098995ef0ae444654fa42342ebb8bc704860cb25bfa70aa8800a67242a17d106df20a1b9f763f6c2617f094c48cb025a1151ed94c649bf466590b13cd4b87e688423ac3143a62c2e1b509487c09da48bc79960ccc13133388ba88e75898a7c96e3837465256b3131c48a3827bd594efa6ac58a514c0b4a70d3536dc862c362a22b98316b45779a4497f260407ae6c5b3cf21a4e8042b8826ae3ab61b0e7279fc1a1cfdc8a2d786f16088c61d894a747143899b218aa011d4113c52a9ed6237798da44b1f2771a2067e8eb763d664eced59a563b8c17b7431958a5b235666b2003e5e39f3b108dc86587dfd5b57ed283d8b9a726fc85ab067385e5a23350340e977e82ac2c306a4dfbe95a12b0a959239c28c533e04ac023953817d9a201802ca6e94922146f9a2554d0038575ba7bc6600c472a4a54c248c2fc1d69c0b81caab086041f95a96358e9bcaad144ead50e8f9982375a30a13c5679d2bafb72912a6013d831ac1e4acff27aca46b27949d521042999f3518f79c506ab41a3b803cb3b31a8a0b06b293a45b1285f34906290f01f570394d9a6b0b475b40c579560498855fc175a14bd23ba6972f138ac6a2e4c5711fa4a213c8936c23063430b456ca3b2b05c5b3bc32f13790a02627500039ee67c3feb68c97fb7c4b8827fd8c89de3d18615488f8bac7e365755213268c31804d34c6be672a34d8045605c2c819ca684285c9d08cc49bcbadd0b3eedaac6cc854afd735512a406a7b97cba14a49e2b7ef37c2f6c54a7fef902b066cea1a2488ae66fd87c56eb2351ac3293d2330b861937fbf42225540259d1ab4a722b7a273f2490c97525949cf8c8504c751467ccbb5102d6fb4d89faad6890187c008770c2524ba0645c8c387b2925af5629e0b6ba9cb55c0c6918a90975bcf854c56c9b94e18bae3545a69449be3509c033c436279611c13ba91c10010bc71a040663c23265a8376ca1b251e05d866268761aba32851b7ef2b490449eb12c72fa1190edb4125e6c4978bb7fc65911f8c446d056a14a6283d6f75036128c0227049e807580357af805bd0713032e73016b87840edcc29bdbb20c61687f1653668544b43381517336a9d77213b5b3d11996e8dcb70872089244c73166b8955982f172c00c6129434b0587927e92fc9ddee8c33fc433ec66134b01816446249fd046c3b1c647f97df2a95b19d642c3d4dc01ff4c8c3a9734a8fb62e7c8f216a81a8af239461054fe89e6c3914cdc5a2a4e246f796d132148213c6d8f7b564ebdef20809c4d0fb109e4115be464f24d0f342dfea83b38f01444760066227cbf83bc02d2587c0a94f1d9bee3eba3e09d08252f7cd9911470b7dd2dcd6c9bbe32fcb4628a1777617f628c33539eb7365a93c9dd0d37d1f5dea544518a978af987144f5c69fc12087f44519106dc1c0f2433c2bcfbcd5eddc7aa87a01c67438c7d1505f5ca2b84bfbbda6705bfec8d9152d58b3b15be3ece14925746469439feeb88adf52883b006a67f29adb32b2dd89b77f390a2d32a488c740df692f5c5f4d199eeee1410096ed01d514ad9d38a311765b38a776ba55817ddf4230073f0165e04e7560a57c2a61f590e1cc94dfd213f39c23e35a0393e439a68d6a0fc274afb67b8f0ca26f94a45b264c824d83816c7242800f26615f1aef081d94062b7609c4078ba5b4fc5e32cc48fe84fd1de285723199bfb16a852cf1b67e20fbb64b8e0aa3fd425b8f7ed93c7b304dd7b8884ce3fd3783386a740a0c4331f941eaa32d4536f6d7cb91e9279e24c2a66189dc144d88396efe63ad3d96c1f5bf3422de40096ab8eb8e7b466ceb14356c9aca487718357bf740fa0f614c1062a6bf9e41c019dbdcc7f877eb8514be2a1cc93ea381e0262235e47d412e17cc28b248106b9f7c86a52842298868802b295f3f0581852fb99746cda24541f0c4b64a19a35aa6452b68a5d040188ba457b274f5fa20325fbe0f60ff5eb10e30eb845ab4d84aa5a2454afe6e6f9edb285e4aee9cf6301c1f36ef553b61e360b22f9c3aa597fae5ee7d6959684bef0e085ac2703f50610ef01e1ba0fadcc3118c8f1d663fb3f7e4269df8b3c36a4232dc0471dafc05993e1dd6c1176250c9849d7b237fdb7e241113ed88964c50524449f673ed29b534109b3ca4dc1be201383a787d9c6334a5447fde4d35d880094678c8e371eadf92f14e4612f1939443e29bcc777d6dc92b392ba2860f877d195201ff3ea338a3204bc70566350202b0629df31a4a4b7ba9286b4494fe01e84f0ed9e94515114dd5664e6657152094f6fc08ac90c1d586a3dc76654c311347755fb952d668adc4e4b9dbff21defc2140638fb62a19d83850b7d40e976667be16b1030c0926a362d4437ec630193b1ce010b4c07c470710b22ec1c3452944179e9a01065966aab29627e9731abc97b1b6a1874dbf89adb2cb6b5517fb047997d63f9482ee4f35ac2537fedca355ebbba7b36a4a5be1ebe3eb68f10212501545f1f339205562b15eeafc551a6616423a9903245baeeb1326fe9d6b89421210267d99ffc6f23ed5ef28c772bd177017b92f1af6d0c4db7d58e22571e05d9dd5a69136dcfc4689d0aa2bb6a1210bb0ae8806c79d892758ae0372f3ad5f1a016fef3f67f030cbe8a1a28473035c428f13dac7b4d044bd9b9c54e0fd88be9b60867e183f718913b6e9d657dc41e0c47ffd8856e143d2451fb139865790d9d2bf7db493b58f2b0ac94c1a1d2456909d3526ce10870bc63298ac5b908d2043618a9841e06d953c76d4f",
     "password_file": "f82087d45d44875f2469129118714c1811028b0b2e173970fd130ae12a258a6a5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0265107c246317b40bd8e4c19336b9caee0d1f79fc2428907e5adf6c62ec188072b196bd570c5a06777c1103b3a64d3e19c16bdfa37c93a70bad7da1d1a6b4bffa4c",
     "export_key": "beee088578f1b48e4729fc54e12f03898281a25dde1a4d6bcc879a5e99cb1ccf976dfc63f9ce2d4f7e839f7d29f8e1b99dc4d0df99d4a32970981ad1a98344fd",
     "session_key": "4da473675189e99303ded9136c2401adbe4737c52d60468e9283c28819327a809b20ffbb21dcdf17a217a8e06b860bc8963ed31efb3d26be31a5c5578a807cb6"
@@ -1633,7 +1794,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_RISTRETTO255: &str = r#"
     "credential_finalization": "f8d7579d69f3d6bc05a0170fb9cd79d3bf70043dd1b3ec94fcf239db8465b93278dcf373f6b738d20c317698c77bfe97f371e55a727f57c433a64e49a1a2bd98ec68e8c9d560bd9e6f6f3c62bc625d2d790b1011b940b97702ca34012cc076955c2fcba755a30fd318c8b5cc3ba6b93271600262cd975f05fe2f2b5a511e42a0",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff3e0b05726b1ce40a5a0e1656d557505c5815a9ebcbea8de0aeb63a9275d3392a86cf4913dae94cddd26351e81b0ea403aa9a38d9dabb32868757794b193b1e072488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991a3569b07ed992042fec0ddf5c57fdf967860b5a468b728fdd702b010a8e371cd26f7c51d327657f1cc52ed02de71f20544cd2db6355adb00cbdcd0f9c27bdf63a1c907cd7f044c0791fa8b26595792d9d5484d2450bd3e6d6b04a6ae4edc1860ec68e8c9d560bd9e6f6f3c62bc625d2d790b1011b940b97702ca34012cc076955c2fcba755a30fd318c8b5cc3ba6b93271600262cd975f05fe2f2b5a511e42a0",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991a3569b07ed992042fec0ddf5c57fdf967860b5a468b728fdd702b010a8e371cd26f7c51d327657f1cc52ed02de71f20544cd2db6355adb00cbdcd0f9c27bdf63aedbdbd92ef96bbcd48b516c25d5cb5288cffa4e66fd2d45afcb8861f0b7bd86cfb0b1ea1a943af7cd20dd1ce256f9383616c66c6949e507a10a6d3415f2b298a1c907cd7f044c0791fa8b26595792d9d5484d2450bd3e6d6b04a6ae4edc1860ec68e8c9d560bd9e6f6f3c62bc625d2d790b1011b940b97702ca34012cc076955c2fcba755a30fd318c8b5cc3ba6b93271600262cd975f05fe2f2b5a511e42a0",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "a3569b07ed992042fec0ddf5c57fdf967860b5a468b728fdd702b010a8e371cd26f7c51d327657f1cc52ed02de71f20544cd2db6355adb00cbdcd0f9c27bdf63"
@@ -1674,7 +1835,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P256_RISTRETTO255: &str = r#"
     "credential_finalization": "95f75f2dae14c30735f7d985580476fd4e78efe9a2173dff662fae45ca34af47712e955b38894b23c0b278ff5bad8ebd659e1ad2a043555c20dc46bb50baf20f7866c34cd3d721ab62b0d6af0f1c83452bdf66507b167c05f9b7691a5ab2ad448293b482f9fa85eb17bd3bc6bad94751658089886a03b5762ca86e30a6581c3a",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e3e0b05726b1ce40a5a0e1656d557505c5815a9ebcbea8de0aeb63a9275d3392a86cf4913dae94cddd26351e81b0ea403aa9a38d9dabb32868757794b193b1e07ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8595c67ba99781abe5cb7420d554084b6bc07518105f36f7ca0c937da81c4207f22531ec171af2bac969146ce856ac18a3173d4297a78428c8f5f27b61ba95b03ce9b9f5251112afce949a4717875a21131ebca30b04eb7f2c820e52b7b9d3b137866c34cd3d721ab62b0d6af0f1c83452bdf66507b167c05f9b7691a5ab2ad448293b482f9fa85eb17bd3bc6bad94751658089886a03b5762ca86e30a6581c3a",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8595c67ba99781abe5cb7420d554084b6bc07518105f36f7ca0c937da81c4207f22531ec171af2bac969146ce856ac18a3173d4297a78428c8f5f27b61ba95b039476898ae6b722065dbe890f76d092b2ed7772af0d06c96a207caa945d87b295dd7710b0cf71ae12d52b5512eec8a20eae67a0b0910526f824ae0b244f12cfc5ce9b9f5251112afce949a4717875a21131ebca30b04eb7f2c820e52b7b9d3b137866c34cd3d721ab62b0d6af0f1c83452bdf66507b167c05f9b7691a5ab2ad448293b482f9fa85eb17bd3bc6bad94751658089886a03b5762ca86e30a6581c3a",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "595c67ba99781abe5cb7420d554084b6bc07518105f36f7ca0c937da81c4207f22531ec171af2bac969146ce856ac18a3173d4297a78428c8f5f27b61ba95b03"
@@ -1715,7 +1876,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P256_RISTRETTO255: &str = r#"
     "credential_finalization": "7387307aeb296cc24e9b54c40ce3d5be15bc8ceedf00556467401941a935c3fdeb393ed97791aa0b6c78b16547ea525fc246da9c5207883321d48f9c8782407ec52074beeee18a4f3a551b5c4c95ae5f4530b19776167aee2481c69198338e2fd3e6f02aa1b74273779986a020cc9f919c680eebc096f0a76c807db0426dde47",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d13e0b05726b1ce40a5a0e1656d557505c5815a9ebcbea8de0aeb63a9275d3392a86cf4913dae94cddd26351e81b0ea403aa9a38d9dabb32868757794b193b1e07a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5d4d49b6b856154ea8b16a32648f916a79c58772a0021d3fc4af0627638546ff9e79eb4828409ce7f5a8d7a1419c04e1a8f66dd311d53bdc054d5ace38e39a5508722cdb2a9c11c719d30df1b8debb22f668100e6882a625a06596bc2b0334ae2c52074beeee18a4f3a551b5c4c95ae5f4530b19776167aee2481c69198338e2fd3e6f02aa1b74273779986a020cc9f919c680eebc096f0a76c807db0426dde47",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5d4d49b6b856154ea8b16a32648f916a79c58772a0021d3fc4af0627638546ff9e79eb4828409ce7f5a8d7a1419c04e1a8f66dd311d53bdc054d5ace38e39a550b6c988ae963983efc8b52df8ff4cfa7f2db000908ad9d2a96dd81302a08b70d3fdd14abbc5af4a9994ea146bcaa00b69f7a3390cad33b9ebd4ad84f1980709888722cdb2a9c11c719d30df1b8debb22f668100e6882a625a06596bc2b0334ae2c52074beeee18a4f3a551b5c4c95ae5f4530b19776167aee2481c69198338e2fd3e6f02aa1b74273779986a020cc9f919c680eebc096f0a76c807db0426dde47",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "d4d49b6b856154ea8b16a32648f916a79c58772a0021d3fc4af0627638546ff9e79eb4828409ce7f5a8d7a1419c04e1a8f66dd311d53bdc054d5ace38e39a550"
@@ -1756,7 +1917,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P256_RISTRETTO255: &str = r#"
     "credential_finalization": "7dd8c90de9c33ec7670ca5b31b6de1544eb9a4b77f4966794d614d6f8b84f6cbcaea85cfbf46856649c9a6095396984b20811e03971cd52b13ab447837865e100cdfb5542eb373299650d6081ba780b7993d8ddb4892024b53a89b09cdfe92dafac9261820984678802c013c8d4ec56af0f9d7e53b4744af393a2476f176f49a",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff3e0b05726b1ce40a5a0e1656d557505c5815a9ebcbea8de0aeb63a9275d3392a86cf4913dae94cddd26351e81b0ea403aa9a38d9dabb32868757794b193b1e072488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00fb211bfb19165c20b9a417c5a33d7c2642ad71fbd50213f04d0f31d02c4b9c6c8bf2c0cbcd828f9e2f01f1dcd0114bc5cfea455064788a46b0dcb802bfb1ffdf82290bbb483f5a3f2af69203177cb7b887e0808e3526663d5d4eeb63ef6c5fae00cdfb5542eb373299650d6081ba780b7993d8ddb4892024b53a89b09cdfe92dafac9261820984678802c013c8d4ec56af0f9d7e53b4744af393a2476f176f49a",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00fb211bfb19165c20b9a417c5a33d7c2642ad71fbd50213f04d0f31d02c4b9c6c8bf2c0cbcd828f9e2f01f1dcd0114bc5cfea455064788a46b0dcb802bfb1ffdf8bc98126d43f77045430ae36a3acc6fb899f96342cabd6e72f84bfca7f36a03722b0682aad4221d8a7f29aa58bb23dba701a1d5a0d1026e095cdddde4c127660d2290bbb483f5a3f2af69203177cb7b887e0808e3526663d5d4eeb63ef6c5fae00cdfb5542eb373299650d6081ba780b7993d8ddb4892024b53a89b09cdfe92dafac9261820984678802c013c8d4ec56af0f9d7e53b4744af393a2476f176f49a",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "b211bfb19165c20b9a417c5a33d7c2642ad71fbd50213f04d0f31d02c4b9c6c8bf2c0cbcd828f9e2f01f1dcd0114bc5cfea455064788a46b0dcb802bfb1ffdf8"
@@ -1797,7 +1958,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_P256: &str = r#"
     "credential_finalization": "0a71ea90e5f9e68df3589d1dfcb905d41130cfba2c86ba0bb5fe03927bee482354f0defbdc7384d15234a690a3355a08fab0f9cff7e6a786f2843df69d21a2acda5aeacf1ca29a203f525f5e2c39f45bf6435e8952c03c141afb54edea590625",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991d4f8e253f96fe75bd1231e7b259a6dfff9ed69a012728051610cfbb7f50dad5a5aaba8cc520568c7c9943f0eb1623e351c5bdf295509b087bf9a4e7dcc3d098eda5aeacf1ca29a203f525f5e2c39f45bf6435e8952c03c141afb54edea590625",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991d4f8e253f96fe75bd1231e7b259a6dfff9ed69a012728051610cfbb7f50dad5adaa5cd570d0165adcd94ab5a0515a9bdefb44ad1f32d6d59032bb5f2609b8a155aaba8cc520568c7c9943f0eb1623e351c5bdf295509b087bf9a4e7dcc3d098eda5aeacf1ca29a203f525f5e2c39f45bf6435e8952c03c141afb54edea590625",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "d4f8e253f96fe75bd1231e7b259a6dfff9ed69a012728051610cfbb7f50dad5a"
@@ -1837,7 +1998,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P256_P256: &str = r#"
     "credential_finalization": "cb4537679a9f91fa57277d814b12f57edd6f292228bd73cbff70bc1b2d2674eb710d5fc977b04168d3ce15295c9156c2289e6629c1213cb676d55a693375c952a28c810e06ce53db9a960240119d1bc93eeb9b8897b5c2fa5d30ead94d477b45",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8b480262cc5a7cf7ecc8cf8858cd3d82e6ede59822cacff5082022bb19019495565b6bca059d5435980bedd4ed5a0754c18115da6696aa4b84d020d8f17d31a03a28c810e06ce53db9a960240119d1bc93eeb9b8897b5c2fa5d30ead94d477b45",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8b480262cc5a7cf7ecc8cf8858cd3d82e6ede59822cacff5082022bb190194955456209eacabdbccf60191b0bfac68bcfde50520bd323dab112d7feedd1c4776c65b6bca059d5435980bedd4ed5a0754c18115da6696aa4b84d020d8f17d31a03a28c810e06ce53db9a960240119d1bc93eeb9b8897b5c2fa5d30ead94d477b45",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "b480262cc5a7cf7ecc8cf8858cd3d82e6ede59822cacff5082022bb190194955"
@@ -1877,7 +2038,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P256_P256: &str = r#"
     "credential_finalization": "45efe63ab8988811f954a00405715260a721aae237f96bbd28ce2ff8f5ee30e0a754bf841820c49417a08c28b887cdf106119ba0fe75badfc618a1835e07eb3d979aab131c69e92a8b478c8b88275b31d410032d93280efa53476d290a536871",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b55427a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff592e6024f4702683fefd9e5dd87e01c007492a67dceb690454198e442a7eb21980f64407eb9305fad1b4a900f34b937bc639b12af304db1e8f246505004d847dd979aab131c69e92a8b478c8b88275b31d410032d93280efa53476d290a536871",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff592e6024f4702683fefd9e5dd87e01c007492a67dceb690454198e442a7eb2198226903ec18788ebf2318b2e9f86512a13901abbd13ef76427a0d6906d5714ed00f64407eb9305fad1b4a900f34b937bc639b12af304db1e8f246505004d847dd979aab131c69e92a8b478c8b88275b31d410032d93280efa53476d290a536871",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "92e6024f4702683fefd9e5dd87e01c007492a67dceb690454198e442a7eb2198"
@@ -1917,7 +2078,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P256_P256: &str = r#"
     "credential_finalization": "b1cb6eecea8c8bbf6f3a830730f9bc9efe16ca224249a64c30df280ba5cc85660bb87c6139f36b7c140a8c9a5e364743501dfbc7096cc09a1911e755d459b4c20644a4992861f4336759e39abc7f8053c9b06211f6cd70071398b59e11c0f527",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff029a906dad8aad24f5a026ce40f62dbe916c9ad57723d8b9ad29d39f472ae8026fbf0ab4e51b171cd8492b98cad76f781e16bb630d3f9ad29836f9a0c6e1b554272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f99a191fa7d4054c587fcdd9b6f6400faa78a3bd466645a94ba729ad9b17e88102fdb47fe365eab4471a1ac194f55ef1add94e7094a35b577fe86f122708c64000644a4992861f4336759e39abc7f8053c9b06211f6cd70071398b59e11c0f527",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f99a191fa7d4054c587fcdd9b6f6400faa78a3bd466645a94ba729ad9b17e88107d0081c2b5eb27232772ef3288e32077ffaaa0b7a74cad1e05efffaa980021182fdb47fe365eab4471a1ac194f55ef1add94e7094a35b577fe86f122708c64000644a4992861f4336759e39abc7f8053c9b06211f6cd70071398b59e11c0f527",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "99a191fa7d4054c587fcdd9b6f6400faa78a3bd466645a94ba729ad9b17e8810"
@@ -1958,7 +2119,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_P384: &str = r#"
     "credential_finalization": "28a9ba75d36321fe0e8976e19c18ef7b667c91446c1ee6c6c915ba84eec70d964205fa1a30dba3f74d6768de5134b0c0087c1ecc5180ceab44885fb90d7905303e93e47ad8a60db60c4dde4c578820cdab76114eecc90597839535c9c9ae78a39cfa55c7509e6ecabbc9807c555eeb26",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230022a4637e93df4a567b09ff2237b4277c870855dd4debdf03f028997c9d6790b5bfda7d11875429ec2b6492722365997bfa86398ce69e6d3d6255ce3c2646afbc39588789fb2084e5adb7d443a4c6d4a5a55290a76d994fec575cb512741a1278615ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230",
-    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991f2b73916ce5491c7c7879636e8bc1ac1ce049b704e9723f955aace19b3f96cbd817d1f1ab38187b13c84d39aba45a772f141cee4f006d6f251b068d370b4764fe6cfb2d461942b54c27aa37d462f9cc03e93e47ad8a60db60c4dde4c578820cdab76114eecc90597839535c9c9ae78a39cfa55c7509e6ecabbc9807c555eeb26",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991f2b73916ce5491c7c7879636e8bc1ac1ce049b704e9723f955aace19b3f96cbd817d1f1ab38187b13c84d39aba45a772af105b9c0d98001b83d3d6504d4260ef629fbffa4e6887f727a9307b8a6c66eb0e7faa395d08c9fd4b0638971060bcb5f141cee4f006d6f251b068d370b4764fe6cfb2d461942b54c27aa37d462f9cc03e93e47ad8a60db60c4dde4c578820cdab76114eecc90597839535c9c9ae78a39cfa55c7509e6ecabbc9807c555eeb26",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "f2b73916ce5491c7c7879636e8bc1ac1ce049b704e9723f955aace19b3f96cbd817d1f1ab38187b13c84d39aba45a772"
@@ -1998,7 +2159,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P256_P384: &str = r#"
     "credential_finalization": "4deebff6db7aed6a24c52efb20e271c5aa40e394c3349d7e389d374764c0f6487cf878a1bed6e42a081a87e74065dd0a430474a50eac58e06d2df3aa05c584f61b168c3d8c1b27581299b6e783f01503b474f18dd82caae35c77c6237e61cc8689b928061e928164846cbaf41163bae4",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02e11403d5b292ef4c20c46bfaa0d59a896ec8e6bfbcd01ef86116ab25934a463171344e828c71de04fb068eaad0e85d8e46a34ca1c536eabda2ffab89cdd8d4b1199773861256518577a186806eb38e10cee2ba7d1defc11f9854ec1461fa737af5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e",
-    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca85bae727d77c4d89a572ea35c9df34e0a33b96008ee4d8f5943271d1884bd9c2f419f727c07a515e1071defed0adf6710ce724103700e50ed68b33339a1693e9763129648771b7235d7d7071c6ac356b91b168c3d8c1b27581299b6e783f01503b474f18dd82caae35c77c6237e61cc8689b928061e928164846cbaf41163bae4",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca85bae727d77c4d89a572ea35c9df34e0a33b96008ee4d8f5943271d1884bd9c2f419f727c07a515e1071defed0adf67107d404e6fd96cec8fb0e5182e9a1a78c6f2f28e61ec0ba4abf80ef0c38819efc3a8c182a7b97f6ff6ab58656432c0c3edce724103700e50ed68b33339a1693e9763129648771b7235d7d7071c6ac356b91b168c3d8c1b27581299b6e783f01503b474f18dd82caae35c77c6237e61cc8689b928061e928164846cbaf41163bae4",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "5bae727d77c4d89a572ea35c9df34e0a33b96008ee4d8f5943271d1884bd9c2f419f727c07a515e1071defed0adf6710"
@@ -2038,7 +2199,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P256_P384: &str = r#"
     "credential_finalization": "2ebecceeee583040a20420503a47ca752b3b830754526a3eceaa6bf2b0d9563cb9f42863a971a4bfa1fed28b3dc564bd739f2302d54c2905706d7bcf2747a5d4a127681cc2ac6b155c4e183d53adb15d8504de33860fc801ab38bb6f9cfbf8b2c2a16abcc108fa3483e3343cd3eee2ae",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f021e0690abfc3116c6f64d1f46f6bd9f11af9fd3895f0b9a7b3051a10095bed0ccd7e78ec27d65334e5acf7881baaa61a9c278a93210dc262cddf6809d694a2ae0d184b311397150ef31aceb8be20b7a682e8dee41202a49ffcd20fc2769503597fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5c765bf7c0378f5e24dfa646bde529e37f76384f9e3f7bbe86b6aa586b9eba37bb5134c0a7738dc095fe87446317094487abc015ce08f7b36032d5b0b3609b0cc6fc9bdf8e31bf96a31442592e62bb077a127681cc2ac6b155c4e183d53adb15d8504de33860fc801ab38bb6f9cfbf8b2c2a16abcc108fa3483e3343cd3eee2ae",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5c765bf7c0378f5e24dfa646bde529e37f76384f9e3f7bbe86b6aa586b9eba37bb5134c0a7738dc095fe8744631709448233ff8803a0625ba95fd481d0a88d3dcd6f92997e67863a67be236c54bba36d644e89718f043857848adb4cebcddd25e7abc015ce08f7b36032d5b0b3609b0cc6fc9bdf8e31bf96a31442592e62bb077a127681cc2ac6b155c4e183d53adb15d8504de33860fc801ab38bb6f9cfbf8b2c2a16abcc108fa3483e3343cd3eee2ae",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "c765bf7c0378f5e24dfa646bde529e37f76384f9e3f7bbe86b6aa586b9eba37bb5134c0a7738dc095fe8744631709448"
@@ -2078,7 +2239,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P256_P384: &str = r#"
     "credential_finalization": "97fd11bd30be15d3360b35081ca1d93f802af14cd0cdfae74292febd45566e39178429d9a261a8ce57e297050ce52099a98d6ab419bff4340501e1d204ae633b2d0cf32db53c2e4abc295ed48b6a74144394bdfa3077065e62a3e9d9dafc3159f87d90131c8cfee7f25d3adcaae44430",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755022a4637e93df4a567b09ff2237b4277c870855dd4debdf03f028997c9d6790b5bfda7d11875429ec2b6492722365997bfa86398ce69e6d3d6255ce3c2646afbc39588789fb2084e5adb7d443a4c6d4a5a55290a76d994fec575cb512741a1278615ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755",
-    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00fd75413a6f99e8830b70d57d1c2aa144e68c53e0458bfe41dc6f4938ac61645d8074dfacb2840c425897b4b46cc86b13980bfaa726871d7c41b5b334a502ab6b072e6ab01b227170228f0b650a4379aa82d0cf32db53c2e4abc295ed48b6a74144394bdfa3077065e62a3e9d9dafc3159f87d90131c8cfee7f25d3adcaae44430",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00fd75413a6f99e8830b70d57d1c2aa144e68c53e0458bfe41dc6f4938ac61645d8074dfacb2840c425897b4b46cc86b13972c657134406a97c769417c70dcb84fe33894b21a5cd93ee03826458d4ff8ca1d9389a5dafb8bd002abb46cc5b089ea380bfaa726871d7c41b5b334a502ab6b072e6ab01b227170228f0b650a4379aa82d0cf32db53c2e4abc295ed48b6a74144394bdfa3077065e62a3e9d9dafc3159f87d90131c8cfee7f25d3adcaae44430",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "d75413a6f99e8830b70d57d1c2aa144e68c53e0458bfe41dc6f4938ac61645d8074dfacb2840c425897b4b46cc86b139"
@@ -2119,7 +2280,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_P521: &str = r#"
     "credential_finalization": "c9e37adaea27621f3560b25442fbc9458462e976d6971b0730c94ccfb38013ead744237553f850051ac9123060008aa9adb1caf4d0667226f54d550d710b930c245b44942123933a6d37fa1cbd1d0f95a593b548dd9e0bfa1c0d36152c8763a6121d78a8ad8466eebae7d6896a393e72204bad4214f5a4556e6147255ceb36e5",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b7c1a0301dd56b385132162cd5dd6063f8024870fda4027f33fe1985a56c31fddade3dfe739b6493be7811d728a46ba3530cca19ff15d030036ad34214a0ffcefbda1511ba90089beeff3ba90d2769583117fb4ceb2a191536d5e2061f895dc68bf7fa64781886f18945229cb98d6a24edee2a0b25178a5e70cf41c56b3b4732bbabe320fc9554483cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b7c1a",
-    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e941299156deb641ddf1f9bec7f8121069dff7eaee19584f9f3a66138ee381350dedcf8c2f1c735e61ded75b4298030a319256a61b2e36bcb9e2645b1fbe9945af603769d4639895182127c6f0ad684b0823dfd11e11b978f4e9d68d6ad053e30ab13cce245b44942123933a6d37fa1cbd1d0f95a593b548dd9e0bfa1c0d36152c8763a6121d78a8ad8466eebae7d6896a393e72204bad4214f5a4556e6147255ceb36e5",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e941299156deb641ddf1f9bec7f8121069dff7eaee19584f9f3a66138ee381350dedcf8c2f1c735e61ded75b4298030a319256a61b2e36bcb9e2645b1fbe9945af60376998e28211cc8c10d9cc5665775ce50d5f323fdd517509a6b8d72dd665e4d85cbfa1c7991c2dde00e8ffc4a7a98163acd87a57e4cfb91a9d94f0fdc6520edf807dd4639895182127c6f0ad684b0823dfd11e11b978f4e9d68d6ad053e30ab13cce245b44942123933a6d37fa1cbd1d0f95a593b548dd9e0bfa1c0d36152c8763a6121d78a8ad8466eebae7d6896a393e72204bad4214f5a4556e6147255ceb36e5",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "56deb641ddf1f9bec7f8121069dff7eaee19584f9f3a66138ee381350dedcf8c2f1c735e61ded75b4298030a319256a61b2e36bcb9e2645b1fbe9945af603769"
@@ -2159,7 +2320,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P256_P521: &str = r#"
     "credential_finalization": "d703369059b329e2e4597d46e363fffb71f892b6d745b7d3f96145b8a251463796d1ed0cc1f2ac8b2dbe6130f2d9a13fcda75441b970af0b052583d55f50874af3669155d601a735ec28874212119152bc843e304184e73ec46be8dcd178e0c49438032a48c16668f132bc3df709caee676d3e74746923e565e00afdd890f49a",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212387c1a0201dba59fdc884db6887b4a92fb2c3c79b3e946d6a566af17374608236746660f8ced2cc7eaf55794a03cf0e0f1cf81a706b480643113ac6e9d8a6df6869a3e8db08301902c1813bd5b6fd873613fb3b15d40c3416dffd6eb15cbd49f93a0a8c84193caab9891396f4efb933f111ccc7efa70f0c2c7f392a461bbbd1eeccdbce746b365668efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212387c1a",
-    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8f4e8b20969bd6ec5361ed065c3ef15ccd151d8ea694cc2e09aef1e9cc20be6dc43174108425415065f8477b9bce8d0e9635af52489070c4e4fbf3463fc1dfd8e7e195d7a133b52e9bc4f8f84a6d353ccddef511ec2f0a76d4a65516099b4e2a0f3669155d601a735ec28874212119152bc843e304184e73ec46be8dcd178e0c49438032a48c16668f132bc3df709caee676d3e74746923e565e00afdd890f49a",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8f4e8b20969bd6ec5361ed065c3ef15ccd151d8ea694cc2e09aef1e9cc20be6dc43174108425415065f8477b9bce8d0e9635af52489070c4e4fbf3463fc1dfd8e190e9cc6467d6d4a5171e6fcd9704627ca6d5277f14abd94127a8d24b8b642ebd3391d36f43ad55d445595514a3dfa45a40c1d1c7867d84927945aa6213dc5e67e195d7a133b52e9bc4f8f84a6d353ccddef511ec2f0a76d4a65516099b4e2a0f3669155d601a735ec28874212119152bc843e304184e73ec46be8dcd178e0c49438032a48c16668f132bc3df709caee676d3e74746923e565e00afdd890f49a",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "f4e8b20969bd6ec5361ed065c3ef15ccd151d8ea694cc2e09aef1e9cc20be6dc43174108425415065f8477b9bce8d0e9635af52489070c4e4fbf3463fc1dfd8e"
@@ -2199,7 +2360,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P256_P521: &str = r#"
     "credential_finalization": "22754f122f3477f455064b415fbded7914f2f7d50f46a5d6c0c3be8caaad6b5827199d0ced2fbe876ae47ea1f1e0046132de2b5c0759015ea155f06353cc7629b9add683d4cab0f3f66d2c725485731126c10e3f6526452e670ed30e2eb21d8df618af30b1138fe84733aa17883c550c78059a0cbd5b2bf73a7f9c41f7cb18af",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd19802015d4fe68f139448b37f4184edf2424b2128b7279d5da380055e94761e12898d58851c3325512c80e95ec5fae16fed502ee132f92472e6f55a25c531b9c0b6a1ec6f009b2807ed73e2885c9ae8fe610c128bd5b6c1191ad536ad6d285a043864deb873e353c5c6dac8c45c0c05c6ca1e449d0e35b30a8a31f6c4e1507e2da1ca72aec769c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198",
-    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5998b637d8af331656f21db4eff5a671937957c88dfe954a72f55c0263d5944c4a15c6d3289f69f1f9b63a4357dc8037474c161c7735f4049fa6e99ec7f46f5d01d7899282aab93ee0f7d1f86313d2d886c99562102cb053476c00a132475322eb9add683d4cab0f3f66d2c725485731126c10e3f6526452e670ed30e2eb21d8df618af30b1138fe84733aa17883c550c78059a0cbd5b2bf73a7f9c41f7cb18af",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5998b637d8af331656f21db4eff5a671937957c88dfe954a72f55c0263d5944c4a15c6d3289f69f1f9b63a4357dc8037474c161c7735f4049fa6e99ec7f46f5d0ea6b1bc558463a35b5ac681d15cd10cb26cd9e7da6a21eef87a0ddd8598c3dfd74822ed83ac8e1e4eabbfdcdd476cd29ae64f25c38b47f6f6fd8aa34d43f8cce1d7899282aab93ee0f7d1f86313d2d886c99562102cb053476c00a132475322eb9add683d4cab0f3f66d2c725485731126c10e3f6526452e670ed30e2eb21d8df618af30b1138fe84733aa17883c550c78059a0cbd5b2bf73a7f9c41f7cb18af",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "998b637d8af331656f21db4eff5a671937957c88dfe954a72f55c0263d5944c4a15c6d3289f69f1f9b63a4357dc8037474c161c7735f4049fa6e99ec7f46f5d0"
@@ -2239,7 +2400,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P256_P521: &str = r#"
     "credential_finalization": "03785af9d2999ca94a1e134d03e8e7b292f5309c2abe07dbde7234f234a251f000ddc79c42af465537ad3f2fde684deeadfa22e3f20de710299c4fe01739e276cc1f369000569a85625c0934f4363e3a1ff13245dcfde9f5e1d2d9f8588f92b0d05b505141a6bf3e9ddbfb3cf4ace3841451d55f00be7cde389aeef105664287",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be03015435d15842258327fc6e2ab9da0dad07d750b93b091245bd2c14bb41f7e4d02fd3f37d2b27267d7e3f50fce50cb8fb4b6b0578a7bf10114abda8686edec5397f9201a17a25443af0a1b250c9c9ed820999022b3d76dce4e42eea8b3ec74f89b6eb5c04bd76921ded0537e25f6fb57cb3a76143482db9762e98d810e0e8e9400eb7f5e3f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be",
-    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5bd3deef31044bafc94b647bba6b6a6538dfa9e5b29c114c48abdeeee8a72e78e1f3091b90d309d46ae087b94871fd2d08572ecec91414ceb484f0ea2d30a0cd12b19bf3dc7ffd030c76dd6c85f52c194b738a2c55ee92e5f73c218b32de53f5cc1f369000569a85625c0934f4363e3a1ff13245dcfde9f5e1d2d9f8588f92b0d05b505141a6bf3e9ddbfb3cf4ace3841451d55f00be7cde389aeef105664287",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5bd3deef31044bafc94b647bba6b6a6538dfa9e5b29c114c48abdeeee8a72e78e1f3091b90d309d46ae087b94871fd2d08572ecec91414ceb484f0ea2d30a0cde926eb0aee4835f84a1d18352f9dd6e8fc8e32aba7b62924eaeaa2f9fcc78ac1c55dea8bee757e2cc4c59ab1281c0d5496e22cba625766c2d1fe410f8dcde87312b19bf3dc7ffd030c76dd6c85f52c194b738a2c55ee92e5f73c218b32de53f5cc1f369000569a85625c0934f4363e3a1ff13245dcfde9f5e1d2d9f8588f92b0d05b505141a6bf3e9ddbfb3cf4ace3841451d55f00be7cde389aeef105664287",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "5bd3deef31044bafc94b647bba6b6a6538dfa9e5b29c114c48abdeeee8a72e78e1f3091b90d309d46ae087b94871fd2d08572ecec91414ceb484f0ea2d30a0cd"
@@ -2281,7 +2442,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_CURVE25519: &str = r#"
     "credential_finalization": "55e73b2dd7e417810f1a3cfc8728d38cce364cc1e94229e67aceed6f62f9705fec8d5e0aa4a4b6961059e35207b58e2cfcb48cf049c585d7af993682ec20240936dd3e76825bc85ebcd29c4414d082f5e6c13b2dffb2dc393a60fa8c537b3dbbe21f7ee25ef390157d2ff33a28458d9908cb6854ee3cc2cb6bd44e37fcc49db5",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffc634a50bde38dba8cdf402ac6187fa08d1f263d4c0bdd0f1a7414053cffecb2a781a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57402488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e941299150f4f04a8499559dc4667e5cfecb062eb5d0d3ebeecf7fdb722a8f029a5f8583926ba929c723d0f04a416d4420cacbd685edf42e6700cb6ac9548e2d8104ea8dd9fc59741b5d794de417fde0cefd9c664e4ea152b9caa103d81ff95e9e09c22d36dd3e76825bc85ebcd29c4414d082f5e6c13b2dffb2dc393a60fa8c537b3dbbe21f7ee25ef390157d2ff33a28458d9908cb6854ee3cc2cb6bd44e37fcc49db5",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e941299150f4f04a8499559dc4667e5cfecb062eb5d0d3ebeecf7fdb722a8f029a5f8583926ba929c723d0f04a416d4420cacbd685edf42e6700cb6ac9548e2d8104ea8d94e9f70edf2b14a63b34b2c9d134df52fa5e9007b54d53f87191923574a1563859f6d6f5dd3a9d47bd028d1c3f82b47907f9144c73eee00644755f55c8910bedd9fc59741b5d794de417fde0cefd9c664e4ea152b9caa103d81ff95e9e09c22d36dd3e76825bc85ebcd29c4414d082f5e6c13b2dffb2dc393a60fa8c537b3dbbe21f7ee25ef390157d2ff33a28458d9908cb6854ee3cc2cb6bd44e37fcc49db5",
     "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
     "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
     "session_key": "50f4f04a8499559dc4667e5cfecb062eb5d0d3ebeecf7fdb722a8f029a5f8583926ba929c723d0f04a416d4420cacbd685edf42e6700cb6ac9548e2d8104ea8d"
@@ -2322,7 +2483,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P256_CURVE25519: &str = r#"
     "credential_finalization": "55e9402fc9910ec163bfd236b095574da6a3f1c6665da8e3b4f03e53d8beaa48c05c68145ecfa268bf332e94726fda2492b4e02d81dc372185145c580a6b56566076a141c4e4963dec17a9dd677353a5a4cbab5157131b60c491f0ce14bd84ed3154ff32fa45b93504616aded56c8466c3c4826ebc1b90e2a5cc0ef4df250e3b",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ec634a50bde38dba8cdf402ac6187fa08d1f263d4c0bdd0f1a7414053cffecb2a781a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5740ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8b0ee54f13d6aeee549caff6444b885ca8ffb1d82c883303c001d0312c303c4f037d4381d247765d7ca1ca9e7a0c8462418ac311680bb0868d962380ec1ab4cdb5cf8687c548cb2ea7b10bc9890ffc3b19bab1d4219192e39172bbcb03fbbfb896076a141c4e4963dec17a9dd677353a5a4cbab5157131b60c491f0ce14bd84ed3154ff32fa45b93504616aded56c8466c3c4826ebc1b90e2a5cc0ef4df250e3b",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8b0ee54f13d6aeee549caff6444b885ca8ffb1d82c883303c001d0312c303c4f037d4381d247765d7ca1ca9e7a0c8462418ac311680bb0868d962380ec1ab4cdbc95d193608568eeefeb6688e41df06217743c73ef43ac311c8f69bc9b3e654792e83f6f3fef81b6619b77f5f0f51f3d5a7c3d8b8c8b6cf93042d06f8f4dbf8f75cf8687c548cb2ea7b10bc9890ffc3b19bab1d4219192e39172bbcb03fbbfb896076a141c4e4963dec17a9dd677353a5a4cbab5157131b60c491f0ce14bd84ed3154ff32fa45b93504616aded56c8466c3c4826ebc1b90e2a5cc0ef4df250e3b",
     "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
     "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
     "session_key": "b0ee54f13d6aeee549caff6444b885ca8ffb1d82c883303c001d0312c303c4f037d4381d247765d7ca1ca9e7a0c8462418ac311680bb0868d962380ec1ab4cdb"
@@ -2363,7 +2524,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P256_CURVE25519: &str = r#"
     "credential_finalization": "dbe278cf97ac5e8c4e73384df0ac4c7d1004d8e7a88c2b583ecab32de8ec9db53d4ba15eafe0ae6193ca0d2ca8b07095c6fa7248c9e6d7946b4534dcea59d2f3361f58aec1a5b51467a58735508750be6888b2eaa2e537704b0522439ef54fedb19a2c9e216fb63e90d69fc72167e108c423d6374b668de0b5f5f2f1f26fe69d",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1c634a50bde38dba8cdf402ac6187fa08d1f263d4c0bdd0f1a7414053cffecb2a781a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5740a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5bffd153ea8d6343d2db6f8b76a63222da1f8687acf2ec7479286abddb146290113f238f4088db4f6b1f50ec54bcd5ad99d8383ee1b01af17626d9e44ee126e6142965ca4372e56d4abfbc9a7a8b86ce63b8747dcaaebea9ef1aee2f6b600d6a5361f58aec1a5b51467a58735508750be6888b2eaa2e537704b0522439ef54fedb19a2c9e216fb63e90d69fc72167e108c423d6374b668de0b5f5f2f1f26fe69d",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff5bffd153ea8d6343d2db6f8b76a63222da1f8687acf2ec7479286abddb146290113f238f4088db4f6b1f50ec54bcd5ad99d8383ee1b01af17626d9e44ee126e61114507d62265daf235e1b9e06070ad5a4523ce48a1ac2ee4e16d4ec8b2ad256f5c1b1d60141ecf06666dfde6637e0d16afafab1158f99115e57c4071a9995e5a42965ca4372e56d4abfbc9a7a8b86ce63b8747dcaaebea9ef1aee2f6b600d6a5361f58aec1a5b51467a58735508750be6888b2eaa2e537704b0522439ef54fedb19a2c9e216fb63e90d69fc72167e108c423d6374b668de0b5f5f2f1f26fe69d",
     "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
     "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
     "session_key": "bffd153ea8d6343d2db6f8b76a63222da1f8687acf2ec7479286abddb146290113f238f4088db4f6b1f50ec54bcd5ad99d8383ee1b01af17626d9e44ee126e61"
@@ -2404,13 +2565,178 @@ pub static TEST_VECTOR_P521_SIGMA_I_P256_CURVE25519: &str = r#"
     "credential_finalization": "abbabc50130e0a7a3ad65fb98340c4fd132154473f9e42fc9afd74669de78832dbdbdb683244dea8d8d5a0a7866fcc331197ec19409f263b784d1489db0d0df72fac6d0e9c18fe53706cb2ff219f61ab995b683d3a2d0648333d978561a78b24787b8e9ba876797ce08acf730f20c184f781275d699383ad518a891b5ed99d26",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffc634a50bde38dba8cdf402ac6187fa08d1f263d4c0bdd0f1a7414053cffecb2a781a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57402488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f0beb1f63e1da72371c94095beeb192c2ed9f73c250cdec0ed7ca2364a518a48cfd82859d6502db5701ea96d968452b1522e2e9b704a5c8140839ff960b89b19ce084528b8c2e4e62e7bcfc3168747c2744a26e9c7123ca5890b5b4848ba64ce32fac6d0e9c18fe53706cb2ff219f61ab995b683d3a2d0648333d978561a78b24787b8e9ba876797ce08acf730f20c184f781275d699383ad518a891b5ed99d26",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f0beb1f63e1da72371c94095beeb192c2ed9f73c250cdec0ed7ca2364a518a48cfd82859d6502db5701ea96d968452b1522e2e9b704a5c8140839ff960b89b19c4d6968bacdb52fa0884281f6ce0e7cf0f3054f1b5065711cb5fcb46feccd7d73cfbd0e2602b69899eee02f77d40a3c29098752d05510861496a0339779ee3c93e084528b8c2e4e62e7bcfc3168747c2744a26e9c7123ca5890b5b4848ba64ce32fac6d0e9c18fe53706cb2ff219f61ab995b683d3a2d0648333d978561a78b24787b8e9ba876797ce08acf730f20c184f781275d699383ad518a891b5ed99d26",
     "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
     "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
     "session_key": "0beb1f63e1da72371c94095beeb192c2ed9f73c250cdec0ed7ca2364a518a48cfd82859d6502db5701ea96d968452b1522e2e9b704a5c8140839ff960b89b19c"
 }
 "#;
 #[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P256_SECP256K1: &str = r#"
+{
+    "client_s_pk": "021761440ac35a297290a591697aadb2f1237b623064235e0efa0046f169d2814c",
+    "client_s_sk": "ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b",
+    "client_e_pk": "03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57",
+    "client_e_sk": "7c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
+    "server_s_pk": "022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "server_s_sk": "65b7435899ada2632d7086307b1f127e56182bde709be796a5ba40c3f925b5f0",
+    "server_e_pk": "028d18f4c3e2422fb6ecf074aa9fa70c9eb67b2de53e653a3045a73a0b44c7ce78",
+    "server_e_sk": "3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493",
+    "dummy_client_pk": "03a57ec39337d292fed1fc054974fe1ffb8aea5eb49294dbd0bf8c68a135e068b2",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "registration_upload": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0112651cd638dcf56f0be26a4198ceec4ecc9905facaa1d260915520c4576abf891fac17d63f1fd8cdebec9e96696e6c92f02bc35499e39cfc2fffb041cff8241162e62d3158f971ac38ea5734b00892b5730d30fdfae9f118cff2b37facf7c5be069bd951a7b8bd033b438e7aa57e3779bf37471c6b4c2b83613017bce406b06a3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084916666248710411325d6be2aa465ae3b800b7d58d0633a9d77824709f198c69236461754c7bb57d92f5098553cf6d2be134809d321ccdf3f59b34a6a488a20f75530781dc21a55b5c7dc74d7b8e730d99fd446c8c175c70ee21c04ccf72e8ca6",
+    "credential_finalization": "949b33946757a038b9719616753a97bbbfa1a4f5214ec29431125bbff60704c2e713aaf81fc74e80aabbbf27beee2b0d46a64c1ad74c6c5d342036433c0955105c268ad57378ed2f1f882957c5a543f81f1157412f819660c1294435f556c2d1",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e26bff2fa46be86af0a90c518b541d2bda960cb00374f28f0efb038e581d5a29a2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e9412991725698811207c2a9c747787b196314561cf5dc93819146af9f45a1dc156241e7257ff68ba03071be1853c0bdb6cdc88d67f60e98fb7754bbc3999e95363a0482ca9403063369360a5190db94b00092beb238618bdd35f43debd4917422401cc85c268ad57378ed2f1f882957c5a543f81f1157412f819660c1294435f556c2d1",
+    "password_file": "0282e3d2c6bc5743c81e50367d3fcf5ab7d6a87d72f4556ef4a1e4efd4e94129912ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819bde7998e570a07ee865cc86e462cf7e191ecc759f304fb344db12c5e7a987478629e0c852d7263ff297e169e4cad2ad832dd5c6b3ef497c8338c76f206e500251",
+    "export_key": "62b868686900df0f1a68ad7d18f04cc14886db48b36593035202efed00e03a0beb49976be242bdcffb088c9d8d6c7f6eee9dc7661ebfcb7153a78332b3c41eea",
+    "session_key": "725698811207c2a9c747787b196314561cf5dc93819146af9f45a1dc156241e7"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_SIGMA_I_P256_SECP256K1: &str = r#"
+{
+    "client_s_pk": "021761440ac35a297290a591697aadb2f1237b623064235e0efa0046f169d2814c",
+    "client_s_sk": "ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b",
+    "client_e_pk": "03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57",
+    "client_e_sk": "7c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
+    "server_s_pk": "022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "server_s_sk": "65b7435899ada2632d7086307b1f127e56182bde709be796a5ba40c3f925b5f0",
+    "server_e_pk": "028d18f4c3e2422fb6ecf074aa9fa70c9eb67b2de53e653a3045a73a0b44c7ce78",
+    "server_e_sk": "3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493",
+    "dummy_client_pk": "03a57ec39337d292fed1fc054974fe1ffb8aea5eb49294dbd0bf8c68a135e068b2",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "registration_upload": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd202a890e83d727d32676b6012ce4e7cd1f5eba22223b83b555c07f32e8d27ba428f2b6d597df610ed674dcb39d2865c91e2730f466efa72f6bfffa1bb1ab9937063f08756746d4724355b3e128f1301dc35b829c7c96b1113dbafd29fe898557fc3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c14930347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fabf57d5a45601effb2e72bf3b318d2c43e0e014d9f66e6bbb5ed184156a6f0338dc0d16fb832e9e58a63dbff932e5993c8477ce2cc557e64d860134c97a0a644e729abe458c7569db8f1ee15f36fba9d1c29f826e8715ef00720f4881b9a828d5",
+    "credential_finalization": "a2722b3f74e80113d4fbb1ee1e290a745c2d0e9a070c88a8b6da3e01c3bed3b6c6945ab5823d38c9a7dce8b3db5e0cd01a931be3fd009930a0096569f8399b133934ee51fd3da0ef0a7c431a41db56f8dfaf041df828760996b874f630f95e21",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e26bff2fa46be86af0a90c518b541d2bda960cb00374f28f0efb038e581d5a29aba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca8bc7999fad30bc4f62a8e2d2fc27203763ef0e3d6645d80406e829c816f22de46afc39a58ee5e517b9e9224b83cb6c7dc8894f88a09b1a2f06b6ea53a5af6672415ca0b7594a43535e6b2339873bf6b419555cf771f346e5b09cd81ddb8d0a1063934ee51fd3da0ef0a7c431a41db56f8dfaf041df828760996b874f630f95e21",
+    "password_file": "027bc63c9e814f31e486b74317935d809becdee6e5fe5bf53335342880925a0ca84226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27faffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b198eaa6745315aaf6d44823cfc32e036564356436eea5d2f98dc68cedaf4f25d",
+    "export_key": "c7d3f226be713924ef78170e520fa5866cc915bb70c7e48c91e418e3674ce1ce",
+    "session_key": "bc7999fad30bc4f62a8e2d2fc27203763ef0e3d6645d80406e829c816f22de46"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_SIGMA_I_P256_SECP256K1: &str = r#"
+{
+    "client_s_pk": "021761440ac35a297290a591697aadb2f1237b623064235e0efa0046f169d2814c",
+    "client_s_sk": "ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b",
+    "client_e_pk": "03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57",
+    "client_e_sk": "7c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
+    "server_s_pk": "022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "server_s_sk": "65b7435899ada2632d7086307b1f127e56182bde709be796a5ba40c3f925b5f0",
+    "server_e_pk": "028d18f4c3e2422fb6ecf074aa9fa70c9eb67b2de53e653a3045a73a0b44c7ce78",
+    "server_e_sk": "3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493",
+    "dummy_client_pk": "03a57ec39337d292fed1fc054974fe1ffb8aea5eb49294dbd0bf8c68a135e068b2",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "registration_upload": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13612e12fd160f11a82e9239e6592090257b234e9497ed319f5240894839704b2ed8e9b26103490d89c628fc2ed5c1e31c21a47a0633f6f01f3f5bf5b7159b94f8febb3b1bace905029118806c0aa2928d69ffbccc3cc738205c70ec8cf1ec08aa3395c4fc58e871417baf808d0b340ce4e03427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c149303f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa847062365115c78f9efad7d95646cb8906c9fdddc94d4565480050ba52f44b334399b385b7f619d340127141ab87877bf9b40089e02c2ae88e95780f6f5987cf9e4ade4f87416893b724dd3ab9866e844ad337aec3a99654d4304b639d4fce3ff",
+    "credential_finalization": "a89629f473bde4ccf5fd237597226c64a198c421bb94f84209f8fa0f3420cd7c96e752aed44cf156611a4e592be8b135e42e6cc23785ab6b0817498fbaa68d088ea4d6b879408b211ba39f4654bcb0e9944167f76fdcc14d8551f32d1b210062",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e26bff2fa46be86af0a90c518b541d2bda960cb00374f28f0efb038e581d5a29aa4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff58e996a83072abc937d966ee159fc7620cb5963d7b4ae6ba882af3141551adad9d5421092e88837bd5f5ed2960c6c30d34269be4e2e4dbe6e5d91b9fcb8b36ea4bb2a01b3fd30895d2c24879180aa6069c86661aa3d415302e9324302b10cc9828ea4d6b879408b211ba39f4654bcb0e9944167f76fdcc14d8551f32d1b210062",
+    "password_file": "02f5f57a7a1beea624455b52285c24c7130284982c2155eeae08cba8c223073ff54b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b579e8d2c0f68ab63a6d0225c14327c59998aad1064c5ee9c47527ecd2a5c906e338e6902a175f63fbc164d1463e7ebdb",
+    "export_key": "5cbb95dbbf4fefb3fbb763000301a4aad7b85d79fc7396041c16d7c9408c1aae322e49f9e1d643bd82eb8b22e1c45511",
+    "session_key": "8e996a83072abc937d966ee159fc7620cb5963d7b4ae6ba882af3141551adad9"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_SIGMA_I_P256_SECP256K1: &str = r#"
+{
+    "client_s_pk": "021761440ac35a297290a591697aadb2f1237b623064235e0efa0046f169d2814c",
+    "client_s_sk": "ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b",
+    "client_e_pk": "03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57",
+    "client_e_sk": "7c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780",
+    "server_s_pk": "022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "server_s_sk": "65b7435899ada2632d7086307b1f127e56182bde709be796a5ba40c3f925b5f0",
+    "server_e_pk": "028d18f4c3e2422fb6ecf074aa9fa70c9eb67b2de53e653a3045a73a0b44c7ce78",
+    "server_e_sk": "3427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493",
+    "dummy_client_pk": "03a57ec39337d292fed1fc054974fe1ffb8aea5eb49294dbd0bf8c68a135e068b2",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d022e4591a17060ae9bae244be0a4fe32ede19b065d97c64355087fcb96ccb78bdf",
+    "registration_upload": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf2705faeb33148e8f55d76d29ec573a433ed70d4ee225a2c5a8fa14b1001fbcce2bfcb50479d46b7b2cbd0115073fbff7d28a67bbae123fd6d2f05ec38171321dc5ce8e3a54667aa984262be44b8bd0884db49dbf17bfb099f3c75a3ae758a75d6f674a3df89a99dc6644d106dd294377dd324a233ad9cc11f78ce9e6d56517bde43427ff48f71d8955166b25349d029315d5a82a8289171b766c551c8ceb2c1493038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc908458394a9ec3c003b4c8033b18f892ae863966fdf5bcb0c62cd2ac88e00de730aaf2e942f59f9eac7515cf52fdf05303e75a6bdaef40cf482095e70c3913571f40e6c73caede225bf2c27e2e2bb6a4a0b74e691ab19201109c0e3fc8ebb29d9329",
+    "credential_finalization": "dc638210062c0d7c4fc863007f6eda582f19138398cb83cb190082d48917b118e415938394fe17da7f77c29465e4b4954eb24ff355305cd47cdbf0a4dfe80fdb5ccacef4634fc3c0211df82d5c48ca8323389376fe33ad39099b2812bc42e28e",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff031073a910ac5a13e32554bfd41deec176f4fb27f51baad920cbb584b76d44106e26bff2fa46be86af0a90c518b541d2bda960cb00374f28f0efb038e581d5a29a2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00fe59c472c0ce29cfc4bf93071abac3e28cf9f98351a3aad06552c33da7a58a8970a591f1c78f7fcf64f838c529a2ede549c2ba50eb624009a47cfcf68cc6edfefae6afabf42d664eedc1a4064e8c225ea7a1c84e1fc6765a89061ccec04d360b45ccacef4634fc3c0211df82d5c48ca8323389376fe33ad39099b2812bc42e28e",
+    "password_file": "020d09a7a97118c72b546637111de6ebebb5efef5f105f100709f607b3388fb00f5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240ffb7f0c880bad59b07df5f5b021fecf3b01df793e3fe4261c020b2cbfe1a819b418428d884c5e33f1dc9bfd9663a634aba9014b3b25b82077551c8a02d2677f9ba340dfed4df5233e16fa1f7db5a735cdc8de74d462cbf67e44d6dd61706067e",
+    "export_key": "23721dde2ce158c7ec9d0d5dee5c01ead394e1a87fce5624f57ba392a1f29e22c20af8aa11e125946185f1ed1f2111ca7afa2b6b2ab2b64371d2cbcbcd0b50b5",
+    "session_key": "e59c472c0ce29cfc4bf93071abac3e28cf9f98351a3aad06552c33da7a58a897"
+}
+"#;
+#[cfg(feature = "ecdsa")]
 #[cfg(feature = "ristretto255")]
 #[cfg(feature = "ristretto255")]
 pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_RISTRETTO255: &str = r#"
@@ -2446,7 +2772,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_RISTRETTO255: &str = r#"
     "credential_finalization": "2ad3986fd927fc578c8b55ef9d31e8a75664aa57325501cef487ac6e86d88df8b6cd415087d37f83f97b48c65c4013b2391b928a8e476cfe11c9902a764a0188a2daa8ca9dda84a4a9ee8014b38f0cf7276b728a1d91ce99930a47b0873e31559b67a7a8db042f5ac7bdda3f341d4c04987d3c33c01a0461968254882758fc0b579960c3042fad7d6d87bdc787bb96d7296446cf8bf6cac848e7a7cb491a2220",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c205567eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212388ad757f43566ab99ac2affb0885e65655938cfdb066589150bad787e4c16c74a4ec8518efe43e654ca0ac831ad5678db781cb54d5cd05fd638adf18541e1ba0a67eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef56ae0cd991ce31ab447ee21d3b51941d17577f3b5c6c2e3a209d08ca2dd8a69cc12876e763648fdda55ad762c910a398dc8f6aff3bc96c7ce9f6eb2d47de9759d8f1e468e33eaae9410809edf44125e2187a4dfc7a6bf7b83a113f5a92d4a2a4d1820db784bb531ece7527e52b210d0b9b67a7a8db042f5ac7bdda3f341d4c04987d3c33c01a0461968254882758fc0b579960c3042fad7d6d87bdc787bb96d7296446cf8bf6cac848e7a7cb491a2220",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef56ae0cd991ce31ab447ee21d3b51941d17577f3b5c6c2e3a209d08ca2dd8a69cc12876e763648fdda55ad762c910a398dc8f6aff3bc96c7ce9f6eb2d47de975925cb25c1e8b158e8fafaf7b7e1c6ecba01184ae3526f9b8cdff25d1e0bbb1282e6afbcee9fdd367411d318b586734912843d2e16190a3a93f6d8d8474d3aa3fed8f1e468e33eaae9410809edf44125e2187a4dfc7a6bf7b83a113f5a92d4a2a4d1820db784bb531ece7527e52b210d0b9b67a7a8db042f5ac7bdda3f341d4c04987d3c33c01a0461968254882758fc0b579960c3042fad7d6d87bdc787bb96d7296446cf8bf6cac848e7a7cb491a2220",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "56ae0cd991ce31ab447ee21d3b51941d17577f3b5c6c2e3a209d08ca2dd8a69cc12876e763648fdda55ad762c910a398dc8f6aff3bc96c7ce9f6eb2d47de9759"
@@ -2487,7 +2813,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P384_RISTRETTO255: &str = r#"
     "credential_finalization": "40047a788c5e211ca2871f813dff5e98f74d95f7863f6da869f48d868c94f93fd89faab1da1bb88e25647f09547a06bcd59e837508453d71c82335682ccf0e0e070e7148a32366deedcd2c592f992b5ab14f169fa91f53bbea91921210bc69acb456b6117850efd444a0d821e116d9c7f9a1805431996fa46a41856787f3c1cc593534ca95b7c19e63a842b78ffb289f167df8b3ba2b524952a7d42dd0d72d0e",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff8ad757f43566ab99ac2affb0885e65655938cfdb066589150bad787e4c16c74a4ec8518efe43e654ca0ac831ad5678db781cb54d5cd05fd638adf18541e1ba0a67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daae48bcb71c55df1386841276717c091a3440b5a02855dc224812eb33ecf71e15cb20bdd30ecfc204264b1bd5f6e3f3f8f648023c62351883ac95982f33e54c14f52db137d2e77e3c764a654bf5777a7e9a126639e594b7a905127ff1f3dc1ffc8d614cb8c18756187b8794e004c21d31db456b6117850efd444a0d821e116d9c7f9a1805431996fa46a41856787f3c1cc593534ca95b7c19e63a842b78ffb289f167df8b3ba2b524952a7d42dd0d72d0e",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daae48bcb71c55df1386841276717c091a3440b5a02855dc224812eb33ecf71e15cb20bdd30ecfc204264b1bd5f6e3f3f8f648023c62351883ac95982f33e54c14fd2c3f94f73f447cafa82c1d5a110785c8341b427b36f9cbd69d09ff62e24f5e8b542293b590052479c745b0f561c4273df7fc03dc486b84816d4c1660fe7101b52db137d2e77e3c764a654bf5777a7e9a126639e594b7a905127ff1f3dc1ffc8d614cb8c18756187b8794e004c21d31db456b6117850efd444a0d821e116d9c7f9a1805431996fa46a41856787f3c1cc593534ca95b7c19e63a842b78ffb289f167df8b3ba2b524952a7d42dd0d72d0e",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "e48bcb71c55df1386841276717c091a3440b5a02855dc224812eb33ecf71e15cb20bdd30ecfc204264b1bd5f6e3f3f8f648023c62351883ac95982f33e54c14f"
@@ -2528,7 +2854,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P384_RISTRETTO255: &str = r#"
     "credential_finalization": "2aae8f6619bf1fe851b19babad7e9bd77d762e6015a0b2bbda1189853d511bedf0faca50784dd26382efa027b0fe3601aa08a6c4eec61869d789caa6f942eb96191d393159b171fce139043b6cd4aa2bbea8ba8cc5163850f33c7a7f7bce85528840842fc26ab5b0eeccaf57309db8439a9491dd3decf06ccdbeabe6e436105e58429c2f190192cf6aead2c5b0d969e3249c42b71274dd86f80ee6291801fdad",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b8367eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e1828ad757f43566ab99ac2affb0885e65655938cfdb066589150bad787e4c16c74a4ec8518efe43e654ca0ac831ad5678db781cb54d5cd05fd638adf18541e1ba0a67eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182",
-    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b8eea5de68f655df5af51e17b716e88cc903f436754c11bd506c4c9a10183be74a1e65db407a02b760cc4ff30123f7345467da0145bce51c7ea919665531b0577ae09ff15059e60af40eb876e72040e1c22a4c15841047dc523e6e3a8d4d8c1447bb8253a64333f95a9d7581b035cfd0838840842fc26ab5b0eeccaf57309db8439a9491dd3decf06ccdbeabe6e436105e58429c2f190192cf6aead2c5b0d969e3249c42b71274dd86f80ee6291801fdad",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b8eea5de68f655df5af51e17b716e88cc903f436754c11bd506c4c9a10183be74a1e65db407a02b760cc4ff30123f7345467da0145bce51c7ea919665531b0577a630a05060f2e7e2533796ccb9d1def2d1669fe2e8f5c7d171b078074c0e2ce188b3adb3476a885946ba74aa3f4ea78dfedfe50a8ffb1eddb4d3f5a1e088ffa43e09ff15059e60af40eb876e72040e1c22a4c15841047dc523e6e3a8d4d8c1447bb8253a64333f95a9d7581b035cfd0838840842fc26ab5b0eeccaf57309db8439a9491dd3decf06ccdbeabe6e436105e58429c2f190192cf6aead2c5b0d969e3249c42b71274dd86f80ee6291801fdad",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "eea5de68f655df5af51e17b716e88cc903f436754c11bd506c4c9a10183be74a1e65db407a02b760cc4ff30123f7345467da0145bce51c7ea919665531b0577a"
@@ -2569,7 +2895,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P384_RISTRETTO255: &str = r#"
     "credential_finalization": "62f7435c0f307fa15525006bab48aca2f26e01dcdc027c418e7505cacee986b28e87152df462e2ebd0286a1fbfcd112d9ee0deeda693c9b6d90d6ba8cd0b19d5eb972f63683c7706553112e2a4f6ebf53e35b0b3fc4a74dff474080576c95de8e27fccab16c934d4ab435612a7dd0d68324a95389872b12e8029bea5f2072e9d0c7279a8f8f51c3716428e8508e06275ebd7c7c726b990f47a1affd662aede2b",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b6967eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212388ad757f43566ab99ac2affb0885e65655938cfdb066589150bad787e4c16c74a4ec8518efe43e654ca0ac831ad5678db781cb54d5cd05fd638adf18541e1ba0a67eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5048f19a83f71bc20baf1e3a7f2a23eb39c6350f114fb8461b61d26354badeae1ecf2b1690144e09c950a956af451909715ac4736d7c9478bcc1affb81acb4e604d13d60b462e3903a64cff3acbfe8eee713bc9089586e8000e99b5ff60c13c3ef112fc850e3a64522ad35716ec80d1a5e27fccab16c934d4ab435612a7dd0d68324a95389872b12e8029bea5f2072e9d0c7279a8f8f51c3716428e8508e06275ebd7c7c726b990f47a1affd662aede2b",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5048f19a83f71bc20baf1e3a7f2a23eb39c6350f114fb8461b61d26354badeae1ecf2b1690144e09c950a956af451909715ac4736d7c9478bcc1affb81acb4e6029d690dd9adb3c4d14ac818d98204698b29cad4094dffafbf48510cb33cf8bd5c860f026baf807ad61e046d0fcd453aaabdb2fc85061a1700308adc095f012644d13d60b462e3903a64cff3acbfe8eee713bc9089586e8000e99b5ff60c13c3ef112fc850e3a64522ad35716ec80d1a5e27fccab16c934d4ab435612a7dd0d68324a95389872b12e8029bea5f2072e9d0c7279a8f8f51c3716428e8508e06275ebd7c7c726b990f47a1affd662aede2b",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "048f19a83f71bc20baf1e3a7f2a23eb39c6350f114fb8461b61d26354badeae1ecf2b1690144e09c950a956af451909715ac4736d7c9478bcc1affb81acb4e60"
@@ -2610,7 +2936,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_P256: &str = r#"
     "credential_finalization": "d570017e2966cb79c41cd24699ef1d049414bfe439b087826c8ff60b415a68c6cdedf63f0739365f8223e45b89b964642a6c006b1020c6e7e31a7cf72da094f70e5d1d4fc962a6ec1dd293c9f8ad738de2a18bea7b11326d6dc2017c46e9b944267ce9ae8eb2264a849c419bfbb3fc70dbaa0b9712559240fcb4f095beef7ef4",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c205567eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238035a6a3b860a091ad51e3ca7658adaa18df94ed4ea80f27b69fe79ea98e9b36be8da06332e63776a71b84635e252a0cae30abd50e1a49e072de96d89b61440489767eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aeffbd02c1ff6c4cd5e30bc16eb82d2dc0e2321e8052931bc2c3b7d740a1a2cf5bcad040d7253ac628d1ee38732c7ec006c0f23bfa6bbce7bda1c9d39d5b9c338f582adaa25e65ce6f3df7d579804812bdd267ce9ae8eb2264a849c419bfbb3fc70dbaa0b9712559240fcb4f095beef7ef4",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aeffbd02c1ff6c4cd5e30bc16eb82d2dc0e2321e8052931bc2c3b7d740a1a2cf5bc07eb2ab437e70298b0f162500aa7d507d9e80dc3f195faffb843b0b51eb6ef4bad040d7253ac628d1ee38732c7ec006c0f23bfa6bbce7bda1c9d39d5b9c338f582adaa25e65ce6f3df7d579804812bdd267ce9ae8eb2264a849c419bfbb3fc70dbaa0b9712559240fcb4f095beef7ef4",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "fbd02c1ff6c4cd5e30bc16eb82d2dc0e2321e8052931bc2c3b7d740a1a2cf5bc"
@@ -2650,7 +2976,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P384_P256: &str = r#"
     "credential_finalization": "672596d726142cb0414801c2aa9c9cc304d5b473f6f0f5dfc78aed7065455aae477b3ea4a0aca10d9672285a41be7c026abb6541465341e814d740d7a6a7cc5d4f507de4143b8a3aea80bd2694fbe3ec9a2ed5b85cb5a477eebbb7b38f75c7f1605428449d7adab9284ba5964e9e425f1ee414ff31095685d421f09940b133e9",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff035a6a3b860a091ad51e3ca7658adaa18df94ed4ea80f27b69fe79ea98e9b36be8da06332e63776a71b84635e252a0cae30abd50e1a49e072de96d89b61440489767eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa905bc0bc94681120bf487f4cb0bd35cc618c8653525860b8692f0b3c6133296374b5cf62cfb176073c86fa6a6d065a24f3db9019cc94aa6113749ebc657ce50980bb72d94716f66c67b0de22d492e57a605428449d7adab9284ba5964e9e425f1ee414ff31095685d421f09940b133e9",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa905bc0bc94681120bf487f4cb0bd35cc618c8653525860b8692f0b3c61332963880abd2a9bae34e96cdfe20b54d094422d79ca45c1f4f8eba64babee8ecde88674b5cf62cfb176073c86fa6a6d065a24f3db9019cc94aa6113749ebc657ce50980bb72d94716f66c67b0de22d492e57a605428449d7adab9284ba5964e9e425f1ee414ff31095685d421f09940b133e9",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "905bc0bc94681120bf487f4cb0bd35cc618c8653525860b8692f0b3c61332963"
@@ -2690,7 +3016,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P384_P256: &str = r#"
     "credential_finalization": "540675263c6c9e50674576026250177b0544a18435e6d0e2b6ca2f07c250e3f1fca6c2407b093881ff7fec2bce6eae303ef30f17f7e394846e323d0178c5afa4140a6e3946dc77a4e6d3249cdf4620c115d9af9b8d7843c8b8db187f2336abf1b0eb999700e111345b7e3464e5cb6f9d5580317552e3ba24bbb482d7c6663a9f",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b8367eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182035a6a3b860a091ad51e3ca7658adaa18df94ed4ea80f27b69fe79ea98e9b36be8da06332e63776a71b84635e252a0cae30abd50e1a49e072de96d89b61440489767eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182",
-    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b81814978d0d4da533969763399620129031c713adc145ddd8d7637d2823f33d2ec55e433315f3cde810e5c8cb6ae460c433111cec6b33ee7f96adc894fcb935cf1d1b692fe5d1bdfe3ef0cf4cd1813215b0eb999700e111345b7e3464e5cb6f9d5580317552e3ba24bbb482d7c6663a9f",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b81814978d0d4da533969763399620129031c713adc145ddd8d7637d2823f33d2ec35f93ae890201b69f0f51fced40b10848ee187168d78bdb4ebdbf803fdf90fec55e433315f3cde810e5c8cb6ae460c433111cec6b33ee7f96adc894fcb935cf1d1b692fe5d1bdfe3ef0cf4cd1813215b0eb999700e111345b7e3464e5cb6f9d5580317552e3ba24bbb482d7c6663a9f",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "1814978d0d4da533969763399620129031c713adc145ddd8d7637d2823f33d2e"
@@ -2730,7 +3056,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P384_P256: &str = r#"
     "credential_finalization": "74ea2b7fdf46b4cf9e823f3bc81e505302a41296076ea5fc96b093339719ca49ea79a7821dfefe6df5b555f8340d5899a7c572c0e8fa7f0f64fa3f3fcffc4462d190b397acaaf682c507625659c5529122e7ed99823410bf1106b3d4b40239a7afa825e40858be80ed9b257e7624fd7fccf6b8298288be3572830bb6f508381b",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b6967eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238035a6a3b860a091ad51e3ca7658adaa18df94ed4ea80f27b69fe79ea98e9b36be8da06332e63776a71b84635e252a0cae30abd50e1a49e072de96d89b61440489767eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f581e0e7456d4e00f8bf6ac9065de673b6e3d12803082ed2a0fde8f2d062200086faf5ebb5de5b06968a4f66e2bbe5a717f3e9539c9eca5ddb020cca2d84eeb752d7c2392e0a7fe0cddd780bffc00b8cadafa825e40858be80ed9b257e7624fd7fccf6b8298288be3572830bb6f508381b",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f581e0e7456d4e00f8bf6ac9065de673b6e3d12803082ed2a0fde8f2d06220008648fe8d55da13a4a02f11ae9be4d84a48b019539679f9add179570788ab52c8a9faf5ebb5de5b06968a4f66e2bbe5a717f3e9539c9eca5ddb020cca2d84eeb752d7c2392e0a7fe0cddd780bffc00b8cadafa825e40858be80ed9b257e7624fd7fccf6b8298288be3572830bb6f508381b",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "81e0e7456d4e00f8bf6ac9065de673b6e3d12803082ed2a0fde8f2d062200086"
@@ -2771,7 +3097,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_P384: &str = r#"
     "credential_finalization": "2beba7636ce6b5b7f4175affe87aa31efca315dd2dcc4101f34915996f9ced5a4173391de6161a834b4c51f8b40fa7449a7a9d02fa2e99520d2c6f55b7fd2400a9c7024f1380f48c170ad4cd439f94835ed1d58e5eb78b8400919d0c0ce55401fc1ea062ee479342ce4421bce343510187ad9e767b55ff6fa6294b0d6e75dea739104b0f4a62368358969af39027e29d",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c20550cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef7a99ca56b73a72f166cbb7364b9f23aa329fd38887788b25d88d1567988c34a260dc9e4643cd102a7cbf68c463d56ce5010a5270b2a53a474758ce0ba6174f7ec9793e6ed2ba9fbf35e48d0815cbe49afbb2245d85963296a477e177ddb8f942fc1ea062ee479342ce4421bce343510187ad9e767b55ff6fa6294b0d6e75dea739104b0f4a62368358969af39027e29d",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef7a99ca56b73a72f166cbb7364b9f23aa329fd38887788b25d88d1567988c34a260dc9e4643cd102a7cbf68c463d56ce5346714b28ed9fdc9d3e75076d2468610fed31c0f62f6af0d646590f0871fe5a3bed36192508d54e7bf379910d82d9dfa010a5270b2a53a474758ce0ba6174f7ec9793e6ed2ba9fbf35e48d0815cbe49afbb2245d85963296a477e177ddb8f942fc1ea062ee479342ce4421bce343510187ad9e767b55ff6fa6294b0d6e75dea739104b0f4a62368358969af39027e29d",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "7a99ca56b73a72f166cbb7364b9f23aa329fd38887788b25d88d1567988c34a260dc9e4643cd102a7cbf68c463d56ce5"
@@ -2811,7 +3137,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P384_P384: &str = r#"
     "credential_finalization": "87690475cdf4f38e76e840132f12385b0092b41e036d1db2ae455710ed44a4ce90df09ef7a8b6321d4dfe91a94d71289f4e7c7bc18d6ffab689333c3d52f9812357ae27cc107f15f62a3bf6ee18ad6c04ca528e6275c198cac5374fc7d58cd82683ec0e3be25fd381697001a28d3baf391e1986ab1c66241e0e0e66fa76f566360e10cf478c4824841b7e4c5d6e254fe",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe15ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f604203066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c015ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
-    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa428aa14a495d625eae57e6e7993996ea8e036ee92d89e509a1a8b4f8fd56441c0de6ae5a601c004ba3126a88799008d205ae39146d4d3b4c7c2b0996a80e2d49169bc0bf46e7ecb80d28948642961bae97cc6acec75b1248b4342b35710c900a683ec0e3be25fd381697001a28d3baf391e1986ab1c66241e0e0e66fa76f566360e10cf478c4824841b7e4c5d6e254fe",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa428aa14a495d625eae57e6e7993996ea8e036ee92d89e509a1a8b4f8fd56441c0de6ae5a601c004ba3126a88799008d28941bf0960f3d943329c452f07211550046cd858870bd2e709712865daac32d20c0a628a35885525d714c4d0bee687f805ae39146d4d3b4c7c2b0996a80e2d49169bc0bf46e7ecb80d28948642961bae97cc6acec75b1248b4342b35710c900a683ec0e3be25fd381697001a28d3baf391e1986ab1c66241e0e0e66fa76f566360e10cf478c4824841b7e4c5d6e254fe",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "428aa14a495d625eae57e6e7993996ea8e036ee92d89e509a1a8b4f8fd56441c0de6ae5a601c004ba3126a88799008d2"
@@ -2851,7 +3177,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P384_P384: &str = r#"
     "credential_finalization": "c0b62e3e965d43b9857ae7b6c4cf9b235568d12ac16f574ae6e36e1bcde5b1ed46541dca4eb8d2d455530ec223f2b961e9ad728481c24981c8a6abed186b1d35aa6f5ef88b37589eb3e89c128ffde58e31df8122e83844dac5e252f2974c4b73464aac9f5831bb10131fca3a8c416b7bc9b968271802fc171e60d239afc2217749eeb41eb2510eb6ff47006eaa61ee5f",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b835295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c05295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
-    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b83ffff68eafd91a569d2feacaae53918f33921a74036727ce72f7d5ca397a316d8776cc65d9d7121c1abcbe3b63d66e7374d280c7454dfa241cd6ddf9701b8104f57559e92305c643203ccde3ec48c2eaf4fabca392cfec0c942dcf5d179ae670464aac9f5831bb10131fca3a8c416b7bc9b968271802fc171e60d239afc2217749eeb41eb2510eb6ff47006eaa61ee5f",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b83ffff68eafd91a569d2feacaae53918f33921a74036727ce72f7d5ca397a316d8776cc65d9d7121c1abcbe3b63d66e73f7eb5c7cb7b2038f1211906f9106c442b60d45843cd7d92f21ee10107b91fd83bf863ffbd327b21a22117bcb62086c9474d280c7454dfa241cd6ddf9701b8104f57559e92305c643203ccde3ec48c2eaf4fabca392cfec0c942dcf5d179ae670464aac9f5831bb10131fca3a8c416b7bc9b968271802fc171e60d239afc2217749eeb41eb2510eb6ff47006eaa61ee5f",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "3ffff68eafd91a569d2feacaae53918f33921a74036727ce72f7d5ca397a316d8776cc65d9d7121c1abcbe3b63d66e73"
@@ -2891,7 +3217,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P384_P384: &str = r#"
     "credential_finalization": "7875615d0105b17d0fc013606231ef0e6e26a20ee00bdd3068b2b46c645c7f7d197c40a43c4990ccbe8aa5a4d14c664ebdbe741144a4e2e60a09e4a6fc19a5791707eddf21f1a38e31b7906f7b1a96814e5c7e505691b0ee25c9f2971d93f662db78b3108585a528c13220176c077ccb41bdb91968c19ef62e43285d27ced55451662b5cfbdfda85ac3f40fa242be7cf",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b690cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f03066d532e77b6252310cdd18b46f9553619f868029bbd1a4cd6cafb709e09a8089b3a77fdc740bcc1aac74e3eced9015a3295971b0126407b430b7d632b959262aabaf82644b88da7a16c53b7f6f5a479f53e10cf7c2652777fb639c7d0ccb2c00cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f57741c7dd56c476db4189331436b24906fc88a6004dfc4f533bd8cddcd6a7fc1794890f6f6c0290a11ce7e001a5f9d3123a195b8895f10f6626650a148798caae88534168a4e507b34da3841877561eac8031eea767029bd9dc635497f94a853adb78b3108585a528c13220176c077ccb41bdb91968c19ef62e43285d27ced55451662b5cfbdfda85ac3f40fa242be7cf",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f57741c7dd56c476db4189331436b24906fc88a6004dfc4f533bd8cddcd6a7fc1794890f6f6c0290a11ce7e001a5f9d31260dedafecae7e4677df367704f3d0aff24940b9cc09a4a2ab8e5d9f82003782f13f797c2e4610a1379961ff2ba9735313a195b8895f10f6626650a148798caae88534168a4e507b34da3841877561eac8031eea767029bd9dc635497f94a853adb78b3108585a528c13220176c077ccb41bdb91968c19ef62e43285d27ced55451662b5cfbdfda85ac3f40fa242be7cf",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "7741c7dd56c476db4189331436b24906fc88a6004dfc4f533bd8cddcd6a7fc1794890f6f6c0290a11ce7e001a5f9d312"
@@ -2932,7 +3258,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_P521: &str = r#"
     "credential_finalization": "2842372f975d53a0c7ce43b03b8298bd028aecffa3a485d25820dd87b638973730fcd977a0267e25f368f22a213c07578c5a6865764522b8ef1c178ec0a41c8dddf8ad3cf2f697ae571e227d4973b79b99fd712f2ea8a937da274e968072b563b31f9f900b354c2d82ecbd9881dae64fa6ecadae7b74f7dc506f8090eadf4bde0d7aceacd9d346be94ab244724788746efa86abfc29837f1f0e8337e0ed8a076",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055c3715be95c6635e1035848bb190fc12548336f38ccba2063e37ed5b94d5efdd4020103546b75065f79290ab61791c6fb722e9f465ceea799ed069c5e11d3aeefb3161b074e949a99dbe94f65bacd3568ac4dafabe68bbcb8d78bf46f3693526d9196a201ca91c4cb04dfa1af018e5d16b403035be1a1ddcf814132095664e535ea1fcb3ca07632b6390d6fe1c6a5ab257cf6049d546f2e1fc7c53e6cc5a970beec653f95abc3715be95c6635e1035848bb190fc12548336f38ccba2063e37ed5b94d5efdd4",
-    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef6f7d44a5c3eb9b3f2d441a9945ed9327f1efe52f49c8c0e9bb3a3d82d18520d58c1b037f9d79856ca06992f64563706cec6be730ca90da0ec2902470d8e0c08d40f95a6191cb7c2639e4c0a225ba597542362069eb07292f3e00caa34c8255c21306cbc9e4a0a2df21ef989f03fd338eb31f9f900b354c2d82ecbd9881dae64fa6ecadae7b74f7dc506f8090eadf4bde0d7aceacd9d346be94ab244724788746efa86abfc29837f1f0e8337e0ed8a076",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef6f7d44a5c3eb9b3f2d441a9945ed9327f1efe52f49c8c0e9bb3a3d82d18520d58c1b037f9d79856ca06992f64563706cec6be730ca90da0ec2902470d8e0c08db2869a1131596428271d9d43934c735b057a59067566c0ccc5983f80b0200fb7c3a34608c6f8d32e0acb094bcd20c6644b6c6b300f728d94ea81a008ad30e18540f95a6191cb7c2639e4c0a225ba597542362069eb07292f3e00caa34c8255c21306cbc9e4a0a2df21ef989f03fd338eb31f9f900b354c2d82ecbd9881dae64fa6ecadae7b74f7dc506f8090eadf4bde0d7aceacd9d346be94ab244724788746efa86abfc29837f1f0e8337e0ed8a076",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "6f7d44a5c3eb9b3f2d441a9945ed9327f1efe52f49c8c0e9bb3a3d82d18520d58c1b037f9d79856ca06992f64563706cec6be730ca90da0ec2902470d8e0c08d"
@@ -2972,7 +3298,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P384_P521: &str = r#"
     "credential_finalization": "3fcef9602a71c11d1fb7d19ef3a1267b54595180dc26e414e9342be236fe8524184ed9dd4fbf578254b5eaed2bdfa2121d8c2d1cd7b2c1d09b25a529c09b37b7f5ecd0fbeea5727af855334815b379dd5c7d0a25c9846fc15f3a5c7655268008fbc883824e147f0fea335a46ddd258e3913c76579a57db0f77ff8acbe14fe9e42462a5c0267416db1af98d57cbb7ca860d47738597ab00a3e6d998c3be8fd746",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe9eafc0924c0f66523516c74f60422655bffaefa6559cb38615edae864dda63cd030116739479ef1ce0a6ef435cefd250967277b90129ad7c0a8675bce41fe6368f77c225bc949e0469e8b080235d679a321109427f39009a57b417e3119bcdd452e34000867cc2750c77be325173181e15583341c3866dd5624c44f93bc4099144edc2227ae5e381c06c8eb534bb395f47418587739ce205ac174ac9cc018d5e8d4b717a549eafc0924c0f66523516c74f60422655bffaefa6559cb38615edae864dda63cd",
-    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa21b62357aecf234dfde01ede8c4237276ea1f89ae9066060176438e28e8d567df36152df8f32606b5890ace9ce3fc745caa6a6bc08305406bd9ab031a0a47c938de494999fd8365925651ba37c23819f5a5320cbdd0b52339e6c3daff7b3522362a1e5a66fbad91ca1945d56235de730fbc883824e147f0fea335a46ddd258e3913c76579a57db0f77ff8acbe14fe9e42462a5c0267416db1af98d57cbb7ca860d47738597ab00a3e6d998c3be8fd746",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa21b62357aecf234dfde01ede8c4237276ea1f89ae9066060176438e28e8d567df36152df8f32606b5890ace9ce3fc745caa6a6bc08305406bd9ab031a0a47c9373eb76642a2089dbaf5f30486d4121b480312524567f6facfb71530d6bebd260b3ceca0491af985b971e6700d2b6b1fa3fdc419bef6f9dc364a4c191a758fd1c8de494999fd8365925651ba37c23819f5a5320cbdd0b52339e6c3daff7b3522362a1e5a66fbad91ca1945d56235de730fbc883824e147f0fea335a46ddd258e3913c76579a57db0f77ff8acbe14fe9e42462a5c0267416db1af98d57cbb7ca860d47738597ab00a3e6d998c3be8fd746",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "21b62357aecf234dfde01ede8c4237276ea1f89ae9066060176438e28e8d567df36152df8f32606b5890ace9ce3fc745caa6a6bc08305406bd9ab031a0a47c93"
@@ -3012,7 +3338,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P384_P521: &str = r#"
     "credential_finalization": "eb3eb2de31edd08aeed5147a903c44b0b42315c0c768bca438b1a454a5b95b873966aa87b2da1e1dddbb5314fc55bf8159007e12993006bb342f4f03e444435b40e9cccfc1f6260115d10871070d27a265e22622b7f41f3851df4700d7ab8416ce3665ba4ad861f42a2d253505b4cdc942d2e0411037d4faa9f03659302eb6f4fb792bdc7027bc78bd43b5ee3bdfe1e5e3acb47bb26acd32787bbc1142d845e8",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b838efa21e976075260a2c2da2dd98ed3fbb2355b374a218ee6517bd59419472ac60301c44777698b6d1e2327c522ecb46b4416c6026082946e9f2e5ecb0e1e99ca1c84a42946adcf7a82cf1871325623206a56aed47adcd900df4a3fded0336ee5fcbd1a0083c56045e40f2bc33674bd746708d744da5e53aa525697034e8f4d128aa4bf4b10e39ac29bcea8de1793cc645de63c85b4b260c4514a48d0d2f4009bea91851f628efa21e976075260a2c2da2dd98ed3fbb2355b374a218ee6517bd59419472ac6",
-    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b8a297436a15636624f108b5e71d463580de92a132c6228f09e003bb04666761af92976dfa332107330b8bce08d01e9671e8686ad2813ed1f6f68e8b3eb0f2fa738d904cdd93eee14f877d2fa0f0afa7245ac58f2c215c3fcc737536f957aaaec7272d7e237dbb70a5fd4011a041798523ce3665ba4ad861f42a2d253505b4cdc942d2e0411037d4faa9f03659302eb6f4fb792bdc7027bc78bd43b5ee3bdfe1e5e3acb47bb26acd32787bbc1142d845e8",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b8a297436a15636624f108b5e71d463580de92a132c6228f09e003bb04666761af92976dfa332107330b8bce08d01e9671e8686ad2813ed1f6f68e8b3eb0f2fa73ebda9ce7a07d17ed99ea0719c86c658a9539976f80f6be8f59f7eb44257dab6356fc899895ae6991c4f8b95225ccb642faf1a53c030af70e6c36bdd0f4bb98f38d904cdd93eee14f877d2fa0f0afa7245ac58f2c215c3fcc737536f957aaaec7272d7e237dbb70a5fd4011a041798523ce3665ba4ad861f42a2d253505b4cdc942d2e0411037d4faa9f03659302eb6f4fb792bdc7027bc78bd43b5ee3bdfe1e5e3acb47bb26acd32787bbc1142d845e8",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "a297436a15636624f108b5e71d463580de92a132c6228f09e003bb04666761af92976dfa332107330b8bce08d01e9671e8686ad2813ed1f6f68e8b3eb0f2fa73"
@@ -3052,7 +3378,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_P384_P521: &str = r#"
     "credential_finalization": "48eaf66a1fdc4ca9b9be9afd1984508f6276601a8d228e2629637315eed721bc9ef98962e5fe6dfe94d1008638a3b800b18dcd70f4a99d13d563d7b731fe2f2ef78b3033861e0a53ce809172cf3419ac631bf8c74e4c95fb811035d17e06b0c9c5f4e9c5c3b4c881ddffe0d9ff69b42dbe5f8fd569cb4753bb770a23e8ef1e9cb058004ea8462eebcf5b2faa16e53ba20029e7368ea359b5cdcfa483a29a7ca3",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69c3715be95c6635e1035848bb190f01bc987569861b10c304ee2dc706c599bd22020103546b75065f79290ab61791c6fb722e9f465ceea799ed069c5e11d3aeefb3161b074e949a99dbe94f65bacd3568ac4dafabe68bbcb8d78bf46f3693526d9196a201ca91c4cb04dfa1af018e5d16b403035be1a1ddcf814132095664e535ea1fcb3ca07632b6390d6fe1c6a5ab257cf6049d546f2e1fc7c53e6cc5a970beec653f95abc3715be95c6635e1035848bb190f01bc987569861b10c304ee2dc706c599bd22",
-    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5c1fa9a4b6dd73353071dd263310db97fa17101759503c9a6ceca77a66f62e65aae55d1acc7516d25cfa90af0e904b5f23cf198fdbad18661bbc50ac392b493d2babcbd39cb1bf07086af013c200c6d3a36332d75499de52e77e1a011a3cea01828f7251dcb9a9d52f355b444ae149116c5f4e9c5c3b4c881ddffe0d9ff69b42dbe5f8fd569cb4753bb770a23e8ef1e9cb058004ea8462eebcf5b2faa16e53ba20029e7368ea359b5cdcfa483a29a7ca3",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5c1fa9a4b6dd73353071dd263310db97fa17101759503c9a6ceca77a66f62e65aae55d1acc7516d25cfa90af0e904b5f23cf198fdbad18661bbc50ac392b493d209e913f4a2ef7336302d28bb2e39c2027cd704a945c5bbb41bd5c438d743f29ba7f6d4c5d3fe2d0d88214829fa54dc9d14ca9a418a84f3b2fe1039f78b5f9ff0babcbd39cb1bf07086af013c200c6d3a36332d75499de52e77e1a011a3cea01828f7251dcb9a9d52f355b444ae149116c5f4e9c5c3b4c881ddffe0d9ff69b42dbe5f8fd569cb4753bb770a23e8ef1e9cb058004ea8462eebcf5b2faa16e53ba20029e7368ea359b5cdcfa483a29a7ca3",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "c1fa9a4b6dd73353071dd263310db97fa17101759503c9a6ceca77a66f62e65aae55d1acc7516d25cfa90af0e904b5f23cf198fdbad18661bbc50ac392b493d2"
@@ -3094,7 +3420,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_CURVE25519: &str = r#"
     "credential_finalization": "4a863c0f4860d72cc2c4f7030dfb16903aa8e59e1df635df9aa35a6b4ec15a59c812ca5ef0d7ec703e961257efb5a47c9bdeddd0c2e3a5ac4456189fc43222892f62cb3364c76531f3c5f41ee2cea7631cb87793a3b210cb8afdf06bf347a9a59f0ceb36cef29df274fc71a345b46c4fd6e83f7c5b06f8e300848e82cc894482ca0698dcd33131eb3e7e6d8e1a8eaf1be8f92138225a7e9898a25557605b1395",
     "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
     "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c205567eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238b4f52a5e20e6e154c41d9c31c2fd646026b7bef5e9e4c52cbf817f7414eb913d883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3554767eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefb3fad87e578e7548c4c0bd10910708f9b5f93cc6b11bdef98a85f886acc728634b73bdd39e01b76d6eb599f9a0025c96693c755e4b1469345a7acf98d29341fdf0d84c43d628caf6ffe590c35bab14a388847e7fb7ec44e46d9760d288892b7096215be4438ca2c307954fe8cc9716e09f0ceb36cef29df274fc71a345b46c4fd6e83f7c5b06f8e300848e82cc894482ca0698dcd33131eb3e7e6d8e1a8eaf1be8f92138225a7e9898a25557605b1395",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefb3fad87e578e7548c4c0bd10910708f9b5f93cc6b11bdef98a85f886acc728634b73bdd39e01b76d6eb599f9a0025c96693c755e4b1469345a7acf98d29341fd34627e6dbd463acbe31ae94ff4268635e82880c24373bcc9292cc13234ecd22815425b63442a83f9a666d9ca8c7c89eeb583cf94ce51835adbf735f340adc9cdf0d84c43d628caf6ffe590c35bab14a388847e7fb7ec44e46d9760d288892b7096215be4438ca2c307954fe8cc9716e09f0ceb36cef29df274fc71a345b46c4fd6e83f7c5b06f8e300848e82cc894482ca0698dcd33131eb3e7e6d8e1a8eaf1be8f92138225a7e9898a25557605b1395",
     "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
     "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
     "session_key": "b3fad87e578e7548c4c0bd10910708f9b5f93cc6b11bdef98a85f886acc728634b73bdd39e01b76d6eb599f9a0025c96693c755e4b1469345a7acf98d29341fd"
@@ -3135,7 +3461,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_P384_CURVE25519: &str = r#"
     "credential_finalization": "ea907ee092ac6ce9d9ccb87a332edf70a9ed3d7c469d02f12d163042011fee25be8d668bd75a0d2181882647d69b20a620eb3aa65c8b0173484636c650a9fb9af6dacf4e0357cc61d3d248c47ba16de6cb2100fdeed47a22a3ac86594fb6a2a2aa9fab155c8ac4dc5db9758429416486c26885410c618afbfceab03acb8719c0006ce8dcbcec47fc4a9e62847058de9510e33fa03bea95827d3a82f674817dbb",
     "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
     "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ffb4f52a5e20e6e154c41d9c31c2fd646026b7bef5e9e4c52cbf817f7414eb913d883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3554767eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daaaba3b761366c7e8e3947370c01220cf39f83f3cbc335ad37bf79ca87d9afde4404d94c7ba917e51df0b4153216b748b5888e435bb60bb3a8f9cf83b7c1d6f7bc862c30ce373203f6e12af766d7024293b73e5289b3b62c663137c10130aa9dead90b70165849e6b477ea6be4a74e4bc5aa9fab155c8ac4dc5db9758429416486c26885410c618afbfceab03acb8719c0006ce8dcbcec47fc4a9e62847058de9510e33fa03bea95827d3a82f674817dbb",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daaaba3b761366c7e8e3947370c01220cf39f83f3cbc335ad37bf79ca87d9afde4404d94c7ba917e51df0b4153216b748b5888e435bb60bb3a8f9cf83b7c1d6f7bcda5f775fcdb342cc12d0a744ea4afb23358204316a7fb1a60a06f6ff73da7d008dfe4ebcafd01fad66ff57e0661e60ec7dff747d3ef57071ec54f91921f3198b862c30ce373203f6e12af766d7024293b73e5289b3b62c663137c10130aa9dead90b70165849e6b477ea6be4a74e4bc5aa9fab155c8ac4dc5db9758429416486c26885410c618afbfceab03acb8719c0006ce8dcbcec47fc4a9e62847058de9510e33fa03bea95827d3a82f674817dbb",
     "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
     "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
     "session_key": "aba3b761366c7e8e3947370c01220cf39f83f3cbc335ad37bf79ca87d9afde4404d94c7ba917e51df0b4153216b748b5888e435bb60bb3a8f9cf83b7c1d6f7bc"
@@ -3176,7 +3502,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_P384_CURVE25519: &str = r#"
     "credential_finalization": "cd86f152d9042e61c2060e26e63c3ad4f3ee820f3a44b98cf049f693aa48c1dc241b6bd9a92781d00ffe81f1341eee9504f70318ca3562635777c2c6911f04bd18df691f2d7067c0c0541deafd766a5835c6d822419e93e2bc851a59388b7d78385b2dd5ba671ff6cc3e4e36338883bf4da54b851a832574fafd4a14890bfbcc24499dac61c997fff4ffa3823f767bdb700ad03bddd2d1b31081c067b2852e15",
     "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
     "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b8367eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182b4f52a5e20e6e154c41d9c31c2fd646026b7bef5e9e4c52cbf817f7414eb913d883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3554767eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182",
-    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b85fa2aec7f66e3983e92543d67381a9d4c2ee7a6937eee19f4fc8a9164e649e13c9c4b8a375c3dc14154c6d8bfe1fad2776caf72065dcf7dbfb304365d0854c750611da349b303b9ff53e77e6ec154e84bda6b0144792e8d78f4d172d1a10ca8c72f3b6cbaaa67d264843e4f1cd482cdf385b2dd5ba671ff6cc3e4e36338883bf4da54b851a832574fafd4a14890bfbcc24499dac61c997fff4ffa3823f767bdb700ad03bddd2d1b31081c067b2852e15",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b85fa2aec7f66e3983e92543d67381a9d4c2ee7a6937eee19f4fc8a9164e649e13c9c4b8a375c3dc14154c6d8bfe1fad2776caf72065dcf7dbfb304365d0854c757a42e9d04ce1750b6f9ed47cb94a40650f92c5c6b4f00eec657b407a16d007b90efb2f3ecc557121f18a8defa7517186aa2e5993b4b0a484df79bd24ec83786d0611da349b303b9ff53e77e6ec154e84bda6b0144792e8d78f4d172d1a10ca8c72f3b6cbaaa67d264843e4f1cd482cdf385b2dd5ba671ff6cc3e4e36338883bf4da54b851a832574fafd4a14890bfbcc24499dac61c997fff4ffa3823f767bdb700ad03bddd2d1b31081c067b2852e15",
     "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
     "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
     "session_key": "5fa2aec7f66e3983e92543d67381a9d4c2ee7a6937eee19f4fc8a9164e649e13c9c4b8a375c3dc14154c6d8bfe1fad2776caf72065dcf7dbfb304365d0854c75"
@@ -3217,49 +3543,1192 @@ pub static TEST_VECTOR_P521_SIGMA_I_P384_CURVE25519: &str = r#"
     "credential_finalization": "a6c56d7f775ac3228d6c6386c5a1e681af7d031bfc4449e053bdafda906f1b8bad353f3c5d0e8f9c244f674b5c49a4aaf03d13ea3eefb223beeca55ee782bd20b63592febe26763bc0723de28bc09899768379d4afd46964b272ad42ce06572265ac3d484fa1507f836bc9aa6f6ea772733950d3f6d16998d1fa0543765b49f3386b58c29c2323c9e900ca06fb4314e1d730751e74ef09879fcad079f4477f4a",
     "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
     "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b6967eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238b4f52a5e20e6e154c41d9c31c2fd646026b7bef5e9e4c52cbf817f7414eb913d883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3554767eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
-    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5623d504d7e78312f81d0cfcd9a297c091991412036f982f8957e5afd6b423b21e7962e7444b559fd184123ce1d462f25a5c967f04752d0f46f585d64c5718a0e0ae5efd26b322f0eeda85e42c3803346685f6558709acd2cdd5d90d73465f746225c4831aa120af8d68c3f61fe42214365ac3d484fa1507f836bc9aa6f6ea772733950d3f6d16998d1fa0543765b49f3386b58c29c2323c9e900ca06fb4314e1d730751e74ef09879fcad079f4477f4a",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5623d504d7e78312f81d0cfcd9a297c091991412036f982f8957e5afd6b423b21e7962e7444b559fd184123ce1d462f25a5c967f04752d0f46f585d64c5718a0e8c2cfc904791f3ce62777ee53cc3f746fa97f08a40a9cda4450ff71c51d882d580c31bb68a685fa7e16d5e9145d56e6a9eebac7228cfb8e8bd59370e5dbdb00f0ae5efd26b322f0eeda85e42c3803346685f6558709acd2cdd5d90d73465f746225c4831aa120af8d68c3f61fe42214365ac3d484fa1507f836bc9aa6f6ea772733950d3f6d16998d1fa0543765b49f3386b58c29c2323c9e900ca06fb4314e1d730751e74ef09879fcad079f4477f4a",
     "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
     "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
     "session_key": "623d504d7e78312f81d0cfcd9a297c091991412036f982f8957e5afd6b423b21e7962e7444b559fd184123ce1d462f25a5c967f04752d0f46f585d64c5718a0e"
 }
 "#;
-#[cfg(feature = "ed25519")]
-#[cfg(feature = "ristretto255")]
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
 #[cfg(feature = "ristretto255")]
-pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_RISTRETTO255: &str = r#"
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_P384_SECP256K1: &str = r#"
 {
-    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
-    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
-    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
-    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
-    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
-    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
-    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
-    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
-    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "client_s_pk": "031c1bad5951cf0487c6ca5c107e0df205e4eefed0a057eb4f14fed0cff3a9f726023dabc398d4661a8973a2f9722affba",
+    "client_s_sk": "293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278b5c3274987a1242ba496ee3dc989f8a1",
+    "client_e_pk": "03fd3751c53cf2abea2cd75d7176ebfb27a5d5e59534c82eff5aa28a93b27e8d888e69e9c55f017651a9d1f71d25ec0308",
+    "client_e_sk": "883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3558767eda7090cfa1824ae66e65a0425fedb",
+    "server_s_pk": "020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "server_s_sk": "434fd03b8290b8ad9086304c96e8ff2f4b8756948ac9c8afe9e38891f3e104b96ad8aba1e5a9a5ce0a1b1f78eeb4326d",
+    "server_e_pk": "023707c59cfaeebe33c7b02841078094fc2e9728ccdd6ce9b745b6090c14f1a5032836ef929e8e841e18ae3715e28a1c16",
+    "server_e_sk": "d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08def51d8da9275dfa6163c352edb126751f",
+    "dummy_client_pk": "021f69e910163a2f98b252f02ad17058534eeccfeb5563e636f61e80036edcf039aa6edf64a100ba5189a0664f88e51aa6",
     "credential_identifier": "637265644964656e746966696572",
     "id_u": "696455",
     "id_s": "696453",
     "password": "70617373776f7264",
-    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
-    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
-    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
-    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
-    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
-    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "blinding_factor": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf399008",
+    "oprf_seed": "98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa97",
+    "dummy_masking_key": "45a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea4a4f65a42b4181ffb2b1f907b68b1fd",
+    "masking_nonce": "fa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
+    "envelope_nonce": "5295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
+    "client_nonce": "0cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
+    "server_nonce": "27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "server_sig_rng": "439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b2655bffaefa6559cb38615edae864dda",
+    "client_sig_rng": "63cd88f119e401930b6ab5b35396841bd3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e949",
     "context": "636f6e74657874",
-    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
-    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
-    "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
-    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705",
-    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325db8fe482e7a2bc1641d38a0cc664c29615c71eb9bb4f6eb630a922eb8b7ee898a3a35d26782ef9ed74bd5688fd181eec848eee6d39ff43f5e4c70685d6cec40bdb6a24a883646a105be5f2169e218ea54ef4741b555a04c677afeda5a8f75d0944ece9cf80e58090ea95fe062299ff1581ed1bc9069e85bcbe33ccfa6492dc4f",
-    "credential_finalization": "8992b1bb9a3cd1ea1af23698a22a3739ef3ed8ea6997d17b4687ff919837765ea34d83ba47f0215da4155bb3646735b54a9219742187a4acce17e7a6b7496b08153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
+    "registration_request": "5e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
+    "registration_response": "60760696588b1277fc8c14d54297d2c4c498f3235634039d625bc33a0c79ed20020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "registration_upload": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
+    "credential_request": "5e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c205567eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212380251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317",
+    "credential_response": "60760696588b1277fc8c14d54297d2c4c498f3235634039d625bc33a0c79ed20fa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc87094fc7368b167f3bb104cc9567641e6e6cfe6ca5c919eb1659836e810bc1b8440de1083435bd9acce1c1ff57ca7a866f18ecd2c51c88de2ddce3e8508f82da88dda547cb2b594e1745422c472439ba9f1db0f89b0e173eaee03e2a24f08cc21fe71deb51f6b4aa778363dd83d5156212ea816f12d6c0282907106df63aeba5bae1745c2055bf2a86c17cd74c8aa466253bbad9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08de039caeb985afac377018ea14f08ff0f96d3df9f9d24c5bbd946febd1bd224a2eef9005b931d918707bbdb9f9e30fa0239ca5618228ad6643b81da2ff6dd94f68c2038153b7ae0124a1818292b5cb1d3759ec11fc78fc2fe4b1ef2a93544079a107c354ca005b9d67470a63478d91fcded9adde374faf913b892fdd5e73b13788d977e9a04835f875196074d2ead0d5df01815944c4af1e8e90ddc9cd8fbbc057ae",
+    "credential_finalization": "90f859bf04b1f9fe701042b32954667ace855132b6aff6766c6bd28a71c9b324d0d84d49441cc387bdc201b0a4ce92ccb3fa8401a4effd6860cab72b5354454e6e1adb2a98a8070b187ca29029488fca59ebcc6aae17f5437742f9856664d1cd35c8fa1424f7684c63b3a0ec4a3f82f40c2d6c9684adcd19e471bf3a01cf3d0e",
+    "client_registration_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c2055",
+    "client_login_state": "c12548336f38ccba2063e37ed5b94d5efdd41cce2022f18a6752585fdf3990085e8c2dfd57dec02ec21897991e9423d742565fb3941554c7545d5b7ef37c205567eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212380251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317273be1f71b348753df9869903e9e93fa912e7cd8127110c83801b6de9b58e1e267eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
+    "server_login_state": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aef016bf345b4c778e46bc9054a11f90a382371898856bccb9322efba7b84b7655b6aeba65ca95a0fe808618a49cf5f75e78853d053154bb1e14ce9989b6bc08a4c4e65a63ed50fad56321e98f5bc75060add33aeb0cd180e32df679d7709fbcd0102a57d7675ba912ae710853f7d95440935c8fa1424f7684c63b3a0ec4a3f82f40c2d6c9684adcd19e471bf3a01cf3d0e",
+    "password_file": "03120a4b85adfaa33526ef5a5222d64d6db64ac4119ce5da429f72c99fdc5f4cdc623eebe6e39ee40433958cdd7feb3aefffcdd635a6eaacc46dacc4fe9f2a6962d1ced006c22d0e3fa4aec0d45ecaaed2932d286b6d5d8f65afb3e2eb6e94b2d633b0b5057be38b54e5b3fd3141d68705293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278778ff98f44b2548ee7cf64edd7efa06dddee610607ec3f8f9c6c31f8eac46d383babcbcfaf1803fd3a5c0050540fb39741aac5269d733332e9fb4e855216486b",
+    "export_key": "e6174df58df692ee055a8cff5f5440859b68c3e8ed328d1b8c8546d90333384116857857fb798e09b1c51c3b61e18b5494ba2cede15f4bd61438cc2804a2149b",
+    "session_key": "016bf345b4c778e46bc9054a11f90a382371898856bccb9322efba7b84b7655b"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_SIGMA_I_P384_SECP256K1: &str = r#"
+{
+    "client_s_pk": "031c1bad5951cf0487c6ca5c107e0df205e4eefed0a057eb4f14fed0cff3a9f726023dabc398d4661a8973a2f9722affba",
+    "client_s_sk": "293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278b5c3274987a1242ba496ee3dc989f8a1",
+    "client_e_pk": "03fd3751c53cf2abea2cd75d7176ebfb27a5d5e59534c82eff5aa28a93b27e8d888e69e9c55f017651a9d1f71d25ec0308",
+    "client_e_sk": "883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3558767eda7090cfa1824ae66e65a0425fedb",
+    "server_s_pk": "020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "server_s_sk": "434fd03b8290b8ad9086304c96e8ff2f4b8756948ac9c8afe9e38891f3e104b96ad8aba1e5a9a5ce0a1b1f78eeb4326d",
+    "server_e_pk": "023707c59cfaeebe33c7b02841078094fc2e9728ccdd6ce9b745b6090c14f1a5032836ef929e8e841e18ae3715e28a1c16",
+    "server_e_sk": "d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08def51d8da9275dfa6163c352edb126751f",
+    "dummy_client_pk": "021f69e910163a2f98b252f02ad17058534eeccfeb5563e636f61e80036edcf039aa6edf64a100ba5189a0664f88e51aa6",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b",
+    "oprf_seed": "98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd214410889440978446",
+    "dummy_masking_key": "9f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa97",
+    "masking_nonce": "45a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea4a4f65a42b4181ffb2b1f907b68b1fd",
+    "envelope_nonce": "fa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc8709",
+    "client_nonce": "15ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
+    "server_nonce": "5295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
+    "server_sig_rng": "0cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "client_sig_rng": "d198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b",
+    "context": "636f6e74657874",
+    "registration_request": "02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
+    "registration_response": "024c08aa42b3ce01b48b630ef8d63325e6bb4afe8e9c4770055e474a5c9d88af2c020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "registration_upload": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
+    "credential_request": "02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff0251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317",
+    "credential_response": "024c08aa42b3ce01b48b630ef8d63325e6bb4afe8e9c4770055e474a5c9d88af2c45a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cfdc4ce6843f50d7eded7b81e1b4aaa7f165096bb12287317764b7c09729a7290dc99f49a7d3d6440e3b77f805d38d01257e5d75bb466b520819cdff3d70b3739e083457e92f8d92d4f5c9eccdc6f082ce883eac1d5a93f7958afcd5babda773a73aff75aa0065dd4d4fb3ea3d07cb21852d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08de026333c113564df3c580bcc8a782b49528afe0cd5f2722a729bafd821aa19c0db38aa67fe2e8e0ac72799a90c86c77fbf722b9b3dbea8c0afe6ee8007e508eb79b98f3b3d202998463b284747fe2147ec45ff9c9a51ac24353ffb81cb62188273c12fe2f8b476e9bbb2b1cd02d52400008879fd6f559ff51af12767f67d62fc8df9684ff500370ff1d0b7ac96be6bdbf738c3d7f6b3edbc01e71524e0bae8eecb8",
+    "credential_finalization": "8a160806d95637cface07d9e467a37b957cef48c7defbe395cd9fe19640b8d74276dd4f3a192b6411a010d0797d059bff09317c59e50c3d32cf1dbdcfddd5ec59088ca8d33df2f29bc99d9be9c7c3b30f8902e6f911fe9ab278b723dc4272136a7a886155b6f360347035eadbe32c587df5599b734e566aabf09f52abe745714",
+    "client_registration_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe",
+    "client_login_state": "2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b02bd1c78f544d3fa1c214b5dc343c6ca75bff3b87b431845ff7cb983aaee5abafe67eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff0251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317273be1f71b348753df9869903e9e93fa912e7cd8127110c83801b6de9b58e1e267eda7090cfa1824ae66e65a0425fedb15ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa5ff735d264c79dd00b1aef42dce45fd0e52cae5a9b8286b98486c74114de17ff27cb257add6717aab6f215174db9eb0be466d9b0babb61cf91c22eb52acb70ba7c88475bbfd1c7134bc2bacab80668fc9683ddb072069c5da5ec692723918800cfeda5dc0fd7a9053fe1336ddd953f77a7a886155b6f360347035eadbe32c587df5599b734e566aabf09f52abe745714",
+    "password_file": "037ffbe4bed3ce6b96a788e00103b74713998d0ddbc7d31ab0c14fe35106b9e9cc8fc69fa13439b5e610b37b16caae2daa27ea69e4932c91110c69f612a04d709e964c543c9bb55888a1ac74e7cb719f13293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278a5defa57a741f1877a176fc506f74774766c99d74e595ef7a33141bb11776d16",
+    "export_key": "2db45102abce37abf0e11fd7710831f8c08372217763e9c41567af8844915e4a",
+    "session_key": "5ff735d264c79dd00b1aef42dce45fd0e52cae5a9b8286b98486c74114de17ff"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_SIGMA_I_P384_SECP256K1: &str = r#"
+{
+    "client_s_pk": "031c1bad5951cf0487c6ca5c107e0df205e4eefed0a057eb4f14fed0cff3a9f726023dabc398d4661a8973a2f9722affba",
+    "client_s_sk": "293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278b5c3274987a1242ba496ee3dc989f8a1",
+    "client_e_pk": "03fd3751c53cf2abea2cd75d7176ebfb27a5d5e59534c82eff5aa28a93b27e8d888e69e9c55f017651a9d1f71d25ec0308",
+    "client_e_sk": "883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3558767eda7090cfa1824ae66e65a0425fedb",
+    "server_s_pk": "020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "server_s_sk": "434fd03b8290b8ad9086304c96e8ff2f4b8756948ac9c8afe9e38891f3e104b96ad8aba1e5a9a5ce0a1b1f78eeb4326d",
+    "server_e_pk": "023707c59cfaeebe33c7b02841078094fc2e9728ccdd6ce9b745b6090c14f1a5032836ef929e8e841e18ae3715e28a1c16",
+    "server_e_sk": "d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08def51d8da9275dfa6163c352edb126751f",
+    "dummy_client_pk": "021f69e910163a2f98b252f02ad17058534eeccfeb5563e636f61e80036edcf039aa6edf64a100ba5189a0664f88e51aa6",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035",
+    "oprf_seed": "98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "dummy_masking_key": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9c",
+    "masking_nonce": "f5762fae1859a5f2873131ca4e1fb52ea4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc8709",
+    "envelope_nonce": "15ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
+    "client_nonce": "5295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
+    "server_nonce": "0cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
+    "server_sig_rng": "27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180439288da0e67df1ac388da3984197d4d",
+    "client_sig_rng": "98e2b63c2e9e05f5a976c845cafe467b2655bffaefa6559cb38615edae864dda63cd88f119e401930b6ab5b35396841b",
+    "context": "636f6e74657874",
+    "registration_request": "023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
+    "registration_response": "02a5d8b315183f5a89f3cf295199d9308466a539b6e163e436632cdbf4198451e506e9552ef71e76e5500e8e5fdd6f38a7020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "registration_upload": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
+    "credential_request": "023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b8367eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e1820251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317",
+    "credential_response": "02a5d8b315183f5a89f3cf295199d9308466a539b6e163e436632cdbf4198451e506e9552ef71e76e5500e8e5fdd6f38a7f5762fae1859a5f2873131ca4e1fb52ea4a4f65a42b4181ffb2b1f907b68b1fd98965e6497b321a9e094d01856285bfc170b00869efad897e5a75140495d75af1aebfa9026f61120304fc7171711b1f5c911613c15fc296dd3387df410300c567e095357d637d77f6e30a411d0d7c7628ce9954a08dae501957a7dc04fee29ea9f5966adb7336463c0a7e17bf667f3ac44ff5db1780c7803a29248dc99be3fcc08d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08de03240b222a62a2d65efa0ccd2082232ff81e706c1b7f72bc2bfefa7b50da96a06ad072e54137c9d5ece5599bd4ee6802a7829d701c33f40328fab3795d4d753da8bf3ba7c634600b57bbaebb2d4def51726546bcabd8c1ab35a9e173d6192747291640350995c2eda0578095e029301603f8ffe8d8532877fffd9f38c46a6156f3b4e63a6c812e2e4c065591943ae1878724dac2f3797adf967aaaf6376becd15c",
+    "credential_finalization": "92f40998a363368a3f6c56d1455c44932d53423309d12c90c7ad5fbb15732b2c336ce8b7d0d9e531019b2aa59fd073e6e6afb1f6bbab6d5191bfc95d47748010066ecef992318b42a4fe01f1986f7545952a1068b8fc557ab7132cb49cfcd22a56c5e59149875b8d9657811bea8c4b8b51af37f6795b7ec853621a97f494ea1d",
+    "client_registration_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b83",
+    "client_login_state": "d3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e9496a1f10ae17d863e90b5f0e82d4a37035023ee7f5d29b4e9fc4049fb82e4af830529755ca987a10c202be2d5451a47d487bca7766ef5ab7a65eecf37bc8c8784b8367eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e1820251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317273be1f71b348753df9869903e9e93fa912e7cd8127110c83801b6de9b58e1e267eda7090cfa1824ae66e65a0425fedb5295a857618fdd85d35f62a1b482e182",
+    "server_login_state": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b8bfdb8bec5c20fe5e429467a205c8b24c612d9dbb26d65dea5b150c4e975a63837882088f6c5c84d3218e9a6f605911a109fe098a61c6a97fea9bc09d0962bb272e726cb89facf3d24dbc9452b82941fc2611249955fb87cd4710ae3ce86dec3e7081f89980e09bc6dffb426b8e655d1f56c5e59149875b8d9657811bea8c4b8b51af37f6795b7ec853621a97f494ea1d",
+    "password_file": "022557a33e7a3d817565691e63681b2c1fd3318671fb425d969c0db56c0faea373c7230f4290ca033fc2a951e725dc82b88873454f6c4dce89561f6d8751b956b7659a33ac3461310e2e906dc06c4ca93aeff153505fdd3fd3c67acd48de26016e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278f211ea834930e202cfb2803e657a88179f1146718593d12775eb37d784d942233a4f465b5edc060c7b8a487f7ac89d3e",
+    "export_key": "7b77421427b852abfe67401eeec34c64e14f2db61e3265d6996d6815f4e226a13ac9d00c3436d59ca56adf84fb94d017",
+    "session_key": "bfdb8bec5c20fe5e429467a205c8b24c612d9dbb26d65dea5b150c4e975a6383"
+}
+"#;
+#[cfg(feature = "ecdsa")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_SIGMA_I_P384_SECP256K1: &str = r#"
+{
+    "client_s_pk": "031c1bad5951cf0487c6ca5c107e0df205e4eefed0a057eb4f14fed0cff3a9f726023dabc398d4661a8973a2f9722affba",
+    "client_s_sk": "293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd278b5c3274987a1242ba496ee3dc989f8a1",
+    "client_e_pk": "03fd3751c53cf2abea2cd75d7176ebfb27a5d5e59534c82eff5aa28a93b27e8d888e69e9c55f017651a9d1f71d25ec0308",
+    "client_e_sk": "883ac3fe2bb01246f4c0b2b29f4c21f9518ca1bcfc7a11264107e941c0e3558767eda7090cfa1824ae66e65a0425fedb",
+    "server_s_pk": "020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "server_s_sk": "434fd03b8290b8ad9086304c96e8ff2f4b8756948ac9c8afe9e38891f3e104b96ad8aba1e5a9a5ce0a1b1f78eeb4326d",
+    "server_e_pk": "023707c59cfaeebe33c7b02841078094fc2e9728ccdd6ce9b745b6090c14f1a5032836ef929e8e841e18ae3715e28a1c16",
+    "server_e_sk": "d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08def51d8da9275dfa6163c352edb126751f",
+    "dummy_client_pk": "021f69e910163a2f98b252f02ad17058534eeccfeb5563e636f61e80036edcf039aa6edf64a100ba5189a0664f88e51aa6",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e7",
+    "oprf_seed": "98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa97",
+    "dummy_masking_key": "45a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea4a4f65a42b4181ffb2b1f907b68b1fd",
+    "masking_nonce": "fa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b9eafc0924c0f66523516c74f6042",
+    "envelope_nonce": "5295a857618fdd85d35f62a1b482e182b3128efa21e976075260a2c2da2dd98e",
+    "client_nonce": "0cf8505dcb05f4e44c87ca6858f2123816a8c3715be95c6635e1035848bb190f",
+    "server_nonce": "27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "server_sig_rng": "439288da0e67df1ac388da3984197d4d98e2b63c2e9e05f5a976c845cafe467b2655bffaefa6559cb38615edae864dda",
+    "client_sig_rng": "63cd88f119e401930b6ab5b35396841bd3fbb2355b374a218ee6517bd59419472ac6070b06ebb5aca042ba996884e949",
+    "context": "636f6e74657874",
+    "registration_request": "0200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
+    "registration_response": "02000dd99c271c6146e9f7a71795fda9af0a74c6e0e306971d1cb61137eef8f0cf452eb715a1081cf089c4e007240334bc94ed737b865bb7461126676797f4b128d80f020cd5cd54c93602a83258504bdab51d3e7b344245e7e7f54c59463960262db29d8c4a9330ef9c0f9ffd935af15e4aee45",
+    "registration_upload": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
+    "credential_request": "0200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b6967eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212380251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317",
+    "credential_response": "02000dd99c271c6146e9f7a71795fda9af0a74c6e0e306971d1cb61137eef8f0cf452eb715a1081cf089c4e007240334bc94ed737b865bb7461126676797f4b128d80ffa3effbdef9513c06e6f215a5cd525d12488cae978cfafe2d8f8804d73dc87094e94abc709d942b05c4aab0a2dccfb564d060d5f77748ae8cd82f61cbf09c52522a1412aea8dba0531766e7513b297af97a374207ab8440c7d0e7237e85f5e3ad66a5740f38532321fd495faaa6419390ffc3e51b624df1dd0e32fb9d6a61117e2a0722a093725ce4eb0bee6daa509a0018bc190ed4f3b5b6d2905c0b0397224772d654252dded39845300430c9a6a1c09d9ca86ea208a70a74d092f85b79e8568991cd8cd72287003dd3799a4f7ab08de039caeb985afac377018ea14f08ff0f96d3df9f9d24c5bbd946febd1bd224a2eefd66b8c1cb00c29c5b2f4c4c1018abecbf74bc8c7517a92bd57ea0c4a08689d2c3f6b42bf33afc5605ff5287213f84b07838635d4e314c64048c547249d295aabf252bef677639604ff32008e730a9f20d4e82276f1c7c3027a3ded19aca47dbf6327b95bbfe50eb658450fba3032c43520a83103ff5c15c9848d4f7a9e3ecaa0",
+    "credential_finalization": "6b45929200d1e0758cf36f008ed1b6305177afbae58b75dbe33fdfb5202657e43d66930e745207814794c3266f849c8297369c60dc03a0b3bc3c3ffbe1377046b5b04c363c2a4be8011ec0efe4928a723c44002937722a3693b0711665b193175bd9531952c3b14d8d693c14b1bdf5fa542217027f45834df3d1802d8adfd8f4",
+    "client_registration_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b69",
+    "client_login_state": "01bc987569861b10c304ee2dc706c599bd22a432dfee79cc3ecf083cc3985a9902dcbcf0f713f31e63cac69b874050e173a698ce67054fcd12b0cfe20796553919e70200e6f09e77c9d0a2040c60a09c160fa17ebbbc316b46c42334dd3f7d674761269b58f3b3c396bf79c54de684b83c5d00208605900ccfb8b55e66b78fb9ee0aa96b6967eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f212380251b79a8994178e100b4c63c4bd3ac66ad9ca19dbbde136da3b557d31ba2ab317273be1f71b348753df9869903e9e93fa912e7cd8127110c83801b6de9b58e1e267eda7090cfa1824ae66e65a0425fedb0cf8505dcb05f4e44c87ca6858f21238",
+    "server_login_state": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f5dca21176a1b7527deedff49c1c8ac7496019fb7f1490674e4d3b31655e0bffda7dddc48daaaf5ba83340f8cdbf10884620d6098675ad73e130d9926fea95d63b9792bba801fdcb4b3d413a01eda96a1ad43af9f4ca5a3d7eebabb2ceeeec70b9c7b4e1e34d58f00044c74e7e93dddeab5bd9531952c3b14d8d693c14b1bdf5fa542217027f45834df3d1802d8adfd8f4",
+    "password_file": "0278b6ff326ae2cf4cf2ce80e50e74089804c77f1cc5adfdaee5271958fefdae1dc2270f16e3315060fe34ebebf40008f54257e57528269b1a8bfae32269dde22124779efd4fd4870aa3ef110a432d2a0dadddb5bfff4bd990209caf8b9d66afbe7f4d9323e7d2d8f80d38305e3580703e293ee84abbda97f2d75a37ee0495459c083a999ec42e7babc9e256e505fbd2789493f80102070246cc859bb5108ff804e54dcb4b9df80937a3efa7e7cec3691f17af4590a49de9a1d2e2bce56fe7dacae12c6cb692cadc75b86e4ece8bcea5d1",
+    "export_key": "024325afc9704b8db39e70d1e1271f3432bee1156b97140b895c6768453d9ec13ca4d32ba50f6af8e3bf57fdf3383e8b8f131ed7c90a6c537283287dc3025a39",
+    "session_key": "dca21176a1b7527deedff49c1c8ac7496019fb7f1490674e4d3b31655e0bffda"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_RISTRETTO255: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67e06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325f3c285929b8ec575679f1aebb4a87f8c3cdfd3f4593f04fe35d869c9ab5c8150284e51c7aa130a48ca47d1d41dd071b1b644ac5cdcf3b0e8847c455edc914e4027d253edd0e868a2af4d22e4969c56286fd01f6bb7e4e1d0cda5505f076b4f4916b400d91a9e6bef4ea50c7bae66b02d67cda0068cae7c734218983ad6230a42",
+    "credential_finalization": "3b501115cee9bcc7abc8d829e610fa195c118547cde2a8ce4e8f205314ab16472183458cd3b3696f435c800d7061d5bc3f84f0b6c50aedce182f6be4169fc2c3b78806684c0fbfd394eae265fbee6919fc7be776de890a89e892a0c3cc20b86da26c14c69fcd7ae8be0761698b5fb125bbe3222477038ace997e4efc2013cdc1",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916ada903f06ee37fe0cc56e24ee0fe5abdf4ba87e24be23e84e80b4f919fb3e1022488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2630382efb8c7fa24d157eb8fae4ca4c58c330c647b667a8031407b677343bb41c7210a9cc2eb5316900915517356463f5fd15ab7b5575bc7128c9f6ffc852077af67245b59d41f821a9de76e3ed190bcc6492eab9370e46b141aefa661fbe4e8914ffbff3d270b0980f0b3f6451d681a18bb3ef1bda2a918c95180d5a14a7991daaf490ca7a221f62f672ad23b2bae939dafd531ccc3da9a9497a41c81aeb8ab78806684c0fbfd394eae265fbee6919fc7be776de890a89e892a0c3cc20b86da26c14c69fcd7ae8be0761698b5fb125bbe3222477038ace997e4efc2013cdc1",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "2630382efb8c7fa24d157eb8fae4ca4c58c330c647b667a8031407b677343bb41c7210a9cc2eb5316900915517356463f5fd15ab7b5575bc7128c9f6ffc85207"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_RISTRETTO255: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67b61c3c122b8571f0439345fa39389019e7ed7ce9fe75d22804863388bd5c06672bad995d24212b1e64f89c914d6f16a3387bc6b19b568eda0a16ce72dbf01f130ff58edc790dcb364228ed086555a28e19fd412daa72c814a7333e622d7999119e2d45226ff7ca97fb38d58929f4857ad8831d7230ea10af11425ba799a03bd80781a235f95de411b021e2a59469a451ed132da87c317d4c823d9533f963badb",
+    "credential_finalization": "67f2c2d31d88c6731d091479398e34debb0a4a089274f50869532e2c664211f49d19d2548fe1f6cd9f1e0c4b2e8a9b161937a25972613bb278912475c36da0bb4bf17828e01cf9a6a34015e239d68ef1f39d5d884bfadee5654af0b58104ce644b5596b362c674b10e58fe323ae68bfafa3b2ac12ff6193a66391b3bc610cee7",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916ada903f06ee37fe0cc56e24ee0fe5abdf4ba87e24be23e84e80b4f919fb3e102ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f0687523476ae5f4f8f42be8b3ce3a763faf6d667c536c7d375d2021bb60fec37186934e34e8409a807dfcd4e1dc17fc91f86dd036b2ffc03f8cf2ab2e9477b64c060fc09965e263b4a7c44497633a757b38cc6daf5c6f17a079127501a879bec303015a83184e9c3b76b3ab20505f57fbe431fdf2adfa70bfd8196443b005d5ddcca76fa1fd03b436ea452c6378d1bb741e8d861057e111ffbd14efff4589b1fd34bf17828e01cf9a6a34015e239d68ef1f39d5d884bfadee5654af0b58104ce644b5596b362c674b10e58fe323ae68bfafa3b2ac12ff6193a66391b3bc610cee7",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "87523476ae5f4f8f42be8b3ce3a763faf6d667c536c7d375d2021bb60fec37186934e34e8409a807dfcd4e1dc17fc91f86dd036b2ffc03f8cf2ab2e9477b64c0"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_RISTRETTO255: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d16c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea6784ba5d49f9427331781e79f276551030d358a4a0bed54d999b1dbe05157d9b606a4eb91bf3970b4f7459252573cad0a179abd70015cb7a0d37bb18bd7139158bdf843fca6e940ca1ab93e01317e359cc41502c3bb7b469c76a54949d74a92190f0c2a43b93c4a4fe23f45b00ce1d270cff05e939f01f3e4c0be0d9d170a2252460e1e1f2cb1336693bb8a161d3eec8f4e0b13e576d69473c2826e1d8cc4421a6",
+    "credential_finalization": "f08055e5c21abaa9c25472585c2c3387940a3048551789bf0b596fd91e892afd714ffeaf6daba48df91bf1f84ff10ae74ce3df0804cc9fc0747e2df619362c1c6a611b0c5254fcd09b79811441a58a23375c167600cc4da9caabf388159edcc9a1bc7c6fb317e8b9e43cf07f22236ab54af592a67ce4802457e2530095563032",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d16c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916ada903f06ee37fe0cc56e24ee0fe5abdf4ba87e24be23e84e80b4f919fb3e102a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc8db64b6c5c0ec1bb8caf6795f7f62c679c52fa2ea37515a17aff5816792d775ad1ef7a8a3486b3b2213f74c6630c4e5672b9f09c72ce41667a686b343b409cee9e69046074f350dd6c695f1bb885b02168eca30c640e5c166c1e0f159d733c392d6f37d205eb82331fa2f438f18679540ded74aafaead0e3186759b58f755b316e8c8f4b7adc562fe0009e7b3585b9d07f5bf5d82d110193932bef341333f231e6a611b0c5254fcd09b79811441a58a23375c167600cc4da9caabf388159edcc9a1bc7c6fb317e8b9e43cf07f22236ab54af592a67ce4802457e2530095563032",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "db64b6c5c0ec1bb8caf6795f7f62c679c52fa2ea37515a17aff5816792d775ad1ef7a8a3486b3b2213f74c6630c4e5672b9f09c72ce41667a686b343b409cee9"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_RISTRETTO255: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa0828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67e06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325d1a30878d19dfdbc60733818483dbdc98d53f7e51e89b279a2ea3df762c9c132450fee42260ff4942c965823ecddc7b29408a9df2d15108854d9671b2d11cdc1d76823889873c2f5e577e0827fd595d29db0518fb4a5ffe7dd23308f23662d6279c8f74a74a1f68742e00befdba6982876508be1148b0663e16f61e1af8de555",
+    "credential_finalization": "42197826b1db16b1f8d22fd427eec3bd11909aa5c042b347b5eec625f3be9b5f42c2abbb756dc4710c8f1f5d3c9b4e5c9c99ee0af27a66be60d5059141519613db4d3493928c4076cdc310e6703cb9f5ae737e65a136d88b3d0f3b8252f61a066fb3ef468efd3d9e053bdef224ee679ab046f538eb0d1dc1063c12387b2e9b79",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff6c3509b51bda084c3f3d3796c7554bb6450f3482c621630bb4be53c09e6a5916ada903f06ee37fe0cc56e24ee0fe5abdf4ba87e24be23e84e80b4f919fb3e1022488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a202040695e30742e58ec4b7bd32276069282f0a976b17255ff2729a62459ff2895529ad1f9afec21e31b1b0f14ae1e35d3daf24fc8b76d8d08d08b5b15340b745c2825492e7c2b5720a3140b66b2c332c34c35b0992dde0bbc5d108628b040012ea44d8d10636480f32f8b119697b9a2361b145b7b242f3b2d913a8136ca79158a3ba9b53e02fd3631249543735c61d575ae084be81f716c7b701bd96b3b039b782ffde44db4d3493928c4076cdc310e6703cb9f5ae737e65a136d88b3d0f3b8252f61a066fb3ef468efd3d9e053bdef224ee679ab046f538eb0d1dc1063c12387b2e9b79",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "e30742e58ec4b7bd32276069282f0a976b17255ff2729a62459ff2895529ad1f9afec21e31b1b0f14ae1e35d3daf24fc8b76d8d08d08b5b15340b745c2825492"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_P256: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b7",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea670218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec69707050f0722f43b0115bd1318ccfe242dd3fa15cda41e25f826e5fa31b9ee607a9049bbe2399048a03a40ee8cc0f323f2c0fafe3cf49def9c5f6c2bef95b2882da753ca418a732e59efb268ba31b18310c6b55080d8bda23b7c46bf9bbd31278d5f55",
+    "credential_finalization": "19b002b6d54972d4c7126321c49b1f00cb6714c361d5c93e24e17576b8e849bff988d4de777c2bf326c29a56796e3e2a15105dde5daf2bf0bea9440abf2a1b0f305e1b22e2a6204c651d8e48065fe8943aa9dca37dad7d5e78257faf22348766",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b79b357570693b3e26ecd2487d79d2e5a55c43a81300d30d45a9dc403e054e79aa2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b54acfa97df42030cc72e5763dd32f2245b3bf24061e14a597d07a33e30d5fa9689b73ad99cafaf452b9c1ec0aba0e8470c8989707990b191bc63ac3ed0f85189d0538035dde0d564be8b0cddbeaaf731982216c74f00280566504846603a59ed305e1b22e2a6204c651d8e48065fe8943aa9dca37dad7d5e78257faf22348766",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "54acfa97df42030cc72e5763dd32f2245b3bf24061e14a597d07a33e30d5fa96"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_P256: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b7",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67033764d541fd90bcf1af004586d31c2b3f896af9f9badf1f4b92c71b78b7da0ff72dc3d7816f522aab0dc7f45b5b31a484504748885ab3e2a8d87bf39ccb4e69beeb69c6d2d7d72d29d766002382213d3aba43c7133d63b60b3964b6573a5204ec91e256187b063a27bcc5510c0a2421757b7470e0f5c0fa615daf43f645ea0d6a",
+    "credential_finalization": "d21c4ef02e7034c91349aa20057433f47d3bac09de8d54e65978178da3f0e6bcfe58526183a860293bab6ed25bd4c2b76f5342a46ecab42c108f39d49c4d478a3f58db84cefc44d0cc6cba76e208be290e3159a0cd47697e1e61955ffa3051bc",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b79b357570693b3e26ecd2487d79d2e5a55c43a81300d30d45a9dc403e054e79aaba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f06e7a2c3e87626804614164a6f8e32fc4e871720ac86422e69ed776c7f1516556fd1c0267dcc3a7915ad2445f43a9457f4f85c3774eb469b19e1c9c6e0d049267eed06c1577581572a6387cbf5b5b5f389c563f451fae946f97e9e316ea97172c63f58db84cefc44d0cc6cba76e208be290e3159a0cd47697e1e61955ffa3051bc",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "e7a2c3e87626804614164a6f8e32fc4e871720ac86422e69ed776c7f1516556f"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_P256: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d10208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b7",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea6702fcb39ecddb8b6163466dd5efe4a5219fd428cd5330bbb99331a269562997102664f219fc9496c58a5b27ccada5009a2102905da7446e8f33b7d61a15ded8407dbda130399edad596da1913b02fd77eab40637f917949060fb79d8ced0f5036c0a9b3b040ed0f2c9e6233082e4d8c0228a10a4c1b5558972abba5cd88e6d58b3d",
+    "credential_finalization": "eaa000fee71fc1d3ab2347920195e92de99705abf3fb5b2e85dcd8d7942a70e176c085f9eb501d98e917a746a037d6ff0c0388972495befdf64cee066b591c45f4a43b9e3b68df2a34ad02210da5fdfe4c1601c5423c97d57c2df615e1c367b0",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d10208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b79b357570693b3e26ecd2487d79d2e5a55c43a81300d30d45a9dc403e054e79aaa4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc8cff163dde2b7ddbc9cc803a9e753b9f1d22396492779608c4bfcd8faad0e27e7d64bdf489625bb7055c63a6aa3a70f3e63904b3724e6d0dea04a80e69337f3291c98903cfa01bd00310a926f71ca1c6a7fecb56a3fd5928130c4d9b4b6b6f431f4a43b9e3b68df2a34ad02210da5fdfe4c1601c5423c97d57c2df615e1c367b0",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "cff163dde2b7ddbc9cc803a9e753b9f1d22396492779608c4bfcd8faad0e27e7"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_P256: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b7",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa0828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea670218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec69707053511e15caea5b49555d7db392a1c17d3c874a6159a3cc859966feea084332bbad46b8bded1a1203abbe1c646e50ecfbbf2865525968ccbea636e64c4ff258767f7f9d1724050c6344f38ee8688e678080882034fc5d99c2aaa256e17821c05b0",
+    "credential_finalization": "6619972e96b781476aa53059e06939618335138373b7b7e97ca5d6c7e6a47f6b9276cbd8ec9ed7c10ca93d20e2869565e51b55bd4528772e8de5f29378371e20b63d67b832c97bab50916c8d203998a3d3cfd1e4044b97bf5c98f309ae372852",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff0208780b5bd8062aaa5a1f9be1b8fe9ac47b13026142391fdf4949aed11d9cf1b79b357570693b3e26ecd2487d79d2e5a55c43a81300d30d45a9dc403e054e79aa2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a202040695d4009acb556bf45988fca9b7b9fc8bce820fc113754d6768deea22004c6ed03f58ef47a70fec245a3b9fcb9be0750ca0a1fd67559093198e298c341b6f777b1abc98e2da0406729ef429cd83031982c7a9184c4030aaf26ac12a4e6f4fbc44a4b63d67b832c97bab50916c8d203998a3d3cfd1e4044b97bf5c98f309ae372852",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "d4009acb556bf45988fca9b7b9fc8bce820fc113754d6768deea22004c6ed03f"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_P384: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c023003d7f454c17189560cb76ffc572527ea1258596e1c96443028fe2f9212e03156522bce38dac17dfc16805c70afaf7e0924",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d5fefc5488845391db3fcc0e5fa0bea67d24b9eafc0924c0f66523516c74f6042039cffb72db40abcdad960a3c5a37c64dfca48f06473d8d5401b0e1a106e4e06fb20539fbd2d76542554d3f5d6cb4136529dd824f5017e57320944261b4054cef7491788d2b07565319d54fe33fb52cf895a8f07aeb1e6e9dec5712e70261c2672b31a20c9b68391f073cdc44246d3dbf362a9ab995cb228a7d26f52d45cd20cc54f65e58f913efe3a10a50a753d203424136893a4d91f08641f8b5d3c1677460c",
+    "credential_finalization": "26d097becf7fa6aaf8fc79f957fb64d7f1d00f7212325ed3f236f844c7baa982ce47204ead21d722df64639cebfcf6bc4beb1f003caa9dc6df1460bdf4384d47f6cf030656bbe14157fce9b196d38d1f884bb4abb3347fc546962cdf2acbf66e1b827d820cbab1c28919ac83f79643f1",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c023003d7f454c17189560cb76ffc572527ea1258596e1c96443028fe2f9212e03156522bce38dac17dfc16805c70afaf7e0924ef1bb30cf2ff7113ce50c9253025cb32baceb4f266de17ad560f9011b5606c5cdb394b5a8e8f22363d39a1203d5d5fb615ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b10ed24b0f4f984a310a3f7aa6d861bdc40912b42130831d19ea647b58561f63e4ef0ff1cece07c3eb93e6008804938f64486deea91af227b05ce74273e6a8aede5d49b17551ec5118d7990e581461d530cf1638410e8dd66c34476ca9af4dbe95da148fd4cf7e0e102f689bf07b3b1f5677c8743879af840eb3f2f8a0f62434cf6cf030656bbe14157fce9b196d38d1f884bb4abb3347fc546962cdf2acbf66e1b827d820cbab1c28919ac83f79643f1",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "10ed24b0f4f984a310a3f7aa6d861bdc40912b42130831d19ea647b58561f63e4ef0ff1cece07c3eb93e6008804938f6"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_P384: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02c17aa964df6db992d11e1637536912c7245fd28b534bc227aa7c7b876a8a863ac919ab60ca94999c15e85357a8ec2421",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec5fefc5488845391db3fcc0e5fa0bea67a4a4f65a42b4181ffb2b1f907b68b1fd03497d0416a2e47d440b6ac568884475c886bf742be60a9f32333fb10c4f84ac3c75cbf38e3dba765e558963bc0d1fe98d9742eb693cc3ab2b3e9165a41180ddae695538b568c018236139c4385c5b8044acb229fae9eb29e084054ffffb8623467fae92c0106c8c9c38070ab9ce880bdeed6de6ba557c51df9c10c2dfa61f158bd661eb639fa786ffa01eb4968bc775c24955f6cd74b7445f0e1013069a8e730e",
+    "credential_finalization": "951c921478eb13aa1bc8e3e4fe2f1d71d3b7b87c9f8b217a48634a3cf204ed40f86ef14d59ace6199416122d146431e2f90b900e45b00b9be97a39e15a1a60eb6532c2e4d0ad4c82954d8e9c9e772fdbd0deb90cbb3351cb5ad812d8017dd9d76fccb8ced4422e5e0ba51d0bf2a7a2e4",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02c17aa964df6db992d11e1637536912c7245fd28b534bc227aa7c7b876a8a863ac919ab60ca94999c15e85357a8ec242142acc7ac25dda2fc6fdf27415fea509858832704a89bcab0be785fe9ac0c07eca5662e5d44f8b21d8c6b7c314a096a23f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f066063cb7483226157661912bb0af3c26b6f323c04a902d74d79a9f0764a045a84e612eb325d9948e51e7a7a86455d26597d1e9d6c89bb120ccec4f4ed12a040fd85fb704d6185c2997ccee03a79528f7e6bee9c99eec8e00b88b1373a301cbbeef4f68fb609444d98881d461eebe6c9cfadb5788329a99b1be19ebf824ccc69136532c2e4d0ad4c82954d8e9c9e772fdbd0deb90cbb3351cb5ad812d8017dd9d76fccb8ced4422e5e0ba51d0bf2a7a2e4",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "6063cb7483226157661912bb0af3c26b6f323c04a902d74d79a9f0764a045a84e612eb325d9948e51e7a7a86455d2659"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_P384: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02fc246d81d28533e2ff21185d5a6c02eeff7ca760321ec325433b5543e9fa2b805f099fb36b92e2fa4e3dfecd9597d748",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef5404365fefc5488845391db3fcc0e5fa0bea672488cae978cfafe2d8f8804d73dc870903596570c4e62f004385efbee012853c749c3beb918dfc88d223150ec6d2d7c785231105b6f83203074b46f51e175046069c1ac8f0d1ac55c48ea973824a4ea0df3623d1603b97a3c9e78d01a9b007ac77654fab752f8ed3e43334af53064e152fa138e869a5d66e5707135196e068412cec176eb08d85447d76fcced887eb951313a8886251d96c293e8a397624e45c0209aa5f7bc8a9116bf259f601a4a75ca8",
+    "credential_finalization": "3353e2dbcc2b91caad64016e5c4b6ecd97226d02c7c4c3daa7ba5e14efde442dbdf896c17d6ecb21b6a3a42189fdcb560888b569afe6d7d76ebab861a47b509e4b22999b9cd0a1625b4c57095e6c750daa3deb585e7e09d10fb19e62f3b95ed2ddb0317bef2f7d48aa9837c4a4d41e22",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02fc246d81d28533e2ff21185d5a6c02eeff7ca760321ec325433b5543e9fa2b805f099fb36b92e2fa4e3dfecd9597d748a78e944d2a72b32291e998d83fc7f5e7b07add66e5b384acf2bfc8992e8a9f75a854cea3a0e10da8e2cec993232a6c80fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc881a12988688d3660d68ce6161f8f5b9578b987d24f0ab8dfac44b9e3e3270ac159c1d0f11e6b9d08938b47047ce1b03862b603a0fe2fbed209c24c404c8d3293f8a898690ad3bc9aa7e2562f6fc3b6111f41e476c6574f6cafbd1c24b6c3b906cd1a016c9d67992da34fc49ec92175a4444a6bb00edfbafee4e63764e76622fc4b22999b9cd0a1625b4c57095e6c750daa3deb585e7e09d10fb19e62f3b95ed2ddb0317bef2f7d48aa9837c4a4d41e22",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "81a12988688d3660d68ce6161f8f5b9578b987d24f0ab8dfac44b9e3e3270ac159c1d0f11e6b9d08938b47047ce1b038"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_P384: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b75503d7f454c17189560cb76ffc572527ea1258596e1c96443028fe2f9212e03156522bce38dac17dfc16805c70afaf7e0924",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa05fefc5488845391db3fcc0e5fa0bea67d24b9eafc0924c0f66523516c74f6042039cffb72db40abcdad960a3c5a37c64dfca48f06473d8d5401b0e1a106e4e06fb20539fbd2d76542554d3f5d6cb413652ae015b294bb0f7a9382adf577d36aebc87b1d32def6908b54666503a8a46862816f01e43dad913d76faebf29ed893d7dcc558b6b32b28067cefe2f135d3eeb3a2ae3117d49828f0712586c27a4ff6ee00f9fe2a72962c3306ebf1ee2b7f7009aea4dc124c3e394ba2f7b2051937e44c2",
+    "credential_finalization": "7135e54720971a90ee199633a6fc030355c892f2be873395ec597760113c8702ebd4b8447ccc6fd6bbd6cc1d87ce73144c407634b0e4507d9a7b376e551f4425432a3025e3e749ec2f505a8ae4d98f5c4535dcc1c21d7fc934d146cb9b0ecbf8cd2209b92ac4439c6e27fcc3b26057a3",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b75503d7f454c17189560cb76ffc572527ea1258596e1c96443028fe2f9212e03156522bce38dac17dfc16805c70afaf7e0924ef1bb30cf2ff7113ce50c9253025cb32baceb4f266de17ad560f9011b5606c5cdb394b5a8e8f22363d39a1203d5d5fb615ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a202040695ad024fba3a1220bee45443d3d663e7feb3892848912f4f903c240c28f923b5baad25a032a242c56bbb3f1cbefd63e106e60f19c8cb7bceec2e4a4d7f4c1fc31f3fa0d991d2bb686016a1d9f6a67ae06f48dbed97652692980ab849b19ac70f270fa85849858c7bd4b1f31ba7b4d453823277ccd5f28d881bcedce8d01e508064432a3025e3e749ec2f505a8ae4d98f5c4535dcc1c21d7fc934d146cb9b0ecbf8cd2209b92ac4439c6e27fcc3b26057a3",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "ad024fba3a1220bee45443d3d663e7feb3892848912f4f903c240c28f923b5baad25a032a242c56bbb3f1cbefd63e106"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_P521: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b8baf02002a02eb324940699000c37d0566797bce84d1c42c03f4564e9dd70c1f62df03d3feb2e6cb942045ce490b40055882d51bb1f160f8aceb69ca98eccbb23b838cdef1",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b312020059ffe2609e501ab3a43d2c1ddf8a75efaef491807ab211c27cc9944063d91f4d5929fd306d0379f6c231fd2a89755ae50eb6a7aee8f53104ba09abf0be8942c8d3c145bdc8b3fcc282dac417291e0e31bcc5ddb6b095d2530460084e159971218fb4f7af4bf50857e51857e31caf19044aa8c09fc831ff3a4eb1300805b30c10d8cacf8ce2f08f870c7e08d073ec6265f05f22782e732c90eff7568083aaa106d433adf2e5c53013c3dd029074ba1890b2d1b2045972bcbfb19afaecae31455ae1",
+    "credential_finalization": "2647820ff73962242654a6cf6bf2addb2d1c575f95e63c987ace3e0d559d29b8ec22476c2667b5750142405bc2f15c33bc85041f7a988ab636ef115e33f74fc30eeef29eec1d97efb4259668f500718ad5aff8f7314e39e3f14ea6711cd4ad96134a234841f74a3b889b20b4028e57bfaafda2289451d4226e77e77e59879fef",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b8baf02002a02eb324940699000c37d0566797bce84d1c42c03f4564e9dd70c1f62df03d3feb2e6cb942045ce490b40055882d51bb1f160f8aceb69ca98eccbb23b838cdef10023219ac851b305598aff22d294e2f6cbd2065f7c32049b5661d816fff50134c94d99176a2921b11aa39da4f9c76e793963f56278d5b9dc121eb47f3931afcfe20083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b8baf",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2a68e803d02a9d4838c3d4fb58caa14246a34d027bb4ce3fa3b098560bfa07949a1a7c87e175571f4daaf158b9d6f8bb1e677a257c7d68bd9283336b6ab2942e90cea375d01aaa687c40ee9bc0bcd3203ab0595810140d812077cb3991a702776e85f69d2c6fb1a8d870b814b88c6c998a9cacb2869054929c3b50e81ebf5011265681058cec1323276d386917f2cbd865026e46f910257e87025b039a626b880eeef29eec1d97efb4259668f500718ad5aff8f7314e39e3f14ea6711cd4ad96134a234841f74a3b889b20b4028e57bfaafda2289451d4226e77e77e59879fef",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "2a68e803d02a9d4838c3d4fb58caa14246a34d027bb4ce3fa3b098560bfa07949a1a7c87e175571f4daaf158b9d6f8bb1e677a257c7d68bd9283336b6ab2942e"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_P521: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212388baf0300560afedcc427c4014519243b01071c17116f515653f178d870cc84b57db66b6c0124c9702355c7fc414a227c8805410c079b143cf425198df2624aadcdc1ca6042",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9becf65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d124880200914bf71ea3f9329d2749a24af3e63c49af4e68274163fd724b51e9074fe6c635f11c51a6b692945da727d1245c8aa27515c4bb68448f1c1ccfb42a8937b22f98baa1175abec4504033865d3b82a0c838d9bc72b86303ad0876b085b2cee9df03e09ca5d2b0c90dc9d73bd40fc5c9b52f680d5c81d6075bd89fb7d9393591838581c36efa6cd68bfb53cb03e9b475ca745ed129cd2d74ff939b2933120d6bfec4733030536ad286857f5022901859cf0e2ae060c26529bbddc582ca9a2c84df1562",
+    "credential_finalization": "5efd3f7efcf40b394ddd057b47950bf62f52544ecad52472082dc8f55752d44d7fbf9f865ee1349ce5fee226ed10a63795dfda0ee883f6879d99ffbfcd9311a5b48ef39ee435a97e3eae478d48badabb4c6c044044771659c7f752ca6aea4d958f799c0c5fb7c78dffee7ae1ad0d381f850665c8f0443c81e23e6fe0cf5722e7",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212388baf0300560afedcc427c4014519243b01071c17116f515653f178d870cc84b57db66b6c0124c9702355c7fc414a227c8805410c079b143cf425198df2624aadcdc1ca60420019972eafd54803a6f2dd311e57504561ad004625f049badb17f866b6615adbfe4f0aa28ade1fbec60f7d6ed70d243f81b892cfa5924f5d8be04189c52d919178608efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212388baf",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f063944d870f56e86bd69d252efc3375756d8bc11b1ceb50bdf4a7fd7c16fb6a2793b6c13d8a7f1ec69c2d7b4055dbd0b607e2e8099773a6e3136164d6b7a39cb3e203421cea150162bc2d43beff39e1033db200205ad50c764176459eee5e1b6211a827c38354106f57de7dbd2903e920c7067e561f8f619ed30d6b7d7cdc7c43c3a82e37dce291e49836408155be758a11578fd366f06569bc8ee3edda3dda6d5b48ef39ee435a97e3eae478d48badabb4c6c044044771659c7f752ca6aea4d958f799c0c5fb7c78dffee7ae1ad0d381f850665c8f0443c81e23e6fe0cf5722e7",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "3944d870f56e86bd69d252efc3375756d8bc11b1ceb50bdf4a7fd7c16fb6a2793b6c13d8a7f1ec69c2d7b4055dbd0b607e2e8099773a6e3136164d6b7a39cb3e"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_P521: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980200abf07c78232bba33a491f92361bdf3616c0db8e9a948bc48232339a299dbc6ef0dcd76ba30e5e998ac87970db1c8290a275fbb5f81aa59e68c4b3392938611ba86",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b02001d673d2bed26d54314073599dcaabde1ffb1c4cce103ec12191de791b45ab6e70d53c09c6fe0ffa688cc2d1ba1345b383711758f17f663c44192ba24fd50418bc74c3e6b0dbb566787f640711deb361e3594aaae696a5fac14dfd6d4120a3574fba6ac954e98a73a862272e84bae2c4eba3462cd6eeb6da3020a0d4b183cb36e558110805ecbb6d02faaeaa3038420c7143deda87a88f1474095f2f3362f47d5741806887c8f0ec225ed459a1e5550c5a1a90838deb3417a91000927ddfc7d1811",
+    "credential_finalization": "c4eb8586c1ec2ed763bcde195bb0f5b00cf68a8138e7fde272cca0457bff9a1669ac9b642b5ecb74a0a8822cab8247d470a20bab94f91b7be2b3e8cb87ccd8967239ac8f7a4e8a37cf43084c6bb599920a2afa6fb75819434040430c72461163b52fdaf9fef8691e20b6284010b12097a4f3f3c9568e913e3979bd6cec5ba775",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980200abf07c78232bba33a491f92361bdf3616c0db8e9a948bc48232339a299dbc6ef0dcd76ba30e5e998ac87970db1c8290a275fbb5f81aa59e68c4b3392938611ba8601918c63b4947dbd03d559b0248745407fc13e2744342671ef128f72a899a035a292a3406c522baf3f6d63c45f1860279d31581d4f189799af783547c91daf982bc3c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc83335c706ef000c120c4c54e35163fdf233015d53876804bb91945b20316efd6f9d787fcc810369d119a0c50046fb03cd80a47ae93b549891ae28682a6ae15d2d5d6cf73b2a7fd09098cb7e27640cfbcbdf5aa67dfc4405511ff24beca794af37ac6a7554296cc38178e4aa0a9915ef3000eb6e62d087d39c1d416fc18df795cc692ddb8831482622c9ed943a497df019d2aa7c81bb991a6e38f0560093a26a437239ac8f7a4e8a37cf43084c6bb599920a2afa6fb75819434040430c72461163b52fdaf9fef8691e20b6284010b12097a4f3f3c9568e913e3979bd6cec5ba775",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "3335c706ef000c120c4c54e35163fdf233015d53876804bb91945b20316efd6f9d787fcc810369d119a0c50046fb03cd80a47ae93b549891ae28682a6ae15d2d"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_P521: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be0201a451d498c777b439ee69268c5007a7eece473cdb68a2988062c716da8b103a9ea7aa810a6eda6c906fce0dad8ec66c360a703a624bcd95ec74657f5951a0c645ab",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa09eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b312020059ffe2609e501ab3a43d2c1ddf8a75efaef491807ab211c27cc9944063d91f4d5929fd306d0379f6c231fd2a89755ae50eb6a7aee8f53104ba09abf0be8942c8d3652e1fa144d15bfdd555d9989015de0b335856a6e8e6a84c9b45c5477d9b86147fef2a9abc82c8c3c127d52e7d410f5206b33bb6e6cbfeb29d03ca16386fea85c7e587f7d5c9d3fea0cbe28e70853cc6d3dd75705c7c5b013db697d01263f5d2845bb42734b9113bdf35cd2f1c7095bbb3f428b673349e12d0c54d779651226c",
+    "credential_finalization": "fc60e98eb0d87669a0d10c75725b2a2441419d8794aafcb5505a1a98bbac996aadee1b2bb39afba498b41536fc596ca0606058577cb18acf41e1089a3b596b36ce7f003dcbc9d43e16e046658fdf1e33c59d9316b9da58a1d55e588c5414f17555d1ad5af9b6cddc2fd46bab22dc7a9bb6e3cbfdeb51960f526ffc11a1f82795",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be0201a451d498c777b439ee69268c5007a7eece473cdb68a2988062c716da8b103a9ea7aa810a6eda6c906fce0dad8ec66c360a703a624bcd95ec74657f5951a0c645ab018bd0cbcdb03d00acc9cd5a648f236f35121a6fe2656cc7c3abd34604fe08161f53902cf93144cfc8c45e1dd9a748ab4dc44e9b4647d465099e9b68a5ee375c4b52f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a202040695e7ea7526df819583c63051d1ffad4f3d231ba9f5e49cef5e98a2ee181ed037682e1dbbf3e75deaef7c04e36dc6c760ca87b25f3ce702d25b5e2b7d5c66a7f5dc475d4685fe11445a4e50f1e385965598f114bdf7c5ae552b5fdfce31f03e76c30ca20ef80e1439ea2e55f78878784132c12b6213a86c6f152cd20d159d0bc5320253be627d0930fa1687732dc8b681c0eff102c06f17863fd928eed929b0394cce7f003dcbc9d43e16e046658fdf1e33c59d9316b9da58a1d55e588c5414f17555d1ad5af9b6cddc2fd46bab22dc7a9bb6e3cbfdeb51960f526ffc11a1f82795",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "e7ea7526df819583c63051d1ffad4f3d231ba9f5e49cef5e98a2ee181ed037682e1dbbf3e75deaef7c04e36dc6c760ca87b25f3ce702d25b5e2b7d5c66a7f5dc"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "curve25519")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_CURVE25519: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d037",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67adc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2eb53cc9137c10faf202b94bc7a0b77f55154c3994afaef8a65d23723948fffa57411970d8b0cef23f5f741c6fd8483f13435b1c39c7b8b6d5980637a26a16935931395ab940a9a519d57070b1366ba0592a3b9397cbb70cdf3617727863f431e2a4d7e8b23f97fc287bc2febb59324c470dc592b27c4e0cbef5207052ba11a9de",
+    "credential_finalization": "3d170efeb8fac7fcc64a32e2bee6ce9ad0b2cc6050eea5b4fdc1d4454fa4e726d942de82813594f7bd5375508d358a1df48b85be44a9818ae5b390c8e6959aa39a0e1392ead5b85945008e566eb50db19da2d572d83f21a03ad54245b0910337e4404e834057cb9004b655dc3fbf15fad266fde41aab209668769891f1e87fcc",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d03788afd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b752488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434bc58a749f846ff7649741d46d3d502ec06c49a1b6a422afeb14df36a37c3400cec45a9f67545303c4c3eb22bb679b5f5ae68b1a9edfdae064cdd91eb283fceb058b2ebca6098cabd7d592c99838d9e209b91f3234172be4551d2b6b743734508655306850e1cebc7aef875668bcdcc8aa3e60bf673776b83320d28f774feed3e2501ee64ee2b7c2ef840b840841de75999e34213cb11e9060be5928f2aa5a9d1b9a0e1392ead5b85945008e566eb50db19da2d572d83f21a03ad54245b0910337e4404e834057cb9004b655dc3fbf15fad266fde41aab209668769891f1e87fcc",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "c58a749f846ff7649741d46d3d502ec06c49a1b6a422afeb14df36a37c3400cec45a9f67545303c4c3eb22bb679b5f5ae68b1a9edfdae064cdd91eb283fceb05"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_CURVE25519: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d037",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67093a155f87a9d6566ada4d19e5052f5751de09f11c04079f4e3ba8427d00b308be91c61cd5ab38392195f6b3dedc0dfaa98cf80c1833181b78451a967a2ad3d1c7666bdfa646eb089b551efea49871b71b3aab943579e86f21eff96c996aa8f9f06dcca9d10b08cdefccf8ff0aee483db78b0c7367803800a6d9bd5ba4aeb0dc351c5d7c956fd3d237b0faf8dbff3f64cd69e35281afef9c17cd3dfdcb973754",
+    "credential_finalization": "224af7c7d67fbfcf77c1e6efd74314dbeca9d2b085d3691f83020eec07639d9992ddf75cefff02059a361bbd9cbafc1f94891b90aed2fa96f22eae1d6ba76559c87dcf91deea1f6278f41c2b631b15e2922f5d4759e499697a8ff8c2adb5642f0e844a054963abd9cfaaf4c6e670d4607f875cd148c9d67661ac56a3e2ff7bc2",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d03788afd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b75ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f063a9bd2f3b754eac2defecbaca58fcf0723db534f19c58c35404718a96106c39e6767de3ec7a48967793127e12636b64afa3f117ba7a9d3475b0b46b69d0699228171de7f7883e8a14344f414b8516f12d41d77110fd7e4caa8dc899512ec0942fb33cb05cd2ece19d1140d85c6d0db3b960ad370c783bbfba3a43142d83362825f62c20d1b962487b19bfb8daf704acc8cd00b771a2a5c9af3ea2989b4a8de5ec87dcf91deea1f6278f41c2b631b15e2922f5d4759e499697a8ff8c2adb5642f0e844a054963abd9cfaaf4c6e670d4607f875cd148c9d67661ac56a3e2ff7bc2",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "3a9bd2f3b754eac2defecbaca58fcf0723db534f19c58c35404718a96106c39e6767de3ec7a48967793127e12636b64afa3f117ba7a9d3475b0b46b69d069922"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_CURVE25519: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d19b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d037",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea6728e80d2f5d020b9144fb37cf28926958356e798667a8f806d0b366226aa3ed62c61caf9411472487842e8f75a3d147d97ed9a937dd48bb7fe19f5ee02dd0d65c6004629eb7f65f3318a00ba97792740ea9eaa49085f4c48afe7ae480c30ff0abd256077043d22c6988f15b3e04b2d6e20d6e1f854a14690104f981123d5cb811d7e28977e7ad87aa820ebbe77ec41676aa5872e9a3b62dd5d1b7f6928bfdc2c6",
+    "credential_finalization": "91d894859af9d97e4fb2eb112695b2f28cfe98865c7506dc21821eef2616cae92289e12b0c3800e0a0ce2947ed7375606950461aed36acbfe2b4f3e3b5329d50e5e842aff151119288c10fbfb041e9bef805a4023f0750873c067630d98295ed258a8d2928dd62efc0bf357d44eea6ffa421afe6208be928faf0233c5bcd086e",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d19b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d03788afd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b75a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc8635432436673e98f4422d2ffe730d89a9e3531be347797d232dd4c6adf8fd0cff9e022ab8b7b4652c36bf4d62c48fd007074975c33f31fc42e6e41c3088c6a36a4507d9ea4052280837b6c13fe29c1f8198b3eb533bc9937050ec52f091d6979fdab468e295e35ba979e945a2ea1415f469f010e1a9658cf742f9e1c2dc1599f1bcb3ecb624798c2d855659dfd63cdfe44fcf3890c6a82f943f362f37343fd05e5e842aff151119288c10fbfb041e9bef805a4023f0750873c067630d98295ed258a8d2928dd62efc0bf357d44eea6ffa421afe6208be928faf0233c5bcd086e",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "635432436673e98f4422d2ffe730d89a9e3531be347797d232dd4c6adf8fd0cff9e022ab8b7b4652c36bf4d62c48fd007074975c33f31fc42e6e41c3088c6a36"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_CURVE25519: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d037",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa0828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67adc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2e7c10a965aa61e6f953881952bc5290ad5d059686c99efb32d055b4743a0175bc17fcc3ec6264cd833724ebd1d50dca3acecf59471b4c62713e73343a2079bbd20b3c40a60b12558548f25628083476853dfa3c31772b48e1a228449f1b864b4cf83ab2c7e80b141ca60da90a9b33713b258d59db614e851b030d936e756c5e95",
+    "credential_finalization": "3cb4b6fee41f270e83a8599e5e82baf557da173200811a6d4d14cd0770aedec5419492319a0ecec638dc68939051cddd5a17176f069b0090d55b0d0ef97fed8da2c1ef10852898d06ce2d60f79ccb21ded584b10f83c48ff504f3a9a95b9bd4c279e41e7d72d76517962821bb11db146676d207a61ff50d0d32e7542293ea87e",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff9b3cee57e7055f6c0bffa784d5b491bb551ae3e72a5a305aa9269f8b1c83d03788afd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b752488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a202040695a624a0424d43d3d8cf107aae77f160b6a3865211b56fccdbd0c61b99e5123ec8d7b0d4735999b3fc812247e66be278830ea566df99f2829ccd562c6d0c061265b9b0a140258816f1eb8ab4162aba964a059e136e1cad26ceaf8402747ac5787696559518f552b1e81038ed40f0a864bffbb34ba7b36ed1fbac2c6e859b662aefaa3dffbf63f9a2aa75edaa3bc726b4a75a6e73ca62fbb6a0104cf365d8f5b200a2c1ef10852898d06ce2d60f79ccb21ded584b10f83c48ff504f3a9a95b9bd4c279e41e7d72d76517962821bb11db146676d207a61ff50d0d32e7542293ea87e",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "a624a0424d43d3d8cf107aae77f160b6a3865211b56fccdbd0c61b99e5123ec8d7b0d4735999b3fc812247e66be278830ea566df99f2829ccd562c6d0c061265"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "secp256k1")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_SECP256K1_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf0033afc5a27daa47dff0f8039829e678b075f492d991bb47746f9241b12bf3f780f8ef05797ee1d58e96d6e303fc20fbd584b480d2834d8154784a889d985f4208b0ad4697ac253005a9708f022895923fe30aca35c16369e6f5a911cc3e651efc48967578c07042c76d0d3ebca15218cab88c63db9508d0eee7b01b011338fd2d828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084d4ab3472c06c9495bb3ed8ee8214ca4501f68d50b916eeee17ac40b1ddaa8a2ce0720c19ef7471c2d6e7c1b184e88c3b7a983db5e4308941faf5b0496af209363dbbd9b6a57b7dd4fc1c7bec14a099bd45c4c234f1aed81cd2cfb6a7ab5e8fa8",
+    "credential_finalization": "3dcff314a9eee849ef636b39093cc0afd872e369bd5ab35074d034f9e9a12ff33101df38bebe6e81037141fe8245a72a3530bc1b49183951b3a286571fd27fea033169fbb144e2f824078ccd2bdba372281a2a95903b246bbfb33b1d730f9385",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca1942488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b368b772c9533e0d147cd6ae232b570c72399936c0969f2f0786403db54fcff8cf4c5c8a4924980bb19306afc109eb583e56a96b1651fda609f8ccf228b4187c998e4d3d625883f61f68c1e87eca2ffbbdc7ebfb126cb77cead62e44a74bb2509033169fbb144e2f824078ccd2bdba372281a2a95903b246bbfb33b1d730f9385",
+    "password_file": "02de8e3d1bb1e262ca8dad6171bd3fa6b9e040b63a81ec0d13098650ad59d9434b2ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf418f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7820c32f343847c3f41f4565ed2fa527e938ecb8f570bc52bbae14967543b159cc467ed647bb0eec036c1afd922cefe82302a6ee274110d87eebe47639d996e4f16",
+    "export_key": "f35efe6becbabef5e4bed4fc782e032f90146b4bf0942481e931e9a56e746884be394741d035912723d532ee52d64bf7f0dc783cc5319537091a306db35c0508",
+    "session_key": "368b772c9533e0d147cd6ae232b570c72399936c0969f2f0786403db54fcff8c"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_SIGMA_I_SECP256K1_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50b030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd210b43d7f7925161968dac68f555cf59e1e7d7b501609920413eb8539daee2ec2115f5c7389e9f039509e6ce08bd2b5e5953afb7e0f50932c3a84f236dfc3e511fe1f99b2459b770a46605e4d87e407163fed2545f5c3d6a306670af29674e9bec828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea670347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fada5416985fa41321b65b786eb1106b24c7c080505557d0e354de0b904b8802029161d743ebd4f4c0081304221bc9306fdf95b5a7115811e08a435d48116a8e32d3fb7b09d89443ae3c75b5a99cf1774b02ac6d0a44427d136c853009785d6d5b",
+    "credential_finalization": "314576a35aed21697fc99749ac3263540590483b269cb65add7debece584bde52d1f4a252061bf7c248e6173eea459e0d21df3bbd25d30514d97993020e74dcbbf91fe32d0531c09b4173dc90df6a720c7868386ce560694160600349ee57b41",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca194ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f06513ce65ad4c6abf005da97ea91f9bbebc282adc6d0e860b2e3ee921963948565a52caf59447ad01e9388b86220657623de0a0f9a91a334d2a0f963b0a1684b8beec2cd508ce46b7cadb66eca4876e2dfbb2d4d2a0508db3de92d9f1bdbef48a6bf91fe32d0531c09b4173dc90df6a720c7868386ce560694160600349ee57b41",
+    "password_file": "027ca400b242ae4ba0df3d7371c55ffcbbf0f81ef0a7714744a0ab7aaab0999f064226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fa18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782c77f44245a5258485ef287cc73428c96f3139e60a46626224451ee19343f3e4d",
+    "export_key": "82b34742e8ef8086112ed1707e2043d9f8c414e48af6b2ed87b543131564c02f",
+    "session_key": "513ce65ad4c6abf005da97ea91f9bbebc282adc6d0e860b2e3ee921963948565"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_SIGMA_I_SECP256K1_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d102583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13600fd824624a671a9e69238f8091b8b1859a2303b4b62b0a46be4e294c0cd266d10ef173ac08f3848555c6534054ad8b66d0e545b5ec5e02970c408fc9cc339ee7af43b6b49b66583a8365f3fd292088d9e010ad4e231aa80272ce747163b20597c9eb1024bb10ea9764428ca3ef540436828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea6703f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa84e3f8a9e29b453fc7e1ba5a218ef8df2c281c6cb9c3c0c6878989e3bd4e9a9010960f7385ea1581f8bc3926f37b770826154c8a5d9c949278678acb3d2966d31be368eb956cb5f7f4329ec5729b04f4e6c56e96a2a14301469adcc421d2451ff",
+    "credential_finalization": "699b5c4f60a43d6a629e37eafa5738b38dfe571bdaa6544ee534e1e689dcede770f963eb03d5504c660351315b31ceb5dae0172b650034ab52953d880b9ef3b1dc9d9d639484221c3c85a3f5c04a2cc0d1de07916cc9cf26b0d258ec7659ad0c",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d102583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca194a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b3f172bde8c78330ea1e5616542cd166f4df6e91ceb570977f4557c6b392f8d045e0cfaf215465ae99a6bef1939db43450950c5bdb9ca8edd432fe5e831dab9264d71a974d3f85c466509584549c389bed2d1abf58a4fa4e070be58bc9f8e8adc9d9d639484221c3c85a3f5c04a2cc0d1de07916cc9cf26b0d258ec7659ad0c",
+    "password_file": "0334245163b21afa91b6b2d5c4608c8804bcd0c45effb5a7d7c4d853e165a83fc84b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8c18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde78243e620347d0bf1c83d35bdab9f8079e98626cc6280e766c24570864da5e63fca6fa1857ef2145dd377d44cbcb8bf0b0d",
+    "export_key": "172fde556a5654102231957753e557a96d4947fb0fbef956de7b25b750328faa52628ae2ae8ef09505ea991d74229817",
+    "session_key": "4b3f172bde8c78330ea1e5616542cd166f4df6e91ceb570977f4557c6b392f8d"
+}
+"#;
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_SIGMA_I_SECP256K1_SECP256K1: &str = r#"
+{
+    "client_s_pk": "032527acb0eb7b8ba316f3836f34d92e0fb993fcf05ad543c4d7324519b2e1be98",
+    "client_s_sk": "18f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde782",
+    "client_e_pk": "03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "client_e_sk": "8bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35",
+    "server_s_pk": "030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "server_s_sk": "1de566bd895c0b4c68fdbef04241509864e745fde936a8121b9b44400beec41c",
+    "server_e_pk": "0243720731978e10b5cd120baa325da481f7f215c8f7bd87533bf1fb47116994e6",
+    "server_e_sk": "828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67",
+    "dummy_client_pk": "030b4d7beebc4f0927ad5a5488ac1e55cf8709370ef07951208c6bd7bb5dfd11b9",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4d030f8f48d535161c2b553e223915d6a61358f6917eccdcd641f6b8aae3b02ec3d6",
+    "registration_upload": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a52610",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf262430324751f83de52c774035e612d7c06e60d9c17eb850bc04d3d075632586221bf616d69595766fc03b6892aaf16095fef8f8280891cb7aa7ebfb5d26957bdcb59c6d92f4aceb4f9c569572ecf654f4f16317c2c14e54dbf82d84bb33091367e4f210beaaf426807183557d456b4c530a1bdef0da2dfaa1ee25b25cd2470fa0828076a29e6c183a615c029bf90bba3e5fefc5488845391db3fcc0e5fa0bea67038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc90840c540537a7577a084fc3bf12c358d93590251c8eb5dbd55714bc9187ca62d75f4e4ac6ae3a17ebc33261013867c403c57b3a6d78c7c231b1ea54eb130b59ba80e3b4ea1d3b1b1b63db2cc65ef04ee3013e09768cc6901b40b730e5fbc4d0219e",
+    "credential_finalization": "dda520acf1bb68736e9827abc56d7727a290b1e058ff9cef671715c514ecc8259a54f29cfa8b01e803fe870b3909b7b0b702bceeb523ca4c793b976c3cf116acfa04c2069d9b8b72af653d676e58f282079df6e1456181692d04c2361d5e566b",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff02583178b4b2ea8cf0927590ccb16cb0c857c5e660cd26150e4048049365a526107653d49e2b9cd065006100512666e6148069cc4f75d292d452d37b000d7ca1942488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a20204069584294d5f3821fceba088c3d222266d96b7566b029dc6125e4842de1ca270185595c9e93fc9789e2ae20078690928fa9da5fe069e59afed8027e6bc0bbedca61ca192f61a1053532cfafe484dffb330e3e2970af2743aa11079f101c60dd7862afa04c2069d9b8b72af653d676e58f282079df6e1456181692d04c2361d5e566b",
+    "password_file": "03a141b794091b64693d3a33c6234ba73120b169610755d2f759bfa9a2020406955cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b724018f4e267c144d8d87ae522ce97517bb4c482b415f9505fc997958a17a9bde7823a967f1e1613a1f4a7b4cee0011cbff3ff6ebc66cca54f2f4a2676fc468839f1398c20b8e4b2a8d5d43df257b77248d2e4dc1a8745cd54318681395fa056b43a",
+    "export_key": "3bd78fc63e279ef2ed12249e5502c2c41f91401cb2c3f3f288c35eca44ef7225ed4b8fe88d427ce273d59bad0101d82df3052a4ca327b24da9193551863c8f83",
+    "session_key": "84294d5f3821fceba088c3d222266d96b7566b029dc6125e4842de1ca2701855"
+}
+"#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "ristretto255")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_RISTRETTO255: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325db8fe482e7a2bc1641d38a0cc664c29615c71eb9bb4f6eb630a922eb8b7ee898a3a35d26782ef9ed74bd5688fd181eec848eee6d39ff43f5e4c70685d6cec40bdb6a24a883646a105be5f2169e218ea54ef4741b555a04c677afeda5a8f75d0944ece9cf80e58090ea95fe062299ff1581ed1bc9069e85bcbe33ccfa6492dc4f",
+    "credential_finalization": "8992b1bb9a3cd1ea1af23698a22a3739ef3ed8ea6997d17b4687ff919837765ea34d83ba47f0215da4155bb3646735b54a9219742187a4acce17e7a6b7496b08153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc6062488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc543262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec603768b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325db6a24a883646a105be5f2169e218ea54ef4741b555a04c677afeda5a8f75d0944ece9cf80e58090ea95fe062299ff1581ed1bc9069e85bcbe33ccfa6492dc4f153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc543262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec6037f110fe6c0e7c3e47cf30290bc791758166f2ed7c67b69c34671bdf53e895eadadc65298d599c55d0f28f204026394e50d493ffd0f1c95266853f03b0afc2222868b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325db6a24a883646a105be5f2169e218ea54ef4741b555a04c677afeda5a8f75d0944ece9cf80e58090ea95fe062299ff1581ed1bc9069e85bcbe33ccfa6492dc4f153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "3262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec6037"
@@ -3300,7 +4769,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_RISTRETTO255: &str = r#"
     "credential_finalization": "37b8bc42a84842ace537f082cac511f9aab299fa49f488eeeab508cf7afe3e0dd576411204ebf96ec450d2f55b15ec259a397a98a65b5a11fc2cff5d107f93069861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4d0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ab61c3c122b8571f0439345fa39389019e7ed7ce9fe75d22804863388bd5c06679b57b2658f38664cd2da0c8184db486c642e47c379b538b3d7fce54a7e15fb4ad0c475f2ae33e1f23f3bb6f5a8586483ec6f5f3f326d29a451201beedbb12f769861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4d1682b1257e197e7aed0655bfea8c9a437005e8a144be51c3548e675b157afadce76150e0a160fdf5236b61eb0a4bc549ac333e69964eafcdb99aeeb94db55e8d0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ab61c3c122b8571f0439345fa39389019e7ed7ce9fe75d22804863388bd5c06679b57b2658f38664cd2da0c8184db486c642e47c379b538b3d7fce54a7e15fb4ad0c475f2ae33e1f23f3bb6f5a8586483ec6f5f3f326d29a451201beedbb12f769861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4d"
@@ -3341,7 +4810,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_RISTRETTO255: &str = r#"
     "credential_finalization": "f2f5e26c24acbe0fd78efe8d2dfa2293c89db2f4d501af70c77c4150b5ef639b9e3269a0b64d4ce9c4ae5fe617c1bb18b7c89546638a56341a95b2d65f25360b63969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1a2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fb0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1a2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a84ba5d49f9427331781e79f276551030d358a4a0bed54d999b1dbe05157d9b6044462b68a003f3c680d7caacb2d949830196f0d2e7b45dae670c7221fc91cd28ba39bf77e605a0ad931d0d2d5fb785f0914662ea968c7766def78c58252b542163969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fb0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c09e8fa25c83883236fc742179bed56dfaaad3db3db12fbc55c26c879f7dfd8d1abf12c85c3a6d3b1794b34cf26b9689cd5f5937b971bbfa8b2210841cc7bba7903cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1a2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a84ba5d49f9427331781e79f276551030d358a4a0bed54d999b1dbe05157d9b6044462b68a003f3c680d7caacb2d949830196f0d2e7b45dae670c7221fc91cd28ba39bf77e605a0ad931d0d2d5fb785f0914662ea968c7766def78c58252b542163969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "b0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c"
@@ -3382,7 +4851,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_RISTRETTO255: &str = r#"
     "credential_finalization": "753a9c607e4ede7496bf557d14679e7142ca4039a1138ae6aec5408aa412fce34afdf443553f8d1d41a1b29ae9a1396e4de5ee1370bbd3d4f51c4bc7f47e3d024a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc6062488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cb69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedd0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e70502003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325c73d9f03c12dbaf61fba93faefbe9eec2aa32fa5a007c3edc1018ba9a4bd79b22198d81777fd7571f65d7abd18fe2bcef8d4f991be709acb3b25d38c827ef8654a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cb69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedd2c7674ca534b02ad7685bbe16d539a7dc1363725eb2f69a33f68a99b5ab8ae36109e9d2c2aa16757127db80f726eda164906bc849aab0f8bb94ced55204e33ef0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e70502003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710ae06cb5b2f770194fba88bf99a5c79aa4261ec3397803b67ef83cb3be294a3325c73d9f03c12dbaf61fba93faefbe9eec2aa32fa5a007c3edc1018ba9a4bd79b22198d81777fd7571f65d7abd18fe2bcef8d4f991be709acb3b25d38c827ef8654a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "b69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedd"
@@ -3423,7 +4892,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_P256: &str = r#"
     "credential_finalization": "c40d75a7feec7d51e4a20b41eaa355d800d5d4254d57b4265c9060adc7c8181a23346e52b04405879ef0b6cd39228689a922c70c15469eafd3a473e430710e0f1d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57802488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc548120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04d68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec6970705fac81c40e0ef25bd21a9e36dc954f0bf25af18136f58e68741cc44788ddf30c21d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc548120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04d6745cd3a537380439fe4232cc3a948636a079e6ad1b18ee41fe9a0c171ea745368b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec6970705fac81c40e0ef25bd21a9e36dc954f0bf25af18136f58e68741cc44788ddf30c21d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "8120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04d"
@@ -3463,7 +4932,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P256: &str = r#"
     "credential_finalization": "46be292285e125504c55e95299d79bf6a9a53a03ced631cb71ef8eca35b09dd0799b9be1214e15591ea7e185484330f68d537094135cbac1c35457fc29634f0abe65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b90389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a033764d541fd90bcf1af004586d31c2b3f896af9f9badf1f4b92c71b78b7da0ff7b4b03556dede64f702c2a89c6978306198f401e39d5de8c647cbc7596f5c720dbe65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b956b213d4505114491b4ca39cfa857677e5df595d3c5632a433182b1f4214617d0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a033764d541fd90bcf1af004586d31c2b3f896af9f9badf1f4b92c71b78b7da0ff7b4b03556dede64f702c2a89c6978306198f401e39d5de8c647cbc7596f5c720dbe65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b9"
@@ -3503,7 +4972,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P256: &str = r#"
     "credential_finalization": "4d01a1e205d25bfb4d9df586f2707ab3b52649a6be08a233a91fc956ac317a539bbb35b1826400fa574567910b6b30b1fa5e6a41444c068beab3a256d9e7b4029b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e803cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a02fcb39ecddb8b6163466dd5efe4a5219fd428cd5330bbb99331a26956299710260aa24a2a25c16a0fb6f5574c692b1da865ae4be374fc814a445e6cb8a2e00b379b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e8f0fc3fc1a8594d5d8e1da3cb8259bc32152e766ac329164389ebc0948f3ebcef03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc57021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a02fcb39ecddb8b6163466dd5efe4a5219fd428cd5330bbb99331a26956299710260aa24a2a25c16a0fb6f5574c692b1da865ae4be374fc814a445e6cb8a2e00b379b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e8"
@@ -3543,7 +5012,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P256: &str = r#"
     "credential_finalization": "68234ffd2b42132a372425245bbf3c90c80b4f35d9207996a08ec8cd1adc027cbeed5a74f6b0d0a66269b9f1192e2102e4152473dd443690f5cc280efa09cb07210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57802488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f220301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc5702003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec69707050ab6f18379ddf2301e7e43e7d0ada56ef98075c63e8e279ad4916729082b5f9f210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f2277f9ce7c81ca1f5ec1f71127d73b97ca62bec5b69e1f0ee83517bd0aa16e383d0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc5702003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0218ff26f632e00a66ff4dc579dc0c862ecdeac63551423c3e61bdeddec69707050ab6f18379ddf2301e7e43e7d0ada56ef98075c63e8e279ad4916729082b5f9f210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f22"
@@ -3583,15 +5052,176 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_P384: &str = r#"
     "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e8ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce6449883e6f1e361d124c642f17788107d406d716bc0fa6425f3ba18734bb3819d2099b6368afd4266e4000ed5ef8cb1fdcd30d5e42c6332fa16c2dc224dfb2c5d16e30ab1210dd4331a89fcc93f29105c0b915b083926b826651706513a5ba8d2079ef873c550caf248372217f80db818ae2eda",
     "credential_finalization": "4e56c31b42ab0b14959218141545349c8327ce79c3deef3c83b65db6a8e07df23e11057014ba9f4251b9577f35bb9739fa48613c86699a16586aeb16b3fb95026109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
-    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c02300318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b00168b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c02300318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c2398dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e8ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce6449883b1210dd4331a89fcc93f29105c0b915b083926b826651706513a5ba8d2079ef873c550caf248372217f80db818ae2eda6109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c02300318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b00134d884829c287bed78d9ba00a43b0d95f7dcbe93aa3268e6cea69cce448b15a9d31061faa7059c975ccad53896b0be9c68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c02300318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c2398dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e8ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce6449883b1210dd4331a89fcc93f29105c0b915b083926b826651706513a5ba8d2079ef873c550caf248372217f80db818ae2eda6109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
+    "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
+    "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
+    "session_key": "ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b001"
+}
+"#;
+#[cfg(feature = "ed25519")]
+pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P384: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50be9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b27",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd58ffbf6e3397719965d42fd3f3a17710aa4a4f65a42b4181ffb2b1f907b68b1fd03a9290aaa5837b8deef438795a1168e0b22d2c4f58b26547f14cfe25535069a76a90e17f97605cd7ee82c395b8ba1a43d00f49d59d49683c6bbe712d653099a5b61934b856adfa317ecb41940315b01ad6a3eeefaf859d926b326eb326c2e40a06590ced387db637148bc82f823644b07ae59336bd05f5330c3b2b9bcda6f1cf2160c96a48e73f78b1ec7348f033c44a4cef19596ad52129cb05d83317d78b6b0",
+    "credential_finalization": "f882a19d77a68ad9040a38ff00a4552167bb8f255db97929eda448ab82aafdcb1d96fe8b2b3b5f5f6b128c7b366b26f4272878d864b8c35f3b3626b85fe0780edc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b279eddfd06d81720bdc1304a9764f71d4d321a7958078f5395952f6d0f1ee33406a59362a0669232c56bb028976f9aad26f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c26bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c7345b1a61451a7a0c0fc2574d8eb07853a4d8585e224789d5b0a5d118c101cd5a282d4e600554ed26e34af200e85ed970389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b27028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd58ffbf6e3397719965d42fd3f3a17710aa4a4f65a42b4181ffb2b1f907b68b1fd03a9290aaa5837b8deef438795a1168e0b22d2c4f58b26547f14cfe25535069a76a90e17f97605cd7ee82c395b8ba1a43dae59336bd05f5330c3b2b9bcda6f1cf2160c96a48e73f78b1ec7348f033c44a4cef19596ad52129cb05d83317d78b6b0dc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
+    "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
+    "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
+    "session_key": "6bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c"
+}
+"#;
+#[cfg(feature = "ed25519")]
+pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P384: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6ce",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa28ffbf6e3397719965d42fd3f3a17710a2488cae978cfafe2d8f8804d73dc87090310f357011b821c1b4637d58451d1f3457107252dd7520ca356213157c654c1c7c8bec65c2943b36e796f5fdb3cf02bc6266281ce032ee53d12736967fc0f9632958b41d2f3cd43e57b8afda66357b5f4eb5824f39107de7e96ab567438ea323f45c57197153bb57a4220bb4a132c2a0ec98cb959fab89e4e63c8b8a2005ca64747a3c29e43dbbf03ba01cc6bd5a13bce41fe30ba0798b5684acedfb4b5e9b6e5",
+    "credential_finalization": "3fcdb489d5c7f20849ca25c478ba62b5454a29a5a60498883e2d1ce48e82df304ba6a65b4358dcdd01521d84225056f0dbf18c122d592be58dbf57d145a8db075a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6cedf5e437032c49f1fcf8428100698765454adc2a13025cfb3e6b4169f3eb8f9e2dd5137e0ce725075cf45952b1e0b1d17fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d064861aa68c4a54118b068ff71d897d291a01d9c8d18ef5ae7ffe7f14c049427a00245f8ec2d864776c61c301450df7771303cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6ce021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa28ffbf6e3397719965d42fd3f3a17710a2488cae978cfafe2d8f8804d73dc87090310f357011b821c1b4637d58451d1f3457107252dd7520ca356213157c654c1c7c8bec65c2943b36e796f5fdb3cf02bc6c98cb959fab89e4e63c8b8a2005ca64747a3c29e43dbbf03ba01cc6bd5a13bce41fe30ba0798b5684acedfb4b5e9b6e55a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
+    "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
+    "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
+    "session_key": "669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d064"
+}
+"#;
+#[cfg(feature = "ed25519")]
+pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P384: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4de9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c2398",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b08ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce644988325341f6a067bc17d057e968fc52c051d0d155945810e7345ab7df138782f78da565df6f46a80701fbd4db6a25342cd46ef1b4d4b2221b86b6bb2adb44e8799026bdce3cad2bdbb1cea4503b20e113f6ab5d8dfaf9ed51bb45029f3614483b3efe87c40accf28e3064dbbf819deac73e2",
+    "credential_finalization": "10830230813bbfd00548139c1c139539ee5a16fcf609fa8123a35941d66f5b71ab7ef91dea33b4bd65771fe90ee004cb9e6df33eb0dc9595b5daff6fe403930db54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388fa1b84cc583694876703155fb022705f7f488165c5dee167e768ab61d0c3e00ac482c12126f6455b45a22d37da74665190301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c239802003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b08ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce64498836bdce3cad2bdbb1cea4503b20e113f6ab5d8dfaf9ed51bb45029f3614483b3efe87c40accf28e3064dbbf819deac73e2b54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
+    "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
+    "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
+    "session_key": "ba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388f"
+}
+"#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_P521: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde07",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3ccd340f15b179aae29e3dd24b66ab1c5f12059a6d42df9fb4b03a6e964600ee2e43a760fbf79b42b9a264c2c710648e31538ff2c167019eec9edf1cfe12ac7470b777a169388677bf158c991fb72d9e6bb1ee5197751ce65861c9f7ff017e1827d3d80896a3cb96f6c3faea642c7e22006666b2b86498b1206d02e9d275aeda83e",
+    "credential_finalization": "723ce398f44c35e6c4e40650000a2eeeffd14ad341c2ddae428eca04284780221b2343e5eb39c834cc9612c32cb5b3ac572b740a03358e2691af7424aa20f804a8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde0701ea73f9f3077cb50a43d0a54750b0e108e639de75c093af4f07e5d2fa01444428b1bde832808bf5879ebd943b935a3812d9dd22d6f1dd21cf2d327fd6bdecdf1b7c83cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc546fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa63e52d468c7098a8a1e45912deaba56db32e97aa8c534f7757b01506b06ed896541f26a1c13650585f0fb6955092be1d0e5fe7708aa0074497f73d8fc2c76f9a68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde07dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cc777a169388677bf158c991fb72d9e6bb1ee5197751ce65861c9f7ff017e1827d3d80896a3cb96f6c3faea642c7e22006666b2b86498b1206d02e9d275aeda83ea8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
-    "session_key": "ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b001"
+    "session_key": "6fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P384: &str = r#"
+pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P521: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3619,19 +5249,19 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P384: &str = r#"
     "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50be9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
-    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b27",
-    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd58ffbf6e3397719965d42fd3f3a17710aa4a4f65a42b4181ffb2b1f907b68b1fd03a9290aaa5837b8deef438795a1168e0b22d2c4f58b26547f14cfe25535069a76a90e17f97605cd7ee82c395b8ba1a43d00f49d59d49683c6bbe712d653099a5b61934b856adfa317ecb41940315b01ad6a3eeefaf859d926b326eb326c2e40a06590ced387db637148bc82f823644b07ae59336bd05f5330c3b2b9bcda6f1cf2160c96a48e73f78b1ec7348f033c44a4cef19596ad52129cb05d83317d78b6b0",
-    "credential_finalization": "f882a19d77a68ad9040a38ff00a4552167bb8f255db97929eda448ab82aafdcb1d96fe8b2b3b5f5f6b128c7b366b26f4272878d864b8c35f3b3626b85fe0780edc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd5f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d12488020172ab2d18d067ee16adeac0f2d4652ebdadab7406284f4e680dbf6c960dbe8203e7069012f6356a77f40930d979edccf7eab55c5925e8c8a0f669509215634dd1b3f29d66740320c6daabd39aeb1c447d883821a7c76ec71c53d38b57b88c4da3eac95a5a248a9132b11dbad3a5aebb96688d8eddf5a90896ee6c22e4756d0f6b0ffc7c03a6372757a4ffe243b3dfcde8ec70f8782256a6daa70a0c2e4de1875510977abfd816a2a8ea6ac35eec2f97044d34996c76efc3c9167571c4caa233bd94",
+    "credential_finalization": "b06ec4b705f4a0fe312b5dd2d30d72d74f2370a97e9f3ae0728d090967eee48fdf03411daba93aeec70780da8b64714484873104d1ba9ca5bdcd3d0b0724040797ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
-    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b279eddfd06d81720bdc1304a9764f71d4d321a7958078f5395952f6d0f1ee33406a59362a0669232c56bb028976f9aad26f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c26bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b27028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd58ffbf6e3397719965d42fd3f3a17710aa4a4f65a42b4181ffb2b1f907b68b1fd03a9290aaa5837b8deef438795a1168e0b22d2c4f58b26547f14cfe25535069a76a90e17f97605cd7ee82c395b8ba1a43dae59336bd05f5330c3b2b9bcda6f1cf2160c96a48e73f78b1ec7348f033c44a4cef19596ad52129cb05d83317d78b6b0dc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293007df96864682bc22266cfbc73ee38e3a244c07926fdae7c0973a0fab994182bc352f845fd6be6c68f9ae0b3f032169c61e5e3eaad370d3c92d4203665ae5c38394a8efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d963d13e9fc772f75d6e0b22e1a1d8eab2146eadd636d7f8ee0ecf19af3b65830a0fe38f6c2a9a5bd610bf072cf4b50acf003f9d9e9184e5dce72e806dcb018b8c30389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd5f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d12488020172ab2d18d067ee16adeac0f2d4652ebdadab7406284f4e680dbf6c960dbe8203e7069012f6356a77f40930d979edccf7eab55c5925e8c8a0f669509215634dd1b3fc7c03a6372757a4ffe243b3dfcde8ec70f8782256a6daa70a0c2e4de1875510977abfd816a2a8ea6ac35eec2f97044d34996c76efc3c9167571c4caa233bd9497ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
-    "session_key": "6bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c"
+    "session_key": "e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d96"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P384: &str = r#"
+pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P521: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3659,19 +5289,19 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P384: &str = r#"
     "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
-    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6ce",
-    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa28ffbf6e3397719965d42fd3f3a17710a2488cae978cfafe2d8f8804d73dc87090310f357011b821c1b4637d58451d1f3457107252dd7520ca356213157c654c1c7c8bec65c2943b36e796f5fdb3cf02bc6266281ce032ee53d12736967fc0f9632958b41d2f3cd43e57b8afda66357b5f4eb5824f39107de7e96ab567438ea323f45c57197153bb57a4220bb4a132c2a0ec98cb959fab89e4e63c8b8a2005ca64747a3c29e43dbbf03ba01cc6bd5a13bce41fe30ba0798b5684acedfb4b5e9b6e5",
-    "credential_finalization": "3fcdb489d5c7f20849ca25c478ba62b5454a29a5a60498883e2d1ce48e82df304ba6a65b4358dcdd01521d84225056f0dbf18c122d592be58dbf57d145a8db075a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa2cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b0300798713663ef98d8d636c651a49aeba1cbc60d31afa0e4d63b4a07c05523bf1060be35c2881badf68cd7f7f19cc4081c7f904d727af790bfd2adacdc7051ce34a007330110d17a78e649845dcc00b8f2282a098dd28580fa3af211737a67a9620079688ab4de78d54732d2361472113fe8032d8f20a93f5635f5801fd95c2339c073a150a1ae73dc3832cc8d769e8733849dfefa4e823d741bb6797311454272e24d2dbe82b2382cc913dcb561445637288ba3a9236fa4fa0d9a0aa50e8c7009eae",
+    "credential_finalization": "9b3f63d224c89d991aacd838124993f12bda46897282a8d3ec62b6cfff66052c58d4031c2e5a33c38030aeeaf2f9ed8dece4252d27af00f6ee8174c364fa99068b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
-    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6cedf5e437032c49f1fcf8428100698765454adc2a13025cfb3e6b4169f3eb8f9e2dd5137e0ce725075cf45952b1e0b1d17fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d06403cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6ce021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa28ffbf6e3397719965d42fd3f3a17710a2488cae978cfafe2d8f8804d73dc87090310f357011b821c1b4637d58451d1f3457107252dd7520ca356213157c654c1c7c8bec65c2943b36e796f5fdb3cf02bc6c98cb959fab89e4e63c8b8a2005ca64747a3c29e43dbbf03ba01cc6bd5a13bce41fe30ba0798b5684acedfb4b5e9b6e55a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6010a3969d61d3e550a5105d2796ba83df8d106e7be9e4fda546c5ff043efb20b19218f7068977d91bf78ef891d2fc5eb3ed32e09d3861452b6ebf4391ecd2b87e521c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c8322366893458340843bd506e942bd2cdebfe9102a5fb2aeb6c835432494f108d7f6445236acf215615460b83ffdbbf892d0ba8ded2a3e9fe08788468a50b02c1260737d03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa2cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b0300798713663ef98d8d636c651a49aeba1cbc60d31afa0e4d63b4a07c05523bf1060be35c2881badf68cd7f7f19cc4081c7f904d727af790bfd2adacdc7051ce34a003a150a1ae73dc3832cc8d769e8733849dfefa4e823d741bb6797311454272e24d2dbe82b2382cc913dcb561445637288ba3a9236fa4fa0d9a0aa50e8c7009eae8b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
-    "session_key": "669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d064"
+    "session_key": "9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c83223668"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P384: &str = r#"
+pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P521: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3699,20 +5329,21 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P384: &str = r#"
     "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4de9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
-    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c2398",
-    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b08ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce644988325341f6a067bc17d057e968fc52c051d0d155945810e7345ab7df138782f78da565df6f46a80701fbd4db6a25342cd46ef1b4d4b2221b86b6bb2adb44e8799026bdce3cad2bdbb1cea4503b20e113f6ab5d8dfaf9ed51bb45029f3614483b3efe87c40accf28e3064dbbf819deac73e2",
-    "credential_finalization": "10830230813bbfd00548139c1c139539ee5a16fcf609fa8123a35941d66f5b71ab7ef91dea33b4bd65771fe90ee004cb9e6df33eb0dc9595b5daff6fe403930db54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef7380",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b09eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cc080104a51bb3ab899674571ab38bb44906e12c88a1675c3c9adab5c367acc4cd96b1d41f7dbb03b5f0f37886861bc6ad9e69d66cfc602cfe3246019a4255020ce50f997db3c3226a675bbd5fa7eb1e6b3a0da786be6d797f5c0e3d384c9c008685059e1baac31bce43dd76be4d1cb5e0cb88d010ca6136b925d34ed447766601",
+    "credential_finalization": "de53b017a8194eb98139c1e97bdcb439bba0ee38887ad19572cd9194ea971aee3f66f6e96eb742feccd31e33816fef2ba02f201be3c88517a0ec7a5ca91ddf07b191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
-    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388f0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c239802003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b08ffbf6e3397719965d42fd3f3a17710ad24b9eafc0924c0f66523516c74f604202f054c08be2e1c18731d8360243a3ba5c0d3b9dace68f21ee27d55b4fb3840a6a3e0603fadbee1d0e0f40c8cce64498836bdce3cad2bdbb1cea4503b20e113f6ab5d8dfaf9ed51bb45029f3614483b3efe87c40accf28e3064dbbf819deac73e2b54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef738001364c3eb9a701ec11a2d8281f19a314ab09c4d054e39d43ba98d74919de1857c5b4da90b204107e7dd605b4e8c97d505ab8f21ac29a8220ae2d191a750f8001e517f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8ced907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7dbc8f0843b315e30bddd32b33bf7bd10ddc5f61f03cd609050a1c676c44e0aa4ce64231b274ef583c186da6d2547410b27649ba9be7d949c56800e302e75c4c20301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef738002003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b09eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cce50f997db3c3226a675bbd5fa7eb1e6b3a0da786be6d797f5c0e3d384c9c008685059e1baac31bce43dd76be4d1cb5e0cb88d010ca6136b925d34ed447766601b191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
-    "session_key": "ba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388f"
+    "session_key": "ed907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7"
 }
 "#;
 #[cfg(feature = "ed25519")]
+#[cfg(feature = "curve25519")]
 #[cfg(feature = "ristretto255")]
-pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_P521: &str = r#"
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_CURVE25519: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3740,19 +5371,20 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_P521: &str = r#"
     "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
-    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde07",
-    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3ccd340f15b179aae29e3dd24b66ab1c5f12059a6d42df9fb4b03a6e964600ee2e43a760fbf79b42b9a264c2c710648e31538ff2c167019eec9edf1cfe12ac7470b777a169388677bf158c991fb72d9e6bb1ee5197751ce65861c9f7ff017e1827d3d80896a3cb96f6c3faea642c7e22006666b2b86498b1206d02e9d275aeda83e",
-    "credential_finalization": "723ce398f44c35e6c4e40650000a2eeeffd14ad341c2ddae428eca04284780221b2343e5eb39c834cc9612c32cb5b3ac572b740a03358e2691af7424aa20f804a8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2eb9b5f148af47a8146360fe3efeb25fa4ef5ccf97da3ace180c622a78fbb6bc3469db87e338f2644eb5378d9f2c64ed8ee4d026bade9bb65141e8936f5e35410dea6b84712938273c5db40459324c3004e9f120cbc0c832db2b57a334bcc0385ab6ef51af6159f029c9a0b5c2b8db137148e3dee2bdbb6386bce1aa9cd9b5c00f",
+    "credential_finalization": "eeff89da15f9fbfdc5177161db9e3fc5c77343316a42423219cfd4da17e10e83fca1663b5f0fc60651b1425811b1dfd1d217752ec50e985d9e38ba2179d5ca072901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
-    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde0701ea73f9f3077cb50a43d0a54750b0e108e639de75c093af4f07e5d2fa01444428b1bde832808bf5879ebd943b935a3812d9dd22d6f1dd21cf2d327fd6bdecdf1b7c83cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc546fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde07dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cc777a169388677bf158c991fb72d9e6bb1ee5197751ce65861c9f7ff017e1827d3d80896a3cb96f6c3faea642c7e22006666b2b86498b1206d02e9d275aeda83ea8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc540f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8baa417dcdaa8f25c2d369cf2a2c837504a6be7ac4c5db2dc7dbabadafaad7d3780007f81e344794a549d8b5049cab83c8754d561076dec51c04989fc6754b862468b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2eea6b84712938273c5db40459324c3004e9f120cbc0c832db2b57a334bcc0385ab6ef51af6159f029c9a0b5c2b8db137148e3dee2bdbb6386bce1aa9cd9b5c00f2901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
-    "session_key": "6fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa"
+    "session_key": "0f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8b"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P521: &str = r#"
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P256_SIGMA_I_ED25519_CURVE25519: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3780,19 +5412,20 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_P521: &str = r#"
     "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50be9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
-    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293",
-    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd5f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d12488020172ab2d18d067ee16adeac0f2d4652ebdadab7406284f4e680dbf6c960dbe8203e7069012f6356a77f40930d979edccf7eab55c5925e8c8a0f669509215634dd1b3f29d66740320c6daabd39aeb1c447d883821a7c76ec71c53d38b57b88c4da3eac95a5a248a9132b11dbad3a5aebb96688d8eddf5a90896ee6c22e4756d0f6b0ffc7c03a6372757a4ffe243b3dfcde8ec70f8782256a6daa70a0c2e4de1875510977abfd816a2a8ea6ac35eec2f97044d34996c76efc3c9167571c4caa233bd94",
-    "credential_finalization": "b06ec4b705f4a0fe312b5dd2d30d72d74f2370a97e9f3ae0728d090967eee48fdf03411daba93aeec70780da8b64714484873104d1ba9ca5bdcd3d0b0724040797ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a093a155f87a9d6566ada4d19e5052f5751de09f11c04079f4e3ba8427d00b308c97cedb780a60c89aad49d82a8b3f0909dc132c3b8faa72a6cdc6114cf0eecc95f659088d300c780593b988ece5493f3a8a2eb059be47eaa7c85c732f207af097d0b819c9ba40b7ff47148909ddcc55802b3b04d4945c0139340dc0a0ebae7fc6debb1b37b70fda3f70a3bf2de4e6c9e93df068e2bb6aa157ec23cd9e07ab32d",
+    "credential_finalization": "9e6f72b00914b653dbd8b47265b5020f8d5fa46a6c416cfe5fa86fb6d1b6a1e1c632eaa6e36f491396020178e2d099deae0b4e526291b2d309ad9d5317d932095d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
-    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293007df96864682bc22266cfbc73ee38e3a244c07926fdae7c0973a0fab994182bc352f845fd6be6c68f9ae0b3f032169c61e5e3eaad370d3c92d4203665ae5c38394a8efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d960389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd5f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d12488020172ab2d18d067ee16adeac0f2d4652ebdadab7406284f4e680dbf6c960dbe8203e7069012f6356a77f40930d979edccf7eab55c5925e8c8a0f669509215634dd1b3fc7c03a6372757a4ffe243b3dfcde8ec70f8782256a6daa70a0c2e4de1875510977abfd816a2a8ea6ac35eec2f97044d34996c76efc3c9167571c4caa233bd9497ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87bba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c201287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2c3b26ba80adb18d326dc33841c923041e16e87221265bf7c8f62076bac06e28c7febf3b44d180077e98fd1a224e896a50c194cb8f68dd476d650dd02b65be5240389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a093a155f87a9d6566ada4d19e5052f5751de09f11c04079f4e3ba8427d00b3087d0b819c9ba40b7ff47148909ddcc55802b3b04d4945c0139340dc0a0ebae7fc6debb1b37b70fda3f70a3bf2de4e6c9e93df068e2bb6aa157ec23cd9e07ab32d5d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
-    "session_key": "e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d96"
+    "session_key": "01287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P521: &str = r#"
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P384_SIGMA_I_ED25519_CURVE25519: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3820,19 +5453,20 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_P521: &str = r#"
     "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
-    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6",
-    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa2cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b0300798713663ef98d8d636c651a49aeba1cbc60d31afa0e4d63b4a07c05523bf1060be35c2881badf68cd7f7f19cc4081c7f904d727af790bfd2adacdc7051ce34a007330110d17a78e649845dcc00b8f2282a098dd28580fa3af211737a67a9620079688ab4de78d54732d2361472113fe8032d8f20a93f5635f5801fd95c2339c073a150a1ae73dc3832cc8d769e8733849dfefa4e823d741bb6797311454272e24d2dbe82b2382cc913dcb561445637288ba3a9236fa4fa0d9a0aa50e8c7009eae",
-    "credential_finalization": "9b3f63d224c89d991aacd838124993f12bda46897282a8d3ec62b6cfff66052c58d4031c2e5a33c38030aeeaf2f9ed8dece4252d27af00f6ee8174c364fa99068b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a28e80d2f5d020b9144fb37cf28926958356e798667a8f806d0b366226aa3ed621143dc340b841491026215e4cc7d604c0b610be1cd3691ca2ac3129c1c5429cbc8b31a0f9e9b8e6bd52614bc15c4da0feb42dd86bdd0b6cf8c0ce708cadae40880f63ad689d466508dda958103627d6cb382ac05082a48a0ec66aae1f1d81f55b4b9172f1c0004e0a390ac485851d783d74251457ac475da62e10e66ce14abc8",
+    "credential_finalization": "7ee21c52760fd6b2e110cc4bb47746d12814371e22368a6ff78374ef89c1e52fdf907cca6a307ca1207e540c8c50608972d50c677a46603c38220868682b96072fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
-    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6010a3969d61d3e550a5105d2796ba83df8d106e7be9e4fda546c5ff043efb20b19218f7068977d91bf78ef891d2fc5eb3ed32e09d3861452b6ebf4391ecd2b87e521c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c8322366803cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa2cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffd24b0300798713663ef98d8d636c651a49aeba1cbc60d31afa0e4d63b4a07c05523bf1060be35c2881badf68cd7f7f19cc4081c7f904d727af790bfd2adacdc7051ce34a003a150a1ae73dc3832cc8d769e8733849dfefa4e823d741bb6797311454272e24d2dbe82b2382cc913dcb561445637288ba3a9236fa4fa0d9a0aa50e8c7009eae8b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87ba4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994b3f6fee3ff55a62583bf2fdcbf84faa7162a52bfa252fe83d07270b3f0b80904eaa86446b301ef5ccc28a5721519c585c31cd5b433eaa356253017fac8b0347503cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a28e80d2f5d020b9144fb37cf28926958356e798667a8f806d0b366226aa3ed6280f63ad689d466508dda958103627d6cb382ac05082a48a0ec66aae1f1d81f55b4b9172f1c0004e0a390ac485851d783d74251457ac475da62e10e66ce14abc82fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
-    "session_key": "9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c83223668"
+    "session_key": "4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994"
 }
 "#;
 #[cfg(feature = "ed25519")]
-pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P521: &str = r#"
+#[cfg(feature = "curve25519")]
+pub static TEST_VECTOR_P521_SIGMA_I_ED25519_CURVE25519: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3860,21 +5494,21 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_P521: &str = r#"
     "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4de9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
-    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef7380",
-    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b09eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cc080104a51bb3ab899674571ab38bb44906e12c88a1675c3c9adab5c367acc4cd96b1d41f7dbb03b5f0f37886861bc6ad9e69d66cfc602cfe3246019a4255020ce50f997db3c3226a675bbd5fa7eb1e6b3a0da786be6d797f5c0e3d384c9c008685059e1baac31bce43dd76be4d1cb5e0cb88d010ca6136b925d34ed447766601",
-    "credential_finalization": "de53b017a8194eb98139c1e97bdcb439bba0ee38887ad19572cd9194ea971aee3f66f6e96eb742feccd31e33816fef2ba02f201be3c88517a0ec7a5ca91ddf07b191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2e2cc59d340724b32be94a8cd81bd411de3af7cbed7c1e43628f0b091c02c711cb2c9d6f2ca89e9f6dae5f5f64fab74dd2fc1335a7ce4697743982ca147b86c20ca2001b3113532d67e1774d80354cd5ad5b088a5d2d218b91bf734537c5fcbde49fbc15b88d2de53cf926a865bfb9561dd18817d03fd5f4201dbadb176097199c",
+    "credential_finalization": "b589aa59e37c1c1728d3f68a8b9531da631c9413028445cebc59dce7265b384dbd5f75c39fc5ec01689c998fc77b97e1fccb59b9ae3908d3f7ed5c74e5b87d083c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
-    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef738001364c3eb9a701ec11a2d8281f19a314ab09c4d054e39d43ba98d74919de1857c5b4da90b204107e7dd605b4e8c97d505ab8f21ac29a8220ae2d191a750f8001e517f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8ced907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e70301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef738002003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b09eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182b3120300223af1f159be71442c050700555116bf31067ec1b63ed9ea04a4a8bca24618519135fefcf8c2a95030e193e9d65e81997535f52e543f0060a2c6fd06e96591c3cce50f997db3c3226a675bbd5fa7eb1e6b3a0da786be6d797f5c0e3d384c9c008685059e1baac31bce43dd76be4d1cb5e0cb88d010ca6136b925d34ed447766601b191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a5d5d04eae7ead2bb731b62ed6bf74dd8c46ae015531a05cb8cdd0fbb0e285bdb366a4d9dd6424e1dfb3303da5aae02cbc9919f6711ddad1d1b4e6f2d231a3b1a0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f22902003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2ea2001b3113532d67e1774d80354cd5ad5b088a5d2d218b91bf734537c5fcbde49fbc15b88d2de53cf926a865bfb9561dd18817d03fd5f4201dbadb176097199c3c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
-    "session_key": "ed907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7"
+    "session_key": "631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a"
 }
 "#;
 #[cfg(feature = "ed25519")]
-#[cfg(feature = "curve25519")]
+#[cfg(feature = "secp256k1")]
 #[cfg(feature = "ristretto255")]
-pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_CURVE25519: &str = r#"
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_SECP256K1: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3902,20 +5536,20 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_CURVE25519: &str = r#"
     "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
-    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
-    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2eb9b5f148af47a8146360fe3efeb25fa4ef5ccf97da3ace180c622a78fbb6bc3469db87e338f2644eb5378d9f2c64ed8ee4d026bade9bb65141e8936f5e35410dea6b84712938273c5db40459324c3004e9f120cbc0c832db2b57a334bcc0385ab6ef51af6159f029c9a0b5c2b8db137148e3dee2bdbb6386bce1aa9cd9b5c00f",
-    "credential_finalization": "eeff89da15f9fbfdc5177161db9e3fc5c77343316a42423219cfd4da17e10e83fca1663b5f0fc60651b1425811b1dfd1d217752ec50e985d9e38ba2179d5ca072901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084c3836b0f9e2ba5f06b5de131f24f6a998d16fb94808648cd2915414e685f20c796d11d312ebe2ecdaa8389fc47d5b16e359548a6d551d1c64d0f75b600bd630629159b95bf6be541329b1003c67bc2db65cc1af52a1913b51977a2bc581018d3",
+    "credential_finalization": "f5c7d4123ad53cd2a9ddff1a8b3dbc38550a775e982149ac9bb1a5fbe49f04cf3225741efd9d9c1b03c8ef7bcffa35dd9ba400ceb96292a43a718a00e7b94c0a9646bf9955fe56510132d31b4af2b82c4eb3202fab1bf056b6ce2e31f5a6f104",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
-    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc540f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2eea6b84712938273c5db40459324c3004e9f120cbc0c832db2b57a334bcc0385ab6ef51af6159f029c9a0b5c2b8db137148e3dee2bdbb6386bce1aa9cd9b5c00f2901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b352488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54a28a665ba0c904b64c7ab031580872a75d2fc45feffb4360e1f2624af5b7dd1a0b5561bda6c53a57e9a76840ebc94fab64d678f8f052a2574bd83dbbc5ba4c8168b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc908429159b95bf6be541329b1003c67bc2db65cc1af52a1913b51977a2bc581018d39646bf9955fe56510132d31b4af2b82c4eb3202fab1bf056b6ce2e31f5a6f104",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
-    "session_key": "0f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8b"
+    "session_key": "a28a665ba0c904b64c7ab031580872a75d2fc45feffb4360e1f2624af5b7dd1a"
 }
 "#;
 #[cfg(feature = "ed25519")]
-#[cfg(feature = "curve25519")]
-pub static TEST_VECTOR_P256_SIGMA_I_ED25519_CURVE25519: &str = r#"
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_SIGMA_I_ED25519_SECP256K1: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3943,20 +5577,20 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_CURVE25519: &str = r#"
     "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50be9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
-    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
-    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a093a155f87a9d6566ada4d19e5052f5751de09f11c04079f4e3ba8427d00b308c97cedb780a60c89aad49d82a8b3f0909dc132c3b8faa72a6cdc6114cf0eecc95f659088d300c780593b988ece5493f3a8a2eb059be47eaa7c85c732f207af097d0b819c9ba40b7ff47148909ddcc55802b3b04d4945c0139340dc0a0ebae7fc6debb1b37b70fda3f70a3bf2de4e6c9e93df068e2bb6aa157ec23cd9e07ab32d",
-    "credential_finalization": "9e6f72b00914b653dbd8b47265b5020f8d5fa46a6c416cfe5fa86fb6d1b6a1e1c632eaa6e36f491396020178e2d099deae0b4e526291b2d309ad9d5317d932095d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fac6ef959dd162ce30025027b09eacc26464cc1a3e2f918fc9f40c26b159469d519679758815328cc2cdd175ab0875ec8b104beb97bcd408135db33df3337e7704e28ed819342d57c29aa7a764e40280c7a8ff7c0572cbe5d9968416d5f92792c8",
+    "credential_finalization": "c45c220552fcc87e08e28ad976d90ae3b43c6f545752db6aec4cc6afd050f6630fc64c54cb573f676c77fc7fa3b9f044fbd54b6c1ae0c1ea189adafa2ff5c808199ce9b45fa5a9e2b7cb6a3ef17d4f1aea32a7c1a97d9580b8873cdaa85d8cf0",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
-    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87bba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c201287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b20389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a093a155f87a9d6566ada4d19e5052f5751de09f11c04079f4e3ba8427d00b3087d0b819c9ba40b7ff47148909ddcc55802b3b04d4945c0139340dc0a0ebae7fc6debb1b37b70fda3f70a3bf2de4e6c9e93df068e2bb6aa157ec23cd9e07ab32d5d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c21ff09ff0b8b2a757e9c6f55c2a6f6b2557b0b7a5f76dcf8fb6fe239b94f0818931a740c5a03842798164193db0780787d1dbf368af8fa0a31ca7ae57af2ad0c40389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fae28ed819342d57c29aa7a764e40280c7a8ff7c0572cbe5d9968416d5f92792c8199ce9b45fa5a9e2b7cb6a3ef17d4f1aea32a7c1a97d9580b8873cdaa85d8cf0",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
-    "session_key": "01287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2"
+    "session_key": "1ff09ff0b8b2a757e9c6f55c2a6f6b2557b0b7a5f76dcf8fb6fe239b94f08189"
 }
 "#;
 #[cfg(feature = "ed25519")]
-#[cfg(feature = "curve25519")]
-pub static TEST_VECTOR_P384_SIGMA_I_ED25519_CURVE25519: &str = r#"
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_SIGMA_I_ED25519_SECP256K1: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -3984,20 +5618,20 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_CURVE25519: &str = r#"
     "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
-    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
-    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a28e80d2f5d020b9144fb37cf28926958356e798667a8f806d0b366226aa3ed621143dc340b841491026215e4cc7d604c0b610be1cd3691ca2ac3129c1c5429cbc8b31a0f9e9b8e6bd52614bc15c4da0feb42dd86bdd0b6cf8c0ce708cadae40880f63ad689d466508dda958103627d6cb382ac05082a48a0ec66aae1f1d81f55b4b9172f1c0004e0a390ac485851d783d74251457ac475da62e10e66ce14abc8",
-    "credential_finalization": "7ee21c52760fd6b2e110cc4bb47746d12814371e22368a6ff78374ef89c1e52fdf907cca6a307ca1207e540c8c50608972d50c677a46603c38220868682b96072fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a03f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa848b739ad3d69ffe3a92c98f6f0a154b47cf4ad7855793e50ea70abb357ae11ecdcb1563060b628a3b4d12563dc728bbc1d439fe7297d7bc594ac6b137b42430dfcbd59476be9686fb99d76d7dfff732379136878e9273e156f7831da06334aea",
+    "credential_finalization": "3880ac7447bd77cbddd575933a404362fee02523897b2955c34359f855a8303e4d85c5f89b43ce1581981ba8c05f9e9e444d1d4cf91a75a1367827a2e03e8c0f1e5734c2de5f389016c1e296a52266676e6da4f00a24068ad9ee6d184ee91513",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
-    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87ba4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e3399403cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a28e80d2f5d020b9144fb37cf28926958356e798667a8f806d0b366226aa3ed6280f63ad689d466508dda958103627d6cb382ac05082a48a0ec66aae1f1d81f55b4b9172f1c0004e0a390ac485851d783d74251457ac475da62e10e66ce14abc82fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fbdebd82d957ab3fa2c1b1ff2b726314ce9eae1dd990339617edb4702f7aa84e5ddcff719b26ebeea6ded4bf59af97cb79b08bf96a8bd84afcd69dda33314b6ac03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a03f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa8fcbd59476be9686fb99d76d7dfff732379136878e9273e156f7831da06334aea1e5734c2de5f389016c1e296a52266676e6da4f00a24068ad9ee6d184ee91513",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
-    "session_key": "4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994"
+    "session_key": "bdebd82d957ab3fa2c1b1ff2b726314ce9eae1dd990339617edb4702f7aa84e5"
 }
 "#;
 #[cfg(feature = "ed25519")]
-#[cfg(feature = "curve25519")]
-pub static TEST_VECTOR_P521_SIGMA_I_ED25519_CURVE25519: &str = r#"
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_SIGMA_I_ED25519_SECP256K1: &str = r#"
 {
     "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
     "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
@@ -4025,15 +5659,15 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_CURVE25519: &str = r#"
     "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4de9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
     "registration_upload": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
-    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229",
-    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2e2cc59d340724b32be94a8cd81bd411de3af7cbed7c1e43628f0b091c02c711cb2c9d6f2ca89e9f6dae5f5f64fab74dd2fc1335a7ce4697743982ca147b86c20ca2001b3113532d67e1774d80354cd5ad5b088a5d2d218b91bf734537c5fcbde49fbc15b88d2de53cf926a865bfb9561dd18817d03fd5f4201dbadb176097199c",
-    "credential_finalization": "b589aa59e37c1c1728d3f68a8b9531da631c9413028445cebc59dce7265b384dbd5f75c39fc5ec01689c998fc77b97e1fccb59b9ae3908d3f7ed5c74e5b87d083c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084c0c3a2fa0a2364b5002a92ef54aaaac24e9f602d514244b4eec6124e475e3f3ebe957cd7463916e01140db6f0ec191505623304b9c3927ba09837b6dc079fe07b282a2ccb454dde783c71dc990958824e0df12802c510df4a976791832c6c210",
+    "credential_finalization": "69eb954c257aadc2083414b38eee27b0858d4ee60907a9dbd2ad7d808516231baa349262d0b30676a2d4ddd11c1e71a978d5750a5d3c3578e53b81d1aae5c202750f1bcce2a6ffae722bcc8349d9c628902e77972ab9136315e3b4f3956a0237",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
-    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f22902003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710aadc4e8ff66ebe878a7cdbc9863a5385ee2774aa29118e0ed13d2e0766b50ba2ea2001b3113532d67e1774d80354cd5ad5b088a5d2d218b91bf734537c5fcbde49fbc15b88d2de53cf926a865bfb9561dd18817d03fd5f4201dbadb176097199c3c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b352488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c018d21827bd98f5261bda479457896e926c31cec6f2029a453564be46e7246e49e6a054c768e7c0396e4e0bc22e4bee0c0483395afae789f44388eef4121cdfc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c802003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084b282a2ccb454dde783c71dc990958824e0df12802c510df4a976791832c6c210750f1bcce2a6ffae722bcc8349d9c628902e77972ab9136315e3b4f3956a0237",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
-    "session_key": "631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a"
+    "session_key": "018d21827bd98f5261bda479457896e926c31cec6f2029a453564be46e7246e4"
 }
 "#;
 #[cfg(feature = "ed25519")]
@@ -4072,7 +5706,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_RISTRETTO255: &str = r#"
     "credential_finalization": "7cae684d90aed615ec9beaff68893a397a79892584850da99ab505962207d5143ed3547ac12c4504a469a686fde09a645bc4a59bacf18477a8d8eb8ebeded30d153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc6062488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc543262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec603799c16b54a33b49aa9b5500944eca61a79e8216f9e963864057df8f388b1c3269d60a14da7e2a48f4aa36bb7af0674e0c64c8caaea142761903c3f868788a61de153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc543262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec6037f110fe6c0e7c3e47cf30290bc791758166f2ed7c67b69c34671bdf53e895eadadc65298d599c55d0f28f204026394e50d493ffd0f1c95266853f03b0afc2222899c16b54a33b49aa9b5500944eca61a79e8216f9e963864057df8f388b1c3269d60a14da7e2a48f4aa36bb7af0674e0c64c8caaea142761903c3f868788a61de153718539ae69fcc385fe6f98acf2692bc9a4213a85421865e2e3b3a42757666f048d1ed4eb06c2a0efdec21cfbdeed8de1d5a3dd7cd92976bd9c5c7c2738188",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "3262d15eb2f8bc58dc6fac6a7361460c0f5861d6e8a2f4a3f2833ca2a7af23ddd4ca7ca93bd4e4bc7b057824ea6c97e5b6383e065f3ffa5980c51bbc34ec6037"
@@ -4113,7 +5747,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_RISTRETTO255: &str = r#"
     "credential_finalization": "bfec45e3ba075f3eaa5ce56ebda9812feb2162263283dfd101fac69ffd81e2118d28bd76e99eeec689a53f7597320fd62a8fd30e2f72e5dbad2d7c6ab0ccf50a9861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52ea2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4dae4a8e431aa5026096b816439b38ee488a7368e4c2209f5ccbd33314795e55d46ffacb519f22f302ddfbdd0876490c686e3f71fbfa8a841edd9791be945790599861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4d1682b1257e197e7aed0655bfea8c9a437005e8a144be51c3548e675b157afadce76150e0a160fdf5236b61eb0a4bc549ac333e69964eafcdb99aeeb94db55e8dae4a8e431aa5026096b816439b38ee488a7368e4c2209f5ccbd33314795e55d46ffacb519f22f302ddfbdd0876490c686e3f71fbfa8a841edd9791be945790599861dc88271ef6e0cacd7559e0f2b4eded01226074b31da0df34bcf6305cd60e95d96be4dfde4a3e22ebcefa52f45d424b06491fb5ef3a262c08dc5e1d722b5b",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "fdee7263089dded3fb64c7eafaaa57396c4eb0181ddae6fb1403ffff70ab92c073911aab82a443cd471162dd2e247c44674afbcc2f13a970cbd2248368349c4d"
@@ -4154,7 +5788,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_RISTRETTO255: &str = r#"
     "credential_finalization": "c91bd87321750f09a087bd410e4c795b1711d89a253daf76b60e202180e580e46383e95cb1487f4a1131598aa1b6adb615add8428338ba79f43e98bfe201640563969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1a2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc606a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fb0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c13836282bbc9267e6e1e86ae3abcb6d0d835c67519c2c567f6bdc9e51d50036fa423a7d6c8d946cf1145c4b614b8537bb7e6ffffbae127d5ce0bd6bf5e21760863969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fb0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c09e8fa25c83883236fc742179bed56dfaaad3db3db12fbc55c26c879f7dfd8d1abf12c85c3a6d3b1794b34cf26b9689cd5f5937b971bbfa8b2210841cc7bba7913836282bbc9267e6e1e86ae3abcb6d0d835c67519c2c567f6bdc9e51d50036fa423a7d6c8d946cf1145c4b614b8537bb7e6ffffbae127d5ce0bd6bf5e21760863969c858c2ab814d1e6d3e608cccfb35fa5a298a1547413b53f9fbd49474d194c5efffe6171c761c6dcc17b20d5903b89a46938e8dec52800cc981b9215e8f7",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "b0ab56bc66268cb59a429db13559785e50a8b2f6f53b5343d37dd6804c4b475d98c689ccd1119208cc15711a93d675f8528c74f37513e36d86695545cc46db0c"
@@ -4195,7 +5829,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_RISTRETTO255: &str = r#"
     "credential_finalization": "e4aa126f51ea7f3e54591e5ab8366089ce34627c099f78a61ab1443262b13be7b97708c439560c2a8c5c406fd47800322901024267385da189bd26d6d1c6530a4a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffa2d5ba995f1410b7d5f97005ccc734e230c1a130e4e710d51561d2a1cdf9e705990dfc03f42284a4a9d5cf73fae1a8f7d721a2a7ea81d5af8be73bbb03efc6062488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cb69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedda1d18f968c0cb788e9e57f011b566d05312a2165a4fbb450e6f482100cdeb3382ceb2247d1d3bb9abb2aa5783fc480f33521b1532a527d2e2078dd86bbdac9cf4a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cb69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedd2c7674ca534b02ad7685bbe16d539a7dc1363725eb2f69a33f68a99b5ab8ae36109e9d2c2aa16757127db80f726eda164906bc849aab0f8bb94ced55204e33efa1d18f968c0cb788e9e57f011b566d05312a2165a4fbb450e6f482100cdeb3382ceb2247d1d3bb9abb2aa5783fc480f33521b1532a527d2e2078dd86bbdac9cf4a4057a5e85eb08d8553b0d8a6d4acc36cc2fd2565abc29518c3a22894f0fd03c6c0e08b04f646840c34fb1cb2cdaf76297b125733b2c0de81c12573c36d749b",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "b69894e9ecd6139f2e0a7f0d93025532ef8d57b21dfd8af4da1d740217b890e56782387c9d6f1db7032f2b2f8d5e1a08b88801982f6262ee071c3f2210f3dedd"
@@ -4236,7 +5870,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_P256: &str = r#"
     "credential_finalization": "3f4c116066badc5bdf22f38867f6150996d8d769fed1d8d1318517f4ee1b09b2de3061cdbcb2cb50ee441da3afd748aa54ddc2bd57576817475a89b87d0290031d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57802488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc548120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04db97577043d8a0ad61d191fa07df26e3070cc52d30f28bc67a46e3132f1dd0edc5a62542db6cd2fe7bb701c37553f4ac44d0e3f192b58436dba4c20ba084dc61f1d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc548120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04d6745cd3a537380439fe4232cc3a948636a079e6ad1b18ee41fe9a0c171ea7453b97577043d8a0ad61d191fa07df26e3070cc52d30f28bc67a46e3132f1dd0edc5a62542db6cd2fe7bb701c37553f4ac44d0e3f192b58436dba4c20ba084dc61f1d468f3d2c221eded5277d7918a51828783cac6c8e0ad57432d2af3a49f743ad",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "8120dfa295ed9a0d3a200e3f4a2eee4c6270df435cccdf556dc885d2c806a04d"
@@ -4276,7 +5910,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_P256: &str = r#"
     "credential_finalization": "c0fd61f63e7ca2043838a94eec9a5a5354da560078e0817c181c30192e841b6cc345425c6f52b044b0a8c8fce2a366c4a0b2891f4cc0c7d389a39d25f7fcc807be65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b9393584c96649f32280bd9a94bfb8b75758019dc6a919c8166dd77b93cd78385b2384730b8892f5d2d591f1b3bb2354143cd45d461833e5900e256e1cadd0014abe65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b956b213d4505114491b4ca39cfa857677e5df595d3c5632a433182b1f4214617d393584c96649f32280bd9a94bfb8b75758019dc6a919c8166dd77b93cd78385b2384730b8892f5d2d591f1b3bb2354143cd45d461833e5900e256e1cadd0014abe65b45c9d49bb9b1669cf077e8b9759a8b5ae9c9c2be0448eb5b05e459bb5a6",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "b2a2ff799f181ab30e066d175ad0b1050ccb1816628d485508508bb44271d4b9"
@@ -4316,7 +5950,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_P256: &str = r#"
     "credential_finalization": "635efab9e5e081fe502ba61656653e388a7db42692fb0f39229914f685b728dd75c356caf253a03ea3dc9d74eea6600be6334302c636eac28522d07e46cf410f9b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b5780a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e839665471d23cc0d7426b35368b02aa90ada71609c3afff9fcda6e961c407a008cffa472a6e2529c0566e2e6c68c9ccfafc122118f04dd19154bdf0b79aade4bb9b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e8f0fc3fc1a8594d5d8e1da3cb8259bc32152e766ac329164389ebc0948f3ebcef39665471d23cc0d7426b35368b02aa90ada71609c3afff9fcda6e961c407a008cffa472a6e2529c0566e2e6c68c9ccfafc122118f04dd19154bdf0b79aade4bb9b8004a4cbdfd8da4ef272fcc3079eba96d10386297c086c73e510e4b24f6ee5",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "5cc62b04868b8c62d4cd38ad5fee9d7d9253dfc246dbdaf42528be76a10678e8"
@@ -4356,7 +5990,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_P256: &str = r#"
     "credential_finalization": "6889cfb64d5e238d2a2e7b592ee63c8c7df6985a38c68534a9a1b3b561f48ecdddc6611d57cb7474ea6aaa3eb3ec1ff2424e52909346ed8aaa0e0600bfa70906210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03bf5d24f2e9291ee278198014d7e0002395d892b25ea06d108456feda2fddfc577c1a6aa69ca785116ed21e536b39b0c1d203ee76399db89ac5db2804307b57802488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f22e02396a10f396db8bceaa4faf1242f1c7a87597730d5c815cd1acd39ca4afaaf760d603f3d64a4e686c1a07dc549994830a4872dcd82ac2a2ac176090732d934210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f2277f9ce7c81ca1f5ec1f71127d73b97ca62bec5b69e1f0ee83517bd0aa16e383de02396a10f396db8bceaa4faf1242f1c7a87597730d5c815cd1acd39ca4afaaf760d603f3d64a4e686c1a07dc549994830a4872dcd82ac2a2ac176090732d934210d7a4a438c45c1dd37d1113a4e982f302db0d5cb6dbedec3744652cbae6465",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "51529ee0a57bd2744a42c467f7e9d521a3a2fb49b3ff5f207ef6cff3a9949f22"
@@ -4397,7 +6031,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_P384: &str = r#"
     "credential_finalization": "a0cc9864bb0a919329669127409447969b9ad40e922665b6c0275f7f21733247da21ca54d77c3291b913d7a70be41fb5743598f4f71e1b777194174bf08c5d036109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02715ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c02300318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff56d083cb1619798759c53fedf35c0230",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b0016c1d1465d66cf60294f588b2f0d86380edf7356216dc815fea507a483b61f15cf76262023c5391493a71be486c3d68584452a9bf9151a48afbffad5e33ad52fa6109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b00134d884829c287bed78d9ba00a43b0d95f7dcbe93aa3268e6cea69cce448b15a9d31061faa7059c975ccad53896b0be9c6c1d1465d66cf60294f588b2f0d86380edf7356216dc815fea507a483b61f15cf76262023c5391493a71be486c3d68584452a9bf9151a48afbffad5e33ad52fa6109c35f17460771df2c71afa4afe792e4f4005c47fd40b46568b54c96d9bd9361673160395785adbbf84cd710356622",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "ab88beaad339535602e6a1568f4cc1a3b3fe6eb0f938e1bcc6cc7a0e75ad6500c353c312e0f469c1084cca7788c2b001"
@@ -4437,7 +6071,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_P384: &str = r#"
     "credential_finalization": "2d58c87a5a9235996fca89cc445d7a0fc6626152086a8e29675d5f9399090b3edc69bbeaabf62e47020a0a96996044aca867828d64ccb1aff62b8aa999fe5e02dc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e02ae70c57ab92797d036d86c82e14f61a256b35c2c51f579efa84a28121171ed6a28228e1a8de7a5c84accc72117c96b279eddfd06d81720bdc1304a9764f71d4d321a7958078f5395952f6d0f1ee33406a59362a0669232c56bb028976f9aad26f5762fae1859a5f2873131ca4e1fb52eb3128efa21e976075260a2c2da2dd98e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c26bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90cd0891e5afbb05efe56dbe4b6be79cf6b2d0e39635edcccde9ecdfa346a49f0f7f33389fae2fdc186923a43983cc65216a4da2fda62fc4eb4603a08cf4e2c5570dc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c26bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c7345b1a61451a7a0c0fc2574d8eb07853a4d8585e224789d5b0a5d118c101cd5a282d4e600554ed26e34af200e85ed97d0891e5afbb05efe56dbe4b6be79cf6b2d0e39635edcccde9ecdfa346a49f0f7f33389fae2fdc186923a43983cc65216a4da2fda62fc4eb4603a08cf4e2c5570dc76282f187574d00096853ea4e9701f7e12bde666e170c1ca69d6c599d384549f02cb25f6bbcff5dd746d0e7538f72a",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "6bd2dc872b134b1021ffdeae01871b36fa9b50491b6f0bb4f8e0acb1a279fc8f848024a28e424c60d29bef45014df90c"
@@ -4477,7 +6111,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_P384: &str = r#"
     "credential_finalization": "ce52ce804232f09e268548f82a8b0581782d288249b2a8b47913a2bcfa0138260df4c1002465065f398982345e27812157e48a962dc63dbd0e22fb0dedabde025a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f02f986176f402e948d572ec6f31b8e64c77c78f2172b17101ce8191bdf9af8f2e1b263d0fb12f8f2246fd3299caeddd6cedf5e437032c49f1fcf8428100698765454adc2a13025cfb3e6b4169f3eb8f9e2dd5137e0ce725075cf45952b1e0b1d17fa3effbdef9513c06e6f215a5cd525d116a8c3715be95c6635e1035848bb190f",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d0643932309f916d976a1a3b4df8a4491bd4b75381727f854056e73ac71b23e1021a660771f94717dbf245c80d4fa5a9d84db256d1ede465f643011d8b4f125b4ff85a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d064861aa68c4a54118b068ff71d897d291a01d9c8d18ef5ae7ffe7f14c049427a00245f8ec2d864776c61c301450df777133932309f916d976a1a3b4df8a4491bd4b75381727f854056e73ac71b23e1021a660771f94717dbf245c80d4fa5a9d84db256d1ede465f643011d8b4f125b4ff85a59c1f7c6a6eebdd16e089fe715424e4a2894601ca554fa8c9ca6f34a751ef20b18958e99c8d18596ba79665bcfe795",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "669f89979ae861ebbb1a9e372d5132bb0d667e2b027785a9ef2c906ad4b452d8cb9b198690b09462ec67356c7253d064"
@@ -4517,7 +6151,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_P384: &str = r#"
     "credential_finalization": "604fe6696fcd30ea0051acc81bd04ed05a7a48d233c3a70dc3dac64e086b8bd85d9f8c8b486df40dc1605c2508b5fcaa3725cb92723f4a648aa2059a44d9e20bb54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a25518815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b7550318f4494b00a15cd18ef0e32b9f3eee8a6720faef9ae33c3220b4fcf9b03caeb5cb775a40a279d2a5332f7f8cc82c23983313ae07d92a19c6cc24cc9c950bded1cfec52b5406d201c54750d4a800858d10b9547c712d76f87136fcb2e5090461815ef1a944a13fdc17c61eb72825d91ff0116f8f7d26a7c58739c92a79677b755",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388f3965410054d6bf7f2d323314b1a64aedc74e652fb23741f955209ae020ad2d4ba4cbe5b6a386605212d888a6a928db048fc834c24e194b7cac4d168152800969b54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8cba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388fa1b84cc583694876703155fb022705f7f488165c5dee167e768ab61d0c3e00ac482c12126f6455b45a22d37da74665193965410054d6bf7f2d323314b1a64aedc74e652fb23741f955209ae020ad2d4ba4cbe5b6a386605212d888a6a928db048fc834c24e194b7cac4d168152800969b54c0b725a9cea3288eb9ce4f0336aab497f58d92cfa5ed46b6a15bcd8fd8425f5bbd0bde7ce4feb3205505da2caa040",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "ba0ead8d155ced5b6864b312ec5c1392b74fd5f09d38e6bddff8b155fa97776d5d14920f4087ce8863e3e3b81d5e388f"
@@ -4558,7 +6192,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_P521: &str = r#"
     "credential_finalization": "47fba1d869c584f5f5da4317263909c14e5fdac122a80ab7c108ed379adbd0f89c6fd067761aaa9d524e73716ba19ed4186d1e88535663e309cc196e66fe4d05a8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c02783cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d02012378f671e45d420b70fa89332db18ad45595fe41947e56647503a2d52c3f1de2e99bcea3d75fbeac061ee751b504020f395f8b09a7ae6ef9f9cd1dc576c6ecde0701ea73f9f3077cb50a43d0a54750b0e108e639de75c093af4f07e5d2fa01444428b1bde832808bf5879ebd943b935a3812d9dd22d6f1dd21cf2d327fd6bdecdf1b7c83cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80be94d",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc546fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4faa70fe500544431a739cd28bdff14428746ac173696672a7b3ccb20cd0331d0fcb942daf0530c7d420cfa0816da5153fab3766bcf663081b6871d669c4d0eead4a8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc546fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa63e52d468c7098a8a1e45912deaba56db32e97aa8c534f7757b01506b06ed896541f26a1c13650585f0fb6955092be1d0e5fe7708aa0074497f73d8fc2c76f9aa70fe500544431a739cd28bdff14428746ac173696672a7b3ccb20cd0331d0fcb942daf0530c7d420cfa0816da5153fab3766bcf663081b6871d669c4d0eead4a8dc6b9778363b9a49e270a4c557f655f6c2540a54ec6de296d4a91c7618dfaeb362166a97692a21da679ed746baedac6b8fe4d540f1881e8f29c5f161cf11a8",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "6fe4f6c48aaccaef7ed57417bcc8ceeb5efb7847c7a5a641a55e821c4616e2b2887917bbca4faccec20b3c09213ea0203affe3af0ac2dc6bb9c4efb3e080a4fa"
@@ -4598,7 +6232,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_P521: &str = r#"
     "credential_finalization": "23ef73a09ffc30ed2ef0731e4d1ca24d360222ace2f5ee13ef1bb29b7ee261028acdb1546920c698616fd1793c4db7c16aa66511863b3b35e7d13af8f5b4200897ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc658efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d0301662d8b2088257e6eade43e94db7ab5bd458c244b21c25fa6804054ecc72d00d1d744ad7a1c2fb40e610ca5f5b9fc0772226a41bfb559a1c1c015fab0f39e122293007df96864682bc22266cfbc73ee38e3a244c07926fdae7c0973a0fab994182bc352f845fd6be6c68f9ae0b3f032169c61e5e3eaad370d3c92d4203665ae5c38394a8efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238e94d",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d9648f73ca129d90867ff904ec008f8f32e4efc6767002ec1a619c2da7d3e22fb70f494e0b5fd59c2b4cb8aba3a7fe440d2d65c7dd405db7cd93edfbbeef81e393697ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c2e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d963d13e9fc772f75d6e0b22e1a1d8eab2146eadd636d7f8ee0ecf19af3b65830a0fe38f6c2a9a5bd610bf072cf4b50acf003f9d9e9184e5dce72e806dcb018b8c348f73ca129d90867ff904ec008f8f32e4efc6767002ec1a619c2da7d3e22fb70f494e0b5fd59c2b4cb8aba3a7fe440d2d65c7dd405db7cd93edfbbeef81e393697ed7b1cee4944620e472b71f3826a7d2abe53139feee9062488a2a3ff1866c2c62f494a875cd564945b13e72d720c691aacfb468a3deddced16c3c48a4b08ec",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "e727eedc3a9e046ea434f5c39a1514b1c7a0724dd64d5160b78565396312e3e7225a3b0051797271a6023aedb92c5fa932b71a7d4bed63970123016722840d96"
@@ -4638,7 +6272,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_P521: &str = r#"
     "credential_finalization": "4d5d81d25983017051bc0fc9c58e2fe57fbc1099719ebf0fb9198b60e4c70c49ec6ed6c883a12799ced9ded067725f81ef9fe95eb12e60d27cb804923754a3038b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd1980300d04923f9ad269e790ab10ee01732db266047e44babf0df8e5951773c47f4e09a3101df1441d5aca2c31826639a031e063cf730c0b96ea0edc845de70166a6638a6010a3969d61d3e550a5105d2796ba83df8d106e7be9e4fda546c5ff043efb20b19218f7068977d91bf78ef891d2fc5eb3ed32e09d3861452b6ebf4391ecd2b87e521c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c8322366831a831515db56b5e7df3ece511281a252f830e950d6a1e26ae90fa6920b05b12478186217c52b7dc187b3ac0f79b60c4bf7d2e32882798c80708e378727ed8b98b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c8322366893458340843bd506e942bd2cdebfe9102a5fb2aeb6c835432494f108d7f6445236acf215615460b83ffdbbf892d0ba8ded2a3e9fe08788468a50b02c1260737d31a831515db56b5e7df3ece511281a252f830e950d6a1e26ae90fa6920b05b12478186217c52b7dc187b3ac0f79b60c4bf7d2e32882798c80708e378727ed8b98b51d371dcb6295431a74d5566f9902f1d079f834847ff84a10bc68ddbd8992fce966852a46324fb426ed8048f826e959f2eeaeb6a15821a81fe910ef8a9abab",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "9b90280589b72fe09ed11d257472d6f494cc38a4c783b7be8647dd2c901812977c55ffb22ed606df7ec39c482dcbf1a0012764f153346c16eb98143c83223668"
@@ -4678,7 +6312,7 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_P521: &str = r#"
     "credential_finalization": "c1531815103650c3c75affc9e63a8c0d0fe167c4592b04fafb521a4cde7087f1baf8af3c8b5c2b2555fa1edbe345bc6ffeb4b52ff52c99d2429de2c11b87d809b191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be02004b74b693fd89f7109a9b035ac8c725af2b16495d294edc9c60820f925fdb78d33acda219505aef2e7ec392579e3fef4eabf07c712a26a50b21817261573cef738001364c3eb9a701ec11a2d8281f19a314ab09c4d054e39d43ba98d74919de1857c5b4da90b204107e7dd605b4e8c97d505ab8f21ac29a8220ae2d191a750f8001e517f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77be",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8ced907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7f2be27a8ec0c7f67c3d7ee0c889e893c29668f08e4d3f414f78ec22a35992a047eea68025a4826c8c543a026bf7c99b185f80ff8e48b7f100b008a04e732640fb191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8ced907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7dbc8f0843b315e30bddd32b33bf7bd10ddc5f61f03cd609050a1c676c44e0aa4ce64231b274ef583c186da6d2547410b27649ba9be7d949c56800e302e75c4c2f2be27a8ec0c7f67c3d7ee0c889e893c29668f08e4d3f414f78ec22a35992a047eea68025a4826c8c543a026bf7c99b185f80ff8e48b7f100b008a04e732640fb191908f7e06543be900aa74f9f5c60e4806d5fa611a376b4032286ce13126fe41fc9682701e713c4ac2addc83722e1f1238c62e900dfe1b8f71151f701c2ab0",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "ed907b02cfd753bda3012effff5800bd48dc1ff1005ffa3f962f27317a013db3ee79b7c57420bc4609e340e8dae1daee29c2b82f73a928481a767522ffbd36e7"
@@ -4720,7 +6354,7 @@ pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_CURVE25519: &str = r#"
     "credential_finalization": "40e4f3e28df96e5a445bc4b56012ce4112d1986eb2bf51fcc445f35eb1249956908fd0d63ea38add5bfadbebe69b92c6583dda6c20980869a75060702132a6022901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
     "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
     "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc540f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8be6815e2aa95cf289638580dd7fb5b2c62406319707dcfc2848771c8161b881af240a37ca7fcc312d7a24d771f96489e5174523a5f89f37ceea503cee0888ad982901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc540f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8baa417dcdaa8f25c2d369cf2a2c837504a6be7ac4c5db2dc7dbabadafaad7d3780007f81e344794a549d8b5049cab83c8754d561076dec51c04989fc6754b8624e6815e2aa95cf289638580dd7fb5b2c62406319707dcfc2848771c8161b881af240a37ca7fcc312d7a24d771f96489e5174523a5f89f37ceea503cee0888ad982901e4bcd3c85c79fde509a8607005780974d6d3008d5a8e504a483b4ef6dbc2118edc1ab7b0618326e63066834ce10f922ec4d54109a9162dac5a7ef8fca56f",
     "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
     "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
     "session_key": "0f2c639ed353b4bb3033b8d3d83f291d9dedac2b9529da769b79356b09de29309894c3f860b9ef20e6fe74d67eadb836cfb7929ac4173e6dc5e91b6d46791e8b"
@@ -4761,7 +6395,7 @@ pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_CURVE25519: &str = r#"
     "credential_finalization": "dc08089044ec5831e3fa7ba6fb300193060ffe7eff56b832701b2fdcf72a8182f10d997fc21e2578b9155bb259c80ba3f6a346a7e0ddb34a6a3763e5412c1c0a5d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
     "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
     "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52eae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87bba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
-    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c201287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2e03089ba31501b5482b28979ebd30e12e1aa69b334948ca287cb103a34535e8d7b6529bbf240849e7a30ff6c7d594374b6949168beaf0d8c0e9ed212813764e35d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c201287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2c3b26ba80adb18d326dc33841c923041e16e87221265bf7c8f62076bac06e28c7febf3b44d180077e98fd1a224e896a50c194cb8f68dd476d650dd02b65be524e03089ba31501b5482b28979ebd30e12e1aa69b334948ca287cb103a34535e8d7b6529bbf240849e7a30ff6c7d594374b6949168beaf0d8c0e9ed212813764e35d687cc0a0535194f9e172d16332d5ebd2ee3f76453dec4cfbe8b5c5f5102b097b265d6f1e83c10f0cb74af233e9a2b2f043a95259e59a90510f7b745261b862",
     "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
     "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
     "session_key": "01287c5056979dbe7a444fa87d9762d31d381f9cd3574efaaa0069bc659bb1df93f469beb3dff75a77d19c03d2ab9234a8f8fa6fb337f1391f412acd66ba26b2"
@@ -4802,7 +6436,7 @@ pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_CURVE25519: &str = r#"
     "credential_finalization": "e6fc1e46c7d266f303eb039d2805212324ca9905b81b3b50e32bf6d405dd844a0df307c0d1da1c136ba9998c26ab6c7928f2465da46bf90b068e825feac0f1022fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
     "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
     "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1ae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87ba4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
-    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994a1844b323938247b38dfb641fc2a7b884c667261f9f607463b2cc6a104c478a5730c9800e055340a30be66802aa5ac0c4f43afe040b130c90ecd660cb9c8650c2fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994b3f6fee3ff55a62583bf2fdcbf84faa7162a52bfa252fe83d07270b3f0b80904eaa86446b301ef5ccc28a5721519c585c31cd5b433eaa356253017fac8b03475a1844b323938247b38dfb641fc2a7b884c667261f9f607463b2cc6a104c478a5730c9800e055340a30be66802aa5ac0c4f43afe040b130c90ecd660cb9c8650c2fe0888abb8bbb112a39df8ddff11c71f02e1d3c63a39b9b17731e58e6a86e1d59a1c0a368748b1aeff0ca6acd4a31271dc4fa017974f9c09dc6d388c562beb0",
     "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
     "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
     "session_key": "4e7198d939bba66e83f836a1b4ac07ee23dba5650d3c472aa4e7d3d7bae876a36209bff189341f38a17c07cd16ee2da25c3278d615feaab7f3b6c961b2e33994"
@@ -4843,9 +6477,174 @@ pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_CURVE25519: &str = r#"
     "credential_finalization": "933d659485b114d7a9dd90e89ca8abdde7d005eeaa4f9355e548357476390a4135db815ca326d9908ee20cc3bd548edd441a528f3ca905a84612cf979b64590c3c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
     "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
     "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ffae7d9883673c35d5ea525d87c80cc01cbdf88f09bbc3badb49fad9d171a7f229e84dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd87b2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
-    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76ae62fe719d0182ae8f72e01f603eafe7f50a010e6f5ddb732ae06c229d72072c8f5d1fb65b9ea5031f48d9541331f096f3d0b8c2c9009c57071486db75522febd3c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a5d5d04eae7ead2bb731b62ed6bf74dd8c46ae015531a05cb8cdd0fbb0e285bdb366a4d9dd6424e1dfb3303da5aae02cbc9919f6711ddad1d1b4e6f2d231a3b1ae62fe719d0182ae8f72e01f603eafe7f50a010e6f5ddb732ae06c229d72072c8f5d1fb65b9ea5031f48d9541331f096f3d0b8c2c9009c57071486db75522febd3c8544597cc17374e65269d3d975d2f2125d88486e716a69e2abfae649091b4ccead818099bd157a01e8bf8b3c8735b6de363b2d4ca7efe0ae134d32b0ad0c5d",
     "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
     "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
     "session_key": "631095b39b65caee09fce27a999fa97e50070feb5be6809dec67709e78ca404663340c9b5040c20c55bf89dfca3fcd1c509e8713d9f72a917a30ccf99b51b76a"
 }
 "#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "secp256k1")]
+#[cfg(feature = "ristretto255")]
+pub static TEST_VECTOR_RISTRETTO255_SIGMA_I_ED25519_PH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "registration_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
+    "credential_request": "68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "dc21e4b8d4d6d7e72fa29858f2765693202f15f72d6f7ece3302b44460553855c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfea395d017cd39b0c23388c9706337a03ab15c300ec86f45a1a3640b793d26b2de6c733e0e6bb91285101d04b5fcfe0f25bfa8b402f7a40a768421e24b7eee080fe6282c1df1e2adca2b342803bc1c7f0f7c981e1c37679b2c3160441f989766aeadedeab81118209414bcd9f160fb502b801b5ffbe9a2dfc3c62f709c852762e64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084e1aa8d03d9cd11322a5b98fa2ba3f3b9f22e7fe451b6eedad24a88756573e679c59bf80998c542a56190c3c01701b84027dff1f248dc11f515868f0f6307000929159b95bf6be541329b1003c67bc2db65cc1af52a1913b51977a2bc581018d3",
+    "credential_finalization": "19c0435e05cba19fba6aa9abc3ada6fa203c33e650aab951037e145cb835e092b1bf32454e5c172e1cb131834a12b042c28e48af95f3971b3e2a908c7af5ae089646bf9955fe56510132d31b4af2b82c4eb3202fab1bf056b6ce2e31f5a6f104",
+    "client_registration_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c027",
+    "client_login_state": "56d083cb1619798759c53fedf35c0230c53769bf817ca73d29b35d8b6413a80b68b6a3bd4f52fb44e68cec8d259f806f8fc676570137775ce8f61c1ca045c0272488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b352488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc54a28a665ba0c904b64c7ab031580872a75d2fc45feffb4360e1f2624af5b7dd1a0b5561bda6c53a57e9a76840ebc94fab64d678f8f052a2574bd83dbbc5ba4c8169ef29c04d291602f9c9c23b0aedcd9949f00db539530b3563401b458ef3b40453b51f51de079e28523f70bfe611f12751565a195438f3676e5fbb44193fa3459646bf9955fe56510132d31b4af2b82c4eb3202fab1bf056b6ce2e31f5a6f104",
+    "password_file": "55e01d34240dfb3fd72e8002a9e6e56736504b4cf6de3f329bb8c2e6800adc542ddca3f4ca1ba199025c9f490d35c1f93f250110c430331fe4761df3e94532fdf5b5f91146c69501203faedbe93b04e0c4204f995eb0261ba563fb4e3ec7abf4b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf022574de1d740b3673d3e6ee6451eb13001c5ba440a4a1bcdff09f02e437618c06e8d2f1a5ba82611c8bb0e76f78886019984293577e111e28ab3c3b007154d8209c",
+    "export_key": "3777603659f32ae9cef33fa68fce451a56e593572b8de021b9e566da789bba72a1d549fbc8be869d7ba7560cd355458e54bdea09bef9f7e46e3f4c9469edff48",
+    "session_key": "a28a665ba0c904b64c7ab031580872a75d2fc45feffb4360e1f2624af5b7dd1a"
+}
+"#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P256_SIGMA_I_ED25519_PH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f",
+    "dummy_masking_key": "83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "masking_nonce": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "envelope_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "client_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_sig_rng": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "client_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "context": "636f6e74657874",
+    "registration_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "registration_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50be9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
+    "credential_request": "0389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "028b9e1cc6ad67bb2142936f53f9ab0aee35db08f4316bf33ce0b1b46372adc50bad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0ddcb01b113293c602a6a45d8fc6b4f5322fa87e0273477d63d2f676aa5bf577a36472a2922a75beffe4a9ee066548eeb57d72d90771d0c0414ff921b8f478a8193003b6e8fd3ce5c98acddf68502ec4b903c7f4fd6127df149da57381863f3dcd564ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a0347b5482eb299862fa178be16a04704d091160af3240507100ba7f4c1efdb89fa73f9243d72f5004c08b0097a9051e0956403ddd61e31c90ea286374834c254eacefaa3efcee3b1dc343191db1084eefc7ca74dbd3c36d102329fd522a18cd300e28ed819342d57c29aa7a764e40280c7a8ff7c0572cbe5d9968416d5f92792c8",
+    "credential_finalization": "f9939718a85515a354711d4f314d5334fc04c8e55713b6a49618cbacc96aa8fdcc51a2a2748bb699164cf70ecf1671277d6e38ffcedc4e3375ce5b8872519903199ce9b45fa5a9e2b7cb6a3ef17d4f1aea32a7c1a97d9580b8873cdaa85d8cf0",
+    "client_registration_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65",
+    "client_login_state": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f212380389554a672d6d40ec3f70af1d02004d459f3f6b04f821ea4179b630e0c515fc65ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "server_login_state": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c21ff09ff0b8b2a757e9c6f55c2a6f6b2557b0b7a5f76dcf8fb6fe239b94f0818931a740c5a03842798164193db0780787d1dbf368af8fa0a31ca7ae57af2ad0c479b9777617d026ceab9368b83c7f7c61c7be9d30ec7751351156f72f4b79878adce8132f527b3e9a813622485af911e1dd12eb90c3a4505d128b475c111db656199ce9b45fa5a9e2b7cb6a3ef17d4f1aea32a7c1a97d9580b8873cdaa85d8cf0",
+    "password_file": "443dcef69ca9fea4fc74ddb4aea8dbe06c5c84d741978bccc052dbee862525c24226853714b55d305621d2ff4ec7e8f51b9040d2b97fe6fbf524d2ebdf1f27fab0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d1de850d3cdb9b040e501e616e1496dc9728e1c2d85aa05c875195953a0ad70",
+    "export_key": "8559f9a065145f65abca6b290743cc1fe932b186bd10aeec1558ab6827f7d5f0",
+    "session_key": "1ff09ff0b8b2a757e9c6f55c2a6f6b2557b0b7a5f76dcf8fb6fe239b94f08189"
+}
+"#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P384_SIGMA_I_ED25519_PH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf180",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6",
+    "dummy_masking_key": "f986681ea7c95b482711cd1540bcaa83ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd",
+    "masking_nonce": "698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedf",
+    "envelope_nonce": "ba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "client_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_sig_rng": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "client_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "context": "636f6e74657874",
+    "registration_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "registration_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
+    "credential_request": "03cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "021191750af08937398160e87d7904d3aff294cde6aef2e604831f11d91d9c7a51dfb040d798351e5634ee25866ba5e8d7698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d138a052ae0bce4565162a1571b1e8b24ca9efa149181a1641728e79cdf6ef54abcb7312d9633cd83795ac2c0fb1c676d82e8aeda8548155324ab361423e3ba8c5c883ac92eac75e5572d45ad12877d70bdf645ea008417298f5d3b485939a1beda9b4b5e2c122c1f5a498dab04ce317fa264ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a03f31354cccfd669b8e1fe01aec99d06e03fddf3e78461dc10f8f58f20d050aaa867594e930df058c53be333b0ff52df5d590ae3f5bbfdea693c840cdae459c4d35fef00f85faff457394267e49180173c5c4cad47182f614c0eab3cd454efb70afcbd59476be9686fb99d76d7dfff732379136878e9273e156f7831da06334aea",
+    "credential_finalization": "3753857e7413e24da856fd38b954cb371e72e741b0a4e2c701434b2932bc7ae1167fc424403710fd84f573b564c7beb4368c79983e95f0d78269f82fc0cc52071e5734c2de5f389016c1e296a52266676e6da4f00a24068ad9ee6d184ee91513",
+    "client_registration_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5",
+    "client_login_state": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5bd198aa66d474460a0959f960309cf18003cce770cdec2d2fe346969374bb727b93d194c261bf6d6a65bfac528ad052c6ab1f3676bb0836a6ee4fde74644c3ed7b5a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d103f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b35a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "server_login_state": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085fbdebd82d957ab3fa2c1b1ff2b726314ce9eae1dd990339617edb4702f7aa84e5ddcff719b26ebeea6ded4bf59af97cb79b08bf96a8bd84afcd69dda33314b6ac7c6ee9543736bc573fa9713fadcc940d7e9eb462cf91af9204a965991344cfd5ee98ca9f9d184e8cfe6436bcf58480cf0b619601a8e7bfd80f3530c4bca832cd1e5734c2de5f389016c1e296a52266676e6da4f00a24068ad9ee6d184ee91513",
+    "password_file": "966c1bbc8fc0fd70c2377ef84b75c84208e32140a1f5f1843c1d318b3a22085f4b96697838ade27288f15d808d59a2d801f84422417515512c8e097ffda9836ee243ee9da1217022028a7c0256017e8cb0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225edd66cb82c9388fedffbfd5cd1cbd04cc623dc61a8b4d4419d0cf6ab0567eae0c6ed14b948651bed0d9e3dc4d16694ad",
+    "export_key": "eedb6683b3406cd13ff75af7acbf427debc32d81e00ea4702f51d15cc47172b90102ff2bf1adaf915875a0ae8fa63a92",
+    "session_key": "bdebd82d957ab3fa2c1b1ff2b726314ce9eae1dd990339617edb4702f7aa84e5"
+}
+"#;
+#[cfg(feature = "ed25519")]
+#[cfg(feature = "secp256k1")]
+pub static TEST_VECTOR_P521_SIGMA_I_ED25519_PH_SECP256K1: &str = r#"
+{
+    "client_s_pk": "83bc4ded8ad75265e437d343952ece798e23ae1fe064cabc38586aff30d06072",
+    "client_s_sk": "b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf0225",
+    "client_e_pk": "b4a93678d8b55383db63926de87cfdd844b32e2de65528c2c5cecb20265fb06d",
+    "client_e_sk": "e94dd791cb2a3f5151577f1e26e3d9592efd5f91e5d6c039bc2a35eeac1fd8fb",
+    "server_s_pk": "e9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "server_s_sk": "426e30dce8dd4e11d946d4d60bab8ebfcaa9b55b3c172802dfa0518762ea8144",
+    "server_e_pk": "c2b3d747e899353d2831b3b68efa6a5d8d3a49c79f12dcce3df9c9ae3a4ba00e",
+    "server_e_sk": "64ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a",
+    "dummy_client_pk": "257dd1514cf419c4ab0969cef6c8b960c17aaa9542f57b3f693e3dcc862aa302",
+    "credential_identifier": "637265644964656e746966696572",
+    "id_u": "696455",
+    "id_s": "696453",
+    "password": "70617373776f7264",
+    "blinding_factor": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc",
+    "oprf_seed": "519dbe4c91dc74d173a34505ea59daaf0c1426d027e21dd83425cfb1b644991f83d121ef67bef278e93f0d1026ce1fc6f986681ea7c95b482711cd1540bcaa83",
+    "dummy_masking_key": "ad3739f1839da9e267c8ff89e58b166a98e3f3e430864ade512adbabf1f1a0dd698eafefb9d5bd2144108894409784469f9f0d8056cd77b7e68bbfdadf240d13",
+    "masking_nonce": "c8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfba20f7169fbdb36c5c93461c192e5a9cf5762fae1859a5f2873131ca4e1fb52e",
+    "envelope_nonce": "a4a4f65a42b4181ffb2b1f907b68b1fdfa3effbdef9513c06e6f215a5cd525d1",
+    "client_nonce": "2488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_nonce": "d24b9eafc0924c0f66523516c74f60425295a857618fdd85d35f62a1b482e182",
+    "server_sig_rng": "b3128efa21e976075260a2c2da2dd98e0cf8505dcb05f4e44c87ca6858f21238",
+    "client_sig_rng": "16a8c3715be95c6635e1035848bb190f27736bcbe9b69fbde386e0271708da5b",
+    "context": "636f6e74657874",
+    "registration_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "registration_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4de9057d8c0b9b2757d79d4ab6a70f4add0838c1034bdb93cb2faf6a5cc149b619",
+    "registration_upload": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
+    "credential_request": "0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c8",
+    "credential_response": "02003693a3d92a391990b42a68cd3fafa123901ce943b68ddbede6ca46d5b12db0038e471611f23ae06c397d5e504c4e66df19c09ac75fba23318e6265954264851e4dc8d9932a0a851f23ac477302256daa9745a55e695ae3047db1d1c901ae30bedfcc2ec2f699ffc97619e403d4abfc8eacdb0e574bf469f74dd25d0126579abd5c44242af34950e58bb0573dc0ce9931691b80c738d5f19ced469dbf5777502ab9c3b55f61d982d84dfdaea21a448c882689b0e2e2a9a2cbbe527523e062ece259f7e737092f4a22b8a46db47decb6b01a531a485f6bc0435906e1d3f7240dd2b064ff37cac3637214feff2c0a454028a78ffbf6e3397719965d42fd3f3a17710a038446ebb5f55b48cbbc3ffdfe12bde1e0d96e9fea7c2f58ca2b8453d546cc9084ebb1d481e91ee88afe4e409113b23d5c84a2b8667f7e306653e5c7c9607209565952e49dba8aa3b67d5ccdc19a5914314c1229eab8cf97984778d26b45bd8f0bb282a2ccb454dde783c71dc990958824e0df12802c510df4a976791832c6c210",
+    "credential_finalization": "ca0b55ed60a83e03335d80106ec9c133f74502fdd2257fe9efa6598be8433b316f14f1daa3d5041358a62baec05f005588e409f2a92e6658ba02dc2d31d26a06750f1bcce2a6ffae722bcc8349d9c628902e77972ab9136315e3b4f3956a0237",
+    "client_registration_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a255188",
+    "client_login_state": "0116f8f7d26a7c58739c92a79677b755283627f15538286319a03c6829c8695a77bed7570cd4597aa2fd45925e558acb346cb3f097e42bea46042547f576e07639fc0301f864fc13c07b2c9b2e0c8ba30d47a68debd95630d317b3f3eb527ccd8eb10c3a3b142ef7e9b739152ffb4a69173001c9a0ef90631e5760eb1ac38ca5d19a2551882488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff03f97adce689461a8b006f5d55e989c93d44e74efd73bbb97f43b3e47068ec29c88bafd79518e856009b74d1c7a0355562c7f73fa8ee832a345f150e0f9a984b352488cae978cfafe2d8f8804d73dc870915ef1a944a13fdc17c61eb72825d91ff",
+    "server_login_state": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c018d21827bd98f5261bda479457896e926c31cec6f2029a453564be46e7246e49e6a054c768e7c0396e4e0bc22e4bee0c0483395afae789f44388eef4121cdfcc2ed8db926a2adf4a91ca3fed2c6a359b94c837e0925968228c2001c43500c194b869c403c674cfb3052507bae794f46a1f30d2e6b95b0cbf5acbd2b96a0b640750f1bcce2a6ffae722bcc8349d9c628902e77972ab9136315e3b4f3956a0237",
+    "password_file": "0bef21fb1ebe905f660b2e07b6a11ec6ea9e066813de82c2360f219993bf8e8c5cfa314a3ee3ee21b972444761a82c945b3e731f32ecdcd4bea59587acaddf4456877b601131e205b5997243b58ebfb11aaa3a512d9eb402dffd1091059b7240b0272807f804342507ede38a92a491723ababd74e8005d79f5adc1263ddf02259d3a55735560670746954041d66162cd8ebeef490daf20a5a6c7281225996373613a493929046436f1c80adac644a91ed2f48f9b1c5fa3f79689777c277fc30b",
+    "export_key": "f97f3f73f74049f76fdff0ad98901807d442ad25d1c18ed4881cbe79acba16e197e33802d18ab62c6126c7fc92ee19c514c1b9ffca9c891e087306a416279967",
+    "session_key": "018d21827bd98f5261bda479457896e926c31cec6f2029a453564be46e7246e4"
+}
+"#;
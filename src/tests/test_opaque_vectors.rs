@@ -187,12 +187,7 @@ fn tests() -> Result<(), ProtocolError> {
 
     #[cfg(feature = "ristretto255")]
     {
-        struct Ristretto255Sha512NoKsf;
-        impl CipherSuite for Ristretto255Sha512NoKsf {
-            type OprfCs = crate::Ristretto255;
-            type KeyExchange = TripleDh<crate::Ristretto255, sha2::Sha512>;
-            type Ksf = Identity;
-        }
+        type Ristretto255Sha512NoKsf = crate::suites::Ristretto255Sha512TripleDh<Identity>;
 
         let ristretto_real_tvs = json_to_test_vectors!(
             rfc,
@@ -269,7 +264,11 @@ fn tests() -> Result<(), ProtocolError> {
         test_fake_vectors::<Ristretto255Sha512Curve25519NoKsf>(&ristretto_fake_tvs)?;
     }
 
+    #[cfg(feature = "p256")]
+    type P256Sha256NoKsf = crate::suites::P256Sha256TripleDh<Identity>;
+    #[cfg(not(feature = "p256"))]
     struct P256Sha256NoKsf;
+    #[cfg(not(feature = "p256"))]
     impl CipherSuite for P256Sha256NoKsf {
         type OprfCs = p256::NistP256;
         type KeyExchange = TripleDh<p256::NistP256, sha2::Sha256>;
@@ -485,13 +484,13 @@ where
             Some(record),
             CredentialRequest::<CS>::deserialize(&parameters.KE1).unwrap(),
             &parameters.credential_identifier,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: parameters.client_identity.as_deref(),
                     server: parameters.server_identity.as_deref(),
                 },
-            },
+            ),
         )?;
         assert_eq!(
             hex::encode(&parameters.handshake_secret),
@@ -608,24 +607,24 @@ where
             Some(record),
             CredentialRequest::<CS>::deserialize(&parameters.KE1).unwrap(),
             &parameters.credential_identifier,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: parameters.client_identity.as_deref(),
                     server: parameters.server_identity.as_deref(),
                 },
-            },
+            ),
         )?;
 
         let server_login_result = server_login_start_result.state.finish(
             CredentialFinalization::deserialize(&parameters.KE3)?,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: parameters.client_identity.as_deref(),
                     server: parameters.server_identity.as_deref(),
                 },
-            },
+            ),
         )?;
 
         assert_eq!(
@@ -676,13 +675,13 @@ where
             None,
             CredentialRequest::<CS>::deserialize(&parameters.KE1).unwrap(),
             &parameters.credential_identifier,
-            ServerLoginParameters {
-                context: Some(&parameters.context),
-                identifiers: Identifiers {
+            ServerLoginParameters::new(
+                Some(&parameters.context),
+                Identifiers {
                     client: parameters.client_identity.as_deref(),
                     server: parameters.server_identity.as_deref(),
                 },
-            },
+            ),
         )?;
         assert_eq!(
             hex::encode(&parameters.KE2),
@@ -0,0 +1,325 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+//
+// This source code is dual-licensed under either the MIT license found in the
+// LICENSE-MIT file in the root directory of this source tree or the Apache
+// License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+// of this source tree. You may select, at your option, one of the above-listed
+// licenses.
+
+#![cfg(all(feature = "dynamic", feature = "ecdsa", feature = "ristretto255"))]
+
+use opaque_ke::dynamic::{
+    DynClient, DynClientLoginFinishParameters, DynClientRegistrationFinishParameters, DynServer,
+};
+use opaque_ke::errors::ProtocolError;
+use opaque_ke::framing::MAGIC;
+use opaque_ke::ksf::Identity;
+use opaque_ke::suites::{NamedCipherSuite, Ristretto255Sha512TripleDh};
+use opaque_ke::{
+    CipherSuite, Ecdsa, Ristretto255, ServerLoginParameters, ServerSetup, SigmaI, TripleDh,
+};
+use p256::NistP256;
+use rand::rngs::OsRng;
+use sha2::{Sha256, Sha512};
+
+const PASSWORD: &[u8] = b"test password";
+const CLIENT_IDENTIFIER: &[u8] = b"test client identifier";
+
+const RISTRETTO255: u16 = 1;
+const P256: u16 = 2;
+const P256_SIGMA_I: u16 = 3;
+
+struct Ristretto255Suite;
+
+impl CipherSuite for Ristretto255Suite {
+    type OprfCs = Ristretto255;
+    type KeyExchange = TripleDh<Ristretto255, Sha512>;
+    type Ksf = Identity;
+
+    const SUITE_ID: u16 = RISTRETTO255;
+}
+
+struct P256Suite;
+
+impl CipherSuite for P256Suite {
+    type OprfCs = NistP256;
+    type KeyExchange = TripleDh<NistP256, Sha256>;
+    type Ksf = Identity;
+
+    const SUITE_ID: u16 = P256;
+}
+
+struct P256SigmaISuite;
+
+impl CipherSuite for P256SigmaISuite {
+    type OprfCs = NistP256;
+    type KeyExchange = SigmaI<Ecdsa<NistP256, Sha256>, NistP256, Sha256>;
+    type Ksf = Identity;
+
+    const SUITE_ID: u16 = P256_SIGMA_I;
+}
+
+fn setup() -> (DynServer, DynClient) {
+    let mut server = DynServer::new();
+    server.register(
+        RISTRETTO255,
+        ServerSetup::<Ristretto255Suite>::new(&mut OsRng),
+    );
+    server.register(P256, ServerSetup::<P256Suite>::new(&mut OsRng));
+    server.register(
+        P256_SIGMA_I,
+        ServerSetup::<P256SigmaISuite>::new(&mut OsRng),
+    );
+
+    let mut client = DynClient::new();
+    client.register::<Ristretto255Suite>(RISTRETTO255);
+    client.register::<P256Suite>(P256);
+    client.register::<P256SigmaISuite>(P256_SIGMA_I);
+
+    (server, client)
+}
+
+fn register(server: &DynServer, client: &DynClient, id: u16) -> Vec<u8> {
+    let start = client.registration_start(&mut OsRng, id, PASSWORD).unwrap();
+    let response = server
+        .registration_start(&start.message, CLIENT_IDENTIFIER)
+        .unwrap();
+    let finish = client
+        .registration_finish(
+            &mut OsRng,
+            &start.state,
+            PASSWORD,
+            &response,
+            Default::default(),
+        )
+        .unwrap();
+
+    server.registration_finish(&finish.message).unwrap()
+}
+
+#[test]
+fn all_suites() {
+    let (server, client) = setup();
+    assert_eq!(
+        server.suites().collect::<Vec<_>>(),
+        [RISTRETTO255, P256, P256_SIGMA_I]
+    );
+
+    for id in [RISTRETTO255, P256, P256_SIGMA_I] {
+        let password_file = register(&server, &client, id);
+        assert_eq!(password_file[..4], MAGIC);
+        assert_eq!(password_file[5..7], id.to_be_bytes());
+
+        let client_start = client.login_start(&mut OsRng, id, PASSWORD).unwrap();
+        let server_start = server
+            .login_start(
+                &mut OsRng,
+                Some(&password_file),
+                &client_start.message,
+                CLIENT_IDENTIFIER,
+                Default::default(),
+            )
+            .unwrap();
+        let client_finish = client
+            .login_finish(
+                &mut OsRng,
+                &client_start.state,
+                PASSWORD,
+                &server_start.message,
+                DynClientLoginFinishParameters::default(),
+            )
+            .unwrap();
+        let server_finish = server
+            .login_finish(
+                &server_start.state,
+                &client_finish.message,
+                Default::default(),
+            )
+            .unwrap();
+
+        assert_eq!(client_finish.session_key, server_finish.session_key);
+    }
+}
+
+#[test]
+fn ksf() {
+    let (server, client) = setup();
+
+    let start = client
+        .registration_start(&mut OsRng, P256, PASSWORD)
+        .unwrap();
+    let response = server
+        .registration_start(&start.message, CLIENT_IDENTIFIER)
+        .unwrap();
+    let parameters = DynClientRegistrationFinishParameters {
+        ksf: Some(&Identity),
+        ..Default::default()
+    };
+    client
+        .registration_finish(&mut OsRng, &start.state, PASSWORD, &response, parameters)
+        .unwrap();
+
+    // The KSF has to be of the type of the ciphersuite
+    let parameters = DynClientRegistrationFinishParameters {
+        ksf: Some(&0_u32),
+        ..Default::default()
+    };
+    assert!(matches!(
+        client.registration_finish(&mut OsRng, &start.state, PASSWORD, &response, parameters),
+        Err(ProtocolError::KsfTypeMismatchError)
+    ));
+}
+
+#[test]
+fn exporter_and_payloads() {
+    let (server, client) = setup();
+
+    for id in [RISTRETTO255, P256, P256_SIGMA_I] {
+        let password_file = register(&server, &client, id);
+        let client_start = client.login_start(&mut OsRng, id, PASSWORD).unwrap();
+        let server_parameters = ServerLoginParameters::default();
+        #[cfg(feature = "payload")]
+        let server_parameters = server_parameters.with_payload(b"server payload");
+        let server_start = server
+            .login_start(
+                &mut OsRng,
+                Some(&password_file),
+                &client_start.message,
+                CLIENT_IDENTIFIER,
+                server_parameters,
+            )
+            .unwrap();
+        let client_finish = client
+            .login_finish(
+                &mut OsRng,
+                &client_start.state,
+                PASSWORD,
+                &server_start.message,
+                DynClientLoginFinishParameters {
+                    #[cfg(feature = "payload")]
+                    payload: Some(b"client payload"),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let server_finish = server
+            .login_finish(
+                &server_start.state,
+                &client_finish.message,
+                Default::default(),
+            )
+            .unwrap();
+
+        let mut client_output = [0; 32];
+        client_finish
+            .export_keying_material(b"label", b"context", &mut client_output)
+            .unwrap();
+        let mut server_output = [0; 32];
+        server_finish
+            .export_keying_material(b"label", b"context", &mut server_output)
+            .unwrap();
+        assert_eq!(client_output, server_output);
+
+        #[cfg(feature = "payload")]
+        {
+            assert_eq!(
+                client_finish.server_payload.as_deref(),
+                Some(b"server payload".as_slice())
+            );
+            assert_eq!(
+                server_finish.client_payload.as_deref(),
+                Some(b"client payload".as_slice())
+            );
+        }
+    }
+}
+
+#[test]
+fn unsupported_suite() {
+    let (server, client) = setup();
+
+    assert!(matches!(
+        client.registration_start(&mut OsRng, 4, PASSWORD),
+        Err(ProtocolError::UnsupportedCipherSuiteError(4))
+    ));
+
+    let mut start = client
+        .registration_start(&mut OsRng, P256, PASSWORD)
+        .unwrap();
+    start.message[5..7].copy_from_slice(&4_u16.to_be_bytes());
+    assert!(matches!(
+        server.registration_start(&start.message, CLIENT_IDENTIFIER),
+        Err(ProtocolError::UnsupportedCipherSuiteError(4))
+    ));
+}
+
+#[test]
+fn mismatched_suite() {
+    let (server, client) = setup();
+    let password_file = register(&server, &client, P256);
+
+    let client_start = client
+        .login_start(&mut OsRng, RISTRETTO255, PASSWORD)
+        .unwrap();
+    assert!(matches!(
+        server.login_start(
+            &mut OsRng,
+            Some(&password_file),
+            &client_start.message,
+            CLIENT_IDENTIFIER,
+            Default::default(),
+        ),
+        Err(ProtocolError::CipherSuiteMismatchError {
+            expected: RISTRETTO255,
+            actual: P256,
+        })
+    ));
+}
+
+#[test]
+fn named_suites() {
+    let mut server = DynServer::new();
+    server.register_named(ServerSetup::<Ristretto255Sha512TripleDh<Identity>>::new(
+        &mut OsRng,
+    ));
+
+    let mut client = DynClient::new();
+    client.register_named::<Ristretto255Sha512TripleDh<Identity>>();
+
+    assert_eq!(
+        server.suites().collect::<Vec<_>>(),
+        [Ristretto255Sha512TripleDh::<Identity>::ID]
+    );
+    assert_eq!(
+        client.suites().collect::<Vec<_>>(),
+        [Ristretto255Sha512TripleDh::<Identity>::ID]
+    );
+
+    let password_file = register(&server, &client, Ristretto255Sha512TripleDh::<Identity>::ID);
+    assert_eq!(
+        password_file[5..7],
+        Ristretto255Sha512TripleDh::<Identity>::ID.to_be_bytes()
+    );
+}
+
+#[test]
+fn mismatched_message_type() {
+    let (server, client) = setup();
+
+    let start = client
+        .registration_start(&mut OsRng, P256, PASSWORD)
+        .unwrap();
+    assert!(matches!(
+        server.login_start(
+            &mut OsRng,
+            None,
+            &start.message,
+            CLIENT_IDENTIFIER,
+            Default::default(),
+        ),
+        Err(ProtocolError::MessageTypeMismatchError {
+            expected: 4,
+            actual: 1,
+        })
+    ));
+}
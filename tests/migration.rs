@@ -10,6 +10,10 @@ use digest::OutputSizeUser;
 use generic_array::GenericArray;
 use generic_array::sequence::{Concat, Split};
 use generic_array::typenum::Sum;
+#[cfg(feature = "compat-v3")]
+use opaque_ke::compat;
+#[cfg(feature = "compat-v3")]
+use opaque_ke::errors::ProtocolError;
 use opaque_ke::ksf::Identity;
 use opaque_ke::{CipherSuite, ClientLogin, ServerLogin, ServerRegistration, ServerSetup, TripleDh};
 use opaque_ke_3::key_exchange::group::KeGroup as v3KeGroup;
@@ -44,9 +48,10 @@ impl CipherSuite for NewCipherSuite {
     type Ksf = Identity;
 }
 
-#[test]
-fn registration_upload() {
-    // V3 registration.
+fn v3_records() -> (
+    v3ServerSetup<OldCipherSuite>,
+    v3ServerRegistration<OldCipherSuite>,
+) {
     let result = v3ClientRegistration::<OldCipherSuite>::start(&mut OsRng, PASSWORD).unwrap();
     let client = result.state;
 
@@ -61,7 +66,38 @@ fn registration_upload() {
         .unwrap()
         .message;
 
-    let old_registration = v3ServerRegistration::finish(upload);
+    (old_server_setup, v3ServerRegistration::finish(upload))
+}
+
+fn login(
+    server_setup: &ServerSetup<NewCipherSuite>,
+    registration: ServerRegistration<NewCipherSuite>,
+) {
+    let result = ClientLogin::<NewCipherSuite>::start(&mut OsRng, PASSWORD).unwrap();
+    let client = result.state;
+
+    let result = ServerLogin::start(
+        &mut OsRng,
+        server_setup,
+        Some(registration),
+        result.message,
+        CLIENT_IDENTIFIER,
+        Default::default(),
+    )
+    .unwrap();
+    let server = result.state;
+
+    let result = client
+        .finish(&mut OsRng, PASSWORD, result.message, Default::default())
+        .unwrap();
+
+    server.finish(result.message, Default::default()).unwrap();
+}
+
+#[test]
+fn registration_upload() {
+    // V3 registration.
+    let (old_server_setup, old_registration) = v3_records();
 
     // `ServerSetup` migration.
     let server_setup = {
@@ -90,23 +126,52 @@ fn registration_upload() {
         ServerRegistration::<NewCipherSuite>::deserialize(&old_registration_serialized).unwrap();
 
     // Check if new `ServerRegistration` still works.
-    let result = ClientLogin::<NewCipherSuite>::start(&mut OsRng, PASSWORD).unwrap();
-    let client = result.state;
-
-    let result = ServerLogin::start(
-        &mut OsRng,
-        &server_setup,
-        Some(registration),
-        result.message,
-        CLIENT_IDENTIFIER,
-        Default::default(),
-    )
-    .unwrap();
-    let server = result.state;
+    login(&server_setup, registration);
+}
 
-    let result = client
-        .finish(&mut OsRng, PASSWORD, result.message, Default::default())
-        .unwrap();
+#[cfg(feature = "compat-v3")]
+#[test]
+fn compat_v3() {
+    let (old_server_setup, old_registration) = v3_records();
+    let old_server_setup_serialized = old_server_setup.serialize();
+    let old_registration_serialized = old_registration.serialize();
 
-    server.finish(result.message, Default::default()).unwrap();
+    let server_setup =
+        compat::v3::server_setup::<NewCipherSuite>(&old_server_setup_serialized).unwrap();
+    assert_eq!(
+        server_setup.keypair().public().serialize().as_slice(),
+        old_server_setup.keypair().public().serialize().as_slice()
+    );
+    let registration =
+        compat::v3::server_registration::<NewCipherSuite>(&old_registration_serialized).unwrap();
+
+    login(&server_setup, registration);
+
+    for len in [0, old_server_setup_serialized.len() - 1] {
+        assert!(matches!(
+            compat::v3::server_setup::<NewCipherSuite>(&old_server_setup_serialized[..len]),
+            Err(ProtocolError::SizeError { .. })
+        ));
+    }
+    let mut too_long = old_registration_serialized.to_vec();
+    too_long.push(0);
+    assert!(matches!(
+        compat::v3::server_registration::<NewCipherSuite>(&too_long),
+        Err(ProtocolError::SizeError { .. })
+    ));
+
+    // Keys are validated, not only the lengths.
+    let seed_len = old_server_setup_serialized.len() - 2 * 32;
+    let mut zero_sk = old_server_setup_serialized.to_vec();
+    zero_sk[seed_len..seed_len + 32].fill(0);
+    assert!(matches!(
+        compat::v3::server_setup::<NewCipherSuite>(&zero_sk),
+        Err(ProtocolError::SerializationError)
+    ));
+    let mut invalid_pk = old_registration_serialized.to_vec();
+    invalid_pk[..33].fill(0);
+    assert!(matches!(
+        compat::v3::server_registration::<NewCipherSuite>(&invalid_pk),
+        Err(ProtocolError::SerializationError)
+    ));
 }
@@ -36,11 +36,13 @@ use elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, Tag, ToEncodedPoint};
 use elliptic_curve::{AffinePoint, CurveArithmetic, FieldBytesSize, Group as _, ProjectivePoint};
 use generic_array::typenum::Unsigned;
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "secp256k1")]
+use opaque_ke::Secp256k1;
 use opaque_ke::key_exchange::KeyExchange;
 use opaque_ke::key_exchange::group::Group;
 #[cfg(all(feature = "ristretto255", feature = "ed25519"))]
 use opaque_ke::key_exchange::group::ed25519::{self, Ed25519};
-use opaque_ke::key_exchange::group::elliptic_curve::NonIdentity;
+use opaque_ke::key_exchange::group::elliptic_curve::CurveGroup;
 #[cfg(feature = "ecdsa")]
 use opaque_ke::key_exchange::sigma_i::ecdsa::{self, Ecdsa, PreHash};
 #[cfg(all(feature = "ristretto255", feature = "ed25519"))]
@@ -143,6 +145,25 @@ fn triple_dh_curve25519() {
     );
 }
 
+#[test]
+#[cfg(feature = "secp256k1")]
+fn triple_dh_secp256k1() {
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        type OprfCs = NistP256;
+        type KeyExchange = TripleDh<Secp256k1, Sha256>;
+        type Ksf = Identity;
+    }
+
+    test::<Suite>(
+        Mechanism::EccKeyPairGen,
+        ObjectIdentifier::new_unwrap("1.3.132.0.10"),
+        Attribute::Derive(true),
+        Mechanism::Sha256Hmac,
+    );
+}
+
 #[test]
 #[cfg(feature = "ecdsa")]
 fn sigma_i_p256() {
@@ -181,6 +202,25 @@ fn sigma_i_p384() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+fn sigma_i_secp256k1() {
+    struct Suite;
+
+    impl CipherSuite for Suite {
+        type OprfCs = NistP256;
+        type KeyExchange = SigmaI<Ecdsa<Secp256k1, Sha256>, Secp256k1, Sha256>;
+        type Ksf = Identity;
+    }
+
+    test::<Suite>(
+        Mechanism::EccKeyPairGen,
+        ObjectIdentifier::new_unwrap("1.3.132.0.10"),
+        Attribute::Sign(true),
+        Mechanism::Sha256Hmac,
+    );
+}
+
 #[test]
 #[cfg(all(feature = "ristretto255", feature = "ed25519"))]
 fn sigma_i_ed25519() {
@@ -429,6 +469,13 @@ impl Pkcs11PublicKey for NistP521 {
     }
 }
 
+#[cfg(feature = "secp256k1")]
+impl Pkcs11PublicKey for Secp256k1 {
+    fn pkcs11_public_key(data: &[u8]) -> PublicKey<Secp256k1> {
+        pkcs11_ec_public_key(data)
+    }
+}
+
 #[cfg(all(feature = "curve25519", feature = "ristretto255"))]
 impl Pkcs11PublicKey for Curve25519 {
     fn pkcs11_public_key(data: &[u8]) -> PublicKey<Curve25519> {
@@ -473,6 +520,17 @@ impl Pkcs11KeyExchange<TripleDh<NistP521, Sha512>> for RemoteKey {
     }
 }
 
+#[cfg(feature = "secp256k1")]
+impl Pkcs11KeyExchange<TripleDh<Secp256k1, Sha256>> for RemoteKey {
+    fn pkcs11_key_exchange<CS: CipherSuite>(
+        &self,
+        server_pk: &PublicKey<Secp256k1>,
+        client_pk: &PublicKey<Secp256k1>,
+    ) -> GenericArray<u8, <Secp256k1 as Group>::PkLen> {
+        pkcs_11_ecdsa_derive_secret::<Secp256k1>(self.0, server_pk, client_pk)
+    }
+}
+
 #[cfg(all(feature = "curve25519", feature = "ristretto255"))]
 impl Pkcs11KeyExchange<TripleDh<Curve25519, Sha512>> for RemoteKey {
     fn pkcs11_key_exchange<CS: CipherSuite>(
@@ -508,6 +566,17 @@ impl Pkcs11KeyExchange<SigmaI<Ecdsa<NistP384, Sha384>, NistP384, Sha384>> for Re
     }
 }
 
+#[cfg(all(feature = "ecdsa", feature = "secp256k1"))]
+impl Pkcs11KeyExchange<SigmaI<Ecdsa<Secp256k1, Sha256>, Secp256k1, Sha256>> for RemoteKey {
+    fn pkcs11_key_exchange<CS: CipherSuite>(
+        &self,
+        _: &PublicKey<Secp256k1>,
+        message: &Message<CS, Secp256k1>,
+    ) -> (ecdsa::Signature<k256::Secp256k1>, PreHash<Sha256>) {
+        pkcs_11_ecdsa_sign::<k256::Secp256k1, Sha256>(self.0, message.hash())
+    }
+}
+
 #[cfg(all(feature = "ristretto255", feature = "ed25519"))]
 impl Pkcs11KeyExchange<SigmaI<PureEddsa<Ed25519>, Ristretto255, Sha512>> for RemoteKey {
     fn pkcs11_key_exchange<CS: CipherSuite>(
@@ -521,13 +590,14 @@ impl Pkcs11KeyExchange<SigmaI<PureEddsa<Ed25519>, Ristretto255, Sha512>> for Rem
 
 fn pkcs11_ec_public_key<G>(data: &[u8]) -> PublicKey<G>
 where
-    G: Group<Pk = NonIdentity<G>> + CurveArithmetic,
-    FieldBytesSize<G>: ModulusSize,
-    AffinePoint<G>:
-        FromEncodedPoint<G> + ToEncodedPoint<G> + PrimeCurveAffine<Curve = ProjectivePoint<G>>,
+    G: CurveGroup,
+    FieldBytesSize<G::Curve>: ModulusSize,
+    AffinePoint<G::Curve>: FromEncodedPoint<G::Curve>
+        + ToEncodedPoint<G::Curve>
+        + PrimeCurveAffine<Curve = ProjectivePoint<G::Curve>>,
 {
     PublicKey::deserialize(
-        elliptic_curve::PublicKey::<G>::from_sec1_bytes(data)
+        elliptic_curve::PublicKey::<G::Curve>::from_sec1_bytes(data)
             .unwrap()
             .to_encoded_point(true)
             .as_bytes(),
@@ -569,9 +639,9 @@ fn pkcs_11_ecdsa_derive_secret<G>(
     client_pk: &PublicKey<G>,
 ) -> GenericArray<u8, <G as Group>::PkLen>
 where
-    G: Group<Pk = NonIdentity<G>> + CurveArithmetic,
-    AffinePoint<G>: DecompressPoint<G> + ToEncodedPoint<G>,
-    FieldBytesSize<G>: ModulusSize,
+    G: CurveGroup,
+    AffinePoint<G::Curve>: DecompressPoint<G::Curve> + ToEncodedPoint<G::Curve>,
+    FieldBytesSize<G::Curve>: ModulusSize,
 {
     let client_pk_point = client_pk.to_group_type();
     let client_pk = client_pk.serialize();
@@ -579,7 +649,7 @@ where
     let client_pk = client_pk.to_der().unwrap();
 
     let shared_secret_bytes = pkcs_11_dh_derive_secret(server_sk, &client_pk);
-    let shared_secret_point = AffinePoint::<G>::decompress(
+    let shared_secret_point = AffinePoint::<G::Curve>::decompress(
         &GenericArray::clone_from_slice(&shared_secret_bytes),
         Choice::from(0),
     )
@@ -587,7 +657,7 @@ where
     let mut shared_secret = GenericArray::default();
     shared_secret[1..].copy_from_slice(&shared_secret_bytes);
 
-    let shifted_client_pk = client_pk_point.0.to_point() + ProjectivePoint::<G>::generator();
+    let shifted_client_pk = client_pk_point.0.to_point() + ProjectivePoint::<G::Curve>::generator();
     let shifted_client_pk = shifted_client_pk.to_affine().to_encoded_point(true);
     let shifted_client_pk = OctetStringRef::new(shifted_client_pk.as_bytes()).unwrap();
     let shifted_client_pk = shifted_client_pk.to_der().unwrap();